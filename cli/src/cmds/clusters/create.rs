@@ -155,6 +155,9 @@ pub fn generate_query_config() -> QueryConfig {
     if config.query.clickhouse_handler_host.is_empty() {
         config.query.clickhouse_handler_host = "127.0.0.1".to_string();
     }
+    if config.query.pg_handler_host.is_empty() {
+        config.query.pg_handler_host = "127.0.0.1".to_string();
+    }
 
     if config.query.http_handler_host.is_empty() {
         config.query.http_handler_host = "127.0.0.1".to_string();
@@ -165,6 +168,9 @@ pub fn generate_query_config() -> QueryConfig {
     if !portpicker::is_free(config.query.clickhouse_handler_port) {
         config.query.clickhouse_handler_port = portpicker::pick_unused_port().unwrap();
     }
+    if !portpicker::is_free(config.query.pg_handler_port) {
+        config.query.pg_handler_port = portpicker::pick_unused_port().unwrap();
+    }
     if !portpicker::is_free(config.query.http_handler_port) {
         config.query.http_handler_port = portpicker::pick_unused_port().unwrap();
     }
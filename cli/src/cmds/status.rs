@@ -464,6 +464,14 @@ impl LocalRuntime for LocalQueryConfig {
                 databend_query::configs::config_query::QUERY_CLICKHOUSE_HANDLER_PORT,
                 conf.query.clickhouse_handler_port.to_string(),
             )
+            .env(
+                databend_query::configs::config_query::QUERY_PG_HANDLER_HOST,
+                conf.query.pg_handler_host,
+            )
+            .env(
+                databend_query::configs::config_query::QUERY_PG_HANDLER_PORT,
+                conf.query.pg_handler_port.to_string(),
+            )
             .env(
                 databend_query::configs::config_query::QUERY_MYSQL_HANDLER_HOST,
                 conf.query.mysql_handler_host,
@@ -23,6 +23,7 @@ use sqlparser::ast::FunctionArg;
 use sqlparser::ast::FunctionArgExpr;
 use sqlparser::ast::Ident;
 use sqlparser::ast::Query;
+use sqlparser::ast::TrimWhereField;
 use sqlparser::ast::UnaryOperator;
 use sqlparser::ast::Value;
 
@@ -69,6 +70,7 @@ pub trait ExprVisitor: Sized + Send {
                 self.visit_substring(expr, substring_from, substring_for)
                     .await
             }
+            Expr::Trim { expr, trim_where } => self.visit_trim(expr, trim_where).await,
             Expr::Between {
                 expr,
                 negated,
@@ -77,6 +79,7 @@ pub trait ExprVisitor: Sized + Send {
             } => self.visit_between(expr, negated, low, high).await,
             Expr::Tuple(exprs) => self.visit_tuple(exprs).await,
             Expr::InList { expr, list, .. } => self.visit_inlist(expr, list).await,
+            Expr::InSubquery { expr, subquery, .. } => self.visit_insubquery(expr, subquery).await,
             other => Result::Err(ErrorCode::SyntaxException(format!(
                 "Unsupported expression: {}, type: {:?}",
                 expr, other
@@ -96,6 +99,10 @@ pub trait ExprVisitor: Sized + Send {
         Ok(())
     }
 
+    async fn visit_insubquery(&mut self, expr: &Expr, _subquery: &Query) -> Result<()> {
+        ExprTraverser::accept(expr, self).await
+    }
+
     async fn visit_tuple(&mut self, exprs: &[Expr]) -> Result<()> {
         match exprs.len() {
             0 => Err(ErrorCode::SyntaxException(
@@ -219,4 +226,18 @@ pub trait ExprVisitor: Sized + Send {
 
         Ok(())
     }
+
+    async fn visit_trim(
+        &mut self,
+        expr: &Expr,
+        trim_where: &Option<(TrimWhereField, Box<Expr>)>,
+    ) -> Result<()> {
+        ExprTraverser::accept(expr, self).await?;
+
+        if let Some((_, trim_what)) = trim_where {
+            ExprTraverser::accept(trim_what, self).await?;
+        }
+
+        Ok(())
+    }
 }
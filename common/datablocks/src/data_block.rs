@@ -36,6 +36,58 @@ impl DataBlock {
         DataBlock { schema, columns }
     }
 
+    /// Create a block after checking that the columns match the schema:
+    /// the same number of columns, the same length, and the compatible types.
+    /// `create` skips these checks and is preferred on hot paths.
+    pub fn try_create(schema: DataSchemaRef, columns: Vec<ColumnRef>) -> Result<Self> {
+        if schema.fields().len() != columns.len() {
+            return Err(ErrorCode::DataStructMissMatch(format!(
+                "Block columns {} do not match the schema fields {}",
+                columns.len(),
+                schema.fields().len()
+            )));
+        }
+
+        let rows = columns.first().map_or(0, |c| c.len());
+        for (field, column) in schema.fields().iter().zip(columns.iter()) {
+            if column.len() != rows {
+                return Err(ErrorCode::BadDataArrayLength(format!(
+                    "Block column {} has {} rows, but the first column has {} rows",
+                    field.name(),
+                    column.len(),
+                    rows
+                )));
+            }
+
+            if !Self::is_compatible_type(field.data_type(), &column.data_type()) {
+                return Err(ErrorCode::BadDataValueType(format!(
+                    "Block column {} has type {:?}, but the schema requires {:?}",
+                    field.name(),
+                    column.data_type(),
+                    field.data_type()
+                )));
+            }
+        }
+
+        Ok(DataBlock { schema, columns })
+    }
+
+    // Columns only keep the physical type, e.g. a Date16 column is a UInt16 column.
+    // A nullable field also accepts the non-nullable column and the null column.
+    fn is_compatible_type(field_type: &DataTypePtr, column_type: &DataTypePtr) -> bool {
+        if field_type.is_nullable() {
+            if column_type.data_type_id().is_null() {
+                return true;
+            }
+        } else if column_type.is_nullable() {
+            return false;
+        }
+
+        let field_id = remove_nullable(field_type).data_type_id();
+        let column_id = remove_nullable(column_type).data_type_id();
+        field_id.to_physical_type() == column_id.to_physical_type()
+    }
+
     #[inline]
     pub fn empty() -> Self {
         DataBlock {
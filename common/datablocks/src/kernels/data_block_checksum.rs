@@ -0,0 +1,126 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::hash::Hasher;
+
+use common_arrow::arrow::bitmap::Bitmap;
+use common_datavalues2::prelude::*;
+use common_datavalues2::with_match_physical_primitive_type;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use twox_hash::XxHash64;
+
+use crate::DataBlock;
+
+impl DataBlock {
+    /// The xxhash64 of the block, the columns are hashed in schema order.
+    /// Each column hashes its validity bitmap first if it contains nulls, then its values,
+    /// so the checksum is independent of the slicing and the constant or nullable wrapping.
+    pub fn checksum(&self) -> Result<u64> {
+        let mut hasher = XxHash64::with_seed(0);
+        hasher.write_usize(self.num_columns());
+        hasher.write_usize(self.num_rows());
+
+        for column in self.columns() {
+            hash_column(column, &mut hasher)?;
+        }
+        Ok(hasher.finish())
+    }
+
+    /// Check the block against the checksum computed by the sender,
+    /// `stage` names where the block comes from in the error message.
+    pub fn verify_checksum(&self, expected: u64, stage: &str) -> Result<()> {
+        let actual = self.checksum()?;
+        match actual == expected {
+            true => Ok(()),
+            false => Err(ErrorCode::ChecksumMismatch(format!(
+                "Block checksum mismatch in {}, expected {:#018x}, but got {:#018x}",
+                stage, expected, actual
+            ))),
+        }
+    }
+}
+
+fn hash_column(column: &ColumnRef, hasher: &mut XxHash64) -> Result<()> {
+    let column = column.convert_full_column();
+    hasher.write_usize(column.len());
+
+    let type_id = column.data_type_id().to_physical_type();
+    match type_id {
+        PhysicalTypeID::Null => {}
+        PhysicalTypeID::Nullable => {
+            let col: &NullableColumn = Series::check_get(&column)?;
+            let validity = col.ensure_validity();
+            if validity.null_count() > 0 {
+                hash_bitmap(validity, hasher);
+            }
+            hash_column(col.inner(), hasher)?;
+        }
+        PhysicalTypeID::Boolean => {
+            let col: &BooleanColumn = Series::check_get(&column)?;
+            hash_bitmap(col.values(), hasher);
+        }
+        PhysicalTypeID::String => {
+            let col: &StringColumn = Series::check_get(&column)?;
+            for value in col.iter() {
+                hasher.write_usize(value.len());
+                hasher.write(value);
+            }
+        }
+        PhysicalTypeID::Array => {
+            let col: &ArrayColumn = Series::check_get(&column)?;
+            let offsets = col.offsets();
+            for window in offsets.windows(2) {
+                hasher.write_i64(window[1] - window[0]);
+            }
+
+            let start = offsets[0] as usize;
+            let length = offsets[offsets.len() - 1] as usize - start;
+            hash_column(&col.values().slice(start, length), hasher)?;
+        }
+        PhysicalTypeID::Struct => {
+            let col: &StructColumn = Series::check_get(&column)?;
+            for value in col.values() {
+                hash_column(value, hasher)?;
+            }
+        }
+        _ => {
+            with_match_physical_primitive_type!(type_id, |$T| {
+                let col: &PrimitiveColumn<$T> = Series::check_get(&column)?;
+                let values = col.values();
+                // Safe because the primitive values are plain old data.
+                let bytes = unsafe {
+                    std::slice::from_raw_parts(
+                        values.as_ptr() as *const u8,
+                        std::mem::size_of_val(values),
+                    )
+                };
+                hasher.write(bytes);
+            })
+        }
+    }
+    Ok(())
+}
+
+fn hash_bitmap(bitmap: &Bitmap, hasher: &mut XxHash64) {
+    let mut word = 0u64;
+    for (i, bit) in bitmap.iter().enumerate() {
+        word |= (bit as u64) << (i % 64);
+        if i % 64 == 63 {
+            hasher.write_u64(word);
+            word = 0;
+        }
+    }
+    hasher.write_u64(word);
+}
@@ -12,8 +12,15 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::hash_map::DefaultHasher;
+use std::hash::Hash;
+use std::hash::Hasher;
+
+use common_datavalues2::prelude::*;
+use common_exception::ErrorCode;
 use common_exception::Result;
 
+use crate::kernels::GroupIndices;
 use crate::kernels::HashMethodKeysU16;
 use crate::kernels::HashMethodKeysU32;
 use crate::kernels::HashMethodKeysU64;
@@ -48,48 +55,118 @@ impl DataBlock {
     }
 
     pub fn group_by_blocks(block: &DataBlock, column_names: &[String]) -> Result<Vec<DataBlock>> {
-        let method = Self::choose_hash_method(block, column_names)?;
-        Ok(match method {
+        // Dictionary columns are grouped on their keys, the groups take the rows of the block.
+        let keys_block = Self::group_keys_block(block, column_names)?;
+        let method = Self::choose_hash_method(&keys_block, column_names)?;
+        let groups = match method {
             HashMethodKind::Serializer(s) => {
-                let blocks = s
-                    .group_by(block, column_names)?
-                    .iter()
-                    .map(|(_, _, b)| b.clone())
-                    .collect();
-                blocks
+                Self::group_rows(s.group_by_get_indices(&keys_block, column_names)?)
             }
             HashMethodKind::KeysU8(s) => {
-                let blocks = s
-                    .group_by(block, column_names)?
-                    .iter()
-                    .map(|(_, _, b)| b.clone())
-                    .collect();
-                blocks
+                Self::group_rows(s.group_by_get_indices(&keys_block, column_names)?)
             }
             HashMethodKind::KeysU16(s) => {
-                let blocks = s
-                    .group_by(block, column_names)?
-                    .iter()
-                    .map(|(_, _, b)| b.clone())
-                    .collect();
-                blocks
+                Self::group_rows(s.group_by_get_indices(&keys_block, column_names)?)
             }
             HashMethodKind::KeysU32(s) => {
-                let blocks = s
-                    .group_by(block, column_names)?
-                    .iter()
-                    .map(|(_, _, b)| b.clone())
-                    .collect();
-                blocks
+                Self::group_rows(s.group_by_get_indices(&keys_block, column_names)?)
             }
             HashMethodKind::KeysU64(s) => {
-                let blocks = s
-                    .group_by(block, column_names)?
+                Self::group_rows(s.group_by_get_indices(&keys_block, column_names)?)
+            }
+        };
+
+        groups
+            .iter()
+            .map(|rows| DataBlock::block_take_by_indices(block, rows))
+            .collect()
+    }
+
+    /// Same groups as `group_by_blocks`, spread into `num_partitions` buckets by
+    /// `hash(group_key) % num_partitions`, so that each bucket can be aggregated
+    /// on its own thread. Rows of one group always land in the same bucket.
+    pub fn group_by_partitioned_blocks(
+        block: &DataBlock,
+        column_names: &[String],
+        num_partitions: usize,
+    ) -> Result<Vec<Vec<DataBlock>>> {
+        if num_partitions == 0 {
+            return Err(ErrorCode::BadArguments(
+                "Number of group by partitions must be greater than 0",
+            ));
+        }
+
+        let keys_block = Self::group_keys_block(block, column_names)?;
+        let method = Self::choose_hash_method(&keys_block, column_names)?;
+        let partitions = match method {
+            HashMethodKind::Serializer(s) => Self::partition_rows(
+                s.group_by_get_indices(&keys_block, column_names)?,
+                num_partitions,
+            ),
+            HashMethodKind::KeysU8(s) => Self::partition_rows(
+                s.group_by_get_indices(&keys_block, column_names)?,
+                num_partitions,
+            ),
+            HashMethodKind::KeysU16(s) => Self::partition_rows(
+                s.group_by_get_indices(&keys_block, column_names)?,
+                num_partitions,
+            ),
+            HashMethodKind::KeysU32(s) => Self::partition_rows(
+                s.group_by_get_indices(&keys_block, column_names)?,
+                num_partitions,
+            ),
+            HashMethodKind::KeysU64(s) => Self::partition_rows(
+                s.group_by_get_indices(&keys_block, column_names)?,
+                num_partitions,
+            ),
+        };
+
+        partitions
+            .iter()
+            .map(|groups| {
+                groups
                     .iter()
-                    .map(|(_, _, b)| b.clone())
-                    .collect();
-                blocks
+                    .map(|rows| DataBlock::block_take_by_indices(block, rows))
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// The group by columns of the block, a dictionary column is replaced by its keys.
+    fn group_keys_block(block: &DataBlock, column_names: &[String]) -> Result<DataBlock> {
+        let mut fields = Vec::with_capacity(column_names.len());
+        let mut columns = Vec::with_capacity(column_names.len());
+        for name in column_names {
+            let column = block.try_column_by_name(name)?;
+            match column.as_any().downcast_ref::<DictionaryColumn>() {
+                Some(dictionary) => {
+                    fields.push(DataField::new(name, u32::to_data_type()));
+                    columns.push(dictionary.keys().arc());
+                }
+                None => {
+                    fields.push(block.schema().field_with_name(name)?.clone());
+                    columns.push(column.clone());
+                }
             }
-        })
+        }
+        Ok(DataBlock::create(DataSchemaRefExt::create(fields), columns))
+    }
+
+    fn group_rows<T>(group_indices: GroupIndices<T>) -> Vec<Vec<u32>> {
+        group_indices.into_values().map(|(rows, _)| rows).collect()
+    }
+
+    fn partition_rows<T: Hash>(
+        group_indices: GroupIndices<T>,
+        num_partitions: usize,
+    ) -> Vec<Vec<Vec<u32>>> {
+        let mut partitions = vec![vec![]; num_partitions];
+        for (group_key, (rows, _)) in group_indices {
+            let mut hasher = DefaultHasher::new();
+            group_key.hash(&mut hasher);
+            let partition = (hasher.finish() % num_partitions as u64) as usize;
+            partitions[partition].push(rows);
+        }
+        partitions
     }
 }
@@ -22,7 +22,7 @@ use common_exception::Result;
 
 use crate::DataBlock;
 
-type GroupIndices<T> = HashMap<T, (Vec<u32>, Vec<DataValue>), ahash::RandomState>;
+pub type GroupIndices<T> = HashMap<T, (Vec<u32>, Vec<DataValue>), ahash::RandomState>;
 type GroupBlock<T> = Vec<(T, Vec<DataValue>, DataBlock)>;
 
 pub trait HashMethod {
@@ -190,17 +190,7 @@ impl HashMethod for HashMethodSerializer {
     fn build_keys(&self, group_columns: &[&ColumnRef], rows: usize) -> Result<Vec<Self::HashKey>> {
         let mut group_keys = Vec::with_capacity(rows);
         {
-            let mut group_key_len = 0;
-            for col in group_columns {
-                let typ = col.data_type();
-                let typ_id = typ.data_type_id();
-                if typ_id.is_integer() {
-                    group_key_len += typ_id.numeric_byte_size()?;
-                } else {
-                    group_key_len += 4;
-                }
-            }
-
+            let group_key_len = Self::group_key_len(group_columns);
             for _i in 0..rows {
                 group_keys.push(Vec::with_capacity(group_key_len));
             }
@@ -213,6 +203,25 @@ impl HashMethod for HashMethodSerializer {
     }
 }
 
+impl HashMethodSerializer {
+    /// The bytes reserved for the serialized key of one row. Numeric columns, which also hold
+    /// the dates and datetimes, reserve their physical width, the others reserve 4 bytes.
+    pub fn group_key_len(group_columns: &[&ColumnRef]) -> usize {
+        group_columns
+            .iter()
+            .map(
+                |col| match col.data_type().data_type_id().to_physical_type() {
+                    PhysicalTypeID::Int8 | PhysicalTypeID::UInt8 => 1,
+                    PhysicalTypeID::Int16 | PhysicalTypeID::UInt16 => 2,
+                    PhysicalTypeID::Int32 | PhysicalTypeID::UInt32 | PhysicalTypeID::Float32 => 4,
+                    PhysicalTypeID::Int64 | PhysicalTypeID::UInt64 | PhysicalTypeID::Float64 => 8,
+                    _ => 4,
+                },
+            )
+            .sum()
+    }
+}
+
 pub struct HashMethodFixedKeys<T> {
     t: PhantomData<T>,
 }
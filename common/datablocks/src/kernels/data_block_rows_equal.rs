@@ -0,0 +1,116 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues2::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::DataBlock;
+
+impl DataBlock {
+    /// Compare the row `left_indices[i]` of `left` with the row `right_indices[i]` of `right`
+    /// on the given columns, the i-th value of the result tells whether the two rows are equal.
+    /// NULL is not equal to anything, unless `null_safe` is true, then NULL is equal to NULL.
+    pub fn rows_equal(
+        left: &DataBlock,
+        left_indices: &[u32],
+        right: &DataBlock,
+        right_indices: &[u32],
+        column_names: &[String],
+        null_safe: bool,
+    ) -> Result<BooleanColumn> {
+        if left_indices.len() != right_indices.len() {
+            return Err(ErrorCode::BadArguments(format!(
+                "Rows equal expects the same number of indices, but got {} and {}",
+                left_indices.len(),
+                right_indices.len()
+            )));
+        }
+        Self::check_indices(left, left_indices)?;
+        Self::check_indices(right, right_indices)?;
+
+        let mut columns = Vec::with_capacity(column_names.len());
+        for name in column_names {
+            let left_column = left.try_column_by_name(name)?;
+            let right_column = right.try_column_by_name(name)?;
+
+            let left_type = remove_nullable(&left_column.data_type());
+            let right_type = remove_nullable(&right_column.data_type());
+            if left_type != right_type {
+                return Err(ErrorCode::BadDataValueType(format!(
+                    "Can not compare column {} with type {} and {}",
+                    name,
+                    left_type.name(),
+                    right_type.name()
+                )));
+            }
+            columns.push((left_column, right_column, left_type));
+        }
+
+        let mut result = vec![true; left_indices.len()];
+        if result.is_empty() {
+            return Ok(BooleanColumn::from_slice(&result));
+        }
+
+        for (left_column, right_column, data_type) in columns {
+            let type_id = data_type.data_type_id().to_physical_type();
+
+            with_match_scalar_type!(type_id, |$T| {
+                let left_viewer = <$T as Scalar>::try_create_viewer(left_column)?;
+                let right_viewer = <$T as Scalar>::try_create_viewer(right_column)?;
+
+                for (i, equal) in result.iter_mut().enumerate() {
+                    if !*equal {
+                        continue;
+                    }
+
+                    let left_row = left_indices[i] as usize;
+                    let right_row = right_indices[i] as usize;
+                    let left_valid = left_viewer.valid_at(left_row);
+                    let right_valid = right_viewer.valid_at(right_row);
+                    *equal = match (left_valid, right_valid) {
+                        (true, true) => {
+                            left_viewer.value_at(left_row) == right_viewer.value_at(right_row)
+                        }
+                        (false, false) => null_safe,
+                        _ => false,
+                    };
+                }
+            }, {
+                if type_id != PhysicalTypeID::Null {
+                    return Err(ErrorCode::BadDataValueType(format!(
+                        "Unsupported rows equal for column with type: {}",
+                        data_type.name()
+                    )));
+                }
+
+                // Both columns are NULL only.
+                result.iter_mut().for_each(|equal| *equal = *equal && null_safe);
+            })
+        }
+
+        Ok(BooleanColumn::from_slice(&result))
+    }
+
+    fn check_indices(block: &DataBlock, indices: &[u32]) -> Result<()> {
+        let num_rows = block.num_rows();
+        match indices.iter().find(|index| **index as usize >= num_rows) {
+            None => Ok(()),
+            Some(index) => Err(ErrorCode::BadArguments(format!(
+                "Row index {} is out of bounds, the block has {} rows",
+                index, num_rows
+            ))),
+        }
+    }
+}
@@ -30,6 +30,8 @@ pub struct SortColumnDescription {
     pub column_name: String,
     pub asc: bool,
     pub nulls_first: bool,
+    /// Compare the String keys ignoring ASCII case.
+    pub case_insensitive: bool,
 }
 
 impl DataBlock {
@@ -40,7 +42,7 @@ impl DataBlock {
     ) -> Result<DataBlock> {
         let order_columns = sort_columns_descriptions
             .iter()
-            .map(|f| Ok(block.try_column_by_name(&f.column_name)?.as_arrow_array()))
+            .map(|f| Ok(Self::sort_key_column(block, f)?.as_arrow_array()))
             .collect::<Result<Vec<_>>>()?;
 
         let order_arrays = sort_columns_descriptions
@@ -78,11 +80,8 @@ impl DataBlock {
         let sort_arrays = sort_columns_descriptions
             .iter()
             .map(|f| {
-                let left = lhs.try_column_by_name(&f.column_name)?.clone();
-                let left = left.as_arrow_array();
-
-                let right = rhs.try_column_by_name(&f.column_name)?.clone();
-                let right = right.as_arrow_array();
+                let left = Self::sort_key_column(lhs, f)?.as_arrow_array();
+                let right = Self::sort_key_column(rhs, f)?.as_arrow_array();
 
                 Ok(vec![left, right])
             })
@@ -140,6 +139,28 @@ impl DataBlock {
         Ok(DataBlock::create(lhs.schema().clone(), columns))
     }
 
+    // The column which the rows are compared with, String keys are lowercased
+    // if the sort is case insensitive.
+    fn sort_key_column(
+        block: &DataBlock,
+        sort_column_description: &SortColumnDescription,
+    ) -> Result<ColumnRef> {
+        let column = block.try_column_by_name(&sort_column_description.column_name)?;
+        let data_type = remove_nullable(&column.data_type());
+        if !sort_column_description.case_insensitive || data_type.data_type_id() != TypeID::String {
+            return Ok(column.clone());
+        }
+
+        let viewer = Vu8::try_create_viewer(column)?;
+        let values = (0..column.len())
+            .map(|row| match viewer.valid_at(row) {
+                true => Some(viewer.value_at(row).to_ascii_lowercase()),
+                false => None,
+            })
+            .collect::<Vec<_>>();
+        Ok(Series::from_data(values))
+    }
+
     pub fn take_arrays_by_slices(
         arrays: &[&dyn Array],
         slices: &[MergeSlice],
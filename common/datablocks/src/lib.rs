@@ -22,4 +22,5 @@ mod memory;
 pub use data_block::DataBlock;
 pub use data_block_debug::*;
 pub use kernels::*;
+pub use memory::InMemoryBlocks;
 pub use memory::InMemoryData;
@@ -19,7 +19,17 @@ use common_infallible::RwLock;
 
 use crate::DataBlock;
 
+/// Blocks of a memory table.
+///
+/// `generation` is bumped whenever existing blocks are removed (truncate, insert overwrite),
+/// block indexes taken from an earlier generation are no longer valid.
+#[derive(Default)]
+pub struct InMemoryBlocks {
+    pub generation: u64,
+    pub blocks: Vec<DataBlock>,
+}
+
 /// Shared store to support memory tables.
 ///
 /// Indexed by table id etc.
-pub type InMemoryData<K> = HashMap<K, Arc<RwLock<Vec<DataBlock>>>>;
+pub type InMemoryData<K> = HashMap<K, Arc<RwLock<InMemoryBlocks>>>;
@@ -15,6 +15,7 @@
 use common_arrow::arrow::record_batch::RecordBatch;
 use common_datablocks::DataBlock;
 use common_datavalues2::prelude::*;
+use common_exception::ErrorCode;
 use common_exception::Result;
 use pretty_assertions::assert_eq;
 
@@ -40,6 +41,76 @@ fn test_data_block() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_data_block_try_create() -> Result<()> {
+    let schema = DataSchemaRefExt::create(vec![
+        DataField::new("a", i64::to_data_type()),
+        DataField::new("b", Date16Type::arc()),
+        DataField::new_nullable("c", i32::to_data_type()),
+    ]);
+
+    // Valid, the date column is physically UInt16 and the nullable field accepts non-nullable column.
+    let block = DataBlock::try_create(schema.clone(), vec![
+        Series::from_data(vec![1i64, 2, 3]),
+        Series::from_data(vec![1u16, 2, 3]),
+        Series::from_data(vec![1i32, 2, 3]),
+    ])?;
+    assert_eq!(3, block.num_rows());
+    assert_eq!(3, block.num_columns());
+
+    let block = DataBlock::try_create(schema.clone(), vec![
+        Series::from_data(vec![1i64, 2, 3]),
+        Series::from_data(vec![1u16, 2, 3]),
+        Series::from_data(vec![Some(1i32), None, Some(3)]),
+    ])?;
+    assert_eq!(3, block.num_rows());
+
+    // Mismatched count.
+    let result = DataBlock::try_create(schema.clone(), vec![
+        Series::from_data(vec![1i64, 2, 3]),
+        Series::from_data(vec![1u16, 2, 3]),
+    ]);
+    assert_eq!(
+        ErrorCode::DataStructMissMatch("").code(),
+        result.err().unwrap().code()
+    );
+
+    // Mismatched length.
+    let result = DataBlock::try_create(schema.clone(), vec![
+        Series::from_data(vec![1i64, 2, 3]),
+        Series::from_data(vec![1u16, 2]),
+        Series::from_data(vec![1i32, 2, 3]),
+    ]);
+    assert_eq!(
+        ErrorCode::BadDataArrayLength("").code(),
+        result.err().unwrap().code()
+    );
+
+    // Mismatched type.
+    let result = DataBlock::try_create(schema.clone(), vec![
+        Series::from_data(vec!["x", "y", "z"]),
+        Series::from_data(vec![1u16, 2, 3]),
+        Series::from_data(vec![1i32, 2, 3]),
+    ]);
+    assert_eq!(
+        ErrorCode::BadDataValueType("").code(),
+        result.err().unwrap().code()
+    );
+
+    // Nullable column into a non-nullable field.
+    let result = DataBlock::try_create(schema, vec![
+        Series::from_data(vec![Some(1i64), None, Some(3)]),
+        Series::from_data(vec![1u16, 2, 3]),
+        Series::from_data(vec![1i32, 2, 3]),
+    ]);
+    assert_eq!(
+        ErrorCode::BadDataValueType("").code(),
+        result.err().unwrap().code()
+    );
+
+    Ok(())
+}
+
 #[test]
 fn test_data_block_convert() -> Result<()> {
     let schema = DataSchemaRefExt::create(vec![
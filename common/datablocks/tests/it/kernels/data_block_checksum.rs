@@ -0,0 +1,90 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datablocks::*;
+use common_datavalues2::prelude::*;
+use common_exception::Result;
+
+fn create_block(a: Vec<i64>, b: Vec<Option<&str>>) -> DataBlock {
+    let schema = DataSchemaRefExt::create(vec![
+        DataField::new("a", i64::to_data_type()),
+        DataField::new("b", wrap_nullable(&Vu8::to_data_type())),
+    ]);
+
+    DataBlock::create(schema, vec![Series::from_data(a), Series::from_data(b)])
+}
+
+#[test]
+fn test_data_block_checksum() -> Result<()> {
+    let block = create_block(vec![1, 2, 3], vec![Some("x"), None, Some("z")]);
+    let checksum = block.checksum()?;
+
+    // Same data, same checksum.
+    let same = create_block(vec![1, 2, 3], vec![Some("x"), None, Some("z")]);
+    assert_eq!(same.checksum()?, checksum);
+    block.verify_checksum(checksum, "test")?;
+
+    // A sliced block hashes as the rebuilt one.
+    let sliced = create_block(vec![0, 1, 2, 3], vec![
+        Some("w"),
+        Some("x"),
+        None,
+        Some("z"),
+    ]);
+    let sliced = DataBlock::create(sliced.schema().clone(), vec![
+        sliced.column(0).slice(1, 3),
+        sliced.column(1).slice(1, 3),
+    ]);
+    assert_eq!(sliced.checksum()?, checksum);
+
+    // Values and validity are both covered.
+    let changed_value = create_block(vec![1, 2, 4], vec![Some("x"), None, Some("z")]);
+    assert_ne!(changed_value.checksum()?, checksum);
+
+    let changed_string = create_block(vec![1, 2, 3], vec![Some("x"), None, Some("y")]);
+    assert_ne!(changed_string.checksum()?, checksum);
+
+    let changed_validity = create_block(vec![1, 2, 3], vec![Some("x"), Some(""), Some("z")]);
+    assert_ne!(changed_validity.checksum()?, checksum);
+
+    Ok(())
+}
+
+#[test]
+fn test_data_block_checksum_const_column() -> Result<()> {
+    let schema = DataSchemaRefExt::create(vec![DataField::new("a", i64::to_data_type())]);
+    let full = DataBlock::create(schema.clone(), vec![Series::from_data(vec![7i64, 7, 7])]);
+    let constant = DataBlock::create(schema, vec![ConstColumn::new(
+        Series::from_data(vec![7i64]),
+        3,
+    )
+    .arc()]);
+
+    assert_eq!(full.checksum()?, constant.checksum()?);
+    Ok(())
+}
+
+#[test]
+fn test_data_block_verify_checksum() -> Result<()> {
+    let block = create_block(vec![1, 2, 3], vec![Some("x"), None, Some("z")]);
+    let checksum = block.checksum()?;
+
+    let result = block.verify_checksum(checksum + 1, "stage 1 on node a");
+    assert!(result.is_err());
+
+    let err = result.unwrap_err();
+    assert_eq!(err.code(), 1075);
+    assert!(err.message().contains("stage 1 on node a"));
+    Ok(())
+}
@@ -70,3 +70,91 @@ fn test_data_block_group_by() -> Result<()> {
     }
     Ok(())
 }
+
+#[test]
+fn test_data_block_group_by_partitioned() -> Result<()> {
+    let schema = DataSchemaRefExt::create(vec![
+        DataField::new("a", i8::to_data_type()),
+        DataField::new("b", Vu8::to_data_type()),
+    ]);
+
+    let block = DataBlock::create(schema, vec![
+        Series::from_data(vec![1i8, 1, 2, 1, 2, 3, 4, 5, 4]),
+        Series::from_data(vec!["x1", "x1", "x2", "x1", "x2", "x3", "x4", "x5", "x4"]),
+    ]);
+
+    let format_groups = |blocks: Vec<DataBlock>| -> Result<Vec<String>> {
+        let mut groups = blocks
+            .iter()
+            .map(|b| pretty_format_blocks(&[b.clone()]))
+            .collect::<Result<Vec<_>>>()?;
+        groups.sort();
+        Ok(groups)
+    };
+
+    for columns in [vec!["a".to_string()], vec![
+        "a".to_string(),
+        "b".to_string(),
+    ]] {
+        let expected = format_groups(DataBlock::group_by_blocks(&block, &columns)?)?;
+        assert_eq!(expected.len(), 5);
+
+        // 5 groups in 2 buckets, at least one bucket holds several groups.
+        for num_partitions in [1, 2, 3, 8] {
+            let partitions =
+                DataBlock::group_by_partitioned_blocks(&block, &columns, num_partitions)?;
+            assert_eq!(partitions.len(), num_partitions);
+
+            let actual = format_groups(partitions.into_iter().flatten().collect())?;
+            assert_eq!(actual, expected);
+        }
+    }
+
+    let result = DataBlock::group_by_partitioned_blocks(&block, &["a".to_string()], 0);
+    assert!(result.is_err());
+    Ok(())
+}
+
+#[test]
+fn test_data_block_group_by_dictionary() -> Result<()> {
+    let schema = DataSchemaRefExt::create(vec![
+        DataField::new("a", i8::to_data_type()),
+        DataField::new("b", Vu8::to_data_type()),
+    ]);
+
+    let a = Series::from_data(vec![1i8, 1, 2, 1, 2, 3, 4, 5, 4]);
+    let b = Series::from_data(vec!["x1", "x1", "x2", "x1", "x2", "x3", "x4", "x1", "x4"]);
+    let block = DataBlock::create(schema.clone(), vec![a.clone(), b.clone()]);
+    let dictionary_block = DataBlock::create(schema, vec![a, DictionaryColumn::try_encode(&b)?]);
+
+    let format_groups = |blocks: Vec<DataBlock>| -> Result<Vec<String>> {
+        let mut groups = blocks
+            .iter()
+            .map(|b| pretty_format_blocks(&[b.clone()]))
+            .collect::<Result<Vec<_>>>()?;
+        groups.sort();
+        Ok(groups)
+    };
+
+    for columns in [vec!["b".to_string()], vec![
+        "a".to_string(),
+        "b".to_string(),
+    ]] {
+        let expected = format_groups(DataBlock::group_by_blocks(&block, &columns)?)?;
+        let actual = format_groups(DataBlock::group_by_blocks(&dictionary_block, &columns)?)?;
+        assert_eq!(actual, expected);
+
+        let partitions = DataBlock::group_by_partitioned_blocks(&dictionary_block, &columns, 3)?;
+        let actual = format_groups(partitions.into_iter().flatten().collect())?;
+        assert_eq!(actual, expected);
+    }
+
+    // The serialized group keys are the same as the ones of the full string column.
+    let rows = block.num_rows();
+    let mut expected = vec![vec![]; rows];
+    Series::serialize(block.column(1), &mut expected)?;
+    let mut actual = vec![vec![]; rows];
+    Series::serialize(dictionary_block.column(1), &mut actual)?;
+    assert_eq!(actual, expected);
+    Ok(())
+}
@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::sync::Arc;
+
 use common_datablocks::*;
 use common_datavalues2::prelude::*;
 use common_exception::Result;
@@ -59,3 +61,92 @@ fn test_data_block_group_by_hash() -> Result<()> {
     ]);
     Ok(())
 }
+
+#[test]
+fn test_data_block_group_by_hash_struct() -> Result<()> {
+    let a = Series::from_data(vec![1i32, 1, 2, 1, 1, 1]);
+    let b = Series::from_data(vec![Some("x"), Some("x"), Some("x"), None, None, Some("")]);
+    let struct_type: DataTypePtr = Arc::new(StructType::create(
+        vec!["a".to_string(), "b".to_string()],
+        vec![a.data_type(), b.data_type()],
+    ));
+    let column = StructColumn::from_data(vec![a, b], struct_type.clone()).arc();
+
+    let schema = DataSchemaRefExt::create(vec![DataField::new("s", struct_type.clone())]);
+    let block = DataBlock::create(schema, vec![column.clone()]);
+    let method = DataBlock::choose_hash_method(&block, &["s".to_string()])?;
+    assert_eq!(method.name(), HashMethodSerializer::default().name());
+
+    // The keys are compared field by field, NULL differs from the default value.
+    let hash = HashMethodSerializer::default();
+    let keys = hash.build_keys(&[&column], block.num_rows())?;
+    assert_eq!(keys[0], keys[1]);
+    assert_ne!(keys[0], keys[2]);
+    assert_ne!(keys[0], keys[3]);
+    assert_eq!(keys[3], keys[4]);
+    assert_ne!(keys[4], keys[5]);
+
+    // The group columns are restored from the keys.
+    let mut deserializer = struct_type.create_deserializer(keys.len());
+    for key in keys.iter() {
+        let mut reader = key.as_slice();
+        deserializer.de(&mut reader)?;
+        assert!(reader.is_empty());
+    }
+    let restored = deserializer.finish_to_column();
+    for row in 0..column.len() {
+        assert_eq!(restored.get(row), column.get(row));
+    }
+    Ok(())
+}
+
+#[test]
+fn test_data_block_group_by_hash_key_len() -> Result<()> {
+    let columns = vec![
+        ("i8", Series::from_data(vec![1i8]), 1),
+        ("i16", Series::from_data(vec![1i16]), 2),
+        ("i32", Series::from_data(vec![1i32]), 4),
+        ("i64", Series::from_data(vec![1i64]), 8),
+        ("u8", Series::from_data(vec![1u8]), 1),
+        ("u16", Series::from_data(vec![1u16]), 2),
+        ("u32", Series::from_data(vec![1u32]), 4),
+        ("u64", Series::from_data(vec![1u64]), 8),
+        ("f32", Series::from_data(vec![1.0f32]), 4),
+        ("f64", Series::from_data(vec![1.0f64]), 8),
+        // Date16, Date32, DateTime32 and DateTime64 columns are stored as integers.
+        ("date16", Series::from_data(vec![18869u16]), 2),
+        ("date32", Series::from_data(vec![18869i32]), 4),
+        ("datetime32", Series::from_data(vec![1630320462u32]), 4),
+        ("datetime64", Series::from_data(vec![1630320462000i64]), 8),
+        ("string", Series::from_data(vec!["x"]), 4),
+    ];
+
+    for (name, column, expect) in &columns {
+        let len = HashMethodSerializer::group_key_len(&[column]);
+        assert_eq!(len, *expect, "case: {}", name);
+    }
+
+    let all = columns.iter().map(|(_, c, _)| c).collect::<Vec<_>>();
+    let expect = columns.iter().map(|(_, _, len)| len).sum::<usize>();
+    assert_eq!(HashMethodSerializer::group_key_len(&all), expect);
+
+    // Integer keys which fit in 8 bytes use the fixed keys methods.
+    let schema = DataSchemaRefExt::create(vec![
+        DataField::new("date16", Date16Type::arc()),
+        DataField::new("i64", i64::to_data_type()),
+        DataField::new("u32", u32::to_data_type()),
+    ]);
+    let block = DataBlock::create(schema, vec![
+        Series::from_data(vec![18869u16]),
+        Series::from_data(vec![1i64]),
+        Series::from_data(vec![1u32]),
+    ]);
+
+    let method = DataBlock::choose_hash_method(&block, &["date16".to_string()])?;
+    assert_eq!(method.name(), HashMethodKeysU16::default().name());
+    let method = DataBlock::choose_hash_method(&block, &["i64".to_string()])?;
+    assert_eq!(method.name(), HashMethodKeysU64::default().name());
+    let method = DataBlock::choose_hash_method(&block, &["u32".to_string(), "i64".to_string()])?;
+    assert_eq!(method.name(), HashMethodSerializer::default().name());
+    Ok(())
+}
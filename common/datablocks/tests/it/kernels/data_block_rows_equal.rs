@@ -0,0 +1,177 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datablocks::*;
+use common_datavalues2::prelude::*;
+use common_exception::Result;
+use rand::rngs::StdRng;
+use rand::Rng;
+use rand::SeedableRng;
+
+#[test]
+fn test_data_block_rows_equal() -> Result<()> {
+    let schema = DataSchemaRefExt::create(vec![
+        DataField::new_nullable("a", i32::to_data_type()),
+        DataField::new("b", Vu8::to_data_type()),
+    ]);
+
+    let left = DataBlock::create(schema.clone(), vec![
+        Series::from_data(vec![Some(1i32), None, Some(3)]),
+        Series::from_data(vec!["x", "y", "z"]),
+    ]);
+    let right = DataBlock::create(schema, vec![
+        Series::from_data(vec![None, Some(1i32), Some(3)]),
+        Series::from_data(vec!["y", "x", "w"]),
+    ]);
+
+    let columns = &["a".to_string(), "b".to_string()];
+    let left_indices = &[0, 1, 2, 0];
+    let right_indices = &[1, 0, 2, 0];
+
+    let result = DataBlock::rows_equal(&left, left_indices, &right, right_indices, columns, false)?;
+    assert_eq!(result.values().iter().collect::<Vec<_>>(), vec![
+        true, false, false, false
+    ]);
+
+    let result = DataBlock::rows_equal(&left, left_indices, &right, right_indices, columns, true)?;
+    assert_eq!(result.values().iter().collect::<Vec<_>>(), vec![
+        true, true, false, false
+    ]);
+
+    // Only compare the column b.
+    let columns = &["b".to_string()];
+    let result = DataBlock::rows_equal(&left, left_indices, &right, right_indices, columns, false)?;
+    assert_eq!(result.values().iter().collect::<Vec<_>>(), vec![
+        true, true, false, false
+    ]);
+
+    Ok(())
+}
+
+#[test]
+fn test_data_block_rows_equal_const() -> Result<()> {
+    let schema = DataSchemaRefExt::create(vec![DataField::new("a", i32::to_data_type())]);
+
+    let left = DataBlock::create(schema.clone(), vec![ConstColumn::new(
+        Series::from_data(vec![2i32]),
+        3,
+    )
+    .arc()]);
+    let right = DataBlock::create(schema, vec![Series::from_data(vec![1i32, 2, 3])]);
+
+    let columns = &["a".to_string()];
+    let result = DataBlock::rows_equal(&left, &[0, 1, 2], &right, &[0, 1, 2], columns, false)?;
+    assert_eq!(result.values().iter().collect::<Vec<_>>(), vec![
+        false, true, false
+    ]);
+    Ok(())
+}
+
+#[test]
+fn test_data_block_rows_equal_errors() -> Result<()> {
+    let left = DataBlock::create(
+        DataSchemaRefExt::create(vec![DataField::new("a", i32::to_data_type())]),
+        vec![Series::from_data(vec![1i32, 2])],
+    );
+    let right = DataBlock::create(
+        DataSchemaRefExt::create(vec![DataField::new("a", i64::to_data_type())]),
+        vec![Series::from_data(vec![1i64, 2])],
+    );
+    let columns = &["a".to_string()];
+
+    let result = DataBlock::rows_equal(&left, &[0], &right, &[0], columns, false);
+    assert_eq!(
+        result.unwrap_err().message(),
+        "Can not compare column a with type Int32 and Int64"
+    );
+
+    let result = DataBlock::rows_equal(&left, &[0, 1], &left, &[0], columns, false);
+    assert_eq!(
+        result.unwrap_err().message(),
+        "Rows equal expects the same number of indices, but got 2 and 1"
+    );
+
+    let result = DataBlock::rows_equal(&left, &[2], &left, &[0], columns, false);
+    assert_eq!(
+        result.unwrap_err().message(),
+        "Row index 2 is out of bounds, the block has 2 rows"
+    );
+    Ok(())
+}
+
+#[test]
+fn test_data_block_rows_equal_random() -> Result<()> {
+    let mut rng = StdRng::seed_from_u64(2022);
+    let schema = DataSchemaRefExt::create(vec![
+        DataField::new_nullable("a", i32::to_data_type()),
+        DataField::new_nullable("b", Vu8::to_data_type()),
+        DataField::new("c", bool::to_data_type()),
+    ]);
+
+    let mut random_block = |rows: usize| {
+        let a = (0..rows)
+            .map(|_| match rng.gen_bool(0.2) {
+                true => None,
+                false => Some(rng.gen_range(0..3i32)),
+            })
+            .collect::<Vec<_>>();
+        let b = (0..rows)
+            .map(|_| match rng.gen_bool(0.2) {
+                true => None,
+                false => Some(["x", "y"][rng.gen_range(0..2)]),
+            })
+            .collect::<Vec<_>>();
+        let c = (0..rows).map(|_| rng.gen_bool(0.5)).collect::<Vec<_>>();
+
+        DataBlock::create(schema.clone(), vec![
+            Series::from_data(a),
+            Series::from_data(b),
+            Series::from_data(c),
+        ])
+    };
+
+    let left = random_block(64);
+    let right = random_block(32);
+    let left_indices = (0..512).map(|i| (i * 7 % 64) as u32).collect::<Vec<_>>();
+    let right_indices = (0..512).map(|i| (i * 5 % 32) as u32).collect::<Vec<_>>();
+    let columns = &["a".to_string(), "b".to_string(), "c".to_string()];
+
+    for null_safe in [false, true] {
+        let result = DataBlock::rows_equal(
+            &left,
+            &left_indices,
+            &right,
+            &right_indices,
+            columns,
+            null_safe,
+        )?;
+
+        // Compare with the naive row by row comparison of the values.
+        for (i, actual) in result.values().iter().enumerate() {
+            let left_row = left_indices[i] as usize;
+            let right_row = right_indices[i] as usize;
+            let expected = (0..columns.len()).all(|col| {
+                let left_value = left.column(col).get(left_row);
+                let right_value = right.column(col).get(right_row);
+                match (left_value.is_null(), right_value.is_null()) {
+                    (true, true) => null_safe,
+                    (false, false) => left_value == right_value,
+                    _ => false,
+                }
+            });
+            assert_eq!(actual, expected, "row {}, null_safe: {}", i, null_safe);
+        }
+    }
+    Ok(())
+}
@@ -33,6 +33,7 @@ fn test_data_block_sort() -> Result<()> {
             column_name: "a".to_owned(),
             asc: true,
             nulls_first: false,
+            case_insensitive: false,
         }];
         let results = DataBlock::sort_block(&raw, &options, Some(3))?;
         assert_eq!(raw.schema(), results.schema());
@@ -54,6 +55,7 @@ fn test_data_block_sort() -> Result<()> {
             column_name: "a".to_owned(),
             asc: false,
             nulls_first: false,
+            case_insensitive: false,
         }];
         let results = DataBlock::sort_block(&raw, &options, Some(3))?;
         assert_eq!(raw.schema(), results.schema());
@@ -94,6 +96,7 @@ fn test_data_block_merge_sort() -> Result<()> {
             column_name: "a".to_owned(),
             asc: true,
             nulls_first: false,
+            case_insensitive: false,
         }];
         let results = DataBlock::merge_sort_block(&raw1, &raw2, &options, None)?;
 
@@ -116,3 +119,270 @@ fn test_data_block_merge_sort() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_data_block_sort_case_insensitive() -> Result<()> {
+    let schema = DataSchemaRefExt::create(vec![
+        DataField::new("a", i64::to_data_type()),
+        DataField::new("b", Vu8::to_data_type()),
+    ]);
+
+    let raw = DataBlock::create(schema, vec![
+        Series::from_data(vec![1, 2, 3, 4]),
+        Series::from_data(vec!["b", "A", "a", "B"]),
+    ]);
+
+    {
+        let options = vec![SortColumnDescription {
+            column_name: "b".to_owned(),
+            asc: true,
+            nulls_first: false,
+            case_insensitive: false,
+        }];
+        let results = DataBlock::sort_block(&raw, &options, None)?;
+
+        let expected = vec![
+            "+---+---+",
+            "| a | b |",
+            "+---+---+",
+            "| 2 | A |",
+            "| 4 | B |",
+            "| 3 | a |",
+            "| 1 | b |",
+            "+---+---+",
+        ];
+        common_datablocks::assert_blocks_eq(expected, &[results]);
+    }
+
+    {
+        let options = vec![
+            SortColumnDescription {
+                column_name: "b".to_owned(),
+                asc: true,
+                nulls_first: false,
+                case_insensitive: true,
+            },
+            SortColumnDescription {
+                column_name: "a".to_owned(),
+                asc: true,
+                nulls_first: false,
+                case_insensitive: true,
+            },
+        ];
+        let results = DataBlock::sort_block(&raw, &options, None)?;
+        assert_eq!(raw.schema(), results.schema());
+
+        let expected = vec![
+            "+---+---+",
+            "| a | b |",
+            "+---+---+",
+            "| 2 | A |",
+            "| 3 | a |",
+            "| 1 | b |",
+            "| 4 | B |",
+            "+---+---+",
+        ];
+        common_datablocks::assert_blocks_eq(expected, &[results]);
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_data_block_merge_sort_case_insensitive() -> Result<()> {
+    let schema = DataSchemaRefExt::create(vec![
+        DataField::new("a", i64::to_data_type()),
+        DataField::new_nullable("b", Vu8::to_data_type()),
+    ]);
+
+    let raw1 = DataBlock::create(schema.clone(), vec![
+        Series::from_data(vec![1, 2]),
+        Series::from_data(vec![Some("a"), Some("C")]),
+    ]);
+
+    let raw2 = DataBlock::create(schema, vec![
+        Series::from_data(vec![4, 3]),
+        Series::from_data(vec![Some("B"), None]),
+    ]);
+
+    let options = vec![SortColumnDescription {
+        column_name: "b".to_owned(),
+        asc: true,
+        nulls_first: false,
+        case_insensitive: true,
+    }];
+    let results = DataBlock::merge_sort_block(&raw1, &raw2, &options, None)?;
+    assert_eq!(raw1.schema(), results.schema());
+
+    let expected = vec![
+        "+---+------+",
+        "| a | b    |",
+        "+---+------+",
+        "| 1 | a    |",
+        "| 4 | B    |",
+        "| 2 | C    |",
+        "| 3 | NULL |",
+        "+---+------+",
+    ];
+    common_datablocks::assert_blocks_eq(expected, &[results]);
+
+    Ok(())
+}
+
+fn nullable_schema() -> DataSchemaRef {
+    DataSchemaRefExt::create(vec![
+        DataField::new("a", wrap_nullable(&i64::to_data_type())),
+        DataField::new("b", wrap_nullable(&Vu8::to_data_type())),
+    ])
+}
+
+fn sort_description(column_name: &str, asc: bool, nulls_first: bool) -> SortColumnDescription {
+    SortColumnDescription {
+        column_name: column_name.to_owned(),
+        asc,
+        nulls_first,
+        case_insensitive: false,
+    }
+}
+
+fn int_values(values: &[Option<i64>]) -> Vec<DataValue> {
+    values
+        .iter()
+        .map(|v| match v {
+            Some(v) => DataValue::Int64(*v),
+            None => DataValue::Null,
+        })
+        .collect()
+}
+
+fn string_values(values: &[Option<&str>]) -> Vec<DataValue> {
+    values
+        .iter()
+        .map(|v| match v {
+            Some(v) => DataValue::String(v.as_bytes().to_vec()),
+            None => DataValue::Null,
+        })
+        .collect()
+}
+
+#[test]
+fn test_data_block_sort_nulls() -> Result<()> {
+    let raw = DataBlock::create(nullable_schema(), vec![
+        Series::from_data(vec![Some(1i64), None, Some(3), Some(2)]),
+        Series::from_data(vec![Some("b"), Some("a"), None, Some("c")]),
+    ]);
+
+    let tests = vec![
+        (
+            true,
+            true,
+            int_values(&[None, Some(1), Some(2), Some(3)]),
+            string_values(&[None, Some("a"), Some("b"), Some("c")]),
+        ),
+        (
+            true,
+            false,
+            int_values(&[Some(1), Some(2), Some(3), None]),
+            string_values(&[Some("a"), Some("b"), Some("c"), None]),
+        ),
+        (
+            false,
+            true,
+            int_values(&[None, Some(3), Some(2), Some(1)]),
+            string_values(&[None, Some("c"), Some("b"), Some("a")]),
+        ),
+        (
+            false,
+            false,
+            int_values(&[Some(3), Some(2), Some(1), None]),
+            string_values(&[Some("c"), Some("b"), Some("a"), None]),
+        ),
+    ];
+
+    for (asc, nulls_first, expect_ints, expect_strings) in tests {
+        let options = vec![sort_description("a", asc, nulls_first)];
+        let results = DataBlock::sort_block(&raw, &options, None)?;
+        assert_eq!(results.column(0).to_values(), expect_ints);
+
+        let options = vec![sort_description("b", asc, nulls_first)];
+        let results = DataBlock::sort_block(&raw, &options, None)?;
+        assert_eq!(results.column(1).to_values(), expect_strings);
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_data_block_sort_nulls_multi_keys() -> Result<()> {
+    let raw = DataBlock::create(nullable_schema(), vec![
+        Series::from_data(vec![Some(1i64), Some(1), Some(1), Some(2)]),
+        Series::from_data(vec![Some("x"), None, Some("y"), None]),
+    ]);
+
+    let tests = vec![
+        (true, string_values(&[None, Some("y"), Some("x"), None])),
+        (false, string_values(&[Some("y"), Some("x"), None, None])),
+    ];
+
+    for (nulls_first, expect) in tests {
+        let options = vec![
+            sort_description("a", true, nulls_first),
+            sort_description("b", false, nulls_first),
+        ];
+        let results = DataBlock::sort_block(&raw, &options, None)?;
+        assert_eq!(
+            results.column(0).to_values(),
+            int_values(&[Some(1), Some(1), Some(1), Some(2)])
+        );
+        assert_eq!(results.column(1).to_values(), expect);
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_data_block_merge_sort_nulls() -> Result<()> {
+    let raw1 = DataBlock::create(nullable_schema(), vec![
+        Series::from_data(vec![Some(1i64), None, Some(3)]),
+        Series::from_data(vec![Some("b1"), Some("b2"), Some("b3")]),
+    ]);
+
+    let raw2 = DataBlock::create(nullable_schema(), vec![
+        Series::from_data(vec![Some(2i64), None]),
+        Series::from_data(vec![Some("b4"), Some("b5")]),
+    ]);
+
+    let tests = vec![
+        (
+            true,
+            true,
+            int_values(&[None, None, Some(1), Some(2), Some(3)]),
+        ),
+        (
+            true,
+            false,
+            int_values(&[Some(1), Some(2), Some(3), None, None]),
+        ),
+        (
+            false,
+            true,
+            int_values(&[None, None, Some(3), Some(2), Some(1)]),
+        ),
+        (
+            false,
+            false,
+            int_values(&[Some(3), Some(2), Some(1), None, None]),
+        ),
+    ];
+
+    for (asc, nulls_first, expect) in tests {
+        let options = vec![sort_description("a", asc, nulls_first)];
+        let lhs = DataBlock::sort_block(&raw1, &options, None)?;
+        let rhs = DataBlock::sort_block(&raw2, &options, None)?;
+
+        let results = DataBlock::merge_sort_block(&lhs, &rhs, &options, None)?;
+        assert_eq!(results.column(0).to_values(), expect);
+    }
+
+    Ok(())
+}
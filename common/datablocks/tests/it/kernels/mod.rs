@@ -12,10 +12,12 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod data_block_checksum;
 mod data_block_concat;
 mod data_block_filter;
 mod data_block_group_by;
 mod data_block_group_by_hash;
+mod data_block_rows_equal;
 mod data_block_scatter;
 mod data_block_slice;
 mod data_block_sort;
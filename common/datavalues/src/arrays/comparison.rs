@@ -341,7 +341,46 @@ impl ArrayCompare<&DFStringArray> for DFStringArray {
     }
 }
 
-impl ArrayCompare<&DFNullArray> for DFNullArray {}
+impl DFNullArray {
+    /// Any comparison with null is null, broadcasting a single row like the other arrays.
+    fn comparison(&self, rhs: &DFNullArray) -> Result<DFBooleanArray> {
+        Ok(DFBooleanArray::full_null(self.len().max(rhs.len())))
+    }
+}
+
+impl ArrayCompare<&DFNullArray> for DFNullArray {
+    fn eq(&self, rhs: &DFNullArray) -> Result<DFBooleanArray> {
+        self.comparison(rhs)
+    }
+
+    fn neq(&self, rhs: &DFNullArray) -> Result<DFBooleanArray> {
+        self.comparison(rhs)
+    }
+
+    fn gt(&self, rhs: &DFNullArray) -> Result<DFBooleanArray> {
+        self.comparison(rhs)
+    }
+
+    fn gt_eq(&self, rhs: &DFNullArray) -> Result<DFBooleanArray> {
+        self.comparison(rhs)
+    }
+
+    fn lt(&self, rhs: &DFNullArray) -> Result<DFBooleanArray> {
+        self.comparison(rhs)
+    }
+
+    fn lt_eq(&self, rhs: &DFNullArray) -> Result<DFBooleanArray> {
+        self.comparison(rhs)
+    }
+
+    fn like(&self, rhs: &DFNullArray) -> Result<DFBooleanArray> {
+        self.comparison(rhs)
+    }
+
+    fn nlike(&self, rhs: &DFNullArray) -> Result<DFBooleanArray> {
+        self.comparison(rhs)
+    }
+}
 
 impl ArrayCompare<&DFStructArray> for DFStructArray {}
 
@@ -29,6 +29,10 @@ macro_rules! impl_compare {
         }
 
         match $self.data_type() {
+            DataType::Null => DFNullArray::from_arrow_array($self.get_array_ref().as_ref())
+                .$method(&DFNullArray::from_arrow_array(
+                    $rhs.get_array_ref().as_ref(),
+                )),
             DataType::Boolean => $self.bool().unwrap().$method($rhs.bool().unwrap()),
             DataType::String => $self.string().unwrap().$method($rhs.string().unwrap()),
             DataType::UInt8 => $self.u8().unwrap().$method($rhs.u8().unwrap()),
@@ -290,6 +290,27 @@ impl Series {
         }
     }
 
+    /// Check if series are equal. Note that `None == None` evaluates to `true`
+    pub fn series_equal_missing(&self, other: &Series) -> bool {
+        if self.get_data_ptr() == other.get_data_ptr() {
+            return true;
+        }
+        if self.len() != other.len() {
+            return false;
+        }
+        if self.null_count() != other.null_count() {
+            return false;
+        }
+
+        match self.eq(other) {
+            Ok(arr) => arr.into_iter().enumerate().all(|(row, equal)| match equal {
+                Some(equal) => equal,
+                None => self.is_null(row) && other.is_null(row),
+            }),
+            Err(_) => false,
+        }
+    }
+
     /// Get a pointer to the underlying data of this Series.
     /// Can be useful for fast comparisons.
     pub fn get_data_ptr(&self) -> usize {
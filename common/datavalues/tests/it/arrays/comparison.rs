@@ -0,0 +1,70 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use common_arrow::arrow::array::NullArray;
+use common_arrow::arrow::datatypes::DataType as ArrowType;
+use common_datavalues::prelude::*;
+use common_exception::Result;
+
+#[test]
+fn test_binary_array_compare() -> Result<()> {
+    let lhs = DFStringArray::new_from_opt_slice(&[
+        Some(&b"\x00\xff"[..]),
+        Some(&b"\x00\xff"[..]),
+        None,
+        Some(&b"\x00"[..]),
+    ]);
+    let rhs = DFStringArray::new_from_opt_slice(&[
+        Some(&b"\x00\xff"[..]),
+        Some(&b"\x00\xfe"[..]),
+        Some(&b"\x00\xff"[..]),
+        None,
+    ]);
+
+    let res = lhs.eq(&rhs)?;
+    let values: Vec<_> = res.into_iter().collect();
+    assert_eq!(values, [Some(true), Some(false), None, None]);
+
+    let res = lhs.neq(&rhs)?;
+    let values: Vec<_> = res.into_iter().collect();
+    assert_eq!(values, [Some(false), Some(true), None, None]);
+
+    // binary_col = x'00ff'
+    let scalar = DFStringArray::new_from_slice(&[&b"\x00\xff"[..]]);
+    let res = lhs.eq(&scalar)?;
+    let values: Vec<_> = res.into_iter().collect();
+    assert_eq!(values, [Some(true), Some(true), None, Some(false)]);
+
+    Ok(())
+}
+
+#[test]
+fn test_null_array_compare() -> Result<()> {
+    let lhs: DFNullArray = NullArray::new_null(ArrowType::Null, 3).into();
+    let rhs: DFNullArray = NullArray::new_null(ArrowType::Null, 3).into();
+    let scalar: DFNullArray = NullArray::new_null(ArrowType::Null, 1).into();
+
+    for res in [
+        lhs.eq(&rhs)?,
+        lhs.neq(&rhs)?,
+        lhs.gt(&rhs)?,
+        lhs.lt_eq(&rhs)?,
+        lhs.eq(&scalar)?,
+        scalar.neq(&lhs)?,
+    ] {
+        let values: Vec<_> = res.into_iter().collect();
+        assert_eq!(values, [None, None, None]);
+    }
+
+    Ok(())
+}
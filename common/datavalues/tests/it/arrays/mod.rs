@@ -13,6 +13,7 @@
 // limitations under the License.
 
 mod boolean;
+mod comparison;
 mod ops;
 mod primitive;
 mod string;
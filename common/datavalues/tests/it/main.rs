@@ -14,4 +14,5 @@
 
 mod arrays;
 mod data_array_filter;
+mod series;
 mod types;
@@ -0,0 +1,79 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::prelude::*;
+
+#[test]
+fn test_series_equal() {
+    struct Test {
+        name: &'static str,
+        lhs: Series,
+        rhs: Series,
+        equal: bool,
+        equal_missing: bool,
+    }
+
+    let tests = vec![
+        Test {
+            name: "no-nulls",
+            lhs: Series::new(vec![1i32, 2, 3]),
+            rhs: Series::new(vec![1i32, 2, 3]),
+            equal: true,
+            equal_missing: true,
+        },
+        Test {
+            name: "different-values",
+            lhs: Series::new(vec![1i32, 2, 3]),
+            rhs: Series::new(vec![1i32, 2, 4]),
+            equal: false,
+            equal_missing: false,
+        },
+        Test {
+            name: "nulls-at-same-positions",
+            lhs: Series::new(vec![Some(1i32), None, Some(3)]),
+            rhs: Series::new(vec![Some(1i32), None, Some(3)]),
+            equal: false,
+            equal_missing: true,
+        },
+        Test {
+            name: "nulls-at-different-positions",
+            lhs: Series::new(vec![Some(1i32), None, Some(3)]),
+            rhs: Series::new(vec![Some(1i32), Some(2), None]),
+            equal: false,
+            equal_missing: false,
+        },
+        Test {
+            name: "strings-with-nulls",
+            lhs: Series::new(vec![Some("a"), None]),
+            rhs: Series::new(vec![Some("a"), None]),
+            equal: false,
+            equal_missing: true,
+        },
+    ];
+
+    for test in tests {
+        assert_eq!(
+            test.lhs.series_equal(&test.rhs),
+            test.equal,
+            "case: {}",
+            test.name
+        );
+        assert_eq!(
+            test.lhs.series_equal_missing(&test.rhs),
+            test.equal_missing,
+            "case: {}",
+            test.name
+        );
+    }
+}
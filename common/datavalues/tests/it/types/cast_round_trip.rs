@@ -0,0 +1,224 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A seeded fuzz harness for `Series::cast_with_type`: random series of each type are
+//! cast to another type and back where that is lossless, and must come back unchanged.
+//! Failures print the seed, set `DATAVALUES_CAST_FUZZ_SEED` to replay or explore other seeds.
+
+use common_arrow::arrow::array::NullArray;
+use common_arrow::arrow::datatypes::DataType as ArrowType;
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use rand::distributions::uniform::SampleUniform;
+use rand::distributions::Distribution;
+use rand::distributions::Standard;
+use rand::rngs::StdRng;
+use rand::Rng;
+use rand::SeedableRng;
+
+const DEFAULT_SEED: u64 = 0x5eed_ca57;
+const ROUNDS: usize = 8;
+const ROWS: usize = 512;
+
+fn seed() -> u64 {
+    std::env::var("DATAVALUES_CAST_FUZZ_SEED")
+        .ok()
+        .and_then(|seed| seed.parse().ok())
+        .unwrap_or(DEFAULT_SEED)
+}
+
+// About a tenth of the values are nulls.
+fn random_series<T>(rng: &mut StdRng) -> Series
+where
+    T: Copy,
+    Standard: Distribution<T>,
+    Series: SeriesFrom<Vec<Option<T>>, [Option<T>]>,
+{
+    let values = (0..ROWS)
+        .map(|_| match rng.gen_bool(0.9) {
+            true => Some(rng.gen::<T>()),
+            false => None,
+        })
+        .collect::<Vec<_>>();
+    Series::new(values)
+}
+
+fn random_series_in<T>(rng: &mut StdRng, low: T, high: T) -> Series
+where
+    T: Copy + PartialOrd + SampleUniform,
+    Series: SeriesFrom<Vec<Option<T>>, [Option<T>]>,
+{
+    let values = (0..ROWS)
+        .map(|_| match rng.gen_bool(0.9) {
+            true => Some(rng.gen_range(low..=high)),
+            false => None,
+        })
+        .collect::<Vec<_>>();
+    Series::new(values)
+}
+
+fn assert_round_trip(seed: u64, series: &Series, via: &DataType) -> Result<()> {
+    let from = series.data_type().clone();
+    let back = series.cast_with_type(via)?.cast_with_type(&from)?;
+    assert!(
+        series.series_equal_missing(&back),
+        "seed {}: casting {:?} to {:?} and back is not lossless",
+        seed,
+        from,
+        via
+    );
+    Ok(())
+}
+
+#[test]
+fn test_cast_round_trip_widening() -> Result<()> {
+    let seed = seed();
+    let mut rng = StdRng::seed_from_u64(seed);
+
+    for _ in 0..ROUNDS {
+        let cases = vec![
+            (random_series::<u8>(&mut rng), vec![
+                DataType::UInt16,
+                DataType::UInt32,
+                DataType::UInt64,
+                DataType::Int16,
+                DataType::Int32,
+                DataType::Int64,
+                DataType::Float32,
+                DataType::Float64,
+                DataType::String,
+            ]),
+            (random_series::<u16>(&mut rng), vec![
+                DataType::UInt32,
+                DataType::UInt64,
+                DataType::Int32,
+                DataType::Int64,
+                DataType::Float32,
+                DataType::Float64,
+                DataType::String,
+            ]),
+            (random_series::<u32>(&mut rng), vec![
+                DataType::UInt64,
+                DataType::Int64,
+                DataType::Float64,
+                DataType::String,
+            ]),
+            (random_series::<u64>(&mut rng), vec![DataType::String]),
+            (random_series::<i8>(&mut rng), vec![
+                DataType::Int16,
+                DataType::Int32,
+                DataType::Int64,
+                DataType::Float32,
+                DataType::Float64,
+                DataType::String,
+            ]),
+            (random_series::<i16>(&mut rng), vec![
+                DataType::Int32,
+                DataType::Int64,
+                DataType::Float32,
+                DataType::Float64,
+                DataType::String,
+            ]),
+            (random_series::<i32>(&mut rng), vec![
+                DataType::Int64,
+                DataType::Float64,
+                DataType::String,
+            ]),
+            (random_series::<i64>(&mut rng), vec![DataType::String]),
+            (random_series::<f32>(&mut rng), vec![
+                DataType::Float64,
+                DataType::String,
+            ]),
+            (random_series::<f64>(&mut rng), vec![DataType::String]),
+            (random_series::<bool>(&mut rng), vec![
+                DataType::UInt8,
+                DataType::Int64,
+            ]),
+        ];
+
+        for (series, vias) in cases {
+            for via in vias {
+                assert_round_trip(seed, &series, &via)?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+// Narrowing casts are lossless as long as the values fit in the narrower type.
+#[test]
+fn test_cast_round_trip_narrowing_in_range() -> Result<()> {
+    let seed = seed();
+    let mut rng = StdRng::seed_from_u64(seed);
+
+    for _ in 0..ROUNDS {
+        let cases = vec![
+            (
+                random_series_in::<i64>(&mut rng, i8::MIN as i64, i8::MAX as i64),
+                DataType::Int8,
+            ),
+            (
+                random_series_in::<i64>(&mut rng, 0, u16::MAX as i64),
+                DataType::UInt16,
+            ),
+            (
+                random_series_in::<u64>(&mut rng, 0, u32::MAX as u64),
+                DataType::UInt32,
+            ),
+            (
+                random_series_in::<i32>(&mut rng, 0, i16::MAX as i32),
+                DataType::UInt16,
+            ),
+            (
+                random_series_in::<f64>(&mut rng, -1e30, 1e30),
+                DataType::String,
+            ),
+        ];
+
+        for (series, via) in cases {
+            assert_round_trip(seed, &series, &via)?;
+        }
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_cast_impossible() -> Result<()> {
+    let seed = seed();
+    let mut rng = StdRng::seed_from_u64(seed);
+    let struct_type = DataType::Struct(vec![DataField::new("a", DataType::Int32, false)]);
+
+    let series = vec![
+        random_series::<u8>(&mut rng),
+        random_series::<i64>(&mut rng),
+        random_series::<f64>(&mut rng),
+        random_series::<bool>(&mut rng),
+        DFNullArray::from(NullArray::new_null(ArrowType::Null, ROWS)).into_series(),
+    ];
+
+    for series in series {
+        let result = series.cast_with_type(&struct_type);
+        assert!(
+            result.is_err(),
+            "seed {}: casting {:?} to {:?} should fail",
+            seed,
+            series.data_type(),
+            struct_type
+        );
+    }
+
+    Ok(())
+}
@@ -13,4 +13,5 @@
 // limitations under the License.
 
 mod cast;
+mod cast_round_trip;
 mod serializations;
@@ -0,0 +1,166 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use common_arrow::arrow::array::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::prelude::*;
+
+/// A low-cardinality string column: every row holds an index (key) into a
+/// dictionary of distinct values. Its logical type is `String`, and the full
+/// `StringColumn` can be restored with `convert_full_column`.
+#[derive(Clone)]
+pub struct DictionaryColumn {
+    keys: PrimitiveColumn<u32>,
+    values: StringColumn,
+}
+
+impl DictionaryColumn {
+    /// The keys must be less than the number of values and the values must be distinct,
+    /// the group by relies on it. Outside of this crate a dictionary is built with `encode`.
+    pub(crate) fn new(keys: PrimitiveColumn<u32>, values: StringColumn) -> Self {
+        debug_assert!(
+            keys.values()
+                .iter()
+                .all(|key| (*key as usize) < values.len()),
+            "Dictionary keys must be less than the number of values"
+        );
+        Self { keys, values }
+    }
+
+    /// Encode a string column, the values keep the order of first appearance.
+    pub fn encode(column: &StringColumn) -> Self {
+        let mut map: HashMap<&[u8], u32> = HashMap::new();
+        let mut builder = MutableStringColumn::with_capacity(0);
+        let mut keys = Vec::with_capacity(column.len());
+
+        for value in column.iter() {
+            let key = *map.entry(value).or_insert_with(|| {
+                builder.append_value(value);
+                (builder.len() - 1) as u32
+            });
+            keys.push(key);
+        }
+
+        Self {
+            keys: PrimitiveColumn::new_from_vec(keys),
+            values: builder.finish(),
+        }
+    }
+
+    /// Encode a full or const string column into a `DictionaryColumn`.
+    pub fn try_encode(column: &ColumnRef) -> Result<ColumnRef> {
+        if column.as_any().downcast_ref::<DictionaryColumn>().is_some() {
+            return Ok(column.clone());
+        }
+
+        let column = column.convert_full_column();
+        let column: &StringColumn = column.as_any().downcast_ref().ok_or_else(|| {
+            ErrorCode::BadDataValueType(format!(
+                "Only String column can be dictionary encoded, but got: {:?}",
+                column.data_type()
+            ))
+        })?;
+        Ok(Arc::new(Self::encode(column)))
+    }
+
+    pub fn keys(&self) -> &PrimitiveColumn<u32> {
+        &self.keys
+    }
+
+    pub fn values(&self) -> &StringColumn {
+        &self.values
+    }
+
+    fn with_keys(&self, keys: ColumnRef) -> ColumnRef {
+        let keys: &PrimitiveColumn<u32> = unsafe { Series::static_cast(&keys) };
+        Arc::new(Self {
+            keys: keys.clone(),
+            values: self.values.clone(),
+        })
+    }
+}
+
+impl Column for DictionaryColumn {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn data_type(&self) -> DataTypePtr {
+        StringType::arc()
+    }
+
+    fn len(&self) -> usize {
+        self.keys.len()
+    }
+
+    fn memory_size(&self) -> usize {
+        self.keys.memory_size() + self.values.memory_size()
+    }
+
+    fn as_arrow_array(&self) -> ArrayRef {
+        self.convert_full_column().as_arrow_array()
+    }
+
+    fn arc(&self) -> ColumnRef {
+        Arc::new(self.clone())
+    }
+
+    fn slice(&self, offset: usize, length: usize) -> ColumnRef {
+        self.with_keys(self.keys.slice(offset, length))
+    }
+
+    fn filter(&self, filter: &BooleanColumn) -> ColumnRef {
+        self.with_keys(self.keys.filter(filter))
+    }
+
+    fn scatter(&self, indices: &[usize], scattered_size: usize) -> Vec<ColumnRef> {
+        self.keys
+            .scatter(indices, scattered_size)
+            .into_iter()
+            .map(|keys| self.with_keys(keys))
+            .collect()
+    }
+
+    fn replicate(&self, offsets: &[usize]) -> ColumnRef {
+        self.with_keys(self.keys.replicate(offsets))
+    }
+
+    fn convert_full_column(&self) -> ColumnRef {
+        let mut builder = MutableStringColumn::with_capacity(self.len());
+        for key in self.keys.values() {
+            let value = unsafe { self.values.value_unchecked(*key as usize) };
+            builder.append_value(value);
+        }
+        builder.to_column()
+    }
+
+    fn get(&self, index: usize) -> DataValue {
+        self.values.get(self.keys.values()[index] as usize)
+    }
+}
+
+impl std::fmt::Debug for DictionaryColumn {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let iter = self.keys.values().iter().map(|key| {
+            String::from_utf8_lossy(unsafe { self.values.value_unchecked(*key as usize) })
+        });
+        let head = "DictionaryColumn";
+        display_fmt(iter, head, self.len(), self.data_type_id(), f)
+    }
+}
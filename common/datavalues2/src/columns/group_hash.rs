@@ -40,7 +40,15 @@ impl Series {
     /// WARN: Can't use `&mut [Vec<u8>]` because it has performance drawback.
     /// Refer: https://github.com/rust-lang/rust-clippy/issues/8334
     pub fn serialize(column: &ColumnRef, vec: &mut Vec<Vec<u8>>) -> Result<()> {
+        if let Some(col) = column.as_any().downcast_ref::<DictionaryColumn>() {
+            return GroupHash::serialize(col, vec);
+        }
+
         let column = column.convert_full_column();
+        if let Some(col) = column.as_any().downcast_ref::<StructColumn>() {
+            return GroupHash::serialize(col, vec);
+        }
+
         // TODO support nullable
         let column = Series::remove_nullable(&column);
         let type_id = column.data_type_id().to_physical_type();
@@ -138,3 +146,50 @@ impl GroupHash for StringColumn {
         Ok(())
     }
 }
+
+impl GroupHash for DictionaryColumn {
+    /// Serialize each distinct value once, rows only copy the bytes of their key.
+    /// The result is the same as serializing the full `StringColumn`, so the aggregator can
+    /// share its hash table between blocks encoded with different dictionaries.
+    fn serialize(&self, vec: &mut Vec<Vec<u8>>) -> Result<()> {
+        assert_eq!(vec.len(), self.len());
+        let mut values = Vec::with_capacity(self.values().len());
+        for value in self.values().iter() {
+            let mut bytes = Vec::with_capacity(value.len() + 4);
+            BinaryWrite::write_binary(&mut bytes, value)?;
+            values.push(bytes);
+        }
+
+        for (key, vec) in self.keys().values().iter().zip(vec.iter_mut()) {
+            vec.extend_from_slice(&values[*key as usize]);
+        }
+        Ok(())
+    }
+}
+
+impl GroupHash for StructColumn {
+    /// Serialize the fields one after another, a nullable field is prefixed with
+    /// its validity so that NULL is distinguished from the default value.
+    fn serialize(&self, vec: &mut Vec<Vec<u8>>) -> Result<()> {
+        assert_eq!(vec.len(), self.len());
+        for field in self.values() {
+            if !field.is_nullable() {
+                Series::serialize(field, vec)?;
+                continue;
+            }
+
+            let mut values = vec![Vec::new(); self.len()];
+            Series::serialize(field, &mut values)?;
+            for (row, (value, vec)) in values.iter().zip(vec.iter_mut()).enumerate() {
+                match field.null_at(row) {
+                    true => BinaryWrite::write_scalar(vec, &0u8)?,
+                    false => {
+                        BinaryWrite::write_scalar(vec, &1u8)?;
+                        vec.extend_from_slice(value);
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
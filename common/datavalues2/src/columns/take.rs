@@ -34,6 +34,15 @@ impl Series {
             let validity_result = Bitmap::from_trusted_len_iter(values);
 
             Ok(Arc::new(NullableColumn::new(inner_result, validity_result)))
+        } else if let Some(dictionary) = column.as_any().downcast_ref::<DictionaryColumn>() {
+            let keys = dictionary.keys().values();
+            let keys: Vec<u32> = indices.iter().map(|index| keys[index.to_usize()]).collect();
+            let keys = PrimitiveColumn::new_from_vec(keys);
+
+            Ok(Arc::new(DictionaryColumn::new(
+                keys,
+                dictionary.values().clone(),
+            )))
         } else {
             let type_id = column.data_type_id().to_physical_type();
 
@@ -28,6 +28,9 @@ pub struct DataField {
     default_expr: Option<Vec<u8>>,
     #[ignore_malloc_size_of = "insignificant"]
     data_type: DataTypePtr,
+    /// comment is declared by `COMMENT 'xxx'` in the column definition
+    #[serde(default)]
+    comment: Option<String>,
 }
 
 impl DataField {
@@ -36,6 +39,7 @@ impl DataField {
             name: name.to_string(),
             default_expr: None,
             data_type,
+            comment: None,
         }
     }
 
@@ -45,6 +49,7 @@ impl DataField {
             name: name.to_string(),
             default_expr: None,
             data_type,
+            comment: None,
         }
     }
 
@@ -54,6 +59,12 @@ impl DataField {
         self
     }
 
+    #[must_use]
+    pub fn with_comment(mut self, comment: Option<String>) -> Self {
+        self.comment = comment;
+        self
+    }
+
     pub fn name(&self) -> &String {
         &self.name
     }
@@ -66,6 +77,10 @@ impl DataField {
         &self.default_expr
     }
 
+    pub fn comment(&self) -> &Option<String> {
+        &self.comment
+    }
+
     #[inline]
     pub fn is_nullable(&self) -> bool {
         self.data_type.is_nullable()
@@ -122,6 +137,9 @@ impl std::fmt::Debug for DataField {
                 &String::from_utf8(default_expr.to_owned()).unwrap(),
             );
         }
+        if let Some(ref comment) = self.comment {
+            debug_struct.field("comment", comment);
+        }
         debug_struct.finish()
     }
 }
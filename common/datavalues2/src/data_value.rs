@@ -20,6 +20,8 @@ use std::sync::Arc;
 
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_io::prelude::format_float;
+use common_io::prelude::FloatFormat;
 use common_macros::MallocSizeOf;
 
 use crate::prelude::*;
@@ -216,7 +218,7 @@ impl DataValue {
         match self {
             DataValue::Int64(v) => Ok(Vec::<u8>::from((*v).to_string())),
             DataValue::UInt64(v) => Ok(Vec::<u8>::from((*v).to_string())),
-            DataValue::Float64(v) => Ok(Vec::<u8>::from((*v).to_string())),
+            DataValue::Float64(v) => Ok(Vec::<u8>::from(format_float(*v, FloatFormat::Shortest))),
             DataValue::String(v) => Ok(v.to_owned()),
             other => Result::Err(ErrorCode::BadDataValueType(format!(
                 "Unexpected type:{:?} to get string",
@@ -19,6 +19,7 @@ use std::sync::Arc;
 use common_arrow::arrow::datatypes::DataType as ArrowType;
 use common_arrow::arrow::datatypes::Field as ArrowField;
 use common_exception::Result;
+use common_io::prelude::FloatFormat;
 use dyn_clone::DynClone;
 
 use super::type_array::ArrayType;
@@ -98,6 +99,10 @@ pub trait DataType: std::fmt::Debug + Sync + Send + DynClone {
 
     fn create_mutable(&self, capacity: usize) -> Box<dyn MutableColumn>;
     fn create_serializer(&self) -> Box<dyn TypeSerializer>;
+    /// The serializer which prints the floats, including the nested ones, with `float_format`.
+    fn create_serializer_with_format(&self, _float_format: FloatFormat) -> Box<dyn TypeSerializer> {
+        self.create_serializer()
+    }
     fn create_deserializer(&self, capacity: usize) -> Box<dyn TypeDeserializer>;
 }
 
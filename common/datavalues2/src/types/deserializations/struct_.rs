@@ -0,0 +1,71 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_io::prelude::BinaryRead;
+
+use crate::prelude::*;
+
+pub struct StructDeserializer {
+    pub inners: Vec<Box<dyn TypeDeserializer>>,
+    pub types: Vec<DataTypePtr>,
+    pub data_type: DataTypePtr,
+}
+
+impl TypeDeserializer for StructDeserializer {
+    // See GroupHash.rs for StructColumn, a nullable field is prefixed with its validity.
+    fn de(&mut self, reader: &mut &[u8]) -> Result<()> {
+        for (inner, typ) in self.inners.iter_mut().zip(self.types.iter()) {
+            if typ.is_nullable() {
+                let valid: u8 = reader.read_scalar()?;
+                if valid == 0 {
+                    inner.de_null();
+                    continue;
+                }
+            }
+            inner.de(reader)?;
+        }
+        Ok(())
+    }
+
+    fn de_default(&mut self) {
+        for inner in self.inners.iter_mut() {
+            inner.de_default();
+        }
+    }
+
+    fn de_batch(&mut self, reader: &[u8], step: usize, rows: usize) -> Result<()> {
+        for row in 0..rows {
+            let mut reader = &reader[step * row..];
+            self.de(&mut reader)?;
+        }
+        Ok(())
+    }
+
+    fn de_text(&mut self, _reader: &[u8]) -> Result<()> {
+        Err(ErrorCode::UnImplement(
+            "Deserializing struct from text is not supported",
+        ))
+    }
+
+    fn finish_to_column(&mut self) -> ColumnRef {
+        let values = self
+            .inners
+            .iter_mut()
+            .map(|inner| inner.finish_to_column())
+            .collect();
+        StructColumn::from_data(values, self.data_type.clone()).arc()
+    }
+}
@@ -18,7 +18,9 @@ use common_clickhouse_srv::types::column::ArcColumnWrapper;
 use common_clickhouse_srv::types::column::ColumnFrom;
 use common_clickhouse_srv::types::HasSqlType;
 use common_exception::Result;
+use common_io::prelude::FloatFormat;
 use common_io::prelude::Marshal;
+use common_io::prelude::NumberFormat;
 use common_io::prelude::Unmarshal;
 use serde_json::Value;
 
@@ -26,32 +28,45 @@ use crate::prelude::*;
 
 pub struct NumberSerializer<T: PrimitiveType> {
     t: PhantomData<T>,
+    float_format: FloatFormat,
 }
 
-impl<T: PrimitiveType> Default for NumberSerializer<T> {
-    fn default() -> Self {
+impl<T: PrimitiveType> NumberSerializer<T> {
+    pub fn create(float_format: FloatFormat) -> Self {
         Self {
             t: Default::default(),
+            float_format,
         }
     }
 }
 
+impl<T: PrimitiveType> Default for NumberSerializer<T> {
+    fn default() -> Self {
+        Self::create(FloatFormat::default())
+    }
+}
+
 impl<T> TypeSerializer for NumberSerializer<T>
 where T: PrimitiveType
         + common_clickhouse_srv::types::StatBuffer
         + Marshal
         + Unmarshal<T>
+        + NumberFormat
         + HasSqlType
         + std::convert::Into<common_clickhouse_srv::types::Value>
         + std::convert::From<common_clickhouse_srv::types::Value>
 {
     fn serialize_value(&self, value: &DataValue) -> Result<String> {
-        Ok(format!("{:?}", value))
+        let value: T = DFTryFrom::try_from(value.clone())?;
+        Ok(value.format_number(self.float_format))
     }
 
     fn serialize_column(&self, column: &ColumnRef) -> Result<Vec<String>> {
         let column: &PrimitiveColumn<T> = Series::check_get(column)?;
-        let result: Vec<String> = column.iter().map(|x| format!("{}", x)).collect();
+        let result: Vec<String> = column
+            .iter()
+            .map(|x| x.format_number(self.float_format))
+            .collect();
         Ok(result)
     }
 
@@ -59,7 +74,13 @@ where T: PrimitiveType
         let column: &PrimitiveColumn<T> = Series::check_get(column)?;
         let result: Vec<Value> = column
             .iter()
-            .map(|x| serde_json::to_value(x).unwrap())
+            .map(|x| match self.float_format {
+                FloatFormat::Shortest => serde_json::to_value(x).unwrap(),
+                // The rounded float is still a JSON number.
+                FloatFormat::Fixed(_) => {
+                    serde_json::from_str(&x.format_number(self.float_format)).unwrap_or(Value::Null)
+                }
+            })
             .collect();
         Ok(result)
     }
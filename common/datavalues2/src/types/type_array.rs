@@ -18,6 +18,7 @@ use common_arrow::arrow::datatypes::DataType as ArrowType;
 use common_arrow::arrow::datatypes::Field;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_io::prelude::FloatFormat;
 
 use super::data_type::DataType;
 use super::data_type::DataTypePtr;
@@ -115,8 +116,12 @@ impl DataType for ArrayType {
     }
 
     fn create_serializer(&self) -> Box<dyn TypeSerializer> {
+        self.create_serializer_with_format(FloatFormat::default())
+    }
+
+    fn create_serializer_with_format(&self, float_format: FloatFormat) -> Box<dyn TypeSerializer> {
         Box::new(ArraySerializer {
-            inner: self.inner.create_serializer(),
+            inner: self.inner.create_serializer_with_format(float_format),
             typ: self.inner.clone(),
         })
     }
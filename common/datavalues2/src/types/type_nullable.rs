@@ -18,6 +18,7 @@ use std::sync::Arc;
 use common_arrow::arrow::bitmap::MutableBitmap;
 use common_arrow::arrow::datatypes::DataType as ArrowType;
 use common_exception::ErrorCode;
+use common_io::prelude::FloatFormat;
 
 use super::data_type::DataType;
 use super::data_type::DataTypePtr;
@@ -80,8 +81,12 @@ impl DataType for NullableType {
     }
 
     fn create_serializer(&self) -> Box<dyn TypeSerializer> {
+        self.create_serializer_with_format(FloatFormat::default())
+    }
+
+    fn create_serializer_with_format(&self, float_format: FloatFormat) -> Box<dyn TypeSerializer> {
         Box::new(NullableSerializer {
-            inner: self.inner.create_serializer(),
+            inner: self.inner.create_serializer_with_format(float_format),
         })
     }
 
@@ -17,6 +17,7 @@ use std::sync::Arc;
 
 use common_arrow::arrow::datatypes::DataType as ArrowType;
 use common_exception::Result;
+use common_io::prelude::FloatFormat;
 
 use super::data_type::DataType;
 use super::type_id::TypeID;
@@ -104,6 +105,13 @@ macro_rules! impl_numeric {
                 Box::new(NumberSerializer::<$ty>::default())
             }
 
+            fn create_serializer_with_format(
+                &self,
+                float_format: FloatFormat,
+            ) -> Box<dyn TypeSerializer> {
+                Box::new(NumberSerializer::<$ty>::create(float_format))
+            }
+
             fn create_deserializer(&self, capacity: usize) -> Box<dyn TypeDeserializer> {
                 Box::new(NumberDeserializer::<$ty> {
                     builder: MutablePrimitiveColumn::<$ty>::with_capacity(capacity),
@@ -17,6 +17,7 @@ use std::sync::Arc;
 use common_arrow::arrow::datatypes::DataType as ArrowType;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_io::prelude::FloatFormat;
 
 use super::data_type::DataType;
 use super::data_type::DataTypePtr;
@@ -98,7 +99,15 @@ impl DataType for StructType {
     }
 
     fn create_serializer(&self) -> Box<dyn TypeSerializer> {
-        let inners = self.types.iter().map(|v| v.create_serializer()).collect();
+        self.create_serializer_with_format(FloatFormat::default())
+    }
+
+    fn create_serializer_with_format(&self, float_format: FloatFormat) -> Box<dyn TypeSerializer> {
+        let inners = self
+            .types
+            .iter()
+            .map(|v| v.create_serializer_with_format(float_format))
+            .collect();
         Box::new(StructSerializer {
             names: self.names.clone(),
             inners,
@@ -106,8 +115,17 @@ impl DataType for StructType {
         })
     }
 
-    fn create_deserializer(&self, _capacity: usize) -> Box<dyn TypeDeserializer> {
-        todo!()
+    fn create_deserializer(&self, capacity: usize) -> Box<dyn TypeDeserializer> {
+        let inners = self
+            .types
+            .iter()
+            .map(|v| v.create_deserializer(capacity))
+            .collect();
+        Box::new(StructDeserializer {
+            inners,
+            types: self.types.clone(),
+            data_type: Arc::new(self.clone()),
+        })
     }
 
     fn create_mutable(&self, _capacity: usize) -> Box<dyn MutableColumn> {
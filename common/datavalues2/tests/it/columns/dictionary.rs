@@ -0,0 +1,72 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues2::prelude::*;
+use common_exception::Result;
+
+#[test]
+fn test_dictionary_column() -> Result<()> {
+    let column: StringColumn = NewColumn::new_from_slice(&["a", "b", "a", "c", "b", "a"]);
+    let dictionary = DictionaryColumn::encode(&column);
+
+    assert_eq!(dictionary.len(), 6);
+    assert_eq!(dictionary.values().len(), 3);
+    assert_eq!(dictionary.keys().values(), &[0, 1, 0, 2, 1, 0]);
+    assert_eq!(dictionary.data_type_id(), TypeID::String);
+    assert_eq!(dictionary.get(3), DataValue::String(b"c".to_vec()));
+
+    let full = dictionary.convert_full_column();
+    let full: &StringColumn = Series::check_get(&full)?;
+    assert_eq!(full.values(), column.values());
+    assert_eq!(full.offsets(), column.offsets());
+
+    let slice = dictionary.slice(2, 3);
+    assert_eq!(slice.len(), 3);
+    assert_eq!(slice.get(1), DataValue::String(b"c".to_vec()));
+    assert!(slice.as_any().downcast_ref::<DictionaryColumn>().is_some());
+
+    let filter = BooleanColumn::from_slice(&[true, false, false, true, true, false]);
+    let filtered = dictionary.filter(&filter);
+    let filtered: &DictionaryColumn = Series::check_get(&filtered)?;
+    assert_eq!(filtered.keys().values(), &[0, 2, 1]);
+    assert_eq!(filtered.values().len(), 3);
+
+    let taken = Series::take(&dictionary.arc(), &[5u32, 3, 1])?;
+    let taken: &DictionaryColumn = Series::check_get(&taken)?;
+    assert_eq!(taken.keys().values(), &[0, 2, 1]);
+
+    let scattered = dictionary.scatter(&[0, 1, 0, 1, 0, 1], 2);
+    assert_eq!(scattered[0].get(2), DataValue::String(b"b".to_vec()));
+    assert_eq!(scattered[1].get(1), DataValue::String(b"c".to_vec()));
+    Ok(())
+}
+
+#[test]
+fn test_dictionary_try_encode() -> Result<()> {
+    let column = Series::from_data(vec!["x", "y", "x"]);
+    let encoded = DictionaryColumn::try_encode(&column)?;
+    let encoded: &DictionaryColumn = Series::check_get(&encoded)?;
+    assert_eq!(encoded.values().len(), 2);
+
+    let column = ConstColumn::new(Series::from_data(vec!["z"]), 4).arc();
+    let encoded = DictionaryColumn::try_encode(&column)?;
+    let encoded: &DictionaryColumn = Series::check_get(&encoded)?;
+    assert_eq!(encoded.keys().values(), &[0, 0, 0, 0]);
+    assert_eq!(encoded.values().len(), 1);
+
+    let column = Series::from_data(vec![1i32, 2]);
+    let result = DictionaryColumn::try_encode(&column);
+    assert!(result.is_err());
+    Ok(())
+}
@@ -14,5 +14,6 @@
 
 mod boolean;
 mod builder;
+mod dictionary;
 mod primitive;
 mod string;
@@ -16,6 +16,7 @@ use std::sync::Arc;
 
 use common_datavalues2::prelude::*;
 use common_exception::Result;
+use common_io::prelude::FloatFormat;
 use pretty_assertions::assert_eq;
 
 #[test]
@@ -46,6 +47,22 @@ fn test_serializers() -> Result<()> {
             val_str: "1",
             col_str: vec!["1".to_owned(), "2".to_owned(), "1".to_owned()],
         },
+        Test {
+            name: "float32",
+            data_type: Float32Type::arc(),
+            value: DataValue::Float64(0.1f32 as f64),
+            column: Series::from_data(vec![0.1f32, 1.0f32, 1e-7f32]),
+            val_str: "0.1",
+            col_str: vec!["0.1".to_owned(), "1".to_owned(), "1e-7".to_owned()],
+        },
+        Test {
+            name: "float64",
+            data_type: Float64Type::arc(),
+            value: DataValue::Float64(0.1 + 0.2),
+            column: Series::from_data(vec![0.1f64, 1e20f64, -2.5f64]),
+            val_str: "0.30000000000000004",
+            col_str: vec!["0.1".to_owned(), "1e20".to_owned(), "-2.5".to_owned()],
+        },
         Test {
             name: "datetime32",
             data_type: DateTime32Type::arc(None),
@@ -162,6 +179,50 @@ fn test_serializers() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_serializers_with_float_format() -> Result<()> {
+    let float_format = FloatFormat::Fixed(2);
+
+    let serializer = Float64Type::arc().create_serializer_with_format(float_format);
+    let column = Series::from_data(vec![0.1f64 + 0.2, 1e20f64, -2.5f64]);
+    assert_eq!(
+        serializer.serialize_value(&DataValue::Float64(1.0 / 3.0))?,
+        "0.33"
+    );
+    assert_eq!(serializer.serialize_column(&column)?, vec![
+        "0.30",
+        "100000000000000000000.00",
+        "-2.50"
+    ]);
+    assert_eq!(serializer.serialize_json(&column)?, vec![
+        serde_json::json!(0.3),
+        serde_json::json!(1e20),
+        serde_json::json!(-2.5)
+    ]);
+
+    // The integers ignore the float format.
+    let serializer = Int32Type::arc().create_serializer_with_format(float_format);
+    assert_eq!(serializer.serialize_value(&DataValue::Int64(7))?, "7");
+
+    // The floats nested in other types are formatted too.
+    let data_type = NullableType::create(Float32Type::arc());
+    let serializer = data_type.create_serializer_with_format(float_format);
+    assert_eq!(
+        serializer.serialize_value(&DataValue::Float64(0.1))?,
+        "0.10"
+    );
+
+    let data_type = StructType::create(vec!["x".to_owned(), "y".to_owned()], vec![
+        Float64Type::arc(),
+        Int8Type::arc(),
+    ]);
+    let serializer = data_type.create_serializer_with_format(float_format);
+    let value = DataValue::Struct(vec![DataValue::Float64(1.2), DataValue::Int64(1)]);
+    assert_eq!(serializer.serialize_value(&value)?, "(1.20, 1)");
+
+    Ok(())
+}
+
 #[test]
 fn test_convert_arrow() {
     let t = DateTime32Type::arc(None);
@@ -135,6 +135,15 @@ build_exceptions! {
     // Network error codes.
     NetworkRequestError(1073),
 
+    // Table error codes.
+    TableDropped(1074),
+
+    // Data exchange error codes.
+    ChecksumMismatch(1075),
+
+    // Query complexity error codes.
+    QueryTooComplex(1076),
+
     // Tenant error codes.
     TenantIsEmpty(1101),
     IndexOutOfBounds(1102),
@@ -150,12 +150,25 @@ impl<const NULLABLE_RESULT: bool> AggregateFunction for AggregateNullUnaryAdapto
         let (all_null, validity) = col.validity();
         let not_null_column = Series::remove_nullable(col);
 
-        self.nested.accumulate_keys(
-            places,
-            offset + self.prefix_size,
-            &[not_null_column],
-            input_rows,
-        )?;
+        match validity {
+            // The null rows hold default values once the nullable wrapper is removed,
+            // only the valid rows are passed to the nested function.
+            Some(v) if v.null_count() > 0 => {
+                let columns = [not_null_column.convert_full_column()];
+                for (row, (valid, place)) in v.iter().zip(places.iter()).enumerate() {
+                    if valid {
+                        let place = place.next(offset + self.prefix_size);
+                        self.nested.accumulate_row(place, &columns, row)?;
+                    }
+                }
+            }
+            _ => self.nested.accumulate_keys(
+                places,
+                offset + self.prefix_size,
+                &[not_null_column],
+                input_rows,
+            )?,
+        }
 
         if !all_null {
             match validity {
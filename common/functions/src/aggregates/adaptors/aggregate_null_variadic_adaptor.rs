@@ -165,12 +165,28 @@ impl<const NULLABLE_RESULT: bool, const STKIP_NULL: bool> AggregateFunction
             not_null_columns.push(Series::remove_nullable(col))
         }
 
-        self.nested.accumulate_keys(
-            places,
-            offset + self.prefix_size,
-            &not_null_columns,
-            input_rows,
-        )?;
+        match &validity {
+            // The null rows hold default values once the nullable wrappers are removed,
+            // only the rows valid in all the columns are passed to the nested function.
+            Some(v) if v.null_count() > 0 => {
+                let columns = not_null_columns
+                    .iter()
+                    .map(|c| c.convert_full_column())
+                    .collect::<Vec<_>>();
+                for (row, (valid, place)) in v.iter().zip(places.iter()).enumerate() {
+                    if valid {
+                        let place = place.next(offset + self.prefix_size);
+                        self.nested.accumulate_row(place, &columns, row)?;
+                    }
+                }
+            }
+            _ => self.nested.accumulate_keys(
+                places,
+                offset + self.prefix_size,
+                &not_null_columns,
+                input_rows,
+            )?,
+        }
 
         if !is_all_null {
             match validity {
@@ -0,0 +1,341 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::alloc::Layout;
+use std::fmt;
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+use common_datavalues2::prelude::*;
+use common_datavalues2::with_match_primitive_type_id;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_io::prelude::*;
+use num::cast::AsPrimitive;
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::StateAddr;
+use crate::aggregates::aggregate_function_factory::AggregateFunctionDescription;
+use crate::aggregates::aggregator_common::assert_unary_arguments;
+use crate::aggregates::AggregateFunction;
+use crate::aggregates::AggregateFunctionRef;
+
+/// The state keeps the count, the mean and the sum of squares of differences from the mean (M2),
+/// so that the partial states of different partitions can be merged.
+#[derive(Serialize, Deserialize)]
+pub struct AggregateVarianceState {
+    pub count: u64,
+    pub mean: f64,
+    pub m2: f64,
+}
+
+/*
+ * Source: "Updating Formulae and a Pairwise Algorithm for Computing Sample Variances"
+ * (T. Chan, G. Golub, R. LeVeque, 1979), the single value update is Welford's algorithm.
+ */
+impl AggregateVarianceState {
+    #[inline(always)]
+    fn add(&mut self, value: f64) {
+        self.count += 1;
+        let delta = value - self.mean;
+        self.mean += delta / self.count as f64;
+        self.m2 += delta * (value - self.mean);
+    }
+
+    #[inline(always)]
+    fn merge(&mut self, other: &Self) {
+        if other.count == 0 {
+            return;
+        }
+
+        if self.count == 0 {
+            self.count = other.count;
+            self.mean = other.mean;
+            self.m2 = other.m2;
+            return;
+        }
+
+        let total = self.count + other.count;
+        let delta = other.mean - self.mean;
+        let factor = self.count as f64 * other.count as f64 / total as f64;
+
+        self.mean += delta * other.count as f64 / total as f64;
+        self.m2 += other.m2 + delta * delta * factor;
+        self.count = total;
+    }
+}
+
+#[derive(Clone)]
+pub struct AggregateVarianceFunction<T, R> {
+    display_name: String,
+    _arguments: Vec<DataField>,
+    t: PhantomData<T>,
+    r: PhantomData<R>,
+}
+
+impl<T, R> AggregateFunction for AggregateVarianceFunction<T, R>
+where
+    T: PrimitiveType + AsPrimitive<f64>,
+    R: AggregateVariance,
+{
+    fn name(&self) -> &str {
+        R::name()
+    }
+
+    fn return_type(&self) -> Result<DataTypePtr> {
+        Ok(f64::to_data_type())
+    }
+
+    fn init_state(&self, place: StateAddr) {
+        place.write(|| AggregateVarianceState {
+            count: 0,
+            mean: 0.0,
+            m2: 0.0,
+        });
+    }
+
+    fn state_layout(&self) -> Layout {
+        Layout::new::<AggregateVarianceState>()
+    }
+
+    fn accumulate(
+        &self,
+        place: StateAddr,
+        columns: &[ColumnRef],
+        validity: Option<&common_arrow::arrow::bitmap::Bitmap>,
+        _input_rows: usize,
+    ) -> Result<()> {
+        let state = place.get::<AggregateVarianceState>();
+        let column: &PrimitiveColumn<T> = unsafe { Series::static_cast(&columns[0]) };
+
+        match validity {
+            Some(bitmap) => {
+                for (value, is_valid) in column.iter().zip(bitmap.iter()) {
+                    if is_valid {
+                        state.add(value.as_());
+                    }
+                }
+            }
+            None => {
+                for value in column.iter() {
+                    state.add(value.as_());
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn accumulate_keys(
+        &self,
+        places: &[StateAddr],
+        offset: usize,
+        columns: &[ColumnRef],
+        _input_rows: usize,
+    ) -> Result<()> {
+        let column: &PrimitiveColumn<T> = unsafe { Series::static_cast(&columns[0]) };
+
+        column.iter().zip(places.iter()).for_each(|(value, place)| {
+            let place = place.next(offset);
+            let state = place.get::<AggregateVarianceState>();
+            state.add(value.as_());
+        });
+        Ok(())
+    }
+
+    fn accumulate_row(&self, place: StateAddr, columns: &[ColumnRef], row: usize) -> Result<()> {
+        let column: &PrimitiveColumn<T> = unsafe { Series::static_cast(&columns[0]) };
+
+        let state = place.get::<AggregateVarianceState>();
+        let v: f64 = unsafe { column.value_unchecked(row).as_() };
+        state.add(v);
+        Ok(())
+    }
+
+    fn serialize(&self, place: StateAddr, writer: &mut BytesMut) -> Result<()> {
+        let state = place.get::<AggregateVarianceState>();
+        serialize_into_buf(writer, state)
+    }
+
+    fn deserialize(&self, place: StateAddr, reader: &mut &[u8]) -> Result<()> {
+        let state = place.get::<AggregateVarianceState>();
+        *state = deserialize_from_slice(reader)?;
+
+        Ok(())
+    }
+
+    fn merge(&self, place: StateAddr, rhs: StateAddr) -> Result<()> {
+        let state = place.get::<AggregateVarianceState>();
+        let rhs = rhs.get::<AggregateVarianceState>();
+        state.merge(rhs);
+        Ok(())
+    }
+
+    #[allow(unused_mut)]
+    fn merge_result(&self, place: StateAddr, column: &mut dyn MutableColumn) -> Result<()> {
+        let state = place.get::<AggregateVarianceState>();
+        let column: &mut MutablePrimitiveColumn<f64> = Series::check_get_mutable_column(column)?;
+        column.append_value(R::apply(state));
+        Ok(())
+    }
+}
+
+impl<T, R> fmt::Display for AggregateVarianceFunction<T, R> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
+
+impl<T, R> AggregateVarianceFunction<T, R>
+where
+    T: PrimitiveType + AsPrimitive<f64>,
+    R: AggregateVariance,
+{
+    pub fn try_create(
+        display_name: &str,
+        arguments: Vec<DataField>,
+    ) -> Result<AggregateFunctionRef> {
+        Ok(Arc::new(Self {
+            display_name: display_name.to_string(),
+            _arguments: arguments,
+            t: PhantomData,
+            r: PhantomData,
+        }))
+    }
+}
+
+pub fn try_create_aggregate_variance_function<R: AggregateVariance>(
+    display_name: &str,
+    _params: Vec<DataValue>,
+    arguments: Vec<DataField>,
+) -> Result<Arc<dyn AggregateFunction>> {
+    assert_unary_arguments(display_name, arguments.len())?;
+
+    let data_type = arguments[0].data_type();
+
+    with_match_primitive_type_id!(data_type.data_type_id(), |$T| {
+        AggregateVarianceFunction::<$T, R>::try_create(display_name, arguments)
+    },
+
+    {
+        Err(ErrorCode::BadDataValueType(format!(
+            "{} does not support type '{:?}'",
+            R::name(),
+            data_type
+        )))
+    })
+}
+
+pub trait AggregateVariance: Send + Sync + 'static {
+    fn name() -> &'static str;
+
+    fn apply(state: &AggregateVarianceState) -> f64;
+}
+
+// Like the covariance functions, the empty population and the sample of less than two values
+// yield infinity.
+fn population_variance(state: &AggregateVarianceState) -> f64 {
+    match state.count {
+        0 => f64::INFINITY,
+        count => state.m2 / count as f64,
+    }
+}
+
+fn sample_variance(state: &AggregateVarianceState) -> f64 {
+    match state.count {
+        0 | 1 => f64::INFINITY,
+        count => state.m2 / (count - 1) as f64,
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Population variance function implementation
+struct AggregateVariancePopulationImpl;
+
+impl AggregateVariance for AggregateVariancePopulationImpl {
+    fn name() -> &'static str {
+        "AggregateVariancePopulationFunction"
+    }
+
+    fn apply(state: &AggregateVarianceState) -> f64 {
+        population_variance(state)
+    }
+}
+
+pub fn aggregate_variance_population_desc() -> AggregateFunctionDescription {
+    AggregateFunctionDescription::creator(Box::new(
+        try_create_aggregate_variance_function::<AggregateVariancePopulationImpl>,
+    ))
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Sample variance function implementation
+struct AggregateVarianceSampleImpl;
+
+impl AggregateVariance for AggregateVarianceSampleImpl {
+    fn name() -> &'static str {
+        "AggregateVarianceSampleFunction"
+    }
+
+    fn apply(state: &AggregateVarianceState) -> f64 {
+        sample_variance(state)
+    }
+}
+
+pub fn aggregate_variance_sample_desc() -> AggregateFunctionDescription {
+    AggregateFunctionDescription::creator(Box::new(
+        try_create_aggregate_variance_function::<AggregateVarianceSampleImpl>,
+    ))
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Population standard deviation function implementation
+struct AggregateStddevPopulationImpl;
+
+impl AggregateVariance for AggregateStddevPopulationImpl {
+    fn name() -> &'static str {
+        "AggregateStddevPopulationFunction"
+    }
+
+    fn apply(state: &AggregateVarianceState) -> f64 {
+        population_variance(state).sqrt()
+    }
+}
+
+pub fn aggregate_stddev_population_desc() -> AggregateFunctionDescription {
+    AggregateFunctionDescription::creator(Box::new(
+        try_create_aggregate_variance_function::<AggregateStddevPopulationImpl>,
+    ))
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Sample standard deviation function implementation
+struct AggregateStddevSampleImpl;
+
+impl AggregateVariance for AggregateStddevSampleImpl {
+    fn name() -> &'static str {
+        "AggregateStddevSampleFunction"
+    }
+
+    fn apply(state: &AggregateVarianceState) -> f64 {
+        sample_variance(state).sqrt()
+    }
+}
+
+pub fn aggregate_stddev_sample_desc() -> AggregateFunctionDescription {
+    AggregateFunctionDescription::creator(Box::new(
+        try_create_aggregate_variance_function::<AggregateStddevSampleImpl>,
+    ))
+}
@@ -20,7 +20,10 @@ use super::aggregate_covariance::aggregate_covariance_population_desc;
 use super::aggregate_covariance::aggregate_covariance_sample_desc;
 use super::aggregate_min_max::aggregate_max_function_desc;
 use super::aggregate_min_max::aggregate_min_function_desc;
-use super::aggregate_stddev_pop::aggregate_stddev_pop_function_desc;
+use super::aggregate_variance::aggregate_stddev_population_desc;
+use super::aggregate_variance::aggregate_stddev_sample_desc;
+use super::aggregate_variance::aggregate_variance_population_desc;
+use super::aggregate_variance::aggregate_variance_sample_desc;
 use super::aggregate_window_funnel::aggregate_window_funnel_function_desc;
 use super::AggregateCountFunction;
 use super::AggregateFunctionFactory;
@@ -41,9 +44,16 @@ impl Aggregators {
         factory.register("argMin", aggregate_arg_min_function_desc());
         factory.register("argMax", aggregate_arg_max_function_desc());
 
-        factory.register("stddev", aggregate_stddev_pop_function_desc());
-        factory.register("stddev_pop", aggregate_stddev_pop_function_desc());
-        factory.register("std", aggregate_stddev_pop_function_desc());
+        factory.register("stddev", aggregate_stddev_population_desc());
+        factory.register("stddev_pop", aggregate_stddev_population_desc());
+        factory.register("stddevPop", aggregate_stddev_population_desc());
+        factory.register("std", aggregate_stddev_population_desc());
+        factory.register("stddev_samp", aggregate_stddev_sample_desc());
+        factory.register("stddevSamp", aggregate_stddev_sample_desc());
+        factory.register("var_pop", aggregate_variance_population_desc());
+        factory.register("varPop", aggregate_variance_population_desc());
+        factory.register("var_samp", aggregate_variance_sample_desc());
+        factory.register("varSamp", aggregate_variance_sample_desc());
 
         factory.register("covar_samp", aggregate_covariance_sample_desc());
         factory.register("covar_pop", aggregate_covariance_population_desc());
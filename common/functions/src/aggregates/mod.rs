@@ -35,7 +35,7 @@ mod aggregate_covariance;
 mod aggregate_min_max;
 mod aggregate_null_result;
 mod aggregate_scalar_state;
-mod aggregate_stddev_pop;
+mod aggregate_variance;
 mod aggregate_window_funnel;
 
 pub use adaptors::*;
@@ -53,8 +53,10 @@ pub use aggregate_function_state::StateAddr;
 pub use aggregate_function_state::StateAddrs;
 pub use aggregate_min_max::AggregateMinMaxFunction;
 pub use aggregate_null_result::AggregateNullResultFunction;
-pub use aggregate_stddev_pop::AggregateStddevPopFunction;
 pub use aggregate_sum::AggregateSumFunction;
+pub use aggregate_variance::AggregateVariance;
+pub use aggregate_variance::AggregateVarianceFunction;
+pub use aggregate_variance::AggregateVarianceState;
 pub use aggregate_window_funnel::AggregateWindowFunnelFunction;
 pub use aggregator::Aggregators;
 pub use aggregator_common::*;
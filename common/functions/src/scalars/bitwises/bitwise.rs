@@ -0,0 +1,37 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::scalars::BitwiseNotFunction;
+use crate::scalars::BitwiseOperator;
+use crate::scalars::Function2Factory;
+
+#[derive(Clone)]
+pub struct BitwiseFunction;
+
+impl BitwiseFunction {
+    pub fn register(factory: &mut Function2Factory) {
+        factory.register_arithmetic("&", BitwiseOperator::And.desc());
+        factory.register_arithmetic("bitAnd", BitwiseOperator::And.desc());
+        factory.register_arithmetic("|", BitwiseOperator::Or.desc());
+        factory.register_arithmetic("bitOr", BitwiseOperator::Or.desc());
+        factory.register_arithmetic("^", BitwiseOperator::Xor.desc());
+        factory.register_arithmetic("bitXor", BitwiseOperator::Xor.desc());
+        factory.register_arithmetic("<<", BitwiseOperator::ShiftLeft.desc());
+        factory.register_arithmetic("bitShiftLeft", BitwiseOperator::ShiftLeft.desc());
+        factory.register_arithmetic(">>", BitwiseOperator::ShiftRight.desc());
+        factory.register_arithmetic("bitShiftRight", BitwiseOperator::ShiftRight.desc());
+        factory.register_arithmetic("~", BitwiseNotFunction::desc());
+        factory.register_arithmetic("bitNot", BitwiseNotFunction::desc());
+    }
+}
@@ -0,0 +1,233 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+use std::ops::BitAnd;
+use std::ops::BitOr;
+use std::ops::BitXor;
+use std::sync::Arc;
+
+use common_datavalues2::prelude::*;
+use common_datavalues2::with_match_integer_types_error;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use num::traits::CheckedShl;
+use num::traits::CheckedShr;
+use num::Zero;
+use num_traits::AsPrimitive;
+
+use crate::scalars::function_factory::FunctionFeatures;
+use crate::scalars::ArithmeticDescription;
+use crate::scalars::EvalContext;
+use crate::scalars::Function2;
+use crate::scalars::ScalarBinaryExpression;
+use crate::scalars::ScalarBinaryFunction;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum BitwiseOperator {
+    And,
+    Or,
+    Xor,
+    ShiftLeft,
+    ShiftRight,
+}
+
+impl BitwiseOperator {
+    pub fn desc(self) -> ArithmeticDescription {
+        let creator = move |_display_name: &str, args: &[&DataTypePtr]| self.try_create_func(args);
+
+        ArithmeticDescription::creator(Box::new(creator))
+            .features(FunctionFeatures::default().deterministic().num_arguments(2))
+    }
+
+    /// AND, OR and XOR return the least common integer type of the arguments,
+    /// the shifts return the type of the shifted argument.
+    pub fn try_create_func(self, args: &[&DataTypePtr]) -> Result<Box<dyn Function2>> {
+        for arg in args {
+            let arg = remove_nullable(arg);
+            if !arg.data_type_id().is_integer() {
+                return Err(ErrorCode::BadArguments(format!(
+                    "Function {} expects integer arguments, but got {}",
+                    self,
+                    arg.name()
+                )));
+            }
+        }
+
+        let left_type = remove_nullable(args[0]).data_type_id();
+        let right_type = remove_nullable(args[1]).data_type_id();
+
+        with_match_integer_types_error!(left_type, |$L| {
+            with_match_integer_types_error!(right_type, |$R| {
+                type O = <($L, $R) as ResultTypeOfBinary>::LeastSuper;
+                match self {
+                    BitwiseOperator::And => BitwiseBinaryFunction::<$L, $R, O, _>::create(
+                        self,
+                        O::to_data_type(),
+                        bit_and::<$L, $R, O>,
+                    ),
+                    BitwiseOperator::Or => BitwiseBinaryFunction::<$L, $R, O, _>::create(
+                        self,
+                        O::to_data_type(),
+                        bit_or::<$L, $R, O>,
+                    ),
+                    BitwiseOperator::Xor => BitwiseBinaryFunction::<$L, $R, O, _>::create(
+                        self,
+                        O::to_data_type(),
+                        bit_xor::<$L, $R, O>,
+                    ),
+                    BitwiseOperator::ShiftLeft => BitwiseBinaryFunction::<$L, $R, $L, _>::create(
+                        self,
+                        $L::to_data_type(),
+                        shift_left::<$L, $R>,
+                    ),
+                    BitwiseOperator::ShiftRight => BitwiseBinaryFunction::<$L, $R, $L, _>::create(
+                        self,
+                        $L::to_data_type(),
+                        shift_right::<$L, $R>,
+                    ),
+                }
+            })
+        })
+    }
+}
+
+impl fmt::Display for BitwiseOperator {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let display = match self {
+            BitwiseOperator::And => "bitAnd",
+            BitwiseOperator::Or => "bitOr",
+            BitwiseOperator::Xor => "bitXor",
+            BitwiseOperator::ShiftLeft => "bitShiftLeft",
+            BitwiseOperator::ShiftRight => "bitShiftRight",
+        };
+        write!(f, "{}", display)
+    }
+}
+
+fn bit_and<L, R, O>(l: L::RefType<'_>, r: R::RefType<'_>, _ctx: &mut EvalContext) -> O
+where
+    L: IntegerType + AsPrimitive<O>,
+    R: IntegerType + AsPrimitive<O>,
+    O: IntegerType + BitAnd<Output = O>,
+{
+    l.to_owned_scalar().as_() & r.to_owned_scalar().as_()
+}
+
+fn bit_or<L, R, O>(l: L::RefType<'_>, r: R::RefType<'_>, _ctx: &mut EvalContext) -> O
+where
+    L: IntegerType + AsPrimitive<O>,
+    R: IntegerType + AsPrimitive<O>,
+    O: IntegerType + BitOr<Output = O>,
+{
+    l.to_owned_scalar().as_() | r.to_owned_scalar().as_()
+}
+
+fn bit_xor<L, R, O>(l: L::RefType<'_>, r: R::RefType<'_>, _ctx: &mut EvalContext) -> O
+where
+    L: IntegerType + AsPrimitive<O>,
+    R: IntegerType + AsPrimitive<O>,
+    O: IntegerType + BitXor<Output = O>,
+{
+    l.to_owned_scalar().as_() ^ r.to_owned_scalar().as_()
+}
+
+// Shifting by a negative amount, or by the bit width or more, gives zero.
+fn shift_left<L, R>(l: L::RefType<'_>, r: R::RefType<'_>, _ctx: &mut EvalContext) -> L
+where
+    L: IntegerType + CheckedShl + Zero,
+    R: IntegerType + AsPrimitive<i64>,
+{
+    let r: i64 = r.to_owned_scalar().as_();
+    u32::try_from(r)
+        .ok()
+        .and_then(|r| l.to_owned_scalar().checked_shl(r))
+        .unwrap_or_else(L::zero)
+}
+
+fn shift_right<L, R>(l: L::RefType<'_>, r: R::RefType<'_>, _ctx: &mut EvalContext) -> L
+where
+    L: IntegerType + CheckedShr + Zero,
+    R: IntegerType + AsPrimitive<i64>,
+{
+    let r: i64 = r.to_owned_scalar().as_();
+    u32::try_from(r)
+        .ok()
+        .and_then(|r| l.to_owned_scalar().checked_shr(r))
+        .unwrap_or_else(L::zero)
+}
+
+#[derive(Clone)]
+pub struct BitwiseBinaryFunction<L: Scalar, R: Scalar, O: Scalar, F> {
+    op: BitwiseOperator,
+    result_type: DataTypePtr,
+    binary: ScalarBinaryExpression<L, R, O, F>,
+}
+
+impl<L, R, O, F> BitwiseBinaryFunction<L, R, O, F>
+where
+    L: Scalar + Send + Sync + Clone,
+    R: Scalar + Send + Sync + Clone,
+    O: Scalar + Send + Sync + Clone,
+    F: ScalarBinaryFunction<L, R, O> + Send + Sync + Clone + 'static,
+{
+    fn create(
+        op: BitwiseOperator,
+        result_type: DataTypePtr,
+        func: F,
+    ) -> Result<Box<dyn Function2>> {
+        Ok(Box::new(Self {
+            op,
+            result_type,
+            binary: ScalarBinaryExpression::<L, R, O, _>::new(func),
+        }))
+    }
+}
+
+impl<L, R, O, F> Function2 for BitwiseBinaryFunction<L, R, O, F>
+where
+    L: Scalar + Send + Sync + Clone,
+    R: Scalar + Send + Sync + Clone,
+    O: Scalar + Send + Sync + Clone,
+    F: ScalarBinaryFunction<L, R, O> + Send + Sync + Clone,
+{
+    fn name(&self) -> &str {
+        "BitwiseBinaryFunction"
+    }
+
+    fn return_type(&self, _args: &[&DataTypePtr]) -> Result<DataTypePtr> {
+        Ok(self.result_type.clone())
+    }
+
+    fn eval(&self, columns: &ColumnsWithField, _input_rows: usize) -> Result<ColumnRef> {
+        let col = self.binary.eval(
+            columns[0].column(),
+            columns[1].column(),
+            &mut EvalContext::default(),
+        )?;
+        Ok(Arc::new(col))
+    }
+}
+
+impl<L, R, O, F> fmt::Display for BitwiseBinaryFunction<L, R, O, F>
+where
+    L: Scalar + Send + Sync + Clone,
+    R: Scalar + Send + Sync + Clone,
+    O: Scalar + Send + Sync + Clone,
+    F: ScalarBinaryFunction<L, R, O> + Send + Sync + Clone,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.op)
+    }
+}
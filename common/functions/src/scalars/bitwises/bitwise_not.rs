@@ -0,0 +1,92 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+use std::marker::PhantomData;
+use std::ops::Not;
+use std::sync::Arc;
+
+use common_datavalues2::prelude::*;
+use common_datavalues2::with_match_integer_types_error;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::scalars::function_factory::FunctionFeatures;
+use crate::scalars::ArithmeticDescription;
+use crate::scalars::Function2;
+use crate::scalars::ScalarUnaryExpression;
+
+fn bit_not<T>(v: T::RefType<'_>) -> T
+where T: IntegerType + Not<Output = T> {
+    !v.to_owned_scalar()
+}
+
+pub struct BitwiseNotFunction;
+
+impl BitwiseNotFunction {
+    pub fn try_create_func(
+        _display_name: &str,
+        args: &[&DataTypePtr],
+    ) -> Result<Box<dyn Function2>> {
+        let arg_type = remove_nullable(args[0]);
+        if !arg_type.data_type_id().is_integer() {
+            return Err(ErrorCode::BadArguments(format!(
+                "Function bitNot expects an integer argument, but got {}",
+                arg_type.name()
+            )));
+        }
+
+        with_match_integer_types_error!(arg_type.data_type_id(), |$T| {
+            Ok(Box::new(BitwiseUnaryFunction::<$T> {
+                result_type: arg_type.clone(),
+                t: PhantomData,
+            }))
+        })
+    }
+
+    pub fn desc() -> ArithmeticDescription {
+        ArithmeticDescription::creator(Box::new(Self::try_create_func))
+            .features(FunctionFeatures::default().deterministic().num_arguments(1))
+    }
+}
+
+#[derive(Clone)]
+struct BitwiseUnaryFunction<T: Scalar> {
+    result_type: DataTypePtr,
+    t: PhantomData<T>,
+}
+
+impl<T> Function2 for BitwiseUnaryFunction<T>
+where T: IntegerType + Not<Output = T> + Send + Sync + Clone
+{
+    fn name(&self) -> &str {
+        "BitwiseUnaryFunction"
+    }
+
+    fn return_type(&self, _args: &[&DataTypePtr]) -> Result<DataTypePtr> {
+        Ok(self.result_type.clone())
+    }
+
+    fn eval(&self, columns: &ColumnsWithField, _input_rows: usize) -> Result<ColumnRef> {
+        let unary = ScalarUnaryExpression::<T, T, _>::new(bit_not::<T>);
+        let col = unary.eval(columns[0].column())?;
+        Ok(Arc::new(col))
+    }
+}
+
+impl<T: Scalar> fmt::Display for BitwiseUnaryFunction<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "bitNot")
+    }
+}
@@ -72,6 +72,22 @@ impl Function2 for ComparisonFunction {
         &self,
         args: &[&common_datavalues2::DataTypePtr],
     ) -> Result<common_datavalues2::DataTypePtr> {
+        // Structs only support equality, which compares them field by field.
+        let is_equality = matches!(
+            self.op,
+            DataValueComparisonOperator::Eq | DataValueComparisonOperator::NotEq
+        );
+        if is_equality
+            && args
+                .iter()
+                .all(|arg| remove_nullable(arg).data_type_id() == TypeID::Struct)
+        {
+            return match args.iter().any(|arg| Self::struct_may_be_null(arg)) {
+                true => Ok(wrap_nullable(&BooleanType::arc())),
+                false => Ok(BooleanType::arc()),
+            };
+        }
+
         // expect array & struct
         let has_array_struct = args
             .iter()
@@ -93,6 +109,10 @@ impl Function2 for ComparisonFunction {
         columns: &common_datavalues2::ColumnsWithField,
         input_rows: usize,
     ) -> Result<common_datavalues2::ColumnRef> {
+        if remove_nullable(columns[0].data_type()).data_type_id() == TypeID::Struct {
+            return self.eval_struct(columns, input_rows);
+        }
+
         if columns[0].data_type() != columns[1].data_type() {
             // TODO cached it inside the function
             let least_supertype = compare_coercion(columns[0].data_type(), columns[1].data_type())?;
@@ -131,6 +151,93 @@ impl Function2 for ComparisonFunction {
     }
 }
 
+impl ComparisonFunction {
+    /// The comparison of two structs is NULL if a struct or one of its fields may be NULL.
+    fn struct_may_be_null(data_type: &DataTypePtr) -> bool {
+        if data_type.is_nullable() {
+            return true;
+        }
+        match data_type.as_any().downcast_ref::<StructType>() {
+            Some(struct_type) => struct_type
+                .types()
+                .iter()
+                .any(|field| field.is_nullable() || field.data_type_id() == TypeID::Null),
+            None => false,
+        }
+    }
+
+    // Two structs are equal if all of their fields are equal. The result is NULL if
+    // either struct is NULL, or if no field differs and a field is NULL on either side.
+    fn eval_struct(&self, columns: &ColumnsWithField, input_rows: usize) -> Result<ColumnRef> {
+        let lhs = columns[0].column().convert_full_column();
+        let rhs = columns[1].column().convert_full_column();
+        let lhs_struct = Series::remove_nullable(&lhs);
+        let rhs_struct = Series::remove_nullable(&rhs);
+        let lhs_struct: &StructColumn = Series::check_get(&lhs_struct)?;
+        let rhs_struct: &StructColumn = Series::check_get(&rhs_struct)?;
+
+        if lhs_struct.values().len() != rhs_struct.values().len() {
+            return Err(ErrorCode::BadArguments(format!(
+                "Illegal types {:?} and {:?} of argument of function {}, structs must have the same number of fields",
+                columns[0].data_type(),
+                columns[1].data_type(),
+                self.name()
+            )));
+        }
+
+        let eq = ComparisonFunction {
+            op: DataValueComparisonOperator::Eq,
+        };
+        // Whether the fields compared so far are all equal, and whether any of them is NULL.
+        let mut states = vec![(true, false); input_rows];
+        let fields = lhs_struct.values().iter().zip(rhs_struct.values().iter());
+        for (lhs_field, rhs_field) in fields {
+            // A NULL typed field has no values to compare.
+            if lhs_field.data_type().is_null() || rhs_field.data_type().is_null() {
+                states.iter_mut().for_each(|(_, unknown)| *unknown = true);
+                continue;
+            }
+
+            let field_columns = vec![
+                ColumnWithField::new(
+                    Series::remove_nullable(lhs_field),
+                    DataField::new("lhs", remove_nullable(&lhs_field.data_type())),
+                ),
+                ColumnWithField::new(
+                    Series::remove_nullable(rhs_field),
+                    DataField::new("rhs", remove_nullable(&rhs_field.data_type())),
+                ),
+            ];
+            let field_eq = eq.eval(&field_columns, input_rows)?;
+            let field_eq: &BooleanColumn = Series::check_get(&field_eq)?;
+
+            for (row, (equal, unknown)) in states.iter_mut().enumerate() {
+                match lhs_field.null_at(row) || rhs_field.null_at(row) {
+                    true => *unknown = true,
+                    false => *equal &= field_eq.values().get_bit(row),
+                }
+            }
+        }
+
+        let is_equality = matches!(self.op, DataValueComparisonOperator::Eq);
+        let result = states.iter().enumerate().map(|(row, (equal, unknown))| {
+            match lhs.null_at(row) || rhs.null_at(row) || (*equal && *unknown) {
+                true => None,
+                false => Some(*equal == is_equality),
+            }
+        });
+
+        let lhs_type = columns[0].data_type();
+        let rhs_type = columns[1].data_type();
+        if Self::struct_may_be_null(lhs_type) || Self::struct_may_be_null(rhs_type) {
+            return Ok(Series::from_data(result.collect::<Vec<_>>()));
+        }
+        Ok(Series::from_data(
+            result.map(|res| res.unwrap_or(false)).collect::<Vec<_>>(),
+        ))
+    }
+}
+
 impl fmt::Display for ComparisonFunction {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.op)
@@ -0,0 +1,141 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues2::prelude::*;
+use common_exception::Result;
+
+use crate::scalars::cast_column_field;
+use crate::scalars::Function2;
+use crate::scalars::Function2Factory;
+
+/// `expr [NOT] BETWEEN low AND high`, the operand is evaluated once and compared with both bounds.
+///
+/// The comparisons are combined with three-valued logic, e.g. `0 BETWEEN 1 AND NULL` is false
+/// as `0 >= 1` already is, while `5 BETWEEN 1 AND NULL` is NULL.
+#[derive(Clone)]
+pub struct ComparisonBetweenFunction {
+    display_name: String,
+    negated: bool,
+    lower: Box<dyn Function2>,
+    lower_type: DataTypePtr,
+    upper: Box<dyn Function2>,
+    upper_type: DataTypePtr,
+}
+
+impl ComparisonBetweenFunction {
+    pub fn try_create(
+        display_name: &str,
+        negated: bool,
+        args: &[&DataTypePtr],
+    ) -> Result<Box<dyn Function2>> {
+        let (lower_op, upper_op) = match negated {
+            false => (">=", "<="),
+            true => ("<", ">"),
+        };
+
+        let factory = Function2Factory::instance();
+        let lower_args = [args[0], args[1]];
+        let lower = factory.get(lower_op, &lower_args)?;
+        let lower_type = lower.return_type(&lower_args)?;
+        let upper_args = [args[0], args[2]];
+        let upper = factory.get(upper_op, &upper_args)?;
+        let upper_type = upper.return_type(&upper_args)?;
+
+        Ok(Box::new(ComparisonBetweenFunction {
+            display_name: display_name.to_string(),
+            negated,
+            lower,
+            lower_type,
+            upper,
+            upper_type,
+        }))
+    }
+
+    fn eval_bound(
+        func: &dyn Function2,
+        data_type: &DataTypePtr,
+        columns: ColumnsWithField,
+        input_rows: usize,
+    ) -> Result<ColumnRef> {
+        let column = func.eval(&columns, input_rows)?;
+        let column = ColumnWithField::new(column, DataField::new("bound", data_type.clone()));
+        cast_column_field(&column, &wrap_nullable(&BooleanType::arc()))
+    }
+}
+
+impl Function2 for ComparisonBetweenFunction {
+    fn name(&self) -> &str {
+        &self.display_name
+    }
+
+    fn return_type(&self, args: &[&DataTypePtr]) -> Result<DataTypePtr> {
+        match args.iter().any(|arg| arg.is_nullable() || arg.is_null()) {
+            true => Ok(wrap_nullable(&BooleanType::arc())),
+            false => Ok(BooleanType::arc()),
+        }
+    }
+
+    fn eval(&self, columns: &ColumnsWithField, input_rows: usize) -> Result<ColumnRef> {
+        let lower = Self::eval_bound(
+            self.lower.as_ref(),
+            &self.lower_type,
+            vec![columns[0].clone(), columns[1].clone()],
+            input_rows,
+        )?;
+        let upper = Self::eval_bound(
+            self.upper.as_ref(),
+            &self.upper_type,
+            vec![columns[0].clone(), columns[2].clone()],
+            input_rows,
+        )?;
+
+        let lower_viewer = bool::try_create_viewer(&lower)?;
+        let upper_viewer = bool::try_create_viewer(&upper)?;
+
+        // BETWEEN is `lower AND upper` which any false comparison decides,
+        // NOT BETWEEN is `lower OR upper` which any true comparison decides.
+        let decisive = self.negated;
+        let mut builder = NullableColumnBuilder::<bool>::with_capacity(input_rows);
+        for (idx, (lower, upper)) in lower_viewer.iter().zip(upper_viewer.iter()).enumerate() {
+            let lower_valid = lower_viewer.valid_at(idx);
+            let upper_valid = upper_viewer.valid_at(idx);
+            match (lower_valid && lower == decisive) || (upper_valid && upper == decisive) {
+                true => builder.append(decisive, true),
+                false => builder.append(!decisive, lower_valid && upper_valid),
+            }
+        }
+        let column = builder.build(input_rows);
+
+        let arg_types = columns.iter().map(|c| c.data_type()).collect::<Vec<_>>();
+        match self.return_type(&arg_types)?.is_nullable() {
+            true => Ok(column),
+            false => Ok(Series::remove_nullable(&column)),
+        }
+    }
+
+    fn passthrough_null(&self) -> bool {
+        false
+    }
+}
+
+impl fmt::Display for ComparisonBetweenFunction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.negated {
+            false => write!(f, "BETWEEN"),
+            true => write!(f, "NOT BETWEEN"),
+        }
+    }
+}
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 mod comparison;
+mod comparison_between;
 mod comparison_eq;
 mod comparison_gt;
 mod comparison_gt_eq;
@@ -23,6 +24,7 @@ mod comparison_not_eq;
 mod comparison_regexp;
 
 pub use comparison::ComparisonFunction;
+pub use comparison_between::ComparisonBetweenFunction;
 pub use comparison_eq::ComparisonEqFunction;
 pub use comparison_gt::ComparisonGtFunction;
 pub use comparison_gt_eq::ComparisonGtEqFunction;
@@ -0,0 +1,158 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues2::prelude::*;
+use common_datavalues2::remove_nullable;
+use common_datavalues2::type_coercion::aggregate_types;
+use common_datavalues2::wrap_nullable;
+use common_exception::Result;
+
+use crate::scalars::cast_column_field;
+use crate::scalars::default_column_cast;
+use crate::scalars::function_factory::FunctionFeatures;
+use crate::scalars::Function2;
+use crate::scalars::Function2Description;
+
+/// Returns the first non-null argument of each row.
+/// The expression executor evaluates the arguments lazily, an argument is only
+/// evaluated for the rows where all the previous arguments are null.
+#[derive(Clone, Debug)]
+pub struct CoalesceFunction {
+    display_name: String,
+}
+
+impl CoalesceFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function2>> {
+        Ok(Box::new(CoalesceFunction {
+            display_name: display_name.to_string(),
+        }))
+    }
+
+    pub fn desc() -> Function2Description {
+        let features = FunctionFeatures::default()
+            .deterministic()
+            .variadic_arguments(1, usize::MAX - 1);
+        Function2Description::creator(Box::new(Self::try_create)).features(features)
+    }
+
+    /// The type of the partial result, which can hold the null rows.
+    fn nullable_type(return_type: &DataTypePtr) -> DataTypePtr {
+        wrap_nullable(&remove_nullable(return_type))
+    }
+
+    /// Replace the null rows of `column` by the values of `fill`,
+    /// `fill` has one value for each null row of `column`.
+    pub fn fill_null_rows(
+        column: &ColumnRef,
+        fill: &ColumnRef,
+        return_type: &DataTypePtr,
+    ) -> Result<ColumnRef> {
+        let nullable_type = Self::nullable_type(return_type);
+        let column = default_column_cast(column, &nullable_type)?;
+        let fill = default_column_cast(fill, &nullable_type)?;
+
+        let mut fill_row = 0;
+        let mut values = Vec::with_capacity(column.len());
+        for row in 0..column.len() {
+            match column.null_at(row) {
+                true => {
+                    values.push(fill.get(fill_row));
+                    fill_row += 1;
+                }
+                false => values.push(column.get(row)),
+            }
+        }
+
+        nullable_type.create_column(&values)
+    }
+
+    /// Convert the partial result into the return type, the result has no null rows
+    /// if the return type is not nullable.
+    pub fn finish(column: &ColumnRef, return_type: &DataTypePtr) -> Result<ColumnRef> {
+        if return_type.data_type_id() == TypeID::Null {
+            return Ok(NullColumn::new(column.len()).arc());
+        }
+
+        let column = default_column_cast(column, &Self::nullable_type(return_type))?;
+        let values = (0..column.len())
+            .map(|row| column.get(row))
+            .collect::<Vec<_>>();
+        return_type.create_column(&values)
+    }
+}
+
+impl Function2 for CoalesceFunction {
+    fn name(&self) -> &str {
+        "CoalesceFunction"
+    }
+
+    fn return_type(&self, args: &[&DataTypePtr]) -> Result<DataTypePtr> {
+        let types = args
+            .iter()
+            .filter(|arg| arg.data_type_id() != TypeID::Null)
+            .map(|arg| remove_nullable(arg))
+            .collect::<Vec<_>>();
+
+        if types.is_empty() {
+            return Ok(NullType::arc());
+        }
+
+        // The result is not null if one of the arguments is not nullable.
+        let super_type = aggregate_types(&types)?;
+        match args
+            .iter()
+            .any(|arg| arg.data_type_id() != TypeID::Null && !arg.is_nullable())
+        {
+            true => Ok(super_type),
+            false => Ok(wrap_nullable(&super_type)),
+        }
+    }
+
+    fn eval(&self, columns: &ColumnsWithField, input_rows: usize) -> Result<ColumnRef> {
+        let types = columns.iter().map(|c| c.data_type()).collect::<Vec<_>>();
+        let return_type = self.return_type(&types)?;
+        if return_type.data_type_id() == TypeID::Null {
+            return Ok(NullColumn::new(input_rows).arc());
+        }
+
+        let nullable_type = Self::nullable_type(&return_type);
+        let columns = columns
+            .iter()
+            .map(|column| cast_column_field(column, &nullable_type))
+            .collect::<Result<Vec<_>>>()?;
+
+        let values = (0..input_rows)
+            .map(|row| {
+                columns
+                    .iter()
+                    .find(|column| !column.null_at(row))
+                    .map(|column| column.get(row))
+                    .unwrap_or(DataValue::Null)
+            })
+            .collect::<Vec<_>>();
+        return_type.create_column(&values)
+    }
+
+    fn passthrough_null(&self) -> bool {
+        false
+    }
+}
+
+impl fmt::Display for CoalesceFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
@@ -12,8 +12,12 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use crate::scalars::CoalesceFunction;
 use crate::scalars::Function2Factory;
+use crate::scalars::GreatestFunction;
 use crate::scalars::IfFunction;
+use crate::scalars::LeastFunction;
+use crate::scalars::MultiIfFunction;
 
 #[derive(Clone)]
 pub struct ConditionalFunction;
@@ -21,5 +25,9 @@ pub struct ConditionalFunction;
 impl ConditionalFunction {
     pub fn register(factory: &mut Function2Factory) {
         factory.register("if", IfFunction::desc());
+        factory.register("coalesce", CoalesceFunction::desc());
+        factory.register("multiIf", MultiIfFunction::desc());
+        factory.register("greatest", GreatestFunction::desc());
+        factory.register("least", LeastFunction::desc());
     }
 }
@@ -0,0 +1,131 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues2::prelude::*;
+use common_datavalues2::remove_nullable;
+use common_datavalues2::type_coercion::aggregate_types;
+use common_datavalues2::with_match_scalar_type;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::scalars::cast_column_field;
+use crate::scalars::function_factory::FunctionFeatures;
+use crate::scalars::Function2;
+use crate::scalars::Function2Description;
+
+pub type GreatestFunction = GreatestLeastFunction<true>;
+pub type LeastFunction = GreatestLeastFunction<false>;
+
+/// greatest(a, b, ...) and least(a, b, ...) return the largest and the smallest argument of
+/// each row. The null arguments are skipped, the result is null only if all of them are null.
+#[derive(Clone, Debug)]
+pub struct GreatestLeastFunction<const IS_GREATEST: bool> {
+    display_name: String,
+}
+
+impl<const IS_GREATEST: bool> GreatestLeastFunction<IS_GREATEST> {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function2>> {
+        Ok(Box::new(Self {
+            display_name: display_name.to_string(),
+        }))
+    }
+
+    pub fn desc() -> Function2Description {
+        let features = FunctionFeatures::default()
+            .deterministic()
+            .variadic_arguments(2, usize::MAX);
+        Function2Description::creator(Box::new(Self::try_create)).features(features)
+    }
+}
+
+impl<const IS_GREATEST: bool> Function2 for GreatestLeastFunction<IS_GREATEST> {
+    fn name(&self) -> &str {
+        &*self.display_name
+    }
+
+    fn return_type(&self, args: &[&DataTypePtr]) -> Result<DataTypePtr> {
+        let types = args.iter().map(|arg| (*arg).clone()).collect::<Vec<_>>();
+        aggregate_types(&types)
+    }
+
+    fn eval(&self, columns: &ColumnsWithField, input_rows: usize) -> Result<ColumnRef> {
+        let args = columns.iter().map(|c| c.data_type()).collect::<Vec<_>>();
+        let return_type = self.return_type(&args)?;
+        if return_type.data_type_id() == TypeID::Null {
+            return Ok(NullColumn::new(input_rows).arc());
+        }
+
+        let columns = columns
+            .iter()
+            .map(|c| cast_column_field(c, &return_type))
+            .collect::<Result<Vec<_>>>()?;
+
+        let type_id = remove_nullable(&return_type).data_type_id();
+        with_match_scalar_type!(type_id.to_physical_type(), |$T| {
+            let viewers = columns
+                .iter()
+                .map($T::try_create_viewer)
+                .collect::<Result<Vec<_>>>()?;
+
+            let pick = |row: usize| {
+                let mut result = None;
+                for viewer in viewers.iter().filter(|viewer| viewer.valid_at(row)) {
+                    let value = viewer.value_at(row);
+                    result = match result {
+                        Some(r) if (IS_GREATEST && r >= value) || (!IS_GREATEST && r <= value) => {
+                            Some(r)
+                        }
+                        _ => Some(value),
+                    };
+                }
+                result
+            };
+
+            if return_type.is_nullable() {
+                let mut builder = NullableColumnBuilder::<$T>::with_capacity(input_rows);
+                for row in 0..input_rows {
+                    match pick(row) {
+                        Some(value) => builder.append(value, true),
+                        None => builder.append(viewers[0].value_at(row), false),
+                    }
+                }
+                Ok(builder.build(input_rows))
+            } else {
+                let mut builder = ColumnBuilder::<$T>::with_capacity(input_rows);
+                for row in 0..input_rows {
+                    // Every row has a value when none of the arguments is nullable.
+                    builder.append(pick(row).unwrap());
+                }
+                Ok(builder.build(input_rows))
+            }
+        }, {
+            Err(ErrorCode::IllegalDataType(format!(
+                "Function {} does not support the type {:?}",
+                self.display_name, return_type
+            )))
+        })
+    }
+
+    fn passthrough_null(&self) -> bool {
+        false
+    }
+}
+
+impl<const IS_GREATEST: bool> fmt::Display for GreatestLeastFunction<IS_GREATEST> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
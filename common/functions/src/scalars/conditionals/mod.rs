@@ -12,8 +12,16 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod coalesce;
 mod conditional;
+mod greatest_least;
 mod r#if;
+mod multi_if;
 
+pub use coalesce::CoalesceFunction;
 pub use conditional::ConditionalFunction;
+pub use greatest_least::GreatestFunction;
+pub use greatest_least::GreatestLeastFunction;
+pub use greatest_least::LeastFunction;
+pub use multi_if::MultiIfFunction;
 pub use r#if::IfFunction;
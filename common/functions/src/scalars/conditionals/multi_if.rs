@@ -0,0 +1,140 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datablocks::DataBlock;
+use common_datavalues2::prelude::*;
+use common_datavalues2::type_coercion::aggregate_types;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::scalars::cast_column_field;
+use crate::scalars::default_column_cast;
+use crate::scalars::function_factory::FunctionFeatures;
+use crate::scalars::Function2;
+use crate::scalars::Function2Description;
+use crate::scalars::IfFunction;
+
+/// multiIf(cond1, val1, cond2, val2, ..., else_val) returns the value of the first true
+/// condition of each row, or `else_val` if there is none. A null condition is false.
+///
+/// The expression executor short-circuits per row: a condition is only evaluated for the rows
+/// where all the previous conditions are false, and a value only for the rows its condition
+/// selects. The values must have a common type, e.g. UInt64 and Int8 have none.
+#[derive(Clone, Debug)]
+pub struct MultiIfFunction {
+    display_name: String,
+}
+
+impl MultiIfFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function2>> {
+        Ok(Box::new(MultiIfFunction {
+            display_name: display_name.to_string(),
+        }))
+    }
+
+    pub fn desc() -> Function2Description {
+        let features = FunctionFeatures::default()
+            .deterministic()
+            .variadic_arguments(3, usize::MAX - 1);
+        Function2Description::creator(Box::new(Self::try_create)).features(features)
+    }
+
+    /// Whether the condition is true for each row, a null condition is false.
+    pub fn true_rows(condition: &ColumnRef) -> Result<Vec<bool>> {
+        let condition = DataBlock::cast_to_nonull_boolean(condition)?;
+        let viewer = bool::try_create_viewer(&condition)?;
+        Ok((0..condition.len())
+            .map(|row| viewer.value_at(row))
+            .collect())
+    }
+
+    /// Write the values of a branch into `result` at `rows`,
+    /// `value` has one value for each of the rows.
+    pub fn fill_rows(
+        result: &mut [DataValue],
+        rows: &[u32],
+        value: &ColumnRef,
+        return_type: &DataTypePtr,
+    ) -> Result<()> {
+        let value = default_column_cast(value, return_type)?;
+        for (index, row) in rows.iter().enumerate() {
+            result[*row as usize] = value.get(index);
+        }
+        Ok(())
+    }
+
+    fn value_types(&self, args: &[&DataTypePtr]) -> Result<DataTypePtr> {
+        if args.len() % 2 == 0 {
+            return Err(ErrorCode::NumberArgumentsNotMatch(format!(
+                "Function {} expects an odd number of arguments, but got {}",
+                self.display_name,
+                args.len()
+            )));
+        }
+
+        let values = args
+            .iter()
+            .skip(1)
+            .step_by(2)
+            .map(|arg| (*arg).clone())
+            .collect::<Vec<_>>();
+        aggregate_types(&values)
+    }
+}
+
+impl Function2 for MultiIfFunction {
+    fn name(&self) -> &str {
+        &*self.display_name
+    }
+
+    fn return_type(&self, args: &[&DataTypePtr]) -> Result<DataTypePtr> {
+        self.value_types(args)
+    }
+
+    fn eval(&self, columns: &ColumnsWithField, input_rows: usize) -> Result<ColumnRef> {
+        let args = columns.iter().map(|c| c.data_type()).collect::<Vec<_>>();
+        let return_type = self.value_types(&args)?;
+        let field = DataField::new(&self.display_name, return_type.clone());
+
+        // Fold the branches into nested ifs from the last one, so the first true
+        // condition of a row takes its value.
+        let if_function = IfFunction::try_create("if")?;
+        let (branches, else_value) = columns.split_at(columns.len() - 1);
+        let mut result = cast_column_field(&else_value[0], &return_type)?;
+
+        for branch in branches.chunks(2).rev() {
+            let value = cast_column_field(&branch[1], &return_type)?;
+            let if_columns = vec![
+                branch[0].clone(),
+                ColumnWithField::new(value, field.clone()),
+                ColumnWithField::new(result, field.clone()),
+            ];
+            result = if_function.eval(&if_columns, input_rows)?;
+        }
+
+        Ok(result)
+    }
+
+    fn passthrough_null(&self) -> bool {
+        false
+    }
+}
+
+impl fmt::Display for MultiIfFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
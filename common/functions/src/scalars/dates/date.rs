@@ -36,6 +36,7 @@ use super::ToStartOfYearFunction;
 use super::ToYYYYMMDDFunction;
 use super::ToYYYYMMDDhhmmssFunction;
 use super::ToYYYYMMFunction;
+use super::ToYearFunction;
 use super::TodayFunction;
 use super::TomorrowFunction;
 use super::YesterdayFunction;
@@ -81,6 +82,7 @@ impl DateFunction {
         factory.register("toStartOfQuarter", ToStartOfQuarterFunction::desc());
 
         factory.register("toStartOfMonth", ToStartOfMonthFunction::desc());
+        factory.register("toYear", ToYearFunction::desc());
         factory.register("toMonth", ToMonthFunction::desc());
         factory.register("toDayOfYear", ToDayOfYearFunction::desc());
         factory.register("toDayOfMonth", ToDayOfMonthFunction::desc());
@@ -42,6 +42,7 @@ pub use number_function::ToStartOfYearFunction;
 pub use number_function::ToYYYYMMDDFunction;
 pub use number_function::ToYYYYMMDDhhmmssFunction;
 pub use number_function::ToYYYYMMFunction;
+pub use number_function::ToYearFunction;
 pub use round_function::RoundFunction;
 pub use simple_date::TodayFunction;
 pub use simple_date::TomorrowFunction;
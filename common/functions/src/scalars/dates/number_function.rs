@@ -22,6 +22,7 @@ use common_datavalues2::chrono::Timelike;
 use common_datavalues2::chrono::Utc;
 use common_datavalues2::prelude::*;
 use common_datavalues2::Date16Type;
+use common_datavalues2::DateTime64Type;
 use common_exception::ErrorCode;
 use common_exception::Result;
 
@@ -168,6 +169,17 @@ impl NumberOperator<u16> for ToStartOfMonth {
     }
 }
 
+#[derive(Clone)]
+pub struct ToYear;
+
+impl NumberOperator<u16> for ToYear {
+    const IS_DETERMINISTIC: bool = true;
+
+    fn to_number(value: DateTime<Utc>) -> u16 {
+        value.year() as u16
+    }
+}
+
 #[derive(Clone)]
 pub struct ToMonth;
 
@@ -374,8 +386,17 @@ where
                 Ok(col.arc())
 
                 },
+            TypeID::DateTime64 => {
+                let data_type = columns[0].field().data_type();
+                let date_time64 = data_type.as_any().downcast_ref::<DateTime64Type>().unwrap();
+                let unary = ScalarUnaryExpression::<i64, R, _>::new(|v| {
+                    T::to_number(date_time64.utc_timestamp(v))
+                });
+                let col = unary.eval(columns[0].column())?;
+                Ok(col.arc())
+            },
             other => Result::Err(ErrorCode::IllegalDataType(format!(
-                "Illegal type {:?} of argument of function {}.Should be a date16/data32 or a dateTime32",
+                "Illegal type {:?} of argument of function {}.Should be a date16/data32 or a dateTime32/dateTime64",
                 other,
                 self.name()))),
         }?;
@@ -427,6 +448,7 @@ pub type ToStartOfYearFunction = NumberFunction<ToStartOfYear, u16>;
 pub type ToStartOfQuarterFunction = NumberFunction<ToStartOfQuarter, u16>;
 pub type ToStartOfMonthFunction = NumberFunction<ToStartOfMonth, u16>;
 
+pub type ToYearFunction = NumberFunction<ToYear, u16>;
 pub type ToMonthFunction = NumberFunction<ToMonth, u8>;
 pub type ToDayOfYearFunction = NumberFunction<ToDayOfYear, u16>;
 pub type ToDayOfMonthFunction = NumberFunction<ToDayOfMonth, u8>;
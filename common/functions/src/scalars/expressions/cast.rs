@@ -20,6 +20,7 @@ use common_exception::Result;
 
 use super::cast_with_type::cast_column_field;
 use crate::scalars::function2::Function2;
+use crate::scalars::Monotonicity2;
 
 #[derive(Clone)]
 pub struct CastFunction {
@@ -56,6 +57,60 @@ impl CastFunction {
             cast_type: Arc::new(nullable_type),
         }))
     }
+
+    pub fn create_with_type(display_name: &str, cast_type: DataTypePtr) -> Box<dyn Function2> {
+        Box::new(Self {
+            _display_name: display_name.to_string(),
+            cast_type,
+        })
+    }
+}
+
+/// The type that a value of `from_type` is cast to. A string that is not a valid date
+/// or datetime is cast to NULL, so a string is always cast to a nullable date type.
+pub fn cast_result_type(from_type: &DataTypePtr, cast_type: &DataTypePtr) -> DataTypePtr {
+    let from_string = remove_nullable(from_type).data_type_id() == TypeID::String;
+    let to_date = matches!(
+        cast_type.data_type_id(),
+        TypeID::Date16 | TypeID::Date32 | TypeID::DateTime32 | TypeID::DateTime64
+    );
+
+    match from_string && to_date {
+        true => wrap_nullable(cast_type),
+        false => cast_type.clone(),
+    }
+}
+
+/// Whether every value of `from_type` converts to `to_type` keeping its order,
+/// e.g. Int32 to Int64 or UInt32 to Float64, but not Int64 to Int32 or Int8 to UInt64.
+pub fn is_widening_cast(from_type: &DataTypePtr, to_type: &DataTypePtr) -> bool {
+    let from_id = remove_nullable(from_type).data_type_id();
+    let to_id = remove_nullable(to_type).data_type_id();
+    if from_id == to_id {
+        return true;
+    }
+
+    if !from_id.is_numeric() || !to_id.is_numeric() {
+        return false;
+    }
+
+    if to_id.is_floating() {
+        // Rounding to the nearest float never reverses the order of two values.
+        return !from_id.is_floating() || to_id == TypeID::Float64;
+    }
+
+    if from_id.is_floating() || (from_id.is_signed_integer() && to_id.is_unsigned_integer()) {
+        return false;
+    }
+
+    match (from_id.numeric_byte_size(), to_id.numeric_byte_size()) {
+        // An unsigned integer fits a signed one only if it is strictly wider.
+        (Ok(from), Ok(to)) if from_id.is_unsigned_integer() && to_id.is_signed_integer() => {
+            from < to
+        }
+        (Ok(from), Ok(to)) => from <= to,
+        _ => false,
+    }
 }
 
 impl Function2 for CastFunction {
@@ -63,12 +118,30 @@ impl Function2 for CastFunction {
         "CastFunction"
     }
 
-    fn return_type(&self, _args: &[&DataTypePtr]) -> Result<DataTypePtr> {
-        Ok(self.cast_type.clone())
+    fn return_type(&self, args: &[&DataTypePtr]) -> Result<DataTypePtr> {
+        Ok(cast_result_type(args[0], &self.cast_type))
     }
 
     fn eval(&self, columns: &ColumnsWithField, _input_rows: usize) -> Result<ColumnRef> {
-        cast_column_field(&columns[0], &self.cast_type)
+        let cast_type = cast_result_type(columns[0].data_type(), &self.cast_type);
+        cast_column_field(&columns[0], &cast_type)
+    }
+
+    fn get_monotonicity(&self, args: &[Monotonicity2]) -> Result<Monotonicity2> {
+        if args[0].is_constant {
+            return Ok(Monotonicity2::clone_without_range(&args[0]));
+        }
+
+        // The source type is only known from the boundaries of the argument.
+        let from_type = match (&args[0].left, &args[0].right) {
+            (Some(boundary), _) | (None, Some(boundary)) => boundary.data_type(),
+            (None, None) => return Ok(Monotonicity2::default()),
+        };
+
+        match is_widening_cast(from_type, &self.cast_type) {
+            true => Ok(Monotonicity2::clone_without_range(&args[0])),
+            false => Ok(Monotonicity2::default()),
+        }
     }
 
     fn passthrough_null(&self) -> bool {
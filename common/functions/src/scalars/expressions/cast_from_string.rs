@@ -21,6 +21,7 @@ use common_datavalues2::prelude::*;
 use common_exception::Result;
 
 use super::cast_with_type::arrow_cast_compute;
+use super::cast_with_type::cast_with_type;
 use super::cast_with_type::new_mutable_bitmap;
 use super::cast_with_type::CastOptions;
 
@@ -42,6 +43,7 @@ pub fn cast_from_string(
                 if let Some(d) = string_to_date(v) {
                     builder.append((d.num_days_from_ce() - EPOCH_DAYS_FROM_CE) as u16);
                 } else {
+                    builder.append(0);
                     bitmap.set(row, false)
                 }
             }
@@ -57,7 +59,10 @@ pub fn cast_from_string(
                     Some(d) => {
                         builder.append((d.num_days_from_ce() - EPOCH_DAYS_FROM_CE) as i32);
                     }
-                    None => bitmap.set(row, false),
+                    None => {
+                        builder.append(0);
+                        bitmap.set(row, false);
+                    }
                 }
             }
             Ok((builder.build(size), Some(bitmap.into())))
@@ -71,7 +76,10 @@ pub fn cast_from_string(
                     Some(t) => {
                         builder.append(t.timestamp() as u32);
                     }
-                    None => bitmap.set(row, false),
+                    None => {
+                        builder.append(0);
+                        bitmap.set(row, false);
+                    }
                 }
             }
             Ok((builder.build(size), Some(bitmap.into())))
@@ -83,16 +91,178 @@ pub fn cast_from_string(
                     Some(d) => {
                         builder.append(d.timestamp_nanos());
                     }
-                    None => bitmap.set(row, false),
+                    None => {
+                        builder.append(0);
+                        bitmap.set(row, false);
+                    }
                 }
             }
             Ok((builder.build(size), Some(bitmap.into())))
         }
+        TypeID::Array => cast_string_to_array(str_column, data_type, cast_options),
         TypeID::Interval => todo!(),
         _ => arrow_cast_compute(column, data_type, cast_options),
     }
 }
 
+fn cast_string_to_array(
+    str_column: &StringColumn,
+    data_type: &DataTypePtr,
+    cast_options: &CastOptions,
+) -> Result<(ColumnRef, Option<Bitmap>)> {
+    let array_type: &ArrayType = data_type.as_any().downcast_ref().unwrap();
+    let inner_type = array_type.inner_type();
+    let size = str_column.len();
+    let mut bitmap = new_mutable_bitmap(size, true);
+    let mut offsets = Vec::with_capacity(size + 1);
+    offsets.push(0_i64);
+
+    let mut builder = NullableColumnBuilder::<Vu8>::with_capacity(size);
+    for (row, v) in str_column.iter().enumerate() {
+        match string_to_array_elements(v) {
+            Some(elements) => {
+                for element in elements {
+                    match element {
+                        Some(element) => builder.append(element.as_slice(), true),
+                        None => builder.append_null(),
+                    }
+                }
+            }
+            None => bitmap.set(row, false),
+        }
+        offsets.push(builder.len() as i64);
+    }
+
+    let elements = builder.build(builder.len());
+    let (values, valids) = match inner_type.data_type_id() {
+        // Arrays cannot be nullable, a nested list is parsed with the validity of each list.
+        TypeID::Array => {
+            let (values, valids) = cast_from_string(&elements, inner_type, cast_options)?;
+            let valids = valids.unwrap_or_else(|| new_mutable_bitmap(values.len(), true).into());
+            (values, valids)
+        }
+        // Cast the elements to nullable first, so an invalid element only invalidates its own row.
+        _ => {
+            let values = cast_with_type(
+                &elements,
+                &elements.data_type(),
+                &wrap_nullable(inner_type),
+                cast_options,
+            )?;
+            let nullable: &NullableColumn = Series::check_get(&values)?;
+            let valids = nullable.ensure_validity().clone();
+            let values = match inner_type.is_nullable() {
+                true => values.clone(),
+                false => nullable.inner().clone(),
+            };
+            (values, valids)
+        }
+    };
+
+    for row in 0..size {
+        for i in offsets[row] as usize..offsets[row + 1] as usize {
+            // A NULL element is only valid if the element type is nullable.
+            let is_null_element = elements.null_at(i) && inner_type.is_nullable();
+            if !valids.get_bit(i) && !is_null_element {
+                bitmap.set(row, false);
+                break;
+            }
+        }
+    }
+
+    let column = ArrayColumn::from_data(data_type.clone(), offsets.into(), values);
+    Ok((column.arc(), Some(bitmap.into())))
+}
+
+/// Splits a list like `[1, null, 'a,b', [2, 3]]` into its elements, `null` is a NULL element.
+/// A quoted element is unquoted, a nested list is kept as it is to be cast to the element type.
+fn string_to_array_elements(list_str: &[u8]) -> Option<Vec<Option<Vec<u8>>>> {
+    let s = std::str::from_utf8(list_str).ok()?.trim();
+    let s = s.strip_prefix('[')?.strip_suffix(']')?;
+    if s.trim().is_empty() {
+        return Some(vec![]);
+    }
+
+    let bytes = s.as_bytes();
+    let mut elements = vec![];
+    let mut pos = 0;
+    loop {
+        while bytes.get(pos)?.is_ascii_whitespace() {
+            pos += 1;
+        }
+
+        let element = match bytes[pos] {
+            b'\'' | b'"' => {
+                let (value, end) = unquote_array_element(bytes, pos)?;
+                pos = end;
+                Some(value)
+            }
+            _ => {
+                let start = pos;
+                let mut depth = 0_usize;
+                while let Some(&c) = bytes.get(pos) {
+                    match c {
+                        b',' if depth == 0 => break,
+                        b'[' => depth += 1,
+                        b']' => depth = depth.checked_sub(1)?,
+                        b'\'' | b'"' => {
+                            pos = unquote_array_element(bytes, pos)?.1;
+                            continue;
+                        }
+                        _ => {}
+                    }
+                    pos += 1;
+                }
+
+                match s[start..pos].trim() {
+                    _ if depth != 0 => return None,
+                    "" => return None,
+                    e if e.eq_ignore_ascii_case("null") => None,
+                    e => Some(e.as_bytes().to_vec()),
+                }
+            }
+        };
+        elements.push(element);
+
+        while bytes.get(pos).map_or(false, u8::is_ascii_whitespace) {
+            pos += 1;
+        }
+
+        match bytes.get(pos) {
+            None => return Some(elements),
+            Some(b',') => pos += 1,
+            Some(_) => return None,
+        }
+    }
+}
+
+/// Reads the quoted string at `start` with backslash escapes, returns the unquoted value
+/// and the position after the closing quote.
+fn unquote_array_element(bytes: &[u8], start: usize) -> Option<(Vec<u8>, usize)> {
+    let quote = bytes[start];
+    let mut value = vec![];
+    let mut pos = start + 1;
+    loop {
+        match *bytes.get(pos)? {
+            b'\\' => {
+                value.push(match *bytes.get(pos + 1)? {
+                    b'n' => b'\n',
+                    b't' => b'\t',
+                    b'r' => b'\r',
+                    b'0' => b'\0',
+                    escaped => escaped,
+                });
+                pos += 2;
+            }
+            c if c == quote => return Some((value, pos + 1)),
+            c => {
+                value.push(c);
+                pos += 1;
+            }
+        }
+    }
+}
+
 // currently use UTC by default
 // TODO support timezone
 #[inline]
@@ -108,8 +278,13 @@ fn string_to_datetime64(date_str: impl AsRef<[u8]>) -> Option<NaiveDateTime> {
     s.and_then(|c| NaiveDateTime::parse_from_str(c, "%Y-%m-%d %H:%M:%S%.9f").ok())
 }
 
+// An ISO-8601 date `yyyy-mm-dd`, the time of a datetime like `yyyy-mm-ddThh:mm:ssZ` is dropped.
 #[inline]
 fn string_to_date(date_str: impl AsRef<[u8]>) -> Option<NaiveDate> {
-    let s = std::str::from_utf8(date_str.as_ref()).ok();
-    s.and_then(|c| c.parse::<NaiveDate>().ok())
+    let s = std::str::from_utf8(date_str.as_ref()).ok()?.trim();
+    let s = match s.as_bytes().get(10) {
+        Some(b'T') | Some(b' ') => &s[..10],
+        _ => s,
+    };
+    s.parse::<NaiveDate>().ok()
 }
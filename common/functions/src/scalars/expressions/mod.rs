@@ -22,6 +22,8 @@ mod expression;
 mod unary;
 
 pub use binary::*;
+pub use cast::cast_result_type;
+pub use cast::is_widening_cast;
 pub use cast::CastFunction;
 pub use cast_with_type::*;
 pub use ctx::EvalContext;
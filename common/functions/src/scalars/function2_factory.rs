@@ -26,6 +26,7 @@ use once_cell::sync::Lazy;
 use super::function2::Function2;
 use super::function_factory::FunctionFeatures;
 use super::ArithmeticFunction;
+use super::BitwiseFunction;
 use super::ComparisonFunction;
 use super::ConditionalFunction;
 use super::Function1Convertor;
@@ -98,6 +99,7 @@ static FUNCTION2_FACTORY: Lazy<Arc<Function2Factory>> = Lazy::new(|| {
     let mut function_factory = Function2Factory::create();
 
     ArithmeticFunction::register(&mut function_factory);
+    BitwiseFunction::register(&mut function_factory);
     ToCastFunction::register(&mut function_factory);
     TupleClassFunction::register(&mut function_factory);
     ComparisonFunction::register(&mut function_factory);
@@ -23,6 +23,7 @@ use crate::scalars::City64WithSeedFunction;
 use crate::scalars::Function2Factory;
 use crate::scalars::Md5HashFunction;
 use crate::scalars::Sha1HashFunction;
+use crate::scalars::Sha256HashFunction;
 use crate::scalars::Sha2HashFunction;
 
 #[derive(Clone)]
@@ -38,6 +39,7 @@ impl HashesFunction {
         factory.register("sha", Sha1HashFunction::desc());
         factory.register("sha1", Sha1HashFunction::desc());
         factory.register("sha2", Sha2HashFunction::desc());
+        factory.register("sha256", Sha256HashFunction::desc());
 
         factory.register("blake3", Blake3HashFunction::desc());
         factory.register("xxhash32", XxHash32Function::desc());
@@ -18,6 +18,7 @@ mod hash;
 mod hash_base;
 mod md5hash;
 mod sha1hash;
+mod sha256hash;
 mod sha2hash;
 
 pub use blake3hash::Blake3HashFunction;
@@ -26,4 +27,5 @@ pub use hash::*;
 pub use hash_base::BaseHashFunction;
 pub use md5hash::Md5HashFunction;
 pub use sha1hash::Sha1HashFunction;
+pub use sha256hash::Sha256HashFunction;
 pub use sha2hash::Sha2HashFunction;
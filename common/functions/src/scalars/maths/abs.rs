@@ -47,7 +47,7 @@ impl AbsFunction {
 }
 
 macro_rules! impl_abs_function {
-    ($column:expr, $type:ident, $cast_type:expr) => {{
+    ($column:expr, $type:ident, $cast_type:expr, $abs:expr) => {{
         let mut series = $column.column().to_minimal_array()?;
 
         // coerce String to Float
@@ -57,13 +57,28 @@ macro_rules! impl_abs_function {
 
         let primitive_array = series.$type()?;
         let column: DataColumn = primitive_array
-            .apply_cast_numeric(|v| v.abs())
+            .apply_cast_numeric($abs)
             .cast_with_type(&$cast_type)?
             .into();
         Ok(column.resize_constant($column.column().len()))
     }};
 }
 
+// The signed integer type is kept, so the absolute value of its minimum overflows.
+macro_rules! check_abs_overflow {
+    ($column:expr, $type:ident, $cast_type:expr) => {{
+        let series = $column.column().to_minimal_array()?;
+        let primitive_array = series.$type()?;
+        if primitive_array.iter().any(|v| v == Some(&$type::MIN)) {
+            return Err(ErrorCode::Overflow(format!(
+                "The absolute value of {} overflows {}",
+                $type::MIN,
+                $cast_type
+            )));
+        }
+    }};
+}
+
 impl Function for AbsFunction {
     fn name(&self) -> &str {
         "abs"
@@ -73,10 +88,6 @@ impl Function for AbsFunction {
         let nullable = args.iter().any(|arg| arg.is_nullable());
         let data_type = if args[0].is_numeric() || args[0].is_string() || args[0].is_null() {
             Ok(match args[0].data_type() {
-                DataType::Int8 => DataType::UInt8,
-                DataType::Int16 => DataType::UInt16,
-                DataType::Int32 => DataType::UInt32,
-                DataType::Int64 => DataType::UInt64,
                 DataType::String => DataType::Float64,
                 dt => dt.clone(),
             })
@@ -92,13 +103,31 @@ impl Function for AbsFunction {
 
     fn eval(&self, columns: &DataColumnsWithField, _input_rows: usize) -> Result<DataColumn> {
         match columns[0].data_type() {
-            DataType::Int8 => impl_abs_function!(columns[0], i8, DataType::UInt8),
-            DataType::Int16 => impl_abs_function!(columns[0], i16, DataType::UInt16),
-            DataType::Int32 => impl_abs_function!(columns[0], i32, DataType::UInt32),
-            DataType::Int64 => impl_abs_function!(columns[0], i64, DataType::UInt16),
-            DataType::Float32 => impl_abs_function!(columns[0], f32, DataType::Float32),
-            DataType::Float64 => impl_abs_function!(columns[0], f64, DataType::Float64),
-            DataType::String => impl_abs_function!(columns[0], f64, DataType::Float64),
+            DataType::Int8 => {
+                check_abs_overflow!(columns[0], i8, DataType::Int8);
+                impl_abs_function!(columns[0], i8, DataType::Int8, |v| v.abs())
+            }
+            DataType::Int16 => {
+                check_abs_overflow!(columns[0], i16, DataType::Int16);
+                impl_abs_function!(columns[0], i16, DataType::Int16, |v| v.abs())
+            }
+            DataType::Int32 => {
+                check_abs_overflow!(columns[0], i32, DataType::Int32);
+                impl_abs_function!(columns[0], i32, DataType::Int32, |v| v.abs())
+            }
+            DataType::Int64 => {
+                check_abs_overflow!(columns[0], i64, DataType::Int64);
+                impl_abs_function!(columns[0], i64, DataType::Int64, |v| v.abs())
+            }
+            DataType::Float32 => {
+                impl_abs_function!(columns[0], f32, DataType::Float32, |v| v.abs())
+            }
+            DataType::Float64 => {
+                impl_abs_function!(columns[0], f64, DataType::Float64, |v| v.abs())
+            }
+            DataType::String => {
+                impl_abs_function!(columns[0], f64, DataType::Float64, |v| v.abs())
+            }
             DataType::UInt8 | DataType::UInt16 | DataType::UInt32 | DataType::UInt64 => {
                 Ok(columns[0].column().clone())
             }
@@ -13,20 +13,16 @@
 // limitations under the License.
 
 use std::fmt;
-use std::sync::Arc;
 
 use common_datavalues2::prelude::*;
 use common_datavalues2::with_match_primitive_type_id;
 use common_exception::Result;
-use num::traits::Pow;
 use num_traits::AsPrimitive;
 
 use crate::scalars::assert_numeric;
 use crate::scalars::function_factory::FunctionFeatures;
-use crate::scalars::EvalContext;
 use crate::scalars::Function2;
 use crate::scalars::Function2Description;
-use crate::scalars::ScalarBinaryExpression;
 
 #[derive(Clone)]
 pub struct PowFunction {
@@ -46,12 +42,17 @@ impl PowFunction {
     }
 }
 
-pub fn scalar_pow<S, T>(value: S, base: T, _ctx: &mut EvalContext) -> f64
+/// A negative base with a fractional exponent has no real result, it is NULL.
+pub fn scalar_pow<S, T>(base: S, exp: T) -> Option<f64>
 where
     S: AsPrimitive<f64>,
     T: AsPrimitive<f64>,
 {
-    value.as_().pow(base.as_())
+    let (base, exp): (f64, f64) = (base.as_(), exp.as_());
+    if base < 0.0 && exp.fract() != 0.0 {
+        return None;
+    }
+    Some(base.powf(exp))
 }
 
 impl Function2 for PowFunction {
@@ -63,15 +64,23 @@ impl Function2 for PowFunction {
         for arg in args {
             assert_numeric(*arg)?;
         }
-        Ok(f64::to_data_type())
+        Ok(wrap_nullable(&Float64Type::arc()))
     }
 
-    fn eval(&self, columns: &ColumnsWithField, _input_rows: usize) -> Result<ColumnRef> {
+    fn eval(&self, columns: &ColumnsWithField, input_rows: usize) -> Result<ColumnRef> {
         with_match_primitive_type_id!(columns[0].data_type().data_type_id(), |$S| {
             with_match_primitive_type_id!(columns[1].data_type().data_type_id(), |$T| {
-                let binary = ScalarBinaryExpression::<$S, $T, f64, _>::new(scalar_pow);
-                let col = binary.eval(columns[0].column(), columns[1].column(), &mut EvalContext::default())?;
-                Ok(Arc::new(col))
+                let lhs = <$S as Scalar>::try_create_viewer(columns[0].column())?;
+                let rhs = <$T as Scalar>::try_create_viewer(columns[1].column())?;
+                let mut builder = NullableColumnBuilder::<f64>::with_capacity(input_rows);
+
+                for row in 0..input_rows {
+                    match scalar_pow(lhs.value_at(row), rhs.value_at(row)) {
+                        Some(value) => builder.append(value, lhs.valid_at(row) && rhs.valid_at(row)),
+                        None => builder.append_null(),
+                    }
+                }
+                Ok(builder.build(input_rows))
             },{
                 unreachable!()
             })
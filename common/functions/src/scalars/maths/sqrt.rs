@@ -23,7 +23,6 @@ use crate::scalars::function2_factory::Function2Description;
 use crate::scalars::function_common::assert_numeric;
 use crate::scalars::function_factory::FunctionFeatures;
 use crate::scalars::Function2;
-use crate::scalars::ScalarUnaryExpression;
 
 #[derive(Clone)]
 pub struct SqrtFunction {
@@ -43,11 +42,6 @@ impl SqrtFunction {
     }
 }
 
-fn sqrt<S>(value: S) -> f64
-where S: AsPrimitive<f64> {
-    value.as_().sqrt()
-}
-
 impl Function2 for SqrtFunction {
     fn name(&self) -> &str {
         &*self.display_name
@@ -55,14 +49,24 @@ impl Function2 for SqrtFunction {
 
     fn return_type(&self, args: &[&DataTypePtr]) -> Result<DataTypePtr> {
         assert_numeric(args[0])?;
-        Ok(Float64Type::arc())
+        // The square root of a negative number is NULL.
+        Ok(wrap_nullable(&Float64Type::arc()))
     }
 
-    fn eval(&self, columns: &ColumnsWithField, _input_rows: usize) -> Result<ColumnRef> {
+    fn eval(&self, columns: &ColumnsWithField, input_rows: usize) -> Result<ColumnRef> {
         with_match_primitive_type_id!(columns[0].data_type().data_type_id(), |$S| {
-             let unary = ScalarUnaryExpression::<$S, f64, _>::new(sqrt::<$S>);
-             let col = unary.eval(columns[0].column())?;
-             Ok(col.arc())
+            let viewer = <$S as Scalar>::try_create_viewer(columns[0].column())?;
+            let mut builder = NullableColumnBuilder::<f64>::with_capacity(input_rows);
+
+            for (row, value) in viewer.iter().enumerate() {
+                let value: f64 = value.as_();
+                if value < 0.0 {
+                    builder.append_null();
+                } else {
+                    builder.append(value.sqrt(), viewer.valid_at(row));
+                }
+            }
+            Ok(builder.build(input_rows))
         },{
             unreachable!()
         })
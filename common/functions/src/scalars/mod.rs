@@ -13,6 +13,7 @@
 // limitations under the License.
 
 mod arithmetics;
+mod bitwises;
 mod comparisons;
 mod conditionals;
 mod dates;
@@ -36,6 +37,7 @@ mod udfs;
 mod uuids;
 
 pub use arithmetics::*;
+pub use bitwises::*;
 pub use comparisons::*;
 pub use conditionals::*;
 pub use dates::*;
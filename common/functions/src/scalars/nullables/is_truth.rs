@@ -0,0 +1,129 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues2::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::scalars::cast_column_field;
+use crate::scalars::function_factory::FunctionFeatures;
+use crate::scalars::Function2;
+use crate::scalars::Function2Description;
+
+/// `x IS [NOT] TRUE` and `x IS [NOT] FALSE`, collapse a nullable boolean into a non-nullable one.
+/// `x IS TRUE` is true only when `x` is true and not null, `x IS NOT TRUE` is its negation.
+#[derive(Clone)]
+pub struct IsTruthFunction {
+    display_name: &'static str,
+    expected: bool,
+    negated: bool,
+}
+
+impl IsTruthFunction {
+    pub fn try_create(
+        display_name: &'static str,
+        expected: bool,
+        negated: bool,
+    ) -> Result<Box<dyn Function2>> {
+        Ok(Box::new(IsTruthFunction {
+            display_name,
+            expected,
+            negated,
+        }))
+    }
+
+    fn desc(
+        display_name: &'static str,
+        expected: bool,
+        negated: bool,
+        negative_function: &'static str,
+    ) -> Function2Description {
+        let creator = move |_display_name: &str| Self::try_create(display_name, expected, negated);
+
+        Function2Description::creator(Box::new(creator)).features(
+            FunctionFeatures::default()
+                .deterministic()
+                .negative_function(negative_function)
+                .bool_function()
+                .num_arguments(1),
+        )
+    }
+
+    pub fn is_true_desc() -> Function2Description {
+        Self::desc("isTrue", true, false, "isnottrue")
+    }
+
+    pub fn is_not_true_desc() -> Function2Description {
+        Self::desc("isNotTrue", true, true, "istrue")
+    }
+
+    pub fn is_false_desc() -> Function2Description {
+        Self::desc("isFalse", false, false, "isnotfalse")
+    }
+
+    pub fn is_not_false_desc() -> Function2Description {
+        Self::desc("isNotFalse", false, true, "isfalse")
+    }
+}
+
+impl Function2 for IsTruthFunction {
+    fn name(&self) -> &str {
+        "IsTruthFunction"
+    }
+
+    fn return_type(&self, args: &[&DataTypePtr]) -> Result<DataTypePtr> {
+        let data_type = remove_nullable(args[0]);
+        match data_type.data_type_id() {
+            TypeID::Boolean | TypeID::Null => Ok(bool::to_data_type()),
+            _ => Err(ErrorCode::IllegalDataType(format!(
+                "Expected boolean or null argument for function {}, but got {:?}",
+                self.display_name, args[0]
+            ))),
+        }
+    }
+
+    fn eval(&self, columns: &ColumnsWithField, input_rows: usize) -> Result<ColumnRef> {
+        let (all_null, _) = columns[0].column().validity();
+        if all_null {
+            return Ok(ConstColumn::new(Series::from_data(vec![self.negated]), input_rows).arc());
+        }
+
+        let data_type = match columns[0].data_type().is_nullable() {
+            true => wrap_nullable(&BooleanType::arc()),
+            false => BooleanType::arc(),
+        };
+
+        let column = cast_column_field(&columns[0], &data_type)?;
+        let viewer = bool::try_create_viewer(&column)?;
+
+        let mut builder = ColumnBuilder::<bool>::with_capacity(input_rows);
+        for (idx, value) in viewer.iter().enumerate() {
+            let matched = viewer.valid_at(idx) && value == self.expected;
+            builder.append(matched != self.negated);
+        }
+        Ok(builder.build(input_rows))
+    }
+
+    fn passthrough_null(&self) -> bool {
+        false
+    }
+}
+
+impl fmt::Display for IsTruthFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
@@ -14,8 +14,10 @@
 
 mod is_not_null;
 mod is_null;
+mod is_truth;
 mod nullable;
 
 pub use is_not_null::IsNotNullFunction;
 pub use is_null::IsNullFunction;
+pub use is_truth::IsTruthFunction;
 pub use nullable::NullableFunction;
@@ -15,6 +15,7 @@
 use crate::scalars::Function2Factory;
 use crate::scalars::IsNotNullFunction;
 use crate::scalars::IsNullFunction;
+use crate::scalars::IsTruthFunction;
 
 #[derive(Clone)]
 pub struct NullableFunction;
@@ -23,5 +24,12 @@ impl NullableFunction {
     pub fn register(factory: &mut Function2Factory) {
         factory.register("isnull", IsNullFunction::desc());
         factory.register("isnotnull", IsNotNullFunction::desc());
+        factory.register("istrue", IsTruthFunction::is_true_desc());
+        factory.register("isnottrue", IsTruthFunction::is_not_true_desc());
+        factory.register("isfalse", IsTruthFunction::is_false_desc());
+        factory.register("isnotfalse", IsTruthFunction::is_not_false_desc());
+        // A null boolean is unknown.
+        factory.register("isunknown", IsNullFunction::desc());
+        factory.register("isnotunknown", IsNotNullFunction::desc());
     }
 }
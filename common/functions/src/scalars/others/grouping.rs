@@ -0,0 +1,75 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues2::ColumnRef;
+use common_datavalues2::ColumnsWithField;
+use common_datavalues2::DataTypePtr;
+use common_datavalues2::DataValue;
+use common_datavalues2::UInt32Type;
+use common_exception::Result;
+
+use crate::scalars::function_factory::FunctionFeatures;
+use crate::scalars::Function2;
+use crate::scalars::Function2Description;
+
+// grouping(k1, ..., kn) returns a bitmask whose bit is 1 if the key is rolled up in the row,
+// the bit of k1 is the most significant one.
+// Without ROLLUP every key is grouped, so it always returns 0. The rows of a rolled up
+// grouping set carry the mask as a column with the same name, which is used instead.
+#[derive(Clone)]
+pub struct GroupingFunction {
+    display_name: String,
+}
+
+impl GroupingFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function2>> {
+        Ok(Box::new(GroupingFunction {
+            display_name: display_name.to_string(),
+        }))
+    }
+
+    pub fn desc() -> Function2Description {
+        Function2Description::creator(Box::new(Self::try_create)).features(
+            FunctionFeatures::default()
+                .deterministic()
+                .variadic_arguments(1, 32),
+        )
+    }
+}
+
+impl fmt::Display for GroupingFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name.to_uppercase())
+    }
+}
+
+impl Function2 for GroupingFunction {
+    fn name(&self) -> &str {
+        &*self.display_name
+    }
+
+    fn return_type(&self, _args: &[&DataTypePtr]) -> Result<DataTypePtr> {
+        Ok(UInt32Type::arc())
+    }
+
+    fn eval(&self, _columns: &ColumnsWithField, input_rows: usize) -> Result<ColumnRef> {
+        UInt32Type::arc().create_constant_column(&DataValue::UInt64(0), input_rows)
+    }
+
+    fn passthrough_null(&self) -> bool {
+        false
+    }
+}
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use super::grouping::GroupingFunction;
 use super::inet_aton::InetAtonFunction;
 use super::inet_aton::TryInetAtonFunction;
 use super::inet_ntoa::InetNtoaFunction;
@@ -27,6 +28,7 @@ impl OtherFunction {
     pub fn register(factory: &mut Function2Factory) {
         factory.register("runningDifference", RunningDifferenceFunction::desc());
         factory.register("ignore", IgnoreFunction::desc());
+        factory.register("grouping", GroupingFunction::desc());
 
         // inet_aton
         factory.register("inet_aton", InetAtonFunction::desc());
@@ -15,13 +15,16 @@
 use std::fmt;
 
 use common_datavalues2::prelude::*;
+use common_exception::ErrorCode;
 use common_exception::Result;
 
-use crate::scalars::assert_string;
+use crate::scalars::cast_column_field;
 use crate::scalars::function_factory::FunctionFeatures;
 use crate::scalars::Function2;
 use crate::scalars::Function2Description;
 
+/// CONCAT(str1, str2, ...) follows MySQL: numeric arguments are converted to strings,
+/// and the result is NULL if any argument is NULL.
 #[derive(Clone)]
 pub struct ConcatFunction {
     _display_name: String,
@@ -38,7 +41,7 @@ impl ConcatFunction {
         Function2Description::creator(Box::new(Self::try_create)).features(
             FunctionFeatures::default()
                 .deterministic()
-                .variadic_arguments(1, 1024),
+                .variadic_arguments(1, usize::MAX),
         )
     }
 }
@@ -50,15 +53,24 @@ impl Function2 for ConcatFunction {
 
     fn return_type(&self, args: &[&DataTypePtr]) -> Result<DataTypePtr> {
         for arg in args {
-            assert_string(*arg)?;
+            if !arg.data_type_id().is_numeric() && !arg.data_type_id().is_string() {
+                return Err(ErrorCode::IllegalDataType(format!(
+                    "Expected string or numeric, but got {}",
+                    arg.data_type_id()
+                )));
+            }
         }
         Ok(Vu8::to_data_type())
     }
 
     fn eval(&self, columns: &ColumnsWithField, input_rows: usize) -> Result<ColumnRef> {
+        let columns = columns
+            .iter()
+            .map(|c| cast_column_field(c, &StringType::arc()))
+            .collect::<Result<Vec<_>>>()?;
         let viewers = columns
             .iter()
-            .map(|c| Vu8::try_create_viewer(c.column()))
+            .map(Vu8::try_create_viewer)
             .collect::<Result<Vec<_>>>()?;
 
         let mut values: Vec<u8> = Vec::with_capacity(input_rows * columns.len());
@@ -222,11 +222,8 @@ impl<const T: u8> Function for LocatingFunction<T> {
                     )
                     .map(|(ss, s, p)| find_at(s, ss, p)),
                     combine_validities(
-                        combine_validities(
-                            ss_array.inner().validity(),
-                            ss_array.inner().validity(),
-                        )
-                        .as_ref(),
+                        combine_validities(ss_array.inner().validity(), s_array.inner().validity())
+                            .as_ref(),
                         p_array.inner().validity(),
                     ),
                 )
@@ -244,6 +241,9 @@ impl<const T: u8> fmt::Display for LocatingFunction<T> {
     }
 }
 
+// The 1-based byte offset of the first substr in str starting from the byte pos, 0 if not found.
+// Offsets count bytes rather than characters, as the strings are not required to be utf8.
+// An empty substr is found at pos if pos is within the str or right after its end.
 #[inline]
 fn find_at(str: &[u8], substr: &[u8], pos: &u64) -> u64 {
     let pos = (*pos) as usize;
@@ -251,6 +251,10 @@ fn find_at(str: &[u8], substr: &[u8], pos: &u64) -> u64 {
         return 0_u64;
     }
     let p = pos - 1;
+    if substr.is_empty() {
+        return if p <= str.len() { pos as u64 } else { 0_u64 };
+    }
+
     if p + substr.len() <= str.len() {
         str[p..]
             .windows(substr.len())
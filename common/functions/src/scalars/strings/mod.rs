@@ -94,7 +94,11 @@ pub use substring::SubstringFunction;
 pub use substring_index::SubstringIndexFunction;
 pub use trim::LTrimFunction;
 pub use trim::RTrimFunction;
+pub use trim::TrimBothFunction;
+pub use trim::TrimCharsFunction;
 pub use trim::TrimFunction;
+pub use trim::TrimLeadingFunction;
+pub use trim::TrimTrailingFunction;
 pub use unhex::UnhexFunction;
 pub use upper::UpperFunction;
 
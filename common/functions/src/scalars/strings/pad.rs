@@ -44,7 +44,8 @@ impl PadOperator for LeftPad {
         self.buff.clear();
         if l != 0 {
             if l > str.len() {
-                let l = l - str.len();
+                // An empty pad can't fill anything, the input is kept as it is.
+                let l = if pad.is_empty() { 0 } else { l - str.len() };
                 while self.buff.len() < l {
                     if self.buff.len() + pad.len() <= l {
                         self.buff.extend_from_slice(pad);
@@ -73,6 +74,8 @@ impl PadOperator for RightPad {
         if l != 0 {
             if l > str.len() {
                 self.buff.extend_from_slice(str);
+                // An empty pad can't fill anything, the input is kept as it is.
+                let l = if pad.is_empty() { str.len() } else { l };
                 while self.buff.len() < l {
                     if self.buff.len() + pad.len() <= l {
                         self.buff.extend_from_slice(pad);
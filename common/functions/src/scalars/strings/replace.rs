@@ -28,19 +28,15 @@ fn apply<'a>(str: &'a [u8], from: &'a [u8], to: &'a [u8], buf: &mut Vec<u8>) {
         buf.extend_from_slice(str);
         return;
     }
-    let mut skip = 0;
-    for (p, w) in str.windows(from.len()).enumerate() {
-        if w == from {
-            buf.extend_from_slice(to);
-            skip = from.len();
-        } else if p + w.len() == str.len() {
-            buf.extend_from_slice(w);
-        } else if skip > 1 {
-            skip -= 1;
-        } else {
-            buf.extend_from_slice(&w[0..1]);
-        }
+
+    // Replace the occurrences from left to right, so they never overlap.
+    let mut rest = str;
+    while let Some(pos) = rest.windows(from.len()).position(|w| w == from) {
+        buf.extend_from_slice(&rest[..pos]);
+        buf.extend_from_slice(to);
+        rest = &rest[pos + from.len()..];
     }
+    buf.extend_from_slice(rest);
 }
 
 #[derive(Clone)]
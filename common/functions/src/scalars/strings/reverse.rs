@@ -24,8 +24,20 @@ impl StringOperator for Reverse {
     #[inline]
     fn try_apply<'a>(&'a mut self, s: &'a [u8], buffer: &mut [u8]) -> Result<usize> {
         let buffer = &mut buffer[0..s.len()];
-        buffer.copy_from_slice(s);
-        buffer.reverse();
+        match std::str::from_utf8(s) {
+            // Reverse by unicode scalar values, keep the bytes of each character in order.
+            Ok(str) => {
+                for (index, c) in str.char_indices() {
+                    let end = s.len() - index;
+                    let start = end - c.len_utf8();
+                    buffer[start..end].copy_from_slice(&s[index..index + c.len_utf8()]);
+                }
+            }
+            Err(_) => {
+                buffer.copy_from_slice(s);
+                buffer.reverse();
+            }
+        }
         Ok(s.len())
     }
 }
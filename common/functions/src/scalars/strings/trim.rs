@@ -12,10 +12,17 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::fmt;
+
+use common_datavalues2::prelude::*;
 use common_exception::Result;
 
 use super::string2string::String2StringFunction;
 use super::string2string::StringOperator;
+use crate::scalars::assert_string;
+use crate::scalars::function_factory::FunctionFeatures;
+use crate::scalars::Function2;
+use crate::scalars::Function2Description;
 
 #[derive(Clone, Default)]
 pub struct LTrim;
@@ -74,3 +81,94 @@ impl StringOperator for Trim {
 pub type LTrimFunction = String2StringFunction<LTrim>;
 pub type RTrimFunction = String2StringFunction<RTrim>;
 pub type TrimFunction = String2StringFunction<Trim>;
+
+pub type TrimBothFunction = TrimCharsFunction<true, true>;
+pub type TrimLeadingFunction = TrimCharsFunction<true, false>;
+pub type TrimTrailingFunction = TrimCharsFunction<false, true>;
+
+/// TRIM([BOTH | LEADING | TRAILING] chars FROM s), removes any of the characters in `chars`
+/// from the given ends of `s`.
+#[derive(Clone)]
+pub struct TrimCharsFunction<const LEADING: bool, const TRAILING: bool> {
+    display_name: String,
+}
+
+impl<const LEADING: bool, const TRAILING: bool> TrimCharsFunction<LEADING, TRAILING> {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function2>> {
+        Ok(Box::new(Self {
+            display_name: display_name.to_string(),
+        }))
+    }
+
+    pub fn desc() -> Function2Description {
+        Function2Description::creator(Box::new(Self::try_create))
+            .features(FunctionFeatures::default().deterministic().num_arguments(2))
+    }
+}
+
+impl<const LEADING: bool, const TRAILING: bool> Function2 for TrimCharsFunction<LEADING, TRAILING> {
+    fn name(&self) -> &str {
+        &*self.display_name
+    }
+
+    fn return_type(&self, args: &[&DataTypePtr]) -> Result<DataTypePtr> {
+        assert_string(args[0])?;
+        assert_string(args[1])?;
+        Ok(Vu8::to_data_type())
+    }
+
+    fn eval(&self, columns: &ColumnsWithField, input_rows: usize) -> Result<ColumnRef> {
+        let col1 = Vu8::try_create_viewer(columns[0].column())?;
+        let col2 = Vu8::try_create_viewer(columns[1].column())?;
+        let mut builder = MutableStringColumn::with_capacity(input_rows);
+
+        col1.iter().zip(col2.iter()).for_each(|(str, chars)| {
+            builder.append_value(trim_chars::<LEADING, TRAILING>(str, chars))
+        });
+        Ok(builder.to_column())
+    }
+}
+
+impl<const LEADING: bool, const TRAILING: bool> fmt::Display
+    for TrimCharsFunction<LEADING, TRAILING>
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.display_name)
+    }
+}
+
+#[inline]
+fn trim_chars<'a, const LEADING: bool, const TRAILING: bool>(
+    str: &'a [u8],
+    chars: &[u8],
+) -> &'a [u8] {
+    // Match the characters of valid UTF-8, so that a multi-byte character is never split.
+    if let (Ok(str), Ok(chars)) = (std::str::from_utf8(str), std::str::from_utf8(chars)) {
+        let chars = chars.chars().collect::<Vec<_>>();
+        let mut str = str;
+        if LEADING {
+            str = str.trim_start_matches(chars.as_slice());
+        }
+        if TRAILING {
+            str = str.trim_end_matches(chars.as_slice());
+        }
+        return str.as_bytes();
+    }
+
+    let mut str = str;
+    if LEADING {
+        let start = str
+            .iter()
+            .position(|ch| !chars.contains(ch))
+            .unwrap_or(str.len());
+        str = &str[start..];
+    }
+    if TRAILING {
+        let end = str
+            .iter()
+            .rposition(|ch| !chars.contains(ch))
+            .map_or(0, |idx| idx + 1);
+        str = &str[..end];
+    }
+    str
+}
@@ -37,53 +37,71 @@ impl<const NEGATED: bool> InFunction<NEGATED> {
     pub fn desc() -> Function2Description {
         Function2Description::creator(Box::new(Self::try_create)).features(
             FunctionFeatures::default()
+                .deterministic()
                 .bool_function()
                 .variadic_arguments(2, usize::MAX),
         )
     }
 }
 
+/// Whether each input row is valid and contained in the set, and whether the set has a NULL.
 macro_rules! scalar_contains {
-    ($T: ident, $INPUT_COL: expr, $ROWS: expr, $COLUMNS: expr, $CAST_TYPE: ident) => {{
-        let mut builder: ColumnBuilder<bool> = ColumnBuilder::with_capacity($ROWS);
-        let mut vals_set = HashSet::with_capacity($ROWS - 1);
+    ($T: ident, $INPUT_COL: expr, $COLUMNS: expr, $SET_ROWS: expr, $CAST_TYPE: ident) => {{
+        let mut vals_set = HashSet::with_capacity($SET_ROWS * ($COLUMNS.len() - 1));
+        let mut set_has_null = false;
         for col in &$COLUMNS[1..] {
             let col = cast_column_field(col, &$CAST_TYPE)?;
             let col_viewer = $T::try_create_viewer(&col)?;
-            if col_viewer.valid_at(0) {
-                let val = col_viewer.value_at(0).to_owned_scalar();
-                vals_set.insert(val);
+            for row in 0..$SET_ROWS {
+                match col_viewer.valid_at(row) {
+                    true => {
+                        vals_set.insert(col_viewer.value_at(row).to_owned_scalar());
+                    }
+                    false => set_has_null = true,
+                }
             }
         }
         let input_viewer = $T::try_create_viewer(&$INPUT_COL)?;
-        for (row, val) in input_viewer.iter().enumerate() {
-            let contains = vals_set.contains(&val.to_owned());
-            let valid = input_viewer.valid_at(row);
-            builder.append(valid && ((contains && !NEGATED) || (!contains && NEGATED)));
-        }
-        return Ok(builder.build($ROWS));
+        let matches = input_viewer
+            .iter()
+            .enumerate()
+            .map(|(row, val)| {
+                (
+                    input_viewer.valid_at(row),
+                    vals_set.contains(&val.to_owned()),
+                )
+            })
+            .collect::<Vec<_>>();
+        (matches, set_has_null)
     }};
 }
 
 macro_rules! float_contains {
-    ($T: ident, $INPUT_COL: expr, $ROWS: expr, $COLUMNS: expr, $CAST_TYPE: ident) => {{
-        let mut builder: ColumnBuilder<bool> = ColumnBuilder::with_capacity($ROWS);
-        let mut vals_set = HashSet::with_capacity($ROWS - 1);
+    ($T: ident, $INPUT_COL: expr, $COLUMNS: expr, $SET_ROWS: expr, $CAST_TYPE: ident) => {{
+        let mut vals_set = HashSet::with_capacity($SET_ROWS * ($COLUMNS.len() - 1));
+        let mut set_has_null = false;
         for col in &$COLUMNS[1..] {
             let col = cast_column_field(col, &$CAST_TYPE)?;
             let col_viewer = $T::try_create_viewer(&col)?;
-            if col_viewer.valid_at(0) {
-                let val = col_viewer.value_at(0);
-                vals_set.insert(OrderedFloat::from(val));
+            for row in 0..$SET_ROWS {
+                match col_viewer.valid_at(row) {
+                    true => {
+                        vals_set.insert(OrderedFloat::from(col_viewer.value_at(row)));
+                    }
+                    false => set_has_null = true,
+                }
             }
         }
         let input_viewer = $T::try_create_viewer(&$INPUT_COL)?;
-        for (row, val) in input_viewer.iter().enumerate() {
-            let contains = vals_set.contains(&OrderedFloat::from(val));
-            let valid = input_viewer.valid_at(row);
-            builder.append(valid && ((contains && !NEGATED) || (!contains && NEGATED)));
-        }
-        return Ok(builder.build($ROWS));
+        let matches = input_viewer
+            .iter()
+            .enumerate()
+            .map(|(row, val)| {
+                let contains = vals_set.contains(&OrderedFloat::from(val));
+                (input_viewer.valid_at(row), contains)
+            })
+            .collect::<Vec<_>>();
+        (matches, set_has_null)
     }};
 }
 
@@ -93,6 +111,21 @@ impl<const NEGATED: bool> Function2 for InFunction<NEGATED> {
     }
 
     fn return_type(&self, args: &[&DataTypePtr]) -> Result<DataTypePtr> {
+        if let Some(inner_type) = Self::subquery_inner_type(args) {
+            // `x IN (subquery)`: the subquery values must be comparable with x.
+            let types = vec![args[0].clone(), inner_type.clone()];
+            aggregate_types(&types).map_err(|_| {
+                ErrorCode::BadArguments(format!(
+                    "The subquery of {} returns {}, which is not comparable with {}",
+                    self,
+                    inner_type.name(),
+                    args[0].name()
+                ))
+            })?;
+
+            return self.return_type(&[args[0], inner_type]);
+        }
+
         for dt in args {
             let type_id = remove_nullable(dt).data_type_id();
             if type_id.is_date_or_date_time()
@@ -110,10 +143,92 @@ impl<const NEGATED: bool> Function2 for InFunction<NEGATED> {
         if input_dt == TypeID::Null {
             return Ok(NullType::arc());
         }
-        Ok(BooleanType::arc())
+
+        match Self::may_be_null(args) {
+            true => Ok(wrap_nullable(&BooleanType::arc())),
+            false => Ok(BooleanType::arc()),
+        }
     }
 
     fn eval(&self, columns: &ColumnsWithField, input_rows: usize) -> Result<ColumnRef> {
+        let types: Vec<DataTypePtr> = columns.iter().map(|col| col.column().data_type()).collect();
+        let types: Vec<&DataTypePtr> = types.iter().collect();
+        if let Some(inner_type) = Self::subquery_inner_type(&types) {
+            let values = match columns[1].column().get(0) {
+                DataValue::Array(values) => values,
+                other => {
+                    return Err(ErrorCode::LogicalError(format!(
+                        "The subquery of {} must be an array, but got {:?}",
+                        self, other
+                    )))
+                }
+            };
+
+            let set_rows = values.len();
+            let set_column = inner_type.create_column(&values)?;
+            let set_field = DataField::new(columns[1].field().name(), inner_type.clone());
+            let columns = [
+                columns[0].clone(),
+                ColumnWithField::new(set_column, set_field),
+            ];
+            return self.eval_in(&columns, input_rows, set_rows);
+        }
+
+        self.eval_in(columns, input_rows, 1)
+    }
+
+    fn passthrough_null(&self) -> bool {
+        false
+    }
+}
+
+impl<const NEGATED: bool> InFunction<NEGATED> {
+    /// The values of `x IN (subquery)` arrive as a single array argument.
+    fn subquery_inner_type<'a>(args: &[&'a DataTypePtr]) -> Option<&'a DataTypePtr> {
+        match args {
+            [_, set_type] if set_type.data_type_id() == TypeID::Array => set_type
+                .as_any()
+                .downcast_ref::<ArrayType>()
+                .map(|array_type| array_type.inner_type()),
+            _ => None,
+        }
+    }
+
+    /// NULL input, or a set with a NULL, makes the result nullable.
+    fn may_be_null(args: &[&DataTypePtr]) -> bool {
+        args.iter()
+            .any(|dt| dt.is_nullable() || dt.data_type_id() == TypeID::Null)
+    }
+
+    /// `x IN (set)` is NULL if x is NULL, or if x is not found and the set has a NULL,
+    /// the same goes for `x NOT IN (set)`.
+    fn build_result(matches: Vec<(bool, bool)>, set_has_null: bool, nullable: bool) -> ColumnRef {
+        let rows = matches.len();
+        if !nullable {
+            let mut builder: ColumnBuilder<bool> = ColumnBuilder::with_capacity(rows);
+            for (_, contains) in matches {
+                builder.append(contains != NEGATED);
+            }
+            return builder.build(rows);
+        }
+
+        let mut builder = NullableColumnBuilder::<bool>::with_capacity(rows);
+        for (valid, contains) in matches {
+            match valid && (contains || !set_has_null) {
+                true => builder.append(contains != NEGATED, true),
+                false => builder.append_null(),
+            }
+        }
+        builder.build(rows)
+    }
+
+    /// Only the first `set_rows` rows of the set columns are the values to match.
+    fn eval_in(
+        &self,
+        columns: &ColumnsWithField,
+        input_rows: usize,
+        set_rows: usize,
+    ) -> Result<ColumnRef> {
         for col in columns {
             let dt = col.column().data_type();
             let type_id = remove_nullable(&dt).data_type_id();
@@ -142,51 +257,31 @@ impl<const NEGATED: bool> Function2 for InFunction<NEGATED> {
 
         let input_col = cast_column_field(input_col, &least_super_dt)?;
 
-        match least_super_type_id {
+        let (matches, set_has_null) = match least_super_type_id {
             TypeID::Boolean => {
-                scalar_contains!(bool, input_col, input_rows, columns, least_super_dt)
-            }
-            TypeID::UInt8 => {
-                scalar_contains!(u8, input_col, input_rows, columns, least_super_dt)
-            }
-            TypeID::UInt16 => {
-                scalar_contains!(u16, input_col, input_rows, columns, least_super_dt)
-            }
-            TypeID::UInt32 => {
-                scalar_contains!(u32, input_col, input_rows, columns, least_super_dt)
-            }
-            TypeID::UInt64 => {
-                scalar_contains!(u64, input_col, input_rows, columns, least_super_dt)
-            }
-            TypeID::Int8 => {
-                scalar_contains!(i8, input_col, input_rows, columns, least_super_dt)
-            }
-            TypeID::Int16 => {
-                scalar_contains!(i16, input_col, input_rows, columns, least_super_dt)
-            }
-            TypeID::Int32 => {
-                scalar_contains!(i32, input_col, input_rows, columns, least_super_dt)
-            }
-            TypeID::Int64 => {
-                scalar_contains!(i64, input_col, input_rows, columns, least_super_dt)
+                scalar_contains!(bool, input_col, columns, set_rows, least_super_dt)
             }
+            TypeID::UInt8 => scalar_contains!(u8, input_col, columns, set_rows, least_super_dt),
+            TypeID::UInt16 => scalar_contains!(u16, input_col, columns, set_rows, least_super_dt),
+            TypeID::UInt32 => scalar_contains!(u32, input_col, columns, set_rows, least_super_dt),
+            TypeID::UInt64 => scalar_contains!(u64, input_col, columns, set_rows, least_super_dt),
+            TypeID::Int8 => scalar_contains!(i8, input_col, columns, set_rows, least_super_dt),
+            TypeID::Int16 => scalar_contains!(i16, input_col, columns, set_rows, least_super_dt),
+            TypeID::Int32 => scalar_contains!(i32, input_col, columns, set_rows, least_super_dt),
+            TypeID::Int64 => scalar_contains!(i64, input_col, columns, set_rows, least_super_dt),
             TypeID::String => {
-                scalar_contains!(Vu8, input_col, input_rows, columns, least_super_dt)
-            }
-            TypeID::Float32 => {
-                float_contains!(f32, input_col, input_rows, columns, least_super_dt);
-            }
-            TypeID::Float64 => {
-                float_contains!(f64, input_col, input_rows, columns, least_super_dt);
+                scalar_contains!(Vu8, input_col, columns, set_rows, least_super_dt)
             }
+            TypeID::Float32 => float_contains!(f32, input_col, columns, set_rows, least_super_dt),
+            TypeID::Float64 => float_contains!(f64, input_col, columns, set_rows, least_super_dt),
             _ => {
                 unimplemented!()
             }
-        }
-    }
+        };
 
-    fn passthrough_null(&self) -> bool {
-        false
+        let column_types: Vec<&DataTypePtr> = types.iter().collect();
+        let nullable = Self::may_be_null(&column_types);
+        Ok(Self::build_result(matches, set_has_null, nullable))
     }
 }
 
@@ -53,7 +53,7 @@ where T: UUIDVerifier + Clone + Sync + Send + 'static
 
     pub fn desc() -> Function2Description {
         Function2Description::creator(Box::new(Self::try_create))
-            .features(FunctionFeatures::default().num_arguments(1))
+            .features(FunctionFeatures::default().deterministic().num_arguments(1))
     }
 }
 
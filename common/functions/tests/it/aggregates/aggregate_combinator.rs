@@ -15,10 +15,12 @@
 use std::borrow::BorrowMut;
 
 use bumpalo::Bump;
+use bytes::BytesMut;
 use common_datavalues2::prelude::*;
 use common_datavalues2::with_match_primitive_type_id;
 use common_exception::Result;
 use common_functions::aggregates::AggregateFunctionFactory;
+use common_functions::aggregates::StateAddr;
 use pretty_assertions::assert_eq;
 
 #[test]
@@ -361,3 +363,74 @@ fn test_aggregate_combinator_function_on_empty_data() -> Result<()> {
     }
     Ok(())
 }
+
+#[test]
+fn test_count_distinct_with_nulls() -> Result<()> {
+    let arena = Bump::new();
+    let factory = AggregateFunctionFactory::instance();
+
+    // Two partitions with duplicates across them, the nulls are not counted.
+    let partitions: Vec<Vec<ColumnRef>> = vec![
+        vec![
+            Series::from_data(vec![Some(1i64), None, Some(2), Some(2), Some(1)]),
+            Series::from_data(vec![Some(1i64), Some(1), None, Some(2), Some(1)]),
+        ],
+        vec![
+            Series::from_data(vec![Some(2i64), Some(3), None, Some(1), Some(3)]),
+            Series::from_data(vec![Some(2i64), Some(3), Some(3), Some(1), Some(4)]),
+        ],
+    ];
+
+    let args = vec![
+        DataField::new_nullable("a", i64::to_data_type()),
+        DataField::new_nullable("b", i64::to_data_type()),
+    ];
+
+    // countDistinct(a) counts {1, 2, 3}, countDistinct(a, b) counts {(1, 1), (2, 2), (3, 3), (3, 4)}.
+    for (args, expect) in [(vec![args[0].clone()], 3u64), (args.clone(), 4u64)] {
+        let func = factory.get("countDistinct", vec![], args.clone())?;
+
+        // Accumulate each partition into a partial state, then merge the serialized
+        // states as the final aggregator does.
+        let final_place = arena.alloc_layout(func.state_layout());
+        func.init_state(final_place.into());
+
+        for columns in partitions.iter() {
+            let columns = &columns[0..args.len()];
+            let rows = columns[0].len();
+
+            let partial_place = arena.alloc_layout(func.state_layout());
+            func.init_state(partial_place.into());
+            func.accumulate(partial_place.into(), columns, None, rows)?;
+
+            let mut writer = BytesMut::new();
+            func.serialize(partial_place.into(), &mut writer)?;
+
+            let state_place = arena.alloc_layout(func.state_layout());
+            func.init_state(state_place.into());
+            func.deserialize(state_place.into(), &mut writer.as_ref())?;
+            func.merge(final_place.into(), state_place.into())?;
+        }
+
+        let mut array = MutablePrimitiveColumn::<u64>::default();
+        func.merge_result(final_place.into(), &mut array)?;
+        assert_eq!(array.values(), &[expect], "{:?}", args);
+
+        // The same partitions grouped by key, all the rows fall into one group.
+        let group_place = arena.alloc_layout(func.state_layout());
+        func.init_state(group_place.into());
+
+        for columns in partitions.iter() {
+            let columns = &columns[0..args.len()];
+            let rows = columns[0].len();
+            let places: Vec<StateAddr> = vec![group_place.into(); rows];
+            func.accumulate_keys(&places, 0, columns, rows)?;
+        }
+
+        let mut array = MutablePrimitiveColumn::<u64>::default();
+        func.merge_result(group_place.into(), &mut array)?;
+        assert_eq!(array.values(), &[expect], "{:?}", args);
+    }
+
+    Ok(())
+}
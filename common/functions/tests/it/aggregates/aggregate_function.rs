@@ -15,6 +15,7 @@
 use std::borrow::BorrowMut;
 
 use bumpalo::Bump;
+use bytes::BytesMut;
 use common_datavalues2::prelude::*;
 use common_datavalues2::with_match_primitive_type_id;
 use common_exception::Result;
@@ -958,3 +959,68 @@ fn test_covariance_with_comparable_data_sets() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_variance_with_partial_states() -> Result<()> {
+    let arena = Bump::new();
+
+    let values = vec![
+        3.5f64, -1.25, 8.0, 2.0, 2.0, 13.75, -4.5, 0.0, 6.25, 1.0, 9.5,
+    ];
+    let count = values.len() as f64;
+    let mean = values.iter().sum::<f64>() / count;
+    let squares = values.iter().map(|v| (v - mean) * (v - mean)).sum::<f64>();
+
+    let args = vec![DataField::new("a", f64::to_data_type())];
+    let factory = AggregateFunctionFactory::instance();
+
+    // Accumulate the partitions into partial states, then merge the serialized states
+    // as the final aggregator does.
+    let run_test = |func_name: &'static str| -> Result<f64> {
+        let func = factory.get(func_name, vec![], args.clone())?;
+
+        let final_place = arena.alloc_layout(func.state_layout());
+        func.init_state(final_place.into());
+
+        for partition in values.chunks(4) {
+            let column: ColumnRef = Series::from_data(partition.to_vec());
+            let partial_place = arena.alloc_layout(func.state_layout());
+            func.init_state(partial_place.into());
+            func.accumulate(partial_place.into(), &[column], None, partition.len())?;
+
+            let mut writer = BytesMut::new();
+            func.serialize(partial_place.into(), &mut writer)?;
+
+            let state_place = arena.alloc_layout(func.state_layout());
+            func.init_state(state_place.into());
+            func.deserialize(state_place.into(), &mut writer.as_ref())?;
+            func.merge(final_place.into(), state_place.into())?;
+        }
+
+        let mut array = MutablePrimitiveColumn::<f64>::default();
+        func.merge_result(final_place.into(), &mut array)?;
+        Ok(array.values()[0])
+    };
+
+    let r = run_test("varPop")?;
+    assert!(approx_eq!(f64, squares / count, r, epsilon = 1e-9));
+
+    let r = run_test("varSamp")?;
+    assert!(approx_eq!(f64, squares / (count - 1.0), r, epsilon = 1e-9));
+
+    let r = run_test("stddevPop")?;
+    assert!(approx_eq!(f64, (squares / count).sqrt(), r, epsilon = 1e-9));
+
+    let r = run_test("stddevSamp")?;
+    assert!(approx_eq!(
+        f64,
+        (squares / (count - 1.0)).sqrt(),
+        r,
+        epsilon = 1e-9
+    ));
+
+    let r = run_test("stddev_pop")?;
+    assert!(approx_eq!(f64, (squares / count).sqrt(), r, epsilon = 1e-9));
+
+    Ok(())
+}
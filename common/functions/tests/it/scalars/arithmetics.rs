@@ -17,6 +17,7 @@ use common_datavalues2::prelude::*;
 use common_exception::Result;
 use common_functions::scalars::*;
 
+use super::scalar_function2_test::test_eval_with_type;
 use super::scalar_function2_test::test_scalar_functions2;
 use super::scalar_function2_test::ScalarFunction2Test;
 
@@ -116,6 +117,34 @@ fn test_arithmetic_function() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_arithmetic_constant() -> Result<()> {
+    let plus =
+        ArithmeticPlusFunction::try_create_func("", &[&Int64Type::arc(), &Int64Type::arc()])?;
+    let columns = vec![
+        ColumnWithField::new(
+            ConstColumn::new(Series::from_data(vec![5i64]), 1000).arc(),
+            DataField::new("a", Int64Type::arc()),
+        ),
+        ColumnWithField::new(
+            ConstColumn::new(Series::from_data(vec![3i64]), 1000).arc(),
+            DataField::new("b", Int64Type::arc()),
+        ),
+    ];
+
+    // Constant inputs are evaluated once, the result stays a constant column.
+    let result = test_eval_with_type(&plus, 1000, &columns, &[
+        &Int64Type::arc(),
+        &Int64Type::arc(),
+    ])?;
+    assert!(result.is_const());
+    assert_eq!(result.len(), 1000);
+    assert_eq!(result.get(0), DataValue::Int64(8));
+    assert_eq!(result.get(999), DataValue::Int64(8));
+
+    Ok(())
+}
+
 #[test]
 fn test_arithmetic_date_interval() -> Result<()> {
     let to_day16 = |y: i32, m: u32, d: u32| -> u16 {
@@ -0,0 +1,156 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues2::prelude::*;
+use common_exception::Result;
+use common_functions::scalars::*;
+
+use super::scalar_function2_test::test_scalar_functions2;
+use super::scalar_function2_test::ScalarFunction2Test;
+
+#[test]
+fn test_bitwise_function() -> Result<()> {
+    let tests = vec![
+        (
+            BitwiseOperator::And.try_create_func(&[&Int64Type::arc(), &Int64Type::arc()])?,
+            ScalarFunction2Test {
+                name: "and-int64-passed",
+                columns: vec![
+                    Series::from_data(vec![12i64, 7, -1]),
+                    Series::from_data(vec![10i64, 2, 5]),
+                ],
+                expect: Series::from_data(vec![8i64, 2, 5]),
+                error: "",
+            },
+        ),
+        (
+            BitwiseOperator::Or.try_create_func(&[&UInt8Type::arc(), &UInt16Type::arc()])?,
+            ScalarFunction2Test {
+                name: "or-diff-passed",
+                columns: vec![
+                    Series::from_data(vec![12u8, 1, 0]),
+                    Series::from_data(vec![10u16, 256, 0]),
+                ],
+                expect: Series::from_data(vec![14u16, 257, 0]),
+                error: "",
+            },
+        ),
+        (
+            BitwiseOperator::Xor.try_create_func(&[&UInt64Type::arc(), &Int64Type::arc()])?,
+            ScalarFunction2Test {
+                name: "xor-uint64-int64-passed",
+                columns: vec![
+                    Series::from_data(vec![12u64, 5, 0]),
+                    Series::from_data(vec![10i64, 5, -1]),
+                ],
+                expect: Series::from_data(vec![6i64, 0, -1]),
+                error: "",
+            },
+        ),
+        (
+            BitwiseOperator::ShiftLeft.try_create_func(&[&Int32Type::arc(), &UInt8Type::arc()])?,
+            ScalarFunction2Test {
+                name: "shift-left-passed",
+                columns: vec![
+                    Series::from_data(vec![1i32, 3, 1, 1]),
+                    Series::from_data(vec![3u8, 2, 31, 32]),
+                ],
+                expect: Series::from_data(vec![8i32, 12, i32::MIN, 0]),
+                error: "",
+            },
+        ),
+        (
+            BitwiseOperator::ShiftRight
+                .try_create_func(&[&UInt32Type::arc(), &Int64Type::arc()])?,
+            ScalarFunction2Test {
+                name: "shift-right-passed",
+                columns: vec![
+                    Series::from_data(vec![16u32, 16, 16, 16]),
+                    Series::from_data(vec![2i64, 0, -1, 40]),
+                ],
+                expect: Series::from_data(vec![4u32, 16, 0, 0]),
+                error: "",
+            },
+        ),
+        (
+            BitwiseOperator::ShiftLeft.try_create_func(&[&UInt16Type::arc(), &Int32Type::arc()])?,
+            ScalarFunction2Test {
+                name: "shift-left-const-passed",
+                columns: vec![
+                    Series::from_data(vec![1u16, 2, 3]),
+                    ConstColumn::new(Series::from_data(vec![2i32]), 3).arc(),
+                ],
+                expect: Series::from_data(vec![4u16, 8, 12]),
+                error: "",
+            },
+        ),
+        (
+            BitwiseNotFunction::try_create_func("", &[&Int8Type::arc()])?,
+            ScalarFunction2Test {
+                name: "not-int8-passed",
+                columns: vec![Series::from_data(vec![0i8, 1, -1])],
+                expect: Series::from_data(vec![-1i8, -2, 0]),
+                error: "",
+            },
+        ),
+        (
+            BitwiseNotFunction::try_create_func("", &[&UInt8Type::arc()])?,
+            ScalarFunction2Test {
+                name: "not-uint8-passed",
+                columns: vec![Series::from_data(vec![0u8, 255, 15])],
+                expect: Series::from_data(vec![255u8, 0, 240]),
+                error: "",
+            },
+        ),
+    ];
+
+    for (test_function, test) in tests {
+        test_scalar_functions2(test_function, &[test])?
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_bitwise_function_with_non_integer() -> Result<()> {
+    let result = BitwiseOperator::And.try_create_func(&[&Float64Type::arc(), &Int64Type::arc()]);
+    assert_eq!(
+        result.err().unwrap().message(),
+        "Function bitAnd expects integer arguments, but got Float64"
+    );
+
+    let result = BitwiseNotFunction::try_create_func("", &[&StringType::arc()]);
+    assert_eq!(
+        result.err().unwrap().message(),
+        "Function bitNot expects an integer argument, but got String"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_bitwise_function_factory() -> Result<()> {
+    let factory = Function2Factory::instance();
+    let args = [&Int64Type::arc(), &Int64Type::arc()];
+    for name in ["&", "|", "^", "<<", ">>", "bitAnd", "bitShiftRight"] {
+        let func = factory.get(name, &args)?;
+        assert_eq!(func.return_type(&args)?.data_type_id(), TypeID::Int64);
+    }
+
+    let args = [&Int32Type::arc()];
+    let func = factory.get("~", &args)?;
+    assert_eq!(func.return_type(&args)?.data_type_id(), TypeID::Int32);
+
+    Ok(())
+}
@@ -12,6 +12,9 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::sync::Arc;
+
+use common_arrow::arrow::bitmap::MutableBitmap;
 use common_datavalues2::prelude::*;
 use common_exception::Result;
 use common_functions::scalars::*;
@@ -168,3 +171,95 @@ fn test_not_regexp_comparison_function() -> Result<()> {
 
     test_scalar_functions2(ComparisonRegexpFunction::try_create_nregexp("")?, &tests)
 }
+
+fn struct_column(a: ColumnRef, b: ColumnRef) -> ColumnRef {
+    let struct_type = StructType::create(vec!["a".to_string(), "b".to_string()], vec![
+        a.data_type(),
+        b.data_type(),
+    ]);
+    StructColumn::from_data(vec![a, b], Arc::new(struct_type)).arc()
+}
+
+#[test]
+fn test_struct_comparison_function() -> Result<()> {
+    let lhs = struct_column(
+        Series::from_data(vec![1i64, 1, 2, 3, 4, 5]),
+        Series::from_data(vec![Some("x"), Some("x"), None, None, Some(""), None]),
+    );
+    let rhs = struct_column(
+        Series::from_data(vec![1i64, 2, 2, 3, 4, 6]),
+        Series::from_data(vec![Some("x"), Some("x"), None, Some("z"), None, Some("y")]),
+    );
+
+    let tests = vec![ScalarFunction2Test {
+        name: "struct-eq-passed",
+        columns: vec![lhs.clone(), rhs.clone()],
+        expect: Series::from_data(vec![Some(true), Some(false), None, None, None, Some(false)]),
+        error: "",
+    }];
+    test_scalar_functions2(ComparisonEqFunction::try_create_func("")?, &tests)?;
+
+    let tests = vec![ScalarFunction2Test {
+        name: "struct-not-eq-passed",
+        columns: vec![lhs.clone(), rhs.clone()],
+        expect: Series::from_data(vec![Some(false), Some(true), None, None, None, Some(true)]),
+        error: "",
+    }];
+    test_scalar_functions2(ComparisonNotEqFunction::try_create_func("")?, &tests)?;
+
+    // A NULL struct compares as NULL.
+    let mut validity = MutableBitmap::with_capacity(lhs.len());
+    for row in 0..lhs.len() {
+        validity.push(row != 0);
+    }
+    let nullable_lhs = NullableColumn::new(lhs.clone(), validity.into()).arc();
+    let tests = vec![ScalarFunction2Test {
+        name: "nullable-struct-eq-passed",
+        columns: vec![nullable_lhs, rhs.clone()],
+        expect: Series::from_data(vec![None, Some(false), None, None, None, Some(false)]),
+        error: "",
+    }];
+    test_scalar_functions2(ComparisonEqFunction::try_create_func("")?, &tests)?;
+
+    let tests = vec![ScalarFunction2Test {
+        name: "struct-lt-failed",
+        columns: vec![lhs, rhs],
+        expect: Series::from_data(Vec::<bool>::new()),
+        error: "Illegal types [Struct, Struct] of argument of function ComparisonFunction, can not be struct or array",
+    }];
+    test_scalar_functions2(ComparisonLtFunction::try_create_func("")?, &tests)
+}
+
+#[test]
+fn test_between_comparison_function() -> Result<()> {
+    let int64 = i64::to_data_type();
+    let nullable_int64 = wrap_nullable(&int64);
+    let arg_types = [&int64, &nullable_int64, &nullable_int64];
+    let columns = vec![
+        Series::from_data(vec![0i64, 3, 5, 5]),
+        Series::from_data(vec![Some(1i64), Some(1), None, Some(1)]),
+        Series::from_data(vec![None, Some(4i64), Some(4), None]),
+    ];
+
+    let tests = vec![ScalarFunction2Test {
+        name: "between-passed",
+        columns: columns.clone(),
+        expect: Series::from_data(vec![Some(false), Some(true), Some(false), None]),
+        error: "",
+    }];
+    test_scalar_functions2(
+        ComparisonBetweenFunction::try_create("between", false, &arg_types)?,
+        &tests,
+    )?;
+
+    let tests = vec![ScalarFunction2Test {
+        name: "not-between-passed",
+        columns,
+        expect: Series::from_data(vec![Some(true), Some(false), Some(true), None]),
+        error: "",
+    }];
+    test_scalar_functions2(
+        ComparisonBetweenFunction::try_create("between", true, &arg_types)?,
+        &tests,
+    )
+}
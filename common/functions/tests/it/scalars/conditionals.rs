@@ -15,7 +15,11 @@ use std::sync::Arc;
 
 use common_datavalues2::prelude::*;
 use common_exception::Result;
+use common_functions::scalars::CoalesceFunction;
+use common_functions::scalars::GreatestFunction;
 use common_functions::scalars::IfFunction;
+use common_functions::scalars::LeastFunction;
+use common_functions::scalars::MultiIfFunction;
 
 use crate::scalars::scalar_function2_test::test_scalar_functions2;
 use crate::scalars::scalar_function2_test::ScalarFunction2Test;
@@ -107,3 +111,216 @@ fn test_if_function() -> Result<()> {
 
     test_scalar_functions2(IfFunction::try_create("if")?, &tests)
 }
+
+#[test]
+fn test_coalesce_function() -> Result<()> {
+    let tests = vec![
+        ScalarFunction2Test {
+            name: "coalesce-non-nullable-first",
+            columns: vec![
+                Series::from_data([1u8, 2, 3]),
+                Series::from_data([Some(5u8), None, None]),
+            ],
+            expect: Series::from_data(vec![1u8, 2, 3]),
+            error: "",
+        },
+        ScalarFunction2Test {
+            name: "coalesce-all-nullable",
+            columns: vec![
+                Series::from_data([Some(1u8), None, None, Some(4)]),
+                Series::from_data([Some(2i32), Some(3), None, Some(5)]),
+            ],
+            expect: Series::from_data(vec![Some(1i32), Some(3), None, Some(4)]),
+            error: "",
+        },
+        ScalarFunction2Test {
+            name: "coalesce-nullable-and-non-nullable",
+            columns: vec![
+                Series::from_data([None, Some(2u8), None]),
+                Series::from_data([Some(5u8), None, None]),
+                Series::from_data([7u8, 8, 9]),
+            ],
+            expect: Series::from_data(vec![5u8, 2, 9]),
+            error: "",
+        },
+        ScalarFunction2Test {
+            name: "coalesce-null",
+            columns: vec![
+                Arc::new(NullColumn::new(3)),
+                Series::from_data(["a", "b", "c"]),
+            ],
+            expect: Series::from_data(vec!["a", "b", "c"]),
+            error: "",
+        },
+        ScalarFunction2Test {
+            name: "coalesce-all-null",
+            columns: vec![Arc::new(NullColumn::new(2)), Arc::new(NullColumn::new(2))],
+            expect: Arc::new(NullColumn::new(2)),
+            error: "",
+        },
+    ];
+
+    test_scalar_functions2(CoalesceFunction::try_create("coalesce")?, &tests)
+}
+
+#[test]
+fn test_multi_if_function() -> Result<()> {
+    let tests = vec![
+        ScalarFunction2Test {
+            name: "multi-if-first-true-condition",
+            columns: vec![
+                Series::from_data([true, false, false, true]),
+                Series::from_data([1u8, 2, 3, 4]),
+                Series::from_data([true, true, false, false]),
+                Series::from_data([10u8, 20, 30, 40]),
+                Series::from_data([100u8, 200, 250, 255]),
+            ],
+            expect: Series::from_data(vec![1u8, 20, 250, 4]),
+            error: "",
+        },
+        ScalarFunction2Test {
+            name: "multi-if-common-type",
+            columns: vec![
+                Series::from_data([true, false, false]),
+                Series::from_data([1u8, 2, 3]),
+                Series::from_data([false, true, false]),
+                Series::from_data([-10i32, -20, -30]),
+                Series::from_data([100i64, 200, 300]),
+            ],
+            expect: Series::from_data(vec![1i64, -20, 300]),
+            error: "",
+        },
+        ScalarFunction2Test {
+            name: "multi-if-null-condition-is-false",
+            columns: vec![
+                Series::from_data([Some(true), None, Some(false)]),
+                Series::from_data([1u8, 2, 3]),
+                Series::from_data([7u8, 8, 9]),
+            ],
+            expect: Series::from_data(vec![1u8, 8, 9]),
+            error: "",
+        },
+        ScalarFunction2Test {
+            name: "multi-if-nullable-value",
+            columns: vec![
+                Series::from_data([true, true, false]),
+                Series::from_data([Some(1u8), None, Some(3)]),
+                Series::from_data([7u8, 8, 9]),
+            ],
+            expect: Series::from_data(vec![Some(1u8), None, Some(9)]),
+            error: "",
+        },
+        ScalarFunction2Test {
+            name: "multi-if-even-arguments",
+            columns: vec![
+                Series::from_data([true, false]),
+                Series::from_data([1u8, 2]),
+            ],
+            expect: Series::from_data(vec![1u8, 2]),
+            error: "Function multiIf expects an odd number of arguments, but got 2",
+        },
+    ];
+
+    test_scalar_functions2(MultiIfFunction::try_create("multiIf")?, &tests)
+}
+
+#[test]
+fn test_greatest_function() -> Result<()> {
+    let tests = vec![
+        ScalarFunction2Test {
+            name: "greatest-common-type",
+            columns: vec![
+                Series::from_data([1u8, 5, 3]),
+                Series::from_data([4i32, -2, 3]),
+                Series::from_data([2i64, 1, 7]),
+            ],
+            expect: Series::from_data(vec![4i64, 5, 7]),
+            error: "",
+        },
+        ScalarFunction2Test {
+            name: "greatest-skip-nulls",
+            columns: vec![
+                Series::from_data([Some(1u8), None, None, Some(9)]),
+                Series::from_data([Some(4u8), Some(2), None, None]),
+                Series::from_data([3u8, 1, 5, 2]),
+            ],
+            expect: Series::from_data(vec![Some(4u8), Some(2), Some(5), Some(9)]),
+            error: "",
+        },
+        ScalarFunction2Test {
+            name: "greatest-all-null",
+            columns: vec![
+                Series::from_data([Some(1.5f64), None]),
+                Series::from_data([Some(2.5f64), None]),
+            ],
+            expect: Series::from_data(vec![Some(2.5f64), None]),
+            error: "",
+        },
+        ScalarFunction2Test {
+            name: "greatest-const",
+            columns: vec![
+                Series::from_data([1u8, 5, 3]),
+                ConstColumn::new(Series::from_data(vec![3u8]), 3).arc(),
+            ],
+            expect: Series::from_data(vec![3u8, 5, 3]),
+            error: "",
+        },
+        ScalarFunction2Test {
+            name: "greatest-string",
+            columns: vec![
+                Series::from_data(["a", "bc", "b"]),
+                Series::from_data(["b", "ab", "b"]),
+            ],
+            expect: Series::from_data(vec!["b", "bc", "b"]),
+            error: "",
+        },
+    ];
+
+    test_scalar_functions2(GreatestFunction::try_create("greatest")?, &tests)
+}
+
+#[test]
+fn test_least_function() -> Result<()> {
+    let tests = vec![
+        ScalarFunction2Test {
+            name: "least-common-type",
+            columns: vec![
+                Series::from_data([1u8, 5, 3]),
+                Series::from_data([4i32, -2, 3]),
+                Series::from_data([2i64, 1, 7]),
+            ],
+            expect: Series::from_data(vec![1i64, -2, 3]),
+            error: "",
+        },
+        ScalarFunction2Test {
+            name: "least-skip-nulls",
+            columns: vec![
+                Series::from_data([Some(1u8), None, None, Some(9)]),
+                Series::from_data([Some(4u8), Some(2), None, None]),
+                Series::from_data([3u8, 1, 5, 2]),
+            ],
+            expect: Series::from_data(vec![Some(1u8), Some(1), Some(5), Some(2)]),
+            error: "",
+        },
+        ScalarFunction2Test {
+            name: "least-all-null",
+            columns: vec![
+                Series::from_data([Some(1.5f64), None]),
+                Series::from_data([Some(2.5f64), None]),
+            ],
+            expect: Series::from_data(vec![Some(1.5f64), None]),
+            error: "",
+        },
+        ScalarFunction2Test {
+            name: "least-const",
+            columns: vec![
+                Series::from_data([1u8, 5, 3]),
+                ConstColumn::new(Series::from_data(vec![3u8]), 3).arc(),
+            ],
+            expect: Series::from_data(vec![1u8, 3, 3]),
+            error: "",
+        },
+    ];
+
+    test_scalar_functions2(LeastFunction::try_create("least")?, &tests)
+}
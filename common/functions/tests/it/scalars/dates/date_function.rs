@@ -236,6 +236,77 @@ fn test_toyyyymmddhhmmss_function() -> Result<()> {
     test_scalar_functions2_with_type(ToYYYYMMDDhhmmssFunction::try_create("a")?, &tests)
 }
 
+#[test]
+fn test_toyear_function() -> Result<()> {
+    let tests = vec![
+        ScalarFunction2WithFieldTest {
+            name: "test_toyear_date16",
+            columns: vec![ColumnWithField::new(
+                Series::from_data(vec![0u16]),
+                DataField::new("dummy_1", Date16Type::arc()),
+            )],
+            expect: Series::from_data(vec![1970u16]),
+            error: "",
+        },
+        ScalarFunction2WithFieldTest {
+            name: "test_toyear_date32_before_epoch",
+            columns: vec![ColumnWithField::new(
+                Series::from_data(vec![-1i32]),
+                DataField::new("dummy_1", Date32Type::arc()),
+            )],
+            expect: Series::from_data(vec![1969u16]),
+            error: "",
+        },
+        ScalarFunction2WithFieldTest {
+            name: "test_toyear_date16_2021_03_15",
+            columns: vec![ColumnWithField::new(
+                Series::from_data(vec![18701u16]),
+                DataField::new("dummy_1", Date16Type::arc()),
+            )],
+            expect: Series::from_data(vec![2021u16]),
+            error: "",
+        },
+        ScalarFunction2WithFieldTest {
+            name: "test_toyear_date32_2021_03_15",
+            columns: vec![ColumnWithField::new(
+                Series::from_data(vec![18701i32]),
+                DataField::new("dummy_1", Date32Type::arc()),
+            )],
+            expect: Series::from_data(vec![2021u16]),
+            error: "",
+        },
+        ScalarFunction2WithFieldTest {
+            name: "test_toyear_datetime_2021_03_15",
+            columns: vec![ColumnWithField::new(
+                Series::from_data(vec![1615809600u32]),
+                DataField::new("dummy_1", DateTime32Type::arc(None)),
+            )],
+            expect: Series::from_data(vec![2021u16]),
+            error: "",
+        },
+        ScalarFunction2WithFieldTest {
+            name: "test_toyear_datetime64_2021_03_15",
+            columns: vec![ColumnWithField::new(
+                Series::from_data(vec![1615809600123i64]),
+                DataField::new("dummy_1", DateTime64Type::arc(3, None)),
+            )],
+            expect: Series::from_data(vec![2021u16]),
+            error: "",
+        },
+        ScalarFunction2WithFieldTest {
+            name: "test_toyear_nullable_date32",
+            columns: vec![ColumnWithField::new(
+                Series::from_data(vec![Some(18701i32), None]),
+                DataField::new_nullable("dummy_1", Date32Type::arc()),
+            )],
+            expect: Series::from_data(vec![Some(2021u16), None]),
+            error: "",
+        },
+    ];
+
+    test_scalar_functions2_with_type(ToYearFunction::try_create("a")?, &tests)
+}
+
 #[test]
 fn test_tomonth_function() -> Result<()> {
     let tests = vec![
@@ -293,6 +364,51 @@ fn test_tomonth_function() -> Result<()> {
             expect: Series::from_data(vec![10u8]),
             error: "",
         },
+        ScalarFunction2WithFieldTest {
+            name: "test_tomonth_date16_2021_03_15",
+            columns: vec![ColumnWithField::new(
+                Series::from_data(vec![18701u16]),
+                DataField::new("dummy_1", Date16Type::arc()),
+            )],
+            expect: Series::from_data(vec![3u8]),
+            error: "",
+        },
+        ScalarFunction2WithFieldTest {
+            name: "test_tomonth_date32_2021_03_15",
+            columns: vec![ColumnWithField::new(
+                Series::from_data(vec![18701i32]),
+                DataField::new("dummy_1", Date32Type::arc()),
+            )],
+            expect: Series::from_data(vec![3u8]),
+            error: "",
+        },
+        ScalarFunction2WithFieldTest {
+            name: "test_tomonth_datetime_2021_03_15",
+            columns: vec![ColumnWithField::new(
+                Series::from_data(vec![1615809600u32]),
+                DataField::new("dummy_1", DateTime32Type::arc(None)),
+            )],
+            expect: Series::from_data(vec![3u8]),
+            error: "",
+        },
+        ScalarFunction2WithFieldTest {
+            name: "test_tomonth_datetime64_2021_03_15",
+            columns: vec![ColumnWithField::new(
+                Series::from_data(vec![1615809600123i64]),
+                DataField::new("dummy_1", DateTime64Type::arc(3, None)),
+            )],
+            expect: Series::from_data(vec![3u8]),
+            error: "",
+        },
+        ScalarFunction2WithFieldTest {
+            name: "test_tomonth_nullable_date32",
+            columns: vec![ColumnWithField::new(
+                Series::from_data(vec![Some(18701i32), None]),
+                DataField::new_nullable("dummy_1", Date32Type::arc()),
+            )],
+            expect: Series::from_data(vec![Some(3u8), None]),
+            error: "",
+        },
     ];
 
     test_scalar_functions2_with_type(ToMonthFunction::try_create("c")?, &tests)
@@ -479,6 +595,51 @@ fn test_todayofmonth_function() -> Result<()> {
             expect: Series::from_data(vec![2u8]),
             error: "",
         },
+        ScalarFunction2WithFieldTest {
+            name: "test_todayofmonth_date16_2021_03_15",
+            columns: vec![ColumnWithField::new(
+                Series::from_data(vec![18701u16]),
+                DataField::new("dummy_1", Date16Type::arc()),
+            )],
+            expect: Series::from_data(vec![15u8]),
+            error: "",
+        },
+        ScalarFunction2WithFieldTest {
+            name: "test_todayofmonth_date32_2021_03_15",
+            columns: vec![ColumnWithField::new(
+                Series::from_data(vec![18701i32]),
+                DataField::new("dummy_1", Date32Type::arc()),
+            )],
+            expect: Series::from_data(vec![15u8]),
+            error: "",
+        },
+        ScalarFunction2WithFieldTest {
+            name: "test_todayofmonth_datetime_2021_03_15",
+            columns: vec![ColumnWithField::new(
+                Series::from_data(vec![1615809600u32]),
+                DataField::new("dummy_1", DateTime32Type::arc(None)),
+            )],
+            expect: Series::from_data(vec![15u8]),
+            error: "",
+        },
+        ScalarFunction2WithFieldTest {
+            name: "test_todayofmonth_datetime64_2021_03_15",
+            columns: vec![ColumnWithField::new(
+                Series::from_data(vec![1615809600123i64]),
+                DataField::new("dummy_1", DateTime64Type::arc(3, None)),
+            )],
+            expect: Series::from_data(vec![15u8]),
+            error: "",
+        },
+        ScalarFunction2WithFieldTest {
+            name: "test_todayofmonth_nullable_date32",
+            columns: vec![ColumnWithField::new(
+                Series::from_data(vec![Some(18701i32), None]),
+                DataField::new_nullable("dummy_1", Date32Type::arc()),
+            )],
+            expect: Series::from_data(vec![Some(15u8), None]),
+            error: "",
+        },
     ];
 
     test_scalar_functions2_with_type(ToDayOfMonthFunction::try_create("a")?, &tests)
@@ -19,6 +19,7 @@ use common_exception::Result;
 use common_functions::scalars::*;
 
 use super::scalar_function2_test::ScalarFunction2WithFieldTest;
+use crate::scalars::scalar_function2_test::test_eval_with_type;
 use crate::scalars::scalar_function2_test::test_scalar_functions2;
 use crate::scalars::scalar_function2_test::test_scalar_functions2_with_type;
 use crate::scalars::scalar_function2_test::ScalarFunction2Test;
@@ -88,7 +89,7 @@ fn test_cast_function() -> Result<()> {
             ScalarFunction2Test {
                 name: "cast-string-to-date16-passed",
                 columns: vec![Series::from_data(vec!["2021-03-05", "2021-10-24"])],
-                expect: Series::from_data(vec![18691u16, 18924]),
+                expect: Series::from_data(vec![Some(18691u16), Some(18924)]),
                 error: "",
             },
         ),
@@ -97,7 +98,42 @@ fn test_cast_function() -> Result<()> {
             ScalarFunction2Test {
                 name: "cast-string-to-date32-passed",
                 columns: vec![Series::from_data(vec!["2021-03-05", "2021-10-24"])],
-                expect: Series::from_data(vec![18691i32, 18924]),
+                expect: Series::from_data(vec![Some(18691i32), Some(18924)]),
+                error: "",
+            },
+        ),
+        (
+            CastFunction::create("cast", "date32")?,
+            ScalarFunction2Test {
+                name: "cast-iso-datetime-string-to-date32-passed",
+                columns: vec![Series::from_data(vec![
+                    "2021-03-05T01:01:01Z",
+                    "2021-10-24 10:10:10",
+                ])],
+                expect: Series::from_data(vec![Some(18691i32), Some(18924)]),
+                error: "",
+            },
+        ),
+        (
+            CastFunction::create("cast", "date32")?,
+            ScalarFunction2Test {
+                name: "cast-invalid-string-to-date32-passed",
+                columns: vec![Series::from_data(vec!["2021-03-05", "2021-02-30", "x"])],
+                expect: Series::from_data(vec![Some(18691i32), None, None]),
+                error: "",
+            },
+        ),
+        (
+            CastFunction::create_with_type("cast", wrap_nullable(&Date32Type::arc())),
+            ScalarFunction2Test {
+                name: "cast-invalid-string-to-nullable-date32-passed",
+                columns: vec![Series::from_data(vec![
+                    "2021-13-01",
+                    "2021-03-05",
+                    "x",
+                    "2021-10-24",
+                ])],
+                expect: Series::from_data(vec![None, Some(18691i32), None, Some(18924)]),
                 error: "",
             },
         ),
@@ -109,10 +145,122 @@ fn test_cast_function() -> Result<()> {
                     "2021-03-05 01:01:01",
                     "2021-10-24 10:10:10",
                 ])],
-                expect: Series::from_data(vec![1614906061u32, 1635070210]),
+                expect: Series::from_data(vec![Some(1614906061u32), Some(1635070210)]),
+                error: "",
+            },
+        ),
+        (
+            CastFunction::create("cast", "array(int32)")?,
+            ScalarFunction2Test {
+                name: "cast-string-to-array-passed",
+                columns: vec![Series::from_data(vec!["[1, 2,3]", " [ 4 ,5 ] ", "[]"])],
+                expect: ArrayColumn::from_data(
+                    Arc::new(ArrayType::create(Int32Type::arc())),
+                    vec![0_i64, 3, 5, 5].into(),
+                    Series::from_data(vec![1i32, 2, 3, 4, 5]),
+                )
+                .arc(),
+                error: "",
+            },
+        ),
+        (
+            CastFunction::create_with_type(
+                "cast",
+                Arc::new(ArrayType::create(wrap_nullable(&Int32Type::arc()))),
+            ),
+            ScalarFunction2Test {
+                name: "cast-string-to-array-with-null-passed",
+                columns: vec![Series::from_data(vec!["[1,null,3]"])],
+                expect: ArrayColumn::from_data(
+                    Arc::new(ArrayType::create(wrap_nullable(&Int32Type::arc()))),
+                    vec![0_i64, 3].into(),
+                    Series::from_data(vec![Some(1i32), None, Some(3)]),
+                )
+                .arc(),
                 error: "",
             },
         ),
+        (
+            CastFunction::create("cast", "array(int32)")?,
+            ScalarFunction2Test {
+                name: "cast-string-to-array-malformed",
+                columns: vec![Series::from_data(vec!["[1, 2", "[1,,2]"])],
+                expect: Series::from_data(Vec::<i32>::new()),
+                error: "Cast error happens in casting from String to Array(Int32)",
+            },
+        ),
+        (
+            CastFunction::create("cast", "array(int32)")?,
+            ScalarFunction2Test {
+                name: "cast-string-to-array-null-element",
+                columns: vec![Series::from_data(vec!["[1,null,3]"])],
+                expect: Series::from_data(Vec::<i32>::new()),
+                error: "Cast error happens in casting from String to Array(Int32)",
+            },
+        ),
+        (
+            CastFunction::create_with_type("cast", Arc::new(ArrayType::create(StringType::arc()))),
+            ScalarFunction2Test {
+                name: "cast-string-to-array-quoted-passed",
+                columns: vec![Series::from_data(vec![r#"["a,b", 'c', "d\"e", 'null']"#])],
+                expect: ArrayColumn::from_data(
+                    Arc::new(ArrayType::create(StringType::arc())),
+                    vec![0_i64, 4].into(),
+                    Series::from_data(vec!["a,b", "c", "d\"e", "null"]),
+                )
+                .arc(),
+                error: "",
+            },
+        ),
+        (
+            CastFunction::create_with_type(
+                "cast",
+                Arc::new(ArrayType::create(Arc::new(ArrayType::create(
+                    Int32Type::arc(),
+                )))),
+            ),
+            ScalarFunction2Test {
+                name: "cast-string-to-nested-array-passed",
+                columns: vec![Series::from_data(vec!["[[1, 2], [], [3]]"])],
+                expect: ArrayColumn::from_data(
+                    Arc::new(ArrayType::create(Arc::new(ArrayType::create(
+                        Int32Type::arc(),
+                    )))),
+                    vec![0_i64, 3].into(),
+                    ArrayColumn::from_data(
+                        Arc::new(ArrayType::create(Int32Type::arc())),
+                        vec![0_i64, 2, 2, 3].into(),
+                        Series::from_data(vec![1i32, 2, 3]),
+                    )
+                    .arc(),
+                )
+                .arc(),
+                error: "",
+            },
+        ),
+        (
+            CastFunction::create_with_type("cast", Arc::new(ArrayType::create(StringType::arc()))),
+            ScalarFunction2Test {
+                name: "cast-string-to-array-unclosed-quote",
+                columns: vec![Series::from_data(vec![r#"["a, b]"#])],
+                expect: Series::from_data(Vec::<i32>::new()),
+                error: "Cast error happens in casting from String to Array(String)",
+            },
+        ),
+        (
+            CastFunction::create_with_type(
+                "cast",
+                Arc::new(ArrayType::create(Arc::new(ArrayType::create(
+                    Int32Type::arc(),
+                )))),
+            ),
+            ScalarFunction2Test {
+                name: "cast-string-to-nested-array-malformed",
+                columns: vec![Series::from_data(vec!["[[1, 2], [3, x]]"])],
+                expect: Series::from_data(Vec::<i32>::new()),
+                error: "Cast error happens in casting from String to Array(Array(Int32))",
+            },
+        ),
     ];
 
     for (test_func, test) in tests {
@@ -158,6 +306,66 @@ fn test_datetime_cast_function() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_date32_string_round_trip() -> Result<()> {
+    let date_type = Date32Type::arc();
+    let string_type = Vu8::to_data_type();
+    let dates = ColumnWithField::new(
+        Series::from_data(vec![-1i32, 0, 18691, 2932896]),
+        DataField::new("dummy_1", date_type.clone()),
+    );
+
+    let to_string = CastFunction::create("cast", "string")?;
+    let strings = test_eval_with_type(&to_string, 4, &[dates], &[&date_type])?;
+    assert_eq!(
+        Series::from_data(vec!["1969-12-31", "1970-01-01", "2021-03-05", "9999-12-31"]),
+        strings.convert_full_column()
+    );
+
+    let strings = ColumnWithField::new(strings, DataField::new("dummy_1", string_type.clone()));
+    let to_date = CastFunction::create("cast", "date32")?;
+    let dates_again = test_eval_with_type(&to_date, 4, &[strings], &[&string_type])?;
+    assert_eq!(
+        Series::from_data(vec![Some(-1i32), Some(0), Some(18691), Some(2932896)]),
+        dates_again.convert_full_column()
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_is_widening_cast() {
+    let cases: Vec<(DataTypePtr, DataTypePtr, bool)> = vec![
+        (i32::to_data_type(), i64::to_data_type(), true),
+        (u32::to_data_type(), u64::to_data_type(), true),
+        (u32::to_data_type(), i64::to_data_type(), true),
+        (u32::to_data_type(), f64::to_data_type(), true),
+        (f32::to_data_type(), f64::to_data_type(), true),
+        (i64::to_data_type(), i64::to_data_type(), true),
+        (
+            i32::to_data_type(),
+            wrap_nullable(&i64::to_data_type()),
+            true,
+        ),
+        (i64::to_data_type(), i32::to_data_type(), false),
+        (u32::to_data_type(), i32::to_data_type(), false),
+        (i8::to_data_type(), u64::to_data_type(), false),
+        (f64::to_data_type(), f32::to_data_type(), false),
+        (f64::to_data_type(), i64::to_data_type(), false),
+        (i32::to_data_type(), StringType::arc(), false),
+    ];
+
+    for (from_type, to_type, expect) in cases {
+        assert_eq!(
+            is_widening_cast(&from_type, &to_type),
+            expect,
+            "cast {:?} to {:?}",
+            from_type,
+            to_type
+        );
+    }
+}
+
 #[test]
 fn test_binary_contains() {
     //create two string columns
@@ -21,6 +21,7 @@ use common_functions::scalars::Blake3HashFunction;
 use common_functions::scalars::City64WithSeedFunction;
 use common_functions::scalars::Md5HashFunction;
 use common_functions::scalars::Sha1HashFunction;
+use common_functions::scalars::Sha256HashFunction;
 use common_functions::scalars::Sha2HashFunction;
 use common_functions::scalars::SipHash64Function;
 use common_functions::scalars::XxHash32Function;
@@ -141,28 +142,89 @@ fn test_siphash_function() -> Result<()> {
 
 #[test]
 fn test_md5hash_function() -> Result<()> {
-    let tests = vec![ScalarFunction2Test {
-        name: "valid input",
-        columns: vec![Series::from_data(["testing"])],
-        expect: Series::from_data(["ae2b1fca515949e5d54fb22b8ed95575"]),
-        error: "",
-    }];
+    let tests = vec![
+        ScalarFunction2Test {
+            name: "valid input",
+            columns: vec![Series::from_data(["testing"])],
+            expect: Series::from_data(["ae2b1fca515949e5d54fb22b8ed95575"]),
+            error: "",
+        },
+        ScalarFunction2Test {
+            name: "known answers",
+            columns: vec![Series::from_data(["", "abc"])],
+            expect: Series::from_data([
+                "d41d8cd98f00b204e9800998ecf8427e",
+                "900150983cd24fb0d6963f7d28e17f72",
+            ]),
+            error: "",
+        },
+        ScalarFunction2Test {
+            name: "null input",
+            columns: vec![Series::from_data(vec![Option::<Vec<u8>>::None])],
+            expect: Series::from_data(vec![Option::<Vec<u8>>::None]),
+            error: "",
+        },
+    ];
 
     test_scalar_functions2(Md5HashFunction::try_create("md5")?, &tests)
 }
 
 #[test]
 fn test_sha1hash_function() -> Result<()> {
-    let tests = vec![ScalarFunction2Test {
-        name: "valid input",
-        columns: vec![Series::from_data(["abc"])],
-        expect: Series::from_data(["a9993e364706816aba3e25717850c26c9cd0d89d"]),
-        error: "",
-    }];
+    let tests = vec![
+        ScalarFunction2Test {
+            name: "valid input",
+            columns: vec![Series::from_data(["abc"])],
+            expect: Series::from_data(["a9993e364706816aba3e25717850c26c9cd0d89d"]),
+            error: "",
+        },
+        ScalarFunction2Test {
+            name: "empty input",
+            columns: vec![Series::from_data([""])],
+            expect: Series::from_data(["da39a3ee5e6b4b0d3255bfef95601890afd80709"]),
+            error: "",
+        },
+        ScalarFunction2Test {
+            name: "null input",
+            columns: vec![Series::from_data(vec![Option::<Vec<u8>>::None])],
+            expect: Series::from_data(vec![Option::<Vec<u8>>::None]),
+            error: "",
+        },
+    ];
 
     test_scalar_functions2(Sha1HashFunction::try_create("sha1")?, &tests)
 }
 
+#[test]
+fn test_sha256hash_function() -> Result<()> {
+    let tests = vec![
+        ScalarFunction2Test {
+            name: "valid input",
+            columns: vec![Series::from_data(["abc"])],
+            expect: Series::from_data([
+                "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad",
+            ]),
+            error: "",
+        },
+        ScalarFunction2Test {
+            name: "empty input",
+            columns: vec![Series::from_data([""])],
+            expect: Series::from_data([
+                "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855",
+            ]),
+            error: "",
+        },
+        ScalarFunction2Test {
+            name: "null input",
+            columns: vec![Series::from_data(vec![Option::<Vec<u8>>::None])],
+            expect: Series::from_data(vec![Option::<Vec<u8>>::None]),
+            error: "",
+        },
+    ];
+
+    test_scalar_functions2(Sha256HashFunction::try_create("sha256")?, &tests)
+}
+
 #[test]
 fn test_sha2hash_function() -> Result<()> {
     let tests = vec![
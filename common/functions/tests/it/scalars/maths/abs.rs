@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use common_datavalues::prelude::*;
+use common_datavalues::DataTypeAndNullable;
 use common_exception::Result;
 use common_functions::scalars::*;
 
@@ -26,14 +27,14 @@ fn test_abs_function() -> Result<()> {
             name: "abs(-1)",
             nullable: false,
             columns: vec![Series::new([-1]).into()],
-            expect: Series::new(vec![1_u32]).into(),
+            expect: Series::new(vec![1_i32]).into(),
             error: "",
         },
         ScalarFunctionTest {
             name: "abs(-10086)",
             nullable: false,
             columns: vec![Series::new([-10086]).into()],
-            expect: Series::new(vec![10086_u32]).into(),
+            expect: Series::new(vec![10086_i32]).into(),
             error: "",
         },
         ScalarFunctionTest {
@@ -43,6 +44,48 @@ fn test_abs_function() -> Result<()> {
             expect: Series::new(vec![2.0_f64]).into(),
             error: "",
         },
+        ScalarFunctionTest {
+            name: "abs(-1.5)",
+            nullable: false,
+            columns: vec![Series::new([-1.5_f64, 2.5]).into()],
+            expect: Series::new(vec![1.5_f64, 2.5]).into(),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "abs(i64)",
+            nullable: false,
+            columns: vec![Series::new([-100000_i64, 3, i64::MAX]).into()],
+            expect: Series::new(vec![100000_i64, 3, i64::MAX]).into(),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "abs(i64::MIN)",
+            nullable: false,
+            columns: vec![Series::new([-1_i64, i64::MIN]).into()],
+            expect: Series::new(vec![1_i64]).into(),
+            error: "The absolute value of -9223372036854775808 overflows Int64",
+        },
+        ScalarFunctionTest {
+            name: "abs(i8::MIN)",
+            nullable: false,
+            columns: vec![Series::new([i8::MIN]).into()],
+            expect: Series::new(vec![0_i8]).into(),
+            error: "The absolute value of -128 overflows Int8",
+        },
+        ScalarFunctionTest {
+            name: "abs(u8)",
+            nullable: false,
+            columns: vec![Series::new([1_u8, 255]).into()],
+            expect: Series::new(vec![1_u8, 255]).into(),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "abs(nullable)",
+            nullable: true,
+            columns: vec![Series::new([Some(-8_i16), None]).into()],
+            expect: Series::new([Some(8_i16), None]).into(),
+            error: "",
+        },
         ScalarFunctionTest {
             name: "abs(true)",
             nullable: false,
@@ -54,3 +97,25 @@ fn test_abs_function() -> Result<()> {
 
     test_scalar_functions(AbsFunction::try_create("abs(false)")?, &tests)
 }
+
+#[test]
+fn test_abs_return_type() -> Result<()> {
+    let abs = AbsFunction::try_create("abs")?;
+    let cases = vec![
+        (DataType::Int8, DataType::Int8),
+        (DataType::Int16, DataType::Int16),
+        (DataType::Int32, DataType::Int32),
+        (DataType::Int64, DataType::Int64),
+        (DataType::UInt64, DataType::UInt64),
+        (DataType::Float32, DataType::Float32),
+        (DataType::String, DataType::Float64),
+    ];
+
+    for (arg_type, expect) in cases {
+        let arg = DataTypeAndNullable::create(&arg_type, false);
+        let return_type = abs.return_type(&[arg])?;
+        assert_eq!(&expect, return_type.data_type(), "abs({:?})", arg_type);
+    }
+
+    Ok(())
+}
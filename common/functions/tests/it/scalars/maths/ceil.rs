@@ -70,6 +70,12 @@ fn test_ceil_function() -> Result<()> {
             expect: Series::from_data(vec![0_f64]),
             error: "Expected a numeric type, but got String",
         },
+        ScalarFunction2Test {
+            name: "ceil(nullable)",
+            columns: vec![Series::from_data(vec![Some(1.2_f64), None])],
+            expect: Series::from_data(vec![Some(2_f64), None]),
+            error: "",
+        },
         ScalarFunction2Test {
             name: "ceil(true)",
             columns: vec![Series::from_data([true])],
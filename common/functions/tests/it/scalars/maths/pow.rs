@@ -25,13 +25,13 @@ fn test_pow_function() -> Result<()> {
         ScalarFunction2Test {
             name: "pow-with-literal",
             columns: vec![Series::from_data([2]), Series::from_data([2])],
-            expect: Series::from_data(vec![4_f64]),
+            expect: Series::from_data(vec![Some(4_f64)]),
             error: "",
         },
         ScalarFunction2Test {
             name: "pow-with-series",
             columns: vec![Series::from_data([2, 2]), Series::from_data([2, -2])],
-            expect: Series::from_data([4_f64, 0.25]),
+            expect: Series::from_data([Some(4_f64), Some(0.25)]),
             error: "",
         },
         ScalarFunction2Test {
@@ -61,6 +61,24 @@ fn test_pow_function() -> Result<()> {
             expect: Series::from_data([Some(4_f64), Some(4.0), None]),
             error: "",
         },
+        ScalarFunction2Test {
+            name: "pow-with-fractional-exp",
+            columns: vec![
+                Series::from_data([4_f64, 2.25, 8.0]),
+                Series::from_data([0.5_f64, 0.5, -1.0]),
+            ],
+            expect: Series::from_data([Some(2_f64), Some(1.5), Some(0.125)]),
+            error: "",
+        },
+        ScalarFunction2Test {
+            name: "pow-negative-base-with-fractional-exp",
+            columns: vec![
+                Series::from_data([-8_i32, -8, -2]),
+                Series::from_data([0.5_f64, 1.0, 3.0]),
+            ],
+            expect: Series::from_data([None, Some(-8_f64), Some(-8.0)]),
+            error: "",
+        },
     ];
 
     test_scalar_functions2(PowFunction::try_create("pow")?, &tests)
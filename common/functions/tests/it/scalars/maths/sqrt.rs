@@ -25,13 +25,13 @@ fn test_sqrt_function() -> Result<()> {
         ScalarFunction2Test {
             name: "sqrt-with-literal",
             columns: vec![Series::from_data(vec![4])],
-            expect: Series::from_data(vec![2_f64]),
+            expect: Series::from_data(vec![Some(2_f64)]),
             error: "",
         },
         ScalarFunction2Test {
             name: "sqrt-with-series",
             columns: vec![Series::from_data(vec![4, 16, 0])],
-            expect: Series::from_data(vec![2_f64, 4.0, 0.0]),
+            expect: Series::from_data(vec![Some(2_f64), Some(4.0), Some(0.0)]),
             error: "",
         },
         ScalarFunction2Test {
@@ -40,6 +40,18 @@ fn test_sqrt_function() -> Result<()> {
             expect: Series::from_data(vec![Some(2_f64), None]),
             error: "",
         },
+        ScalarFunction2Test {
+            name: "sqrt-with-negative",
+            columns: vec![Series::from_data(vec![-4_i32, 9, -1])],
+            expect: Series::from_data(vec![None, Some(3_f64), None]),
+            error: "",
+        },
+        ScalarFunction2Test {
+            name: "sqrt-with-float",
+            columns: vec![Series::from_data(vec![2.25_f64, -0.5])],
+            expect: Series::from_data(vec![Some(1.5_f64), None]),
+            error: "",
+        },
     ];
 
     test_scalar_functions2(SqrtFunction::try_create("sqrt")?, &tests)
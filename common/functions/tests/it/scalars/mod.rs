@@ -13,6 +13,7 @@
 // limitations under the License.
 
 mod arithmetics;
+mod bitwises;
 mod comparisons;
 mod conditionals;
 mod dates;
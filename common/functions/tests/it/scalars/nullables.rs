@@ -42,3 +42,28 @@ fn test_is_not_null_function() -> Result<()> {
 
     test_scalar_functions2(IsNotNullFunction::try_create_func("")?, &tests)
 }
+
+#[test]
+fn test_is_truth_functions() -> Result<()> {
+    let cases = vec![
+        ("isTrue", true, false, vec![true, false, false]),
+        ("isNotTrue", true, true, vec![false, true, true]),
+        ("isFalse", false, false, vec![false, true, false]),
+        ("isNotFalse", false, true, vec![true, false, true]),
+    ];
+
+    for (name, expected, negated, expect) in cases {
+        let tests = vec![ScalarFunction2Test {
+            name: "nullable-boolean-passed",
+            columns: vec![Series::from_data(vec![Some(true), Some(false), None])],
+            expect: Series::from_data(expect),
+            error: "",
+        }];
+
+        test_scalar_functions2(
+            IsTruthFunction::try_create(name, expected, negated)?,
+            &tests,
+        )?;
+    }
+    Ok(())
+}
@@ -15,6 +15,7 @@
 use std::sync::Arc;
 
 use common_exception::Result;
+use common_functions::scalars::GroupingFunction;
 use common_functions::scalars::InetAtonFunction;
 use common_functions::scalars::InetNtoaFunction;
 use common_functions::scalars::RunningDifferenceFunction;
@@ -426,3 +427,29 @@ fn test_inet_ntoa_function() -> Result<()> {
     let test_func = InetNtoaFunction::try_create("inet_ntoa")?;
     test_scalar_functions2(test_func, &tests)
 }
+
+#[test]
+fn test_grouping_function() -> Result<()> {
+    use common_datavalues2::prelude::*;
+
+    let tests = vec![
+        ScalarFunction2Test {
+            name: "one_key",
+            columns: vec![Series::from_data([1_u8, 2, 3])],
+            expect: Series::from_data([0_u32, 0, 0]),
+            error: "",
+        },
+        ScalarFunction2Test {
+            name: "nullable_keys",
+            columns: vec![
+                Series::from_data([Some(1_i64), None]),
+                Series::from_data(["a", "b"]),
+            ],
+            expect: Series::from_data([0_u32, 0]),
+            error: "",
+        },
+    ];
+
+    let test_func = GroupingFunction::try_create("grouping")?;
+    test_scalar_functions2(test_func, &tests)
+}
@@ -0,0 +1,79 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues2::prelude::*;
+use common_exception::Result;
+use common_functions::scalars::ConcatFunction;
+
+use crate::scalars::scalar_function2_test::test_scalar_functions2;
+use crate::scalars::scalar_function2_test::ScalarFunction2Test;
+
+#[test]
+fn test_concat_function() -> Result<()> {
+    let tests = vec![
+        ScalarFunction2Test {
+            name: "concat-two-passed",
+            columns: vec![
+                Series::from_data(vec!["My", "Data"]),
+                Series::from_data(vec!["SQL", "bend"]),
+            ],
+            expect: Series::from_data(vec!["MySQL", "Databend"]),
+            error: "",
+        },
+        ScalarFunction2Test {
+            name: "concat-three-passed",
+            columns: vec![
+                Series::from_data(vec!["John", "Jane"]),
+                Series::from_data(vec![" ", " "]),
+                Series::from_data(vec!["Smith", "Doe"]),
+            ],
+            expect: Series::from_data(vec!["John Smith", "Jane Doe"]),
+            error: "",
+        },
+        ScalarFunction2Test {
+            name: "concat-mixed-type-passed",
+            columns: vec![
+                Series::from_data(vec!["a", "b"]),
+                Series::from_data(vec![1_u8, 2_u8]),
+                Series::from_data(vec![-3_i64, 4_i64]),
+                Series::from_data(vec![10_i32, 20_i32]),
+            ],
+            expect: Series::from_data(vec!["a1-310", "b2420"]),
+            error: "",
+        },
+        ScalarFunction2Test {
+            name: "concat-boolean-error",
+            columns: vec![Series::from_data(vec!["a"]), Series::from_data(vec![true])],
+            expect: Series::from_data(vec![""]),
+            error: "Expected string or numeric, but got Boolean",
+        },
+    ];
+
+    test_scalar_functions2(ConcatFunction::try_create("concat")?, &tests)
+}
+
+#[test]
+fn test_concat_nullable() -> Result<()> {
+    let tests = vec![ScalarFunction2Test {
+        name: "concat-nullable-passed",
+        columns: vec![
+            Series::from_data(vec![Some("a"), None, Some("c")]),
+            Series::from_data(vec![Some("x"), Some("y"), None]),
+        ],
+        expect: Series::from_data(vec![Some("ax"), None, None]),
+        error: "",
+    }];
+
+    test_scalar_functions2(ConcatFunction::try_create("concat")?, &tests)
+}
@@ -131,7 +131,87 @@ fn test_locate_function() -> Result<()> {
             expect: Series::new([2_u64, 3_u64]).into(),
             error: "",
         },
+        ScalarFunctionTest {
+            name: "empty needle",
+            nullable: false,
+            columns: vec![
+                DataColumn::Constant(DataValue::String(Some(b"".to_vec())), 1),
+                DataColumn::Constant(DataValue::String(Some(b"abc".to_vec())), 1),
+                DataColumn::Array(Series::new([1_u64, 4_u64, 5_u64])),
+            ],
+            expect: Series::new([1_u64, 4_u64, 0_u64]).into(),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "series with null, series, series",
+            nullable: true,
+            columns: vec![
+                DataColumn::Array(Series::new([Some("b"), None, Some("b")])),
+                DataColumn::Array(Series::new([Some("abc"), Some("abc"), None])),
+                DataColumn::Array(Series::new([1_u64, 1_u64, 1_u64])),
+            ],
+            expect: Series::new([Some(2_u64), None, None]).into(),
+            error: "",
+        },
     ];
 
     test_scalar_functions(LocateFunction::try_create("locate")?, &tests)
 }
+
+#[test]
+fn test_position_function() -> Result<()> {
+    let tests = vec![
+        ScalarFunctionTest {
+            name: "found",
+            nullable: false,
+            columns: vec![
+                Series::new(["bar", "c"]).into(),
+                Series::new(["foobarbar", "abc"]).into(),
+            ],
+            expect: Series::new([4_u64, 3_u64]).into(),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "not found",
+            nullable: false,
+            columns: vec![
+                Series::new(["xbar", "abcd"]).into(),
+                Series::new(["foobar", "abc"]).into(),
+            ],
+            expect: Series::new([0_u64, 0_u64]).into(),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "empty needle",
+            nullable: false,
+            columns: vec![
+                Series::new(["", ""]).into(),
+                Series::new(["abc", ""]).into(),
+            ],
+            expect: Series::new([1_u64, 1_u64]).into(),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "byte offset",
+            nullable: false,
+            columns: vec![
+                DataColumn::Constant(DataValue::String(Some("b".as_bytes().to_vec())), 1),
+                DataColumn::Constant(DataValue::String(Some("éb".as_bytes().to_vec())), 1),
+            ],
+            expect: DataColumn::Constant(DataValue::UInt64(Some(3)), 1),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "null",
+            nullable: true,
+            columns: vec![
+                DataColumn::Constant(DataValue::String(None), 2),
+                Series::new(["abc", "def"]).into(),
+            ],
+            expect: DataColumn::Constant(DataValue::Null, 2),
+            error: "",
+        },
+    ];
+
+    test_scalar_functions(PositionFunction::try_create("position")?, &tests)
+}
@@ -12,8 +12,12 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod concat;
 mod locate;
 mod lower;
+mod pad;
+mod replace;
+mod reverse;
 mod substring;
 mod trim;
 
@@ -0,0 +1,137 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues2::prelude::*;
+use common_exception::Result;
+use common_functions::scalars::LeftPadFunction;
+use common_functions::scalars::RightPadFunction;
+
+use crate::scalars::scalar_function2_test::test_scalar_functions2;
+use crate::scalars::scalar_function2_test::ScalarFunction2Test;
+
+#[test]
+fn test_lpad_function() -> Result<()> {
+    let tests = vec![
+        ScalarFunction2Test {
+            name: "lpad-truncate-passed",
+            columns: vec![
+                Series::from_data(vec!["12345", "abc"]),
+                Series::from_data(vec![3_u64, 0]),
+                Series::from_data(vec!["x", "x"]),
+            ],
+            expect: Series::from_data(vec!["123", ""]),
+            error: "",
+        },
+        ScalarFunction2Test {
+            name: "lpad-pad-passed",
+            columns: vec![
+                Series::from_data(vec!["12345", "abc"]),
+                Series::from_data(vec![8_u64, 3]),
+                Series::from_data(vec!["x", "x"]),
+            ],
+            expect: Series::from_data(vec!["xxx12345", "abc"]),
+            error: "",
+        },
+        ScalarFunction2Test {
+            name: "lpad-multi-char-pad-passed",
+            columns: vec![
+                Series::from_data(vec!["12345", "1"]),
+                Series::from_data(vec![10_u64, 6]),
+                Series::from_data(vec!["abc", "ab"]),
+            ],
+            expect: Series::from_data(vec!["abcab12345", "ababa1"]),
+            error: "",
+        },
+        ScalarFunction2Test {
+            name: "lpad-empty-pad-passed",
+            columns: vec![
+                Series::from_data(vec!["12345", "12345"]),
+                Series::from_data(vec![10_u64, 3]),
+                Series::from_data(vec!["", ""]),
+            ],
+            expect: Series::from_data(vec!["12345", "123"]),
+            error: "",
+        },
+        ScalarFunction2Test {
+            name: "lpad-nullable-passed",
+            columns: vec![
+                Series::from_data(vec![Some("abc"), None, Some("abc")]),
+                Series::from_data(vec![Some(5_u64), Some(5), None]),
+                Series::from_data(vec![Some("x"), Some("x"), Some("x")]),
+            ],
+            expect: Series::from_data(vec![Some("xxabc"), None, None]),
+            error: "",
+        },
+    ];
+
+    test_scalar_functions2(LeftPadFunction::try_create("lpad")?, &tests)
+}
+
+#[test]
+fn test_rpad_function() -> Result<()> {
+    let tests = vec![
+        ScalarFunction2Test {
+            name: "rpad-truncate-passed",
+            columns: vec![
+                Series::from_data(vec!["12345", "abc"]),
+                Series::from_data(vec![3_u64, 0]),
+                Series::from_data(vec!["x", "x"]),
+            ],
+            expect: Series::from_data(vec!["123", ""]),
+            error: "",
+        },
+        ScalarFunction2Test {
+            name: "rpad-pad-passed",
+            columns: vec![
+                Series::from_data(vec!["12345", "abc"]),
+                Series::from_data(vec![8_u64, 3]),
+                Series::from_data(vec!["x", "x"]),
+            ],
+            expect: Series::from_data(vec!["12345xxx", "abc"]),
+            error: "",
+        },
+        ScalarFunction2Test {
+            name: "rpad-multi-char-pad-passed",
+            columns: vec![
+                Series::from_data(vec!["12345", "1"]),
+                Series::from_data(vec![10_u64, 6]),
+                Series::from_data(vec!["abc", "ab"]),
+            ],
+            expect: Series::from_data(vec!["12345abcab", "1ababa"]),
+            error: "",
+        },
+        ScalarFunction2Test {
+            name: "rpad-empty-pad-passed",
+            columns: vec![
+                Series::from_data(vec!["12345", "12345"]),
+                Series::from_data(vec![10_u64, 3]),
+                Series::from_data(vec!["", ""]),
+            ],
+            expect: Series::from_data(vec!["12345", "123"]),
+            error: "",
+        },
+        ScalarFunction2Test {
+            name: "rpad-nullable-passed",
+            columns: vec![
+                Series::from_data(vec![Some("abc"), None, Some("abc")]),
+                Series::from_data(vec![Some(5_u64), Some(5), None]),
+                Series::from_data(vec![Some("x"), Some("x"), Some("x")]),
+            ],
+            expect: Series::from_data(vec![Some("abcxx"), None, None]),
+            error: "",
+        },
+    ];
+
+    test_scalar_functions2(RightPadFunction::try_create("rpad")?, &tests)
+}
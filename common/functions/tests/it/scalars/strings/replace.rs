@@ -0,0 +1,78 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues2::prelude::*;
+use common_exception::Result;
+use common_functions::scalars::ReplaceFunction;
+
+use crate::scalars::scalar_function2_test::test_scalar_functions2;
+use crate::scalars::scalar_function2_test::ScalarFunction2Test;
+
+#[test]
+fn test_replace_function() -> Result<()> {
+    let tests = vec![
+        ScalarFunction2Test {
+            name: "replace-passed",
+            columns: vec![
+                Series::from_data(vec!["abcabc", "abc", "a", "xyz"]),
+                Series::from_data(vec!["bc", "abc", "abc", "x"]),
+                Series::from_data(vec!["X", "", "X", "xx"]),
+            ],
+            expect: Series::from_data(vec!["aXaX", "", "a", "xxyz"]),
+            error: "",
+        },
+        ScalarFunction2Test {
+            name: "replace-overlapping-passed",
+            columns: vec![
+                Series::from_data(vec!["aaa", "aaaa", "abab"]),
+                Series::from_data(vec!["aa", "aa", "bab"]),
+                Series::from_data(vec!["b", "b", "X"]),
+            ],
+            expect: Series::from_data(vec!["ba", "bb", "aX"]),
+            error: "",
+        },
+        ScalarFunction2Test {
+            name: "replace-empty-from-passed",
+            columns: vec![
+                Series::from_data(vec!["abc", ""]),
+                Series::from_data(vec!["", ""]),
+                Series::from_data(vec!["X", "X"]),
+            ],
+            expect: Series::from_data(vec!["abc", ""]),
+            error: "",
+        },
+        ScalarFunction2Test {
+            name: "replace-constant-from-to-passed",
+            columns: vec![
+                Series::from_data(vec!["a-b-c", "-", "abc"]),
+                ConstColumn::new(Series::from_data(vec!["-"]), 3).arc(),
+                ConstColumn::new(Series::from_data(vec!["+"]), 3).arc(),
+            ],
+            expect: Series::from_data(vec!["a+b+c", "+", "abc"]),
+            error: "",
+        },
+        ScalarFunction2Test {
+            name: "replace-null-passed",
+            columns: vec![
+                Series::from_data(vec![Some("abc"), None, Some("abc"), Some("abc")]),
+                Series::from_data(vec![Some("b"), Some("b"), None, Some("b")]),
+                Series::from_data(vec![Some("X"), Some("X"), Some("X"), None]),
+            ],
+            expect: Series::from_data(vec![Some("aXc"), None, None, None]),
+            error: "",
+        },
+    ];
+
+    test_scalar_functions2(ReplaceFunction::try_create("replace")?, &tests)
+}
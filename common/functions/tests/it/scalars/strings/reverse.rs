@@ -0,0 +1,58 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues2::prelude::*;
+use common_exception::Result;
+use common_functions::scalars::ReverseFunction;
+
+use crate::scalars::scalar_function2_test::test_scalar_functions2;
+use crate::scalars::scalar_function2_test::ScalarFunction2Test;
+
+#[test]
+fn test_reverse_function() -> Result<()> {
+    let tests = vec![
+        ScalarFunction2Test {
+            name: "reverse-ascii-passed",
+            columns: vec![Series::from_data(vec!["abcde", "a"])],
+            expect: Series::from_data(vec!["edcba", "a"]),
+            error: "",
+        },
+        ScalarFunction2Test {
+            name: "reverse-multi-byte-passed",
+            columns: vec![Series::from_data(vec!["数据库", "héllo"])],
+            expect: Series::from_data(vec!["库据数", "olléh"]),
+            error: "",
+        },
+        ScalarFunction2Test {
+            name: "reverse-empty-passed",
+            columns: vec![Series::from_data(vec![""])],
+            expect: Series::from_data(vec![""]),
+            error: "",
+        },
+        ScalarFunction2Test {
+            name: "reverse-null-passed",
+            columns: vec![Series::from_data(vec![Some("abc"), None])],
+            expect: Series::from_data(vec![Some("cba"), None]),
+            error: "",
+        },
+        ScalarFunction2Test {
+            name: "reverse-number-failed",
+            columns: vec![Series::from_data(vec![1_i64])],
+            expect: Series::from_data(vec![""]),
+            error: "Expected string arg, but got Int64",
+        },
+    ];
+
+    test_scalar_functions2(ReverseFunction::try_create("reverse")?, &tests)
+}
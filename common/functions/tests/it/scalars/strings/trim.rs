@@ -16,7 +16,10 @@ use common_datavalues2::prelude::*;
 use common_exception::Result;
 use common_functions::scalars::LTrimFunction;
 use common_functions::scalars::RTrimFunction;
+use common_functions::scalars::TrimBothFunction;
 use common_functions::scalars::TrimFunction;
+use common_functions::scalars::TrimLeadingFunction;
+use common_functions::scalars::TrimTrailingFunction;
 
 use crate::scalars::scalar_function2_test::test_scalar_functions2;
 use crate::scalars::scalar_function2_test::ScalarFunction2Test;
@@ -76,3 +79,77 @@ fn test_trim_nullable() -> Result<()> {
 
     test_scalar_functions2(TrimFunction::try_create("trim")?, &tests)
 }
+
+#[test]
+fn test_trim_both_function() -> Result<()> {
+    let tests = vec![
+        ScalarFunction2Test {
+            name: "trim-both-custom-chars-passed",
+            columns: vec![
+                Series::from_data(vec!["xxabcxx", "xyabcyx", "abc", "xxxx", ""]),
+                Series::from_data(vec!["x", "xy", "x", "x", "x"]),
+            ],
+            expect: Series::from_data(vec!["abc", "abc", "abc", "", ""]),
+            error: "",
+        },
+        ScalarFunction2Test {
+            name: "trim-both-empty-chars-passed",
+            columns: vec![
+                Series::from_data(vec!["  abc  "]),
+                Series::from_data(vec![""]),
+            ],
+            expect: Series::from_data(vec!["  abc  "]),
+            error: "",
+        },
+        ScalarFunction2Test {
+            name: "trim-both-utf8-chars-passed",
+            columns: vec![
+                Series::from_data(vec!["éabcé", "日本語日", "ééabc"]),
+                Series::from_data(vec!["è", "日", "é"]),
+            ],
+            expect: Series::from_data(vec!["éabcé", "本語", "abc"]),
+            error: "",
+        },
+        ScalarFunction2Test {
+            name: "trim-both-nullable-passed",
+            columns: vec![
+                Series::from_data(vec![Some("xabcx"), None, Some("xx")]),
+                Series::from_data(vec![Some("x"), Some("x"), None]),
+            ],
+            expect: Series::from_data(vec![Some("abc"), None, None]),
+            error: "",
+        },
+    ];
+
+    test_scalar_functions2(TrimBothFunction::try_create("trim_both")?, &tests)
+}
+
+#[test]
+fn test_trim_leading_function() -> Result<()> {
+    let tests = vec![ScalarFunction2Test {
+        name: "trim-leading-passed",
+        columns: vec![
+            Series::from_data(vec!["xxabcxx", "abcxx", "xxxx"]),
+            Series::from_data(vec!["x", "x", "x"]),
+        ],
+        expect: Series::from_data(vec!["abcxx", "abcxx", ""]),
+        error: "",
+    }];
+
+    test_scalar_functions2(TrimLeadingFunction::try_create("trim_leading")?, &tests)
+}
+
+#[test]
+fn test_trim_trailing_function() -> Result<()> {
+    let tests = vec![ScalarFunction2Test {
+        name: "trim-trailing-passed",
+        columns: vec![
+            Series::from_data(vec!["xxabcxx", "xxabc", "xxxx"]),
+            Series::from_data(vec!["x", "x", "x"]),
+        ],
+        expect: Series::from_data(vec!["xxabc", "xxabc", ""]),
+        error: "",
+    }];
+
+    test_scalar_functions2(TrimTrailingFunction::try_create("trim_trailing")?, &tests)
+}
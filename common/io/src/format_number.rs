@@ -0,0 +1,84 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Display;
+
+/// How the floats are printed as text.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FloatFormat {
+    /// The shortest representation which parses back to the same value.
+    Shortest,
+    /// A fixed number of digits after the point.
+    Fixed(usize),
+}
+
+impl Default for FloatFormat {
+    fn default() -> Self {
+        FloatFormat::Shortest
+    }
+}
+
+/// Format a float as text.
+///
+/// The shortest representation of `0.1_f32` is `0.1` rather than the digits of its f64 widening,
+/// and the integral values have no trailing `.0`.
+pub fn format_float<F: ryu::Float + Display>(value: F, format: FloatFormat) -> String {
+    match format {
+        FloatFormat::Fixed(precision) => format!("{:.*}", precision, value),
+        FloatFormat::Shortest => {
+            let mut buffer = ryu::Buffer::new();
+            let formatted = buffer.format(value);
+            formatted
+                .strip_suffix(".0")
+                .unwrap_or(formatted)
+                .to_string()
+        }
+    }
+}
+
+/// The text representation of a number in query results, the integers ignore the float format.
+pub trait NumberFormat {
+    fn format_number(&self, format: FloatFormat) -> String;
+}
+
+macro_rules! impl_integer_format {
+    ($ty:ty) => {
+        impl NumberFormat for $ty {
+            fn format_number(&self, _format: FloatFormat) -> String {
+                self.to_string()
+            }
+        }
+    };
+}
+
+impl_integer_format!(u8);
+impl_integer_format!(u16);
+impl_integer_format!(u32);
+impl_integer_format!(u64);
+impl_integer_format!(i8);
+impl_integer_format!(i16);
+impl_integer_format!(i32);
+impl_integer_format!(i64);
+
+impl NumberFormat for f32 {
+    fn format_number(&self, format: FloatFormat) -> String {
+        format_float(*self, format)
+    }
+}
+
+impl NumberFormat for f64 {
+    fn format_number(&self, format: FloatFormat) -> String {
+        format_float(*self, format)
+    }
+}
@@ -20,6 +20,7 @@ pub mod prelude;
 mod binary_read;
 mod binary_write;
 mod buf_read;
+mod format_number;
 mod marshal;
 mod options_deserializer;
 mod stat_buffer;
@@ -20,6 +20,9 @@ pub use crate::binary_write::put_uvarint;
 pub use crate::binary_write::BinaryWrite;
 pub use crate::binary_write::BinaryWriteBuf;
 pub use crate::buf_read::BufReadExt;
+pub use crate::format_number::format_float;
+pub use crate::format_number::FloatFormat;
+pub use crate::format_number::NumberFormat;
 pub use crate::marshal::Marshal;
 pub use crate::options_deserializer::OptionsDeserializer;
 pub use crate::options_deserializer::OptionsDeserializerError;
@@ -0,0 +1,70 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_io::prelude::*;
+
+#[test]
+fn test_format_float_shortest() {
+    assert_eq!(
+        format_float(0.1 + 0.2, FloatFormat::Shortest),
+        "0.30000000000000004"
+    );
+    assert_eq!(format_float(0.1f64, FloatFormat::Shortest), "0.1");
+    assert_eq!(format_float(0.1f32, FloatFormat::Shortest), "0.1");
+    assert_eq!(
+        format_float(1.0f64 / 3.0, FloatFormat::Shortest),
+        "0.3333333333333333"
+    );
+    assert_eq!(
+        format_float(1.0f32 / 3.0, FloatFormat::Shortest),
+        "0.33333334"
+    );
+    assert_eq!(format_float(1.0f64, FloatFormat::Shortest), "1");
+    assert_eq!(format_float(-100.0f64, FloatFormat::Shortest), "-100");
+    assert_eq!(format_float(-0.0f64, FloatFormat::Shortest), "-0");
+    assert_eq!(format_float(1e20f64, FloatFormat::Shortest), "1e20");
+    assert_eq!(format_float(1.5e-7f64, FloatFormat::Shortest), "1.5e-7");
+    assert_eq!(
+        format_float(f64::MAX, FloatFormat::Shortest),
+        "1.7976931348623157e308"
+    );
+    assert_eq!(format_float(f64::NAN, FloatFormat::Shortest), "NaN");
+    assert_eq!(format_float(f64::INFINITY, FloatFormat::Shortest), "inf");
+    assert_eq!(
+        format_float(f64::NEG_INFINITY, FloatFormat::Shortest),
+        "-inf"
+    );
+}
+
+#[test]
+fn test_format_float_precision() {
+    assert_eq!(format_float(0.1 + 0.2, FloatFormat::Fixed(2)), "0.30");
+    assert_eq!(format_float(2.5f64, FloatFormat::Fixed(0)), "2");
+    assert_eq!(format_float(1.0f32 / 3.0, FloatFormat::Fixed(3)), "0.333");
+    assert_eq!(
+        format_float(1e20f64, FloatFormat::Fixed(1)),
+        "100000000000000000000.0"
+    );
+}
+
+#[test]
+fn test_format_number() {
+    assert_eq!(255u8.format_number(FloatFormat::Shortest), "255");
+    assert_eq!((-1i64).format_number(FloatFormat::Shortest), "-1");
+    assert_eq!(0.1f32.format_number(FloatFormat::Shortest), "0.1");
+    assert_eq!(3.0f64.format_number(FloatFormat::Shortest), "3");
+    assert_eq!(255u8.format_number(FloatFormat::Fixed(2)), "255");
+    assert_eq!(0.125f64.format_number(FloatFormat::Fixed(2)), "0.12");
+    assert_eq!(3.0f64.format_number(FloatFormat::Fixed(0)), "3");
+}
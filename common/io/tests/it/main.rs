@@ -15,6 +15,7 @@
 mod binary_read;
 mod binary_write;
 mod buf_read;
+mod format_number;
 mod marshal;
 mod options_deserializer;
 mod utils;
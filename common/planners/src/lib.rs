@@ -21,6 +21,7 @@ mod plan_database_create;
 mod plan_database_drop;
 mod plan_database_show_create;
 mod plan_empty;
+mod plan_expand;
 mod plan_explain;
 mod plan_expression;
 mod plan_expression_action;
@@ -41,9 +42,12 @@ mod plan_kill;
 mod plan_limit;
 mod plan_limit_by;
 mod plan_node;
+mod plan_node_analyzer;
 mod plan_node_builder;
 mod plan_node_display;
+mod plan_node_display_graphviz;
 mod plan_node_display_indent;
+mod plan_node_encoding;
 mod plan_node_extras;
 mod plan_node_rewriter;
 mod plan_node_stage;
@@ -63,6 +67,7 @@ mod plan_show_grants;
 mod plan_show_metrics;
 mod plan_show_processlist;
 mod plan_show_settings;
+mod plan_show_table_status;
 mod plan_show_tables;
 mod plan_show_users;
 mod plan_sink;
@@ -74,6 +79,7 @@ mod plan_table_drop;
 mod plan_table_optimize;
 mod plan_table_show_create;
 mod plan_table_truncate;
+mod plan_union;
 mod plan_use_database;
 mod plan_user_alter;
 mod plan_user_create;
@@ -97,6 +103,7 @@ pub use plan_database_create::DatabaseOptions;
 pub use plan_database_drop::DropDatabasePlan;
 pub use plan_database_show_create::ShowCreateDatabasePlan;
 pub use plan_empty::EmptyPlan;
+pub use plan_expand::ExpandPlan;
 pub use plan_explain::ExplainPlan;
 pub use plan_explain::ExplainType;
 pub use plan_expression::Expression;
@@ -112,6 +119,9 @@ pub use plan_expression_common::extract_aliases;
 pub use plan_expression_common::find_aggregate_exprs;
 pub use plan_expression_common::find_aggregate_exprs_in_expr;
 pub use plan_expression_common::find_columns_not_satisfy_exprs;
+pub use plan_expression_common::find_grouping_exprs;
+pub use plan_expression_common::find_nondeterministic_exprs_in_expr;
+pub use plan_expression_common::normalize_to_cnf;
 pub use plan_expression_common::rebase_expr;
 pub use plan_expression_common::rebase_expr_from_input;
 pub use plan_expression_common::resolve_aliases_to_exprs;
@@ -120,6 +130,9 @@ pub use plan_expression_common::unwrap_alias_exprs;
 pub use plan_expression_common::RequireColumnsVisitor;
 pub use plan_expression_function::add;
 pub use plan_expression_function::avg;
+pub use plan_expression_function::count;
+pub use plan_expression_function::max;
+pub use plan_expression_function::min;
 pub use plan_expression_function::modular;
 pub use plan_expression_function::neg;
 pub use plan_expression_function::not;
@@ -131,6 +144,8 @@ pub use plan_expression_monotonicity::ExpressionMonotonicityVisitor;
 pub use plan_expression_rewriter::ExpressionRewriter;
 pub use plan_expression_sort::sort;
 pub use plan_expression_validator::validate_expression;
+pub use plan_expression_validator::validate_projection_expression;
+pub use plan_expression_validator::validate_sort_expression;
 pub use plan_expression_visitor::ExpressionVisitor;
 pub use plan_expression_visitor::Recursion;
 pub use plan_filter::FilterPlan;
@@ -141,8 +156,13 @@ pub use plan_kill::KillPlan;
 pub use plan_limit::LimitPlan;
 pub use plan_limit_by::LimitByPlan;
 pub use plan_node::PlanNode;
+pub use plan_node_analyzer::PlanAnalyzer;
+pub use plan_node_analyzer::PlanStats;
 pub use plan_node_builder::PlanBuilder;
+pub use plan_node_display_graphviz::PlanGraphvizBuilder;
+pub use plan_node_encoding::PLAN_FORMAT_VERSION;
 pub use plan_node_extras::Extras;
+pub use plan_node_extras::TableSample;
 pub use plan_node_rewriter::PlanRewriter;
 pub use plan_node_rewriter::RewriteHelper;
 pub use plan_node_stage::StageKind;
@@ -166,6 +186,7 @@ pub use plan_show_grants::ShowGrantsPlan;
 pub use plan_show_metrics::ShowMetricsPlan;
 pub use plan_show_processlist::ShowProcessListsPlan;
 pub use plan_show_settings::ShowSettingsPlan;
+pub use plan_show_table_status::ShowTableStatusPlan;
 pub use plan_show_tables::ShowTablesPlan;
 pub use plan_show_users::ShowUsersPlan;
 pub use plan_sink::SinkPlan;
@@ -180,6 +201,7 @@ pub use plan_table_optimize::Optimization;
 pub use plan_table_optimize::OptimizeTablePlan;
 pub use plan_table_show_create::ShowCreateTablePlan;
 pub use plan_table_truncate::TruncateTablePlan;
+pub use plan_union::UnionPlan;
 pub use plan_use_database::UseDatabasePlan;
 pub use plan_user_alter::AlterUserPlan;
 pub use plan_user_create::CreateUserPlan;
@@ -0,0 +1,40 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_datavalues2::DataSchemaRef;
+
+use crate::Expression;
+use crate::PlanNode;
+
+/// Repeats each input row once per set, with the expressions of the set appended as columns,
+/// e.g. the grouping sets of ROLLUP are aggregated at once from a single scan of the input.
+/// The expressions of every set have the same names and have been coerced to the same types.
+#[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq)]
+pub struct ExpandPlan {
+    pub sets: Vec<Vec<Expression>>,
+    pub schema: DataSchemaRef,
+    pub input: Arc<PlanNode>,
+}
+
+impl ExpandPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        self.schema.clone()
+    }
+
+    pub fn set_input(&mut self, node: &PlanNode) {
+        self.input = Arc::new(node.clone());
+    }
+}
@@ -23,6 +23,9 @@ pub enum ExplainType {
     Syntax,
     Graph,
     Pipeline,
+    Optimized,
+    WithTypes,
+    Analyze,
 }
 
 #[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq)]
@@ -27,7 +27,9 @@ use crate::plan_expression_common::ExpressionDataTypeVisitor;
 use crate::ExpressionVisitor;
 use crate::PlanNode;
 
-static OP_SET: Lazy<HashSet<&'static str>> = Lazy::new(|| {
+// The arguments of a context function are bound from the query context,
+// it is called without any argument.
+static CONTEXT_FUNCTIONS: Lazy<HashSet<&'static str>> = Lazy::new(|| {
     ["database", "version", "current_user"]
         .iter()
         .copied()
@@ -82,9 +84,23 @@ pub enum Expression {
         right: Box<Expression>,
     },
 
+    /// A range check such as "age BETWEEN 18 AND 40", the expr is evaluated only once.
+    Between {
+        expr: Box<Expression>,
+        negated: bool,
+        low: Box<Expression>,
+        high: Box<Expression>,
+    },
+
     /// ScalarFunction with a set of arguments.
     /// Note: BinaryFunction is a also kind of functions function
-    ScalarFunction { op: String, args: Vec<Expression> },
+    ScalarFunction {
+        op: String,
+        args: Vec<Expression>,
+        /// The call site of a nondeterministic function, numbered when the plan is built.
+        /// Each call of `SELECT rand(), rand()` has its own column.
+        ordinal: Option<usize>,
+    },
 
     /// AggregateFunction with a set of arguments.
     AggregateFunction {
@@ -102,6 +118,8 @@ pub enum Expression {
         asc: bool,
         /// Whether to put Nulls before all other data values
         nulls_first: bool,
+        /// Whether to compare strings ignoring the case, e.g. `ORDER BY name COLLATE nocase`
+        case_insensitive: bool,
         /// The original expression from parser. Because sort 'expr' field maybe overwritten by a Column expression, like
         /// from BinaryExpression { +, number, number} to Column(number+number), the orig_expr is for keeping the original
         /// one that is before overwritten. This field is mostly for function monotonicity optimization purpose.
@@ -182,8 +200,22 @@ impl Expression {
                     right.column_name()
                 )
             }
-            Expression::ScalarFunction { op, args } => {
-                match OP_SET.get(&op.to_lowercase().as_ref()) {
+            Expression::Between {
+                expr,
+                negated,
+                low,
+                high,
+            } => {
+                format!(
+                    "({} {}between {} and {})",
+                    expr.column_name(),
+                    if *negated { "not " } else { "" },
+                    low.column_name(),
+                    high.column_name()
+                )
+            }
+            Expression::ScalarFunction { op, args, ordinal } => {
+                let name = match CONTEXT_FUNCTIONS.get(&op.to_lowercase().as_ref()) {
                     Some(_) => format!("{}()", op),
                     None => {
                         let args_column_name =
@@ -191,6 +223,12 @@ impl Expression {
 
                         format!("{}({})", op, args_column_name.join(", "))
                     }
+                };
+
+                // The first call site keeps the plain name.
+                match ordinal {
+                    Some(ordinal) if *ordinal > 1 => format!("{}#{}", name, ordinal),
+                    _ => name,
                 }
             }
             Expression::AggregateFunction {
@@ -339,9 +377,21 @@ impl Expression {
         }
     }
 
+    /// The call site ordinal of a nondeterministic scalar function.
+    pub fn ordinal(&self) -> Option<usize> {
+        match self {
+            Expression::ScalarFunction { ordinal, .. } => *ordinal,
+            _ => None,
+        }
+    }
+
     pub fn create_scalar_function(op: &str, args: Expressions) -> Expression {
         let op = op.to_string();
-        Expression::ScalarFunction { op, args }
+        Expression::ScalarFunction {
+            op,
+            args,
+            ordinal: None,
+        }
     }
 
     pub fn create_unary_expression(op: &str, mut args: Expressions) -> Expression {
@@ -376,7 +426,17 @@ impl fmt::Debug for Expression {
                 write!(f, "({} {:?})", op, expr)
             }
 
-            Expression::ScalarFunction { op, args } => {
+            Expression::Between {
+                expr,
+                negated,
+                low,
+                high,
+            } => {
+                let op = if *negated { "NOT BETWEEN" } else { "BETWEEN" };
+                write!(f, "({:?} {} {:?} AND {:?})", expr, op, low, high)
+            }
+
+            Expression::ScalarFunction { op, args, ordinal } => {
                 write!(f, "{}(", op)?;
 
                 for (i, _) in args.iter().enumerate() {
@@ -385,7 +445,12 @@ impl fmt::Debug for Expression {
                     }
                     write!(f, "{:?}", args[i],)?;
                 }
-                write!(f, ")")
+                write!(f, ")")?;
+
+                match ordinal {
+                    Some(ordinal) if *ordinal > 1 => write!(f, "#{}", ordinal),
+                    _ => Ok(()),
+                }
             }
 
             Expression::AggregateFunction {
@@ -17,6 +17,8 @@ use std::fmt;
 use common_datavalues2::prelude::*;
 use common_functions::scalars::Function2;
 
+use crate::Expression;
+
 #[derive(Debug, Clone)]
 pub enum ExpressionAction {
     /// Column which must be in input.
@@ -25,6 +27,8 @@ pub enum ExpressionAction {
     Constant(ActionConstant),
     Alias(ActionAlias),
     Function(ActionFunction),
+    Coalesce(ActionCoalesce),
+    MultiIf(ActionMultiIf),
 }
 
 #[derive(Debug, Clone)]
@@ -59,6 +63,25 @@ pub struct ActionFunction {
     pub arg_types: Vec<DataTypePtr>,
 }
 
+/// Only the first argument is computed by the chain, the others are
+/// evaluated lazily for the rows where all the previous arguments are null.
+#[derive(Debug, Clone)]
+pub struct ActionCoalesce {
+    pub name: String,
+    pub return_type: DataTypePtr,
+    pub args: Vec<Expression>,
+}
+
+/// Only the first condition is computed by the chain. Each following condition is evaluated
+/// lazily for the rows where all the previous ones are false, each value for the rows
+/// its condition selects.
+#[derive(Debug, Clone)]
+pub struct ActionMultiIf {
+    pub name: String,
+    pub return_type: DataTypePtr,
+    pub args: Vec<Expression>,
+}
+
 impl ExpressionAction {
     pub fn column_name(&self) -> &str {
         match self {
@@ -66,6 +89,8 @@ impl ExpressionAction {
             ExpressionAction::Constant(c) => &c.name,
             ExpressionAction::Alias(a) => &a.name,
             ExpressionAction::Function(f) => &f.name,
+            ExpressionAction::Coalesce(c) => &c.name,
+            ExpressionAction::MultiIf(m) => &m.name,
         }
     }
 }
@@ -17,12 +17,15 @@ use common_datavalues2::DataTypePtr;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_functions::scalars::CastFunction;
+use common_functions::scalars::ComparisonBetweenFunction;
 use common_functions::scalars::Function2Factory;
 
 use crate::ActionAlias;
+use crate::ActionCoalesce;
 use crate::ActionConstant;
 use crate::ActionFunction;
 use crate::ActionInput;
+use crate::ActionMultiIf;
 use crate::Expression;
 use crate::ExpressionAction;
 use crate::ExpressionVisitor;
@@ -53,8 +56,19 @@ impl ExpressionChain {
         struct ExpressionActionVisitor(*mut ExpressionChain);
 
         impl ExpressionVisitor for ExpressionActionVisitor {
-            fn pre_visit(self, _expr: &Expression) -> Result<Recursion<Self>> {
-                Ok(Recursion::Continue(self))
+            fn pre_visit(self, expr: &Expression) -> Result<Recursion<Self>> {
+                match expr {
+                    // Common subexpression, it's already computed by the chain
+                    _ if unsafe { (*self.0).is_computed(expr) } => Ok(Recursion::Stop(self)),
+                    // Only the first argument of a lazy function is computed eagerly
+                    Expression::ScalarFunction { op, args, .. }
+                        if ExpressionChain::is_lazy_function(op) && !args.is_empty() =>
+                    unsafe {
+                        (*self.0).recursion_add_expr(&args[0])?;
+                        Ok(Recursion::Stop(self))
+                    },
+                    _ => Ok(Recursion::Continue(self)),
+                }
             }
 
             fn post_visit(self, expr: &Expression) -> Result<Self> {
@@ -69,8 +83,107 @@ impl ExpressionChain {
         Ok(())
     }
 
+    /// The arguments of a lazy function after the first one are not computed by the chain,
+    /// they are evaluated at execution only for the rows that need them.
+    pub fn is_lazy_function(op: &str) -> bool {
+        Self::is_coalesce(op) || op.eq_ignore_ascii_case("multiIf")
+    }
+
+    fn is_coalesce(op: &str) -> bool {
+        op.eq_ignore_ascii_case("coalesce")
+    }
+
+    /// Whether a deterministic function expression already has an action in the chain,
+    /// the repeated subexpression is computed only once.
+    fn is_computed(&self, expr: &Expression) -> bool {
+        let is_function = matches!(
+            expr,
+            Expression::ScalarFunction { .. }
+                | Expression::UnaryExpression { .. }
+                | Expression::BinaryExpression { .. }
+                | Expression::Between { .. }
+                | Expression::Cast { .. }
+        );
+
+        if !is_function {
+            return false;
+        }
+
+        let name = expr.column_name();
+        let computed = self.actions.iter().any(|action| match action {
+            ExpressionAction::Function(f) => f.name == name,
+            ExpressionAction::Coalesce(c) => c.name == name,
+            ExpressionAction::MultiIf(m) => m.name == name,
+            _ => false,
+        });
+
+        computed && Self::is_deterministic(expr)
+    }
+
+    fn is_deterministic(expr: &Expression) -> bool {
+        struct DeterministicVisitor(bool);
+
+        impl ExpressionVisitor for DeterministicVisitor {
+            fn pre_visit(self, expr: &Expression) -> Result<Recursion<Self>> {
+                let deterministic = match expr {
+                    Expression::ScalarFunction { op, .. }
+                    | Expression::UnaryExpression { op, .. }
+                    | Expression::BinaryExpression { op, .. } => Function2Factory::instance()
+                        .get_features(op)
+                        .map(|features| features.is_deterministic)
+                        .unwrap_or(false),
+                    Expression::AggregateFunction { .. } => false,
+                    _ => true,
+                };
+
+                match deterministic {
+                    true => Ok(Recursion::Continue(self)),
+                    false => Ok(Recursion::Stop(DeterministicVisitor(false))),
+                }
+            }
+        }
+
+        match DeterministicVisitor(true).visit(expr) {
+            Ok(visitor) => visitor.0,
+            Err(_) => false,
+        }
+    }
+
     fn add_expr(&mut self, expr: &Expression) -> Result<()> {
+        if self.is_computed(expr) {
+            return Ok(());
+        }
+
         match expr {
+            Expression::ScalarFunction { op, args, .. }
+                if Self::is_lazy_function(op) && !args.is_empty() =>
+            {
+                let arg_types = args
+                    .iter()
+                    .map(|action| action.to_data_type(&self.schema))
+                    .collect::<Result<Vec<_>>>()?;
+
+                let arg_types2: Vec<&DataTypePtr> = arg_types.iter().collect();
+                let func = Function2Factory::instance().get(op, &arg_types2)?;
+                let return_type = func.return_type(&arg_types2)?;
+
+                let name = expr.column_name();
+                let args = args.clone();
+                let action = match Self::is_coalesce(op) {
+                    true => ExpressionAction::Coalesce(ActionCoalesce {
+                        name,
+                        return_type,
+                        args,
+                    }),
+                    false => ExpressionAction::MultiIf(ActionMultiIf {
+                        name,
+                        return_type,
+                        args,
+                    }),
+                };
+                self.actions.push(action);
+            }
+
             Expression::Alias(name, sub_expr) => {
                 let return_type = expr.to_data_type(&self.schema)?;
 
@@ -163,7 +276,41 @@ impl ExpressionChain {
                 self.actions.push(ExpressionAction::Function(function));
             }
 
-            Expression::ScalarFunction { op, args } => {
+            Expression::Between {
+                expr: sub_expr,
+                negated,
+                low,
+                high,
+            } => {
+                let arg_types = vec![
+                    sub_expr.to_data_type(&self.schema)?,
+                    low.to_data_type(&self.schema)?,
+                    high.to_data_type(&self.schema)?,
+                ];
+
+                let func_name = "between".to_string();
+                let arg_types2: Vec<&DataTypePtr> = arg_types.iter().collect();
+                let func =
+                    ComparisonBetweenFunction::try_create(&func_name, *negated, &arg_types2)?;
+                let return_type = func.return_type(&arg_types2)?;
+
+                let function = ActionFunction {
+                    name: expr.column_name(),
+                    func_name,
+                    func,
+                    arg_names: vec![
+                        sub_expr.column_name(),
+                        low.column_name(),
+                        high.column_name(),
+                    ],
+                    arg_types,
+                    return_type,
+                };
+
+                self.actions.push(ExpressionAction::Function(function));
+            }
+
+            Expression::ScalarFunction { op, args, .. } => {
                 let arg_types = args
                     .iter()
                     .map(|action| action.to_data_type(&self.schema))
@@ -198,8 +345,8 @@ impl ExpressionChain {
                 is_nullable,
             } => {
                 let func_name = "cast".to_string();
-                let return_type = data_type.clone();
                 let type_name = format!("{:?}", data_type);
+                let arg_type = sub_expr.to_data_type(&self.schema)?;
 
                 let func = if *is_nullable {
                     CastFunction::create_try(&func_name, &type_name)
@@ -207,12 +354,13 @@ impl ExpressionChain {
                     CastFunction::create(&func_name, &type_name)
                 }?;
 
+                let return_type = func.return_type(&[&arg_type])?;
                 let function = ActionFunction {
                     name: expr.column_name(),
                     func_name,
                     func,
                     arg_names: vec![sub_expr.column_name()],
-                    arg_types: vec![sub_expr.to_data_type(&self.schema)?],
+                    arg_types: vec![arg_type],
                     return_type,
                 };
 
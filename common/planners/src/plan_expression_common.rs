@@ -18,6 +18,8 @@ use std::collections::HashSet;
 use common_datavalues2::prelude::*;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_functions::scalars::cast_result_type;
+use common_functions::scalars::ComparisonBetweenFunction;
 use common_functions::scalars::Function2Factory;
 
 use crate::Expression;
@@ -51,6 +53,26 @@ pub fn find_aggregate_exprs_in_expr(expr: &Expression) -> Vec<Expression> {
     })
 }
 
+/// Collect the `grouping(...)` functions, they are resolved by the grouping sets of ROLLUP.
+pub fn find_grouping_exprs(exprs: &[Expression]) -> Vec<Expression> {
+    find_exprs_in_exprs(exprs, &|nest_exprs| match nest_exprs {
+        Expression::ScalarFunction { op, .. } => op.eq_ignore_ascii_case("grouping"),
+        _ => false,
+    })
+}
+
+/// Collect the scalar functions that may return different results for the same arguments.
+/// Context functions are constant within a query, so they are not collected.
+pub fn find_nondeterministic_exprs_in_expr(expr: &Expression) -> Vec<Expression> {
+    find_exprs_in_expr(expr, &|nest_exprs| match nest_exprs {
+        Expression::ScalarFunction { op, .. } => Function2Factory::instance()
+            .get_features(op)
+            .map(|features| !features.is_deterministic && !features.is_context_func)
+            .unwrap_or(false),
+        _ => false,
+    })
+}
+
 /// Collect all arguments from aggregation function and append to this exprs
 /// [ColumnExpr(b), Aggr(sum(a, b))] ---> [ColumnExpr(b), ColumnExpr(a)]
 
@@ -280,12 +302,25 @@ where F: Fn(&Expression) -> Result<Option<Expression>> {
                 right: Box::new(clone_with_replacement(&**right, replacement_fn)?),
             }),
 
-            Expression::ScalarFunction { op, args } => Ok(Expression::ScalarFunction {
+            Expression::Between {
+                expr: nested_expr,
+                negated,
+                low,
+                high,
+            } => Ok(Expression::Between {
+                expr: Box::new(clone_with_replacement(&**nested_expr, replacement_fn)?),
+                negated: *negated,
+                low: Box::new(clone_with_replacement(&**low, replacement_fn)?),
+                high: Box::new(clone_with_replacement(&**high, replacement_fn)?),
+            }),
+
+            Expression::ScalarFunction { op, args, ordinal } => Ok(Expression::ScalarFunction {
                 op: op.clone(),
                 args: args
                     .iter()
                     .map(|e| clone_with_replacement(e, replacement_fn))
                     .collect::<Result<Vec<Expression>>>()?,
+                ordinal: *ordinal,
             }),
 
             Expression::AggregateFunction {
@@ -307,11 +342,13 @@ where F: Fn(&Expression) -> Result<Option<Expression>> {
                 expr: nested_expr,
                 asc,
                 nulls_first,
+                case_insensitive,
                 origin_expr,
             } => Ok(Expression::Sort {
                 expr: Box::new(clone_with_replacement(&**nested_expr, replacement_fn)?),
                 asc: *asc,
                 nulls_first: *nulls_first,
+                case_insensitive: *case_insensitive,
                 origin_expr: origin_expr.clone(),
             }),
             Expression::Cast {
@@ -365,6 +402,54 @@ pub fn resolve_aliases_to_exprs(
     })
 }
 
+/// The most conjuncts `normalize_to_cnf` distributes into.
+const MAX_CNF_CONJUNCTS: usize = 64;
+
+/// Rebuilds a boolean `expr` in conjunctive normal form by distributing OR over AND,
+/// so that each conjunct can be pushed down or used for pruning on its own.
+///  `(a AND b) OR c` ---> `(a OR c) AND (b OR c)`
+/// NOT is kept as it is. The `expr` is returned unchanged if the result would have
+/// more than `MAX_CNF_CONJUNCTS` conjuncts.
+pub fn normalize_to_cnf(expr: &Expression) -> Expression {
+    match cnf_conjuncts(expr) {
+        None => expr.clone(),
+        Some(conjuncts) => {
+            let mut conjuncts = conjuncts.into_iter();
+            let first = conjuncts.next().unwrap();
+            conjuncts.fold(first, |left, right| left.and(right))
+        }
+    }
+}
+
+fn cnf_conjuncts(expr: &Expression) -> Option<Vec<Expression>> {
+    match expr {
+        Expression::BinaryExpression { left, op, right } if op.eq_ignore_ascii_case("and") => {
+            let mut conjuncts = cnf_conjuncts(left)?;
+            conjuncts.extend(cnf_conjuncts(right)?);
+            match conjuncts.len() > MAX_CNF_CONJUNCTS {
+                true => None,
+                false => Some(conjuncts),
+            }
+        }
+        Expression::BinaryExpression { left, op, right } if op.eq_ignore_ascii_case("or") => {
+            let left = cnf_conjuncts(left)?;
+            let right = cnf_conjuncts(right)?;
+            if left.len() * right.len() > MAX_CNF_CONJUNCTS {
+                return None;
+            }
+
+            let mut conjuncts = Vec::with_capacity(left.len() * right.len());
+            for left in &left {
+                for right in &right {
+                    conjuncts.push(left.or(right.clone()));
+                }
+            }
+            Some(conjuncts)
+        }
+        other => Some(vec![other.clone()]),
+    }
+}
+
 /// Rebuilds an `expr` using the inner expr for expression
 ///  `(a + b) as c` ---> `(a + b)`
 pub fn unwrap_alias_exprs(expr: &Expression) -> Result<Expression> {
@@ -415,6 +500,21 @@ impl ExpressionDataTypeVisitor {
         self.stack.push(return_type);
         Ok(self)
     }
+
+    fn visit_between(mut self, negated: bool) -> Result<ExpressionDataTypeVisitor> {
+        let mut arguments = Vec::with_capacity(3);
+        for index in 0..3 {
+            arguments.push(self.stack.pop().ok_or_else(|| {
+                ErrorCode::LogicalError(format!("Expected 3 arguments, actual {}.", index))
+            })?);
+        }
+
+        let arguments: Vec<&DataTypePtr> = arguments.iter().collect();
+        let function = ComparisonBetweenFunction::try_create("between", negated, &arguments)?;
+        let return_type = function.return_type(&arguments)?;
+        self.stack.push(return_type);
+        Ok(self)
+    }
 }
 
 impl ExpressionVisitor for ExpressionDataTypeVisitor {
@@ -451,7 +551,8 @@ impl ExpressionVisitor for ExpressionDataTypeVisitor {
             }
             Expression::BinaryExpression { op, .. } => self.visit_function(op, 2),
             Expression::UnaryExpression { op, .. } => self.visit_function(op, 1),
-            Expression::ScalarFunction { op, args } => self.visit_function(op, args.len()),
+            Expression::ScalarFunction { op, args, .. } => self.visit_function(op, args.len()),
+            Expression::Between { negated, .. } => self.visit_between(*negated),
             expr @ Expression::AggregateFunction { args, .. } => {
                 // Pop arguments.
                 for index in 0..args.len() {
@@ -475,10 +576,10 @@ impl ExpressionVisitor for ExpressionDataTypeVisitor {
                     None => Err(ErrorCode::LogicalError(
                         "Cast expr expected 1 arguments, actual 0.",
                     )),
-                    Some(_) => Ok(data_type),
+                    Some(from_type) => Ok(cast_result_type(&from_type, data_type)),
                 }?;
 
-                self.stack.push(inner_type.clone());
+                self.stack.push(inner_type);
                 Ok(self)
             }
             Expression::Alias(_, _) | Expression::Sort { .. } => Ok(self),
@@ -54,6 +54,16 @@ pub fn modular(left: Expression, right: Expression) -> Expression {
     binary_expr(left, "%", right)
 }
 
+/// count() aggregate function.
+pub fn count() -> Expression {
+    Expression::AggregateFunction {
+        op: "count".to_string(),
+        distinct: false,
+        params: vec![],
+        args: vec![],
+    }
+}
+
 /// sum() aggregate function.
 pub fn sum(other: Expression) -> Expression {
     Expression::AggregateFunction {
@@ -74,6 +84,26 @@ pub fn avg(other: Expression) -> Expression {
     }
 }
 
+/// min() aggregate function.
+pub fn min(other: Expression) -> Expression {
+    Expression::AggregateFunction {
+        op: "min".to_string(),
+        distinct: false,
+        params: vec![],
+        args: vec![other],
+    }
+}
+
+/// max() aggregate function.
+pub fn max(other: Expression) -> Expression {
+    Expression::AggregateFunction {
+        op: "max".to_string(),
+        distinct: false,
+        params: vec![],
+        args: vec![other],
+    }
+}
+
 impl Expression {
     /// And.
     #[must_use]
@@ -19,6 +19,7 @@ use common_datavalues2::DataField;
 use common_datavalues2::DataSchemaRef;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_functions::scalars::CastFunction;
 use common_functions::scalars::Function2;
 use common_functions::scalars::Function2Factory;
 use common_functions::scalars::Monotonicity2;
@@ -162,6 +163,39 @@ impl ExpressionMonotonicityVisitor {
         Ok(self)
     }
 
+    fn visit_cast(mut self, data_type: &DataTypePtr, is_nullable: bool) -> Result<Self> {
+        let (_, arg_monotonic) = self
+            .stack
+            .pop()
+            .ok_or_else(|| ErrorCode::LogicalError("Expected 1 argument for cast, actual 0."))?;
+
+        // TRY_CAST yields NULL instead of failing, like CastFunction::create_try.
+        let cast_type = match is_nullable && data_type.can_inside_nullable() {
+            true => wrap_nullable(data_type),
+            false => data_type.clone(),
+        };
+        let func = CastFunction::create_with_type("cast", cast_type);
+        let mut monotonic = match self.single_point {
+            false => func.get_monotonicity(&[arg_monotonic.clone()])?,
+            true => Monotonicity2::create_constant(),
+        };
+
+        if !monotonic.is_monotonic && !monotonic.is_constant {
+            return Err(ErrorCode::UnknownException(
+                "Cast is not monotonic in the variables range",
+            ));
+        }
+
+        let return_type = func.return_type(&[])?;
+        monotonic.left =
+            Self::try_calculate_boundary(func.as_ref(), &return_type, vec![arg_monotonic.left])?;
+        monotonic.right =
+            Self::try_calculate_boundary(func.as_ref(), &return_type, vec![arg_monotonic.right])?;
+
+        self.stack.push((return_type, monotonic));
+        Ok(self)
+    }
+
     /// Check whether the expression is monotonic or not. The left should be <= right.
     /// Return the monotonicity information, together with column name if any.
     pub fn check_expression(
@@ -189,6 +223,7 @@ impl ExpressionMonotonicityVisitor {
         if let Expression::Sort {
             asc,
             nulls_first,
+            case_insensitive,
             origin_expr,
             ..
         } = sort_expr
@@ -206,6 +241,7 @@ impl ExpressionMonotonicityVisitor {
                 expr: Box::new(col(column_name)),
                 asc: new_asc,
                 nulls_first: *nulls_first,
+                case_insensitive: *case_insensitive,
                 origin_expr: origin_expr.clone(),
             });
         }
@@ -264,8 +300,12 @@ impl ExpressionVisitor for ExpressionMonotonicityVisitor {
             }
             Expression::BinaryExpression { op, .. } => self.visit_function(op, 2),
             Expression::UnaryExpression { op, .. } => self.visit_function(op, 1),
-            Expression::ScalarFunction { op, args } => self.visit_function(op, args.len()),
-            // Todo: Expression::Cast
+            Expression::ScalarFunction { op, args, .. } => self.visit_function(op, args.len()),
+            Expression::Cast {
+                data_type,
+                is_nullable,
+                ..
+            } => self.visit_cast(data_type, *is_nullable),
             _ => Err(ErrorCode::UnknownException("Unable to get monotonicity")),
         }
     }
@@ -60,15 +60,32 @@ pub trait ExpressionRewriter: Sized {
         })
     }
 
+    fn mutate_between(
+        &mut self,
+        expr: Expression,
+        negated: bool,
+        low: Expression,
+        high: Expression,
+        _origin_expr: &Expression,
+    ) -> Result<Expression> {
+        Ok(Expression::Between {
+            expr: Box::new(expr),
+            negated,
+            low: Box::new(low),
+            high: Box::new(high),
+        })
+    }
+
     fn mutate_scalar_function(
         &mut self,
         name: &str,
         args: Vec<Expression>,
-        _origin_expr: &Expression,
+        origin_expr: &Expression,
     ) -> Result<Expression> {
         Ok(Expression::ScalarFunction {
             op: name.to_string(),
             args,
+            ordinal: origin_expr.ordinal(),
         })
     }
 
@@ -174,12 +191,14 @@ pub trait ExpressionRewriter: Sized {
         expr: Expression,
         asc: bool,
         nulls_first: bool,
+        case_insensitive: bool,
         origin_expr: &Expression,
     ) -> Result<Expression> {
         Ok(Expression::Sort {
             expr: Box::new(expr),
             asc,
             nulls_first,
+            case_insensitive,
             origin_expr: Box::new(origin_expr.clone()),
         })
     }
@@ -242,7 +261,21 @@ impl<T: ExpressionRewriter> ExpressionVisitor for ExpressionRewriteVisitor<T> {
                     Ok(self)
                 }
             },
-            Expression::ScalarFunction { op, args } => {
+            Expression::Between { negated, .. } => {
+                match (self.stack.pop(), self.stack.pop(), self.stack.pop()) {
+                    (Some(new_expr), Some(low), Some(high)) => {
+                        self.stack.push(
+                            self.inner
+                                .mutate_between(new_expr, *negated, low, high, expr)?,
+                        );
+                        Ok(self)
+                    }
+                    (_, _, _) => Err(ErrorCode::LogicalError(
+                        "Between expr expected 3 arguments.",
+                    )),
+                }
+            }
+            Expression::ScalarFunction { op, args, .. } => {
                 let mut args_expr = Vec::with_capacity(args.len());
                 for index in 0..args.len() {
                     match self.stack.pop() {
@@ -328,6 +361,7 @@ impl<T: ExpressionRewriter> ExpressionVisitor for ExpressionRewriteVisitor<T> {
             Expression::Sort {
                 asc,
                 nulls_first,
+                case_insensitive,
                 origin_expr,
                 ..
             } => match self.stack.pop() {
@@ -335,9 +369,13 @@ impl<T: ExpressionRewriter> ExpressionVisitor for ExpressionRewriteVisitor<T> {
                     "Sort expr expected 1 parameters, actual 0.",
                 )),
                 Some(expr) => {
-                    let new_expr = self
-                        .inner
-                        .mutate_sort(expr, *asc, *nulls_first, origin_expr)?;
+                    let new_expr = self.inner.mutate_sort(
+                        expr,
+                        *asc,
+                        *nulls_first,
+                        *case_insensitive,
+                        origin_expr,
+                    )?;
                     self.stack.push(new_expr);
                     Ok(self)
                 }
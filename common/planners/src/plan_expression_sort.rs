@@ -20,6 +20,7 @@ pub fn sort(name: &str, asc: bool, nulls_first: bool) -> Expression {
         expr: Box::new(col(name)),
         asc,
         nulls_first,
+        case_insensitive: false,
         origin_expr: Box::new(col(name)),
     }
 }
@@ -21,36 +21,95 @@ use crate::ExpressionVisitor;
 use crate::Recursion;
 
 // Visitor the expressions to do some validator
-struct ExpressionValidator<'a, F>
-where F: Fn(&Expression) -> Result<()>
-{
+struct ExpressionValidator<'a> {
+    root: &'a Expression,
+    allow_wildcard: bool,
+    allow_sort: bool,
+    aggregate_depth: usize,
     error: Option<ErrorCode>,
-    test_fn: &'a F,
 }
 
-impl<'a, F> ExpressionValidator<'a, F>
-where F: Fn(&Expression) -> Result<()>
-{
-    /// Create a new finder with the `test_fn`
-    fn new(test_fn: &'a F) -> Self {
+impl<'a> ExpressionValidator<'a> {
+    fn new(root: &'a Expression, allow_wildcard: bool, allow_sort: bool) -> Self {
         Self {
+            root,
+            allow_wildcard,
+            allow_sort,
+            aggregate_depth: 0,
             error: None,
-            test_fn,
+        }
+    }
+
+    fn is_root(&self, expr: &Expression) -> bool {
+        std::ptr::eq(self.root, expr)
+    }
+
+    fn validate(&mut self, expr: &Expression) -> Result<()> {
+        match expr {
+            Expression::ScalarFunction { op, args, .. } => {
+                let features = Function2Factory::instance().get_features(op)?;
+                validate_function_arg(
+                    op,
+                    args.len(),
+                    features.variadic_arguments,
+                    features.num_arguments,
+                )
+                .map_err(|e| {
+                    ErrorCode::SyntaxException(format!(
+                        "{} in expression `{}`",
+                        e.message(),
+                        self.root.column_name()
+                    ))
+                })
+            }
+            Expression::AggregateFunction { op, .. } => {
+                if self.aggregate_depth > 0 {
+                    return Err(ErrorCode::SyntaxException(format!(
+                        "Aggregate function `{}` is found inside another aggregate function in expression `{}`",
+                        op,
+                        self.root.column_name()
+                    )));
+                }
+                self.aggregate_depth += 1;
+                Ok(())
+            }
+            Expression::Wildcard if !(self.allow_wildcard && self.is_root(expr)) => {
+                Err(ErrorCode::SyntaxException(format!(
+                    "Wildcard is only allowed at the top of a projection, but found in expression `{}`",
+                    self.root.column_name()
+                )))
+            }
+            Expression::Sort { .. } if !(self.allow_sort && self.is_root(expr)) => {
+                Err(ErrorCode::SyntaxException(format!(
+                    "Sort expression is only allowed in ORDER BY, but found in expression `{}`",
+                    self.root.column_name()
+                )))
+            }
+            _ => Ok(()),
         }
     }
 }
 
-impl<'a, F> ExpressionVisitor for ExpressionValidator<'a, F>
-where F: Fn(&Expression) -> Result<()>
-{
-    fn pre_visit(self, expr: &Expression) -> Result<Recursion<Self>> {
-        match (self.test_fn)(expr) {
+impl<'a> ExpressionVisitor for ExpressionValidator<'a> {
+    fn pre_visit(mut self, expr: &Expression) -> Result<Recursion<Self>> {
+        if self.error.is_some() {
+            return Ok(Recursion::Stop(self));
+        }
+
+        match self.validate(expr) {
             Ok(()) => Ok(Recursion::Continue(self)),
-            Err(e) => Ok(Recursion::Stop(ExpressionValidator {
-                error: Some(e),
-                test_fn: self.test_fn,
-            })),
+            Err(e) => {
+                self.error = Some(e);
+                Ok(Recursion::Stop(self))
+            }
+        }
+    }
+
+    fn post_visit(mut self, expr: &Expression) -> Result<Self> {
+        if let Expression::AggregateFunction { .. } = expr {
+            self.aggregate_depth = self.aggregate_depth.saturating_sub(1);
         }
+        Ok(self)
     }
 }
 
@@ -84,27 +143,28 @@ fn validate_function_arg(
     }
 }
 
-// Can works before expression,filter,having in PlanBuilder
-pub fn validate_expression(expr: &Expression) -> Result<()> {
-    let validator = ExpressionValidator::new(&|expr: &Expression| match expr {
-        Expression::ScalarFunction { op, args } => {
-            let features = Function2Factory::instance().get_features(op)?;
-            validate_function_arg(
-                op,
-                args.len(),
-                features.variadic_arguments,
-                features.num_arguments,
-            )
-        }
+fn validate(expr: &Expression, allow_wildcard: bool, allow_sort: bool) -> Result<()> {
+    let validator = ExpressionValidator::new(expr, allow_wildcard, allow_sort);
 
-        // Currently no need to check  UnaryExpression and BinaryExpression
-        // todo: AggregateFunction validation after generic AggregateFunctions
-        _ => Ok(()),
-    });
-
-    let validator = expr.accept(validator)?;
+    // Use `visit` rather than `accept`, the latter pre-visits the root twice.
+    let validator = validator.visit(expr)?;
     match validator.error {
         Some(err) => Err(err),
         None => Ok(()),
     }
 }
+
+// Can works before expression,filter,having in PlanBuilder
+pub fn validate_expression(expr: &Expression) -> Result<()> {
+    validate(expr, false, false)
+}
+
+/// Validate a projection item, a wildcard is allowed as the whole item.
+pub fn validate_projection_expression(expr: &Expression) -> Result<()> {
+    validate(expr, true, false)
+}
+
+/// Validate an order by item, the item itself must be a sort expression.
+pub fn validate_sort_expression(expr: &Expression) -> Result<()> {
+    validate(expr, false, true)
+}
@@ -56,6 +56,13 @@ pub trait ExpressionVisitor: Sized {
                                 Expression::UnaryExpression { expr, .. } => {
                                     stack.push(RecursionProcessing::Call(expr));
                                 }
+                                Expression::Between {
+                                    expr, low, high, ..
+                                } => {
+                                    stack.push(RecursionProcessing::Call(expr));
+                                    stack.push(RecursionProcessing::Call(low));
+                                    stack.push(RecursionProcessing::Call(high));
+                                }
                                 Expression::ScalarFunction { args, .. } => {
                                     for arg in args {
                                         stack.push(RecursionProcessing::Call(arg));
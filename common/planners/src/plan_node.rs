@@ -36,6 +36,7 @@ use crate::DropUserPlan;
 use crate::DropUserStagePlan;
 use crate::DropUserUDFPlan;
 use crate::EmptyPlan;
+use crate::ExpandPlan;
 use crate::ExplainPlan;
 use crate::ExpressionPlan;
 use crate::FilterPlan;
@@ -60,6 +61,7 @@ use crate::SortPlan;
 use crate::StagePlan;
 use crate::SubQueriesSetPlan;
 use crate::TruncateTablePlan;
+use crate::UnionPlan;
 use crate::UseDatabasePlan;
 
 #[allow(clippy::large_enum_variant)]
@@ -72,6 +74,7 @@ pub enum PlanNode {
     Remote(RemotePlan),
     Projection(ProjectionPlan),
     Expression(ExpressionPlan),
+    Expand(ExpandPlan),
     AggregatorPartial(AggregatorPartialPlan),
     AggregatorFinal(AggregatorFinalPlan),
     Filter(FilterPlan),
@@ -82,6 +85,7 @@ pub enum PlanNode {
     ReadSource(ReadDataSourcePlan),
     SubQueryExpression(SubQueriesSetPlan),
     Sink(SinkPlan),
+    Union(UnionPlan),
 
     // Explain.
     Explain(ExplainPlan),
@@ -152,6 +156,7 @@ impl PlanNode {
             PlanNode::Remote(v) => v.schema(),
             PlanNode::Projection(v) => v.schema(),
             PlanNode::Expression(v) => v.schema(),
+            PlanNode::Expand(v) => v.schema(),
             PlanNode::AggregatorPartial(v) => v.schema(),
             PlanNode::AggregatorFinal(v) => v.schema(),
             PlanNode::Filter(v) => v.schema(),
@@ -162,6 +167,7 @@ impl PlanNode {
             PlanNode::Sort(v) => v.schema(),
             PlanNode::SubQueryExpression(v) => v.schema(),
             PlanNode::Sink(v) => v.schema(),
+            PlanNode::Union(v) => v.schema(),
 
             // Explain.
             PlanNode::Explain(v) => v.schema(),
@@ -231,6 +237,7 @@ impl PlanNode {
             PlanNode::Remote(_) => "RemotePlan",
             PlanNode::Projection(_) => "ProjectionPlan",
             PlanNode::Expression(_) => "ExpressionPlan",
+            PlanNode::Expand(_) => "ExpandPlan",
             PlanNode::AggregatorPartial(_) => "AggregatorPartialPlan",
             PlanNode::AggregatorFinal(_) => "AggregatorFinalPlan",
             PlanNode::Filter(_) => "FilterPlan",
@@ -241,6 +248,7 @@ impl PlanNode {
             PlanNode::Sort(_) => "SortPlan",
             PlanNode::SubQueryExpression(_) => "CreateSubQueriesSets",
             PlanNode::Sink(_) => "SinkPlan",
+            PlanNode::Union(_) => "UnionPlan",
 
             // Explain.
             PlanNode::Explain(_) => "ExplainPlan",
@@ -307,6 +315,7 @@ impl PlanNode {
             PlanNode::Broadcast(v) => vec![v.input.clone()],
             PlanNode::Projection(v) => vec![v.input.clone()],
             PlanNode::Expression(v) => vec![v.input.clone()],
+            PlanNode::Expand(v) => vec![v.input.clone()],
             PlanNode::AggregatorPartial(v) => vec![v.input.clone()],
             PlanNode::AggregatorFinal(v) => vec![v.input.clone()],
             PlanNode::Filter(v) => vec![v.input.clone()],
@@ -317,6 +326,7 @@ impl PlanNode {
             PlanNode::Sort(v) => vec![v.input.clone()],
             PlanNode::SubQueryExpression(v) => v.get_inputs(),
             PlanNode::Sink(v) => vec![v.input.clone()],
+            PlanNode::Union(v) => v.inputs.clone(),
 
             _ => vec![],
         }
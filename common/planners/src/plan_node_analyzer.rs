@@ -0,0 +1,101 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::sync::Arc;
+
+use common_exception::Result;
+
+use crate::Expression;
+use crate::ExpressionVisitor;
+use crate::PlanNode;
+use crate::PlanVisitor;
+use crate::ReadDataSourcePlan;
+use crate::Recursion;
+
+/// The size of a plan, used to reject pathological queries before execution.
+#[derive(PartialEq, Eq, Clone, Debug, Default)]
+pub struct PlanStats {
+    /// Depth of the plan tree, the plans of subqueries are children of the expressions using them.
+    pub depth: usize,
+    /// Number of expression nodes in all the plan nodes.
+    pub expression_nodes: usize,
+    /// Number of partitions of all the scans.
+    pub partitions: usize,
+}
+
+/// Collects the `PlanStats` of a plan tree.
+#[derive(Default)]
+pub struct PlanAnalyzer {
+    stats: PlanStats,
+    depth: usize,
+}
+
+impl PlanAnalyzer {
+    pub fn analyze(plan: &PlanNode) -> Result<PlanStats> {
+        let mut analyzer = PlanAnalyzer::default();
+        analyzer.visit_plan_node(plan)?;
+        Ok(analyzer.stats)
+    }
+}
+
+impl PlanVisitor for PlanAnalyzer {
+    fn visit_plan_node(&mut self, node: &PlanNode) -> Result<()> {
+        self.depth += 1;
+        self.stats.depth = self.stats.depth.max(self.depth);
+        let res = self.walk_plan_node(node);
+        self.depth -= 1;
+        res
+    }
+
+    fn visit_expr(&mut self, expr: &Expression) -> Result<()> {
+        let counter = ExpressionCounter::default().visit(expr)?;
+        self.stats.expression_nodes += counter.nodes;
+
+        for subquery_plan in counter.subquery_plans {
+            self.visit_subquery_plan(subquery_plan.as_ref())?;
+        }
+        Ok(())
+    }
+
+    fn visit_read_data_source(&mut self, plan: &ReadDataSourcePlan) -> Result<()> {
+        self.stats.partitions += plan.parts.len();
+        Ok(())
+    }
+}
+
+#[derive(Default)]
+struct ExpressionCounter {
+    nodes: usize,
+    // Subqueries may be nested anywhere in the expression.
+    subquery_plans: Vec<Arc<PlanNode>>,
+}
+
+impl ExpressionVisitor for ExpressionCounter {
+    fn pre_visit(mut self, expr: &Expression) -> Result<Recursion<Self>> {
+        self.nodes += 1;
+        match expr {
+            Expression::Subquery { query_plan, .. }
+            | Expression::ScalarSubquery { query_plan, .. } => {
+                self.subquery_plans.push(query_plan.clone());
+            }
+            _ => {}
+        }
+        Ok(Recursion::Continue(self))
+    }
+}
+
+impl PlanNode {
+    pub fn analyze(&self) -> Result<PlanStats> {
+        PlanAnalyzer::analyze(self)
+    }
+}
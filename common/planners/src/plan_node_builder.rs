@@ -16,6 +16,8 @@ use std::sync::Arc;
 
 use common_datablocks::DataBlock;
 use common_datavalues2::prelude::*;
+use common_datavalues2::type_coercion::merge_types;
+use common_exception::ErrorCode;
 use common_exception::Result;
 
 use crate::col;
@@ -24,10 +26,12 @@ use crate::validate_expression;
 use crate::AggregatorFinalPlan;
 use crate::AggregatorPartialPlan;
 use crate::EmptyPlan;
+use crate::ExpandPlan;
 use crate::ExplainPlan;
 use crate::ExplainType;
 use crate::Expression;
 use crate::ExpressionPlan;
+use crate::Expressions;
 use crate::FilterPlan;
 use crate::HavingPlan;
 use crate::LimitByPlan;
@@ -37,6 +41,7 @@ use crate::ProjectionPlan;
 use crate::RewriteHelper;
 use crate::SelectPlan;
 use crate::SortPlan;
+use crate::UnionPlan;
 
 pub enum AggregateMode {
     Partial,
@@ -101,6 +106,56 @@ impl PlanBuilder {
         })))
     }
 
+    /// Apply an expand, each input row is repeated once per set with the expressions of the set.
+    /// The expressions at the same position are named after the first set, and every expression
+    /// whose type differs from the merged type of the position is casted.
+    pub fn expand(&self, sets: &[Expressions]) -> Result<Self> {
+        if sets.is_empty() {
+            return Err(ErrorCode::BadArguments(
+                "Expand requires at least one set of expressions",
+            ));
+        }
+
+        let input_schema = self.plan.schema();
+        let mut fields = RewriteHelper::exprs_to_fields(&sets[0], &input_schema)?;
+        for set in &sets[1..] {
+            let set_fields = RewriteHelper::exprs_to_fields(set, &input_schema)?;
+            if set_fields.len() != fields.len() {
+                return Err(ErrorCode::BadArguments(format!(
+                    "Expand requires the same number of expressions in each set, but got {} and {}",
+                    fields.len(),
+                    set_fields.len()
+                )));
+            }
+
+            for (field, set_field) in fields.iter_mut().zip(set_fields.iter()) {
+                let data_type =
+                    merge_types(field.data_type(), set_field.data_type()).map_err(|_| {
+                        ErrorCode::IllegalDataType(format!(
+                            "Expand expression types {:?} and {:?} are not compatible",
+                            field.data_type(),
+                            set_field.data_type()
+                        ))
+                    })?;
+                *field = DataField::new(field.name(), data_type);
+            }
+        }
+
+        let sets = sets
+            .iter()
+            .map(|set| Self::coerce_expand_set(set, &fields, &input_schema))
+            .collect::<Result<Vec<_>>>()?;
+
+        let mut merged = input_schema.fields().clone();
+        merged.extend(fields);
+
+        Ok(Self::from(&PlanNode::Expand(ExpandPlan {
+            sets,
+            schema: DataSchemaRefExt::create(merged),
+            input: Arc::new(self.plan.clone()),
+        })))
+    }
+
     /// Apply a projection.
     pub fn project(&self, exprs: &[Expression]) -> Result<Self> {
         let input_schema = self.plan.schema();
@@ -215,10 +270,16 @@ impl PlanBuilder {
     }
 
     pub fn sort(&self, exprs: &[Expression]) -> Result<Self> {
+        self.sort_limit(exprs, None)
+    }
+
+    /// Apply a sort which keeps only the first `limit` rows
+    pub fn sort_limit(&self, exprs: &[Expression], limit: Option<usize>) -> Result<Self> {
         Ok(Self::from(&PlanNode::Sort(SortPlan {
             order_by: exprs.to_vec(),
             schema: self.plan.schema(),
             input: self.wrap_subquery_plan(exprs)?,
+            limit,
         })))
     }
 
@@ -248,6 +309,57 @@ impl PlanBuilder {
         })))
     }
 
+    /// Apply a UNION ALL of the current plan and `others`.
+    /// The output column names come from the current plan, and every input whose
+    /// columns differ from the merged schema is renamed and casted by a projection.
+    pub fn union_all(&self, others: &[PlanNode]) -> Result<Self> {
+        let mut inputs = vec![self.plan.clone()];
+        inputs.extend_from_slice(others);
+
+        let first_schema = self.plan.schema();
+        let mut types = first_schema
+            .fields()
+            .iter()
+            .map(|f| f.data_type().clone())
+            .collect::<Vec<_>>();
+
+        for input in others {
+            let schema = input.schema();
+            if schema.fields().len() != types.len() {
+                return Err(ErrorCode::BadArguments(format!(
+                    "UNION ALL requires the same number of columns in each query, but got {} and {}",
+                    types.len(),
+                    schema.fields().len()
+                )));
+            }
+
+            for (typ, field) in types.iter_mut().zip(schema.fields().iter()) {
+                *typ = merge_types(typ, field.data_type()).map_err(|_| {
+                    ErrorCode::IllegalDataType(format!(
+                        "UNION ALL column types {:?} and {:?} are not compatible",
+                        typ,
+                        field.data_type()
+                    ))
+                })?;
+            }
+        }
+
+        let fields = first_schema
+            .fields()
+            .iter()
+            .zip(types.iter())
+            .map(|(f, typ)| DataField::new(f.name(), typ.clone()))
+            .collect::<Vec<_>>();
+        let schema = DataSchemaRefExt::create(fields);
+
+        let inputs = inputs
+            .iter()
+            .map(|input| Self::coerce_union_input(input, &schema).map(Arc::new))
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(Self::from(&PlanNode::Union(UnionPlan { schema, inputs })))
+    }
+
     pub fn select(&self) -> Result<Self> {
         Ok(Self::from(&PlanNode::Select(SelectPlan {
             input: Arc::new(self.plan.clone()),
@@ -266,6 +378,63 @@ impl PlanBuilder {
         Ok(self.plan.clone())
     }
 
+    fn coerce_union_input(input: &PlanNode, schema: &DataSchemaRef) -> Result<PlanNode> {
+        let input_schema = input.schema();
+        if input_schema.fields() == schema.fields() {
+            return Ok(input.clone());
+        }
+
+        let exprs = input_schema
+            .fields()
+            .iter()
+            .zip(schema.fields().iter())
+            .map(|(from, to)| {
+                let column = col(from.name());
+                let expr = match from.data_type() == to.data_type() {
+                    true => column,
+                    false => Expression::Cast {
+                        expr: Box::new(column),
+                        data_type: to.data_type().clone(),
+                        is_nullable: false,
+                    },
+                };
+                Expression::Alias(to.name().clone(), Box::new(expr))
+            })
+            .collect::<Vec<_>>();
+
+        PlanBuilder::from(input).project(&exprs)?.build()
+    }
+
+    fn coerce_expand_set(
+        set: &[Expression],
+        fields: &[DataField],
+        input_schema: &DataSchemaRef,
+    ) -> Result<Expressions> {
+        set.iter()
+            .zip(fields.iter())
+            .map(|(expr, field)| {
+                let data_type = expr.to_data_type(input_schema)?;
+                if &data_type == field.data_type() && &expr.column_name() == field.name() {
+                    return Ok(expr.clone());
+                }
+
+                let expr = match expr {
+                    Expression::Alias(_, expr) => expr.as_ref().clone(),
+                    expr => expr.clone(),
+                };
+                let expr = match &data_type == field.data_type() {
+                    true => expr,
+                    false => Expression::Cast {
+                        expr: Box::new(expr),
+                        data_type: field.data_type().clone(),
+                        is_nullable: false,
+                    },
+                };
+                Ok(Expression::Alias(field.name().clone(), Box::new(expr)))
+            })
+            .collect()
+    }
+
     fn wrap_subquery_plan(&self, exprs: &[Expression]) -> Result<Arc<PlanNode>> {
         let input = &self.plan;
         let sub_queries = RewriteHelper::collect_exprs_sub_queries(exprs)?;
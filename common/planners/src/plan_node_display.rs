@@ -20,6 +20,7 @@ use common_datavalues2::remove_nullable;
 use common_datavalues2::DataField;
 use common_datavalues2::DataSchema;
 
+use crate::plan_node_display_graphviz::PlanGraphvizBuilder;
 use crate::plan_node_display_indent::PlanNodeIndentFormatDisplay;
 use crate::PlanNode;
 
@@ -28,6 +29,17 @@ impl PlanNode {
         PlanNodeIndentFormatDisplay::create(0, self, false)
     }
 
+    pub fn display_indent_format_with_types(&self) -> impl fmt::Display + '_ {
+        PlanNodeIndentFormatDisplay::create(0, self, false).with_types(true)
+    }
+
+    pub fn display_indent_format_with_annotation<'a>(
+        &'a self,
+        annotation: &'a dyn Fn(&PlanNode) -> Option<String>,
+    ) -> impl fmt::Display + 'a {
+        PlanNodeIndentFormatDisplay::create(0, self, false).with_annotation(Some(annotation))
+    }
+
     pub fn display_graphviz(&self) -> impl fmt::Display + '_ {
         struct Wrapper<'a>(&'a PlanNode);
         impl<'a> fmt::Display for Wrapper<'a> {
@@ -36,9 +48,8 @@ impl PlanNode {
                     f,
                     "// Begin Databend GraphViz Plan (see https://graphviz.org)"
                 )?;
-                writeln!(f, "digraph {{")?;
-                // TODO()
-                writeln!(f, "}}")?;
+                let dot = PlanGraphvizBuilder::build(self.0).map_err(|_| fmt::Error)?;
+                write!(f, "{}", dot)?;
                 writeln!(f, "// End Databend GraphViz Plan")?;
                 Ok(())
             }
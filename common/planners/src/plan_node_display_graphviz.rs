@@ -0,0 +1,136 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::Result;
+
+use crate::Expression;
+use crate::PlanNode;
+use crate::PlanVisitor;
+
+/// Renders a plan tree as a Graphviz DOT digraph.
+/// Each plan node is a box labeled with its name and key expressions,
+/// an edge goes from each input to the plan node consuming it.
+#[derive(Default)]
+pub struct PlanGraphvizBuilder {
+    dot: String,
+    next_id: usize,
+    // The DOT ids of the plan nodes on the path from the root.
+    consumers: Vec<usize>,
+}
+
+impl PlanGraphvizBuilder {
+    pub fn build(plan: &PlanNode) -> Result<String> {
+        let mut builder = PlanGraphvizBuilder::default();
+        builder.dot.push_str("digraph {\n");
+        builder.visit_plan_node(plan)?;
+        builder.dot.push_str("}\n");
+        Ok(builder.dot)
+    }
+
+    fn node_label(node: &PlanNode) -> String {
+        let details = match node {
+            PlanNode::Projection(plan) => Some(Self::format_exprs(&plan.expr)),
+            PlanNode::Expression(plan) => Some(format!(
+                "{} ({})",
+                Self::format_exprs(&plan.exprs),
+                plan.desc
+            )),
+            PlanNode::Expand(plan) => Some(
+                plan.sets
+                    .iter()
+                    .map(|set| format!("[{}]", Self::format_exprs(set)))
+                    .collect::<Vec<_>>()
+                    .join(", "),
+            ),
+            PlanNode::AggregatorPartial(plan) => Some(format!(
+                "groupBy=[{}], aggr=[{}]",
+                Self::format_exprs(&plan.group_expr),
+                Self::format_exprs(&plan.aggr_expr)
+            )),
+            PlanNode::AggregatorFinal(plan) => Some(format!(
+                "groupBy=[{}], aggr=[{}]",
+                Self::format_exprs(&plan.group_expr),
+                Self::format_exprs(&plan.aggr_expr)
+            )),
+            PlanNode::Filter(plan) => Some(format!("{:?}", plan.predicate)),
+            PlanNode::Having(plan) => Some(format!("{:?}", plan.predicate)),
+            PlanNode::Sort(plan) => Some(match plan.limit {
+                None => Self::format_exprs(&plan.order_by),
+                Some(limit) => format!("{} (limit: {})", Self::format_exprs(&plan.order_by), limit),
+            }),
+            PlanNode::Limit(plan) => Some(match plan.n {
+                None => format!("all, offset: {}", plan.offset),
+                Some(n) => format!("{}, offset: {}", n, plan.offset),
+            }),
+            PlanNode::LimitBy(plan) => Some(format!(
+                "{} by {}",
+                plan.limit,
+                Self::format_exprs(&plan.limit_by)
+            )),
+            PlanNode::Stage(plan) => Some(format!("{:?}", plan.scatters_expr)),
+            PlanNode::ReadSource(plan) => Some(format!(
+                "{}\nread_rows: {}, read_bytes: {}",
+                plan.table_info.desc, plan.statistics.read_rows, plan.statistics.read_bytes
+            )),
+            _ => None,
+        };
+
+        match details {
+            None => node.name().to_string(),
+            Some(details) => format!("{}\n{}", node.name(), details),
+        }
+    }
+
+    fn format_exprs(exprs: &[Expression]) -> String {
+        exprs
+            .iter()
+            .map(|expr| format!("{:?}", expr))
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+
+    // A quoted DOT string, the line breaks become the `\n` escapes of the DOT label.
+    fn quote(label: &str) -> String {
+        let escaped = label
+            .replace('\\', "\\\\")
+            .replace('"', "\\\"")
+            .replace('\n', "\\n");
+        format!("\"{}\"", escaped)
+    }
+}
+
+impl PlanVisitor for PlanGraphvizBuilder {
+    fn visit_plan_node(&mut self, node: &PlanNode) -> Result<()> {
+        if matches!(node, PlanNode::Empty(_)) {
+            return Ok(());
+        }
+
+        let id = self.next_id;
+        self.next_id += 1;
+        self.dot.push_str(&format!(
+            "    n{} [shape=box, label={}];\n",
+            id,
+            Self::quote(&Self::node_label(node))
+        ));
+        if let Some(consumer) = self.consumers.last() {
+            self.dot
+                .push_str(&format!("    n{} -> n{};\n", id, consumer));
+        }
+
+        self.consumers.push(id);
+        let res = self.walk_plan_node(node);
+        self.consumers.pop();
+        res
+    }
+}
@@ -12,9 +12,13 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
 use std::fmt;
 use std::fmt::Formatter;
 
+use common_datavalues2::remove_nullable;
+use common_datavalues2::DataSchemaRef;
+
 use crate::AggregatorFinalPlan;
 use crate::AggregatorPartialPlan;
 use crate::BroadcastPlan;
@@ -22,8 +26,12 @@ use crate::CreateDatabasePlan;
 use crate::CreateTablePlan;
 use crate::DropDatabasePlan;
 use crate::DropTablePlan;
+use crate::ExpandPlan;
 use crate::Expression;
 use crate::ExpressionPlan;
+use crate::InsertInputSource;
+use crate::InsertPlan;
+use crate::LimitByPlan;
 use crate::LimitPlan;
 use crate::PlanNode;
 use crate::ProjectionPlan;
@@ -31,11 +39,14 @@ use crate::ReadDataSourcePlan;
 use crate::SortPlan;
 use crate::StagePlan;
 use crate::SubQueriesSetPlan;
+use crate::TruncateTablePlan;
 
 pub struct PlanNodeIndentFormatDisplay<'a> {
     indent: usize,
     node: &'a PlanNode,
     printed_indent: bool,
+    with_types: bool,
+    annotation: Option<&'a dyn Fn(&PlanNode) -> Option<String>>,
 }
 
 impl<'a> PlanNodeIndentFormatDisplay<'a> {
@@ -44,8 +55,26 @@ impl<'a> PlanNodeIndentFormatDisplay<'a> {
             indent,
             node,
             printed_indent: printed,
+            with_types: false,
+            annotation: None,
         }
     }
+
+    /// Annotate the expressions of each node with the type and nullability
+    /// inferred against the node's input schema.
+    pub fn with_types(mut self, with_types: bool) -> Self {
+        self.with_types = with_types;
+        self
+    }
+
+    /// Append the text returned by `annotation` to the line of each node.
+    pub fn with_annotation(
+        mut self,
+        annotation: Option<&'a dyn Fn(&PlanNode) -> Option<String>>,
+    ) -> Self {
+        self.annotation = annotation;
+        self
+    }
 }
 
 impl<'a> fmt::Display for PlanNodeIndentFormatDisplay<'a> {
@@ -54,44 +83,136 @@ impl<'a> fmt::Display for PlanNodeIndentFormatDisplay<'a> {
             write!(f, "{}", str::repeat("  ", self.indent))?;
         }
 
-        match self.node {
-            PlanNode::Stage(plan) => Self::format_stage(f, plan),
-            PlanNode::Broadcast(plan) => Self::format_broadcast(f, plan),
-            PlanNode::Projection(plan) => Self::format_projection(f, plan),
-            PlanNode::Expression(plan) => Self::format_expression(f, plan),
-            PlanNode::AggregatorPartial(plan) => Self::format_aggregator_partial(f, plan),
-            PlanNode::AggregatorFinal(plan) => Self::format_aggregator_final(f, plan),
-            PlanNode::Filter(plan) => write!(f, "Filter: {:?}", plan.predicate),
-            PlanNode::Having(plan) => write!(f, "Having: {:?}", plan.predicate),
-            PlanNode::Sort(plan) => Self::format_sort(f, plan),
-            PlanNode::Limit(plan) => Self::format_limit(f, plan),
-            PlanNode::SubQueryExpression(plan) => Self::format_subquery_expr(f, plan),
-            PlanNode::ReadSource(plan) => Self::format_read_source(f, plan),
-            PlanNode::CreateDatabase(plan) => Self::format_create_database(f, plan),
-            PlanNode::DropDatabase(plan) => Self::format_drop_database(f, plan),
-            PlanNode::CreateTable(plan) => Self::format_create_table(f, plan),
-            PlanNode::DropTable(plan) => Self::format_drop_table(f, plan),
-            _ => {
-                let mut printed = true;
-
-                for input in self.node.inputs() {
-                    if matches!(input.as_ref(), PlanNode::Empty(_)) {
-                        continue;
+        if !(self.with_types && self.format_typed_node(f)?) {
+            match self.node {
+                PlanNode::Stage(plan) => Self::format_stage(f, plan),
+                PlanNode::Broadcast(plan) => Self::format_broadcast(f, plan),
+                PlanNode::Projection(plan) => Self::format_projection(f, plan),
+                PlanNode::Expression(plan) => Self::format_expression(f, plan),
+                PlanNode::Expand(plan) => Self::format_expand(f, plan),
+                PlanNode::AggregatorPartial(plan) => Self::format_aggregator_partial(f, plan),
+                PlanNode::AggregatorFinal(plan) => Self::format_aggregator_final(f, plan),
+                PlanNode::Filter(plan) => write!(f, "Filter: {:?}", plan.predicate),
+                PlanNode::Having(plan) => write!(f, "Having: {:?}", plan.predicate),
+                PlanNode::Sort(plan) => Self::format_sort(f, plan),
+                PlanNode::Limit(plan) => Self::format_limit(f, plan),
+                PlanNode::LimitBy(plan) => Self::format_limit_by(f, plan),
+                PlanNode::Union(_) => write!(f, "UnionAll"),
+                PlanNode::SubQueryExpression(plan) => Self::format_subquery_expr(f, plan),
+                PlanNode::ReadSource(plan) => Self::format_read_source(f, plan),
+                PlanNode::CreateDatabase(plan) => Self::format_create_database(f, plan),
+                PlanNode::DropDatabase(plan) => Self::format_drop_database(f, plan),
+                PlanNode::CreateTable(plan) => Self::format_create_table(f, plan),
+                PlanNode::DropTable(plan) => Self::format_drop_table(f, plan),
+                PlanNode::TruncateTable(plan) => Self::format_truncate_table(f, plan),
+                PlanNode::Insert(plan) => self.format_insert(f, plan),
+                _ => {
+                    let mut printed = true;
+
+                    for input in self.node.inputs() {
+                        if matches!(input.as_ref(), PlanNode::Empty(_)) {
+                            continue;
+                        }
+
+                        if !printed {
+                            writeln!(f)?;
+                        }
+
+                        PlanNodeIndentFormatDisplay::create(self.indent, input.as_ref(), printed)
+                            .with_types(self.with_types)
+                            .with_annotation(self.annotation)
+                            .fmt(f)?;
+                        printed = true;
                     }
 
-                    if !printed {
-                        writeln!(f)?;
-                    }
+                    return fmt::Result::Ok(());
+                }
+            }?;
+        }
+
+        self.format_annotation(f)?;
+        self.format_inputs(f)
+    }
+}
 
-                    PlanNodeIndentFormatDisplay::create(self.indent, input.as_ref(), printed)
-                        .fmt(f)?;
-                    printed = true;
+impl<'a> PlanNodeIndentFormatDisplay<'a> {
+    fn format_typed_node(&self, f: &mut Formatter) -> Result<bool, fmt::Error> {
+        match self.node {
+            PlanNode::Projection(plan) => {
+                write!(f, "Projection: ")?;
+                Self::format_typed_exprs(f, &plan.expr, &plan.input.schema())?;
+                Ok(true)
+            }
+            PlanNode::Expression(plan) => {
+                write!(f, "Expression: ")?;
+                Self::format_typed_exprs(f, &plan.exprs, &plan.input.schema())?;
+                write!(f, " ({})", plan.desc)?;
+                Ok(true)
+            }
+            PlanNode::Expand(plan) => {
+                let schema = plan.input.schema();
+                write!(f, "Expand: ")?;
+                for (i, set) in plan.sets.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "[")?;
+                    Self::format_typed_exprs(f, set, &schema)?;
+                    write!(f, "]")?;
                 }
+                Ok(true)
+            }
+            PlanNode::AggregatorPartial(plan) => {
+                let schema = plan.input.schema();
+                write!(f, "AggregatorPartial: groupBy=[")?;
+                Self::format_typed_exprs(f, &plan.group_expr, &schema)?;
+                write!(f, "], aggr=[")?;
+                Self::format_typed_exprs(f, &plan.aggr_expr, &schema)?;
+                write!(f, "]")?;
+                Ok(true)
+            }
+            PlanNode::AggregatorFinal(plan) => {
+                let schema = &plan.schema_before_group_by;
+                write!(f, "AggregatorFinal: groupBy=[")?;
+                Self::format_typed_exprs(f, &plan.group_expr, schema)?;
+                write!(f, "], aggr=[")?;
+                Self::format_typed_exprs(f, &plan.aggr_expr, schema)?;
+                write!(f, "]")?;
+                Ok(true)
+            }
+            PlanNode::Filter(plan) => {
+                write!(f, "Filter: ")?;
+                Self::format_typed_expr(f, &plan.predicate, &plan.input.schema())?;
+                Ok(true)
+            }
+            PlanNode::Having(plan) => {
+                write!(f, "Having: ")?;
+                Self::format_typed_expr(f, &plan.predicate, &plan.input.schema())?;
+                Ok(true)
+            }
+            PlanNode::Sort(plan) => {
+                write!(f, "Sort: ")?;
+                Self::format_typed_exprs(f, &plan.order_by, &plan.input.schema())?;
+                if let Some(limit) = plan.limit {
+                    write!(f, " (limit: {})", limit)?;
+                }
+                Ok(true)
+            }
+            _ => Ok(false),
+        }
+    }
 
-                return fmt::Result::Ok(());
+    fn format_annotation(&self, f: &mut Formatter) -> fmt::Result {
+        if let Some(annotation) = self.annotation {
+            if let Some(text) = annotation(self.node) {
+                write!(f, " [{}]", text)?;
             }
-        }?;
+        }
 
+        fmt::Result::Ok(())
+    }
+
+    fn format_inputs(&self, f: &mut Formatter) -> fmt::Result {
         let new_indent = self.indent + 1;
         for input in self.node.inputs() {
             if matches!(input.as_ref(), PlanNode::Empty(_)) {
@@ -99,14 +220,51 @@ impl<'a> fmt::Display for PlanNodeIndentFormatDisplay<'a> {
             }
 
             writeln!(f)?;
-            PlanNodeIndentFormatDisplay::create(new_indent, &input, false).fmt(f)?;
+            PlanNodeIndentFormatDisplay::create(new_indent, &input, false)
+                .with_types(self.with_types)
+                .with_annotation(self.annotation)
+                .fmt(f)?;
         }
 
         fmt::Result::Ok(())
     }
-}
 
-impl<'a> PlanNodeIndentFormatDisplay<'a> {
+    fn format_typed_exprs(
+        f: &mut Formatter,
+        exprs: &[Expression],
+        schema: &DataSchemaRef,
+    ) -> fmt::Result {
+        for (i, expr) in exprs.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            Self::format_typed_expr(f, expr, schema)?;
+        }
+
+        fmt::Result::Ok(())
+    }
+
+    // An expression which fails to infer prints the error in place of the type,
+    // so the rest of the plan is still displayed.
+    fn format_typed_expr(
+        f: &mut Formatter,
+        expr: &Expression,
+        schema: &DataSchemaRef,
+    ) -> fmt::Result {
+        match expr.to_data_field(schema) {
+            Ok(field) => {
+                let nullable_str = if field.is_nullable() {
+                    "NULL"
+                } else {
+                    "NOT NULL"
+                };
+                let not_null_type = remove_nullable(field.data_type());
+                write!(f, "{:?}:{:?} {}", expr, not_null_type, nullable_str)
+            }
+            Err(cause) => write!(f, "{:?}:<error: {}>", expr, cause.message()),
+        }
+    }
+
     fn format_stage(f: &mut Formatter, plan: &StagePlan) -> fmt::Result {
         write!(f, "RedistributeStage[expr: {:?}]", plan.scatters_expr)
     }
@@ -149,6 +307,18 @@ impl<'a> PlanNodeIndentFormatDisplay<'a> {
         write!(f, " ({})", plan.desc)
     }
 
+    fn format_expand(f: &mut Formatter, plan: &ExpandPlan) -> fmt::Result {
+        write!(f, "Expand: ")?;
+        for i in 0..plan.sets.len() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{:?}", plan.sets[i])?;
+        }
+
+        fmt::Result::Ok(())
+    }
+
     fn format_aggregator_partial(f: &mut Formatter, plan: &AggregatorPartialPlan) -> fmt::Result {
         write!(
             f,
@@ -180,6 +350,10 @@ impl<'a> PlanNodeIndentFormatDisplay<'a> {
             )?;
         }
 
+        if let Some(limit) = plan.limit {
+            write!(f, " (limit: {})", limit)?;
+        }
+
         fmt::Result::Ok(())
     }
 
@@ -191,6 +365,10 @@ impl<'a> PlanNodeIndentFormatDisplay<'a> {
         }
     }
 
+    fn format_limit_by(f: &mut Formatter, plan: &LimitByPlan) -> fmt::Result {
+        write!(f, "LimitBy: {} by {:?}", plan.limit, plan.limit_by)
+    }
+
     fn format_subquery_expr(f: &mut Formatter, plan: &SubQueriesSetPlan) -> fmt::Result {
         let mut names = Vec::with_capacity(plan.expressions.len());
         for expression in &plan.expressions {
@@ -215,7 +393,7 @@ impl<'a> PlanNodeIndentFormatDisplay<'a> {
         )?;
 
         if let Some(p) = &plan.push_downs {
-            if p.limit.is_some() || p.projection.is_some() {
+            if p.limit.is_some() || p.projection.is_some() || p.sample.is_some() {
                 write!(f, ", push_downs: [")?;
                 let mut comma = false;
                 if p.projection.is_some() {
@@ -238,6 +416,14 @@ impl<'a> PlanNodeIndentFormatDisplay<'a> {
 
                     write!(f, "limit: {:?}", p.limit.unwrap())?;
                     write!(f, ", order_by: {:?}", p.order_by)?;
+                    comma = true;
+                }
+
+                if let Some(sample) = &p.sample {
+                    if comma {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "sample: {}", sample)?;
                 }
 
                 write!(f, "]")?;
@@ -264,6 +450,42 @@ impl<'a> PlanNodeIndentFormatDisplay<'a> {
         write!(f, " if_exists:{:}", plan.if_exists)
     }
 
+    // Only the metadata of the insert is shown, the source of a streaming insert is never read.
+    fn format_insert(&self, f: &mut Formatter, plan: &InsertPlan) -> fmt::Result {
+        let fields = plan
+            .schema()
+            .fields()
+            .iter()
+            .cloned()
+            .enumerate()
+            .collect::<BTreeMap<_, _>>();
+        write!(
+            f,
+            "Insert into {}.{}, schema: {}, overwrite: {}",
+            plan.database_name,
+            plan.table_name,
+            PlanNode::display_scan_fields(&fields),
+            plan.overwrite
+        )?;
+
+        match &plan.source {
+            InsertInputSource::SelectPlan(select) => {
+                write!(f, ", source: select")?;
+                writeln!(f)?;
+                PlanNodeIndentFormatDisplay::create(self.indent + 1, select, false)
+                    .with_types(self.with_types)
+                    .with_annotation(self.annotation)
+                    .fmt(f)
+            }
+            InsertInputSource::Expressions(rows) => {
+                write!(f, ", source: values, rows: {}", rows.len())
+            }
+            InsertInputSource::StreamingWithFormat(format) => {
+                write!(f, ", source: streaming, format: {}", format)
+            }
+        }
+    }
+
     fn format_create_table(f: &mut Formatter, plan: &CreateTablePlan) -> fmt::Result {
         write!(f, "Create table {:}.{:}", plan.db, plan.table)?;
         write!(f, " {:},", plan.schema())?;
@@ -278,4 +500,9 @@ impl<'a> PlanNodeIndentFormatDisplay<'a> {
         write!(f, "Drop table {:}.{:},", plan.db, plan.table)?;
         write!(f, " if_exists:{:}", plan.if_exists)
     }
+
+    fn format_truncate_table(f: &mut Formatter, plan: &TruncateTablePlan) -> fmt::Result {
+        write!(f, "Truncate table {:}.{:},", plan.db, plan.table)?;
+        write!(f, " purge:{:}", plan.purge)
+    }
 }
@@ -0,0 +1,77 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::convert::TryInto;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_exception::ToErrorCode;
+
+use crate::PlanNode;
+
+/// The version of the `PlanNode::to_bytes` format.
+///
+/// Bump it on every change of the plan structures that older nodes can't decode, so a node
+/// receiving a plan from a newer one fails with a clear error.
+pub const PLAN_FORMAT_VERSION: u8 = 1;
+
+// version: u8 | payload length: u64 (little endian) | payload
+const HEADER_SIZE: usize = 1 + 8;
+
+impl PlanNode {
+    /// Encodes the plan for shipping to other nodes.
+    pub fn to_bytes(&self) -> Result<Vec<u8>> {
+        let payload = serde_json::to_vec(self).map_err_to_code(ErrorCode::LogicalError, || {
+            format!("Cannot serialize plan {}", self.name())
+        })?;
+
+        let mut bytes = Vec::with_capacity(HEADER_SIZE + payload.len());
+        bytes.push(PLAN_FORMAT_VERSION);
+        bytes.extend_from_slice(&(payload.len() as u64).to_le_bytes());
+        bytes.extend_from_slice(&payload);
+        Ok(bytes)
+    }
+
+    /// Decodes a plan encoded by `to_bytes`.
+    pub fn from_bytes(bytes: &[u8]) -> Result<PlanNode> {
+        if bytes.len() < HEADER_SIZE {
+            return Err(ErrorCode::BadBytes(format!(
+                "Plan bytes are too short, expect at least {} bytes, but got {}",
+                HEADER_SIZE,
+                bytes.len()
+            )));
+        }
+
+        let version = bytes[0];
+        if version != PLAN_FORMAT_VERSION {
+            return Err(ErrorCode::BadBytes(format!(
+                "Unsupported plan format version {}, this node supports version {}",
+                version, PLAN_FORMAT_VERSION
+            )));
+        }
+
+        // The header size is checked above.
+        let length = u64::from_le_bytes(bytes[1..HEADER_SIZE].try_into().unwrap());
+        let payload = &bytes[HEADER_SIZE..];
+        if payload.len() as u64 != length {
+            return Err(ErrorCode::BadBytes(format!(
+                "Plan payload length mismatch, expect {} bytes, but got {}",
+                length,
+                payload.len()
+            )));
+        }
+
+        serde_json::from_slice(payload)
+            .map_err_to_code(ErrorCode::BadBytes, || "Cannot deserialize plan")
+    }
+}
@@ -12,8 +12,30 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::fmt;
+
 use crate::Expression;
 
+/// Keep each row of the table with the given probability:
+/// `TABLESAMPLE [BERNOULLI] (percent [PERCENT]) [REPEATABLE (seed)]`.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
+pub struct TableSample {
+    /// Percentage of the rows to keep, in [0, 100].
+    pub percent: f64,
+    /// Seed to make the sample reproducible, a random sample if None.
+    pub seed: Option<u64>,
+}
+
+impl fmt::Display for TableSample {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} PERCENT", self.percent)?;
+        if let Some(seed) = self.seed {
+            write!(f, " REPEATABLE ({})", seed)?;
+        }
+        Ok(())
+    }
+}
+
 /// Extras is a wrapper for push down items.
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
 pub struct Extras {
@@ -25,6 +47,8 @@ pub struct Extras {
     pub limit: Option<usize>,
     /// Optional order_by expression plan
     pub order_by: Vec<Expression>,
+    /// Optional sample of the rows read
+    pub sample: Option<TableSample>,
 }
 
 impl Extras {
@@ -34,6 +58,7 @@ impl Extras {
             filters: vec![],
             limit: None,
             order_by: vec![],
+            sample: None,
         }
     }
 }
@@ -42,6 +42,7 @@ use crate::DropUserPlan;
 use crate::DropUserStagePlan;
 use crate::DropUserUDFPlan;
 use crate::EmptyPlan;
+use crate::ExpandPlan;
 use crate::ExplainPlan;
 use crate::Expression;
 use crate::ExpressionPlan;
@@ -70,6 +71,7 @@ use crate::SinkPlan;
 use crate::SortPlan;
 use crate::StagePlan;
 use crate::TruncateTablePlan;
+use crate::UnionPlan;
 use crate::UseDatabasePlan;
 
 /// `PlanRewriter` is a visitor that can help to rewrite `PlanNode`
@@ -105,12 +107,14 @@ pub trait PlanRewriter: Sized {
             PlanNode::Remote(plan) => self.rewrite_remote(plan),
             PlanNode::Having(plan) => self.rewrite_having(plan),
             PlanNode::Expression(plan) => self.rewrite_expression(plan),
+            PlanNode::Expand(plan) => self.rewrite_expand(plan),
             PlanNode::Sort(plan) => self.rewrite_sort(plan),
             PlanNode::Limit(plan) => self.rewrite_limit(plan),
             PlanNode::LimitBy(plan) => self.rewrite_limit_by(plan),
             PlanNode::ReadSource(plan) => self.rewrite_read_data_source(plan),
             PlanNode::SubQueryExpression(plan) => self.rewrite_sub_queries_sets(plan),
             PlanNode::Sink(plan) => self.rewrite_sink(plan),
+            PlanNode::Union(plan) => self.rewrite_union(plan),
 
             // Query.
             PlanNode::Select(plan) => self.rewrite_select(plan),
@@ -268,6 +272,16 @@ pub trait PlanRewriter: Sized {
             .build()
     }
 
+    fn rewrite_expand(&mut self, plan: &ExpandPlan) -> Result<PlanNode> {
+        let new_input = self.rewrite_plan_node(plan.input.as_ref())?;
+        let new_sets = plan
+            .sets
+            .iter()
+            .map(|set| self.rewrite_exprs(&new_input.schema(), set))
+            .collect::<Result<Vec<_>>>()?;
+        PlanBuilder::from(&new_input).expand(&new_sets)?.build()
+    }
+
     fn rewrite_sub_queries_sets(&mut self, plan: &SubQueriesSetPlan) -> Result<PlanNode> {
         // We don't touch expressions, it should be rebuilt by a new expressions
         self.rewrite_plan_node(plan.input.as_ref())
@@ -288,7 +302,9 @@ pub trait PlanRewriter: Sized {
     fn rewrite_sort(&mut self, plan: &SortPlan) -> Result<PlanNode> {
         let new_input = self.rewrite_plan_node(plan.input.as_ref())?;
         let new_order_by = self.rewrite_exprs(&new_input.schema(), &plan.order_by)?;
-        PlanBuilder::from(&new_input).sort(&new_order_by)?.build()
+        PlanBuilder::from(&new_input)
+            .sort_limit(&new_order_by, plan.limit)?
+            .build()
     }
 
     fn rewrite_limit(&mut self, plan: &LimitPlan) -> Result<PlanNode> {
@@ -298,6 +314,17 @@ pub trait PlanRewriter: Sized {
             .build()
     }
 
+    fn rewrite_union(&mut self, plan: &UnionPlan) -> Result<PlanNode> {
+        let new_inputs = plan
+            .inputs
+            .iter()
+            .map(|input| self.rewrite_plan_node(input.as_ref()))
+            .collect::<Result<Vec<_>>>()?;
+        PlanBuilder::from(&new_inputs[0])
+            .union_all(&new_inputs[1..])?
+            .build()
+    }
+
     fn rewrite_limit_by(&mut self, plan: &LimitByPlan) -> Result<PlanNode> {
         let new_input = self.rewrite_plan_node(plan.input.as_ref())?;
         PlanBuilder::from(&new_input)
@@ -540,7 +567,19 @@ impl RewriteHelper {
                 })
             }
 
-            Expression::ScalarFunction { op, args } => {
+            Expression::Between {
+                expr,
+                negated,
+                low,
+                high,
+            } => Ok(Expression::Between {
+                expr: Box::new(RewriteHelper::expr_rewrite_alias(expr, data)?),
+                negated: *negated,
+                low: Box::new(RewriteHelper::expr_rewrite_alias(low, data)?),
+                high: Box::new(RewriteHelper::expr_rewrite_alias(high, data)?),
+            }),
+
+            Expression::ScalarFunction { op, args, ordinal } => {
                 let new_args: Result<Vec<Expression>> = args
                     .iter()
                     .map(|v| RewriteHelper::expr_rewrite_alias(v, data))
@@ -550,6 +589,7 @@ impl RewriteHelper {
                     Ok(v) => Ok(Expression::ScalarFunction {
                         op: op.clone(),
                         args: v,
+                        ordinal: *ordinal,
                     }),
                     Err(v) => Err(v),
                 }
@@ -671,6 +711,13 @@ impl RewriteHelper {
             Expression::BinaryExpression { left, right, .. } => {
                 vec![left.as_ref().clone(), right.as_ref().clone()]
             }
+            Expression::Between {
+                expr, low, high, ..
+            } => vec![
+                expr.as_ref().clone(),
+                low.as_ref().clone(),
+                high.as_ref().clone(),
+            ],
             Expression::ScalarFunction { args, .. } => args.clone(),
             Expression::AggregateFunction { args, .. } => args.clone(),
             Expression::Wildcard => vec![],
@@ -695,6 +742,14 @@ impl RewriteHelper {
                 l.append(&mut r);
                 l
             }
+            Expression::Between {
+                expr, low, high, ..
+            } => {
+                let mut v = Self::expression_plan_columns(expr)?;
+                v.append(&mut Self::expression_plan_columns(low)?);
+                v.append(&mut Self::expression_plan_columns(high)?);
+                v
+            }
             Expression::ScalarFunction { args, .. } => {
                 let mut v = vec![];
                 for arg in args {
@@ -717,6 +772,95 @@ impl RewriteHelper {
         })
     }
 
+    /// Split a conjunctive predicate into its conjuncts.
+    ///
+    /// (a AND b) AND c
+    /// ->
+    /// [a, b, c]
+    pub fn split_conjunctions(expr: &Expression) -> Vec<Expression> {
+        match expr {
+            Expression::BinaryExpression { left, op, right } if op.eq_ignore_ascii_case("and") => {
+                let mut conjunctions = Self::split_conjunctions(left);
+                conjunctions.append(&mut Self::split_conjunctions(right));
+                conjunctions
+            }
+            other => vec![other.clone()],
+        }
+    }
+
+    /// Rebuild a conjunctive predicate with the conjuncts ordered by the estimated cost,
+    /// so that cheap and selective conjuncts are evaluated first.
+    /// Conjuncts with the same cost keep their original order.
+    pub fn reorder_conjunctions(expr: &Expression) -> Expression {
+        let mut conjunctions = Self::split_conjunctions(expr);
+        if conjunctions.len() < 2 {
+            return expr.clone();
+        }
+
+        conjunctions.sort_by_key(|conjunction| {
+            (
+                Self::estimate_cost(conjunction),
+                Self::selectivity_rank(conjunction),
+            )
+        });
+
+        let mut conjunctions = conjunctions.into_iter();
+        let first = conjunctions.next().unwrap();
+        conjunctions.fold(first, |left, right| left.and(right))
+    }
+
+    /// Estimate the cost to evaluate an expression.
+    /// Columns and literals are free, operators are cheap,
+    /// function calls and pattern matching are costlier and sub queries are the most expensive.
+    pub fn estimate_cost(expr: &Expression) -> usize {
+        const OPERATOR_COST: usize = 1;
+        const FUNCTION_COST: usize = 10;
+        const SUBQUERY_COST: usize = 1000;
+
+        match expr {
+            Expression::Column(_) | Expression::QualifiedColumn(_) => 0,
+            Expression::Literal { .. } | Expression::Wildcard => 0,
+            Expression::Subquery { .. } | Expression::ScalarSubquery { .. } => SUBQUERY_COST,
+            Expression::Alias(_, expr) | Expression::Sort { expr, .. } => Self::estimate_cost(expr),
+            Expression::UnaryExpression { expr, .. } | Expression::Cast { expr, .. } => {
+                OPERATOR_COST + Self::estimate_cost(expr)
+            }
+            Expression::BinaryExpression { left, op, right } => {
+                let op_cost = match op.to_lowercase().as_str() {
+                    "like" | "not like" | "regexp" | "not regexp" | "rlike" | "not rlike" => {
+                        FUNCTION_COST
+                    }
+                    _ => OPERATOR_COST,
+                };
+                op_cost + Self::estimate_cost(left) + Self::estimate_cost(right)
+            }
+            Expression::Between {
+                expr, low, high, ..
+            } => {
+                2 * OPERATOR_COST
+                    + Self::estimate_cost(expr)
+                    + Self::estimate_cost(low)
+                    + Self::estimate_cost(high)
+            }
+            Expression::ScalarFunction { args, .. }
+            | Expression::AggregateFunction { args, .. } => {
+                FUNCTION_COST + args.iter().map(Self::estimate_cost).sum::<usize>()
+            }
+        }
+    }
+
+    // Equality comparisons usually filter out more rows than range comparisons.
+    fn selectivity_rank(expr: &Expression) -> usize {
+        match expr {
+            Expression::BinaryExpression { op, .. } => match op.as_str() {
+                "=" => 0,
+                "<" | "<=" | ">" | ">=" | "<>" | "!=" => 1,
+                _ => 2,
+            },
+            _ => 2,
+        }
+    }
+
     /// Collect all unique projection fields to a map.
     fn projections_to_map(plan: &PlanNode, map: &mut HashMap<String, Expression>) -> Result<()> {
         match plan {
@@ -768,9 +912,16 @@ impl RewriteHelper {
                 op: op.clone(),
                 expr: Box::new(expressions[0].clone()),
             },
-            Expression::ScalarFunction { op, .. } => Expression::ScalarFunction {
+            Expression::Between { negated, .. } => Expression::Between {
+                expr: Box::new(expressions[0].clone()),
+                negated: *negated,
+                low: Box::new(expressions[1].clone()),
+                high: Box::new(expressions[2].clone()),
+            },
+            Expression::ScalarFunction { op, ordinal, .. } => Expression::ScalarFunction {
                 op: op.clone(),
                 args: expressions.to_vec(),
+                ordinal: *ordinal,
             },
             Expression::AggregateFunction {
                 op,
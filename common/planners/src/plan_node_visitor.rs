@@ -35,6 +35,7 @@ use crate::DropUserPlan;
 use crate::DropUserStagePlan;
 use crate::DropUserUDFPlan;
 use crate::EmptyPlan;
+use crate::ExpandPlan;
 use crate::ExplainPlan;
 use crate::Expression;
 use crate::ExpressionPlan;
@@ -60,6 +61,7 @@ use crate::SinkPlan;
 use crate::SortPlan;
 use crate::StagePlan;
 use crate::TruncateTablePlan;
+use crate::UnionPlan;
 use crate::UseDatabasePlan;
 
 /// `PlanVisitor` implements visitor pattern(reference [syn](https://docs.rs/syn/1.0.72/syn/visit/trait.Visit.html)) for `PlanNode`.
@@ -105,6 +107,12 @@ use crate::UseDatabasePlan;
 /// ```
 pub trait PlanVisitor {
     fn visit_plan_node(&mut self, node: &PlanNode) -> Result<()> {
+        self.walk_plan_node(node)
+    }
+
+    /// Dispatches `node` to the visit method of its variant, an overridden `visit_plan_node`
+    /// can call it to keep the default traversal.
+    fn walk_plan_node(&mut self, node: &PlanNode) -> Result<()> {
         match node {
             // Base.
             PlanNode::AggregatorPartial(plan) => self.visit_aggregate_partial(plan),
@@ -118,11 +126,13 @@ pub trait PlanVisitor {
             PlanNode::Remote(plan) => self.visit_remote(plan),
             PlanNode::Having(plan) => self.visit_having(plan),
             PlanNode::Expression(plan) => self.visit_expression(plan),
+            PlanNode::Expand(plan) => self.visit_expand(plan),
             PlanNode::Limit(plan) => self.visit_limit(plan),
             PlanNode::LimitBy(plan) => self.visit_limit_by(plan),
             PlanNode::ReadSource(plan) => self.visit_read_data_source(plan),
             PlanNode::SubQueryExpression(plan) => self.visit_sub_queries_sets(plan),
             PlanNode::Sink(plan) => self.visit_append(plan),
+            PlanNode::Union(plan) => self.visit_union(plan),
 
             // Query.
             PlanNode::Select(plan) => self.visit_select(plan),
@@ -247,6 +257,14 @@ pub trait PlanVisitor {
         self.visit_exprs(&plan.exprs)
     }
 
+    fn visit_expand(&mut self, plan: &ExpandPlan) -> Result<()> {
+        self.visit_plan_node(plan.input.as_ref())?;
+        for set in &plan.sets {
+            self.visit_exprs(set)?;
+        }
+        Ok(())
+    }
+
     fn visit_sub_queries_sets(&mut self, plan: &SubQueriesSetPlan) -> Result<()> {
         self.visit_plan_node(plan.input.as_ref())?;
         self.visit_exprs(&plan.expressions)
@@ -275,6 +293,13 @@ pub trait PlanVisitor {
         self.visit_plan_node(plan.input.as_ref())
     }
 
+    fn visit_union(&mut self, plan: &UnionPlan) -> Result<()> {
+        for input in &plan.inputs {
+            self.visit_plan_node(input.as_ref())?;
+        }
+        Ok(())
+    }
+
     fn visit_read_data_source(&mut self, _: &ReadDataSourcePlan) -> Result<()> {
         Ok(())
     }
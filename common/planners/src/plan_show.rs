@@ -24,6 +24,7 @@ use crate::ShowGrantsPlan;
 use crate::ShowMetricsPlan;
 use crate::ShowProcessListsPlan;
 use crate::ShowSettingsPlan;
+use crate::ShowTableStatusPlan;
 use crate::ShowTablesPlan;
 use crate::ShowUsersPlan;
 
@@ -45,6 +46,7 @@ pub enum PlanShowKind {
 pub enum ShowPlan {
     ShowDatabases(ShowDatabasesPlan),
     ShowTables(ShowTablesPlan),
+    ShowTableStatus(ShowTableStatusPlan),
     ShowEngines(ShowEnginesPlan),
     ShowFunctions(ShowFunctionsPlan),
     ShowMetrics(ShowMetricsPlan),
@@ -27,6 +27,8 @@ pub struct SortPlan {
     pub input: Arc<PlanNode>,
     /// Output data schema
     pub schema: DataSchemaRef,
+    /// Only the first `limit` rows are kept, set when the sort feeds a LIMIT (top-N)
+    pub limit: Option<usize>,
 }
 
 impl SortPlan {
@@ -0,0 +1,33 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_datavalues2::DataSchemaRef;
+
+use crate::PlanNode;
+
+/// UNION ALL of several inputs, each input yields the same number of columns
+/// and has been coerced to the union schema.
+#[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq)]
+pub struct UnionPlan {
+    pub schema: DataSchemaRef,
+    pub inputs: Vec<Arc<PlanNode>>,
+}
+
+impl UnionPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        self.schema.clone()
+    }
+}
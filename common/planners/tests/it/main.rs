@@ -13,12 +13,17 @@
 // limitations under the License.
 
 mod plan_aggregator;
+mod plan_analyzer;
 mod plan_builder;
 mod plan_describe_table;
 mod plan_display;
+mod plan_encoding;
+mod plan_expand;
 mod plan_explain;
 mod plan_expression;
+mod plan_expression_chain;
 mod plan_expression_monotonicity;
+mod plan_expression_validator;
 mod plan_extras;
 mod plan_filter;
 mod plan_having;
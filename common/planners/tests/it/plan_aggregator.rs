@@ -14,6 +14,7 @@
 
 use std::sync::Arc;
 
+use common_datavalues2::prelude::*;
 use common_exception::Result;
 use common_planners::*;
 use pretty_assertions::assert_eq;
@@ -41,3 +42,56 @@ fn test_aggregator_plan() -> Result<()> {
     assert_eq!(expect, actual);
     Ok(())
 }
+
+#[test]
+fn test_aggregator_min_max_plan() -> Result<()> {
+    let source = Test::create().generate_source_plan_for_test(10000)?;
+    let aggr_exprs = &[
+        min(col("number")).alias("minx"),
+        max(col("number")).alias("maxx"),
+    ];
+    let plan = PlanBuilder::from(&source)
+        .aggregate_partial(aggr_exprs, &[modular(col("number"), lit(3u8))])?
+        .aggregate_final(source.schema(), aggr_exprs, &[modular(
+            col("number"),
+            lit(3u8),
+        )])?
+        .project(&[col("minx"), col("maxx")])?
+        .build()?;
+    let explain = PlanNode::Explain(ExplainPlan {
+        typ: ExplainType::Syntax,
+        input: Arc::new(plan),
+    });
+    let expect = "\
+        Projection: minx:UInt64, maxx:UInt64\
+        \n  AggregatorFinal: groupBy=[[(number % 3)]], aggr=[[min(number) as minx, max(number) as maxx]]\
+        \n    AggregatorPartial: groupBy=[[(number % 3)]], aggr=[[min(number) as minx, max(number) as maxx]]\
+        \n      ReadDataSource: scan schema: [number:UInt64], statistics: [read_rows: 10000, read_bytes: 80000, partitions_scanned: 8, partitions_total: 8]";
+    let actual = format!("{:?}", explain);
+    assert_eq!(expect, actual);
+    Ok(())
+}
+
+#[test]
+fn test_aggregator_min_max_function() -> Result<()> {
+    let schema = DataSchemaRefExt::create(vec![
+        DataField::new("a", u64::to_data_type()),
+        DataField::new("b", Vu8::to_data_type()),
+        DataField::new_nullable("c", i32::to_data_type()),
+    ]);
+
+    // Nullability of the result follows the input column.
+    for (expr, return_type) in [
+        (min(col("a")), u64::to_data_type()),
+        (max(col("a")), u64::to_data_type()),
+        (min(col("b")), Vu8::to_data_type()),
+        (max(col("b")), Vu8::to_data_type()),
+        (min(col("c")), wrap_nullable(&i32::to_data_type())),
+        (max(col("c")), wrap_nullable(&i32::to_data_type())),
+    ] {
+        let func = expr.to_aggregate_function(&schema)?;
+        assert_eq!(func.return_type()?.name(), return_type.name());
+        assert_eq!(expr.nullable(&schema)?, return_type.is_nullable());
+    }
+    Ok(())
+}
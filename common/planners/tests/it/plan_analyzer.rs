@@ -0,0 +1,68 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use common_exception::Result;
+use common_planners::*;
+use pretty_assertions::assert_eq;
+
+use crate::test::Test;
+
+#[test]
+fn test_plan_analyzer() -> Result<()> {
+    let source = Test::create().generate_source_plan_for_test(10000)?;
+    let plan = PlanBuilder::from(&source)
+        .filter(col("number").eq(lit(1i64)))?
+        .project(&[col("number")])?
+        .build()?;
+
+    let expect = PlanStats {
+        depth: 3,
+        expression_nodes: 4,
+        partitions: 8,
+    };
+    assert_eq!(expect, plan.analyze()?);
+    Ok(())
+}
+
+#[test]
+fn test_plan_analyzer_nested_expressions() -> Result<()> {
+    let source = Test::create().generate_source_plan_for_test(10000)?;
+
+    // number + 1 + 1 + ... + 1
+    let mut expr = col("number");
+    for _ in 0..500 {
+        expr = add(expr, lit(1u64));
+    }
+
+    let plan = PlanBuilder::from(&source).project(&[expr])?.build()?;
+    let stats = plan.analyze()?;
+    assert_eq!(stats.depth, 2);
+    assert_eq!(stats.expression_nodes, 1 + 2 * 500);
+    Ok(())
+}
+
+#[test]
+fn test_plan_analyzer_nested_plans() -> Result<()> {
+    let source = Test::create().generate_source_plan_for_test(10000)?;
+
+    let mut builder = PlanBuilder::from(&source);
+    for n in 0..100 {
+        builder = builder.limit(n + 1)?;
+    }
+
+    let stats = builder.build()?.analyze()?;
+    assert_eq!(stats.depth, 101);
+    assert_eq!(stats.expression_nodes, 0);
+    assert_eq!(stats.partitions, 8);
+    Ok(())
+}
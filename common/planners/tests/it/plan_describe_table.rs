@@ -23,6 +23,7 @@ fn test_describe_table_plan() -> Result<()> {
         DataField::new("Field", Vu8::to_data_type()),
         DataField::new("Type", Vu8::to_data_type()),
         DataField::new("Null", Vu8::to_data_type()),
+        DataField::new("Comment", Vu8::to_data_type()),
     ]);
 
     let describe = PlanNode::DescribeTable(DescribeTablePlan {
@@ -35,7 +36,8 @@ fn test_describe_table_plan() -> Result<()> {
     DataSchema { fields: [\
         DataField { name: \"Field\", data_type: String, nullable: false }, \
         DataField { name: \"Type\", data_type: String, nullable: false }, \
-        DataField { name: \"Null\", data_type: String, nullable: false }], \
+        DataField { name: \"Null\", data_type: String, nullable: false }, \
+        DataField { name: \"Comment\", data_type: String, nullable: false }], \
         metadata: {} \
     }";
     let actual = format!("{:?}", describe.schema());
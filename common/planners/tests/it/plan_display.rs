@@ -15,10 +15,13 @@
 use std::collections::HashMap;
 
 use common_datavalues2::prelude::*;
+use common_exception::ErrorCode;
 use common_exception::Result;
 use common_meta_types::TableMeta;
 use common_planners::*;
 
+use crate::test::Test;
+
 #[test]
 fn test_plan_display_indent() -> Result<()> {
     use pretty_assertions::assert_eq;
@@ -50,3 +53,97 @@ fn test_plan_display_indent() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_plan_display_between() -> Result<()> {
+    use pretty_assertions::assert_eq;
+
+    let source = Test::create().generate_source_plan_for_test(10000)?;
+    let plan = PlanBuilder::from(&source)
+        .filter(Expression::Between {
+            expr: Box::new(col("number")),
+            negated: false,
+            low: Box::new(lit(1i64)),
+            high: Box::new(lit(5i64)),
+        })?
+        .project(&[col("number")])?
+        .build()?;
+
+    let expect = "\
+    Projection: number:UInt64\
+    \n  Filter: (number BETWEEN 1 AND 5)\
+    \n    ReadDataSource: scan schema: [number:UInt64], statistics: [read_rows: 10000, read_bytes: 80000, partitions_scanned: 8, partitions_total: 8]";
+    assert_eq!(expect, format!("{:?}", plan));
+
+    let not_between = Expression::Between {
+        expr: Box::new(col("number")),
+        negated: true,
+        low: Box::new(lit(1i64)),
+        high: Box::new(lit(5i64)),
+    };
+    assert_eq!("(number NOT BETWEEN 1 AND 5)", format!("{:?}", not_between));
+    assert_eq!("(number not between 1 and 5)", not_between.column_name());
+
+    Ok(())
+}
+
+#[test]
+fn test_plan_display_union_all() -> Result<()> {
+    use pretty_assertions::assert_eq;
+
+    let source = Test::create().generate_source_plan_for_test(10000)?;
+    let cast = |expr: Expression, data_type: DataTypePtr| Expression::Cast {
+        expr: Box::new(expr),
+        data_type,
+        is_nullable: false,
+    };
+
+    let renamed = PlanBuilder::from(&source)
+        .project(&[col("number").alias("id")])?
+        .build()?;
+    let narrowed = PlanBuilder::from(&source)
+        .project(&[cast(col("number"), u32::to_data_type()).alias("n32")])?
+        .build()?;
+    let plan = PlanBuilder::from(&source)
+        .project(&[col("number")])?
+        .union_all(&[renamed, narrowed])?
+        .build()?;
+
+    let expect = "\
+    UnionAll\
+    \n  Projection: number:UInt64\
+    \n    ReadDataSource: scan schema: [number:UInt64], statistics: [read_rows: 10000, read_bytes: 80000, partitions_scanned: 8, partitions_total: 8]\
+    \n  Projection: id as number:UInt64\
+    \n    Projection: number as id:UInt64\
+    \n      ReadDataSource: scan schema: [number:UInt64], statistics: [read_rows: 10000, read_bytes: 80000, partitions_scanned: 8, partitions_total: 8]\
+    \n  Projection: cast(n32 as UInt64) as number:UInt64\
+    \n    Projection: cast(number as UInt32) as n32:UInt32\
+    \n      ReadDataSource: scan schema: [number:UInt64], statistics: [read_rows: 10000, read_bytes: 80000, partitions_scanned: 8, partitions_total: 8]";
+    assert_eq!(expect, format!("{:?}", plan));
+
+    // Branches must have the same number of columns.
+    let wider = PlanBuilder::from(&source)
+        .project(&[col("number"), col("number").alias("other")])?
+        .build()?;
+    let result = PlanBuilder::from(&source)
+        .project(&[col("number")])?
+        .union_all(&[wider]);
+    assert_eq!(
+        ErrorCode::BadArguments("").code(),
+        result.err().unwrap().code()
+    );
+
+    // And column types which can be merged.
+    let string = PlanBuilder::from(&source)
+        .project(&[cast(col("number"), Vu8::to_data_type()).alias("s")])?
+        .build()?;
+    let result = PlanBuilder::from(&source)
+        .project(&[col("number")])?
+        .union_all(&[string]);
+    assert_eq!(
+        ErrorCode::IllegalDataType("").code(),
+        result.err().unwrap().code()
+    );
+
+    Ok(())
+}
@@ -0,0 +1,178 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::sync::Arc;
+
+use common_datavalues2::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_planners::*;
+use pretty_assertions::assert_eq;
+
+use crate::test::Test;
+
+fn assert_round_trip(plan: PlanNode) -> Result<()> {
+    let bytes = plan.to_bytes()?;
+    assert_eq!(bytes[0], PLAN_FORMAT_VERSION);
+    assert_eq!(plan, PlanNode::from_bytes(&bytes)?, "{}", plan.name());
+    Ok(())
+}
+
+#[test]
+fn test_plan_encoding_query_plans() -> Result<()> {
+    let source = Test::create().generate_source_plan_for_test(10000)?;
+    let aggr_exprs = &[sum(add(col("number"), lit(1u64)))];
+    let group_exprs = &[col("number")];
+
+    let before_group_by = PlanBuilder::from(&source)
+        .filter(col("number").gt(lit(1u64)))?
+        .expression(&[add(col("number"), lit(1u64))], "Before GroupBy")?;
+    let query = before_group_by
+        .aggregate_partial(aggr_exprs, group_exprs)?
+        .aggregate_final(before_group_by.build()?.schema(), aggr_exprs, group_exprs)?
+        .having(col("number").lt(lit(8u64)))?
+        .sort(&[Expression::Sort {
+            expr: Box::new(col("number")),
+            asc: false,
+            nulls_first: false,
+            case_insensitive: false,
+            origin_expr: Box::new(col("number")),
+        }])?
+        .limit_by(1, &[col("number")])?
+        .limit_offset(Some(3), 1)?
+        .project(&[col("number").alias("n")])?;
+
+    let plans = vec![
+        PlanBuilder::empty().build()?,
+        source.clone(),
+        query.build()?,
+        query.select()?.build()?,
+        query.explain()?.build()?,
+        PlanBuilder::from(&source)
+            .union_all(&[source.clone()])?
+            .build()?,
+    ];
+
+    for plan in plans {
+        assert_round_trip(plan)?;
+    }
+    Ok(())
+}
+
+#[test]
+fn test_plan_encoding_insert_plans() -> Result<()> {
+    let source = Test::create().generate_source_plan_for_test(10000)?;
+    let schema = source.schema();
+    let insert = |source| {
+        PlanNode::Insert(InsertPlan {
+            database_name: "db".to_string(),
+            table_name: "t".to_string(),
+            table_id: 1,
+            schema: schema.clone(),
+            overwrite: false,
+            source,
+        })
+    };
+
+    let plans = vec![
+        insert(InsertInputSource::SelectPlan(Box::new(source.clone()))),
+        insert(InsertInputSource::Expressions(vec![vec![lit(1u64)], vec![
+            add(lit(1u64), lit(2u64)),
+        ]])),
+        insert(InsertInputSource::StreamingWithFormat("CSV".to_string())),
+    ];
+
+    for plan in plans {
+        // InsertPlan only compares the target table, check the source explicitly.
+        let decoded = PlanNode::from_bytes(&plan.to_bytes()?)?;
+        match (plan, decoded) {
+            (PlanNode::Insert(plan), PlanNode::Insert(decoded)) => {
+                assert!(plan == decoded);
+                assert_eq!(
+                    serde_json::to_string(&plan.source).unwrap(),
+                    serde_json::to_string(&decoded.source).unwrap()
+                );
+            }
+            _ => unreachable!(),
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn test_plan_encoding_statement_plans() -> Result<()> {
+    let plans = vec![
+        PlanNode::DropDatabase(DropDatabasePlan {
+            if_exists: true,
+            tenant: "tenant".to_string(),
+            db: "db".to_string(),
+        }),
+        PlanNode::TruncateTable(TruncateTablePlan {
+            db: "db".to_string(),
+            table: "t".to_string(),
+            purge: true,
+        }),
+        PlanNode::UseDatabase(UseDatabasePlan {
+            db: "db".to_string(),
+        }),
+        PlanNode::SetVariable(SettingPlan {
+            vars: vec![VarValue {
+                variable: "max_threads".to_string(),
+                value: "1".to_string(),
+            }],
+        }),
+        PlanNode::Kill(KillPlan {
+            id: "query-id".to_string(),
+            kill_connection: false,
+        }),
+        PlanNode::Explain(ExplainPlan {
+            typ: ExplainType::Pipeline,
+            input: Arc::new(PlanBuilder::empty().build()?),
+        }),
+    ];
+
+    for plan in plans {
+        assert_round_trip(plan)?;
+    }
+    Ok(())
+}
+
+#[test]
+fn test_plan_encoding_errors() -> Result<()> {
+    let plan = Test::create().generate_source_plan_for_test(10000)?;
+    let bytes = plan.to_bytes()?;
+
+    // Plan from a newer node.
+    let mut newer = bytes.clone();
+    newer[0] = PLAN_FORMAT_VERSION + 1;
+    let err = PlanNode::from_bytes(&newer).unwrap_err();
+    assert_eq!(err.code(), ErrorCode::BadBytesCode());
+    assert_eq!(
+        err.message(),
+        format!(
+            "Unsupported plan format version {}, this node supports version {}",
+            PLAN_FORMAT_VERSION + 1,
+            PLAN_FORMAT_VERSION
+        )
+    );
+
+    // Truncated payload.
+    let err = PlanNode::from_bytes(&bytes[..bytes.len() - 1]).unwrap_err();
+    assert_eq!(err.code(), ErrorCode::BadBytesCode());
+
+    // Truncated header.
+    let err = PlanNode::from_bytes(&bytes[..4]).unwrap_err();
+    assert_eq!(err.code(), ErrorCode::BadBytesCode());
+
+    Ok(())
+}
@@ -0,0 +1,52 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues2::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_planners::*;
+
+use crate::test::Test;
+
+#[test]
+fn test_expand_plan() -> Result<()> {
+    use pretty_assertions::assert_eq;
+
+    let source = Test::create().generate_source_plan_for_test(10000)?;
+    let one = Expression::create_literal_with_type(DataValue::UInt64(1), u32::to_data_type());
+    let plan = PlanBuilder::from(&source)
+        .expand(&[vec![col("number").alias("key")], vec![one.alias("key")]])?
+        .build()?;
+
+    let expect = "\
+    Expand: [number as key], [cast(1 as UInt64) as key]\
+    \n  ReadDataSource: scan schema: [number:UInt64], statistics: [read_rows: 10000, read_bytes: 80000, partitions_scanned: 8, partitions_total: 8]";
+    assert_eq!(expect, format!("{:?}", plan));
+
+    let expect = DataSchemaRefExt::create(vec![
+        DataField::new("number", u64::to_data_type()),
+        DataField::new("key", u64::to_data_type()),
+    ]);
+    assert_eq!(expect, plan.schema());
+
+    // Sets must have the same number of expressions.
+    let result = PlanBuilder::from(&source)
+        .expand(&[vec![col("number")], vec![col("number"), col("number")]]);
+    assert_eq!(
+        ErrorCode::BadArguments("").code(),
+        result.err().unwrap().code()
+    );
+
+    Ok(())
+}
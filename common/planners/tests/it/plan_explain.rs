@@ -47,3 +47,70 @@ fn test_explain_plan() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_explain_plan_graphviz() -> Result<()> {
+    let source = Test::create().generate_source_plan_for_test(10000)?;
+    let plan = PlanBuilder::from(&source)
+        .filter(add(col("number"), lit(1)).eq(lit(4)))?
+        .project(&[col("number").alias("c1"), col("number").alias("c2")])?
+        .limit(10)?
+        .build()?;
+
+    let expect = vec![
+        "// Begin Databend GraphViz Plan (see https://graphviz.org)",
+        "digraph {",
+        "    n0 [shape=box, label=\"LimitPlan\\n10, offset: 0\"];",
+        "    n1 [shape=box, label=\"ProjectionPlan\\nnumber as c1, number as c2\"];",
+        "    n1 -> n0;",
+        "    n2 [shape=box, label=\"FilterPlan\\n((number + 1) = 4)\"];",
+        "    n2 -> n1;",
+        "    n3 [shape=box, label=\"ReadSourcePlan\\n'system'.'numbers_mt'\\nread_rows: 10000, read_bytes: 80000\"];",
+        "    n3 -> n2;",
+        "}",
+        "// End Databend GraphViz Plan",
+    ];
+    let actual = format!("{}", plan.display_graphviz());
+    assert_eq!(expect, actual.lines().collect::<Vec<_>>());
+
+    Ok(())
+}
+
+#[test]
+fn test_explain_plan_with_types() -> Result<()> {
+    let schema = DataSchemaRefExt::create(vec![
+        DataField::new("number", u64::to_data_type()),
+        DataField::new_nullable("c", i32::to_data_type()),
+    ]);
+    let aggr_exprs = &[sum(add(col("number"), lit(1u64)))];
+    let group_exprs = &[col("c")];
+
+    let before_group_by = PlanBuilder::create(schema)
+        .filter(col("c").gt(lit(1)))?
+        .expression(
+            &[add(col("number"), lit(1u64)), Expression::Cast {
+                expr: Box::new(col("number")),
+                data_type: i32::to_data_type(),
+                is_nullable: false,
+            }],
+            "Before GroupBy",
+        )?;
+    let plan = before_group_by
+        .aggregate_partial(aggr_exprs, group_exprs)?
+        .aggregate_final(before_group_by.build()?.schema(), aggr_exprs, group_exprs)?
+        .having(col("missing").eq(lit(1u64)))?
+        .project(&[col("c"), col("sum((number + 1))")])?
+        .build()?;
+
+    let expect = "\
+    Projection: c:Int32 NULL, sum((number + 1)):UInt64 NOT NULL\
+    \n  Having: (missing = 1):<error: Unable to get field named \"missing\". Valid fields: [\"sum((number + 1))\", \"c\"]>\
+    \n    AggregatorFinal: groupBy=[c:Int32 NULL], aggr=[sum((number + 1)):UInt64 NOT NULL]\
+    \n      AggregatorPartial: groupBy=[c:Int32 NULL], aggr=[sum((number + 1)):UInt64 NOT NULL]\
+    \n        Expression: (number + 1):UInt64 NOT NULL, cast(number as Int32):Int32 NOT NULL (Before GroupBy)\
+    \n          Filter: (c > 1):Boolean NULL";
+    let actual = format!("{}", plan.display_indent_format_with_types());
+    assert_eq!(expect, actual);
+
+    Ok(())
+}
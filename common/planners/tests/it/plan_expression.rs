@@ -82,6 +82,7 @@ fn test_expression_validate() -> Result<()> {
             expression: Expression::ScalarFunction {
                 op: "toTypeName".to_string(),
                 args: vec![],
+                ordinal: None,
             },
             error: Some(ErrorCode::NumberArgumentsNotMatch(
                 "Function `toTypeName` expect to have 1 arguments, but got 0",
@@ -92,6 +93,7 @@ fn test_expression_validate() -> Result<()> {
             expression: Expression::ScalarFunction {
                 op: "example".to_string(),
                 args: vec![col("33")],
+                ordinal: None,
             },
             error: Some(ErrorCode::NumberArgumentsNotMatch(
                 "Function `example` expect to have 0 arguments, but got 1",
@@ -102,6 +104,7 @@ fn test_expression_validate() -> Result<()> {
             expression: Expression::ScalarFunction {
                 op: "example".to_string(),
                 args: vec![],
+                ordinal: None,
             },
             error: None,
         },
@@ -123,3 +126,43 @@ fn test_expression_validate() -> Result<()> {
     }
     Ok(())
 }
+
+#[test]
+fn test_normalize_to_cnf() -> Result<()> {
+    let a = col("a").eq(lit(1i64));
+    let b = col("b").eq(lit(2i64));
+    let c = col("c").eq(lit(3i64));
+
+    // (a AND b) OR c
+    let cnf = normalize_to_cnf(&a.and(b.clone()).or(c.clone()));
+    assert_eq!(a.or(c.clone()).and(b.or(c.clone())), cnf);
+    assert_eq!(
+        "(((a = 1) or (c = 3)) and ((b = 2) or (c = 3)))",
+        format!("{:?}", cnf)
+    );
+
+    // a AND (b OR c) is already in CNF.
+    let expr = a.and(b.or(c.clone()));
+    assert_eq!(expr, normalize_to_cnf(&expr));
+
+    // (a AND b) OR (c AND d)
+    let d = col("d").eq(lit(4i64));
+    let cnf = normalize_to_cnf(&a.and(b).or(c.and(d)));
+    assert_eq!(
+        "(((((a = 1) or (c = 3)) and ((a = 1) or (d = 4))) and ((b = 2) or (c = 3))) and ((b = 2) or (d = 4)))",
+        format!("{:?}", cnf)
+    );
+
+    // (x0 AND y0) OR ... OR (x6 AND y6) has 2^7 conjuncts in CNF, it is kept as it is.
+    let expr = (0..7)
+        .map(|i| {
+            let x = col(&format!("x{}", i)).eq(lit(i as i64));
+            let y = col(&format!("y{}", i)).eq(lit(i as i64));
+            x.and(y)
+        })
+        .reduce(|left, right| left.or(right))
+        .unwrap();
+    assert_eq!(expr, normalize_to_cnf(&expr));
+
+    Ok(())
+}
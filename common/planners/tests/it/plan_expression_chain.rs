@@ -0,0 +1,55 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues2::prelude::*;
+use common_exception::Result;
+use common_planners::*;
+
+fn count_actions(chain: &ExpressionChain, name: &str) -> usize {
+    chain
+        .actions
+        .iter()
+        .filter(|action| matches!(action, ExpressionAction::Function(_)))
+        .filter(|action| action.column_name() == name)
+        .count()
+}
+
+#[test]
+fn test_expression_chain_common_subexpression() -> Result<()> {
+    let schema = DataSchemaRefExt::create(vec![DataField::new("a", i64::to_data_type())]);
+
+    let abs = Expression::create_scalar_function("abs", vec![col("a")]);
+    let exprs = vec![
+        add(abs.clone(), lit(1i64)),
+        Expression::create_binary_expression("*", vec![abs.clone(), lit(2i64)]),
+    ];
+
+    let chain = ExpressionChain::try_create(schema, &exprs)?;
+    assert_eq!(count_actions(&chain, &abs.column_name()), 1);
+    assert_eq!(count_actions(&chain, &exprs[0].column_name()), 1);
+    assert_eq!(count_actions(&chain, &exprs[1].column_name()), 1);
+    Ok(())
+}
+
+#[test]
+fn test_expression_chain_skip_non_deterministic() -> Result<()> {
+    let schema = DataSchemaRefExt::create(vec![DataField::new("a", i64::to_data_type())]);
+
+    let rand = Expression::create_scalar_function("rand", vec![]);
+    let exprs = vec![add(rand.clone(), lit(1i64)), add(rand.clone(), lit(2i64))];
+
+    let chain = ExpressionChain::try_create(schema, &exprs)?;
+    assert_eq!(count_actions(&chain, &rand.column_name()), 2);
+    Ok(())
+}
@@ -47,6 +47,16 @@ fn create_u8(d: u8) -> Option<ColumnWithField> {
     Some(ColumnWithField::new(col, data_field))
 }
 
+fn create_i64(d: i64) -> Option<ColumnWithField> {
+    let data_field = DataField::new("y", i64::to_data_type());
+    let col = data_field
+        .data_type()
+        .create_constant_column(&DataValue::Int64(d), 1)
+        .unwrap();
+
+    Some(ColumnWithField::new(col, data_field))
+}
+
 fn create_datetime(d: u32) -> Option<ColumnWithField> {
     let data_field = DataField::new("x", DateTime32Type::arc(None));
     let col = data_field
@@ -452,6 +462,77 @@ fn test_abs_function() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_cast_function() -> Result<()> {
+    let test_suite = vec![
+        Test {
+            name: "f(y) = cast(y as Float64) where 1 <= y <= 100",
+            expr: Expression::Cast {
+                expr: Box::new(col("y")),
+                data_type: f64::to_data_type(),
+                is_nullable: false,
+            },
+            column: "y",
+            left: create_i64(1),
+            right: create_i64(100),
+            expect_mono: Monotonicity2 {
+                is_monotonic: true,
+                is_positive: true,
+                is_constant: false,
+                left: create_f64(1.0),
+                right: create_f64(100.0),
+            },
+        },
+        Test {
+            name: "f(y) = -cast(y as Float64) where y >= 1",
+            expr: Expression::create_unary_expression("negate", vec![Expression::Cast {
+                expr: Box::new(col("y")),
+                data_type: f64::to_data_type(),
+                is_nullable: false,
+            }]),
+            column: "y",
+            left: create_i64(1),
+            right: None,
+            expect_mono: Monotonicity2 {
+                is_monotonic: true,
+                is_positive: false,
+                is_constant: false,
+                left: create_f64(-1.0),
+                right: None,
+            },
+        },
+        Test {
+            name: "f(x) = cast(x as Int8) where 1.0 <= x <= 1000.0",
+            expr: Expression::Cast {
+                expr: Box::new(col("x")),
+                data_type: i8::to_data_type(),
+                is_nullable: false,
+            },
+            column: "x",
+            left: create_f64(1.0),
+            right: create_f64(1000.0),
+            expect_mono: Monotonicity2::default(),
+        },
+        Test {
+            name: "f(x) = abs(cast(x as Float64)) where -5 <= x <= 5",
+            expr: Expression::create_scalar_function("abs", vec![Expression::Cast {
+                expr: Box::new(col("x")),
+                data_type: f64::to_data_type(),
+                is_nullable: false,
+            }]),
+            column: "x",
+            left: create_f64(-5.0),
+            right: create_f64(5.0),
+            expect_mono: Monotonicity2::default(),
+        },
+    ];
+
+    for t in test_suite.into_iter() {
+        verify_test(t)?;
+    }
+    Ok(())
+}
+
 #[test]
 fn test_dates_function() -> Result<()> {
     let test_suite = vec![
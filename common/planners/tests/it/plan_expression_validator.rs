@@ -0,0 +1,84 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::Result;
+use common_planners::*;
+
+#[test]
+fn test_validate_expression_passed() -> Result<()> {
+    validate_expression(&add(col("a"), lit(1i64)))?;
+    validate_expression(&Expression::create_scalar_function("abs", vec![col("a")]))?;
+    validate_expression(&add(sum(col("a")), avg(col("b"))))?;
+    validate_projection_expression(&Expression::Wildcard)?;
+    validate_sort_expression(&sort("a", true, false))?;
+    Ok(())
+}
+
+#[test]
+fn test_validate_function_arguments() -> Result<()> {
+    let expr = Expression::create_scalar_function("abs", vec![col("a"), col("b")]);
+    let result = validate_expression(&expr);
+    assert!(result.is_err());
+
+    let err = result.unwrap_err();
+    assert_eq!(err.code(), 1005);
+    assert!(err
+        .message()
+        .contains("expect to have 1 arguments, but got 2"));
+    assert!(err.message().contains(&expr.column_name()));
+    Ok(())
+}
+
+#[test]
+fn test_validate_nested_aggregate() -> Result<()> {
+    let expr = sum(avg(col("a")));
+    let result = validate_expression(&expr);
+    assert!(result.is_err());
+
+    let err = result.unwrap_err();
+    assert_eq!(err.code(), 1005);
+    assert!(err.message().contains("inside another aggregate function"));
+    assert!(err.message().contains(&expr.column_name()));
+    Ok(())
+}
+
+#[test]
+fn test_validate_wildcard() -> Result<()> {
+    // Wildcard is only allowed as a whole projection item.
+    let expr = Expression::create_scalar_function("abs", vec![Expression::Wildcard]);
+    let result = validate_projection_expression(&expr);
+    assert!(result.is_err());
+    assert_eq!(result.unwrap_err().code(), 1005);
+
+    let result = validate_expression(&Expression::Wildcard);
+    assert!(result.is_err());
+    assert_eq!(result.unwrap_err().code(), 1005);
+    Ok(())
+}
+
+#[test]
+fn test_validate_sort() -> Result<()> {
+    let expr = add(sort("a", true, false), lit(1i64));
+    let result = validate_sort_expression(&expr);
+    assert!(result.is_err());
+
+    let err = result.unwrap_err();
+    assert_eq!(err.code(), 1005);
+    assert!(err.message().contains("only allowed in ORDER BY"));
+
+    let result = validate_expression(&sort("a", true, false));
+    assert!(result.is_err());
+    assert_eq!(result.unwrap_err().code(), 1005);
+    Ok(())
+}
@@ -38,16 +38,19 @@ fn test_rewrite_projection_alias_plan() -> Result<()> {
                 Box::new(Expression::ScalarFunction {
                     op: "plus".to_string(),
                     args: vec![lit(1i32), col("z")],
+                    ordinal: None,
                 })
                 .alias("x"),
                 Box::new(Expression::ScalarFunction {
                     op: "plus".to_string(),
                     args: vec![lit(1i32), col("x")],
+                    ordinal: None,
                 })
                 .alias("y"),
                 Box::new(Expression::ScalarFunction {
                     op: "plus".to_string(),
                     args: vec![lit(1i32), col("y")],
+                    ordinal: None,
                 })
                 .alias("z"),
             ],
@@ -60,11 +63,13 @@ fn test_rewrite_projection_alias_plan() -> Result<()> {
                 Box::new(Expression::ScalarFunction {
                     op: "plus".to_string(),
                     args: vec![lit(1i32), col("z")],
+                    ordinal: None,
                 })
                 .alias("x"),
                 Box::new(Expression::ScalarFunction {
                     op: "plus".to_string(),
                     args: vec![lit(1i32), col("y")],
+                    ordinal: None,
                 })
                 .alias("x"),
             ],
@@ -79,11 +84,13 @@ fn test_rewrite_projection_alias_plan() -> Result<()> {
                 Box::new(Expression::ScalarFunction {
                     op: "add".to_string(),
                     args: vec![lit(1i32), col("x")],
+                    ordinal: None,
                 })
                 .alias("y"),
                 Expression::ScalarFunction {
                     op: "multiply".to_string(),
                     args: vec![col("y"), col("y")],
+                    ordinal: None,
                 },
             ],
             expect_str: "[x, add(1, x) as y, multiply(add(1, x), add(1, x))]",
@@ -95,16 +102,19 @@ fn test_rewrite_projection_alias_plan() -> Result<()> {
                 Box::new(Expression::ScalarFunction {
                     op: "add".to_string(),
                     args: vec![lit(1i32), lit(1i64)],
+                    ordinal: None,
                 })
                 .alias("x"),
                 Box::new(Expression::ScalarFunction {
                     op: "add".to_string(),
                     args: vec![lit(1i32), col("x")],
+                    ordinal: None,
                 })
                 .alias("y"),
                 Expression::ScalarFunction {
                     op: "multiply".to_string(),
                     args: vec![col("x"), col("y")],
+                    ordinal: None,
                 },
             ],
             expect_str:
@@ -117,16 +127,19 @@ fn test_rewrite_projection_alias_plan() -> Result<()> {
                 Box::new(Expression::ScalarFunction {
                     op: "add".to_string(),
                     args: vec![col("x"), lit(1i64)],
+                    ordinal: None,
                 })
                 .alias("x"),
                 Box::new(Expression::ScalarFunction {
                     op: "add".to_string(),
                     args: vec![lit(1i32), col("x")],
+                    ordinal: None,
                 })
                 .alias("y"),
                 Expression::ScalarFunction {
                     op: "multiply".to_string(),
                     args: vec![col("x"), col("y")],
+                    ordinal: None,
                 },
             ],
             expect_str:
@@ -165,11 +178,13 @@ fn test_rewrite_expressions_plan() -> Result<()> {
     let exprs = vec![Expression::ScalarFunction {
         op: "multiply".to_string(),
         args: vec![col("x"), col("y")],
+        ordinal: None,
     }];
 
     let expect_plan = Expression::ScalarFunction {
         op: "multiply".to_string(),
         args: vec![col("number"), col("number")],
+        ordinal: None,
     };
     let actual_plan = RewriteHelper::rewrite_alias_exprs(&actual, &exprs)?;
     assert_eq!(expect_plan, actual_plan[0]);
@@ -177,6 +192,40 @@ fn test_rewrite_expressions_plan() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_reorder_conjunctions() -> Result<()> {
+    use pretty_assertions::assert_eq;
+
+    let expensive = Expression::ScalarFunction {
+        op: "sipHash".to_string(),
+        args: vec![col("a")],
+        ordinal: None,
+    }
+    .gt(lit(1i64));
+    let range = col("b").lt(lit(10i64));
+    let equality = col("c").eq(lit(1i64));
+
+    let predicate = expensive.and(range.clone()).and(equality.clone());
+    let conjunctions = RewriteHelper::split_conjunctions(&predicate);
+    assert_eq!(
+        vec![expensive.clone(), range.clone(), equality.clone()],
+        conjunctions
+    );
+
+    let reordered = RewriteHelper::reorder_conjunctions(&predicate);
+    assert_eq!(equality.and(range).and(expensive), reordered);
+    assert_eq!(
+        "(((c = 1) and (b < 10)) and (sipHash(a) > 1))",
+        format!("{:?}", reordered)
+    );
+
+    // Non conjunctive predicates are kept as they are.
+    let predicate = col("a").eq(lit(1i64)).or(col("b").eq(lit(2i64)));
+    assert_eq!(predicate, RewriteHelper::reorder_conjunctions(&predicate));
+
+    Ok(())
+}
+
 struct DefaultRewriter;
 
 impl PlanRewriter for DefaultRewriter {
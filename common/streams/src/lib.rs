@@ -19,6 +19,7 @@ mod stream_cast;
 mod stream_correct_with_schema;
 mod stream_datablock;
 mod stream_limit_by;
+mod stream_profiling;
 mod stream_progress;
 mod stream_skip;
 mod stream_sort;
@@ -33,6 +34,8 @@ pub use stream_cast::CastStream;
 pub use stream_correct_with_schema::CorrectWithSchemaStream;
 pub use stream_datablock::DataBlockStream;
 pub use stream_limit_by::LimitByStream;
+pub use stream_profiling::ProfilingStream;
+pub use stream_profiling::StreamProfiling;
 pub use stream_progress::ProgressStream;
 pub use stream_skip::SkipStream;
 pub use stream_sort::SortStream;
@@ -122,6 +122,6 @@ where R: io::Read + Send + Sync
             .map(|deser| deser.finish_to_column())
             .collect::<Vec<_>>();
 
-        Ok(Some(DataBlock::create(self.schema.clone(), columns)))
+        Ok(Some(DataBlock::try_create(self.schema.clone(), columns)?))
     }
 }
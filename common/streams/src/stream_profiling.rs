@@ -0,0 +1,107 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::task::Context;
+use std::task::Poll;
+use std::time::Duration;
+use std::time::Instant;
+
+use common_datablocks::DataBlock;
+use common_exception::Result;
+use futures::Stream;
+use pin_project_lite::pin_project;
+
+use crate::SendableDataBlockStream;
+
+/// The execution metrics shared by the streams of one pipeline stage.
+#[derive(Default)]
+pub struct StreamProfiling {
+    rows: AtomicUsize,
+    bytes: AtomicUsize,
+    elapsed_nanos: AtomicU64,
+}
+
+impl StreamProfiling {
+    pub fn create() -> Arc<Self> {
+        Arc::new(Self::default())
+    }
+
+    /// The number of rows the streams produced.
+    pub fn rows(&self) -> usize {
+        self.rows.load(Ordering::Relaxed)
+    }
+
+    /// The memory size of the blocks the streams produced.
+    pub fn bytes(&self) -> usize {
+        self.bytes.load(Ordering::Relaxed)
+    }
+
+    /// The wall time spent in polling the slowest stream, including its inputs.
+    pub fn elapsed(&self) -> Duration {
+        Duration::from_nanos(self.elapsed_nanos.load(Ordering::Relaxed))
+    }
+}
+
+pin_project! {
+    pub struct ProfilingStream {
+        #[pin]
+        input: SendableDataBlockStream,
+        profiling: Arc<StreamProfiling>,
+        elapsed: Duration,
+    }
+}
+
+impl ProfilingStream {
+    pub fn create(input: SendableDataBlockStream, profiling: Arc<StreamProfiling>) -> Self {
+        Self {
+            input,
+            profiling,
+            elapsed: Duration::default(),
+        }
+    }
+}
+
+impl Stream for ProfilingStream {
+    type Item = Result<DataBlock>;
+
+    fn poll_next(
+        self: std::pin::Pin<&mut Self>,
+        ctx: &mut Context<'_>,
+    ) -> Poll<Option<Self::Item>> {
+        let this = self.project();
+
+        let start = Instant::now();
+        let poll = this.input.poll_next(ctx);
+        *this.elapsed += start.elapsed();
+
+        let profiling = this.profiling;
+        profiling
+            .elapsed_nanos
+            .fetch_max(this.elapsed.as_nanos() as u64, Ordering::Relaxed);
+
+        if let Poll::Ready(Some(Ok(block))) = &poll {
+            profiling
+                .rows
+                .fetch_add(block.num_rows(), Ordering::Relaxed);
+            profiling
+                .bytes
+                .fetch_add(block.memory_size(), Ordering::Relaxed);
+        }
+        poll
+    }
+}
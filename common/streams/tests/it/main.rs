@@ -16,5 +16,6 @@ mod source;
 mod stream_cast;
 mod stream_datablock;
 mod stream_limit_by;
+mod stream_profiling;
 mod stream_progress;
 mod stream_skip;
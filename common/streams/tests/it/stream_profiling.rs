@@ -0,0 +1,44 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_base::tokio;
+use common_datablocks::*;
+use common_datavalues2::prelude::*;
+use common_exception::Result;
+use common_streams::*;
+use futures::TryStreamExt;
+
+#[tokio::test]
+async fn test_profiling_stream() -> Result<()> {
+    let schema = DataSchemaRefExt::create(vec![DataField::new("a", i64::to_data_type())]);
+
+    let block = DataBlock::create(schema.clone(), vec![Series::from_data(vec![1i64, 2, 3])]);
+
+    let profiling = StreamProfiling::create();
+    for _ in 0..2 {
+        let input =
+            DataBlockStream::create(schema.clone(), None, vec![block.clone(), block.clone()]);
+        let stream = ProfilingStream::create(Box::pin(input), profiling.clone());
+        let result = stream.try_collect::<Vec<_>>().await?;
+        assert_eq!(result.len(), 2);
+    }
+
+    // The rows and bytes of all the streams are summed up.
+    assert_eq!(profiling.rows(), 12);
+    assert_eq!(profiling.bytes(), block.memory_size() * 4);
+
+    Ok(())
+}
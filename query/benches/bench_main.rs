@@ -18,6 +18,8 @@ mod suites;
 
 criterion_main! {
     suites::bench_aggregate_query_sql::benches,
+    suites::bench_block_checksum::benches,
+    suites::bench_expression_executor::benches,
     suites::bench_filter_query_sql::benches,
     suites::bench_limit_query_sql::benches,
     suites::bench_sort_query_sql::benches,
@@ -0,0 +1,53 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datablocks::DataBlock;
+use common_datavalues2::prelude::*;
+use criterion::criterion_group;
+use criterion::criterion_main;
+use criterion::Criterion;
+
+fn create_block(rows: usize) -> DataBlock {
+    let schema = DataSchemaRefExt::create(vec![
+        DataField::new("a", u64::to_data_type()),
+        DataField::new("b", wrap_nullable(&Vu8::to_data_type())),
+    ]);
+
+    let a = (0..rows as u64).collect::<Vec<_>>();
+    let b = (0..rows)
+        .map(|i| match i % 10 {
+            0 => None,
+            _ => Some(format!("value_{}", i)),
+        })
+        .collect::<Vec<_>>();
+    let b = b.iter().map(|v| v.as_deref()).collect::<Vec<_>>();
+
+    DataBlock::create(schema, vec![Series::from_data(a), Series::from_data(b)])
+}
+
+fn criterion_benchmark_block_checksum(c: &mut Criterion) {
+    let block = create_block(1_000_000);
+
+    c.bench_function("DataBlock::checksum 1000000 rows", |b| {
+        b.iter(|| block.checksum().unwrap())
+    });
+
+    // The baseline of the exchange path, the checksum should be modest against it.
+    c.bench_function("RecordBatch::try_from 1000000 rows", |b| {
+        b.iter(|| common_arrow::arrow::record_batch::RecordBatch::try_from(block.clone()).unwrap())
+    });
+}
+
+criterion_group!(benches, criterion_benchmark_block_checksum);
+criterion_main!(benches);
@@ -0,0 +1,99 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use common_datablocks::DataBlock;
+use common_datavalues2::prelude::*;
+use common_planners::add;
+use common_planners::col;
+use common_planners::lit;
+use common_planners::Expression;
+use criterion::criterion_group;
+use criterion::criterion_main;
+use criterion::Criterion;
+use databend_query::pipelines::transforms::ExpressionExecutor;
+
+fn create_blocks(schema: &DataSchemaRef, blocks: usize, rows: usize) -> Vec<DataBlock> {
+    (0..blocks)
+        .map(|block| {
+            let a = (0..rows)
+                .map(|i| match i % 2 {
+                    0 => None,
+                    _ => Some((block * rows + i) as u64),
+                })
+                .collect::<Vec<_>>();
+            let b = (0..rows)
+                .map(|i| match i % 3 {
+                    0 => None,
+                    _ => Some(i as u64),
+                })
+                .collect::<Vec<_>>();
+
+            DataBlock::create(schema.clone(), vec![
+                Series::from_data(a),
+                Series::from_data(b),
+            ])
+        })
+        .collect()
+}
+
+fn criterion_benchmark_expression_executor(c: &mut Criterion) {
+    let schema = DataSchemaRefExt::create(vec![
+        DataField::new_nullable("a", u64::to_data_type()),
+        DataField::new_nullable("b", u64::to_data_type()),
+    ]);
+    let expr = Expression::create_scalar_function("coalesce", vec![
+        col("a"),
+        add(col("b"), lit(1u8)),
+        lit(0u8),
+    ]);
+    let output_schema = DataSchemaRefExt::create(vec![expr.to_data_field(&schema).unwrap()]);
+    let create_executor = || {
+        ExpressionExecutor::try_create(
+            "bench",
+            schema.clone(),
+            output_schema.clone(),
+            vec![expr.clone()],
+            false,
+        )
+        .unwrap()
+    };
+
+    let blocks = create_blocks(&schema, 10_000, 8);
+
+    c.bench_function(
+        "ExpressionExecutor coalesce 10000 blocks, one executor",
+        |b| {
+            b.iter(|| {
+                let executor = create_executor();
+                for block in blocks.iter() {
+                    executor.execute(block).unwrap();
+                }
+            })
+        },
+    );
+
+    // The baseline compiles the expression for every block.
+    c.bench_function(
+        "ExpressionExecutor coalesce 10000 blocks, executor per block",
+        |b| {
+            b.iter(|| {
+                for block in blocks.iter() {
+                    create_executor().execute(block).unwrap();
+                }
+            })
+        },
+    );
+}
+
+criterion_group!(benches, criterion_benchmark_expression_executor);
+criterion_main!(benches);
@@ -22,6 +22,8 @@ fn criterion_benchmark_sort_query(c: &mut Criterion) {
     let queries = vec![
         "SELECT number FROM numbers_mt(10000000) ORDER BY number DESC LIMIT 10",
         "SELECT number FROM numbers_mt(10000000) ORDER BY number ASC LIMIT 10",
+        "SELECT number FROM numbers_mt(1000000) ORDER BY number DESC LIMIT 10",
+        "SELECT number FROM numbers_mt(1000000) ORDER BY number % 1000, number LIMIT 10",
     ];
 
     for query in queries {
@@ -23,6 +23,8 @@ use databend_query::sql::PlanParser;
 use futures::StreamExt;
 
 pub mod bench_aggregate_query_sql;
+pub mod bench_block_checksum;
+pub mod bench_expression_executor;
 pub mod bench_filter_query_sql;
 pub mod bench_limit_query_sql;
 pub mod bench_sort_query_sql;
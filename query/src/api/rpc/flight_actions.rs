@@ -28,6 +28,7 @@ pub struct ShuffleAction {
     pub plan: PlanNode,
     pub sinks: Vec<String>,
     pub scatters_expression: Expression,
+    pub checksum: bool,
 }
 
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug)]
@@ -36,6 +37,7 @@ pub struct BroadcastAction {
     pub stage_id: String,
     pub plan: PlanNode,
     pub sinks: Vec<String>,
+    pub checksum: bool,
 }
 
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug)]
@@ -162,6 +164,14 @@ impl FlightAction {
             _ => unimplemented!(),
         }
     }
+
+    pub fn get_checksum(&self) -> bool {
+        match self {
+            FlightAction::BroadcastAction(action) => action.checksum,
+            FlightAction::PrepareShuffleAction(action) => action.checksum,
+            _ => unimplemented!(),
+        }
+    }
 }
 
 impl TryInto<FlightAction> for Action {
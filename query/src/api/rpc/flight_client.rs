@@ -47,10 +47,15 @@ impl FlightClient {
         ticket: FlightTicket,
         schema: DataSchemaRef,
         timeout: u64,
+        verify_checksum: bool,
     ) -> Result<SendableDataBlockStream> {
         let ticket = ticket.try_into()?;
         let inner = self.do_get(ticket, timeout).await?;
-        Ok(Box::pin(FlightDataStream::from_remote(schema, inner)))
+        Ok(Box::pin(FlightDataStream::from_remote(
+            schema,
+            inner,
+            verify_checksum,
+        )))
     }
 
     pub async fn execute_action(&mut self, action: FlightAction, timeout: u64) -> Result<()> {
@@ -33,27 +33,12 @@ impl FlightDataStream {
     pub fn from_remote(
         schema: DataSchemaRef,
         inner: Streaming<FlightData>,
+        verify_checksum: bool,
     ) -> impl Stream<Item = Result<DataBlock, ErrorCode>> {
         inner.map(move |flight_data| -> Result<DataBlock, ErrorCode> {
             match flight_data {
                 Err(status) => Err(ErrorCode::UnknownException(status.message())),
-                Ok(flight_data) => {
-                    let arrow_schema = Arc::new(schema.to_arrow());
-                    let ipc_fields = common_arrow::arrow::io::ipc::write::default_ipc_fields(
-                        &arrow_schema.fields,
-                    );
-                    let ipc_schema = common_arrow::arrow::io::ipc::IpcSchema {
-                        fields: ipc_fields,
-                        is_little_endian: true,
-                    };
-                    let batch = deserialize_batch(
-                        &flight_data,
-                        arrow_schema,
-                        &ipc_schema,
-                        &Default::default(),
-                    )?;
-                    batch.try_into()
-                }
+                Ok(flight_data) => Self::deserialize_block(&schema, &flight_data, verify_checksum),
             }
         })
     }
@@ -64,26 +49,42 @@ impl FlightDataStream {
     pub fn from_receiver(
         schema: DataSchemaRef,
         inner: Receiver<Result<FlightData, ErrorCode>>,
+        verify_checksum: bool,
     ) -> impl Stream<Item = Result<DataBlock, ErrorCode>> {
         ReceiverStream::new(inner).map(move |flight_data| match flight_data {
             Err(error_code) => Err(error_code),
-            Ok(flight_data) => {
-                let arrow_schema = Arc::new(schema.to_arrow());
-                let ipc_fields =
-                    common_arrow::arrow::io::ipc::write::default_ipc_fields(&arrow_schema.fields);
-                let ipc_schema = common_arrow::arrow::io::ipc::IpcSchema {
-                    fields: ipc_fields,
-                    is_little_endian: true,
-                };
-
-                let batch = deserialize_batch(
-                    &flight_data,
-                    arrow_schema,
-                    &ipc_schema,
-                    &Default::default(),
-                )?;
-                batch.try_into()
-            }
+            Ok(flight_data) => Self::deserialize_block(&schema, &flight_data, verify_checksum),
         })
     }
+
+    pub fn deserialize_block(
+        schema: &DataSchemaRef,
+        flight_data: &FlightData,
+        verify_checksum: bool,
+    ) -> Result<DataBlock, ErrorCode> {
+        let arrow_schema = Arc::new(schema.to_arrow());
+        let ipc_fields =
+            common_arrow::arrow::io::ipc::write::default_ipc_fields(&arrow_schema.fields);
+        let ipc_schema = common_arrow::arrow::io::ipc::IpcSchema {
+            fields: ipc_fields,
+            is_little_endian: true,
+        };
+
+        let batch = deserialize_batch(flight_data, arrow_schema, &ipc_schema, &Default::default())?;
+        let block: DataBlock = batch.try_into()?;
+
+        // The checksum of the block is attached by the sender as the app metadata.
+        if verify_checksum {
+            let checksum =
+                <[u8; 8]>::try_from(flight_data.app_metadata.as_slice()).map_err(|_| {
+                    ErrorCode::ChecksumMismatch(format!(
+                        "Expect a checksum of 8 bytes in data exchange, but got {} bytes",
+                        flight_data.app_metadata.len()
+                    ))
+                })?;
+            block.verify_checksum(u64::from_le_bytes(checksum), "data exchange")?;
+        }
+
+        Ok(block)
+    }
 }
@@ -43,6 +43,7 @@ use crate::sessions::SessionRef;
 struct StreamInfo {
     #[allow(unused)]
     schema: DataSchemaRef,
+    checksum: bool,
     tx: mpsc::Sender<Result<DataBlock>>,
     rx: mpsc::Receiver<Result<DataBlock>>,
 }
@@ -77,7 +78,7 @@ impl DatabendQueryFlightDispatcher {
     pub fn get_stream(
         &self,
         ticket: &StreamTicket,
-    ) -> Result<(mpsc::Receiver<Result<DataBlock>>, DataSchemaRef)> {
+    ) -> Result<(mpsc::Receiver<Result<DataBlock>>, DataSchemaRef, bool)> {
         let stage_name = format!("{}/{}", ticket.query_id, ticket.stage_id);
         if let Some(notify) = self.stages_notify.write().remove(&stage_name) {
             notify.notify_waiters();
@@ -85,7 +86,7 @@ impl DatabendQueryFlightDispatcher {
 
         let stream_name = format!("{}/{}", stage_name, ticket.stream);
         match self.streams.write().remove(&stream_name) {
-            Some(stream_info) => Ok((stream_info.rx, stream_info.schema)),
+            Some(stream_info) => Ok((stream_info.rx, stream_info.schema, stream_info.checksum)),
             None => Err(ErrorCode::NotFoundStream("Stream is not found")),
         }
     }
@@ -96,7 +97,9 @@ impl DatabendQueryFlightDispatcher {
         let stage_id = action.get_stage_id();
         let action_sinks = action.get_sinks();
         let data_schema = action.get_plan().schema();
-        self.create_stage_streams(&query_id, &stage_id, &data_schema, &action_sinks);
+        let checksum = action.get_checksum();
+        Self::apply_exchange_checksum(&session, checksum)?;
+        self.create_stage_streams(&query_id, &stage_id, &data_schema, checksum, &action_sinks);
 
         match action.get_sinks().len() {
             0 => Err(ErrorCode::LogicalError("")),
@@ -114,7 +117,9 @@ impl DatabendQueryFlightDispatcher {
         let stage_id = action.get_stage_id();
         let action_sinks = action.get_sinks();
         let data_schema = action.get_plan().schema();
-        self.create_stage_streams(&query_id, &stage_id, &data_schema, &action_sinks);
+        let checksum = action.get_checksum();
+        Self::apply_exchange_checksum(&session, checksum)?;
+        self.create_stage_streams(&query_id, &stage_id, &data_schema, checksum, &action_sinks);
 
         match action.get_sinks().len() {
             0 => Err(ErrorCode::LogicalError("")),
@@ -143,6 +148,8 @@ impl DatabendQueryFlightDispatcher {
         assert_eq!(action_sinks.len(), 1);
         let stage_name = format!("{}/{}", action_query_id, action_stage_id);
         let stages_notify = self.stages_notify.clone();
+        let local_node = query_context.get_cluster().local_id();
+        tracing::info!("Executing stage {} on node {}", stage_name, local_node);
 
         let stream_name = format!("{}/{}", stage_name, action_sinks[0]);
         let tx_ref = self.streams.read().get(&stream_name).map(|x| x.tx.clone());
@@ -151,10 +158,16 @@ impl DatabendQueryFlightDispatcher {
         query_context.try_spawn(
             async move {
                 let _session = session;
-                wait_start(stage_name, stages_notify).await;
+                wait_start(stage_name.clone(), stages_notify).await;
 
                 match pipeline.execute().await {
                     Err(error) => {
+                        tracing::error!(
+                            "Stage {} failed on node {}, cause: {}",
+                            stage_name,
+                            local_node,
+                            error
+                        );
                         tx.send(Err(error)).await.ok();
                     }
                     Ok(mut abortable_stream) => {
@@ -219,6 +232,8 @@ impl DatabendQueryFlightDispatcher {
 
         let stage_name = format!("{}/{}", action_query_id, action_stage_id);
         let stages_notify = self.stages_notify.clone();
+        let local_node = query_context.get_cluster().local_id();
+        tracing::info!("Executing stage {} on node {}", stage_name, local_node);
 
         let flight_scatter = T::try_create(
             action.get_plan().schema(),
@@ -229,7 +244,7 @@ impl DatabendQueryFlightDispatcher {
         query_context.try_spawn(
             async move {
                 let _session = session;
-                wait_start(stage_name, stages_notify).await;
+                wait_start(stage_name.clone(), stages_notify).await;
 
                 let sinks_tx_ref = &sinks_tx;
                 let forward_blocks = async move {
@@ -253,6 +268,13 @@ impl DatabendQueryFlightDispatcher {
                 };
 
                 if let Err(error) = forward_blocks.await {
+                    tracing::error!(
+                        "Stage {} failed on node {}, cause: {}",
+                        stage_name,
+                        local_node,
+                        error
+                    );
+
                     for tx in &sinks_tx {
                         if !tx.is_closed() {
                             let send_error_message = tx.send(Err(error.clone()));
@@ -267,11 +289,22 @@ impl DatabendQueryFlightDispatcher {
         Ok(())
     }
 
+    // The stages of this action fetch from other nodes with the same setting as the query.
+    fn apply_exchange_checksum(session: &SessionRef, checksum: bool) -> Result<()> {
+        let value = if checksum { "1" } else { "0" };
+        session.get_settings().set_settings(
+            "enable_exchange_checksum".to_string(),
+            value.to_string(),
+            false,
+        )
+    }
+
     fn create_stage_streams(
         &self,
         query_id: &str,
         stage_id: &str,
         schema: &DataSchemaRef,
+        checksum: bool,
         streams_name: &[String],
     ) {
         let stage_name = format!("{}/{}", query_id, stage_id);
@@ -287,6 +320,7 @@ impl DatabendQueryFlightDispatcher {
 
             streams.insert(stream_name, StreamInfo {
                 schema: schema.clone(),
+                checksum,
                 tx,
                 rx,
             });
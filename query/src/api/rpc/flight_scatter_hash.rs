@@ -95,6 +95,7 @@ impl HashFlightScatter {
                     u64::to_data_type(),
                 ),
             ],
+            ordinal: None,
         }
     }
 }
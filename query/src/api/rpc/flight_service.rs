@@ -108,16 +108,17 @@ impl FlightService for DatabendQueryFlightService {
 
         match ticket {
             FlightTicket::StreamTicket(steam_ticket) => {
-                let (receiver, data_schema) = self.dispatcher.get_stream(&steam_ticket)?;
+                let (receiver, data_schema, checksum) =
+                    self.dispatcher.get_stream(&steam_ticket)?;
                 let arrow_schema = data_schema.to_arrow();
                 let ipc_fields = default_ipc_fields(arrow_schema.fields());
 
                 serialize_schema(&arrow_schema, &ipc_fields);
 
-                Ok(RawResponse::new(
-                    Box::pin(FlightDataStream::create(receiver, ipc_fields))
-                        as FlightStream<FlightData>,
+                Ok(RawResponse::new(Box::pin(FlightDataStream::create(
+                    receiver, ipc_fields, checksum,
                 ))
+                    as FlightStream<FlightData>))
             }
         }
     }
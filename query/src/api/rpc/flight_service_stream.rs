@@ -17,6 +17,7 @@ use std::convert::TryInto;
 use common_arrow::arrow::io::flight::serialize_batch;
 use common_arrow::arrow::io::ipc::write::WriteOptions;
 use common_arrow::arrow::io::ipc::IpcField;
+use common_arrow::arrow::record_batch::RecordBatch;
 use common_arrow::arrow_format::flight::data::FlightData;
 use common_base::tokio::macros::support::Pin;
 use common_base::tokio::macros::support::Poll;
@@ -30,19 +31,45 @@ pub struct FlightDataStream {
     input: Receiver<common_exception::Result<DataBlock>>,
     ipc_fields: Vec<IpcField>,
     options: WriteOptions,
+    checksum: bool,
 }
 
 impl FlightDataStream {
     pub fn create(
         input: Receiver<common_exception::Result<DataBlock>>,
         ipc_fields: Vec<IpcField>,
+        checksum: bool,
     ) -> FlightDataStream {
         FlightDataStream {
             input,
             ipc_fields,
             options: WriteOptions { compression: None },
+            checksum,
         }
     }
+
+    fn serialize_block(&self, block: DataBlock) -> Result<FlightData, Status> {
+        let checksum = match self.checksum {
+            true => Some(block.checksum()?),
+            false => None,
+        };
+
+        let record_batch: RecordBatch = block.try_into()?;
+        let (dicts, mut values) = serialize_batch(&record_batch, &self.ipc_fields, &self.options);
+
+        if !dicts.is_empty() {
+            return Err(Status::unimplemented(
+                "DatabendQuery does not implement dicts.",
+            ));
+        }
+
+        // The receiver verifies the deserialized block against the checksum.
+        if let Some(checksum) = checksum {
+            values.app_metadata = checksum.to_le_bytes().to_vec();
+        }
+
+        Ok(values)
+    }
 }
 
 impl Stream for FlightDataStream {
@@ -52,20 +79,7 @@ impl Stream for FlightDataStream {
         self.input.poll_recv(cx).map(|x| match x {
             None => None,
             Some(Err(error)) => Some(Err(Status::from(error))),
-            Some(Ok(block)) => match block.try_into() {
-                Err(error) => Some(Err(Status::from(error))),
-                Ok(record_batch) => {
-                    let (dicts, values) =
-                        serialize_batch(&record_batch, &self.ipc_fields, &self.options);
-
-                    match dicts.is_empty() {
-                        true => Some(Ok(values)),
-                        false => Some(Err(Status::unimplemented(
-                            "DatabendQuery does not implement dicts.",
-                        ))),
-                    }
-                }
-            },
+            Some(Ok(block)) => Some(self.serialize_block(block)),
         })
     }
 }
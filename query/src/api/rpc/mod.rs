@@ -17,6 +17,7 @@ pub use flight_actions::CancelAction;
 pub use flight_actions::FlightAction;
 pub use flight_actions::ShuffleAction;
 pub use flight_client::FlightClient;
+pub use flight_client_stream::FlightDataStream as FlightClientStream;
 pub use flight_dispatcher::DatabendQueryFlightDispatcher;
 pub use flight_service::DatabendQueryFlightService;
 pub use flight_tickets::FlightTicket;
@@ -28,6 +28,7 @@ use databend_query::metrics::MetricService;
 use databend_query::servers::ClickHouseHandler;
 use databend_query::servers::HttpHandler;
 use databend_query::servers::MySQLHandler;
+use databend_query::servers::PgHandler;
 use databend_query::servers::Server;
 use databend_query::servers::ShutdownHandle;
 use databend_query::sessions::SessionManager;
@@ -107,6 +108,24 @@ async fn main(_global_tracker: Arc<RuntimeTracker>) -> common_exception::Result<
             listening.port(),
         );
     }
+
+    // PostgreSQL handler.
+    {
+        let hostname = conf.query.pg_handler_host.clone();
+        let listening = format!("{}:{}", hostname, conf.query.pg_handler_port);
+
+        let mut srv = PgHandler::create(session_manager.clone());
+        let listening = srv.start(listening.parse()?).await?;
+        shutdown_handle.add_service(srv);
+
+        tracing::info!(
+            "PostgreSQL handler listening on {}, Usage: psql -U root -h {} -p {}",
+            listening,
+            listening.ip(),
+            listening.port(),
+        );
+    }
+
     // HTTP handler.
     {
         let hostname = conf.query.http_handler_host.clone();
@@ -90,6 +90,11 @@ pub trait Catalog: DynClone + Send + Sync {
 
     async fn drop_table(&self, req: DropTableReq) -> Result<DropTableReply>;
 
+    // Check a table is dropped, the instances created before may still be in use.
+    fn is_table_dropped(&self, _table_id: MetaId) -> bool {
+        false
+    }
+
     // Check a db.table is exists or not.
     async fn exists_table(&self, tenant: &str, db_name: &str, table_name: &str) -> Result<bool> {
         match self.get_table(tenant, db_name, table_name).await {
@@ -12,11 +12,13 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeSet;
 use std::sync::Arc;
 
 use common_datablocks::InMemoryData;
 use common_infallible::RwLock;
 use common_meta_api::MetaApi;
+use common_meta_types::MetaId;
 
 use crate::databases::DatabaseFactory;
 use crate::storages::StorageFactory;
@@ -27,4 +29,6 @@ pub struct CatalogContext {
     pub storage_factory: Arc<StorageFactory>,
     pub database_factory: Arc<DatabaseFactory>,
     pub in_memory_data: Arc<RwLock<InMemoryData<u64>>>,
+    // The latest tables dropped through this catalog, their instances may still be in use.
+    pub dropped_tables: Arc<RwLock<BTreeSet<MetaId>>>,
 }
@@ -268,6 +268,10 @@ impl Catalog for DatabaseCatalog {
         self.mutable_catalog.drop_table(req).await
     }
 
+    fn is_table_dropped(&self, table_id: MetaId) -> bool {
+        self.mutable_catalog.is_table_dropped(table_id)
+    }
+
     async fn upsert_table_option(
         &self,
         req: UpsertTableOptionReq,
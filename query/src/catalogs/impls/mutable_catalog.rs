@@ -51,6 +51,10 @@ use crate::storages::StorageDescription;
 use crate::storages::StorageFactory;
 use crate::storages::Table;
 
+// Table ids are increasing, so the oldest drops are forgotten first. The scans
+// of a table are long finished by then.
+const MAX_DROPPED_TABLES: usize = 1024;
+
 /// Catalog based on MetaStore
 /// - System Database NOT included
 /// - Meta data of databases are saved in meta store
@@ -118,6 +122,7 @@ impl MutableCatalog {
             storage_factory: Arc::new(storage_factory),
             database_factory: Arc::new(database_factory),
             in_memory_data: Arc::new(Default::default()),
+            dropped_tables: Arc::new(Default::default()),
         };
         Ok(MutableCatalog { ctx })
     }
@@ -232,10 +237,35 @@ impl Catalog for MutableCatalog {
     }
 
     async fn drop_table(&self, req: DropTableReq) -> Result<DropTableReply> {
+        let table_info = self
+            .ctx
+            .meta
+            .get_table(GetTableReq::new(&req.tenant, &req.db, &req.table))
+            .await
+            .ok();
         let res = self.ctx.meta.drop_table(req).await?;
+
+        // The running scans hold their own references to the in-memory data,
+        // so it is only freed once they are finished.
+        if let Some(table_info) = table_info {
+            let table_id = table_info.ident.table_id;
+            {
+                let mut dropped_tables = self.ctx.dropped_tables.write();
+                dropped_tables.insert(table_id);
+                if dropped_tables.len() > MAX_DROPPED_TABLES {
+                    let oldest = *dropped_tables.iter().next().unwrap();
+                    dropped_tables.remove(&oldest);
+                }
+            }
+            self.ctx.in_memory_data.write().remove(&table_id);
+        }
         Ok(res)
     }
 
+    fn is_table_dropped(&self, table_id: MetaId) -> bool {
+        self.ctx.dropped_tables.read().contains(&table_id)
+    }
+
     async fn upsert_table_option(
         &self,
         req: UpsertTableOptionReq,
@@ -27,6 +27,8 @@ pub const QUERY_MYSQL_HANDLER_PORT: &str = "QUERY_MYSQL_HANDLER_PORT";
 pub const QUERY_MAX_ACTIVE_SESSIONS: &str = "QUERY_MAX_ACTIVE_SESSIONS";
 pub const QUERY_CLICKHOUSE_HANDLER_HOST: &str = "QUERY_CLICKHOUSE_HANDLER_HOST";
 pub const QUERY_CLICKHOUSE_HANDLER_PORT: &str = "QUERY_CLICKHOUSE_HANDLER_PORT";
+pub const QUERY_PG_HANDLER_HOST: &str = "QUERY_PG_HANDLER_HOST";
+pub const QUERY_PG_HANDLER_PORT: &str = "QUERY_PG_HANDLER_PORT";
 pub const QUERY_HTTP_HANDLER_HOST: &str = "QUERY_HTTP_HANDLER_HOST";
 pub const QUERY_HTTP_HANDLER_PORT: &str = "QUERY_HTTP_HANDLER_PORT";
 pub const QUERY_HTTP_HANDLER_RESULT_TIMEOUT_MILLIS: &str =
@@ -96,6 +98,12 @@ pub struct QueryConfig {
     #[clap(long, env = QUERY_CLICKHOUSE_HANDLER_PORT, default_value = "9000")]
     pub clickhouse_handler_port: u16,
 
+    #[clap(long, env = QUERY_PG_HANDLER_HOST, default_value = "127.0.0.1")]
+    pub pg_handler_host: String,
+
+    #[clap(long, env = QUERY_PG_HANDLER_PORT, default_value = "5433")]
+    pub pg_handler_port: u16,
+
     #[clap(long, env = QUERY_HTTP_HANDLER_HOST, default_value = "127.0.0.1")]
     pub http_handler_host: String,
 
@@ -230,6 +238,8 @@ impl Default for QueryConfig {
             max_active_sessions: 256,
             clickhouse_handler_host: "127.0.0.1".to_string(),
             clickhouse_handler_port: 9000,
+            pg_handler_host: "127.0.0.1".to_string(),
+            pg_handler_port: 5433,
             http_handler_host: "127.0.0.1".to_string(),
             http_handler_port: 8000,
             http_handler_result_timeout_millis: 10000,
@@ -305,6 +315,20 @@ impl QueryConfig {
             u16,
             QUERY_CLICKHOUSE_HANDLER_PORT
         );
+        env_helper!(
+            mut_config,
+            query,
+            pg_handler_host,
+            String,
+            QUERY_PG_HANDLER_HOST
+        );
+        env_helper!(
+            mut_config,
+            query,
+            pg_handler_port,
+            u16,
+            QUERY_PG_HANDLER_PORT
+        );
         env_helper!(
             mut_config,
             query,
@@ -17,6 +17,25 @@ use common_datavalues2::DataSchemaRefExt;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_streams::SendableDataBlockStream;
+use futures::TryStreamExt;
+
+/// What a statement produces, so that every protocol handler dispatches the same way.
+pub enum InterpreterResult {
+    /// Rows with their schema, such as SELECT, SHOW and DESCRIBE.
+    ResultSet(DataSchemaRef, SendableDataBlockStream),
+    /// Number of rows written, such as INSERT.
+    Affected(usize),
+    /// Nothing but the side effects, such as DDL and SET.
+    Empty,
+}
+
+impl InterpreterResult {
+    /// Drain the stream for its side effects.
+    pub async fn empty(stream: SendableDataBlockStream) -> Result<InterpreterResult> {
+        stream.try_collect::<Vec<_>>().await?;
+        Ok(InterpreterResult::Empty)
+    }
+}
 
 #[async_trait::async_trait]
 pub trait Interpreter: Sync + Send {
@@ -31,6 +50,20 @@ pub trait Interpreter: Sync + Send {
         input_stream: Option<SendableDataBlockStream>,
     ) -> Result<SendableDataBlockStream>;
 
+    /// Execute and classify the result.
+    /// An interpreter with a result schema produces rows, the others only have side effects.
+    async fn execute_result(
+        &self,
+        input_stream: Option<SendableDataBlockStream>,
+    ) -> Result<InterpreterResult> {
+        let schema = self.schema();
+        let stream = self.execute(input_stream).await?;
+        match schema.fields().is_empty() {
+            true => InterpreterResult::empty(stream).await,
+            false => Ok(InterpreterResult::ResultSet(schema, stream)),
+        }
+    }
+
     /// Do some start work for the interpreter.
     async fn start(&self) -> Result<()> {
         Err(ErrorCode::UnImplement(format!(
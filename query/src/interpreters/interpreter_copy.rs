@@ -35,6 +35,7 @@ use opendal::Operator as DalOperator;
 
 use crate::interpreters::Interpreter;
 use crate::interpreters::InterpreterPtr;
+use crate::interpreters::InterpreterResult;
 use crate::sessions::QueryContext;
 
 pub struct CopyInterpreter {
@@ -107,6 +108,17 @@ impl Interpreter for CopyInterpreter {
             vec![],
         )))
     }
+
+    async fn execute_result(
+        &self,
+        input_stream: Option<SendableDataBlockStream>,
+    ) -> Result<InterpreterResult> {
+        let dal_ctx = self.ctx.get_dal_context();
+        let written_rows = dal_ctx.get_metrics().write_rows;
+        self.execute(input_stream).await?;
+        let affected_rows = dal_ctx.get_metrics().write_rows - written_rows;
+        Ok(InterpreterResult::Affected(affected_rows))
+    }
 }
 
 /// @my_ext_stage/tutorials/sample.csv -> stage: my_ext_stage,  location: /tutorials/sample.csv
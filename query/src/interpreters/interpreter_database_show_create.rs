@@ -46,6 +46,10 @@ impl Interpreter for ShowCreateDatabaseInterpreter {
         "ShowCreateDatabaseInterpreter"
     }
 
+    fn schema(&self) -> DataSchemaRef {
+        self.plan.schema()
+    }
+
     async fn execute(
         &self,
         _input_stream: Option<SendableDataBlockStream>,
@@ -69,7 +73,7 @@ impl Interpreter for ShowCreateDatabaseInterpreter {
                 info.push_str(&engine);
             }
         }
-        let schema = self.plan.schema();
+        let schema = self.schema();
         let block = DataBlock::create(schema.clone(), vec![
             Series::from_data(vec![name.as_bytes()]),
             Series::from_data(vec![info.into_bytes()]),
@@ -19,14 +19,17 @@ use common_datavalues2::prelude::*;
 use common_exception::Result;
 use common_planners::ExplainPlan;
 use common_planners::ExplainType;
+use common_planners::PlanNode;
 use common_streams::DataBlockStream;
 use common_streams::SendableDataBlockStream;
+use futures::TryStreamExt;
 
 use crate::interpreters::plan_schedulers;
 use crate::interpreters::Interpreter;
 use crate::interpreters::InterpreterPtr;
 use crate::optimizers::Optimizers;
 use crate::pipelines::processors::PipelineBuilder;
+use crate::pipelines::transforms::PlanProfiling;
 use crate::sessions::QueryContext;
 
 pub struct ExplainInterpreter {
@@ -50,6 +53,9 @@ impl Interpreter for ExplainInterpreter {
             ExplainType::Graph => self.explain_graph(),
             ExplainType::Syntax => self.explain_syntax(),
             ExplainType::Pipeline => self.explain_pipeline(),
+            ExplainType::Optimized => self.explain_optimized(),
+            ExplainType::WithTypes => self.explain_with_types(),
+            ExplainType::Analyze => self.explain_analyze().await,
         }?;
 
         Ok(Box::pin(DataBlockStream::create(schema, None, vec![block])))
@@ -95,6 +101,36 @@ impl ExplainInterpreter {
         Ok(DataBlock::create(schema, vec![formatted_plan]))
     }
 
+    /// The plan after the optimizers without the distributed stages,
+    /// e.g. the expressions are shown after constant folding.
+    fn explain_optimized(&self) -> Result<DataBlock> {
+        let schema = self.schema();
+        let optimizer = Optimizers::without_scatters(self.ctx.clone());
+        let plan = plan_schedulers::apply_plan_rewrite(optimizer, &self.explain.input)?;
+        let formatted_plan = Series::from_data(
+            format!("{:?}", plan)
+                .lines()
+                .map(|s| s.as_bytes())
+                .collect::<Vec<_>>(),
+        );
+        Ok(DataBlock::create(schema, vec![formatted_plan]))
+    }
+
+    /// Like the syntax explain, but each expression is annotated with its inferred
+    /// type, e.g. `sum((number + 1)):UInt64 NOT NULL`.
+    fn explain_with_types(&self) -> Result<DataBlock> {
+        let schema = self.schema();
+        let optimizer = Optimizers::without_scatters(self.ctx.clone());
+        let plan = plan_schedulers::apply_plan_rewrite(optimizer, &self.explain.input)?;
+        let formatted_plan = Series::from_data(
+            format!("{}", plan.display_indent_format_with_types())
+                .lines()
+                .map(|s| s.as_bytes())
+                .collect::<Vec<_>>(),
+        );
+        Ok(DataBlock::create(schema, vec![formatted_plan]))
+    }
+
     fn explain_pipeline(&self) -> Result<DataBlock> {
         let schema = self.schema();
         let optimizer = Optimizers::without_scatters(self.ctx.clone());
@@ -102,12 +138,65 @@ impl ExplainInterpreter {
 
         let pipeline_builder = PipelineBuilder::create(self.ctx.clone());
         let pipeline = pipeline_builder.build(&plan)?;
+        let formatted_pipeline = format!("{}{:?}", self.query_settings_header()?, pipeline);
         let formatted_pipeline = Series::from_data(
-            format!("{:?}", pipeline)
+            formatted_pipeline
                 .lines()
                 .map(|s| s.as_bytes())
                 .collect::<Vec<_>>(),
         );
         Ok(DataBlock::create(schema, vec![formatted_pipeline]))
     }
+
+    /// The effective values of the settings overridden by the `SETTINGS` clause of the query,
+    /// e.g. `Settings: max_threads = 1`, empty without the clause.
+    fn query_settings_header(&self) -> Result<String> {
+        let names = self.ctx.get_query_settings_names();
+        if names.is_empty() {
+            return Ok(String::new());
+        }
+
+        let settings = self.ctx.get_settings();
+        let values = names
+            .iter()
+            .map(|name| Ok(format!("{} = {}", name, settings.get_setting_value(name)?)))
+            .collect::<Result<Vec<_>>>()?;
+        Ok(format!("Settings: {}\n", values.join(", ")))
+    }
+
+    /// Execute the plan and annotate each plan node with the rows and bytes
+    /// it produced and the wall time spent until its output was drained.
+    async fn explain_analyze(&self) -> Result<DataBlock> {
+        let schema = self.schema();
+        let optimizer = Optimizers::without_scatters(self.ctx.clone());
+        let plan = plan_schedulers::apply_plan_rewrite(optimizer, &self.explain.input)?;
+
+        let profiling = PlanProfiling::create();
+        let pipeline_builder =
+            PipelineBuilder::create(self.ctx.clone()).with_profiling(profiling.clone());
+        let mut pipeline = pipeline_builder.build(&plan)?;
+        let stream = pipeline.execute().await?;
+        stream.try_collect::<Vec<_>>().await?;
+
+        let annotation = |node: &PlanNode| {
+            profiling.get(node).map(|metrics| {
+                format!(
+                    "rows: {}, bytes: {}, elapsed: {:?}",
+                    metrics.rows(),
+                    metrics.bytes(),
+                    metrics.elapsed()
+                )
+            })
+        };
+        let formatted_plan = Series::from_data(
+            format!(
+                "{}",
+                plan.display_indent_format_with_annotation(&annotation)
+            )
+            .lines()
+            .map(|s| s.as_bytes())
+            .collect::<Vec<_>>(),
+        );
+        Ok(DataBlock::create(schema, vec![formatted_plan]))
+    }
 }
@@ -53,6 +53,7 @@ use crate::interpreters::ShowGrantsInterpreter;
 use crate::interpreters::ShowMetricsInterpreter;
 use crate::interpreters::ShowProcessListInterpreter;
 use crate::interpreters::ShowSettingsInterpreter;
+use crate::interpreters::ShowTableStatusInterpreter;
 use crate::interpreters::ShowTablesInterpreter;
 use crate::interpreters::ShowUsersInterpreter;
 use crate::interpreters::TruncateTableInterpreter;
@@ -85,6 +86,9 @@ impl InterpreterFactory {
             PlanNode::Show(ShowPlan::ShowTables(v)) => {
                 ShowTablesInterpreter::try_create(ctx_clone, v)
             }
+            PlanNode::Show(ShowPlan::ShowTableStatus(v)) => {
+                ShowTableStatusInterpreter::try_create(ctx_clone, v)
+            }
             PlanNode::Show(ShowPlan::ShowEngines(v)) => {
                 ShowEnginesInterpreter::try_create(ctx_clone, v)
             }
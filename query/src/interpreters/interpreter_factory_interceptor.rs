@@ -23,6 +23,7 @@ use crate::interpreters::access::ManagementModeAccess;
 use crate::interpreters::Interpreter;
 use crate::interpreters::InterpreterPtr;
 use crate::interpreters::InterpreterQueryLog;
+use crate::interpreters::InterpreterResult;
 use crate::sessions::QueryContext;
 
 pub struct InterceptorInterpreter {
@@ -64,6 +65,26 @@ impl Interpreter for InterceptorInterpreter {
         Ok(Box::pin(metric_stream))
     }
 
+    async fn execute_result(
+        &self,
+        input_stream: Option<SendableDataBlockStream>,
+    ) -> Result<InterpreterResult> {
+        // Management mode access check.
+        self.management_mode_access.check(&self.plan)?;
+
+        match self.inner.execute_result(input_stream).await? {
+            InterpreterResult::ResultSet(schema, result_stream) => {
+                let metric_stream =
+                    ProgressStream::try_create(result_stream, self.ctx.get_result_progress())?;
+                Ok(InterpreterResult::ResultSet(
+                    schema,
+                    Box::pin(metric_stream),
+                ))
+            }
+            result => Ok(result),
+        }
+    }
+
     async fn start(&self) -> Result<()> {
         self.query_log.log_start().await
     }
@@ -29,6 +29,7 @@ use crate::interpreters::interpreter_insert_with_stream::InsertWithStream;
 use crate::interpreters::plan_schedulers::InsertWithPlan;
 use crate::interpreters::Interpreter;
 use crate::interpreters::InterpreterPtr;
+use crate::interpreters::InterpreterResult;
 use crate::pipelines::transforms::AddOnStream;
 use crate::sessions::QueryContext;
 
@@ -124,4 +125,17 @@ impl Interpreter for InsertInterpreter {
             vec![],
         )))
     }
+
+    async fn execute_result(
+        &self,
+        input_stream: Option<SendableDataBlockStream>,
+    ) -> Result<InterpreterResult> {
+        // The insertion is committed by this node in cluster mode too, with the
+        // operation logs of every node, so the written rows are counted here.
+        let dal_ctx = self.ctx.get_dal_context();
+        let written_rows = dal_ctx.get_metrics().write_rows;
+        self.execute(input_stream).await?;
+        let affected_rows = dal_ctx.get_metrics().write_rows - written_rows;
+        Ok(InterpreterResult::Affected(affected_rows))
+    }
 }
@@ -17,6 +17,7 @@ use std::sync::Arc;
 
 use common_datablocks::DataBlock;
 use common_datavalues2::prelude::*;
+use common_exception::ErrorCode;
 use common_exception::Result;
 use common_planners::Expression;
 use common_streams::ProgressStream;
@@ -62,14 +63,26 @@ impl SendableWithSchema for &[Vec<Expression>] {
             .as_ref()
             .iter()
             .map(|exprs| {
-                let executor = ExpressionExecutor::try_create(
-                    "Insert into from values",
-                    dummy.clone(),
-                    schema.clone(),
-                    exprs.clone(),
-                    true,
-                )?;
-                executor.execute(&one_row_block)
+                // Evaluate the columns one by one, so the errors can tell which column is invalid.
+                let columns = exprs
+                    .iter()
+                    .zip(schema.fields())
+                    .map(|(expr, field)| {
+                        let output_schema = DataSchemaRefExt::create(vec![field.clone()]);
+                        ExpressionExecutor::try_create(
+                            "Insert into from values",
+                            dummy.clone(),
+                            output_schema,
+                            vec![expr.clone()],
+                            true,
+                        )
+                        .and_then(|executor| executor.execute(&one_row_block))
+                        .and_then(|block| non_nullable_column(block.column(0), field))
+                        .map_err(|e| e.add_message_back(format!(", column: {}", field.name())))
+                    })
+                    .collect::<common_exception::Result<Vec<_>>>()?;
+
+                DataBlock::try_create(schema.clone(), columns)
             })
             .collect::<common_exception::Result<Vec<_>>>()?;
 
@@ -79,3 +92,19 @@ impl SendableWithSchema for &[Vec<Expression>] {
         Ok(stream)
     }
 }
+
+/// The casts from strings into dates return NULL for the invalid dates, the NULL can't
+/// be inserted into the non-nullable columns.
+fn non_nullable_column(column: &ColumnRef, field: &DataField) -> Result<ColumnRef> {
+    if field.is_nullable() || !column.is_nullable() {
+        return Ok(column.clone());
+    }
+
+    match column.null_at(0) {
+        true => Err(ErrorCode::BadDataValueType(format!(
+            "Can not insert NULL into non-nullable column {}",
+            field.name()
+        ))),
+        false => Ok(Series::remove_nullable(column)),
+    }
+}
@@ -19,6 +19,7 @@ use std::time::UNIX_EPOCH;
 use common_datablocks::DataBlock;
 use common_datavalues2::prelude::Series;
 use common_datavalues2::prelude::SeriesFrom;
+use common_exception::ErrorCode;
 use common_exception::Result;
 use common_planners::PlanNode;
 
@@ -161,6 +162,21 @@ impl InterpreterQueryLog {
     }
 
     pub async fn log_start(&self) -> Result<()> {
+        let log_event = self.start_event()?;
+        self.write_log(&log_event).await
+    }
+
+    /// Log an error which is not returned to the client, e.g. ignored by the `ignore_error` hint.
+    pub async fn log_error(&self, error: &ErrorCode) -> Result<()> {
+        let mut log_event = self.start_event()?;
+        log_event.log_type = LogType::Error;
+        log_event.exception_code = error.code() as i32;
+        log_event.exception = error.message();
+        log_event.stack_trace = error.backtrace_str();
+        self.write_log(&log_event).await
+    }
+
+    fn start_event(&self) -> Result<LogEvent> {
         // User.
         let handler_type = self.ctx.get_current_session().get_type();
         let tenant_id = self.ctx.get_tenant();
@@ -243,7 +259,7 @@ impl InterpreterQueryLog {
             extra: "".to_string(),
         };
 
-        self.write_log(&log_event).await
+        Ok(log_event)
     }
 
     pub async fn log_finish(&self) -> Result<()> {
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
 use std::pin::Pin;
 use std::sync::Arc;
 use std::task::Context;
@@ -21,7 +22,10 @@ use common_datablocks::DataBlock;
 use common_datavalues2::DataSchemaRef;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_planners::Expression;
+use common_planners::ExpressionRewriter;
 use common_planners::PlanNode;
+use common_planners::PlanRewriter;
 use common_planners::SelectPlan;
 use common_streams::SendableDataBlockStream;
 use common_tracing::tracing;
@@ -33,6 +37,7 @@ use crate::interpreters::InterpreterPtr;
 use crate::optimizers::Optimizers;
 use crate::pipelines::new::executor::PipelinePullingExecutor;
 use crate::pipelines::new::QueryPipelineBuilder;
+use crate::pipelines::transforms::receive_scalar_subquery_value;
 use crate::sessions::QueryContext;
 
 pub struct SelectInterpreter {
@@ -45,11 +50,104 @@ impl SelectInterpreter {
         Ok(Arc::new(SelectInterpreter { ctx, select }))
     }
 
-    fn rewrite_plan(&self) -> Result<PlanNode> {
-        plan_schedulers::apply_plan_rewrite(
-            Optimizers::create(self.ctx.clone()),
-            &self.select.input,
-        )
+    // Reject the pathological plans before anything is scheduled.
+    fn check_plan_complexity(&self) -> Result<()> {
+        let settings = self.ctx.get_settings();
+        let stats = self.select.input.analyze()?;
+
+        let max_plan_depth = settings.get_max_plan_depth()? as usize;
+        if stats.depth > max_plan_depth {
+            return Err(ErrorCode::QueryTooComplex(format!(
+                "The depth of the query plan is {}, which exceeds max_plan_depth {}",
+                stats.depth, max_plan_depth
+            )));
+        }
+
+        let max_expression_nodes = settings.get_max_expression_nodes()? as usize;
+        if stats.expression_nodes > max_expression_nodes {
+            return Err(ErrorCode::QueryTooComplex(format!(
+                "The query plan has {} expression nodes, which exceeds max_expression_nodes {}",
+                stats.expression_nodes, max_expression_nodes
+            )));
+        }
+
+        Ok(())
+    }
+
+    fn rewrite_plan(&self, select: &SelectPlan) -> Result<PlanNode> {
+        plan_schedulers::apply_plan_rewrite(Optimizers::create(self.ctx.clone()), &select.input)
+    }
+
+    // The subqueries are uncorrelated, so each scalar subquery is evaluated once up front
+    // and substituted by its value.
+    async fn substitute_scalar_subqueries(&self) -> Result<SelectPlan> {
+        let mut rewriter = ScalarSubqueriesRewriter::default();
+        rewriter.rewrite_plan_node(&self.select.input)?;
+
+        if rewriter.subqueries.is_empty() {
+            return Ok(self.select.clone());
+        }
+
+        for (name, query_plan) in std::mem::take(&mut rewriter.subqueries) {
+            let subquery_ctx = QueryContext::create_from(self.ctx.clone());
+            let subquery = match query_plan.as_ref() {
+                PlanNode::Select(plan) => plan.clone(),
+                plan => SelectPlan {
+                    input: Arc::new(plan.clone()),
+                },
+            };
+
+            let interpreter = SelectInterpreter::try_create(subquery_ctx, subquery)?;
+            let value = receive_scalar_subquery_value(interpreter.execute(None).await?).await?;
+            let literal = Expression::Literal {
+                value,
+                column_name: Some(name.clone()),
+                data_type: Expression::to_scalar_subquery_type(&query_plan),
+            };
+            rewriter.values.insert(name, literal);
+        }
+
+        Ok(SelectPlan {
+            input: Arc::new(rewriter.rewrite_plan_node(&self.select.input)?),
+        })
+    }
+}
+
+/// Collect the scalar subqueries of the plan, and replace the evaluated ones with their values.
+/// The scalar subqueries inside the subqueries are left to the subquery interpreters.
+#[derive(Default)]
+struct ScalarSubqueriesRewriter {
+    subqueries: Vec<(String, Arc<PlanNode>)>,
+    values: HashMap<String, Expression>,
+}
+
+impl PlanRewriter for ScalarSubqueriesRewriter {
+    fn rewrite_expr(&mut self, _schema: &DataSchemaRef, expr: &Expression) -> Result<Expression> {
+        struct SubstituteScalarSubquery<'a>(&'a mut ScalarSubqueriesRewriter);
+
+        impl<'a> ExpressionRewriter for SubstituteScalarSubquery<'a> {
+            fn mutate_scalar_subquery(
+                &mut self,
+                name: &str,
+                subquery: &Arc<PlanNode>,
+                _origin_expr: &Expression,
+            ) -> Result<Expression> {
+                if let Some(value) = self.0.values.get(name) {
+                    return Ok(value.clone());
+                }
+
+                if !self.0.subqueries.iter().any(|(v, _)| v == name) {
+                    self.0.subqueries.push((name.to_string(), subquery.clone()));
+                }
+
+                Ok(Expression::ScalarSubquery {
+                    name: name.to_string(),
+                    query_plan: subquery.clone(),
+                })
+            }
+        }
+
+        SubstituteScalarSubquery(self).mutate(expr)
     }
 }
 
@@ -68,6 +166,9 @@ impl Interpreter for SelectInterpreter {
         &self,
         _input_stream: Option<SendableDataBlockStream>,
     ) -> Result<SendableDataBlockStream> {
+        self.check_plan_complexity()?;
+        let select = self.substitute_scalar_subqueries().await?;
+
         // TODO: maybe panic?
         let settings = self.ctx.get_settings();
 
@@ -79,13 +180,13 @@ impl Interpreter for SelectInterpreter {
             }
 
             let builder = QueryPipelineBuilder::create(self.ctx.clone());
-            let mut new_pipeline = builder.finalize(&self.select)?;
+            let mut new_pipeline = builder.finalize(&select)?;
             new_pipeline.set_max_threads(settings.get_max_threads()? as usize);
             let executor = PipelinePullingExecutor::try_create(new_pipeline)?;
 
             Ok(Box::pin(NewProcessorStreamWrap::create(executor)?))
         } else {
-            let optimized_plan = self.rewrite_plan()?;
+            let optimized_plan = self.rewrite_plan(&select)?;
             plan_schedulers::schedule_query(&self.ctx, &optimized_plan).await
         }
     }
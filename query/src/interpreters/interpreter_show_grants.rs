@@ -42,11 +42,15 @@ impl Interpreter for ShowGrantsInterpreter {
         "ShowGrantsInterpreter"
     }
 
+    fn schema(&self) -> DataSchemaRef {
+        DataSchemaRefExt::create(vec![DataField::new("Grants", Vu8::to_data_type())])
+    }
+
     async fn execute(
         &self,
         _input_stream: Option<SendableDataBlockStream>,
     ) -> Result<SendableDataBlockStream> {
-        let schema = DataSchemaRefExt::create(vec![DataField::new("Grants", Vu8::to_data_type())]);
+        let schema = self.schema();
 
         // TODO: add permission check on reading user grants
         let user_info = match self.plan.user_identity {
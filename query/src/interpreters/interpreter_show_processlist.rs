@@ -22,6 +22,7 @@ use common_streams::SendableDataBlockStream;
 
 use crate::interpreters::Interpreter;
 use crate::interpreters::InterpreterPtr;
+use crate::interpreters::InterpreterResult;
 use crate::interpreters::SelectInterpreter;
 use crate::optimizers::Optimizers;
 use crate::sessions::QueryContext;
@@ -44,6 +45,20 @@ impl ShowProcessListInterpreter {
     fn build_query(&self) -> Result<String> {
         Ok("SELECT * FROM system.processes".to_string())
     }
+
+    async fn select_interpreter(&self) -> Result<InterpreterPtr> {
+        let query = self.build_query()?;
+        let plan = PlanParser::parse(self.ctx.clone(), &query).await?;
+        let optimized = Optimizers::create(self.ctx.clone()).optimize(&plan)?;
+
+        if let PlanNode::Select(plan) = optimized {
+            SelectInterpreter::try_create(self.ctx.clone(), plan)
+        } else {
+            Err(ErrorCode::LogicalError(
+                "Show processlist build query error",
+            ))
+        }
+    }
 }
 
 #[async_trait::async_trait]
@@ -56,17 +71,16 @@ impl Interpreter for ShowProcessListInterpreter {
         &self,
         input_stream: Option<SendableDataBlockStream>,
     ) -> Result<SendableDataBlockStream> {
-        let query = self.build_query()?;
-        let plan = PlanParser::parse(self.ctx.clone(), &query).await?;
-        let optimized = Optimizers::create(self.ctx.clone()).optimize(&plan)?;
+        self.select_interpreter().await?.execute(input_stream).await
+    }
 
-        if let PlanNode::Select(plan) = optimized {
-            let interpreter = SelectInterpreter::try_create(self.ctx.clone(), plan)?;
-            interpreter.execute(input_stream).await
-        } else {
-            return Err(ErrorCode::LogicalError(
-                "Show processlist build query error",
-            ));
-        }
+    async fn execute_result(
+        &self,
+        input_stream: Option<SendableDataBlockStream>,
+    ) -> Result<InterpreterResult> {
+        self.select_interpreter()
+            .await?
+            .execute_result(input_stream)
+            .await
     }
 }
@@ -0,0 +1,107 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_planners::PlanNode;
+use common_planners::PlanShowKind;
+use common_planners::ShowTableStatusPlan;
+use common_streams::SendableDataBlockStream;
+
+use crate::interpreters::Interpreter;
+use crate::interpreters::InterpreterPtr;
+use crate::interpreters::InterpreterResult;
+use crate::interpreters::SelectInterpreter;
+use crate::optimizers::Optimizers;
+use crate::sessions::QueryContext;
+use crate::sql::PlanParser;
+
+pub struct ShowTableStatusInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: ShowTableStatusPlan,
+}
+
+impl ShowTableStatusInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: ShowTableStatusPlan) -> Result<InterpreterPtr> {
+        Ok(Arc::new(ShowTableStatusInterpreter { ctx, plan }))
+    }
+
+    fn build_query(&self) -> Result<String> {
+        // Column names follow MySQL SHOW TABLE STATUS.
+        let select = "SELECT name AS Name, engine AS Engine, num_rows AS Rows, \
+            data_bytes AS Data_length, created_on AS Create_time FROM system.tables";
+
+        // The database is a string literal in the query, so the quotes must be escaped.
+        let database = match &self.plan.kind {
+            PlanShowKind::FromOrIn(v) => v.clone(),
+            _ => self.ctx.get_current_database(),
+        }
+        .replace('\'', "''");
+
+        return match &self.plan.kind {
+            PlanShowKind::All | PlanShowKind::FromOrIn(_) => Ok(format!(
+                "{} WHERE database = '{}' ORDER BY name",
+                select, database
+            )),
+            PlanShowKind::Like(v) => Ok(format!(
+                "{} WHERE database = '{}' AND name LIKE {} ORDER BY name",
+                select, database, v
+            )),
+            PlanShowKind::Where(v) => Ok(format!(
+                "{} WHERE database = '{}' AND ({}) ORDER BY name",
+                select, database, v
+            )),
+        };
+    }
+
+    async fn select_interpreter(&self) -> Result<InterpreterPtr> {
+        let query = self.build_query()?;
+        let plan = PlanParser::parse(self.ctx.clone(), &query).await?;
+        let optimized = Optimizers::create(self.ctx.clone()).optimize(&plan)?;
+
+        if let PlanNode::Select(plan) = optimized {
+            SelectInterpreter::try_create(self.ctx.clone(), plan)
+        } else {
+            Err(ErrorCode::LogicalError(
+                "Show table status build query error",
+            ))
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for ShowTableStatusInterpreter {
+    fn name(&self) -> &str {
+        "ShowTableStatusInterpreter"
+    }
+
+    async fn execute(
+        &self,
+        input_stream: Option<SendableDataBlockStream>,
+    ) -> Result<SendableDataBlockStream> {
+        self.select_interpreter().await?.execute(input_stream).await
+    }
+
+    async fn execute_result(
+        &self,
+        input_stream: Option<SendableDataBlockStream>,
+    ) -> Result<InterpreterResult> {
+        self.select_interpreter()
+            .await?
+            .execute_result(input_stream)
+            .await
+    }
+}
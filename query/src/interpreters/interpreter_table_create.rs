@@ -61,6 +61,8 @@ impl Interpreter for CreateTableInterpreter {
             )
             .await?;
 
+        self.check_table_options()?;
+
         match &self.plan.as_select {
             Some(select_plan_node) => {
                 self.create_table_as_select(input_stream, select_plan_node.clone())
@@ -72,6 +74,16 @@ impl Interpreter for CreateTableInterpreter {
 }
 
 impl CreateTableInterpreter {
+    fn check_table_options(&self) -> Result<()> {
+        let engine = self.plan.table_meta.engine.to_uppercase();
+        let engines = self.ctx.get_catalog().get_table_engines();
+        match engines.iter().find(|desc| desc.engine_name == engine) {
+            Some(desc) => desc.check_table_options(&self.plan.table_meta.options),
+            // Unknown engines are rejected by the catalog.
+            None => Ok(()),
+        }
+    }
+
     async fn create_table_as_select(
         &self,
         input_stream: Option<SendableDataBlockStream>,
@@ -80,6 +92,19 @@ impl CreateTableInterpreter {
         let tenant = self.ctx.get_tenant();
         let catalog = self.ctx.get_catalog();
 
+        // For 'CREATE TABLE IF NOT EXISTS .. AS SELECT', the existing table must stay untouched.
+        if self.plan.if_not_exists
+            && catalog
+                .exists_table(tenant.as_str(), &self.plan.db, &self.plan.table)
+                .await?
+        {
+            return Ok(Box::pin(DataBlockStream::create(
+                self.plan.schema(),
+                None,
+                vec![],
+            )));
+        }
+
         // TODO: maybe the table creation and insertion should be a transaction, but it may require create_table support 2pc.
         catalog.create_table(self.plan.clone().into()).await?;
         let table = catalog
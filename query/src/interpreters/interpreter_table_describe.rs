@@ -42,6 +42,10 @@ impl Interpreter for DescribeTableInterpreter {
         "DescribeTableInterpreter"
     }
 
+    fn schema(&self) -> DataSchemaRef {
+        self.plan.schema()
+    }
+
     async fn execute(
         &self,
         _input_stream: Option<SendableDataBlockStream>,
@@ -54,6 +58,7 @@ impl Interpreter for DescribeTableInterpreter {
         let mut names: Vec<String> = vec![];
         let mut types: Vec<String> = vec![];
         let mut nulls: Vec<String> = vec![];
+        let mut comments: Vec<String> = vec![];
         for field in schema.fields().iter() {
             names.push(field.name().to_string());
             types.push(format!("{:?}", remove_nullable(field.data_type())));
@@ -62,17 +67,20 @@ impl Interpreter for DescribeTableInterpreter {
             } else {
                 "NO".to_string()
             });
+            comments.push(field.comment().clone().unwrap_or_default());
         }
         let names: Vec<&[u8]> = names.iter().map(|x| x.as_bytes()).collect();
         let types: Vec<&[u8]> = types.iter().map(|x| x.as_bytes()).collect();
         let nulls: Vec<&[u8]> = nulls.iter().map(|x| x.as_bytes()).collect();
+        let comments: Vec<&[u8]> = comments.iter().map(|x| x.as_bytes()).collect();
 
-        let desc_schema = self.plan.schema();
+        let desc_schema = self.schema();
 
         let block = DataBlock::create(desc_schema.clone(), vec![
             Series::from_data(names),
             Series::from_data(types),
             Series::from_data(nulls),
+            Series::from_data(comments),
         ]);
 
         Ok(Box::pin(DataBlockStream::create(desc_schema, None, vec![
@@ -26,6 +26,7 @@ use crate::catalogs::Catalog;
 use crate::interpreters::Interpreter;
 use crate::interpreters::InterpreterPtr;
 use crate::sessions::QueryContext;
+use crate::storages::fuse::TBL_OPT_KEY_SNAPSHOT_LOC;
 
 pub struct ShowCreateTableInterpreter {
     ctx: Arc<QueryContext>,
@@ -44,6 +45,13 @@ impl Interpreter for ShowCreateTableInterpreter {
         "ShowCreateTableInterpreter"
     }
 
+    fn schema(&self) -> DataSchemaRef {
+        DataSchemaRefExt::create(vec![
+            DataField::new("Table", Vu8::to_data_type()),
+            DataField::new("Create Table", Vu8::to_data_type()),
+        ])
+    }
+
     async fn execute(
         &self,
         _input_stream: Option<SendableDataBlockStream>,
@@ -61,30 +69,33 @@ impl Interpreter for ShowCreateTableInterpreter {
 
         let mut table_info = format!("CREATE TABLE `{}` (\n", name);
         for field in schema.fields().iter() {
+            let comment = match field.comment() {
+                Some(comment) => format!(" COMMENT '{}'", comment.replace('\'', "''")),
+                None => "".to_string(),
+            };
             let column = format!(
-                "  `{}` {},\n",
+                "  `{}` {}{},\n",
                 field.name(),
-                format_data_type_sql(field.data_type())
+                format_data_type_sql(field.data_type()),
+                comment
             );
             table_info.push_str(column.as_str());
         }
         let table_engine = format!(") ENGINE={}", engine);
         table_info.push_str(table_engine.as_str());
-        table_info.push_str(
-            table
-                .options()
-                .iter()
-                .map(|(k, v)| format!(" {}='{}'", k.to_uppercase(), v))
-                .collect::<Vec<_>>()
-                .join("")
-                .as_str(),
-        );
+        // The snapshot location is maintained by the engine, not given by the user.
+        let mut options = table
+            .options()
+            .iter()
+            .filter(|(name, _)| name.as_str() != TBL_OPT_KEY_SNAPSHOT_LOC)
+            .collect::<Vec<_>>();
+        options.sort();
+        for (name, value) in options {
+            let option = format!(" {}='{}'", name.to_uppercase(), value.replace('\'', "''"));
+            table_info.push_str(option.as_str());
+        }
 
-        let show_fields = vec![
-            DataField::new("Table", Vu8::to_data_type()),
-            DataField::new("Create Table", Vu8::to_data_type()),
-        ];
-        let show_schema = DataSchemaRefExt::create(show_fields);
+        let show_schema = self.schema();
 
         let block = DataBlock::create(show_schema.clone(), vec![
             Series::from_data(vec![name.as_bytes()]),
@@ -46,11 +46,15 @@ impl Interpreter for DescribeUserStageInterpreter {
         "DescribeUserStageInterpreter"
     }
 
+    fn schema(&self) -> DataSchemaRef {
+        self.plan.schema()
+    }
+
     async fn execute(
         &self,
         _input_stream: Option<SendableDataBlockStream>,
     ) -> Result<SendableDataBlockStream> {
-        let schema = self.plan.schema();
+        let schema = self.schema();
         let default_stage = UserStageInfo::default();
 
         let tenant = self.ctx.get_tenant();
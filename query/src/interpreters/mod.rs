@@ -36,6 +36,7 @@ mod interpreter_show_grants;
 mod interpreter_show_metrics;
 mod interpreter_show_processlist;
 mod interpreter_show_settings;
+mod interpreter_show_table_status;
 mod interpreter_show_tables;
 mod interpreter_show_users;
 mod interpreter_table_create;
@@ -60,6 +61,7 @@ mod plan_schedulers;
 
 pub use interpreter::Interpreter;
 pub use interpreter::InterpreterPtr;
+pub use interpreter::InterpreterResult;
 pub use interpreter_admin_use_tenant::UseTenantInterpreter;
 pub use interpreter_copy::CopyInterpreter;
 pub use interpreter_database_create::CreateDatabaseInterpreter;
@@ -81,6 +83,7 @@ pub use interpreter_show_grants::ShowGrantsInterpreter;
 pub use interpreter_show_metrics::ShowMetricsInterpreter;
 pub use interpreter_show_processlist::ShowProcessListInterpreter;
 pub use interpreter_show_settings::ShowSettingsInterpreter;
+pub use interpreter_show_table_status::ShowTableStatusInterpreter;
 pub use interpreter_show_tables::ShowTablesInterpreter;
 pub use interpreter_show_users::ShowUsersInterpreter;
 pub use interpreter_table_create::CreateTableInterpreter;
@@ -24,6 +24,7 @@ use common_planners::AggregatorFinalPlan;
 use common_planners::AggregatorPartialPlan;
 use common_planners::BroadcastPlan;
 use common_planners::EmptyPlan;
+use common_planners::ExpandPlan;
 use common_planners::Expression;
 use common_planners::ExpressionPlan;
 use common_planners::Expressions;
@@ -42,6 +43,7 @@ use common_planners::SortPlan;
 use common_planners::StageKind;
 use common_planners::StagePlan;
 use common_planners::SubQueriesSetPlan;
+use common_planners::UnionPlan;
 use common_tracing::tracing;
 
 use crate::api::BroadcastAction;
@@ -70,6 +72,7 @@ pub struct PlanScheduler {
     running_mode: RunningMode,
     query_context: Arc<QueryContext>,
     subqueries_expressions: Vec<Expressions>,
+    checksum: bool,
 }
 
 impl PlanScheduler {
@@ -89,14 +92,16 @@ impl PlanScheduler {
             cluster_nodes_name.push(cluster_nodes[index].id.clone());
         }
 
+        let checksum = context.get_settings().get_enable_exchange_checksum()? != 0;
         Ok(PlanScheduler {
             local_pos,
             nodes_plan,
-            stage_id: uuid::Uuid::new_v4().to_string(),
+            stage_id: String::new(),
             query_context: context,
             subqueries_expressions: vec![],
             cluster_nodes: cluster_nodes_name,
             running_mode: RunningMode::Standalone,
+            checksum,
         })
     }
 
@@ -164,6 +169,22 @@ impl Tasks {
 }
 
 impl PlanScheduler {
+    /// The stage id is the index of the stage in the query, so that the stages are
+    /// reproducible between the plan and the logs of all the nodes.
+    fn register_stage(&self, kind: &str) -> String {
+        let stage_id = self
+            .query_context
+            .register_query_stage(kind, self.cluster_nodes.clone());
+
+        tracing::debug!(
+            "Schedule stage {} of query {}, kind: {}",
+            stage_id,
+            self.query_context.get_id(),
+            kind
+        );
+        stage_id
+    }
+
     fn normal_action(&self, stage: &StagePlan, input: &PlanNode) -> ShuffleAction {
         ShuffleAction {
             stage_id: self.stage_id.clone(),
@@ -171,6 +192,7 @@ impl PlanScheduler {
             plan: input.clone(),
             sinks: self.cluster_nodes.clone(),
             scatters_expression: stage.scatters_expr.clone(),
+            checksum: self.checksum,
         }
     }
 
@@ -211,6 +233,7 @@ impl PlanScheduler {
             plan: input.clone(),
             sinks: self.cluster_nodes.clone(),
             scatters_expression: stage.scatters_expr.clone(),
+            checksum: self.checksum,
         }
     }
 
@@ -254,6 +277,7 @@ impl PlanScheduler {
             plan: input.clone(),
             sinks: vec![self.cluster_nodes[self.local_pos].clone()],
             scatters_expression: stage.scatters_expr.clone(),
+            checksum: self.checksum,
         }
     }
 
@@ -309,7 +333,9 @@ impl PlanScheduler {
             PlanNode::Broadcast(plan) => self.visit_broadcast(plan, tasks),
             PlanNode::Having(plan) => self.visit_having(plan, tasks),
             PlanNode::Expression(plan) => self.visit_expression(plan, tasks),
+            PlanNode::Expand(plan) => self.visit_expand(plan, tasks),
             PlanNode::SubQueryExpression(plan) => self.visit_subqueries_set(plan, tasks),
+            PlanNode::Union(plan) => self.visit_union(plan, tasks),
             _ => Err(ErrorCode::UnImplement("")),
         }
     }
@@ -406,7 +432,8 @@ impl PlanScheduler {
         self.visit_plan_node(stage.input.as_ref(), tasks)?;
 
         // Entering new stage
-        self.stage_id = uuid::Uuid::new_v4().to_string();
+        let kind = format!("{:?}", stage.kind);
+        self.stage_id = self.register_stage(&kind);
 
         match stage.kind {
             StageKind::Normal => self.schedule_normal_tasks(stage, tasks),
@@ -419,7 +446,7 @@ impl PlanScheduler {
         self.visit_plan_node(plan.input.as_ref(), tasks)?;
 
         // Entering new stage
-        self.stage_id = uuid::Uuid::new_v4().to_string();
+        self.stage_id = self.register_stage("Broadcast");
 
         match self.running_mode {
             RunningMode::Cluster => self.visit_cluster_broadcast(tasks),
@@ -435,6 +462,7 @@ impl PlanScheduler {
             query_id: self.query_context.get_id(),
             plan: input.clone(),
             sinks: self.cluster_nodes.clone(),
+            checksum: self.checksum,
         }
     }
 
@@ -534,6 +562,33 @@ impl PlanScheduler {
         }
     }
 
+    fn visit_expand(&mut self, plan: &ExpandPlan, tasks: &mut Tasks) -> Result<()> {
+        self.visit_plan_node(plan.input.as_ref(), tasks)?;
+        match self.running_mode {
+            RunningMode::Cluster => self.visit_cluster_expand(plan),
+            RunningMode::Standalone => self.visit_local_expand(plan),
+        };
+        Ok(())
+    }
+
+    fn visit_local_expand(&mut self, plan: &ExpandPlan) {
+        self.nodes_plan[self.local_pos] = PlanNode::Expand(ExpandPlan {
+            sets: plan.sets.clone(),
+            schema: plan.schema.clone(),
+            input: Arc::new(self.nodes_plan[self.local_pos].clone()),
+        });
+    }
+
+    fn visit_cluster_expand(&mut self, plan: &ExpandPlan) {
+        for index in 0..self.nodes_plan.len() {
+            self.nodes_plan[index] = PlanNode::Expand(ExpandPlan {
+                sets: plan.sets.clone(),
+                schema: plan.schema.clone(),
+                input: Arc::new(self.nodes_plan[index].clone()),
+            });
+        }
+    }
+
     fn visit_subqueries_set(&mut self, plan: &SubQueriesSetPlan, tasks: &mut Tasks) -> Result<()> {
         self.visit_plan_node(plan.input.as_ref(), tasks)?;
 
@@ -645,6 +700,44 @@ impl PlanScheduler {
         Ok(subquery_scheduler.nodes_plan)
     }
 
+    fn visit_union(&mut self, plan: &UnionPlan, tasks: &mut Tasks) -> Result<()> {
+        // The scatters optimizer converges the inputs unless all of them run in cluster mode.
+        let mut inputs_nodes_plan = Vec::with_capacity(plan.inputs.len());
+        for input in &plan.inputs {
+            let input_context = QueryContext::create_from(self.query_context.clone());
+            let mut input_scheduler = PlanScheduler::try_create(input_context)?;
+            input_scheduler.visit_plan_node(input.as_ref(), tasks)?;
+            self.running_mode = input_scheduler.running_mode;
+            inputs_nodes_plan.push(input_scheduler.nodes_plan);
+        }
+
+        match self.running_mode {
+            RunningMode::Cluster => self.visit_cluster_union(plan, &inputs_nodes_plan),
+            RunningMode::Standalone => self.visit_local_union(plan, &inputs_nodes_plan),
+        };
+        Ok(())
+    }
+
+    fn union_plan(plan: &UnionPlan, inputs_nodes_plan: &[Vec<PlanNode>], index: usize) -> PlanNode {
+        PlanNode::Union(UnionPlan {
+            schema: plan.schema(),
+            inputs: inputs_nodes_plan
+                .iter()
+                .map(|nodes_plan| Arc::new(nodes_plan[index].clone()))
+                .collect(),
+        })
+    }
+
+    fn visit_local_union(&mut self, plan: &UnionPlan, inputs_nodes_plan: &[Vec<PlanNode>]) {
+        self.nodes_plan[self.local_pos] = Self::union_plan(plan, inputs_nodes_plan, self.local_pos);
+    }
+
+    fn visit_cluster_union(&mut self, plan: &UnionPlan, inputs_nodes_plan: &[Vec<PlanNode>]) {
+        for index in 0..self.nodes_plan.len() {
+            self.nodes_plan[index] = Self::union_plan(plan, inputs_nodes_plan, index);
+        }
+    }
+
     fn visit_filter(&mut self, plan: &FilterPlan, tasks: &mut Tasks) -> Result<()> {
         self.visit_plan_node(plan.input.as_ref(), tasks)?;
         match self.running_mode {
@@ -713,6 +806,7 @@ impl PlanScheduler {
             schema: plan.schema.clone(),
             order_by: plan.order_by.clone(),
             input: Arc::new(self.nodes_plan[self.local_pos].clone()),
+            limit: plan.limit,
         });
     }
 
@@ -722,6 +816,7 @@ impl PlanScheduler {
                 schema: plan.schema.clone(),
                 order_by: plan.order_by.clone(),
                 input: Arc::new(self.nodes_plan[index].clone()),
+                limit: plan.limit,
             });
         }
     }
@@ -21,11 +21,13 @@ use crate::api::CancelAction;
 use crate::api::FlightAction;
 use crate::interpreters::plan_schedulers::Scheduled;
 use crate::sessions::QueryContext;
+use crate::sessions::QueryStageState;
 
 pub async fn handle_error(context: &Arc<QueryContext>, scheduled: Scheduled, timeout: u64) {
     let query_id = context.get_id();
     let config = context.get_config();
     let cluster = context.get_cluster();
+    context.set_query_stages_state(QueryStageState::Failed);
 
     for (_stream_name, scheduled_node) in scheduled {
         match cluster.create_node_conn(&scheduled_node.id, &config).await {
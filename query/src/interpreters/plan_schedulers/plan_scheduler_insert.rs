@@ -115,9 +115,9 @@ impl<'a> InsertWithPlan<'a> {
         let select_schema = select_plan.schema();
 
         // validate schema
-        if select_schema.fields().len() < output_schema.fields().len() {
+        if select_schema.fields().len() != output_schema.fields().len() {
             return Err(ErrorCode::BadArguments(
-                "Fields in select statement is less than expected",
+                "Fields in select statement is not equal with expected",
             ));
         }
 
@@ -26,6 +26,7 @@ use crate::interpreters::plan_schedulers::ScheduledStream;
 use crate::interpreters::PlanScheduler;
 use crate::pipelines::processors::PipelineBuilder;
 use crate::sessions::QueryContext;
+use crate::sessions::QueryStageState;
 
 #[tracing::instrument(level = "debug", skip(ctx), fields(ctx.id = ctx.get_id().as_str()))]
 pub async fn schedule_query(
@@ -48,6 +49,8 @@ pub async fn schedule_query(
         scheduled.insert(node.id.clone(), node.clone());
     }
 
+    ctx.set_query_stages_state(QueryStageState::Running);
+
     let pipeline_builder = PipelineBuilder::create(ctx.clone());
     let mut in_local_pipeline = pipeline_builder.build(&scheduled_tasks.get_local_task())?;
 
@@ -31,6 +31,7 @@ use futures::StreamExt;
 
 use crate::interpreters::plan_schedulers;
 use crate::sessions::QueryContext;
+use crate::sessions::QueryStageState;
 
 pub type Scheduled = HashMap<String, Arc<NodeInfo>>;
 
@@ -81,8 +82,13 @@ impl Stream for ScheduledStream {
         self.inner.poll_next_unpin(cx).map(|x| match x {
             None => {
                 self.is_success.store(true, Ordering::Relaxed);
+                self.ctx.set_query_stages_state(QueryStageState::Finished);
                 None
             }
+            Some(Err(cause)) => {
+                self.ctx.set_query_stages_state(QueryStageState::Failed);
+                Some(Err(cause))
+            }
             other => other,
         })
     }
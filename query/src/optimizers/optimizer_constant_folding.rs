@@ -22,11 +22,15 @@ use common_functions::scalars::Function2Factory;
 use common_planners::AggregatorFinalPlan;
 use common_planners::AggregatorPartialPlan;
 use common_planners::Expression;
+use common_planners::ExpressionChain;
 use common_planners::ExpressionRewriter;
+use common_planners::ExpressionVisitor;
 use common_planners::Expressions;
 use common_planners::PlanBuilder;
 use common_planners::PlanNode;
 use common_planners::PlanRewriter;
+use common_planners::ReadDataSourcePlan;
+use common_planners::Recursion;
 
 use crate::optimizers::Optimizer;
 use crate::pipelines::transforms::ExpressionExecutor;
@@ -45,22 +49,69 @@ impl ConstantFoldingImpl {
             .any(|expr| !matches!(expr, Expression::Literal { .. }))
     }
 
-    fn rewrite_function<F>(op: &str, args: Expressions, name: String, f: F) -> Result<Expression>
-    where F: Fn(&str, Expressions) -> Expression {
+    fn rewrite_function<F>(
+        failures: &mut Vec<(Expression, ErrorCode)>,
+        op: &str,
+        args: Expressions,
+        name: String,
+        f: F,
+    ) -> Result<Expression>
+    where
+        F: Fn(&str, Expressions) -> Expression,
+    {
         let factory = Function2Factory::instance();
         let function_features = factory.get_features(op)?;
 
         if function_features.is_deterministic && Self::constants_arguments(&args) {
-            let op = op.to_string();
-            return ConstantFoldingImpl::execute_expression(
-                Expression::ScalarFunction { op, args },
-                name,
-            );
+            let expr = Expression::create_scalar_function(op, args.clone());
+
+            match ConstantFoldingImpl::execute_expression(expr, name) {
+                Ok(expr) => return Ok(expr),
+                Err(cause) => {
+                    // Keep the expression, it's only an error if it's evaluated.
+                    let expr = f(op, args);
+                    failures.push((expr.clone(), cause));
+                    return Ok(expr);
+                }
+            }
         }
 
         Ok(f(op, args))
     }
 
+    /// Drop the failures in the arguments of a lazy function that may be never evaluated,
+    /// only the first argument of a lazy function is always evaluated.
+    fn tolerate_lazy_failures(
+        failures: &mut Vec<(Expression, ErrorCode)>,
+        start: usize,
+        args: &[Expression],
+    ) {
+        let mut index = start;
+        while index < failures.len() {
+            match args.first() {
+                Some(first) if Self::contains_expr(first, &failures[index].0) => index += 1,
+                _ => {
+                    failures.remove(index);
+                }
+            }
+        }
+    }
+
+    fn contains_expr(expr: &Expression, target: &Expression) -> bool {
+        struct Finder<'a>(&'a Expression, bool);
+
+        impl<'a> ExpressionVisitor for Finder<'a> {
+            fn pre_visit(self, expr: &Expression) -> Result<Recursion<Self>> {
+                match self.1 || expr == self.0 {
+                    true => Ok(Recursion::Stop(Finder(self.0, true))),
+                    false => Ok(Recursion::Continue(self)),
+                }
+            }
+        }
+
+        matches!(expr.accept(Finder(target, false)), Ok(Finder(_, true)))
+    }
+
     fn expr_executor(schema: &DataSchemaRef, expr: Expression) -> Result<ExpressionExecutor> {
         let output_fields = vec![expr.to_data_field(schema)?];
         let output_schema = DataSchemaRefExt::create(output_fields);
@@ -74,59 +125,75 @@ impl ConstantFoldingImpl {
     }
 
     fn execute_expression(expression: Expression, origin_name: String) -> Result<Expression> {
-        let input_fields = vec![DataField::new("_dummy", u8::to_data_type())];
-        let input_schema = Arc::new(DataSchema::new(input_fields));
-
-        let data_type = expression.to_data_type(&input_schema)?;
-        let expression_executor = Self::expr_executor(&input_schema, expression)?;
-        let const_col = ConstColumn::new(Series::from_data(vec![1u8]), 1);
-        let dummy_columns = vec![Arc::new(const_col) as ColumnRef];
-        let data_block = DataBlock::create(input_schema, dummy_columns);
-        let executed_data_block = expression_executor.execute(&data_block)?;
-
-        ConstantFoldingImpl::convert_to_expression(origin_name, executed_data_block, data_type)
-    }
-
-    fn convert_to_expression(
-        column_name: String,
-        data_block: DataBlock,
-        data_type: DataTypePtr,
-    ) -> Result<Expression> {
-        debug_assert!(data_block.num_rows() == 1);
-        debug_assert!(data_block.num_columns() == 1);
-
-        let column_name = Some(column_name);
-        let value = data_block.column(0).get_checked(0)?;
+        let (value, data_type) = ConstantFoldingOptimizer::evaluate_constant(expression)?;
         Ok(Expression::Literal {
             value,
-            column_name,
+            column_name: Some(origin_name),
             data_type,
         })
     }
 }
 
 impl PlanRewriter for ConstantFoldingImpl {
-    fn rewrite_expr(&mut self, schema: &DataSchemaRef, origin: &Expression) -> Result<Expression> {
+    fn rewrite_expr(&mut self, _schema: &DataSchemaRef, origin: &Expression) -> Result<Expression> {
         /* TODO: constant folding for subquery and scalar subquery
          * For example:
          *   before optimize: SELECT (SELECT 1 + 2)
          *   after optimize: SELECT 3
          */
-        struct ConstantExpressionRewriter(*mut ConstantFoldingImpl, DataSchemaRef);
+        struct ConstantExpressionRewriter<'a> {
+            // The expressions that failed to fold, with the errors.
+            failures: &'a mut Vec<(Expression, ErrorCode)>,
+            // The number of failures when entering each lazy function.
+            lazy_starts: Vec<usize>,
+        }
+
+        impl<'a> ConstantExpressionRewriter<'a> {
+            fn execute_expression(&mut self, expr: Expression, name: String) -> Expression {
+                match ConstantFoldingImpl::execute_expression(expr.clone(), name) {
+                    Ok(expr) => expr,
+                    Err(cause) => {
+                        self.failures.push((expr.clone(), cause));
+                        expr
+                    }
+                }
+            }
+        }
+
+        impl<'a> ExpressionRewriter for ConstantExpressionRewriter<'a> {
+            fn pre_visit(&mut self, expr: &Expression) -> Result<bool> {
+                if let Expression::ScalarFunction { op, .. } = expr {
+                    if ExpressionChain::is_lazy_function(op) {
+                        self.lazy_starts.push(self.failures.len());
+                    }
+                }
+                Ok(true)
+            }
 
-        impl ExpressionRewriter for ConstantExpressionRewriter {
             fn mutate_scalar_function(
                 &mut self,
                 name: &str,
                 args: Vec<Expression>,
                 origin_expr: &Expression,
             ) -> Result<Expression> {
+                if ExpressionChain::is_lazy_function(name) {
+                    if let Some(start) = self.lazy_starts.pop() {
+                        ConstantFoldingImpl::tolerate_lazy_failures(self.failures, start, &args);
+                    }
+                }
+
                 let origin_name = origin_expr.column_name();
+                let ordinal = origin_expr.ordinal();
                 ConstantFoldingImpl::rewrite_function(
+                    self.failures,
                     name,
                     args,
                     origin_name,
-                    Expression::create_scalar_function,
+                    |op, args| Expression::ScalarFunction {
+                        op: op.to_string(),
+                        args,
+                        ordinal,
+                    },
                 )
             }
 
@@ -138,6 +205,7 @@ impl PlanRewriter for ConstantFoldingImpl {
             ) -> Result<Expression> {
                 let origin_name = origin_expr.column_name();
                 ConstantFoldingImpl::rewrite_function(
+                    self.failures,
                     op,
                     vec![expr],
                     origin_name,
@@ -154,6 +222,7 @@ impl PlanRewriter for ConstantFoldingImpl {
             ) -> Result<Expression> {
                 let origin_name = origin_expr.column_name();
                 ConstantFoldingImpl::rewrite_function(
+                    self.failures,
                     op,
                     vec![left, right],
                     origin_name,
@@ -161,6 +230,30 @@ impl PlanRewriter for ConstantFoldingImpl {
                 )
             }
 
+            fn mutate_between(
+                &mut self,
+                expr: Expression,
+                negated: bool,
+                low: Expression,
+                high: Expression,
+                origin_expr: &Expression,
+            ) -> Result<Expression> {
+                let is_constant = [&expr, &low, &high]
+                    .iter()
+                    .all(|arg| matches!(arg, Expression::Literal { .. }));
+                let optimize_expr = Expression::Between {
+                    expr: Box::new(expr),
+                    negated,
+                    low: Box::new(low),
+                    high: Box::new(high),
+                };
+
+                match is_constant {
+                    true => Ok(self.execute_expression(optimize_expr, origin_expr.column_name())),
+                    false => Ok(optimize_expr),
+                }
+            }
+
             fn mutate_cast(
                 &mut self,
                 typ: &DataTypePtr,
@@ -175,10 +268,7 @@ impl PlanRewriter for ConstantFoldingImpl {
                         is_nullable,
                     };
 
-                    return ConstantFoldingImpl::execute_expression(
-                        optimize_expr,
-                        origin_expr.column_name(),
-                    );
+                    return Ok(self.execute_expression(optimize_expr, origin_expr.column_name()));
                 }
 
                 Ok(Expression::Cast {
@@ -189,7 +279,27 @@ impl PlanRewriter for ConstantFoldingImpl {
             }
         }
 
-        ConstantExpressionRewriter(self, schema.clone()).mutate(origin)
+        let mut failures = vec![];
+        let expr = ConstantExpressionRewriter {
+            failures: &mut failures,
+            lazy_starts: vec![],
+        }
+        .mutate(origin)?;
+
+        match failures.into_iter().next() {
+            None => Ok(expr),
+            Some((_, cause)) => Err(cause),
+        }
+    }
+
+    fn rewrite_read_data_source(&mut self, plan: &ReadDataSourcePlan) -> Result<PlanNode> {
+        // Fold the pushed down filters too, so they are the same as the filter plan.
+        let mut new_plan = plan.clone();
+        if let Some(extras) = &mut new_plan.push_downs {
+            let schema = plan.table_info.schema();
+            extras.filters = self.rewrite_exprs(&schema, &extras.filters)?;
+        }
+        Ok(PlanNode::ReadSource(new_plan))
     }
 
     fn rewrite_aggregate_partial(&mut self, plan: &AggregatorPartialPlan) -> Result<PlanNode> {
@@ -250,4 +360,22 @@ impl ConstantFoldingOptimizer {
     pub fn create(_ctx: Arc<QueryContext>) -> Self {
         ConstantFoldingOptimizer {}
     }
+
+    /// Evaluate an expression without column references, returns the value and its type.
+    pub fn evaluate_constant(expression: Expression) -> Result<(DataValue, DataTypePtr)> {
+        let input_fields = vec![DataField::new("_dummy", u8::to_data_type())];
+        let input_schema = Arc::new(DataSchema::new(input_fields));
+
+        let data_type = expression.to_data_type(&input_schema)?;
+        let expression_executor = ConstantFoldingImpl::expr_executor(&input_schema, expression)?;
+        let const_col = ConstColumn::new(Series::from_data(vec![1u8]), 1);
+        let dummy_columns = vec![Arc::new(const_col) as ColumnRef];
+        let data_block = DataBlock::create(input_schema, dummy_columns);
+        let executed_data_block = expression_executor.execute(&data_block)?;
+
+        debug_assert!(executed_data_block.num_rows() == 1);
+        debug_assert!(executed_data_block.num_columns() == 1);
+        let value = executed_data_block.column(0).get_checked(0)?;
+        Ok((value, data_type))
+    }
 }
@@ -88,7 +88,7 @@ impl ExprTransformImpl {
                     Expression::create_binary_expression,
                 ),
             },
-            Expression::ScalarFunction { op, args } => Self::inverse_expr(
+            Expression::ScalarFunction { op, args, .. } => Self::inverse_expr(
                 op.to_lowercase().as_str(),
                 args.clone(),
                 origin,
@@ -98,6 +98,17 @@ impl ExprTransformImpl {
             Expression::UnaryExpression { op, expr } if op.to_lowercase().eq("not") => {
                 Self::truth_transformer(expr, !is_negated)
             }
+            Expression::Between {
+                expr,
+                negated,
+                low,
+                high,
+            } => Ok(Expression::Between {
+                expr: expr.clone(),
+                negated: *negated != is_negated,
+                low: low.clone(),
+                high: high.clone(),
+            }),
             _ => {
                 if !is_negated {
                     Ok(origin.clone())
@@ -146,6 +157,7 @@ impl ExprTransformImpl {
                 other => Self::make_condition(other, origin),
             },
             Expression::ScalarFunction { op, .. } => Self::make_condition(op.as_str(), origin),
+            Expression::Between { .. } => Ok(origin.clone()),
             _ => Ok(origin.not_eq(lit(0))),
         }
     }
@@ -306,6 +318,7 @@ impl PlanRewriter for ExprTransformImpl {
         let new_predicate = Self::constant_transformer(&plan.predicate)?;
         let new_predicate = Self::boolean_transformer(&new_predicate)?;
         let new_predicate = Self::truth_transformer(&new_predicate, false)?;
+        let new_predicate = RewriteHelper::reorder_conjunctions(&new_predicate);
         PlanBuilder::from(&new_input).filter(new_predicate)?.build()
     }
 
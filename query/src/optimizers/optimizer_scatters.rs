@@ -31,6 +31,7 @@ use common_planners::ReadDataSourcePlan;
 use common_planners::SortPlan;
 use common_planners::StageKind;
 use common_planners::StagePlan;
+use common_planners::UnionPlan;
 
 use crate::optimizers::Optimizer;
 use crate::sessions::QueryContext;
@@ -116,7 +117,7 @@ impl ScattersOptimizerImpl {
         match self.input.take() {
             None => Err(ErrorCode::LogicalError("Cluster sort input is None")),
             Some(input) => Self::convergent_shuffle_stage_builder(input)
-                .sort(&plan.order_by)?
+                .sort_limit(&plan.order_by, plan.limit)?
                 .build(),
         }
     }
@@ -125,7 +126,7 @@ impl ScattersOptimizerImpl {
         match self.input.take() {
             None => Err(ErrorCode::LogicalError("Standalone sort input is None")),
             Some(input) => PlanBuilder::from(input.as_ref())
-                .sort(&plan.order_by)?
+                .sort_limit(&plan.order_by, plan.limit)?
                 .build(),
         }
     }
@@ -194,6 +195,7 @@ impl ScattersOptimizerImpl {
         let scatters_expr = Expression::ScalarFunction {
             op: String::from("sipHash"),
             args: vec![Expression::Column(key.into())],
+            ordinal: None,
         };
 
         Ok(PlanNode::Stage(StagePlan {
@@ -228,6 +230,39 @@ impl PlanRewriter for ScattersOptimizerImpl {
         }
     }
 
+    fn rewrite_union(&mut self, plan: &UnionPlan) -> Result<PlanNode> {
+        // Each input is an independent query, which may or may not run in cluster mode.
+        let mut new_inputs = Vec::with_capacity(plan.inputs.len());
+        let mut inputs_running_mode = Vec::with_capacity(plan.inputs.len());
+        for input in &plan.inputs {
+            let input_ctx = QueryContext::create_from(self.ctx.clone());
+            let mut input_optimizer = ScattersOptimizerImpl::create(input_ctx);
+            new_inputs.push(input_optimizer.rewrite_plan_node(input.as_ref())?);
+            inputs_running_mode.push(input_optimizer.running_mode);
+        }
+
+        // Keep running in cluster mode only if all the inputs do, otherwise converge them.
+        match inputs_running_mode
+            .iter()
+            .all(|mode| matches!(mode, RunningMode::Cluster))
+        {
+            true => self.running_mode = RunningMode::Cluster,
+            false => {
+                self.running_mode = RunningMode::Standalone;
+                for (input, mode) in new_inputs.iter_mut().zip(&inputs_running_mode) {
+                    if let RunningMode::Cluster = mode {
+                        *input = Self::convergent_shuffle_stage(input.clone())?;
+                    }
+                }
+            }
+        }
+
+        Ok(PlanNode::Union(UnionPlan {
+            schema: plan.schema(),
+            inputs: new_inputs.into_iter().map(Arc::new).collect(),
+        }))
+    }
+
     fn rewrite_aggregate_partial(&mut self, plan: &AggregatorPartialPlan) -> Result<PlanNode> {
         let new_input = Arc::new(self.rewrite_plan_node(&plan.input)?);
 
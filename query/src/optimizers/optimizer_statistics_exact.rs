@@ -55,8 +55,10 @@ impl PlanRewriter for StatisticsExactImpl<'_> {
                 }],
                 PlanNode::Expression(ExpressionPlan { input, .. }),
             ) if op == "count" && args.len() == 1 => match (&args[0], input.as_ref()) {
+                // The statistics count the whole table, not the rows of a TABLESAMPLE.
                 (Expression::Literal { .. }, PlanNode::ReadSource(read_source_plan))
-                    if read_source_plan.statistics.is_exact =>
+                    if read_source_plan.statistics.is_exact
+                        && !matches!(&read_source_plan.push_downs, Some(extras) if extras.sample.is_some()) =>
                 {
                     let db_name = "system";
                     let table_name = "one";
@@ -84,7 +84,11 @@ impl PlanRewriter for TopNPushDownImpl {
             _ => {}
         }
 
-        let new_input = self.rewrite_plan_node(plan.input.as_ref())?;
+        let mut new_input = self.rewrite_plan_node(plan.input.as_ref())?;
+        if let Some(limit) = plan.n {
+            new_input = Self::fuse_top_n(&new_input, limit + plan.offset)?;
+        }
+
         let plan_node = PlanBuilder::from(&new_input)
             .limit_offset(plan.n, plan.offset)?
             .build();
@@ -95,7 +99,22 @@ impl PlanRewriter for TopNPushDownImpl {
         plan_node
     }
 
+    fn rewrite_limit_by(&mut self, plan: &LimitByPlan) -> Result<PlanNode> {
+        // LIMIT BY drops rows per key, so the rows below it are not bounded by the top n.
+        self.limit = None;
+
+        let new_input = self.rewrite_plan_node(plan.input.as_ref())?;
+        PlanBuilder::from(&new_input)
+            .limit_by(plan.limit, &plan.limit_by)?
+            .build()
+    }
+
     fn rewrite_read_data_source(&mut self, plan: &ReadDataSourcePlan) -> Result<PlanNode> {
+        // A sample is taken from the rows read, so it must see the whole table rather than the top n.
+        if matches!(&plan.push_downs, Some(extras) if extras.sample.is_some()) {
+            return Ok(PlanNode::ReadSource(plan.clone()));
+        }
+
         // push the limit and order_by down to read_source_plan
         if let Some(n) = self.limit {
             let mut new_plan = plan.clone();
@@ -112,6 +131,7 @@ impl PlanRewriter for TopNPushDownImpl {
                         filters: extras.filters.clone(),
                         limit: Some(new_limit),
                         order_by: self.get_sort_columns(plan.schema())?,
+                        sample: extras.sample.clone(),
                     })
                 }
                 None => {
@@ -137,7 +157,9 @@ impl PlanRewriter for TopNPushDownImpl {
         }
         let new_input = self.rewrite_plan_node(plan.input.as_ref())?;
         let new_order_by = self.rewrite_exprs(&new_input.schema(), &plan.order_by)?;
-        PlanBuilder::from(&new_input).sort(&new_order_by)?.build()
+        PlanBuilder::from(&new_input)
+            .sort_limit(&new_order_by, plan.limit)?
+            .build()
     }
 }
 
@@ -151,6 +173,29 @@ impl TopNPushDownImpl {
         }
     }
 
+    // Fuse the sort feeding a limit into a top-N sort, which keeps only the first `limit` rows.
+    // Projections and expressions keep the number and the order of rows, so they may stand in between.
+    fn fuse_top_n(plan: &PlanNode, limit: usize) -> Result<PlanNode> {
+        match plan {
+            PlanNode::Projection(plan) => {
+                let mut new_plan = plan.clone();
+                new_plan.set_input(&Self::fuse_top_n(plan.input.as_ref(), limit)?);
+                Ok(PlanNode::Projection(new_plan))
+            }
+            PlanNode::Expression(plan) => {
+                let mut new_plan = plan.clone();
+                new_plan.set_input(&Self::fuse_top_n(plan.input.as_ref(), limit)?);
+                Ok(PlanNode::Expression(new_plan))
+            }
+            PlanNode::Sort(plan) => {
+                let mut new_plan = plan.clone();
+                new_plan.limit = Some(plan.limit.map_or(limit, |n| n.min(limit)));
+                Ok(PlanNode::Sort(new_plan))
+            }
+            _ => Ok(plan.clone()),
+        }
+    }
+
     // For every order by columns, try the best to extract the native columns.
     // For example 'order by age+3, number+5', will return expression of two columns,
     // 'age' and 'number', since f(age)=age+3 and f(number)=number+5 are both monotonic functions.
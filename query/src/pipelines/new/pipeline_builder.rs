@@ -272,6 +272,12 @@ impl PlanVisitor for QueryPipelineBuilder {
     }
 
     fn visit_read_data_source(&mut self, plan: &ReadDataSourcePlan) -> Result<()> {
+        if matches!(&plan.push_downs, Some(extras) if extras.sample.is_some()) {
+            return Err(ErrorCode::UnImplement(
+                "TABLESAMPLE is unimplemented in the new processor framework.",
+            ));
+        }
+
         // Bind plan partitions to context.
         self.ctx.try_set_partitions(plan.parts.clone())?;
         let table = self.ctx.build_table_from_source_plan(plan)?;
@@ -18,6 +18,7 @@ use common_exception::Result;
 use common_planners::AggregatorFinalPlan;
 use common_planners::AggregatorPartialPlan;
 use common_planners::BroadcastPlan;
+use common_planners::ExpandPlan;
 use common_planners::ExpressionPlan;
 use common_planners::FilterPlan;
 use common_planners::HavingPlan;
@@ -32,6 +33,7 @@ use common_planners::SinkPlan;
 use common_planners::SortPlan;
 use common_planners::StagePlan;
 use common_planners::SubQueriesSetPlan;
+use common_planners::UnionPlan;
 use common_tracing::tracing;
 
 use crate::api::FlightTicket;
@@ -39,12 +41,15 @@ use crate::pipelines::processors::Pipeline;
 use crate::pipelines::transforms::AggregatorFinalTransform;
 use crate::pipelines::transforms::AggregatorPartialTransform;
 use crate::pipelines::transforms::CreateSetsTransform;
+use crate::pipelines::transforms::ExpandTransform;
 use crate::pipelines::transforms::ExpressionTransform;
 use crate::pipelines::transforms::GroupByFinalTransform;
 use crate::pipelines::transforms::GroupByPartialTransform;
 use crate::pipelines::transforms::HavingTransform;
 use crate::pipelines::transforms::LimitByTransform;
 use crate::pipelines::transforms::LimitTransform;
+use crate::pipelines::transforms::PlanProfiling;
+use crate::pipelines::transforms::ProfilingTransform;
 use crate::pipelines::transforms::ProjectionTransform;
 use crate::pipelines::transforms::RemoteTransform;
 use crate::pipelines::transforms::SinkTransform;
@@ -57,20 +62,23 @@ use crate::sessions::QueryContext;
 
 pub struct PipelineBuilder {
     ctx: Arc<QueryContext>,
-
-    limit: Option<usize>,
-    offset: usize,
+    profiling: Option<Arc<PlanProfiling>>,
 }
 
 impl PipelineBuilder {
     pub fn create(ctx: Arc<QueryContext>) -> PipelineBuilder {
         PipelineBuilder {
             ctx,
-            limit: None,
-            offset: 0,
+            profiling: None,
         }
     }
 
+    /// Collect the execution metrics of each plan node into `profiling`.
+    pub fn with_profiling(mut self, profiling: Arc<PlanProfiling>) -> PipelineBuilder {
+        self.profiling = Some(profiling);
+        self
+    }
+
     #[tracing::instrument(level = "debug", skip(self))]
     pub fn build(mut self, node: &PlanNode) -> Result<Pipeline> {
         tracing::debug!("Received plan:\n{:?}", node);
@@ -80,12 +88,31 @@ impl PipelineBuilder {
     }
 
     fn visit(&mut self, node: &PlanNode) -> Result<Pipeline> {
+        let mut pipeline = self.visit_node(node)?;
+
+        if let Some(profiling) = &self.profiling {
+            // The select plan shares the pipeline of its input.
+            if !matches!(node, PlanNode::Select(_)) {
+                let stream_profiling = profiling.register(node);
+                pipeline.add_simple_transform(|| {
+                    Ok(Box::new(ProfilingTransform::create(
+                        stream_profiling.clone(),
+                    )))
+                })?;
+            }
+        }
+
+        Ok(pipeline)
+    }
+
+    fn visit_node(&mut self, node: &PlanNode) -> Result<Pipeline> {
         match node {
             PlanNode::Select(node) => self.visit_select(node),
             PlanNode::Stage(node) => self.visit_stage(node),
             PlanNode::Broadcast(node) => self.visit_broadcast(node),
             PlanNode::Remote(node) => self.visit_remote(node),
             PlanNode::Expression(node) => self.visit_expression(node),
+            PlanNode::Expand(node) => self.visit_expand(node),
             PlanNode::Projection(node) => self.visit_projection(node),
             PlanNode::AggregatorPartial(node) => self.visit_aggregator_partial(node),
             PlanNode::AggregatorFinal(node) => self.visit_aggregator_final(node),
@@ -97,6 +124,7 @@ impl PipelineBuilder {
             PlanNode::ReadSource(node) => self.visit_read_data_source(node),
             PlanNode::SubQueryExpression(node) => self.visit_create_sets(node),
             PlanNode::Sink(node) => self.visit_sink(node),
+            PlanNode::Union(node) => self.visit_union(node),
             other => Result::Err(ErrorCode::UnknownPlan(format!(
                 "Build pipeline from the plan node unsupported:{:?}",
                 other.name()
@@ -108,6 +136,24 @@ impl PipelineBuilder {
         self.visit(&*node.input)
     }
 
+    fn visit_union(&mut self, node: &UnionPlan) -> Result<Pipeline> {
+        // Each input is an independent query, its outputs become the sources of the union.
+        let mut pipeline = Pipeline::create(self.ctx.clone());
+        for input in &node.inputs {
+            // Each input binds its own partitions, so it needs its own context.
+            let mut input_builder = PipelineBuilder {
+                ctx: QueryContext::create_from(self.ctx.clone()),
+                profiling: self.profiling.clone(),
+            };
+            let input_pipeline = input_builder.visit(input.as_ref())?;
+            for processor in input_pipeline.last_pipe()?.processors() {
+                pipeline.add_source(processor)?;
+            }
+        }
+
+        Ok(pipeline)
+    }
+
     fn visit_stage(&self, _: &StagePlan) -> Result<Pipeline> {
         Result::Err(ErrorCode::LogicalError(
             "Logical Error: visit_stage_plan in pipeline_builder",
@@ -150,6 +196,18 @@ impl PipelineBuilder {
         Ok(pipeline)
     }
 
+    fn visit_expand(&mut self, node: &ExpandPlan) -> Result<Pipeline> {
+        let mut pipeline = self.visit(&*node.input)?;
+        pipeline.add_simple_transform(|| {
+            Ok(Box::new(ExpandTransform::try_create(
+                node.input.schema(),
+                node.schema(),
+                node.sets.clone(),
+            )?))
+        })?;
+        Ok(pipeline)
+    }
+
     fn visit_projection(&mut self, node: &ProjectionPlan) -> Result<Pipeline> {
         let mut pipeline = self.visit(&*node.input)?;
         pipeline.add_simple_transform(|| {
@@ -165,6 +223,11 @@ impl PipelineBuilder {
     fn visit_aggregator_partial(&mut self, node: &AggregatorPartialPlan) -> Result<Pipeline> {
         let mut pipeline = self.visit(&*node.input)?;
 
+        let max_aggregate_threads = self.ctx.get_settings().get_max_aggregate_threads()? as usize;
+        if pipeline.last_pipe()?.nums() > max_aggregate_threads {
+            pipeline.mixed_processor(max_aggregate_threads)?;
+        }
+
         if node.group_expr.is_empty() {
             pipeline.add_simple_transform(|| {
                 Ok(Box::new(AggregatorPartialTransform::try_create(
@@ -209,7 +272,8 @@ impl PipelineBuilder {
                     node.group_expr.clone(),
                 )))
             })?;
-            pipeline.mixed_processor(self.ctx.get_settings().get_max_threads()? as usize)?;
+            let max_aggregate_threads = self.ctx.get_settings().get_max_aggregate_threads()?;
+            pipeline.mixed_processor(max_aggregate_threads as usize)?;
         }
         Ok(pipeline)
     }
@@ -239,10 +303,15 @@ impl PipelineBuilder {
     fn visit_sort(&mut self, plan: &SortPlan) -> Result<Pipeline> {
         let mut pipeline = self.visit(&*plan.input)?;
 
-        // The number of rows should be limit + offset. For example, for the query
+        // A top-N sort keeps at most limit + offset rows. For example, for the query
         // 'select * from numbers(100) order by number desc limit 10 offset 5', the
-        // sort pipeline should return at least 15 rows.
-        let rows_limit = self.limit.map(|limit| limit + self.offset);
+        // sort pipeline returns 15 rows.
+        let rows_limit = plan.limit;
+
+        let max_sort_threads = self.ctx.get_settings().get_max_sort_threads()? as usize;
+        if pipeline.last_pipe()?.nums() > max_sort_threads {
+            pipeline.mixed_processor(max_sort_threads)?;
+        }
 
         // processor 1: block ---> sort_stream
         // processor 2: block ---> sort_stream
@@ -285,9 +354,6 @@ impl PipelineBuilder {
     }
 
     fn visit_limit(&mut self, node: &LimitPlan) -> Result<Pipeline> {
-        self.limit = node.n;
-        self.offset = node.offset;
-
         let mut pipeline = self.visit(&*node.input)?;
         pipeline.merge_processor()?;
         pipeline.add_simple_transform(|| {
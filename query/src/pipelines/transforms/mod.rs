@@ -15,6 +15,7 @@
 mod transform_aggregator_final;
 mod transform_aggregator_partial;
 mod transform_create_sets;
+mod transform_expand;
 mod transform_expression;
 mod transform_expression_executor;
 mod transform_filter;
@@ -22,6 +23,7 @@ mod transform_group_by_final;
 mod transform_group_by_partial;
 mod transform_limit;
 mod transform_limit_by;
+mod transform_profiling;
 mod transform_projection;
 mod transform_remote;
 mod transform_sort_merge;
@@ -35,8 +37,10 @@ mod transform_sink;
 pub use streams::AddOnStream;
 pub use transform_aggregator_final::AggregatorFinalTransform;
 pub use transform_aggregator_partial::AggregatorPartialTransform;
+pub use transform_create_sets::receive_scalar_subquery_value;
 pub use transform_create_sets::CreateSetsTransform;
 pub use transform_create_sets::SubQueriesPuller;
+pub use transform_expand::ExpandTransform;
 pub use transform_expression::ExpressionTransform;
 pub use transform_expression_executor::ExpressionExecutor;
 pub use transform_filter::HavingTransform;
@@ -45,6 +49,8 @@ pub use transform_group_by_final::GroupByFinalTransform;
 pub use transform_group_by_partial::GroupByPartialTransform;
 pub use transform_limit::LimitTransform;
 pub use transform_limit_by::LimitByTransform;
+pub use transform_profiling::PlanProfiling;
+pub use transform_profiling::ProfilingTransform;
 pub use transform_projection::ProjectionTransform;
 pub use transform_remote::RemoteTransform;
 pub use transform_sink::SinkTransform;
@@ -237,42 +237,48 @@ impl<'a> SubQueriesPuller<'a> {
 
     fn receive_scalar_subquery_res(mut pipeline: Pipeline) -> SharedFuture<'a> {
         let subquery_future = async move {
-            let mut stream = pipeline.execute().await?;
+            let stream = pipeline.execute().await?;
+            receive_scalar_subquery_value(stream).await
+        };
 
-            let mut columns = None;
-            while let Some(data_block) = stream.next().await {
-                let data_block = data_block?;
+        subquery_future.boxed().shared()
+    }
+}
 
-                if data_block.num_rows() != 1 || columns.is_some() {
+/// Receive the only row of a scalar subquery, it's a struct if there are several columns.
+pub async fn receive_scalar_subquery_value(
+    mut stream: SendableDataBlockStream,
+) -> Result<DataValue> {
+    let mut columns = None;
+    while let Some(data_block) = stream.next().await {
+        let data_block = data_block?;
+
+        if data_block.num_rows() != 1 || columns.is_some() {
+            return Err(ErrorCode::ScalarSubqueryBadRows(
+                "Scalar subquery result set must be one row.",
+            ));
+        }
+
+        let mut columns_data = Vec::with_capacity(data_block.num_columns());
+        for column in data_block.columns() {
+            match column.len() {
+                1 => columns_data.push(column.get(0)),
+                _ => {
                     return Err(ErrorCode::ScalarSubqueryBadRows(
                         "Scalar subquery result set must be one row.",
-                    ));
-                }
-
-                let mut columns_data = Vec::with_capacity(data_block.num_columns());
-                for column in data_block.columns() {
-                    match column.len() {
-                        1 => columns_data.push(column.get(0)),
-                        _ => {
-                            return Err(ErrorCode::ScalarSubqueryBadRows(
-                                "Scalar subquery result set must be one row.",
-                            ))
-                        }
-                    }
+                    ))
                 }
-
-                columns = Some(columns_data)
             }
+        }
 
-            match columns {
-                Some(mut data) if data.len() == 1 => Ok(data.remove(0)),
-                Some(data) => Ok(DataValue::Struct(data)),
-                None => Err(ErrorCode::ScalarSubqueryBadRows(
-                    "Scalar subquery result set must be one row.",
-                )),
-            }
-        };
+        columns = Some(columns_data)
+    }
 
-        subquery_future.boxed().shared()
+    match columns {
+        Some(mut data) if data.len() == 1 => Ok(data.remove(0)),
+        Some(data) => Ok(DataValue::Struct(data)),
+        None => Err(ErrorCode::ScalarSubqueryBadRows(
+            "Scalar subquery result set must be one row.",
+        )),
     }
 }
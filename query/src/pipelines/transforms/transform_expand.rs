@@ -0,0 +1,124 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::sync::Arc;
+
+use common_datablocks::DataBlock;
+use common_datavalues2::DataSchemaRef;
+use common_exception::Result;
+use common_planners::col;
+use common_planners::Expression;
+use common_streams::SendableDataBlockStream;
+use futures::stream::StreamExt;
+
+use crate::pipelines::processors::EmptyProcessor;
+use crate::pipelines::processors::Processor;
+use crate::pipelines::transforms::ExpressionExecutor;
+
+/// Repeats each input block once per set, with the columns of the set appended.
+///
+/// Example:
+/// SELECT a, b, sum(c) FROM t GROUP BY ROLLUP(a, b);
+/// Expand transform with the sets [a, b], [a, NULL], [NULL, NULL] makes three blocks
+/// from each input block:
+/// |a|b|c|a|b|
+/// |a|b|c|a|NULL|
+/// |a|b|c|NULL|NULL|
+pub struct ExpandTransform {
+    input: Arc<dyn Processor>,
+    executors: Vec<ExpressionExecutor>,
+}
+
+impl ExpandTransform {
+    pub fn try_create(
+        input_schema: DataSchemaRef,
+        output_schema: DataSchemaRef,
+        sets: Vec<Vec<Expression>>,
+    ) -> Result<Self> {
+        let executors = sets
+            .into_iter()
+            .map(|set| {
+                let mut exprs = input_schema
+                    .fields()
+                    .iter()
+                    .map(|f| col(f.name()))
+                    .collect::<Vec<_>>();
+                exprs.extend(set);
+
+                ExpressionExecutor::try_create(
+                    "expand executor",
+                    input_schema.clone(),
+                    output_schema.clone(),
+                    exprs,
+                    true,
+                )
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(ExpandTransform {
+            input: Arc::new(EmptyProcessor::create()),
+            executors,
+        })
+    }
+
+    // The constants of a set become full columns, so the blocks of every set have the same layout.
+    fn expand_block(executor: &ExpressionExecutor, block: &DataBlock) -> Result<DataBlock> {
+        let block = executor.execute(block)?;
+        let columns = block
+            .columns()
+            .iter()
+            .map(|column| column.convert_full_column())
+            .collect::<Vec<_>>();
+        Ok(DataBlock::create(block.schema().clone(), columns))
+    }
+}
+
+#[async_trait::async_trait]
+impl Processor for ExpandTransform {
+    fn name(&self) -> &str {
+        "ExpandTransform"
+    }
+
+    fn connect_to(&mut self, input: Arc<dyn Processor>) -> Result<()> {
+        self.input = input;
+        Ok(())
+    }
+
+    fn inputs(&self) -> Vec<Arc<dyn Processor>> {
+        vec![self.input.clone()]
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    async fn execute(&self) -> Result<SendableDataBlockStream> {
+        let executors = self.executors.clone();
+        let input_stream = self.input.execute().await?;
+
+        let stream = input_stream.flat_map(move |block| {
+            let blocks = match block {
+                Err(cause) => vec![Err(cause)],
+                Ok(block) => executors
+                    .iter()
+                    .map(|executor| Self::expand_block(executor, &block))
+                    .collect::<Vec<_>>(),
+            };
+            futures::stream::iter(blocks)
+        });
+
+        Ok(Box::pin(stream))
+    }
+}
@@ -65,6 +65,10 @@ impl ExpressionTransform {
             executor,
         })
     }
+
+    pub fn executor(&self) -> &ExpressionExecutor {
+        &self.executor
+    }
 }
 
 #[async_trait::async_trait]
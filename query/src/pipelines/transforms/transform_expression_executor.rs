@@ -19,7 +19,12 @@ use common_datablocks::DataBlock;
 use common_datavalues2::prelude::*;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_functions::scalars::CoalesceFunction;
+use common_functions::scalars::MultiIfFunction;
+use common_infallible::RwLock;
+use common_planners::ActionCoalesce;
 use common_planners::ActionFunction;
+use common_planners::ActionMultiIf;
 use common_planners::Expression;
 use common_planners::ExpressionAction;
 use common_planners::ExpressionChain;
@@ -31,11 +36,13 @@ use common_tracing::tracing;
 pub struct ExpressionExecutor {
     // description of this executor
     description: String,
-    _input_schema: DataSchemaRef,
+    input_schema: DataSchemaRef,
     output_schema: DataSchemaRef,
     chain: Arc<ExpressionChain>,
     // whether to perform alias action in executor
     alias_project: bool,
+    // executors of the lazily evaluated arguments, compiled once and shared by all blocks
+    lazy_executors: Arc<RwLock<HashMap<String, Arc<ExpressionExecutor>>>>,
 }
 
 impl ExpressionExecutor {
@@ -50,13 +57,18 @@ impl ExpressionExecutor {
 
         Ok(Self {
             description: description.to_string(),
-            _input_schema: input_schema,
+            input_schema,
             output_schema,
             chain: Arc::new(chain),
             alias_project,
+            lazy_executors: Arc::new(RwLock::new(HashMap::new())),
         })
     }
 
+    pub fn chain(&self) -> &ExpressionChain {
+        &self.chain
+    }
+
     pub fn validate(&self) -> Result<()> {
         Ok(())
     }
@@ -110,6 +122,14 @@ impl ExpressionExecutor {
                     let column_with_field = self.execute_function(&mut column_map, f, rows)?;
                     column_map.insert(f.name.as_str(), column_with_field);
                 }
+                ExpressionAction::Coalesce(c) => {
+                    let column_with_field = self.execute_coalesce(block, &mut column_map, c)?;
+                    column_map.insert(c.name.as_str(), column_with_field);
+                }
+                ExpressionAction::MultiIf(m) => {
+                    let column_with_field = self.execute_multi_if(block, &column_map, m)?;
+                    column_map.insert(m.name.as_str(), column_with_field);
+                }
                 ExpressionAction::Constant(constant) => {
                     let column = constant
                         .data_type
@@ -188,4 +208,155 @@ impl ExpressionExecutor {
             DataField::new(&f.name, f.return_type.clone()),
         ))
     }
+
+    // Evaluate the arguments one by one, each argument is only evaluated for
+    // the rows where the result is still null.
+    fn execute_coalesce(
+        &self,
+        block: &DataBlock,
+        column_map: &mut HashMap<&str, ColumnWithField>,
+        c: &ActionCoalesce,
+    ) -> Result<ColumnWithField> {
+        let first_name = c.args[0].column_name();
+        let mut column = column_map
+            .get(first_name.as_str())
+            .map(|column| column.column().clone())
+            .ok_or_else(|| {
+                ErrorCode::LogicalError("Arguments must be prepared before coalesce transform")
+            })?;
+
+        for arg in c.args.iter().skip(1) {
+            let nulls = (0..column.len())
+                .map(|row| column.null_at(row))
+                .collect::<Vec<_>>();
+            let null_rows = nulls.iter().filter(|null| **null).count();
+            if null_rows == 0 {
+                break;
+            }
+
+            let null_block = match null_rows == block.num_rows() {
+                true => block.clone(),
+                false => {
+                    let predicate = BooleanColumn::from_slice(&nulls).arc();
+                    DataBlock::filter_block(block, &predicate)?
+                }
+            };
+
+            let executor = self.lazy_executor(block.schema(), arg)?;
+            let fill = executor.execute(&null_block)?;
+            column = CoalesceFunction::fill_null_rows(&column, fill.column(0), &c.return_type)?;
+        }
+
+        let column = CoalesceFunction::finish(&column, &c.return_type)?;
+        Ok(ColumnWithField::new(
+            column,
+            DataField::new(&c.name, c.return_type.clone()),
+        ))
+    }
+
+    // Evaluate the branches one by one, the condition of a branch is only evaluated for the
+    // rows where all the previous conditions are false, its value for the rows it selects.
+    fn execute_multi_if(
+        &self,
+        block: &DataBlock,
+        column_map: &HashMap<&str, ColumnWithField>,
+        m: &ActionMultiIf,
+    ) -> Result<ColumnWithField> {
+        let first_name = m.args[0].column_name();
+        let first_condition = column_map
+            .get(first_name.as_str())
+            .map(|column| column.column().clone())
+            .ok_or_else(|| {
+                ErrorCode::LogicalError("Arguments must be prepared before multiIf transform")
+            })?;
+
+        let mut values = vec![DataValue::Null; block.num_rows()];
+        let mut remaining = (0..block.num_rows() as u32).collect::<Vec<_>>();
+        let (branches, else_value) = m.args.split_at(m.args.len() - 1);
+        for (index, branch) in branches.chunks(2).enumerate() {
+            if remaining.is_empty() {
+                break;
+            }
+
+            let condition = match index {
+                0 => first_condition.clone(),
+                _ => {
+                    let executor = self.lazy_executor(block.schema(), &branch[0])?;
+                    let remaining_block = Self::take_rows(block, &remaining)?;
+                    executor.execute(&remaining_block)?.column(0).clone()
+                }
+            };
+
+            let selected = MultiIfFunction::true_rows(&condition)?;
+            let (selected_rows, other_rows): (Vec<_>, Vec<_>) = remaining
+                .iter()
+                .zip(selected)
+                .partition(|(_, selected)| *selected);
+            let selected_rows = selected_rows
+                .into_iter()
+                .map(|(row, _)| *row)
+                .collect::<Vec<_>>();
+            remaining = other_rows.into_iter().map(|(row, _)| *row).collect();
+
+            if !selected_rows.is_empty() {
+                let executor = self.lazy_executor(block.schema(), &branch[1])?;
+                let value = executor.execute(&Self::take_rows(block, &selected_rows)?)?;
+                MultiIfFunction::fill_rows(
+                    &mut values,
+                    &selected_rows,
+                    value.column(0),
+                    &m.return_type,
+                )?;
+            }
+        }
+
+        if !remaining.is_empty() {
+            let executor = self.lazy_executor(block.schema(), &else_value[0])?;
+            let value = executor.execute(&Self::take_rows(block, &remaining)?)?;
+            MultiIfFunction::fill_rows(&mut values, &remaining, value.column(0), &m.return_type)?;
+        }
+
+        let column = m.return_type.create_column(&values)?;
+        Ok(ColumnWithField::new(
+            column,
+            DataField::new(&m.name, m.return_type.clone()),
+        ))
+    }
+
+    fn take_rows(block: &DataBlock, rows: &[u32]) -> Result<DataBlock> {
+        match rows.len() == block.num_rows() {
+            true => Ok(block.clone()),
+            false => DataBlock::block_take_by_indices(block, rows),
+        }
+    }
+
+    // The chain of a lazily evaluated argument only depends on the argument and the input schema,
+    // so it is compiled on the first block and reused for the following ones.
+    fn lazy_executor(
+        &self,
+        schema: &DataSchemaRef,
+        expr: &Expression,
+    ) -> Result<Arc<ExpressionExecutor>> {
+        let cacheable = schema == &self.input_schema;
+        let name = expr.column_name();
+        if cacheable {
+            if let Some(executor) = self.lazy_executors.read().get(&name) {
+                return Ok(executor.clone());
+            }
+        }
+
+        let output_schema = DataSchemaRefExt::create(vec![expr.to_data_field(schema)?]);
+        let executor = Arc::new(ExpressionExecutor::try_create(
+            "lazy executor",
+            schema.clone(),
+            output_schema,
+            vec![expr.clone()],
+            false,
+        )?);
+
+        if cacheable {
+            self.lazy_executors.write().insert(name, executor.clone());
+        }
+        Ok(executor)
+    }
 }
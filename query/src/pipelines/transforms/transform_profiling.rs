@@ -0,0 +1,102 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use common_exception::Result;
+use common_infallible::Mutex;
+use common_planners::PlanNode;
+use common_streams::ProfilingStream;
+use common_streams::SendableDataBlockStream;
+use common_streams::StreamProfiling;
+use common_tracing::tracing;
+
+use crate::pipelines::processors::EmptyProcessor;
+use crate::pipelines::processors::Processor;
+
+/// The execution metrics of the plan nodes, used by EXPLAIN ANALYZE.
+/// A node is identified by its address, so the plan must not be moved
+/// between building the pipeline and reading the metrics.
+#[derive(Default)]
+pub struct PlanProfiling {
+    nodes: Mutex<HashMap<usize, Arc<StreamProfiling>>>,
+}
+
+impl PlanProfiling {
+    pub fn create() -> Arc<Self> {
+        Arc::new(Self::default())
+    }
+
+    pub fn register(&self, node: &PlanNode) -> Arc<StreamProfiling> {
+        let mut nodes = self.nodes.lock();
+        nodes
+            .entry(Self::node_key(node))
+            .or_insert_with(StreamProfiling::create)
+            .clone()
+    }
+
+    pub fn get(&self, node: &PlanNode) -> Option<Arc<StreamProfiling>> {
+        self.nodes.lock().get(&Self::node_key(node)).cloned()
+    }
+
+    fn node_key(node: &PlanNode) -> usize {
+        node as *const PlanNode as usize
+    }
+}
+
+pub struct ProfilingTransform {
+    profiling: Arc<StreamProfiling>,
+    input: Arc<dyn Processor>,
+}
+
+impl ProfilingTransform {
+    pub fn create(profiling: Arc<StreamProfiling>) -> Self {
+        ProfilingTransform {
+            profiling,
+            input: Arc::new(EmptyProcessor::create()),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl Processor for ProfilingTransform {
+    fn name(&self) -> &str {
+        "ProfilingTransform"
+    }
+
+    fn connect_to(&mut self, input: Arc<dyn Processor>) -> Result<()> {
+        self.input = input;
+        Ok(())
+    }
+
+    fn inputs(&self) -> Vec<Arc<dyn Processor>> {
+        vec![self.input.clone()]
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    #[tracing::instrument(level = "debug", name = "profiling_execute", skip(self))]
+    async fn execute(&self) -> Result<SendableDataBlockStream> {
+        tracing::debug!("execute...");
+        let input_stream = self.input.execute().await?;
+        Ok(Box::pin(ProfilingStream::create(
+            input_stream,
+            self.profiling.clone(),
+        )))
+    }
+}
@@ -20,6 +20,7 @@ use common_exception::ErrorCode;
 use common_exception::Result;
 use common_streams::SendableDataBlockStream;
 use common_tracing::tracing;
+use futures::StreamExt;
 
 use crate::api::FlightClient;
 use crate::api::FlightTicket;
@@ -58,6 +59,20 @@ impl RemoteTransform {
             .create_node_conn(&node_name, &self.ctx.get_config())
             .await
     }
+
+    fn stage_id(&self) -> String {
+        match &self.ticket {
+            FlightTicket::StreamTicket(ticket) => ticket.stage_id.clone(),
+        }
+    }
+
+    fn stage_error(ctx: &QueryContext, stage_id: &str, node: &str, cause: ErrorCode) -> ErrorCode {
+        let message = match ctx.get_query_stages().len() {
+            0 => format!("error in stage {} on node {}", stage_id, node),
+            stages => format!("error in stage {}/{} on node {}", stage_id, stages, node),
+        };
+        cause.add_message(message)
+    }
 }
 
 #[async_trait::async_trait]
@@ -90,12 +105,28 @@ impl Processor for RemoteTransform {
 
         let data_schema = self.schema.clone();
         let timeout = self.ctx.get_settings().get_flight_client_timeout()?;
+        let verify_checksum = self.ctx.get_settings().get_enable_exchange_checksum()? != 0;
 
+        let stage_id = self.stage_id();
+        let fetch_node_name = self.fetch_node_name.clone();
         let fetch_ticket = self.ticket.clone();
         let mut flight_client = self.flight_client().await?;
         let fetch_stream = flight_client
-            .fetch_stream(fetch_ticket, data_schema, timeout)
-            .await?;
-        Ok(Box::pin(self.ctx.try_create_abortable(fetch_stream)?))
+            .fetch_stream(fetch_ticket, data_schema, timeout, verify_checksum)
+            .await
+            .map_err(|cause| Self::stage_error(&self.ctx, &stage_id, &fetch_node_name, cause))?;
+
+        let ctx = self.ctx.clone();
+        let fetch_stream = fetch_stream.map(move |item| match item {
+            Ok(block) => {
+                ctx.add_query_stage_progress(&stage_id, block.num_rows(), block.memory_size());
+                Ok(block)
+            }
+            Err(cause) => Err(Self::stage_error(&ctx, &stage_id, &fetch_node_name, cause)),
+        });
+
+        Ok(Box::pin(
+            self.ctx.try_create_abortable(Box::pin(fetch_stream))?,
+        ))
     }
 }
@@ -81,6 +81,18 @@ impl Processor for SortMergeTransform {
 
         while let Some(block) = stream.next().await {
             blocks.push(block?);
+
+            // Top-N: keep only the first `limit` rows seen so far instead of buffering every block.
+            if let Some(limit) = self.limit {
+                if blocks.len() > 1 {
+                    let top_n = DataBlock::merge_sort_blocks(
+                        &blocks,
+                        &sort_columns_descriptions,
+                        Some(limit),
+                    )?;
+                    blocks = vec![top_n];
+                }
+            }
         }
 
         let results = match blocks.len() {
@@ -92,6 +92,7 @@ pub fn get_sort_descriptions(
                 ref expr,
                 asc,
                 nulls_first,
+                case_insensitive,
                 ..
             } => {
                 let column_name = expr.to_data_field(schema)?.name().clone();
@@ -99,6 +100,7 @@ pub fn get_sort_descriptions(
                     column_name,
                     asc,
                     nulls_first,
+                    case_insensitive,
                 });
             }
             _ => {
@@ -13,19 +13,31 @@
 // limitations under the License.
 
 use std::any::Any;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::Hash;
+use std::hash::Hasher;
 use std::sync::Arc;
 
+use async_stream::stream;
+use common_datablocks::DataBlock;
+use common_datavalues2::prelude::*;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_planners::ReadDataSourcePlan;
+use common_planners::TableSample;
 use common_streams::CorrectWithSchemaStream;
 use common_streams::ProgressStream;
 use common_streams::SendableDataBlockStream;
 use common_tracing::tracing;
+use rand::rngs::StdRng;
+use rand::Rng;
+use rand::SeedableRng;
+use tokio_stream::StreamExt;
 
 use crate::pipelines::processors::EmptyProcessor;
 use crate::pipelines::processors::Processor;
 use crate::sessions::QueryContext;
+use crate::storages::Table;
 
 pub struct SourceTransform {
     ctx: Arc<QueryContext>,
@@ -39,14 +51,108 @@ impl SourceTransform {
 
     async fn read_table(&self) -> Result<SendableDataBlockStream> {
         let table = self.ctx.build_table_from_source_plan(&self.source_plan)?;
+        match self
+            .source_plan
+            .push_downs
+            .as_ref()
+            .and_then(|v| v.sample.clone())
+        {
+            None => Self::read_parts(&self.ctx, &self.ctx, table, &self.source_plan).await,
+            Some(sample) => match sample.seed {
+                None => {
+                    let stream =
+                        Self::read_parts(&self.ctx, &self.ctx, table, &self.source_plan).await?;
+                    Ok(Box::pin(stream.map(move |data_block| {
+                        data_block.and_then(|block| {
+                            Self::sample_block(&sample, StdRng::from_entropy(), block)
+                        })
+                    })))
+                }
+                Some(seed) => Ok(self.read_repeatable_sample(table, sample, seed)),
+            },
+        }
+    }
 
-        let table_stream = table.read(self.ctx.clone(), &self.source_plan);
+    /// Read the partitions in the queue of `parts_ctx`.
+    async fn read_parts(
+        ctx: &Arc<QueryContext>,
+        parts_ctx: &Arc<QueryContext>,
+        table: Arc<dyn Table>,
+        source_plan: &ReadDataSourcePlan,
+    ) -> Result<SendableDataBlockStream> {
+        let table_stream = table.read(parts_ctx.clone(), source_plan);
         let progress_stream =
-            ProgressStream::try_create(table_stream.await?, self.ctx.get_scan_progress())?;
+            ProgressStream::try_create(table_stream.await?, ctx.get_scan_progress())?;
 
-        Ok(Box::pin(
-            self.ctx.try_create_abortable(Box::pin(progress_stream))?,
-        ))
+        let stream = ctx.try_create_abortable(Box::pin(progress_stream))?;
+        Ok(Box::pin(stream))
+    }
+
+    // With REPEATABLE, the partitions are read one by one, each from its own context. The rows
+    // kept depend only on the seed, the part name and the offset of the block in the part, so
+    // the sample does not change with the thread a part is read by.
+    fn read_repeatable_sample(
+        &self,
+        table: Arc<dyn Table>,
+        sample: TableSample,
+        seed: u64,
+    ) -> SendableDataBlockStream {
+        let ctx = self.ctx.clone();
+        let source_plan = self.source_plan.clone();
+        Box::pin(stream! {
+            loop {
+                let part = match ctx.try_get_partitions(1) {
+                    Ok(mut parts) => match parts.pop() {
+                        None => break,
+                        Some(part) => part,
+                    },
+                    Err(cause) => {
+                        yield Err(cause);
+                        break;
+                    }
+                };
+
+                let part_name = part.name.clone();
+                let part_ctx = QueryContext::create_from(ctx.clone());
+                let part_stream = match part_ctx.try_set_partitions(vec![part]) {
+                    Ok(_) => Self::read_parts(&ctx, &part_ctx, table.clone(), &source_plan).await,
+                    Err(cause) => Err(cause),
+                };
+                let mut part_stream = match part_stream {
+                    Ok(part_stream) => part_stream,
+                    Err(cause) => {
+                        yield Err(cause);
+                        break;
+                    }
+                };
+
+                let mut offset = 0;
+                while let Some(data_block) = part_stream.next().await {
+                    yield data_block.and_then(|block| {
+                        let rng = StdRng::seed_from_u64(Self::part_seed(seed, &part_name, offset));
+                        offset += block.num_rows() as u64;
+                        Self::sample_block(&sample, rng, block)
+                    });
+                }
+            }
+        })
+    }
+
+    fn part_seed(seed: u64, part_name: &str, offset: u64) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        seed.hash(&mut hasher);
+        part_name.hash(&mut hasher);
+        offset.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    // Keep each row with the probability of the sample percentage.
+    fn sample_block(sample: &TableSample, mut rng: StdRng, block: DataBlock) -> Result<DataBlock> {
+        let probability = sample.percent / 100.0;
+        let mask = (0..block.num_rows())
+            .map(|_| rng.gen_bool(probability))
+            .collect::<Vec<_>>();
+        DataBlock::filter_block(&block, &BooleanColumn::from_slice(&mask).arc())
     }
 }
 
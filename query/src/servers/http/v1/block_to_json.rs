@@ -16,6 +16,7 @@ use std::sync::Arc;
 
 use common_datablocks::DataBlock;
 use common_exception::Result;
+use common_io::prelude::FloatFormat;
 use serde_json::Value as JsonValue;
 
 pub(crate) type JsonBlock = Vec<Vec<JsonValue>>;
@@ -35,7 +36,7 @@ fn transpose(col_table: Vec<Vec<JsonValue>>) -> Vec<Vec<JsonValue>> {
     row_table
 }
 
-pub fn block_to_json(block: &DataBlock) -> Result<Vec<Vec<JsonValue>>> {
+pub fn block_to_json(block: &DataBlock, float_format: FloatFormat) -> Result<Vec<Vec<JsonValue>>> {
     let mut col_table = Vec::new();
     let columns_size = block.columns().len();
     for col_index in 0..columns_size {
@@ -43,7 +44,7 @@ pub fn block_to_json(block: &DataBlock) -> Result<Vec<Vec<JsonValue>>> {
         let column = column.convert_full_column();
         let field = block.schema().field(col_index);
         let data_type = field.data_type();
-        let serializer = data_type.create_serializer();
+        let serializer = data_type.create_serializer_with_format(float_format);
         col_table.push(serializer.serialize_json(&column)?);
     }
 
@@ -23,9 +23,13 @@ use common_base::ProgressValues;
 use common_base::TrySpawn;
 use common_datablocks::DataBlock;
 use common_datavalues2::DataSchemaRef;
+use common_datavalues2::DataSchemaRefExt;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_io::prelude::FloatFormat;
 use common_meta_types::UserInfo;
+use common_streams::DataBlockStream;
+use common_streams::SendableDataBlockStream;
 use common_tracing::tracing;
 use futures::StreamExt;
 use serde::Deserialize;
@@ -35,6 +39,7 @@ use ExecuteState::*;
 use super::http_query::HttpQueryRequest;
 use crate::interpreters::Interpreter;
 use crate::interpreters::InterpreterFactory;
+use crate::interpreters::InterpreterResult;
 use crate::sessions::QueryContext;
 use crate::sessions::SessionManager;
 use crate::sessions::SessionRef;
@@ -138,7 +143,7 @@ impl ExecuteState {
         session_manager: &Arc<SessionManager>,
         user_info: &UserInfo,
         block_tx: mpsc::Sender<DataBlock>,
-    ) -> Result<(Arc<RwLock<Executor>>, DataSchemaRef)> {
+    ) -> Result<(Arc<RwLock<Executor>>, DataSchemaRef, FloatFormat)> {
         let sql = &request.sql;
         let session = session_manager.create_session("http-statement")?;
         let ctx = session.create_query_context().await?;
@@ -147,18 +152,26 @@ impl ExecuteState {
         };
         ctx.attach_query_str(sql);
         session.set_current_user(user_info.clone());
+        let float_format = ctx.get_settings().get_float_format()?;
 
         let plan = PlanParser::parse(ctx.clone(), sql).await?;
-        let schema = plan.schema();
 
-        let interpreter = InterpreterFactory::get(ctx.clone(), plan.clone())?;
+        let interpreter = InterpreterFactory::get(ctx.clone(), plan)?;
         // Write Start to query log table.
         let _ = interpreter
             .start()
             .await
             .map_err(|e| tracing::error!("interpreter.start.error: {:?}", e));
 
-        let data_stream = interpreter.execute(None).await?;
+        let (schema, data_stream) = match interpreter.execute_result(None).await? {
+            InterpreterResult::ResultSet(schema, data_stream) => (schema, data_stream),
+            InterpreterResult::Affected(_) | InterpreterResult::Empty => {
+                let schema = DataSchemaRefExt::create(vec![]);
+                let data_stream: SendableDataBlockStream =
+                    Box::pin(DataBlockStream::create(schema.clone(), None, vec![]));
+                (schema, data_stream)
+            }
+        };
         let mut data_stream = ctx.try_create_abortable(data_stream)?;
 
         let (abort_tx, mut abort_rx) = mpsc::channel(2);
@@ -202,6 +215,6 @@ impl ExecuteState {
                 tracing::debug!("drop block sender!");
             })?;
 
-        Ok((executor_clone, schema))
+        Ok((executor_clone, schema, float_format))
     }
 }
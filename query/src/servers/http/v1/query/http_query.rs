@@ -110,9 +110,13 @@ impl HttpQuery {
         //TODO(youngsofun): support config/set channel size
         let (block_tx, block_rx) = mpsc::channel(10);
 
-        let (state, schema) =
+        let (state, schema, float_format) =
             ExecuteState::try_create(&request, session_manager, user_info, block_tx).await?;
-        let data = Arc::new(TokioMutex::new(ResultDataManager::new(schema, block_rx)));
+        let data = Arc::new(TokioMutex::new(ResultDataManager::new(
+            schema,
+            float_format,
+            block_rx,
+        )));
         let query = HttpQuery {
             id: id.to_string(),
             request,
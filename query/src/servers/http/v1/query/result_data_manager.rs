@@ -22,6 +22,7 @@ use common_datablocks::DataBlock;
 use common_datavalues2::DataSchemaRef;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_io::prelude::FloatFormat;
 use common_tracing::tracing;
 
 use crate::servers::http::v1::block_to_json;
@@ -50,6 +51,7 @@ pub struct ResponseData {
 
 pub struct ResultDataManager {
     pub(crate) schema: DataSchemaRef,
+    float_format: FloatFormat,
     total_rows: usize,
     total_pages: usize,
     last_page: Option<Page>,
@@ -58,9 +60,14 @@ pub struct ResultDataManager {
 }
 
 impl ResultDataManager {
-    pub fn new(schema: DataSchemaRef, block_rx: mpsc::Receiver<DataBlock>) -> ResultDataManager {
+    pub fn new(
+        schema: DataSchemaRef,
+        float_format: FloatFormat,
+        block_rx: mpsc::Receiver<DataBlock>,
+    ) -> ResultDataManager {
         ResultDataManager {
             schema,
+            float_format,
             block_rx,
             total_rows: 0,
             last_page: None,
@@ -130,13 +137,14 @@ impl ResultDataManager {
         let mut results: Vec<JsonBlock> = Vec::new();
         let mut rows = 0;
         let block_rx = &mut self.block_rx;
+        let float_format = self.float_format;
 
         let mut end = false;
         loop {
             match ResultDataManager::receive(block_rx, tp).await {
                 Ok(block) => {
                     rows += block.num_rows();
-                    results.push(block_to_json(&block).unwrap());
+                    results.push(block_to_json(&block, float_format).unwrap());
                     // TODO(youngsofun):  set it in post if needed
                     if rows >= TARGET_ROWS_PER_PAGE {
                         break;
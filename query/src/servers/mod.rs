@@ -21,8 +21,11 @@ pub use server::ShutdownHandle;
 
 pub use self::mysql::MySQLConnection;
 pub use self::mysql::MySQLHandler;
+pub use self::postgres::PgConnection;
+pub use self::postgres::PgHandler;
 
 mod clickhouse;
 pub mod http;
 mod mysql;
+mod postgres;
 pub(crate) mod server;
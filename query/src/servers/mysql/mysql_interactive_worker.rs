@@ -20,6 +20,7 @@ use common_datablocks::DataBlock;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_io::prelude::*;
+use common_planners::EmptyPlan;
 use common_planners::PlanNode;
 use common_tracing::tracing;
 use metrics::histogram;
@@ -34,8 +35,11 @@ use regex::RegexSet;
 use tokio_stream::StreamExt;
 
 use crate::interpreters::InterpreterFactory;
+use crate::interpreters::InterpreterQueryLog;
+use crate::interpreters::InterpreterResult;
 use crate::servers::mysql::writers::DFInitResultWriter;
 use crate::servers::mysql::writers::DFQueryResultWriter;
+use crate::servers::mysql::writers::QueryResult;
 use crate::sessions::QueryContext;
 use crate::sessions::SessionRef;
 use crate::sql::PlanParser;
@@ -168,7 +172,8 @@ impl<W: std::io::Write + Send> AsyncMysqlShim<W> for InteractiveWorker<W> {
             ));
         }
 
-        let mut writer = DFQueryResultWriter::create(writer);
+        let float_format = self.session.get_settings().get_float_format()?;
+        let mut writer = DFQueryResultWriter::create(writer, float_format);
 
         let instant = Instant::now();
         let blocks = self.base.do_query(query).await;
@@ -266,22 +271,28 @@ impl<W: std::io::Write> InteractiveWorkerBase<W> {
     }
 
     #[tracing::instrument(level = "debug", skip(self))]
-    async fn do_query(&mut self, query: &str) -> Result<(Vec<DataBlock>, String)> {
+    async fn do_query(&mut self, query: &str) -> Result<QueryResult> {
         tracing::debug!("{}", query);
 
         if self.federated_server_setup_set_or_jdbc_command(query) {
-            Ok((vec![DataBlock::empty()], String::from("")))
+            Ok(QueryResult::default())
         } else {
             let context = self.session.create_query_context().await?;
             context.attach_query_str(query);
             let (plan, hints) = PlanParser::parse_with_hint(query, context.clone()).await;
+            let ignore_error_code = hints.iter().find_map(|v| v.ignore_error_code);
 
             match hints
                 .iter()
                 .find(|v| v.error_code.is_some())
                 .and_then(|x| x.error_code)
             {
-                None => Self::exec_query(plan, &context).await,
+                None => match ignore_error_code {
+                    None => Self::exec_query(plan, &context).await,
+                    Some(ignore_error_code) => {
+                        Self::exec_query_ignore_error(plan, &context, ignore_error_code).await
+                    }
+                },
                 Some(hint_error_code) => match Self::exec_query(plan, &context).await {
                     Ok(_) => Err(ErrorCode::UnexpectedError(format!(
                         "Expected server error code: {} but got: Ok.",
@@ -289,7 +300,7 @@ impl<W: std::io::Write> InteractiveWorkerBase<W> {
                     ))),
                     Err(error_code) => {
                         if hint_error_code == error_code.code() {
-                            Ok((vec![DataBlock::empty()], String::from("")))
+                            Ok(QueryResult::default())
                         } else {
                             let actual_code = error_code.code();
                             Err(error_code.add_message(format!(
@@ -307,7 +318,7 @@ impl<W: std::io::Write> InteractiveWorkerBase<W> {
     async fn exec_query(
         plan: Result<PlanNode>,
         context: &Arc<QueryContext>,
-    ) -> Result<(Vec<DataBlock>, String)> {
+    ) -> Result<QueryResult> {
         let instant = Instant::now();
 
         let interpreter = InterpreterFactory::get(context.clone(), plan?)?;
@@ -316,20 +327,53 @@ impl<W: std::io::Write> InteractiveWorkerBase<W> {
             .start()
             .await
             .map_err(|e| tracing::error!("interpreter.start.error: {:?}", e));
-        let data_stream = interpreter.execute(None).await?;
+        let interpreter_result = interpreter.execute_result(None).await?;
         histogram!(
             super::mysql_metrics::METRIC_INTERPRETER_USEDTIME,
             instant.elapsed()
         );
 
-        let collector = data_stream.collect::<Result<Vec<DataBlock>>>();
-        let query_result = collector.await;
+        let query_result = match interpreter_result {
+            InterpreterResult::ResultSet(_, data_stream) => data_stream
+                .collect::<Result<Vec<DataBlock>>>()
+                .await
+                .map(|blocks| (blocks, 0)),
+            InterpreterResult::Affected(rows) => Ok((vec![], rows as u64)),
+            InterpreterResult::Empty => Ok((vec![], 0)),
+        };
         // Write finish query log.
         let _ = interpreter
             .finish()
             .await
             .map_err(|e| tracing::error!("interpreter.finish.error: {:?}", e));
-        query_result.map(|data| (data, Self::extra_info(context, instant)))
+        query_result.map(|(blocks, affected_rows)| {
+            QueryResult::create(blocks, affected_rows, Self::extra_info(context, instant))
+        })
+    }
+
+    /// The `ignore_error` hint turns the hinted error into an OK result with no rows,
+    /// the ignored error is still written to the query log.
+    async fn exec_query_ignore_error(
+        plan: Result<PlanNode>,
+        context: &Arc<QueryContext>,
+        ignore_error_code: u16,
+    ) -> Result<QueryResult> {
+        let log_plan = match &plan {
+            Ok(plan) => plan.clone(),
+            Err(_) => PlanNode::Empty(EmptyPlan::create()),
+        };
+
+        match Self::exec_query(plan, context).await {
+            Err(error_code) if error_code.code() == ignore_error_code => {
+                let query_log = InterpreterQueryLog::create(context.clone(), log_plan);
+                let _ = query_log
+                    .log_error(&error_code)
+                    .await
+                    .map_err(|e| tracing::error!("interpreter.log_error.error: {:?}", e));
+                Ok(QueryResult::default())
+            }
+            result => result,
+        }
     }
 
     fn extra_info(context: &Arc<QueryContext>, instant: Instant) -> String {
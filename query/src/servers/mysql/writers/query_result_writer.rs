@@ -26,22 +26,48 @@ use common_exception::ErrorCode;
 use common_exception::Result;
 use common_exception::ABORT_QUERY;
 use common_exception::ABORT_SESSION;
+use common_io::prelude::FloatFormat;
 use common_tracing::tracing;
 use msql_srv::*;
 
+/// Collected outcome of a query: the result set blocks, or the rows affected by a write.
+#[derive(Default)]
+pub struct QueryResult {
+    pub blocks: Vec<DataBlock>,
+    pub affected_rows: u64,
+    pub extra_info: String,
+}
+
+impl QueryResult {
+    pub fn create(blocks: Vec<DataBlock>, affected_rows: u64, extra_info: String) -> QueryResult {
+        QueryResult {
+            blocks,
+            affected_rows,
+            extra_info,
+        }
+    }
+}
+
 pub struct DFQueryResultWriter<'a, W: std::io::Write> {
     inner: Option<QueryResultWriter<'a, W>>,
+    float_format: FloatFormat,
 }
 
 impl<'a, W: std::io::Write> DFQueryResultWriter<'a, W> {
-    pub fn create(inner: QueryResultWriter<'a, W>) -> DFQueryResultWriter<'a, W> {
-        DFQueryResultWriter::<'a, W> { inner: Some(inner) }
+    pub fn create(
+        inner: QueryResultWriter<'a, W>,
+        float_format: FloatFormat,
+    ) -> DFQueryResultWriter<'a, W> {
+        DFQueryResultWriter::<'a, W> {
+            inner: Some(inner),
+            float_format,
+        }
     }
 
-    pub fn write(&mut self, query_result: Result<(Vec<DataBlock>, String)>) -> Result<()> {
+    pub fn write(&mut self, query_result: Result<QueryResult>) -> Result<()> {
         if let Some(writer) = self.inner.take() {
             match query_result {
-                Ok((blocks, extra_info)) => Self::ok(blocks, extra_info, writer)?,
+                Ok(query_result) => Self::ok(query_result, writer, self.float_format)?,
                 Err(error) => Self::err(&error, writer)?,
             }
         }
@@ -49,16 +75,18 @@ impl<'a, W: std::io::Write> DFQueryResultWriter<'a, W> {
     }
 
     fn ok(
-        blocks: Vec<DataBlock>,
-        extra_info: String,
+        query_result: QueryResult,
         dataset_writer: QueryResultWriter<'a, W>,
+        float_format: FloatFormat,
     ) -> Result<()> {
         // XXX: num_columns == 0 may is error?
         let default_response = OkResponse {
-            info: extra_info,
+            info: query_result.extra_info,
+            affected_rows: query_result.affected_rows,
             ..Default::default()
         };
 
+        let blocks = query_result.blocks;
         if blocks.is_empty() || (blocks[0].num_columns() == 0) {
             dataset_writer.completed(default_response)?;
             return Ok(());
@@ -159,15 +187,16 @@ impl<'a, W: std::io::Write> DFQueryResultWriter<'a, W> {
                                 (TypeID::String, DataValue::String(v)) => {
                                     row_writer.write_col(v)?
                                 }
-                                (TypeID::Struct, DataValue::Struct(_)) => {
-                                    let serializer = data_type.create_serializer();
+                                (TypeID::Struct, DataValue::Struct(_))
+                                | (TypeID::Float32 | TypeID::Float64, DataValue::Float64(_)) => {
+                                    let serializer =
+                                        data_type.create_serializer_with_format(float_format);
                                     row_writer.write_col(serializer.serialize_value(&val)?)?
                                 }
                                 (_, DataValue::Int64(v)) => row_writer.write_col(v)?,
 
                                 (_, DataValue::UInt64(v)) => row_writer.write_col(v)?,
 
-                                (_, DataValue::Float64(v)) => row_writer.write_col(v)?,
                                 (_, v) => {
                                     return Err(ErrorCode::BadDataValueType(format!(
                                         "Unsupported column type:{:?}, expected type in schema: {:?}",
@@ -0,0 +1,318 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::net::SocketAddr;
+
+use common_base::tokio::io::AsyncRead;
+use common_base::tokio::io::AsyncWrite;
+use common_datablocks::DataBlock;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_io::prelude::FloatFormat;
+use common_tracing::tracing;
+use futures::TryStreamExt;
+
+use crate::interpreters::InterpreterFactory;
+use crate::interpreters::InterpreterResult;
+use crate::servers::postgres::pg_protocol::read_message;
+use crate::servers::postgres::pg_protocol::read_startup_message;
+use crate::servers::postgres::pg_protocol::refuse_ssl;
+use crate::servers::postgres::pg_protocol::write_messages;
+use crate::servers::postgres::pg_protocol::BackendMessage;
+use crate::servers::postgres::pg_protocol::FieldDescription;
+use crate::servers::postgres::pg_protocol::FrontendMessage;
+use crate::servers::postgres::pg_protocol::StartupMessage;
+use crate::sessions::SessionRef;
+use crate::sql::PlanParser;
+use crate::users::auth::auth_mgr::Credential;
+
+/// Serve one PostgreSQL connection with the simple query protocol.
+pub struct InteractiveWorker {
+    session: SessionRef,
+    client_addr: SocketAddr,
+}
+
+impl InteractiveWorker {
+    pub fn create(session: SessionRef, client_addr: SocketAddr) -> InteractiveWorker {
+        InteractiveWorker {
+            session,
+            client_addr,
+        }
+    }
+
+    pub async fn run<S: AsyncRead + AsyncWrite + Unpin>(self, mut stream: S) -> Result<()> {
+        let params = match self.startup(&mut stream).await? {
+            None => return Ok(()),
+            Some(params) => params,
+        };
+
+        if let Err(cause) = self.handshake(&mut stream, &params).await {
+            let messages = [Self::error_response("28000", &cause)];
+            write_messages(&mut stream, &messages).await?;
+            return Err(cause);
+        }
+
+        loop {
+            let mut messages = match read_message(&mut stream).await? {
+                None | Some(FrontendMessage::Terminate) => return Ok(()),
+                Some(_) if self.session.is_aborting() => {
+                    let cause = ErrorCode::AbortedSession(
+                        "Aborting this connection. because we are try aborting server.",
+                    );
+                    let messages = [Self::error_response("57P01", &cause)];
+                    write_messages(&mut stream, &messages).await?;
+                    return Err(cause);
+                }
+                Some(FrontendMessage::Query(query)) => {
+                    self.on_query(&mut stream, &query).await?;
+                    vec![]
+                }
+                Some(FrontendMessage::Password(_)) => vec![Self::unsupported_message(b'p')],
+                Some(FrontendMessage::Unsupported(tag)) => vec![Self::unsupported_message(tag)],
+            };
+
+            messages.push(BackendMessage::ReadyForQuery);
+            write_messages(&mut stream, &messages).await?;
+        }
+    }
+
+    async fn startup<S: AsyncRead + AsyncWrite + Unpin>(
+        &self,
+        stream: &mut S,
+    ) -> Result<Option<HashMap<String, String>>> {
+        loop {
+            match read_startup_message(stream).await? {
+                StartupMessage::SslRequest => refuse_ssl(stream).await?,
+                // Queries are not cancellable through a side connection yet.
+                StartupMessage::CancelRequest => return Ok(None),
+                StartupMessage::Startup { params } => return Ok(Some(params)),
+            }
+        }
+    }
+
+    async fn handshake<S: AsyncRead + AsyncWrite + Unpin>(
+        &self,
+        stream: &mut S,
+        params: &HashMap<String, String>,
+    ) -> Result<()> {
+        let user = params.get("user").map(String::as_str).unwrap_or("root");
+
+        // Only ask for a password when the user has one.
+        match self.authenticate(user, None).await {
+            Ok(_) => {}
+            Err(cause) if cause.code() == ErrorCode::AuthenticateFailureCode() => {
+                let messages = [BackendMessage::AuthenticationCleartextPassword];
+                write_messages(stream, &messages).await?;
+
+                match read_message(stream).await? {
+                    Some(FrontendMessage::Password(password)) => {
+                        self.authenticate(user, Some(password.into_bytes())).await?
+                    }
+                    _ => {
+                        return Err(ErrorCode::AuthenticateFailure(
+                            "PostgreSQL handler expected a password message",
+                        ));
+                    }
+                }
+            }
+            Err(cause) => return Err(cause),
+        }
+
+        // libpq defaults the database to the user name, which is not a database here.
+        if let Some(database) = params.get("database") {
+            if database != user {
+                let context = self.session.create_query_context().await?;
+                context.set_current_database(database.clone()).await?;
+            }
+        }
+
+        let messages = [
+            BackendMessage::AuthenticationOk,
+            BackendMessage::ParameterStatus(
+                "server_version",
+                format!("{}-{}", "12.0", *crate::configs::DATABEND_COMMIT_VERSION),
+            ),
+            BackendMessage::ParameterStatus("server_encoding", "UTF8".to_string()),
+            BackendMessage::ParameterStatus("client_encoding", "UTF8".to_string()),
+            BackendMessage::ParameterStatus("DateStyle", "ISO, MDY".to_string()),
+            BackendMessage::ParameterStatus("integer_datetimes", "on".to_string()),
+            BackendMessage::ReadyForQuery,
+        ];
+        write_messages(stream, &messages).await
+    }
+
+    async fn authenticate(&self, user: &str, password: Option<Vec<u8>>) -> Result<()> {
+        let credential = Credential::Password {
+            name: user.to_string(),
+            password,
+            hostname: Some(self.client_addr.ip().to_string()),
+        };
+
+        let user_info = self
+            .session
+            .get_session_manager()
+            .get_auth_manager()
+            .auth(&credential)
+            .await?;
+        self.session.set_current_user(user_info);
+        Ok(())
+    }
+
+    async fn on_query<S: AsyncWrite + Unpin>(&self, stream: &mut S, query: &str) -> Result<()> {
+        if query.trim().trim_end_matches(';').trim().is_empty() {
+            return write_messages(stream, &[BackendMessage::EmptyQueryResponse]).await;
+        }
+
+        match self.do_query(stream, query).await {
+            Ok(_) => Ok(()),
+            Err(cause) => {
+                tracing::error!("OnQuery Error: {:?}", cause);
+                write_messages(stream, &[Self::error_response("XX000", &cause)]).await
+            }
+        }
+    }
+
+    async fn do_query<S: AsyncWrite + Unpin>(&self, stream: &mut S, query: &str) -> Result<()> {
+        tracing::debug!("{}", query);
+
+        let context = self.session.create_query_context().await?;
+        context.attach_query_str(query);
+        let plan = PlanParser::parse(context.clone(), query).await?;
+        let interpreter = InterpreterFactory::get(context.clone(), plan)?;
+        let float_format = context.get_settings().get_float_format()?;
+
+        // Write start query log.
+        let _ = interpreter
+            .start()
+            .await
+            .map_err(|e| tracing::error!("interpreter.start.error: {:?}", e));
+        let query_result = match interpreter.execute_result(None).await {
+            Ok(result) => Self::write_result(stream, query, result, float_format).await,
+            Err(cause) => Err(cause),
+        };
+        // Write finish query log.
+        let _ = interpreter
+            .finish()
+            .await
+            .map_err(|e| tracing::error!("interpreter.finish.error: {:?}", e));
+
+        query_result
+    }
+
+    // The rows are sent block by block, so the result is never buffered as a whole.
+    async fn write_result<S: AsyncWrite + Unpin>(
+        stream: &mut S,
+        query: &str,
+        result: InterpreterResult,
+        float_format: FloatFormat,
+    ) -> Result<()> {
+        let (schema, mut data_stream) = match result {
+            InterpreterResult::ResultSet(schema, data_stream) => (schema, data_stream),
+            InterpreterResult::Affected(rows) => {
+                let messages = [BackendMessage::CommandComplete(format!(
+                    "INSERT 0 {}",
+                    rows
+                ))];
+                return write_messages(stream, &messages).await;
+            }
+            InterpreterResult::Empty => {
+                let messages = [BackendMessage::CommandComplete(Self::command_tag(query))];
+                return write_messages(stream, &messages).await;
+            }
+        };
+
+        let fields: Vec<_> = schema
+            .fields()
+            .iter()
+            .map(FieldDescription::from_field)
+            .collect();
+        let booleans: Vec<_> = fields.iter().map(|f| f.is_boolean()).collect();
+        write_messages(stream, &[BackendMessage::RowDescription(fields)]).await?;
+
+        let mut rows = 0;
+        while let Some(block) = data_stream.try_next().await? {
+            if block.num_columns() > 0 {
+                write_messages(stream, &Self::data_rows(&block, &booleans, float_format)?).await?;
+                rows += block.num_rows();
+            }
+        }
+
+        let messages = [BackendMessage::CommandComplete(format!("SELECT {}", rows))];
+        write_messages(stream, &messages).await
+    }
+
+    fn data_rows(
+        block: &DataBlock,
+        booleans: &[bool],
+        float_format: FloatFormat,
+    ) -> Result<Vec<BackendMessage>> {
+        let mut columns = Vec::with_capacity(block.num_columns());
+        for (column, field) in block.columns().iter().zip(block.schema().fields()) {
+            let column = column.convert_full_column();
+            let serializer = field
+                .data_type()
+                .create_serializer_with_format(float_format);
+            let values = serializer.serialize_column(&column)?;
+            columns.push((column, values));
+        }
+
+        let mut messages = Vec::with_capacity(block.num_rows());
+        for row in 0..block.num_rows() {
+            let values = columns
+                .iter()
+                .zip(booleans)
+                .map(|((column, values), is_boolean)| match column.null_at(row) {
+                    true => None,
+                    // Booleans serialize as 1/0, PostgreSQL clients read t/f.
+                    false if *is_boolean => Some(match values[row].as_str() {
+                        "0" => "f".to_string(),
+                        _ => "t".to_string(),
+                    }),
+                    false => Some(values[row].clone()),
+                })
+                .collect();
+            messages.push(BackendMessage::DataRow(values));
+        }
+
+        Ok(messages)
+    }
+
+    // Statements without a result set are tagged with their leading keyword.
+    fn command_tag(query: &str) -> String {
+        query
+            .split_whitespace()
+            .next()
+            .unwrap_or_default()
+            .trim_end_matches(';')
+            .to_uppercase()
+    }
+
+    fn error_response(code: &'static str, cause: &ErrorCode) -> BackendMessage {
+        BackendMessage::ErrorResponse {
+            code,
+            message: format!("{}", cause),
+        }
+    }
+
+    fn unsupported_message(tag: u8) -> BackendMessage {
+        BackendMessage::ErrorResponse {
+            code: "0A000",
+            message: format!(
+                "Unsupported message type '{}', only the simple query protocol is supported",
+                tag as char
+            ),
+        }
+    }
+}
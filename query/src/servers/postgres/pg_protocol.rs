@@ -0,0 +1,266 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+
+use common_base::tokio::io::AsyncRead;
+use common_base::tokio::io::AsyncReadExt;
+use common_base::tokio::io::AsyncWrite;
+use common_base::tokio::io::AsyncWriteExt;
+use common_datavalues2::prelude::TypeID;
+use common_datavalues2::remove_nullable;
+use common_datavalues2::DataField;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+// Codes of the untagged messages a client may open the connection with.
+const PROTOCOL_VERSION_3: i32 = 196608;
+const SSL_REQUEST_CODE: i32 = 80877103;
+const CANCEL_REQUEST_CODE: i32 = 80877102;
+
+const MAX_MESSAGE_LENGTH: usize = 64 * 1024 * 1024;
+
+pub enum StartupMessage {
+    Startup { params: HashMap<String, String> },
+    SslRequest,
+    CancelRequest,
+}
+
+pub enum FrontendMessage {
+    Password(String),
+    Query(String),
+    Terminate,
+    Unsupported(u8),
+}
+
+pub struct FieldDescription {
+    name: String,
+    type_oid: i32,
+    type_len: i16,
+}
+
+impl FieldDescription {
+    pub fn from_field(field: &DataField) -> FieldDescription {
+        // The type oids and lengths are those of the pg_type catalog.
+        let (type_oid, type_len) = match remove_nullable(field.data_type()).data_type_id() {
+            TypeID::Boolean => (16, 1),
+            TypeID::Int8 | TypeID::Int16 | TypeID::UInt8 => (21, 2),
+            TypeID::Int32 | TypeID::UInt16 => (23, 4),
+            TypeID::Int64 | TypeID::UInt32 => (20, 8),
+            TypeID::UInt64 => (1700, -1),
+            TypeID::Float32 => (700, 4),
+            TypeID::Float64 => (701, 8),
+            TypeID::Date16 | TypeID::Date32 => (1082, 4),
+            TypeID::DateTime32 | TypeID::DateTime64 => (1114, 8),
+            _ => (25, -1),
+        };
+
+        FieldDescription {
+            name: field.name().to_string(),
+            type_oid,
+            type_len,
+        }
+    }
+
+    pub fn is_boolean(&self) -> bool {
+        self.type_oid == 16
+    }
+}
+
+pub enum BackendMessage {
+    AuthenticationOk,
+    AuthenticationCleartextPassword,
+    ParameterStatus(&'static str, String),
+    ReadyForQuery,
+    RowDescription(Vec<FieldDescription>),
+    DataRow(Vec<Option<String>>),
+    CommandComplete(String),
+    EmptyQueryResponse,
+    ErrorResponse { code: &'static str, message: String },
+}
+
+impl BackendMessage {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        let tag = match self {
+            BackendMessage::AuthenticationOk => b'R',
+            BackendMessage::AuthenticationCleartextPassword => b'R',
+            BackendMessage::ParameterStatus(..) => b'S',
+            BackendMessage::ReadyForQuery => b'Z',
+            BackendMessage::RowDescription(_) => b'T',
+            BackendMessage::DataRow(_) => b'D',
+            BackendMessage::CommandComplete(_) => b'C',
+            BackendMessage::EmptyQueryResponse => b'I',
+            BackendMessage::ErrorResponse { .. } => b'E',
+        };
+
+        buf.push(tag);
+        // The length includes itself but not the tag, it is patched once the body is written.
+        let start = buf.len();
+        buf.extend_from_slice(&[0; 4]);
+
+        match self {
+            BackendMessage::AuthenticationOk => buf.extend_from_slice(&0_i32.to_be_bytes()),
+            BackendMessage::AuthenticationCleartextPassword => {
+                buf.extend_from_slice(&3_i32.to_be_bytes())
+            }
+            BackendMessage::ParameterStatus(name, value) => {
+                put_cstr(buf, name);
+                put_cstr(buf, value);
+            }
+            // Always idle, there are no transaction blocks.
+            BackendMessage::ReadyForQuery => buf.push(b'I'),
+            BackendMessage::RowDescription(fields) => {
+                buf.extend_from_slice(&(fields.len() as i16).to_be_bytes());
+                for field in fields {
+                    put_cstr(buf, &field.name);
+                    // Table oid and column attribute number, zero as not a table column.
+                    buf.extend_from_slice(&0_i32.to_be_bytes());
+                    buf.extend_from_slice(&0_i16.to_be_bytes());
+                    buf.extend_from_slice(&field.type_oid.to_be_bytes());
+                    buf.extend_from_slice(&field.type_len.to_be_bytes());
+                    // Type modifier and text format.
+                    buf.extend_from_slice(&(-1_i32).to_be_bytes());
+                    buf.extend_from_slice(&0_i16.to_be_bytes());
+                }
+            }
+            BackendMessage::DataRow(values) => {
+                buf.extend_from_slice(&(values.len() as i16).to_be_bytes());
+                for value in values {
+                    match value {
+                        None => buf.extend_from_slice(&(-1_i32).to_be_bytes()),
+                        Some(value) => {
+                            buf.extend_from_slice(&(value.len() as i32).to_be_bytes());
+                            buf.extend_from_slice(value.as_bytes());
+                        }
+                    }
+                }
+            }
+            BackendMessage::CommandComplete(tag) => put_cstr(buf, tag),
+            BackendMessage::EmptyQueryResponse => {}
+            BackendMessage::ErrorResponse { code, message } => {
+                buf.push(b'S');
+                put_cstr(buf, "ERROR");
+                buf.push(b'V');
+                put_cstr(buf, "ERROR");
+                buf.push(b'C');
+                put_cstr(buf, code);
+                buf.push(b'M');
+                put_cstr(buf, message);
+                buf.push(0);
+            }
+        }
+
+        let len = (buf.len() - start) as i32;
+        buf[start..start + 4].copy_from_slice(&len.to_be_bytes());
+    }
+}
+
+fn put_cstr(buf: &mut Vec<u8>, value: &str) {
+    buf.extend_from_slice(value.as_bytes());
+    buf.push(0);
+}
+
+fn get_cstr(buf: &[u8]) -> Result<(String, &[u8])> {
+    match buf.iter().position(|b| *b == 0) {
+        None => Err(ErrorCode::BadBytes(
+            "Unterminated string in PostgreSQL message",
+        )),
+        Some(pos) => Ok((
+            String::from_utf8_lossy(&buf[..pos]).into_owned(),
+            &buf[pos + 1..],
+        )),
+    }
+}
+
+async fn read_body<R: AsyncRead + Unpin>(reader: &mut R) -> Result<Vec<u8>> {
+    let len = reader.read_i32().await?;
+    if len < 4 || len as usize > MAX_MESSAGE_LENGTH {
+        return Err(ErrorCode::BadBytes(format!(
+            "Invalid PostgreSQL message length: {}",
+            len
+        )));
+    }
+
+    let mut body = vec![0; len as usize - 4];
+    reader.read_exact(&mut body).await?;
+    Ok(body)
+}
+
+/// Read the first message of a connection, which has no tag.
+pub async fn read_startup_message<R: AsyncRead + Unpin>(reader: &mut R) -> Result<StartupMessage> {
+    let body = read_body(reader).await?;
+    if body.len() < 4 {
+        return Err(ErrorCode::BadBytes("Invalid PostgreSQL startup message"));
+    }
+
+    let code = i32::from_be_bytes([body[0], body[1], body[2], body[3]]);
+    match code {
+        SSL_REQUEST_CODE => Ok(StartupMessage::SslRequest),
+        CANCEL_REQUEST_CODE => Ok(StartupMessage::CancelRequest),
+        PROTOCOL_VERSION_3 => {
+            let mut params = HashMap::new();
+            let mut rest = &body[4..];
+            while !rest.is_empty() && rest[0] != 0 {
+                let (name, remain) = get_cstr(rest)?;
+                let (value, remain) = get_cstr(remain)?;
+                params.insert(name, value);
+                rest = remain;
+            }
+            Ok(StartupMessage::Startup { params })
+        }
+        _ => Err(ErrorCode::UnImplement(format!(
+            "Unsupported PostgreSQL protocol version {}.{}",
+            code >> 16,
+            code & 0xFFFF
+        ))),
+    }
+}
+
+/// Read a tagged message, None if the client closed the connection.
+pub async fn read_message<R: AsyncRead + Unpin>(reader: &mut R) -> Result<Option<FrontendMessage>> {
+    let tag = match reader.read_u8().await {
+        Ok(tag) => tag,
+        Err(cause) if cause.kind() == std::io::ErrorKind::UnexpectedEof => return Ok(None),
+        Err(cause) => return Err(cause.into()),
+    };
+
+    let body = read_body(reader).await?;
+    match tag {
+        b'Q' => Ok(Some(FrontendMessage::Query(get_cstr(&body)?.0))),
+        b'p' => Ok(Some(FrontendMessage::Password(get_cstr(&body)?.0))),
+        b'X' => Ok(Some(FrontendMessage::Terminate)),
+        tag => Ok(Some(FrontendMessage::Unsupported(tag))),
+    }
+}
+
+pub async fn write_messages<W: AsyncWrite + Unpin>(
+    writer: &mut W,
+    messages: &[BackendMessage],
+) -> Result<()> {
+    let mut buf = Vec::new();
+    for message in messages {
+        message.encode(&mut buf);
+    }
+
+    writer.write_all(&buf).await?;
+    writer.flush().await?;
+    Ok(())
+}
+
+/// Answer an SSL request, we only talk plain text.
+pub async fn refuse_ssl<W: AsyncWrite + Unpin>(writer: &mut W) -> Result<()> {
+    writer.write_all(b"N").await?;
+    writer.flush().await?;
+    Ok(())
+}
@@ -15,6 +15,7 @@
 mod metrics;
 mod query_ctx;
 mod query_ctx_shared;
+mod query_stage_info;
 mod session;
 mod session_ctx;
 mod session_info;
@@ -25,6 +26,8 @@ mod session_settings;
 
 pub use query_ctx::QueryContext;
 pub use query_ctx_shared::QueryContextShared;
+pub use query_stage_info::QueryStageInfo;
+pub use query_stage_info::QueryStageState;
 pub use session::Session;
 pub use session_ctx::SessionContext;
 pub use session_info::ProcessInfo;
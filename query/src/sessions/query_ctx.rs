@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
 use std::collections::VecDeque;
 use std::future::Future;
 use std::net::SocketAddr;
@@ -46,6 +47,8 @@ use crate::configs::Config;
 use crate::servers::http::v1::HttpQueryHandle;
 use crate::sessions::ProcessInfo;
 use crate::sessions::QueryContextShared;
+use crate::sessions::QueryStageInfo;
+use crate::sessions::QueryStageState;
 use crate::sessions::Session;
 use crate::sessions::SessionRef;
 use crate::sessions::Settings;
@@ -165,6 +168,23 @@ impl QueryContext {
         self.shared.get_cluster()
     }
 
+    /// Register a new stage of the distributed query, returns the id of the stage.
+    pub fn register_query_stage(&self, kind: &str, nodes: Vec<String>) -> String {
+        self.shared.register_query_stage(kind, nodes)
+    }
+
+    pub fn get_query_stages(&self) -> Vec<QueryStageInfo> {
+        self.shared.get_query_stages()
+    }
+
+    pub fn set_query_stages_state(&self, state: QueryStageState) {
+        self.shared.set_query_stages_state(state)
+    }
+
+    pub fn add_query_stage_progress(&self, stage_id: &str, rows: usize, bytes: usize) {
+        self.shared.add_query_stage_progress(stage_id, rows, bytes)
+    }
+
     pub fn get_catalog(&self) -> Arc<DatabaseCatalog> {
         self.shared.get_catalog()
     }
@@ -231,6 +251,16 @@ impl QueryContext {
         self.shared.get_settings()
     }
 
+    // Override settings for this query only, e.g. `SELECT ... SETTINGS max_threads = 1`.
+    pub fn apply_query_settings(&self, changes: &HashMap<String, String>) -> Result<()> {
+        self.shared.apply_query_settings(changes)
+    }
+
+    // Get the names of the settings overridden for this query, in order.
+    pub fn get_query_settings_names(&self) -> Vec<String> {
+        self.shared.get_query_settings_names()
+    }
+
     pub fn get_config(&self) -> Config {
         self.shared.conf.clone()
     }
@@ -16,6 +16,7 @@ use std::collections::hash_map::Entry;
 use std::collections::HashMap;
 use std::sync::atomic::AtomicUsize;
 use std::sync::Arc;
+use std::time::Instant;
 
 use common_base::Progress;
 use common_base::Runtime;
@@ -34,6 +35,8 @@ use crate::catalogs::DatabaseCatalog;
 use crate::clusters::Cluster;
 use crate::configs::Config;
 use crate::servers::http::v1::HttpQueryHandle;
+use crate::sessions::QueryStageInfo;
+use crate::sessions::QueryStageState;
 use crate::sessions::Session;
 use crate::sessions::Settings;
 use crate::storages::Table;
@@ -65,6 +68,10 @@ pub struct QueryContextShared {
     pub(in crate::sessions) running_plan: Arc<RwLock<Option<PlanNode>>>,
     pub(in crate::sessions) tables_refs: Arc<Mutex<HashMap<DatabaseAndTable, Arc<dyn Table>>>>,
     pub(in crate::sessions) dal_ctx: Arc<DalContext>,
+    pub(in crate::sessions) query_stages: Arc<RwLock<Vec<QueryStageInfo>>>,
+    pub(in crate::sessions) created_time: Instant,
+    pub(in crate::sessions) query_settings: Arc<RwLock<Option<Arc<Settings>>>>,
+    pub(in crate::sessions) query_settings_names: Arc<RwLock<Vec<String>>>,
 }
 
 impl QueryContextShared {
@@ -89,6 +96,10 @@ impl QueryContextShared {
             running_plan: Arc::new(RwLock::new(None)),
             tables_refs: Arc::new(Mutex::new(HashMap::new())),
             dal_ctx: Arc::new(Default::default()),
+            query_stages: Arc::new(RwLock::new(Vec::new())),
+            created_time: Instant::now(),
+            query_settings: Arc::new(RwLock::new(None)),
+            query_settings_names: Arc::new(RwLock::new(Vec::new())),
         }))
     }
 
@@ -132,7 +143,42 @@ impl QueryContextShared {
     }
 
     pub fn get_settings(&self) -> Arc<Settings> {
-        self.session.get_settings()
+        match &*self.query_settings.read() {
+            Some(query_settings) => query_settings.clone(),
+            None => self.session.get_settings(),
+        }
+    }
+
+    /// Apply the settings of the query `SETTINGS` clause to a snapshot of the session settings,
+    /// the session settings are left untouched.
+    ///
+    /// The precedence from high to low is: the `SETTINGS` clause, `SET` of the session,
+    /// `SET GLOBAL` and the default value. Comment hints never carry settings, so they can't conflict.
+    pub fn apply_query_settings(&self, changes: &HashMap<String, String>) -> Result<()> {
+        if changes.is_empty() {
+            return Ok(());
+        }
+
+        let mut query_settings = self.query_settings.write();
+        let settings = match &*query_settings {
+            Some(settings) => settings.clone(),
+            None => Arc::new(self.session.get_settings().snapshot()),
+        };
+
+        for (name, value) in changes {
+            settings.set_settings(name.clone(), value.clone(), false)?;
+        }
+        *query_settings = Some(settings);
+
+        let mut names = self.query_settings_names.write();
+        names.extend(changes.keys().cloned());
+        names.sort();
+        names.dedup();
+        Ok(())
+    }
+
+    pub fn get_query_settings_names(&self) -> Vec<String> {
+        self.query_settings_names.read().clone()
     }
 
     pub fn get_catalog(&self) -> Arc<DatabaseCatalog> {
@@ -205,6 +251,36 @@ impl QueryContextShared {
         *running_plan = Some(plan.clone());
     }
 
+    pub fn register_query_stage(&self, kind: &str, nodes: Vec<String>) -> String {
+        let query_id = self.init_query_id.read().clone();
+        let mut query_stages = self.query_stages.write();
+        let stage_id = (query_stages.len() + 1).to_string();
+        query_stages.push(QueryStageInfo::create(&query_id, &stage_id, kind, nodes));
+        stage_id
+    }
+
+    pub fn get_query_stages(&self) -> Vec<QueryStageInfo> {
+        self.query_stages.read().clone()
+    }
+
+    pub fn set_query_stages_state(&self, state: QueryStageState) {
+        let mut query_stages = self.query_stages.write();
+        for query_stage in query_stages.iter_mut() {
+            // A failed stage keeps its state.
+            if query_stage.state != QueryStageState::Failed {
+                query_stage.state = state;
+            }
+        }
+    }
+
+    pub fn add_query_stage_progress(&self, stage_id: &str, rows: usize, bytes: usize) {
+        let mut query_stages = self.query_stages.write();
+        if let Some(query_stage) = query_stages.iter_mut().find(|s| s.stage_id == stage_id) {
+            query_stage.read_rows += rows;
+            query_stage.read_bytes += bytes;
+        }
+    }
+
     pub fn add_source_abort_handle(&self, handle: AbortHandle) {
         let mut sources_abort_handle = self.sources_abort_handle.write();
         sources_abort_handle.push(handle);
@@ -0,0 +1,63 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum QueryStageState {
+    Scheduled,
+    Running,
+    Finished,
+    Failed,
+}
+
+impl Display for QueryStageState {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            QueryStageState::Scheduled => write!(f, "Scheduled"),
+            QueryStageState::Running => write!(f, "Running"),
+            QueryStageState::Finished => write!(f, "Finished"),
+            QueryStageState::Failed => write!(f, "Failed"),
+        }
+    }
+}
+
+/// The coordinator side view of one stage of a distributed query.
+/// The stage id is the index of the stage in the query(starting from 1),
+/// so it is the same in the plan, the flight actions and the logs of every node.
+#[derive(Clone, Debug, PartialEq)]
+pub struct QueryStageInfo {
+    pub query_id: String,
+    pub stage_id: String,
+    pub kind: String,
+    pub nodes: Vec<String>,
+    pub state: QueryStageState,
+    pub read_rows: usize,
+    pub read_bytes: usize,
+}
+
+impl QueryStageInfo {
+    pub fn create(query_id: &str, stage_id: &str, kind: &str, nodes: Vec<String>) -> Self {
+        QueryStageInfo {
+            query_id: query_id.to_string(),
+            stage_id: stage_id.to_string(),
+            kind: kind.to_string(),
+            nodes,
+            state: QueryStageState::Scheduled,
+            read_rows: 0,
+            read_bytes: 0,
+        }
+    }
+}
@@ -14,11 +14,13 @@
 
 use std::net::SocketAddr;
 use std::sync::Arc;
+use std::time::Duration;
 
 use common_base::ProgressValues;
 use common_dal_context::DalMetrics;
 use common_meta_types::UserInfo;
 
+use crate::sessions::QueryStageInfo;
 use crate::sessions::Session;
 use crate::sessions::SessionContext;
 use crate::sessions::Settings;
@@ -35,6 +37,9 @@ pub struct ProcessInfo {
     pub memory_usage: i64,
     pub dal_metrics: Option<DalMetrics>,
     pub scan_progress_value: Option<ProgressValues>,
+    pub query_stages: Vec<QueryStageInfo>,
+    pub query_id: Option<String>,
+    pub query_elapsed: Option<Duration>,
 }
 
 impl Session {
@@ -66,6 +71,9 @@ impl Session {
             memory_usage,
             dal_metrics: Session::query_dal_metrics(status),
             scan_progress_value: Session::query_scan_progress_value(status),
+            query_stages: Session::query_stages(status),
+            query_id: Session::query_id(status),
+            query_elapsed: Session::query_elapsed(status),
         }
     }
 
@@ -110,4 +118,26 @@ impl Session {
             .as_ref()
             .map(|context_shared| context_shared.scan_progress.get_values())
     }
+
+    fn query_stages(status: &SessionContext) -> Vec<QueryStageInfo> {
+        status
+            .get_query_context_shared()
+            .as_ref()
+            .map(|context_shared| context_shared.get_query_stages())
+            .unwrap_or_default()
+    }
+
+    fn query_id(status: &SessionContext) -> Option<String> {
+        status
+            .get_query_context_shared()
+            .as_ref()
+            .map(|context_shared| context_shared.init_query_id.read().clone())
+    }
+
+    fn query_elapsed(status: &SessionContext) -> Option<Duration> {
+        status
+            .get_query_context_shared()
+            .as_ref()
+            .map(|context_shared| context_shared.created_time.elapsed())
+    }
 }
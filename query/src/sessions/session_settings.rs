@@ -21,6 +21,7 @@ use common_datavalues2::prelude::*;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_infallible::RwLock;
+use common_io::prelude::FloatFormat;
 use common_meta_types::UserSetting;
 
 use crate::configs::Config;
@@ -88,6 +89,38 @@ impl Settings {
                 desc: "The maximum number of threads to execute the request. By default, it is determined automatically.",
             },
 
+            // max_aggregate_threads
+            SettingValue {
+                default_value: DataValue::UInt64(0),
+                user_setting: UserSetting::create("max_aggregate_threads", DataValue::UInt64(0)),
+                level: ScopeLevel::Session,
+                desc: "The maximum number of threads to execute the aggregation. By default, it is 0 which means using max_threads.",
+            },
+
+            // max_plan_depth
+            SettingValue {
+                default_value: DataValue::UInt64(256),
+                user_setting: UserSetting::create("max_plan_depth", DataValue::UInt64(256)),
+                level: ScopeLevel::Session,
+                desc: "The maximum depth of the query plan, deeper queries are rejected before execution. By default, it is 256.",
+            },
+
+            // max_expression_nodes
+            SettingValue {
+                default_value: DataValue::UInt64(100000),
+                user_setting: UserSetting::create("max_expression_nodes", DataValue::UInt64(100000)),
+                level: ScopeLevel::Session,
+                desc: "The maximum number of expression nodes in the query plan, larger queries are rejected before execution. By default, it is 100000.",
+            },
+
+            // max_sort_threads
+            SettingValue {
+                default_value: DataValue::UInt64(0),
+                user_setting: UserSetting::create("max_sort_threads", DataValue::UInt64(0)),
+                level: ScopeLevel::Session,
+                desc: "The maximum number of threads to execute the sort. By default, it is 0 which means using max_threads.",
+            },
+
             // flight_client_timeout
             SettingValue {
                 default_value: DataValue::UInt64(60),
@@ -143,6 +176,30 @@ impl Settings {
                 level: ScopeLevel::Session,
                 desc: "Enable new processor framework if value != 0, default value: 0",
             },
+
+            // enable_exchange_checksum
+            SettingValue {
+                default_value: DataValue::UInt64(1),
+                user_setting: UserSetting::create("enable_exchange_checksum", DataValue::UInt64(1)),
+                level: ScopeLevel::Session,
+                desc: "Verify the block checksum on data exchange if value != 0, default value: 1",
+            },
+
+            // sql_dialect
+            SettingValue {
+                default_value: DataValue::String("ansi".as_bytes().to_vec()),
+                user_setting: UserSetting::create("sql_dialect", DataValue::String("ansi".as_bytes().to_vec())),
+                level: ScopeLevel::Session,
+                desc: "The SQL dialect, 'ansi' or 'mysql'. In mysql dialect '||' is the logical OR instead of the string concatenation. By default, it is ansi.",
+            },
+
+            // float_precision
+            SettingValue {
+                default_value: DataValue::String("shortest".as_bytes().to_vec()),
+                user_setting: UserSetting::create("float_precision", DataValue::String("shortest".as_bytes().to_vec())),
+                level: ScopeLevel::Session,
+                desc: "Digits after the point of floats in query results, or 'shortest' for the shortest round-trip representation. By default, it is shortest.",
+            },
         ];
 
         let settings = Arc::new(RwLock::new(HashMap::default()));
@@ -194,6 +251,36 @@ impl Settings {
         self.try_set_u64(key, val, false)
     }
 
+    // Get max_aggregate_threads, falls back to max_threads if unset.
+    pub fn get_max_aggregate_threads(&self) -> Result<u64> {
+        let key = "max_aggregate_threads";
+        match self.try_get_u64(key)? {
+            0 => self.get_max_threads(),
+            v => Ok(v),
+        }
+    }
+
+    // Get max_sort_threads, falls back to max_threads if unset.
+    pub fn get_max_sort_threads(&self) -> Result<u64> {
+        let key = "max_sort_threads";
+        match self.try_get_u64(key)? {
+            0 => self.get_max_threads(),
+            v => Ok(v),
+        }
+    }
+
+    // Get max_plan_depth.
+    pub fn get_max_plan_depth(&self) -> Result<u64> {
+        let key = "max_plan_depth";
+        self.try_get_u64(key)
+    }
+
+    // Get max_expression_nodes.
+    pub fn get_max_expression_nodes(&self) -> Result<u64> {
+        let key = "max_expression_nodes";
+        self.try_get_u64(key)
+    }
+
     // Get flight client timeout.
     pub fn get_flight_client_timeout(&self) -> Result<u64> {
         let key = "flight_client_timeout";
@@ -235,6 +322,42 @@ impl Settings {
         self.try_get_u64(key)
     }
 
+    pub fn get_enable_exchange_checksum(&self) -> Result<u64> {
+        let key = "enable_exchange_checksum";
+        self.try_get_u64(key)
+    }
+
+    // Get sql dialect.
+    pub fn get_sql_dialect(&self) -> Result<String> {
+        let key = "sql_dialect";
+        self.try_get_string(key)
+    }
+
+    // Get the format of the floats in query results.
+    pub fn get_float_format(&self) -> Result<FloatFormat> {
+        let key = "float_precision";
+        match self.try_get_string(key)?.as_str() {
+            "shortest" => Ok(FloatFormat::Shortest),
+            digits => Ok(FloatFormat::Fixed(digits.parse::<usize>()?)),
+        }
+    }
+
+    // Get the current value of a setting by name.
+    pub fn get_setting_value(&self, key: &str) -> Result<DataValue> {
+        let setting = self.check_and_get_setting_value(key)?;
+        Ok(setting.user_setting.value)
+    }
+
+    // A detached copy of the settings, changing it doesn't affect the settings it comes from.
+    pub fn snapshot(&self) -> Settings {
+        let settings = self.settings.read().clone();
+        Settings {
+            settings: Arc::new(RwLock::new(settings)),
+            user_api: self.user_api.clone(),
+            session_ctx: self.session_ctx.clone(),
+        }
+    }
+
     fn check_and_get_setting_value(&self, key: &str) -> Result<SettingValue> {
         let settings = self.settings.read();
         let setting = settings
@@ -270,6 +393,53 @@ impl Settings {
         Ok(())
     }
 
+    // Get string value, we don't get from the metasrv.
+    fn try_get_string(&self, key: &str) -> Result<String> {
+        let setting = self.check_and_get_setting_value(key)?;
+        Ok(String::from_utf8(setting.user_setting.value.as_string()?)?)
+    }
+
+    // Set string value to settings map, if is_global will write to metasrv.
+    fn try_set_string(&self, key: &str, val: String, is_global: bool) -> Result<()> {
+        let mut settings = self.settings.write();
+        let mut setting = settings
+            .get_mut(key)
+            .ok_or_else(|| ErrorCode::UnknownVariable(format!("Unknown variable: {:?}", key)))?;
+        setting.user_setting.value = DataValue::String(val.into_bytes());
+
+        if is_global {
+            let tenant = self.session_ctx.get_current_tenant();
+            let _ = futures::executor::block_on(
+                self.user_api
+                    .get_setting_api_client(&tenant)?
+                    .set_setting(setting.user_setting.clone()),
+            )?;
+            setting.level = ScopeLevel::Global;
+        }
+
+        Ok(())
+    }
+
+    // Check the value of the settings which only accept some values.
+    fn check_string_value(key: &str, val: &str) -> Result<String> {
+        let val = val.to_lowercase();
+        match key {
+            "sql_dialect" if val != "ansi" && val != "mysql" => {
+                Err(ErrorCode::BadArguments(format!(
+                    "Unsupported value:{:?} for variable:{:?}, expected 'ansi' or 'mysql'",
+                    val, key
+                )))
+            }
+            "float_precision" if val != "shortest" && val.parse::<usize>().is_err() => {
+                Err(ErrorCode::BadArguments(format!(
+                    "Unsupported value:{:?} for variable:{:?}, expected 'shortest' or the number of digits",
+                    val, key
+                )))
+            }
+            _ => Ok(val),
+        }
+    }
+
     pub fn get_setting_values(&self) -> Vec<DataValue> {
         let settings = self.settings.read();
 
@@ -300,6 +470,10 @@ impl Settings {
                 let u64_val = val.parse::<u64>()?;
                 self.try_set_u64(&key, u64_val, is_global)?;
             }
+            TypeID::String => {
+                let string_val = Self::check_string_value(&key, &val)?;
+                self.try_set_string(&key, string_val, is_global)?;
+            }
             v => {
                 return Err(ErrorCode::UnknownVariable(format!(
                     "Unsupported variable:{:?} type:{:?} when set_settings().",
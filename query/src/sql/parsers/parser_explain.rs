@@ -16,6 +16,7 @@
 // See notice.md
 
 use common_planners::ExplainType;
+use sqlparser::keywords::Keyword;
 use sqlparser::parser::ParserError;
 use sqlparser::tokenizer::Token;
 
@@ -38,12 +39,41 @@ impl<'a> DfParser<'a> {
                     self.parser.next_token();
                     ExplainType::Graph
                 }
+                "OPTIMIZED" => {
+                    self.parser.next_token();
+                    ExplainType::Optimized
+                }
+                "ANALYZE" => {
+                    self.parser.next_token();
+                    ExplainType::Analyze
+                }
+                "WITH" => {
+                    self.parser.next_token();
+                    match self.parser.peek_token() {
+                        Token::Word(w) if w.value.to_uppercase() == "TYPES" => {
+                            self.parser.next_token();
+                            ExplainType::WithTypes
+                        }
+                        _ => {
+                            // Not EXPLAIN WITH TYPES, e.g. a query starts with WITH.
+                            self.parser.prev_token();
+                            ExplainType::Syntax
+                        }
+                    }
+                }
                 _ => ExplainType::Syntax,
             },
             _ => ExplainType::Syntax,
         };
 
-        let statement = Box::new(self.parse_query()?);
-        Ok(DfStatement::Explain(DfExplain { typ, statement }))
+        let statement = match self.parser.peek_token() {
+            Token::Word(w) if w.keyword == Keyword::INSERT => self.parse_insert()?,
+            _ => self.parse_query()?,
+        };
+
+        Ok(DfStatement::Explain(DfExplain {
+            typ,
+            statement: Box::new(statement),
+        }))
     }
 }
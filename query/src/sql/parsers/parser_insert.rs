@@ -15,6 +15,10 @@
 // Borrow from apache/arrow/rust/datafusion/src/sql/sql_parser
 // See notice.md
 
+use std::collections::HashMap;
+
+use sqlparser::ast::Query;
+use sqlparser::ast::SetExpr;
 use sqlparser::ast::Statement;
 use sqlparser::parser::ParserError;
 
@@ -32,25 +36,63 @@ impl<'a> DfParser<'a> {
                 table_name,
                 columns,
                 overwrite,
-                source,
-                partitioned,
-                format,
-                after_columns,
-                table,
-                on,
-            } => Ok(DfStatement::InsertQuery(DfInsertStatement {
-                or,
-                table_name,
-                columns,
-                overwrite,
-                source,
+                mut source,
                 partitioned,
                 format,
                 after_columns,
                 table,
                 on,
-            })),
+            } => {
+                let settings = self.parse_insert_settings(&mut source)?;
+                Ok(DfStatement::InsertQuery(DfInsertStatement {
+                    or,
+                    table_name,
+                    columns,
+                    overwrite,
+                    source,
+                    partitioned,
+                    format,
+                    after_columns,
+                    table,
+                    on,
+                    settings,
+                }))
+            }
             _ => parser_err!("Expect set insert statement"),
         }
     }
+
+    // The trailing SETTINGS of `INSERT ... VALUES ...` or `INSERT ... SELECT ...`.
+    fn parse_insert_settings(
+        &mut self,
+        source: &mut Option<Box<Query>>,
+    ) -> Result<HashMap<String, String>, ParserError> {
+        let has_settings = match source.as_deref_mut() {
+            Some(Query {
+                body: SetExpr::Select(select),
+                order_by,
+                limit,
+                offset,
+                ..
+            }) => {
+                let ends_with_from = select.selection.is_none()
+                    && select.group_by.is_empty()
+                    && select.having.is_none()
+                    && order_by.is_empty()
+                    && limit.is_none()
+                    && offset.is_none();
+                self.consume_settings_keyword(
+                    ends_with_from,
+                    &mut select.from,
+                    &mut select.projection,
+                )
+            }
+            _ => self.consume_token("SETTINGS"),
+        };
+
+        match has_settings {
+            true => self.parse_settings(),
+            false => Ok(HashMap::new()),
+        }
+    }
 }
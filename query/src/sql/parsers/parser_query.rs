@@ -15,9 +15,21 @@
 // Borrow from apache/arrow/rust/datafusion/src/sql/sql_parser
 // See notice.md
 
+use sqlparser::ast::Ident;
+use sqlparser::ast::SelectItem;
+use sqlparser::ast::SetExpr;
+use sqlparser::ast::TableAlias;
+use sqlparser::ast::TableFactor;
+use sqlparser::ast::TableWithJoins;
+use sqlparser::keywords::Keyword;
+use sqlparser::keywords::RESERVED_FOR_TABLE_ALIAS;
+use sqlparser::parser::Parser;
 use sqlparser::parser::ParserError;
+use sqlparser::tokenizer::Token;
 
+use crate::parser_err;
 use crate::sql::statements::DfQueryStatement;
+use crate::sql::statements::DfUnionAllStatement;
 use crate::sql::DfParser;
 use crate::sql::DfStatement;
 
@@ -26,8 +38,243 @@ impl<'a> DfParser<'a> {
     pub(crate) fn parse_query(&mut self) -> Result<DfStatement, ParserError> {
         // self.parser.prev_token();
         let native_query = self.parser.parse_query()?;
-        Ok(DfStatement::Query(Box::new(DfQueryStatement::try_from(
-            native_query,
-        )?)))
+        match &native_query.body {
+            SetExpr::SetOperation { .. } => Ok(DfStatement::UnionAll(Box::new(
+                DfUnionAllStatement::try_from(native_query)?,
+            ))),
+            _ => {
+                let mut query = DfQueryStatement::try_from(native_query)?;
+                if query.limit.is_some() && self.parser.parse_keyword(Keyword::BY) {
+                    self.parse_limit_by(&mut query)?;
+                }
+
+                let ends_with_from = query.selection.is_none()
+                    && query.group_by.is_empty()
+                    && query.having.is_none()
+                    && query.order_by.is_empty()
+                    && query.limit.is_none()
+                    && query.offset.is_none();
+                if self.consume_settings_keyword(
+                    ends_with_from,
+                    &mut query.from,
+                    &mut query.projection,
+                ) {
+                    query.settings = self.parse_settings()?;
+                }
+
+                Ok(DfStatement::Query(Box::new(query)))
+            }
+        }
+    }
+
+    // LIMIT n BY expr[, expr...] [LIMIT m] [OFFSET k], the LIMIT already parsed is the per key limit.
+    fn parse_limit_by(&mut self, query: &mut DfQueryStatement) -> Result<(), ParserError> {
+        if query.offset.is_some() {
+            return Err(ParserError::ParserError(String::from(
+                "OFFSET in LIMIT BY is not yet implement",
+            )));
+        }
+
+        query.limit_by_count = query.limit.take();
+        query.limit_by = self.parser.parse_comma_separated(Parser::parse_expr)?;
+
+        if self.parser.parse_keyword(Keyword::LIMIT) {
+            query.limit = self.parser.parse_limit()?;
+        }
+
+        if self.parser.parse_keyword(Keyword::OFFSET) {
+            query.offset = Some(self.parser.parse_offset()?);
+        }
+
+        Ok(())
+    }
+
+    // The SETTINGS keyword of a trailing `SETTINGS name = value, ...` clause.
+    // SETTINGS is not a keyword of the sql parser, so right after the select list or the FROM clause
+    // it is parsed as the alias of the last item, e.g. `SELECT * FROM t SETTINGS max_threads = 1`,
+    // such an alias followed by more tokens is taken back as the keyword.
+    pub(crate) fn consume_settings_keyword(
+        &mut self,
+        ends_with_from: bool,
+        from: &mut [TableWithJoins],
+        projection: &mut [SelectItem],
+    ) -> bool {
+        if self.consume_token("SETTINGS") {
+            return true;
+        }
+
+        match self.parser.peek_token() {
+            Token::EOF | Token::SemiColon => false,
+            _ if !ends_with_from => false,
+            _ => Self::take_settings_alias(from, projection),
+        }
+    }
+
+    fn take_settings_alias(from: &mut [TableWithJoins], projection: &mut [SelectItem]) -> bool {
+        let is_settings = |ident: &Ident| {
+            ident.quote_style.is_none() && ident.value.eq_ignore_ascii_case("settings")
+        };
+
+        if let Some(table) = from.last_mut() {
+            let relation = match table.joins.last_mut() {
+                Some(join) => &mut join.relation,
+                None => &mut table.relation,
+            };
+
+            let alias = match relation {
+                TableFactor::Table { alias, .. }
+                | TableFactor::Derived { alias, .. }
+                | TableFactor::TableFunction { alias, .. } => alias,
+                TableFactor::NestedJoin(_) => return false,
+            };
+
+            let is_settings_alias = matches!(alias, Some(TableAlias { name, columns }) if columns.is_empty() && is_settings(name));
+            if is_settings_alias {
+                *alias = None;
+            }
+            return is_settings_alias;
+        }
+
+        match projection.last_mut() {
+            Some(item) => {
+                let unnamed = match item {
+                    SelectItem::ExprWithAlias { expr, alias } if is_settings(alias) => {
+                        SelectItem::UnnamedExpr(expr.clone())
+                    }
+                    _ => return false,
+                };
+                *item = unnamed;
+                true
+            }
+            None => false,
+        }
+    }
+
+    // TABLESAMPLE [BERNOULLI] (n [PERCENT]) [REPEATABLE (seed)] after a table.
+    // The sql parser doesn't know the clause, so it is rewritten into the table hint
+    // `WITH (TABLESAMPLE(n [, seed]))` before parsing, and the hint is analyzed with the table.
+    // Elsewhere, e.g. `SELECT tablesample(1)`, TABLESAMPLE is left as a name.
+    pub(crate) fn rewrite_table_sample(tokens: Vec<Token>) -> Result<Vec<Token>, ParserError> {
+        let mut rewritten = Vec::with_capacity(tokens.len());
+        let mut seen_from = false;
+        let mut last_significant = None;
+        let mut index = 0;
+        while index < tokens.len() {
+            let mut pos = index + 1;
+            if Self::is_word(&tokens[index], "TABLESAMPLE")
+                && Self::next_is_word(&tokens, &mut pos, "BERNOULLI")
+            {
+                pos += 1;
+            }
+
+            let is_sample = Self::is_word(&tokens[index], "TABLESAMPLE")
+                && seen_from
+                && Self::ends_table_factor(last_significant)
+                && Self::next_significant(&tokens, &mut pos) == Some(&Token::LParen);
+            if !is_sample {
+                if Self::is_word(&tokens[index], "FROM") {
+                    seen_from = true;
+                }
+                if !matches!(tokens[index], Token::Whitespace(_)) {
+                    last_significant = Some(&tokens[index]);
+                }
+                rewritten.push(tokens[index].clone());
+                index += 1;
+                continue;
+            }
+
+            pos += 1;
+            let percent = Self::expect_sample_number(&tokens, &mut pos)?;
+            if Self::next_is_word(&tokens, &mut pos, "PERCENT") {
+                pos += 1;
+            }
+            Self::expect_sample_token(&tokens, &mut pos, &Token::RParen)?;
+
+            let mut seed = None;
+            let mut next = pos;
+            if Self::next_is_word(&tokens, &mut next, "REPEATABLE") {
+                pos = next + 1;
+                Self::expect_sample_token(&tokens, &mut pos, &Token::LParen)?;
+                seed = Some(Self::expect_sample_number(&tokens, &mut pos)?);
+                Self::expect_sample_token(&tokens, &mut pos, &Token::RParen)?;
+            }
+
+            rewritten.push(Token::make_keyword("WITH"));
+            rewritten.push(Token::LParen);
+            rewritten.push(Token::make_word("TABLESAMPLE", None));
+            rewritten.push(Token::LParen);
+            rewritten.push(percent);
+            if let Some(seed) = seed {
+                rewritten.push(Token::Comma);
+                rewritten.push(seed);
+            }
+            rewritten.push(Token::RParen);
+            rewritten.push(Token::RParen);
+            last_significant = tokens.get(pos - 1);
+            index = pos;
+        }
+
+        Ok(rewritten)
+    }
+
+    // A table factor ends with the table name or alias, or the `)` of a table function.
+    fn ends_table_factor(token: Option<&Token>) -> bool {
+        match token {
+            Some(Token::RParen) => true,
+            Some(Token::Word(w)) => {
+                w.quote_style.is_some()
+                    || (w.keyword != Keyword::FROM
+                        && !RESERVED_FOR_TABLE_ALIAS.contains(&w.keyword))
+            }
+            _ => false,
+        }
+    }
+
+    fn is_word(token: &Token, word: &str) -> bool {
+        matches!(token, Token::Word(w) if w.quote_style.is_none() && w.value.eq_ignore_ascii_case(word))
+    }
+
+    fn next_is_word(tokens: &[Token], pos: &mut usize, word: &str) -> bool {
+        matches!(Self::next_significant(tokens, pos), Some(token) if Self::is_word(token, word))
+    }
+
+    // Moves pos to the next token which is not a whitespace.
+    fn next_significant<'t>(tokens: &'t [Token], pos: &mut usize) -> Option<&'t Token> {
+        while let Some(Token::Whitespace(_)) = tokens.get(*pos) {
+            *pos += 1;
+        }
+        tokens.get(*pos)
+    }
+
+    fn expect_sample_token(
+        tokens: &[Token],
+        pos: &mut usize,
+        expected: &Token,
+    ) -> Result<(), ParserError> {
+        match Self::next_significant(tokens, pos) {
+            Some(token) if token == expected => {
+                *pos += 1;
+                Ok(())
+            }
+            Some(token) => parser_err!(format!(
+                "Expected {} in TABLESAMPLE, found: {}",
+                expected, token
+            )),
+            None => parser_err!(format!("Expected {} in TABLESAMPLE, found: EOF", expected)),
+        }
+    }
+
+    fn expect_sample_number(tokens: &[Token], pos: &mut usize) -> Result<Token, ParserError> {
+        match Self::next_significant(tokens, pos) {
+            Some(token @ Token::Number(..)) => {
+                *pos += 1;
+                Ok(token.clone())
+            }
+            Some(token) => parser_err!(format!(
+                "Expected a number in TABLESAMPLE, found: {}",
+                token
+            )),
+            None => parser_err!("Expected a number in TABLESAMPLE, found: EOF"),
+        }
     }
 }
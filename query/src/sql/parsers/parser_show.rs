@@ -22,6 +22,7 @@ use sqlparser::tokenizer::Token;
 use crate::sql::statements::DfShowDatabases;
 use crate::sql::statements::DfShowFunctions;
 use crate::sql::statements::DfShowKind;
+use crate::sql::statements::DfShowTableStatus;
 use crate::sql::statements::DfShowTables;
 use crate::sql::DfParser;
 use crate::sql::DfStatement;
@@ -50,6 +51,35 @@ impl<'a> DfParser<'a> {
         }
     }
 
+    // parse show table status [from db] [like 'xx' | where expr].
+    pub(crate) fn parse_show_table_status(&mut self) -> Result<DfStatement, ParserError> {
+        if !self.consume_token("STATUS") {
+            return self.expected("STATUS", self.parser.peek_token());
+        }
+
+        let tok = self.parser.next_token();
+        match &tok {
+            Token::EOF | Token::SemiColon => Ok(DfStatement::ShowTableStatus(
+                DfShowTableStatus::create(DfShowKind::All),
+            )),
+            Token::Word(w) => match w.keyword {
+                Keyword::LIKE => Ok(DfStatement::ShowTableStatus(DfShowTableStatus::create(
+                    DfShowKind::Like(self.parser.parse_identifier()?),
+                ))),
+                Keyword::WHERE => Ok(DfStatement::ShowTableStatus(DfShowTableStatus::create(
+                    DfShowKind::Where(self.parser.parse_expr()?),
+                ))),
+                Keyword::FROM | Keyword::IN => {
+                    Ok(DfStatement::ShowTableStatus(DfShowTableStatus::create(
+                        DfShowKind::FromOrIn(self.parser.parse_object_name()?),
+                    )))
+                }
+                _ => self.expected("like or where", tok),
+            },
+            _ => self.expected("like or where", tok),
+        }
+    }
+
     // parse show databases where database = xxx or where database
     pub(crate) fn parse_show_databases(&mut self) -> Result<DfStatement, ParserError> {
         let tok = self.parser.next_token();
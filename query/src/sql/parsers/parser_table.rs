@@ -16,6 +16,7 @@
 // See notice.md
 
 use sqlparser::ast::ColumnDef;
+use sqlparser::ast::ColumnOption;
 use sqlparser::ast::ColumnOptionDef;
 use sqlparser::ast::TableConstraint;
 use sqlparser::keywords::Keyword;
@@ -142,6 +143,9 @@ impl<'a> DfParser<'a> {
                         self.parser.peek_token(),
                     );
                 }
+            } else if self.consume_token("COMMENT") {
+                let option = self.parse_column_comment()?;
+                options.push(ColumnOptionDef { name: None, option });
             } else if let Some(option) = self.parser.parse_optional_column_option()? {
                 options.push(ColumnOptionDef { name: None, option });
             } else {
@@ -156,6 +160,17 @@ impl<'a> DfParser<'a> {
         })
     }
 
+    // The column comment is kept as a dialect specific option: COMMENT 'xxx'
+    fn parse_column_comment(&mut self) -> Result<ColumnOption, ParserError> {
+        match self.parser.next_token() {
+            Token::SingleQuotedString(comment) => Ok(ColumnOption::DialectSpecific(vec![
+                Token::make_keyword("COMMENT"),
+                Token::SingleQuotedString(comment),
+            ])),
+            unexpected => self.expected("literal string after COMMENT", unexpected),
+        }
+    }
+
     // This is a copy of the equivalent implementation in sqlparser.
     fn parse_columns(&mut self) -> Result<(Vec<ColumnDef>, Vec<TableConstraint>), ParserError> {
         let mut columns = vec![];
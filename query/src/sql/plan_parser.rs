@@ -14,8 +14,16 @@
 
 use std::sync::Arc;
 
+use common_datavalues2::DataValue;
+use common_datavalues2::UInt32Type;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_planners::col;
+use common_planners::find_grouping_exprs;
+use common_planners::lit_null;
+use common_planners::validate_expression;
+use common_planners::validate_projection_expression;
+use common_planners::validate_sort_expression;
 use common_planners::ExplainPlan;
 use common_planners::Expression;
 use common_planners::PlanBuilder;
@@ -71,13 +79,17 @@ impl PlanParser {
     }
 
     pub fn build_query_plan(data: &QueryAnalyzeState) -> Result<PlanNode> {
+        Self::validate_query_expressions(data)?;
+
         let from = Self::build_from_plan(data)?;
         let filter = Self::build_filter_plan(from, data)?;
         let group_by = Self::build_group_by_plan(filter, data)?;
         let before_order = Self::build_before_order(group_by, data)?;
         let having = Self::build_having_plan(before_order, data)?;
-        let order_by = Self::build_order_by_plan(having, data)?;
-        let projection = Self::build_projection_plan(order_by, data)?;
+        let distinct = Self::build_distinct_plan(having, data)?;
+        let order_by = Self::build_order_by_plan(distinct, data)?;
+        let limit_by = Self::build_limit_by_plan(order_by, data)?;
+        let projection = Self::build_projection_plan(limit_by, data)?;
         let limit = Self::build_limit_plan(projection, data)?;
 
         Ok(PlanNode::Select(SelectPlan {
@@ -85,6 +97,32 @@ impl PlanParser {
         }))
     }
 
+    fn validate_query_expressions(data: &QueryAnalyzeState) -> Result<()> {
+        let expressions = data
+            .filter
+            .iter()
+            .chain(data.having.iter())
+            .chain(data.expressions.iter())
+            .chain(data.group_by_expressions.iter())
+            .chain(data.limit_by_expressions.iter())
+            .chain(data.aggregate_expressions.iter())
+            .chain(data.before_group_by_expressions.iter());
+
+        for expr in expressions {
+            validate_expression(expr)?;
+        }
+
+        for expr in &data.order_by_expressions {
+            validate_sort_expression(expr)?;
+        }
+
+        for expr in &data.projection_expressions {
+            validate_projection_expression(expr)?;
+        }
+
+        Ok(())
+    }
+
     fn build_from_plan(data: &QueryAnalyzeState) -> Result<PlanNode> {
         match &data.relation {
             QueryRelation::None => Err(ErrorCode::LogicalError("Not from in select query")),
@@ -114,17 +152,137 @@ impl PlanParser {
             false => {
                 let input_plan = Self::build_before_group_by(plan, data)?;
 
-                let schema = input_plan.schema();
                 let group_by_exprs = &data.group_by_expressions;
                 let aggregate_exprs = &data.aggregate_expressions;
-                PlanBuilder::from(&input_plan)
-                    .aggregate_partial(aggregate_exprs, group_by_exprs)?
-                    .aggregate_final(schema, aggregate_exprs, group_by_exprs)?
-                    .build()
+                match data.group_by_rollup {
+                    true => Self::build_rollup_plan(&input_plan, data),
+                    false => {
+                        Self::build_aggregate_plan(&input_plan, aggregate_exprs, group_by_exprs)
+                    }
+                }
             }
         }
     }
 
+    fn build_aggregate_plan(
+        input_plan: &PlanNode,
+        aggregate_exprs: &[Expression],
+        group_by_exprs: &[Expression],
+    ) -> Result<PlanNode> {
+        let schema = input_plan.schema();
+        PlanBuilder::from(input_plan)
+            .aggregate_partial(aggregate_exprs, group_by_exprs)?
+            .aggregate_final(schema, aggregate_exprs, group_by_exprs)?
+            .build()
+    }
+
+    /// GROUP BY ROLLUP(a, b) aggregates by (a, b), (a) and () in turn. The input is scanned once,
+    /// each row is expanded into one row per grouping set with the rolled up keys set to NULL,
+    /// and the copies of the keys are aggregated together with the id of the grouping set.
+    /// Each grouping set also yields the mask of every `grouping(...)` in the query.
+    fn build_rollup_plan(input_plan: &PlanNode, data: &QueryAnalyzeState) -> Result<PlanNode> {
+        let group_by_exprs = &data.group_by_expressions;
+        let aggregate_exprs = &data.aggregate_expressions;
+        let grouping_exprs = find_grouping_exprs(
+            &data
+                .expressions
+                .iter()
+                .chain(data.having.iter())
+                .chain(data.order_by_expressions.iter())
+                .cloned()
+                .collect::<Vec<_>>(),
+        );
+
+        // The position of each grouping(...) argument in the group by expressions.
+        let mut grouping_positions = Vec::with_capacity(grouping_exprs.len());
+        for grouping_expr in &grouping_exprs {
+            if let Expression::ScalarFunction { args, .. } = grouping_expr {
+                let mut positions = Vec::with_capacity(args.len());
+                for arg in args {
+                    let name = arg.column_name();
+                    match group_by_exprs.iter().position(|e| e.column_name() == name) {
+                        Some(position) => positions.push(position),
+                        None => {
+                            return Err(ErrorCode::SyntaxException(format!(
+                                "Argument {} of grouping() must be a GROUP BY expression",
+                                name
+                            )))
+                        }
+                    }
+                }
+                grouping_positions.push(positions);
+            }
+        }
+
+        // The keys are copied under new names, so the arguments of the aggregate functions
+        // are not rolled up along with the keys.
+        let key_names = (0..group_by_exprs.len())
+            .map(|index| format!("_rollup_key_{}", index))
+            .collect::<Vec<_>>();
+        let grouping_names = grouping_exprs
+            .iter()
+            .map(|grouping_expr| grouping_expr.column_name())
+            .collect::<Vec<_>>();
+
+        let all_positions = (0..group_by_exprs.len()).collect::<Vec<_>>();
+        let mask_literal = |positions: &[usize], keys: usize| {
+            let mask = positions.iter().fold(0_u64, |mask, position| {
+                (mask << 1) | (*position >= keys) as u64
+            });
+            Expression::create_literal_with_type(DataValue::UInt64(mask), UInt32Type::arc())
+        };
+
+        let mut grouping_sets = Vec::with_capacity(group_by_exprs.len() + 1);
+        for keys in (0..=group_by_exprs.len()).rev() {
+            let mut exprs = Vec::with_capacity(group_by_exprs.len() + grouping_exprs.len() + 1);
+            for (index, group_by_expr) in group_by_exprs.iter().enumerate() {
+                let key = match index < keys {
+                    true => col(&group_by_expr.column_name()),
+                    false => lit_null(),
+                };
+                exprs.push(Expression::Alias(key_names[index].clone(), Box::new(key)));
+            }
+
+            exprs.push(Expression::Alias(
+                "_grouping_id".to_string(),
+                Box::new(mask_literal(&all_positions, keys)),
+            ));
+
+            for (name, positions) in grouping_names.iter().zip(&grouping_positions) {
+                exprs.push(Expression::Alias(
+                    name.clone(),
+                    Box::new(mask_literal(positions, keys)),
+                ));
+            }
+
+            grouping_sets.push(exprs);
+        }
+
+        let mut rollup_keys = key_names.iter().map(|name| col(name)).collect::<Vec<_>>();
+        rollup_keys.push(col("_grouping_id"));
+        rollup_keys.extend(grouping_names.iter().map(|name| col(name)));
+
+        let expand_plan = PlanBuilder::from(input_plan)
+            .expand(&grouping_sets)?
+            .build()?;
+        let aggregate = Self::build_aggregate_plan(&expand_plan, aggregate_exprs, &rollup_keys)?;
+
+        let mut exprs = Vec::with_capacity(group_by_exprs.len() + aggregate_exprs.len());
+        for (group_by_expr, key_name) in group_by_exprs.iter().zip(&key_names) {
+            exprs.push(Expression::Alias(
+                group_by_expr.column_name(),
+                Box::new(col(key_name)),
+            ));
+        }
+
+        for aggregate_expr in aggregate_exprs {
+            exprs.push(col(&aggregate_expr.column_name()));
+        }
+
+        exprs.extend(grouping_names.iter().map(|name| col(name)));
+        PlanBuilder::from(&aggregate).project(&exprs)?.build()
+    }
+
     fn build_before_group_by(plan: PlanNode, data: &QueryAnalyzeState) -> Result<PlanNode> {
         fn is_all_column(exprs: &[Expression]) -> bool {
             exprs
@@ -171,6 +329,21 @@ impl PlanParser {
         }
     }
 
+    /// SELECT DISTINCT groups by every projected column, before ORDER BY and LIMIT.
+    fn build_distinct_plan(plan: PlanNode, data: &QueryAnalyzeState) -> Result<PlanNode> {
+        match data.distinct {
+            false => Ok(plan),
+            true => {
+                let schema = plan.schema();
+                let distinct_exprs = data.distinct_expressions();
+                PlanBuilder::from(&plan)
+                    .aggregate_partial(&[], &distinct_exprs)?
+                    .aggregate_final(schema, &[], &distinct_exprs)?
+                    .build()
+            }
+        }
+    }
+
     fn build_order_by_plan(plan: PlanNode, data: &QueryAnalyzeState) -> Result<PlanNode> {
         match data.order_by_expressions.is_empty() {
             true => Ok(plan),
@@ -180,6 +353,16 @@ impl PlanParser {
         }
     }
 
+    /// LIMIT BY works on the sorted rows, before the projection and the global LIMIT.
+    fn build_limit_by_plan(plan: PlanNode, data: &QueryAnalyzeState) -> Result<PlanNode> {
+        match data.limit_by_count {
+            None => Ok(plan),
+            Some(limit) => PlanBuilder::from(&plan)
+                .limit_by(limit, &data.limit_by_expressions)?
+                .build(),
+        }
+    }
+
     fn build_projection_plan(plan: PlanNode, data: &QueryAnalyzeState) -> Result<PlanNode> {
         PlanBuilder::from(&plan)
             .project(&data.projection_expressions)?
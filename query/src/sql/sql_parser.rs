@@ -62,6 +62,7 @@ impl<'a> DfParser<'a> {
     pub fn new_with_dialect(sql: &str, dialect: &'a dyn Dialect) -> Result<Self, ParserError> {
         let mut tokenizer = Tokenizer::new(dialect, sql);
         let tokens = tokenizer.tokenize()?;
+        let tokens = DfParser::rewrite_table_sample(tokens)?;
 
         Ok(DfParser {
             parser: Parser::new(tokens, dialect),
@@ -113,6 +114,9 @@ impl<'a> DfParser<'a> {
                 Some(Token::Whitespace(Whitespace::SingleLineComment { comment, prefix })) => {
                     hints.push(DfHint::create_from_comment(comment, prefix));
                 }
+                Some(Token::Whitespace(Whitespace::MultiLineComment(comment))) => {
+                    hints.push(DfHint::create_from_multi_line_comment(comment));
+                }
                 Some(Token::Whitespace(Whitespace::Newline)) | Some(Token::EOF) | None => break,
                 _ => continue,
             }
@@ -158,6 +162,8 @@ impl<'a> DfParser<'a> {
                         self.parser.next_token();
                         if self.consume_token("TABLES") {
                             self.parse_show_tables()
+                        } else if self.consume_token("TABLE") {
+                            self.parse_show_table_status()
                         } else if self.consume_token("DATABASES") {
                             self.parse_show_databases()
                         } else if self.consume_token("SETTINGS") {
@@ -364,6 +370,22 @@ impl<'a> DfParser<'a> {
         Ok(options)
     }
 
+    // Parse the `name = value[, name = value...]` list after the SETTINGS keyword of a query.
+    pub(crate) fn parse_settings(&mut self) -> Result<HashMap<String, String>, ParserError> {
+        let mut settings = HashMap::new();
+        loop {
+            let name = self.parser.parse_identifier()?;
+            self.parser.expect_token(&Token::Eq)?;
+            let value = self.parse_value_or_ident()?;
+            settings.insert(name.value.to_lowercase(), value);
+
+            if !self.parser.consume_token(&Token::Comma) {
+                break;
+            }
+        }
+        Ok(settings)
+    }
+
     pub(crate) fn consume_token(&mut self, expected: &str) -> bool {
         if self.parser.peek_token().to_string().to_uppercase() == *expected.to_uppercase() {
             self.parser.next_token();
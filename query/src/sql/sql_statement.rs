@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use nom::bytes::complete::tag;
+use nom::bytes::complete::tag_no_case;
 use nom::bytes::complete::take_till1;
 use nom::character::complete::digit1;
 use nom::character::complete::multispace0;
@@ -51,9 +52,11 @@ use crate::sql::statements::DfShowGrants;
 use crate::sql::statements::DfShowMetrics;
 use crate::sql::statements::DfShowProcessList;
 use crate::sql::statements::DfShowSettings;
+use crate::sql::statements::DfShowTableStatus;
 use crate::sql::statements::DfShowTables;
 use crate::sql::statements::DfShowUsers;
 use crate::sql::statements::DfTruncateTable;
+use crate::sql::statements::DfUnionAllStatement;
 use crate::sql::statements::DfUseDatabase;
 use crate::sql::statements::DfUseTenant;
 
@@ -62,6 +65,7 @@ use crate::sql::statements::DfUseTenant;
 pub enum DfStatement {
     // ANSI SQL AST node
     Query(Box<DfQueryStatement>),
+    UnionAll(Box<DfUnionAllStatement>),
     Explain(DfExplain),
 
     // Databases.
@@ -74,6 +78,7 @@ pub enum DfStatement {
 
     // Tables.
     ShowTables(DfShowTables),
+    ShowTableStatus(DfShowTableStatus),
     ShowCreateTable(DfShowCreateTable),
     CreateTable(DfCreateTable),
     DescribeTable(DfDescribeTable),
@@ -133,9 +138,11 @@ pub enum DfStatement {
 /// Comment hints from SQL.
 /// It'll be enabled when using `--comment` in mysql client.
 /// Eg: `SELECT * FROM system.number LIMIT 1; -- { ErrorCode 25 }`
+/// Eg: `INSERT /*+ ignore_error(1025) */ INTO t VALUES(1);`
 #[derive(Debug, Clone, PartialEq)]
 pub struct DfHint {
     pub error_code: Option<u16>,
+    pub ignore_error_code: Option<u16>,
     pub comment: String,
     pub prefix: String,
 }
@@ -149,11 +156,26 @@ impl DfHint {
 
         Self {
             error_code,
+            ignore_error_code: None,
             comment: comment.to_owned(),
             prefix: prefix.to_owned(),
         }
     }
 
+    pub fn create_from_multi_line_comment(comment: &str) -> Self {
+        let ignore_error_code = match Self::parse_ignore_error(comment) {
+            Ok((_, c)) => c,
+            Err(_) => None,
+        };
+
+        Self {
+            error_code: None,
+            ignore_error_code,
+            comment: comment.to_owned(),
+            prefix: "/*".to_owned(),
+        }
+    }
+
     //  { ErrorCode 25 }
     pub fn parse_code(comment: &str) -> IResult<&str, Option<u16>> {
         let (comment, _) = take_till1(|c| c == '{')(comment)?;
@@ -166,4 +188,21 @@ impl DfHint {
         let code = code.parse::<u16>().ok();
         Ok((comment, code))
     }
+
+    //  + ignore_error(1025)
+    pub fn parse_ignore_error(comment: &str) -> IResult<&str, Option<u16>> {
+        let (comment, _) = multispace0(comment)?;
+        let (comment, _) = tag("+")(comment)?;
+        let (comment, _) = multispace0(comment)?;
+        let (comment, _) = tag_no_case("ignore_error")(comment)?;
+        let (comment, _) = multispace0(comment)?;
+        let (comment, _) = tag("(")(comment)?;
+        let (comment, _) = multispace0(comment)?;
+        let (comment, code) = digit1(comment)?;
+        let (comment, _) = multispace0(comment)?;
+        let (comment, _) = tag(")")(comment)?;
+
+        let code = code.parse::<u16>().ok();
+        Ok((comment, code))
+    }
 }
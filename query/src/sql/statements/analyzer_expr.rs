@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
 use std::convert::TryFrom;
 use std::sync::Arc;
 
@@ -27,17 +28,25 @@ use common_exception::ErrorCode;
 use common_exception::Result;
 use common_functions::aggregates::AggregateFunctionFactory;
 use common_functions::is_builtin_function;
+use common_functions::scalars::Function2Factory;
+use common_infallible::Mutex;
 use common_planners::Expression;
+use common_planners::PlanNode;
+use sqlparser::ast::BinaryOperator;
 use sqlparser::ast::Expr;
 use sqlparser::ast::FunctionArgExpr;
 use sqlparser::ast::Ident;
 use sqlparser::ast::Query;
+use sqlparser::ast::SelectItem;
+use sqlparser::ast::TrimWhereField;
 use sqlparser::ast::UnaryOperator;
 use sqlparser::ast::Value;
 
 use crate::functions::ContextFunction;
 use crate::sessions::QueryContext;
 use crate::sql::statements::analyzer_value_expr::ValueExprAnalyzer;
+use crate::sql::statements::query::JoinedSchema;
+use crate::sql::statements::query::JoinedSchemaAnalyzer;
 use crate::sql::statements::AnalyzableStatement;
 use crate::sql::statements::AnalyzedResult;
 use crate::sql::statements::DfQueryStatement;
@@ -46,11 +55,30 @@ use crate::sql::SQLCommon;
 
 pub struct ExpressionAnalyzer {
     context: Arc<QueryContext>,
+    outer_schema: Option<JoinedSchema>,
+    /// The number of call sites of each nondeterministic function.
+    nondeterministic_calls: Mutex<HashMap<String, usize>>,
 }
 
 impl ExpressionAnalyzer {
     pub fn create(context: Arc<QueryContext>) -> ExpressionAnalyzer {
-        ExpressionAnalyzer { context }
+        ExpressionAnalyzer {
+            context,
+            outer_schema: None,
+            nondeterministic_calls: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Create an analyzer whose subqueries can detect references to the columns of `schema`.
+    pub fn create_with_outer_schema(
+        context: Arc<QueryContext>,
+        schema: JoinedSchema,
+    ) -> ExpressionAnalyzer {
+        ExpressionAnalyzer {
+            context,
+            outer_schema: Some(schema),
+            nondeterministic_calls: Mutex::new(HashMap::new()),
+        }
     }
 
     pub async fn analyze(&self, expr: &Expr) -> Result<Expression> {
@@ -69,6 +97,7 @@ impl ExpressionAnalyzer {
                 ExprRPNItem::Cast(v) => self.analyze_cast(v, &mut stack)?,
                 ExprRPNItem::Between(negated) => self.analyze_between(*negated, &mut stack)?,
                 ExprRPNItem::InList(v) => self.analyze_inlist(v, &mut stack)?,
+                ExprRPNItem::InSubquery(v) => self.analyze_insubquery(v, &mut stack).await?,
             }
         }
 
@@ -120,7 +149,11 @@ impl ExpressionAnalyzer {
             "IN".to_string()
         };
 
-        args.push(Expression::ScalarFunction { op, args: list });
+        args.push(Expression::ScalarFunction {
+            op,
+            args: list,
+            ordinal: None,
+        });
         Ok(())
     }
 
@@ -183,9 +216,11 @@ impl ExpressionAnalyzer {
             true => {
                 let op = info.name.clone();
                 let arguments = args.to_owned();
+                let ordinal = self.nondeterministic_ordinal(&op);
                 Ok(Expression::ScalarFunction {
                     op,
                     args: arguments,
+                    ordinal,
                 })
             }
             false => {
@@ -193,11 +228,31 @@ impl ExpressionAnalyzer {
                 Ok(Expression::ScalarFunction {
                     op,
                     args: context_args,
+                    ordinal: None,
                 })
             }
         }
     }
 
+    /// Number the call sites of a nondeterministic function, so that each call is evaluated
+    /// into its own column instead of sharing one by the same name.
+    fn nondeterministic_ordinal(&self, op: &str) -> Option<usize> {
+        let is_deterministic = Function2Factory::instance()
+            .get_features(op)
+            .map(|features| features.is_deterministic)
+            .unwrap_or(true);
+
+        match is_deterministic {
+            true => None,
+            false => {
+                let mut calls = self.nondeterministic_calls.lock();
+                let ordinal = calls.entry(op.to_lowercase()).or_insert(0);
+                *ordinal += 1;
+                Some(*ordinal)
+            }
+        }
+    }
+
     fn aggr_function(&self, info: &FunctionExprInfo, args: &[Expression]) -> Result<Expression> {
         let mut parameters = Vec::with_capacity(info.parameters.len());
 
@@ -257,29 +312,51 @@ impl ExpressionAnalyzer {
         args.push(Expression::ScalarFunction {
             op: "EXISTS".to_lowercase(),
             args: subquery,
+            ordinal: None,
         });
         Ok(())
     }
 
-    async fn analyze_subquery(&self, subquery: &Query) -> Result<Expression> {
-        let statement = DfQueryStatement::try_from(subquery.clone())?;
+    async fn analyze_insubquery(
+        &self,
+        info: &InSubqueryInfo,
+        args: &mut Vec<Expression>,
+    ) -> Result<()> {
+        let expr = args
+            .pop()
+            .ok_or_else(|| ErrorCode::LogicalError("It's a bug."))?;
 
-        let query_context = self.context.clone();
-        let subquery_context = QueryContext::create_from(query_context.clone());
-
-        let analyze_subquery = statement.analyze(subquery_context);
-        if let AnalyzedResult::SelectQuery(analyze_data) = analyze_subquery.await? {
-            let subquery_plan = PlanParser::build_query_plan(&analyze_data)?;
-            return Ok(Expression::Subquery {
-                name: query_context.get_subquery_name(&subquery_plan),
-                query_plan: Arc::new(subquery_plan),
-            });
+        let subquery = self.analyze_subquery(&info.subquery).await?;
+        if let Expression::Subquery { query_plan, .. } = &subquery {
+            let columns = query_plan.schema().fields().len();
+            if columns != 1 {
+                return Err(ErrorCode::SemanticError(format!(
+                    "The subquery of IN must return exactly one column, but got {}",
+                    columns
+                )));
+            }
         }
 
-        Err(ErrorCode::SyntaxException(format!(
-            "Unsupported subquery type {:?}",
-            subquery
-        )))
+        let op = if info.negated {
+            "NOT_IN".to_string()
+        } else {
+            "IN".to_string()
+        };
+
+        args.push(Expression::ScalarFunction {
+            op,
+            args: vec![expr, subquery],
+            ordinal: None,
+        });
+        Ok(())
+    }
+
+    async fn analyze_subquery(&self, subquery: &Query) -> Result<Expression> {
+        let subquery_plan = self.build_subquery_plan(subquery).await?;
+        Ok(Expression::Subquery {
+            name: self.context.get_subquery_name(&subquery_plan),
+            query_plan: Arc::new(subquery_plan),
+        })
     }
 
     async fn analyze_scalar_subquery(
@@ -287,26 +364,78 @@ impl ExpressionAnalyzer {
         subquery: &Query,
         args: &mut Vec<Expression>,
     ) -> Result<()> {
+        let subquery_plan = self.build_subquery_plan(subquery).await?;
+        args.push(Expression::ScalarSubquery {
+            name: self.context.get_subquery_name(&subquery_plan),
+            query_plan: Arc::new(subquery_plan),
+        });
+
+        Ok(())
+    }
+
+    async fn build_subquery_plan(&self, subquery: &Query) -> Result<PlanNode> {
         let statement = DfQueryStatement::try_from(subquery.clone())?;
+        let subquery_context = QueryContext::create_from(self.context.clone());
 
-        let query_context = self.context.clone();
-        let subquery_context = QueryContext::create_from(query_context.clone());
+        match statement.analyze(subquery_context.clone()).await {
+            Ok(AnalyzedResult::SelectQuery(analyze_data)) => {
+                PlanParser::build_query_plan(&analyze_data)
+            }
+            Ok(_) => Err(ErrorCode::SyntaxException(format!(
+                "Unsupported subquery type {:?}",
+                subquery
+            ))),
+            Err(cause) if cause.code() == ErrorCode::UnknownColumnCode() => {
+                match self
+                    .find_correlated_column(&statement, subquery_context)
+                    .await?
+                {
+                    None => Err(cause),
+                    Some(column_name) => Err(ErrorCode::UnImplement(format!(
+                        "Correlated subquery is unimplemented, outer column {} is referenced",
+                        column_name
+                    ))),
+                }
+            }
+            Err(cause) => Err(cause),
+        }
+    }
+
+    // Find the first column that is unknown in the subquery but resolvable in the outer query.
+    async fn find_correlated_column(
+        &self,
+        statement: &DfQueryStatement,
+        ctx: Arc<QueryContext>,
+    ) -> Result<Option<String>> {
+        let outer_schema = match &self.outer_schema {
+            None => return Ok(None),
+            Some(outer_schema) => outer_schema,
+        };
 
-        let analyze_subquery = statement.analyze(subquery_context);
-        if let AnalyzedResult::SelectQuery(analyze_data) = analyze_subquery.await? {
-            let subquery_plan = PlanParser::build_query_plan(&analyze_data)?;
-            args.push(Expression::ScalarSubquery {
-                name: query_context.get_subquery_name(&subquery_plan),
-                query_plan: Arc::new(subquery_plan),
-            });
+        let inner_schema = JoinedSchemaAnalyzer::create(ctx).analyze(statement).await?;
 
-            return Ok(());
+        let mut collector = IdentifierCollector::default();
+        for item in &statement.projection {
+            match item {
+                SelectItem::UnnamedExpr(expr) => {
+                    ExprTraverser::accept(expr, &mut collector).await?
+                }
+                SelectItem::ExprWithAlias { expr, .. } => {
+                    ExprTraverser::accept(expr, &mut collector).await?
+                }
+                _ => {}
+            }
+        }
+
+        let exprs = statement.selection.iter().chain(statement.having.iter());
+        for expr in exprs.chain(statement.group_by.iter()) {
+            ExprTraverser::accept(expr, &mut collector).await?;
         }
 
-        Err(ErrorCode::SyntaxException(format!(
-            "Unsupported subquery type {:?}",
-            subquery
-        )))
+        Ok(collector
+            .names
+            .into_iter()
+            .find(|name| !inner_schema.contains_column(name) && outer_schema.contains_column(name)))
     }
 
     fn analyze_wildcard(&self, arguments: &mut Vec<Expression>) -> Result<()> {
@@ -338,22 +467,12 @@ impl ExpressionAnalyzer {
         }
 
         let s_args = args.split_off(args.len() - 3);
-        let expression = s_args[0].clone();
-        let low_expression = s_args[1].clone();
-        let high_expression = s_args[2].clone();
-
-        match negated {
-            false => args.push(
-                expression
-                    .gt_eq(low_expression)
-                    .and(expression.lt_eq(high_expression)),
-            ),
-            true => args.push(
-                expression
-                    .lt(low_expression)
-                    .or(expression.gt(high_expression)),
-            ),
-        };
+        args.push(Expression::Between {
+            expr: Box::new(s_args[0].clone()),
+            negated,
+            low: Box::new(s_args[1].clone()),
+            high: Box::new(s_args[2].clone()),
+        });
 
         Ok(())
     }
@@ -378,6 +497,11 @@ struct InListInfo {
     negated: bool,
 }
 
+struct InSubqueryInfo {
+    subquery: Box<Query>,
+    negated: bool,
+}
+
 enum ExprRPNItem {
     Value(Value),
     Identifier(Ident),
@@ -389,6 +513,7 @@ enum ExprRPNItem {
     Cast(DataTypePtr),
     Between(bool),
     InList(InListInfo),
+    InSubquery(InSubqueryInfo),
 }
 
 impl ExprRPNItem {
@@ -468,6 +593,21 @@ impl ExprRPNBuilder {
                     _ => self.rpn.push(ExprRPNItem::unary_operator(op.to_string())),
                 }
             }
+            Expr::BinaryOp {
+                op: BinaryOperator::StringConcat,
+                ..
+            } => {
+                // '||' is the logical OR in mysql dialect.
+                let dialect = self.context.get_settings().get_sql_dialect()?;
+                match dialect.as_str() {
+                    "mysql" => self
+                        .rpn
+                        .push(ExprRPNItem::binary_operator("OR".to_string())),
+                    _ => self
+                        .rpn
+                        .push(ExprRPNItem::function(String::from("concat"), 2)),
+                }
+            }
             Expr::BinaryOp { op, .. } => {
                 self.rpn.push(ExprRPNItem::binary_operator(op.to_string()));
             }
@@ -519,6 +659,19 @@ impl ExprRPNBuilder {
                     }
                 }
             }
+            Expr::Trim { trim_where, .. } => match trim_where {
+                None => self
+                    .rpn
+                    .push(ExprRPNItem::function(String::from("trim"), 1)),
+                Some((field, _)) => {
+                    let name = match field {
+                        TrimWhereField::Both => "trim_both",
+                        TrimWhereField::Leading => "trim_leading",
+                        TrimWhereField::Trailing => "trim_trailing",
+                    };
+                    self.rpn.push(ExprRPNItem::function(String::from(name), 2));
+                }
+            },
             Expr::Between { negated, .. } => {
                 self.rpn.push(ExprRPNItem::Between(*negated));
             }
@@ -538,6 +691,12 @@ impl ExprRPNBuilder {
                 list_size: list.len(),
                 negated: *negated,
             })),
+            Expr::InSubquery {
+                subquery, negated, ..
+            } => self.rpn.push(ExprRPNItem::InSubquery(InSubqueryInfo {
+                subquery: subquery.clone(),
+                negated: *negated,
+            })),
             _ => (),
         }
 
@@ -584,3 +743,24 @@ impl ExprVisitor for ExprRPNBuilder {
         Ok(())
     }
 }
+
+#[derive(Default)]
+struct IdentifierCollector {
+    names: Vec<String>,
+}
+
+#[async_trait]
+impl ExprVisitor for IdentifierCollector {
+    fn visit_identifier(&mut self, ident: &Ident) -> Result<()> {
+        self.names.push(ident.value.clone());
+        Ok(())
+    }
+
+    fn visit_identifiers(&mut self, idents: &[Ident]) -> Result<()> {
+        if let Some(ident) = idents.last() {
+            self.names.push(ident.value.clone());
+        }
+
+        Ok(())
+    }
+}
@@ -43,6 +43,7 @@ pub enum QueryRelation {
 
 #[derive(Clone)]
 pub struct QueryAnalyzeState {
+    pub distinct: bool,
     pub filter: Option<Expression>,
     pub having: Option<Expression>,
     pub order_by_expressions: Vec<Expression>,
@@ -51,9 +52,14 @@ pub struct QueryAnalyzeState {
     pub projection_expressions: Vec<Expression>,
 
     pub group_by_expressions: Vec<Expression>,
+    /// GROUP BY ROLLUP(...), also aggregates by every prefix of the group by expressions.
+    pub group_by_rollup: bool,
     pub aggregate_expressions: Vec<Expression>,
     pub before_group_by_expressions: Vec<Expression>,
 
+    pub limit_by_count: Option<usize>,
+    pub limit_by_expressions: Vec<Expression>,
+
     pub limit: Option<usize>,
     pub offset: Option<usize>,
 
@@ -68,6 +74,17 @@ impl QueryAnalyzeState {
         }
     }
 
+    /// The columns which SELECT DISTINCT deduplicates the rows on, i.e. the projection without aliases.
+    pub fn distinct_expressions(&self) -> Vec<Expression> {
+        self.projection_expressions
+            .iter()
+            .map(|expr| match expr {
+                Expression::Alias(_, expr) => expr.as_ref().clone(),
+                _ => expr.clone(),
+            })
+            .collect()
+    }
+
     pub fn add_before_group_expression(&mut self, expr: &Expression) {
         if !self.before_group_by_expressions.contains(expr) {
             self.before_group_by_expressions.push(expr.clone());
@@ -78,14 +95,18 @@ impl QueryAnalyzeState {
 impl Default for QueryAnalyzeState {
     fn default() -> Self {
         QueryAnalyzeState {
+            distinct: false,
             filter: None,
             having: None,
             order_by_expressions: vec![],
             expressions: vec![],
             projection_expressions: vec![],
             group_by_expressions: vec![],
+            group_by_rollup: false,
             aggregate_expressions: vec![],
             before_group_by_expressions: vec![],
+            limit_by_count: None,
+            limit_by_expressions: vec![],
             limit: None,
             offset: None,
             relation: QueryRelation::None,
@@ -110,6 +131,10 @@ impl Debug for QueryAnalyzeState {
             debug_struct.field("aggregator", &self.group_by_expressions);
         }
 
+        if self.group_by_rollup {
+            debug_struct.field("rollup", &self.group_by_rollup);
+        }
+
         if !self.aggregate_expressions.is_empty() {
             debug_struct.field("aggregate", &self.aggregate_expressions);
         }
@@ -129,10 +154,18 @@ impl Debug for QueryAnalyzeState {
             debug_struct.field("order_by", &self.order_by_expressions);
         }
 
+        if !self.limit_by_expressions.is_empty() {
+            debug_struct.field("limit_by", &self.limit_by_expressions);
+        }
+
         if !self.projection_expressions.is_empty() {
             debug_struct.field("projection", &self.projection_expressions);
         }
 
+        if self.distinct {
+            debug_struct.field("distinct", &self.distinct);
+        }
+
         debug_struct.finish()
     }
 }
@@ -147,6 +180,7 @@ impl AnalyzableStatement for DfStatement {
     async fn analyze(&self, ctx: Arc<QueryContext>) -> Result<AnalyzedResult> {
         match self {
             DfStatement::Query(v) => v.analyze(ctx).await,
+            DfStatement::UnionAll(v) => v.analyze(ctx).await,
             DfStatement::Explain(v) => v.analyze(ctx).await,
             DfStatement::ShowDatabases(v) => v.analyze(ctx).await,
             DfStatement::ShowCreateDatabase(v) => v.analyze(ctx).await,
@@ -162,6 +196,7 @@ impl AnalyzableStatement for DfStatement {
             DfStatement::UseTenant(v) => v.analyze(ctx).await,
             DfStatement::ShowCreateTable(v) => v.analyze(ctx).await,
             DfStatement::ShowTables(v) => v.analyze(ctx).await,
+            DfStatement::ShowTableStatus(v) => v.analyze(ctx).await,
             DfStatement::ShowSettings(v) => v.analyze(ctx).await,
             DfStatement::ShowProcessList(v) => v.analyze(ctx).await,
             DfStatement::ShowMetrics(v) => v.analyze(ctx).await,
@@ -51,9 +51,11 @@ mod statement_show_kind;
 mod statement_show_metrics;
 mod statement_show_processlist;
 mod statement_show_settings;
+mod statement_show_table_status;
 mod statement_show_tables;
 mod statement_show_users;
 mod statement_truncate_table;
+mod statement_union_all;
 mod statement_use_database;
 mod statement_use_tenant;
 
@@ -97,8 +99,10 @@ pub use statement_show_kind::DfShowKind;
 pub use statement_show_metrics::DfShowMetrics;
 pub use statement_show_processlist::DfShowProcessList;
 pub use statement_show_settings::DfShowSettings;
+pub use statement_show_table_status::DfShowTableStatus;
 pub use statement_show_tables::DfShowTables;
 pub use statement_show_users::DfShowUsers;
 pub use statement_truncate_table::DfTruncateTable;
+pub use statement_union_all::DfUnionAllStatement;
 pub use statement_use_database::DfUseDatabase;
 pub use statement_use_tenant::DfUseTenant;
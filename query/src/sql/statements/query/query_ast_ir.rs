@@ -26,6 +26,8 @@ pub struct QueryASTIR {
     pub aggregate_expressions: Vec<Expression>,
     pub order_by_expressions: Vec<Expression>,
     pub projection_expressions: Vec<Expression>,
+    pub limit_by_count: Option<usize>,
+    pub limit_by_expressions: Vec<Expression>,
     pub limit: Option<usize>,
     pub offset: Option<usize>,
 }
@@ -42,6 +44,7 @@ pub trait QueryASTIRVisitor<Data> {
 
         Self::visit_group_by(&mut ir.group_by_expressions, data)?;
         Self::visit_order_by(&mut ir.order_by_expressions, data)?;
+        Self::visit_limit_by(&mut ir.limit_by_expressions, data)?;
         Self::visit_aggregates(&mut ir.aggregate_expressions, data)?;
         Self::visit_projection(&mut ir.projection_expressions, data)?;
         Ok(())
@@ -59,6 +62,13 @@ pub trait QueryASTIRVisitor<Data> {
                 Self::visit_recursive_expr(left, data)?;
                 Self::visit_recursive_expr(right, data)
             }
+            Expression::Between {
+                expr, low, high, ..
+            } => {
+                Self::visit_recursive_expr(expr, data)?;
+                Self::visit_recursive_expr(low, data)?;
+                Self::visit_recursive_expr(high, data)
+            }
             Expression::ScalarFunction { args, .. } => {
                 for arg in args {
                     Self::visit_recursive_expr(arg, data)?;
@@ -116,6 +126,14 @@ pub trait QueryASTIRVisitor<Data> {
         Ok(())
     }
 
+    fn visit_limit_by(exprs: &mut Vec<Expression>, data: &mut Data) -> Result<()> {
+        for expr in exprs {
+            Self::visit_recursive_expr(expr, data)?;
+        }
+
+        Ok(())
+    }
+
     fn visit_projection(exprs: &mut Vec<Expression>, data: &mut Data) -> Result<()> {
         for expr in exprs {
             Self::visit_recursive_expr(expr, data)?;
@@ -149,6 +167,10 @@ impl Debug for QueryASTIR {
             debug_struct.field("order by", &self.order_by_expressions);
         }
 
+        if !self.limit_by_expressions.is_empty() {
+            debug_struct.field("limit by", &self.limit_by_expressions);
+        }
+
         if !self.projection_expressions.is_empty() {
             debug_struct.field("projection", &self.projection_expressions);
         }
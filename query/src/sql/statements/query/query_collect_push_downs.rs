@@ -62,12 +62,14 @@ impl QueryCollectPushDowns {
         for index in 0..schema.get_tables_desc().len() {
             let table_desc = &schema.get_tables_desc()[index];
             let projection = self.collect_table_require_columns(table_desc);
+            let sample = table_desc.get_sample();
 
             schema.set_table_push_downs(index, Extras {
                 projection: Some(projection),
                 filters: self.require_filters.clone(),
                 limit: None,
                 order_by: vec![],
+                sample,
             });
         }
 
@@ -13,35 +13,54 @@
 // limitations under the License.
 
 use std::collections::HashMap;
+use std::collections::HashSet;
 use std::sync::Arc;
 
+use common_datavalues2::prelude::*;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_planners::extract_aliases;
 use common_planners::find_aggregate_exprs_in_expr;
+use common_planners::find_column_exprs;
+use common_planners::find_nondeterministic_exprs_in_expr;
 use common_planners::resolve_aliases_to_exprs;
+use common_planners::unwrap_alias_exprs;
 use common_planners::Expression;
 use sqlparser::ast::Expr;
+use sqlparser::ast::ObjectName;
 use sqlparser::ast::OffsetRows;
 use sqlparser::ast::SelectItem;
+use sqlparser::ast::Value;
 
+use crate::optimizers::ConstantFoldingOptimizer;
 use crate::sessions::QueryContext;
 use crate::sql::statements::analyzer_expr::ExpressionAnalyzer;
+use crate::sql::statements::query::JoinedSchema;
+use crate::sql::statements::query::QualifiedRewriter;
 use crate::sql::statements::query::QueryASTIR;
 use crate::sql::statements::DfQueryStatement;
 
 pub struct QueryNormalizer {
+    ctx: Arc<QueryContext>,
     query_ast_ir: QueryASTIR,
     expression_analyzer: ExpressionAnalyzer,
     aliases_map: HashMap<String, Expression>,
+    /// The columns of FROM, WHERE only falls back to projection aliases for other names.
+    input_schema: Option<JoinedSchema>,
 }
 
 /// Replace alias in query and collect aggregate functions
 impl QueryNormalizer {
-    fn create(ctx: Arc<QueryContext>) -> QueryNormalizer {
+    fn create(
+        ctx: Arc<QueryContext>,
+        expression_analyzer: ExpressionAnalyzer,
+        input_schema: Option<JoinedSchema>,
+    ) -> QueryNormalizer {
         QueryNormalizer {
-            expression_analyzer: ExpressionAnalyzer::create(ctx),
+            ctx,
+            expression_analyzer,
             aliases_map: HashMap::new(),
+            input_schema,
             query_ast_ir: QueryASTIR {
                 filter_predicate: None,
                 group_by_expressions: vec![],
@@ -49,6 +68,8 @@ impl QueryNormalizer {
                 aggregate_expressions: vec![],
                 order_by_expressions: vec![],
                 projection_expressions: vec![],
+                limit_by_count: None,
+                limit_by_expressions: vec![],
                 limit: None,
                 offset: None,
             },
@@ -56,19 +77,32 @@ impl QueryNormalizer {
     }
 
     pub async fn normalize(ctx: Arc<QueryContext>, v: &DfQueryStatement) -> Result<QueryASTIR> {
-        let query_normalizer = QueryNormalizer::create(ctx);
+        let analyzer = ExpressionAnalyzer::create(ctx.clone());
+        let query_normalizer = QueryNormalizer::create(ctx, analyzer, None);
         query_normalizer.transform(v).await
     }
 
-    pub async fn transform(mut self, query: &DfQueryStatement) -> Result<QueryASTIR> {
-        if let Err(cause) = self.visit_filter(query).await {
-            return Err(cause.add_message_back(" (while in analyze select filter)"));
-        }
+    /// Normalize the query, subqueries inside it can see the columns of the outer `schema`.
+    pub async fn normalize_with_schema(
+        ctx: Arc<QueryContext>,
+        v: &DfQueryStatement,
+        schema: &JoinedSchema,
+    ) -> Result<QueryASTIR> {
+        let analyzer = ExpressionAnalyzer::create_with_outer_schema(ctx.clone(), schema.clone());
+        let query_normalizer = QueryNormalizer::create(ctx, analyzer, Some(schema.clone()));
+        query_normalizer.transform(v).await
+    }
 
+    pub async fn transform(mut self, query: &DfQueryStatement) -> Result<QueryASTIR> {
+        // The projection goes first, so WHERE can see its aliases.
         if let Err(cause) = self.analyze_projection(query).await {
             return Err(cause.add_message_back(" (while in analyze select projection)"));
         }
 
+        if let Err(cause) = self.visit_filter(query).await {
+            return Err(cause.add_message_back(" (while in analyze select filter)"));
+        }
+
         if let Err(cause) = self.analyze_group_by(query).await {
             return Err(cause.add_message_back(" (while in analyze select group by)"));
         }
@@ -81,6 +115,10 @@ impl QueryNormalizer {
             return Err(cause.add_message_back(" (while in analyze select order by)"));
         }
 
+        if let Err(cause) = self.analyze_limit_by(query).await {
+            return Err(cause.add_message_back(" (while in analyze select limit by)"));
+        }
+
         if let Err(cause) = self.analyze_limit(query).await {
             return Err(cause.add_message_back(" (while in analyze select limit)"));
         }
@@ -91,14 +129,46 @@ impl QueryNormalizer {
     async fn visit_filter(&mut self, query: &DfQueryStatement) -> Result<()> {
         if let Some(predicate) = &query.selection {
             let analyzer = &self.expression_analyzer;
-            self.query_ast_ir.filter_predicate = Some(analyzer.analyze(predicate).await?);
+            let expression = analyzer.analyze(predicate).await?;
+            self.query_ast_ir.filter_predicate = Some(self.resolve_filter_aliases(&expression)?);
         }
 
         Ok(())
     }
 
+    /// Replace the names in WHERE that are not columns of FROM with the projection aliases.
+    fn resolve_filter_aliases(&self, expr: &Expression) -> Result<Expression> {
+        let input_schema = match &self.input_schema {
+            None => return Ok(expr.clone()),
+            Some(input_schema) => input_schema,
+        };
+
+        let mut aliases_map = HashMap::new();
+        for column_expr in find_column_exprs(&[expr.clone()]) {
+            if let Expression::Column(name) = column_expr {
+                if input_schema.contains_column(&name) {
+                    continue;
+                }
+
+                if let Some(aliased_expr) = self.aliases_map.get(&name) {
+                    if !find_aggregate_exprs_in_expr(aliased_expr).is_empty() {
+                        return Err(ErrorCode::SemanticError(format!(
+                            "WHERE cannot reference the aggregate alias {}, use HAVING instead",
+                            name
+                        )));
+                    }
+
+                    aliases_map.insert(name, aliased_expr.clone());
+                }
+            }
+        }
+
+        resolve_aliases_to_exprs(expr, &aliases_map)
+    }
+
     async fn analyze_projection(&mut self, query: &DfQueryStatement) -> Result<()> {
         let projection_expressions = self.projection_exprs(query).await?;
+        Self::check_projection_aliases(&projection_expressions)?;
         self.aliases_map = extract_aliases(&projection_expressions);
 
         for projection_expression in &projection_expressions {
@@ -109,9 +179,55 @@ impl QueryNormalizer {
         Ok(())
     }
 
+    /// An alias names an output column, so it must not collide with the name of another
+    /// output column. The later of the two is reported.
+    fn check_projection_aliases(projection_expressions: &[Expression]) -> Result<()> {
+        let mut column_names = HashSet::new();
+        let mut alias_names = HashSet::new();
+        for projection_expression in projection_expressions {
+            let name = projection_expression.column_name();
+            let collided = match projection_expression {
+                Expression::Alias(..) => column_names.contains(&name),
+                _ => alias_names.contains(&name),
+            };
+
+            if collided {
+                return Err(ErrorCode::SemanticError(format!(
+                    "Duplicate column name {} in the select list",
+                    name
+                )));
+            }
+
+            if let Expression::Alias(..) = projection_expression {
+                alias_names.insert(name.clone());
+            }
+            column_names.insert(name);
+        }
+
+        Ok(())
+    }
+
     async fn analyze_group_by(&mut self, query: &DfQueryStatement) -> Result<()> {
         for group_by_expr in &query.group_by {
-            let expression = self.resolve_aliases(group_by_expr).await?;
+            let expression = match self.resolve_ordinal(group_by_expr, "GROUP BY")? {
+                Some(expression) if !find_aggregate_exprs_in_expr(&expression).is_empty() => {
+                    return Err(ErrorCode::SemanticError(format!(
+                        "GROUP BY position {} refers to an aggregate function",
+                        group_by_expr
+                    )));
+                }
+                Some(expression) => expression,
+                None => self.resolve_aliases(group_by_expr).await?,
+            };
+
+            if let Some(function) = find_nondeterministic_exprs_in_expr(&expression).first() {
+                return Err(ErrorCode::SemanticError(format!(
+                    "GROUP BY expression {} contains the nondeterministic function {}",
+                    expression.column_name(),
+                    function.column_name()
+                )));
+            }
+
             self.query_ast_ir.group_by_expressions.push(expression);
         }
 
@@ -130,15 +246,29 @@ impl QueryNormalizer {
 
     async fn analyze_order_by(&mut self, query: &DfQueryStatement) -> Result<()> {
         for order_by_expr in &query.order_by {
-            let expression = self.resolve_aliases(&order_by_expr.expr).await?;
+            let (expr, case_insensitive) = match &order_by_expr.expr {
+                Expr::Collate { expr, collation } => {
+                    (expr.as_ref(), Self::is_case_insensitive(collation)?)
+                }
+                expr => (expr, false),
+            };
+            let expression = match self.resolve_ordinal(expr, "ORDER BY")? {
+                Some(expression) => expression,
+                None => self.resolve_aliases(expr).await?,
+            };
+
+            // NULLs are the smallest values unless NULLS FIRST/LAST is given.
+            let asc = order_by_expr.asc.unwrap_or(true);
+            let nulls_first = order_by_expr.nulls_first.unwrap_or(asc);
 
             self.add_aggregate_function(&expression)?;
             self.query_ast_ir
                 .order_by_expressions
                 .push(Expression::Sort {
                     expr: Box::new(expression.clone()),
-                    asc: order_by_expr.asc.unwrap_or(true),
-                    nulls_first: order_by_expr.asc.unwrap_or(true),
+                    asc,
+                    nulls_first,
+                    case_insensitive,
                     origin_expr: Box::new(expression),
                 });
         }
@@ -146,16 +276,45 @@ impl QueryNormalizer {
         Ok(())
     }
 
-    async fn analyze_limit(&mut self, query: &DfQueryStatement) -> Result<()> {
-        if let Some(limit) = &query.limit {
+    fn is_case_insensitive(collation: &ObjectName) -> Result<bool> {
+        match collation.to_string().to_lowercase().as_str() {
+            "binary" => Ok(false),
+            "nocase" => Ok(true),
+            _ => Err(ErrorCode::SyntaxException(format!(
+                "Unsupported collation: {}, only binary and nocase are supported",
+                collation
+            ))),
+        }
+    }
+
+    async fn analyze_limit_by(&mut self, query: &DfQueryStatement) -> Result<()> {
+        if let Some(limit) = &query.limit_by_count {
             let expression_analyzer = &self.expression_analyzer;
             let limit_literal = match expression_analyzer.analyze(limit).await? {
                 Expression::Literal { value, .. } => Ok(value.as_u64()? as usize),
                 _ => Err(ErrorCode::SyntaxException(format!(
-                    "Unexpected expression for LIMIT clause: {:?}",
+                    "Unexpected expression for LIMIT BY clause: {:?}",
                     limit
                 ))),
             }?;
+            self.query_ast_ir.limit_by_count = Some(limit_literal);
+        }
+
+        for limit_by_expr in &query.limit_by {
+            let expression = self.resolve_aliases(limit_by_expr).await?;
+
+            self.add_aggregate_function(&expression)?;
+            self.query_ast_ir.limit_by_expressions.push(expression);
+        }
+
+        Ok(())
+    }
+
+    async fn analyze_limit(&mut self, query: &DfQueryStatement) -> Result<()> {
+        if let Some(limit) = &query.limit {
+            let expression_analyzer = &self.expression_analyzer;
+            let expression = expression_analyzer.analyze(limit).await?;
+            let limit_literal = Self::limit_count(expression, "LIMIT", limit)?;
             self.query_ast_ir.limit = Some(limit_literal);
         }
 
@@ -165,19 +324,47 @@ impl QueryNormalizer {
             }
 
             let expression_analyzer = &self.expression_analyzer;
-            let offset_literal = match expression_analyzer.analyze(&offset.value).await? {
-                Expression::Literal { value, .. } => Ok(value.as_u64()? as usize),
-                _ => Err(ErrorCode::SyntaxException(format!(
-                    "Unexpected expression for LIMIT clause: {:?}",
-                    offset
-                ))),
-            }?;
+            let expression = expression_analyzer.analyze(&offset.value).await?;
+            let offset_literal = Self::limit_count(expression, "OFFSET", &offset.value)?;
             self.query_ast_ir.offset = Some(offset_literal);
         }
 
         Ok(())
     }
 
+    /// LIMIT and OFFSET accept any constant expression with a non-negative integer result.
+    fn limit_count(expression: Expression, clause: &str, expr: &Expr) -> Result<usize> {
+        let value = match expression {
+            Expression::Literal { value, .. } => value,
+            expression if find_column_exprs(&[expression.clone()]).is_empty() => {
+                ConstantFoldingOptimizer::evaluate_constant(expression)?.0
+            }
+            _ => {
+                return Err(ErrorCode::SyntaxException(format!(
+                    "Unexpected expression for {} clause: {:?}",
+                    clause, expr
+                )));
+            }
+        };
+
+        match value {
+            DataValue::UInt64(v) => Ok(v as usize),
+            DataValue::Int64(v) if v >= 0 => Ok(v as usize),
+            DataValue::Int64(v) => Err(ErrorCode::SyntaxException(format!(
+                "{} must be a non-negative integer, but got {}",
+                clause, v
+            ))),
+            // e.g. LIMIT 10 / 2, the division always returns a float.
+            DataValue::Float64(v) if v >= 0.0 && v.fract() == 0.0 && v <= usize::MAX as f64 => {
+                Ok(v as usize)
+            }
+            other => Err(ErrorCode::SyntaxException(format!(
+                "{} must be an integer, but got {}",
+                clause, other
+            ))),
+        }
+    }
+
     async fn projection_exprs(&self, query: &DfQueryStatement) -> Result<Vec<Expression>> {
         let mut output_columns = Vec::with_capacity(query.projection.len());
 
@@ -187,6 +374,16 @@ impl QueryNormalizer {
                 SelectItem::Wildcard => {
                     output_columns.push(Expression::Wildcard);
                 }
+                SelectItem::QualifiedWildcard(qualifier) => {
+                    // Expanded to the columns of the qualified table by QualifiedRewriter.
+                    let mut name_parts = qualifier
+                        .0
+                        .iter()
+                        .map(|ident| ident.value.clone())
+                        .collect::<Vec<_>>();
+                    name_parts.push(String::from("*"));
+                    output_columns.push(Expression::QualifiedColumn(name_parts));
+                }
                 SelectItem::UnnamedExpr(expr) => {
                     output_columns.push(expr_analyzer.analyze(expr).await?);
                 }
@@ -213,6 +410,46 @@ impl QueryNormalizer {
         resolve_aliases_to_exprs(&expression_analyzer.analyze(expr).await?, aliases_map)
     }
 
+    /// Resolve a bare positive integer literal as a 1-based select-list position.
+    fn resolve_ordinal(&self, expr: &Expr, clause: &str) -> Result<Option<Expression>> {
+        let position = match expr {
+            Expr::Value(Value::Number(value, _)) => match value.parse::<usize>() {
+                Ok(position) => position,
+                Err(_) => return Ok(None),
+            },
+            _ => return Ok(None),
+        };
+
+        // The positions count the columns of the expanded wildcards.
+        let projection_expressions = match &self.input_schema {
+            None => self.query_ast_ir.projection_expressions.clone(),
+            Some(input_schema) => QualifiedRewriter::expand_projection(
+                input_schema,
+                self.ctx.clone(),
+                &self.query_ast_ir.projection_expressions,
+            )?,
+        };
+
+        if projection_expressions.contains(&Expression::Wildcard) {
+            return Err(ErrorCode::SemanticError(format!(
+                "{} position {} is not supported with wildcard projection",
+                clause, position
+            )));
+        }
+
+        match position.checked_sub(1) {
+            Some(index) if index < projection_expressions.len() => {
+                Ok(Some(unwrap_alias_exprs(&projection_expressions[index])?))
+            }
+            _ => Err(ErrorCode::SemanticError(format!(
+                "{} position {} is not in select list (valid range is [1, {}])",
+                clause,
+                position,
+                projection_expressions.len()
+            ))),
+        }
+    }
+
     fn add_aggregate_function(&mut self, expr: &Expression) -> Result<()> {
         for aggregate_expr in find_aggregate_exprs_in_expr(expr) {
             if !self
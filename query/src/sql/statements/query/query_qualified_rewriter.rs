@@ -47,20 +47,10 @@ impl QueryASTIRVisitor<QualifiedRewriter> for QualifiedRewriter {
     fn visit_projection(exprs: &mut Vec<Expression>, data: &mut QualifiedRewriter) -> Result<()> {
         let mut new_exprs = Vec::with_capacity(exprs.len());
 
-        // TODO: alias.*
         for projection_expr in exprs.iter_mut() {
-            if let Expression::Alias(_, x) = projection_expr {
-                if let Expression::Wildcard = x.as_ref() {
-                    return Err(ErrorCode::SyntaxException("* AS alias is wrong syntax"));
-                }
-            }
-
-            match projection_expr {
-                Expression::Wildcard => Self::expand_wildcard(data, &mut new_exprs),
-                _ => {
-                    Self::visit_recursive_expr(projection_expr, data)?;
-                    new_exprs.push(projection_expr.clone());
-                }
+            if !data.expand_wildcards(projection_expr, &mut new_exprs)? {
+                Self::visit_recursive_expr(projection_expr, data)?;
+                new_exprs.push(projection_expr.clone());
             }
         }
 
@@ -82,17 +72,101 @@ impl QualifiedRewriter {
         QualifiedRewriter::visit(ir, &mut rewriter)
     }
 
+    /// The projection with its wildcards expanded into the columns, the other expressions
+    /// are kept as they are.
+    pub fn expand_projection(
+        schema: &JoinedSchema,
+        ctx: Arc<QueryContext>,
+        exprs: &[Expression],
+    ) -> Result<Vec<Expression>> {
+        let rewriter = QualifiedRewriter {
+            tables_schema: schema.clone(),
+            ctx,
+        };
+
+        let mut new_exprs = Vec::with_capacity(exprs.len());
+        for projection_expr in exprs {
+            if !rewriter.expand_wildcards(projection_expr, &mut new_exprs)? {
+                new_exprs.push(projection_expr.clone());
+            }
+        }
+
+        Ok(new_exprs)
+    }
+
+    // Returns false if the expression is not a wildcard.
+    fn expand_wildcards(
+        &self,
+        projection_expr: &Expression,
+        columns_expression: &mut Vec<Expression>,
+    ) -> Result<bool> {
+        if let Expression::Alias(_, x) = projection_expr {
+            if let Expression::Wildcard = x.as_ref() {
+                return Err(ErrorCode::SyntaxException("* AS alias is wrong syntax"));
+            }
+        }
+
+        match projection_expr {
+            Expression::Wildcard => self.expand_wildcard(columns_expression),
+            Expression::QualifiedColumn(names) if Self::is_qualified_wildcard(names) => {
+                let qualifier = &names[..names.len() - 1];
+                self.expand_qualified_wildcard(qualifier, columns_expression)?
+            }
+            _ => return Ok(false),
+        }
+
+        Ok(true)
+    }
+
     fn expand_wildcard(&self, columns_expression: &mut Vec<Expression>) {
         for table_desc in self.tables_schema.get_tables_desc() {
-            for column_desc in table_desc.get_columns_desc() {
-                let name = column_desc.short_name.clone();
-                match column_desc.is_ambiguity {
-                    true => {
-                        let prefix = table_desc.get_name_parts().join(".");
-                        columns_expression.push(Expression::Column(format!("{}.{}", prefix, name)));
-                    }
-                    false => columns_expression.push(Expression::Column(name)),
+            Self::expand_table_columns(table_desc, columns_expression);
+        }
+    }
+
+    fn is_qualified_wildcard(names: &[String]) -> bool {
+        names.len() > 1 && names[names.len() - 1] == "*"
+    }
+
+    // qualifier.* expands to the columns of the matched table only.
+    fn expand_qualified_wildcard(
+        &self,
+        qualifier: &[String],
+        columns_expression: &mut Vec<Expression>,
+    ) -> Result<()> {
+        let current_database = self.ctx.get_current_database();
+        for table_desc in self.tables_schema.get_tables_desc() {
+            let name_parts = table_desc.get_name_parts();
+            let matched = name_parts == qualifier
+                // use current_database; table.*
+                || (name_parts.len() > 1
+                    && name_parts[1..] == *qualifier
+                    && current_database == name_parts[0]);
+
+            if matched {
+                Self::expand_table_columns(table_desc, columns_expression);
+                return Ok(());
+            }
+        }
+
+        Err(ErrorCode::UnknownTable(format!(
+            "Unknown table {}",
+            qualifier.join(".")
+        )))
+    }
+
+    fn expand_table_columns(
+        table_desc: &JoinedTableDesc,
+        columns_expression: &mut Vec<Expression>,
+    ) {
+        for column_desc in table_desc.get_columns_desc() {
+            let name = column_desc.short_name.clone();
+            match column_desc.is_ambiguity {
+                true => {
+                    let prefix = table_desc.get_name_parts().join(".");
+                    columns_expression.push(Expression::Column(format!("{}.{}", prefix, name)));
                 }
+                false => columns_expression.push(Expression::Column(name)),
             }
         }
     }
@@ -22,6 +22,7 @@ use common_datavalues2::prelude::*;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_planners::Extras;
+use common_planners::TableSample;
 
 use crate::sql::statements::QueryAnalyzeState;
 use crate::storages::Table;
@@ -93,6 +94,13 @@ impl JoinedSchema {
         }
     }
 
+    pub fn set_table_sample(&mut self, table_pos: usize, table_sample: Option<TableSample>) {
+        let table_desc = &mut self.tables_long_name_columns[table_pos];
+        if let JoinedTableDesc::Table { sample, .. } = table_desc {
+            *sample = table_sample;
+        }
+    }
+
     pub fn take_tables_desc(self) -> Vec<JoinedTableDesc> {
         self.tables_long_name_columns
     }
@@ -165,6 +173,7 @@ pub enum JoinedTableDesc {
         name_parts: Vec<String>,
         columns_desc: Vec<JoinedColumnDesc>,
         push_downs: Option<Extras>,
+        sample: Option<TableSample>,
     },
     Subquery {
         state: Box<QueryAnalyzeState>,
@@ -187,6 +196,7 @@ impl JoinedTableDesc {
             columns_desc,
             name_parts: prefix,
             push_downs: None,
+            sample: None,
         }
     }
 
@@ -218,6 +228,13 @@ impl JoinedTableDesc {
             JoinedTableDesc::Subquery { columns_desc, .. } => columns_desc,
         }
     }
+
+    pub fn get_sample(&self) -> Option<TableSample> {
+        match self {
+            JoinedTableDesc::Table { sample, .. } => sample.clone(),
+            JoinedTableDesc::Subquery { .. } => None,
+        }
+    }
 }
 
 #[derive(Clone)]
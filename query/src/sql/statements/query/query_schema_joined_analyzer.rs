@@ -16,7 +16,11 @@ use std::sync::Arc;
 
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_planners::TableSample;
+use sqlparser::ast::Expr;
+use sqlparser::ast::Function;
 use sqlparser::ast::FunctionArg;
+use sqlparser::ast::FunctionArgExpr;
 use sqlparser::ast::Ident;
 use sqlparser::ast::JoinOperator;
 use sqlparser::ast::ObjectName;
@@ -24,6 +28,7 @@ use sqlparser::ast::Query;
 use sqlparser::ast::TableAlias;
 use sqlparser::ast::TableFactor;
 use sqlparser::ast::TableWithJoins;
+use sqlparser::ast::Value;
 
 use crate::catalogs::Catalog;
 use crate::sessions::QueryContext;
@@ -98,16 +103,19 @@ impl JoinedSchemaAnalyzer {
         let (database, table) = self.resolve_table(&item.name)?;
         let read_table = self.ctx.get_table(&database, &table).await?;
 
-        match &item.alias {
+        let mut schema = match &item.alias {
             None => {
                 let name_prefix = vec![database, table];
-                JoinedSchema::from_table(read_table, name_prefix)
+                JoinedSchema::from_table(read_table, name_prefix)?
             }
             Some(table_alias) => {
                 let name_prefix = vec![table_alias.name.value.clone()];
-                JoinedSchema::from_table(read_table, name_prefix)
+                JoinedSchema::from_table(read_table, name_prefix)?
             }
-        }
+        };
+
+        schema.set_table_sample(0, item.sample.clone());
+        Ok(schema)
     }
 
     async fn table_function(&self, item: &TableFunctionRPNItem) -> Result<JoinedSchema> {
@@ -130,13 +138,16 @@ impl JoinedSchemaAnalyzer {
 
         let catalog = self.ctx.get_catalog();
         let table_function = catalog.get_table_function(&table_name, Some(table_args))?;
-        match &item.alias {
-            None => JoinedSchema::from_table(table_function.as_table(), Vec::new()),
+        let mut schema = match &item.alias {
+            None => JoinedSchema::from_table(table_function.as_table(), Vec::new())?,
             Some(table_alias) => {
                 let name_prefix = vec![table_alias.name.value.clone()];
-                JoinedSchema::from_table(table_function.as_table(), name_prefix)
+                JoinedSchema::from_table(table_function.as_table(), name_prefix)?
             }
-        }
+        };
+
+        schema.set_table_sample(0, item.sample.clone());
+        Ok(schema)
     }
 
     fn resolve_table(&self, name: &ObjectName) -> Result<(String, String)> {
@@ -154,6 +165,7 @@ impl JoinedSchemaAnalyzer {
 struct TableRPNItem {
     name: ObjectName,
     alias: Option<TableAlias>,
+    sample: Option<TableSample>,
 }
 
 struct DerivedRPNItem {
@@ -165,6 +177,7 @@ struct TableFunctionRPNItem {
     name: ObjectName,
     args: Vec<FunctionArg>,
     alias: Option<TableAlias>,
+    sample: Option<TableSample>,
 }
 
 enum RelationRPNItem {
@@ -193,6 +206,7 @@ impl RelationRPNBuilder {
         self.rpn.push(RelationRPNItem::Table(TableRPNItem {
             name: ObjectName(vec![Ident::new("system"), Ident::new("one")]),
             alias: None,
+            sample: None,
         }));
     }
 
@@ -233,15 +247,10 @@ impl RelationRPNBuilder {
                 alias,
                 with_hints,
             } => {
-                if !with_hints.is_empty() {
-                    return Err(ErrorCode::SyntaxException(
-                        "MSSQL-specific `WITH (...)` hints is unsupported.",
-                    ));
-                }
-
+                let sample = Self::table_sample(with_hints)?;
                 match args.is_empty() {
-                    true => self.visit_table(name, alias),
-                    false => self.visit_table_function(name, args, alias),
+                    true => self.visit_table(name, alias, sample),
+                    false => self.visit_table_function(name, args, alias, sample),
                 }
             }
             TableFactor::Derived {
@@ -266,10 +275,16 @@ impl RelationRPNBuilder {
         }
     }
 
-    fn visit_table(&mut self, name: &ObjectName, alias: &Option<TableAlias>) -> Result<()> {
+    fn visit_table(
+        &mut self,
+        name: &ObjectName,
+        alias: &Option<TableAlias>,
+        sample: Option<TableSample>,
+    ) -> Result<()> {
         self.rpn.push(RelationRPNItem::Table(TableRPNItem {
             name: name.clone(),
             alias: alias.clone(),
+            sample,
         }));
         Ok(())
     }
@@ -279,13 +294,78 @@ impl RelationRPNBuilder {
         name: &ObjectName,
         args: &[FunctionArg],
         alias: &Option<TableAlias>,
+        sample: Option<TableSample>,
     ) -> Result<()> {
         self.rpn
             .push(RelationRPNItem::TableFunction(TableFunctionRPNItem {
                 name: name.clone(),
                 args: args.to_owned(),
                 alias: alias.clone(),
+                sample,
             }));
         Ok(())
     }
+
+    // The parser rewrites `TABLESAMPLE (n PERCENT) [REPEATABLE (seed)]` into the hint
+    // `WITH (TABLESAMPLE(n [, seed]))`, other hints are unsupported.
+    fn table_sample(with_hints: &[Expr]) -> Result<Option<TableSample>> {
+        let args = match with_hints {
+            [] => return Ok(None),
+            [Expr::Function(Function { name, args, .. })]
+                if name.to_string().eq_ignore_ascii_case("tablesample") =>
+            {
+                args
+            }
+            _ => {
+                return Err(ErrorCode::SyntaxException(
+                    "MSSQL-specific `WITH (...)` hints is unsupported.",
+                ))
+            }
+        };
+
+        let numbers = args
+            .iter()
+            .map(|arg| match arg {
+                FunctionArg::Unnamed(FunctionArgExpr::Expr(Expr::Value(Value::Number(n, _)))) => {
+                    Ok(n.as_str())
+                }
+                _ => Err(ErrorCode::SyntaxException(format!(
+                    "TABLESAMPLE arguments must be numbers, but got {}",
+                    arg
+                ))),
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let (percent, seed) = match numbers.as_slice() {
+            [percent] => (percent, None),
+            [percent, seed] => (percent, Some(seed)),
+            _ => {
+                return Err(ErrorCode::SyntaxException(
+                    "TABLESAMPLE must be TABLESAMPLE (n PERCENT) [REPEATABLE (seed)]",
+                ))
+            }
+        };
+
+        let percent = match percent.parse::<f64>() {
+            Ok(percent) if (0.0..=100.0).contains(&percent) => percent,
+            _ => {
+                return Err(ErrorCode::BadArguments(format!(
+                    "TABLESAMPLE percentage must be between 0 and 100, but got {}",
+                    percent
+                )))
+            }
+        };
+
+        let seed = match seed {
+            None => None,
+            Some(seed) => Some(seed.parse::<u64>().map_err(|_| {
+                ErrorCode::BadArguments(format!(
+                    "TABLESAMPLE REPEATABLE seed must be an unsigned integer, but got {}",
+                    seed
+                ))
+            })?),
+        };
+
+        Ok(Some(TableSample { percent, seed }))
+    }
 }
@@ -27,6 +27,7 @@ use common_tracing::tracing;
 use sqlparser::ast::ColumnDef;
 use sqlparser::ast::ColumnOption;
 use sqlparser::ast::ObjectName;
+use sqlparser::tokenizer::Token;
 
 use super::analyzer_expr::ExpressionAnalyzer;
 use crate::sessions::QueryContext;
@@ -57,6 +58,12 @@ pub struct DfCreateTable {
 impl AnalyzableStatement for DfCreateTable {
     #[tracing::instrument(level = "debug", skip(self, ctx), fields(ctx.id = ctx.get_id().as_str()))]
     async fn analyze(&self, ctx: Arc<QueryContext>) -> Result<AnalyzedResult> {
+        if self.query.is_some() && !self.columns.is_empty() {
+            return Err(ErrorCode::SemanticError(
+                "Create table as select can not define columns, they are derived from the select",
+            ));
+        }
+
         let mut table_meta = self.table_meta(ctx.clone()).await?;
         let if_not_exists = self.if_not_exists;
         let tenant = ctx.get_tenant();
@@ -68,7 +75,7 @@ impl AnalyzableStatement for DfCreateTable {
                 let statements = vec![DfStatement::Query(query_statement.clone())];
                 let select_plan = PlanParser::build_plan(statements, ctx).await?;
 
-                // The schema contains two parts: create table (if specified) and select.
+                // The schema contains two parts: create table like (if specified) and select.
                 let mut fields = table_meta.schema.fields().to_vec();
                 let fields_map = fields
                     .iter()
@@ -115,10 +122,16 @@ impl DfCreateTable {
     async fn table_meta(&self, ctx: Arc<QueryContext>) -> Result<TableMeta> {
         let engine = self.engine.clone();
         let schema = self.table_schema(ctx).await?;
+        // Table option names are case-insensitive.
+        let options = self
+            .options
+            .iter()
+            .map(|(name, value)| (name.to_uppercase(), value.clone()))
+            .collect();
         Ok(TableMeta {
             schema,
             engine,
-            options: self.options.clone(),
+            options,
             ..Default::default()
         })
     }
@@ -143,6 +156,7 @@ impl DfCreateTable {
                 for column in &self.columns {
                     let mut nullable = true;
                     let mut default_expr = None;
+                    let mut comment = None;
                     for opt in &column.options {
                         match &opt.option {
                             ColumnOption::NotNull => {
@@ -152,6 +166,15 @@ impl DfCreateTable {
                                 let expr = expr_analyzer.analyze(expr).await?;
                                 default_expr = Some(serde_json::to_vec(&expr)?);
                             }
+                            ColumnOption::DialectSpecific(tokens) => {
+                                if let [Token::Word(word), Token::SingleQuotedString(value)] =
+                                    tokens.as_slice()
+                                {
+                                    if word.value.eq_ignore_ascii_case("COMMENT") {
+                                        comment = Some(value.clone());
+                                    }
+                                }
+                            }
                             _ => {}
                         }
                     }
@@ -159,9 +182,11 @@ impl DfCreateTable {
                         if nullable {
                             DataField::new_nullable(&column.name.value, data_type)
                                 .with_default_expr(default_expr)
+                                .with_comment(comment)
                         } else {
                             DataField::new(&column.name.value, data_type)
                                 .with_default_expr(default_expr)
+                                .with_comment(comment)
                         }
                     })?;
                     fields.push(field);
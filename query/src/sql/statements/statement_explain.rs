@@ -16,12 +16,15 @@ use std::sync::Arc;
 
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_planners::ExplainPlan;
 use common_planners::ExplainType;
+use common_planners::PlanNode;
 use common_tracing::tracing;
 
 use crate::sessions::QueryContext;
 use crate::sql::statements::AnalyzableStatement;
 use crate::sql::statements::AnalyzedResult;
+use crate::sql::statements::DfInsertStatement;
 use crate::sql::statements::DfQueryStatement;
 use crate::sql::statements::QueryAnalyzeState;
 use crate::sql::DfStatement;
@@ -45,7 +48,10 @@ impl AnalyzableStatement for DfExplain {
                     explain_query_state,
                 )))
             }
-            _ => Err(ErrorCode::SyntaxException("Only support EXPLAIN SELECT")),
+            DfStatement::InsertQuery(v) => Self::analyze_explain_insert(ctx, self.typ, v).await,
+            _ => Err(ErrorCode::SyntaxException(
+                "Only support EXPLAIN SELECT and EXPLAIN INSERT",
+            )),
         }
     }
 }
@@ -62,4 +68,31 @@ impl DfExplain {
             )),
         }
     }
+
+    // The insert is only planned, so the explain reports the target and the source
+    // without reading any of the inserted data.
+    async fn analyze_explain_insert(
+        ctx: Arc<QueryContext>,
+        typ: ExplainType,
+        v: &DfInsertStatement,
+    ) -> Result<AnalyzedResult> {
+        if typ != ExplainType::Syntax {
+            return Err(ErrorCode::SyntaxException(format!(
+                "EXPLAIN {:?} is not supported for INSERT",
+                typ
+            )));
+        }
+
+        match v.analyze(ctx).await? {
+            AnalyzedResult::SimpleQuery(plan) => Ok(AnalyzedResult::SimpleQuery(Box::new(
+                PlanNode::Explain(ExplainPlan {
+                    typ,
+                    input: Arc::new(*plan),
+                }),
+            ))),
+            _ => Err(ErrorCode::LogicalError(
+                "Logical error: analyze insert must be return simple query analyze result.",
+            )),
+        }
+    }
 }
@@ -12,16 +12,27 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
 use std::sync::Arc;
 
+use common_datavalues2::remove_nullable;
+use common_datavalues2::DataField;
 use common_datavalues2::DataSchemaRef;
 use common_datavalues2::DataSchemaRefExt;
+use common_datavalues2::DataValue;
+use common_datavalues2::ToDataType;
+use common_datavalues2::TypeID;
+use common_datavalues2::Vu8;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_functions::scalars::cast_with_type;
+use common_functions::scalars::DEFAULT_CAST_OPTIONS;
 use common_planners::Expression;
+use common_planners::ExpressionVisitor;
 use common_planners::InsertInputSource;
 use common_planners::InsertPlan;
 use common_planners::PlanNode;
+use common_planners::Recursion;
 use common_tracing::tracing;
 use sqlparser::ast::Expr;
 use sqlparser::ast::Ident;
@@ -62,6 +73,8 @@ pub struct DfInsertStatement {
     pub table: bool,
     /// on duplicate key update
     pub on: Option<OnInsert>,
+    /// ClickHouse style `SETTINGS name = value, ...`, overrides the settings for this query only.
+    pub settings: HashMap<String, String>,
 }
 
 #[async_trait::async_trait]
@@ -69,17 +82,21 @@ impl AnalyzableStatement for DfInsertStatement {
     #[tracing::instrument(level = "debug", skip(self, ctx), fields(ctx.id = ctx.get_id().as_str()))]
     async fn analyze(&self, ctx: Arc<QueryContext>) -> Result<AnalyzedResult> {
         self.is_supported()?;
+        ctx.apply_query_settings(&self.settings)?;
 
         let (database_name, table_name) = self.resolve_table(&ctx)?;
         let write_table = ctx.get_table(&database_name, &table_name).await?;
         let table_id = write_table.get_id();
-        let schema = self.insert_schema(write_table)?;
+        let schema = self.insert_schema(write_table.clone())?;
 
         let input_source = match &self.source {
             None => self.analyze_insert_without_source().await,
             Some(source) => match &source.body {
                 SetExpr::Values(v) => self.analyze_insert_values(ctx.clone(), v, &schema).await,
-                SetExpr::Select(_) => self.analyze_insert_select(ctx.clone(), source).await,
+                SetExpr::Select(_) => {
+                    self.analyze_insert_select(ctx.clone(), source, &schema, &write_table.schema())
+                        .await
+                }
                 _ => Err(ErrorCode::SyntaxException(
                     "Insert must be have values or select source.",
                 )),
@@ -143,23 +160,24 @@ impl DfInsertStatement {
 
         let expression_analyzer = ExpressionAnalyzer::create(ctx);
         let mut value_exprs = Vec::with_capacity(values.0.len());
-        for value in &values.0 {
+        for (row, value) in values.0.iter().enumerate() {
+            if value.len() != schema.fields().len() {
+                return Err(ErrorCode::BadArguments(format!(
+                    "Insert into {} columns, but the row {} of values has {} columns",
+                    schema.fields().len(),
+                    row + 1,
+                    value.len()
+                )));
+            }
+
             let mut exprs = Vec::with_capacity(value.len());
-            for (i, v) in value.iter().enumerate() {
+            for (v, field) in value.iter().zip(schema.fields()) {
                 let expr = expression_analyzer.analyze(v).await?;
-                let expr = if &expr.to_data_type(schema)? != schema.field(i).data_type() {
-                    Expression::Cast {
-                        expr: Box::new(expr),
-                        data_type: schema.field(i).data_type().clone(),
-                        is_nullable: schema.field(i).is_nullable(),
-                    }
-                } else {
-                    expr
-                };
-                exprs.push(Expression::Alias(
-                    schema.field(i).name().to_string(),
-                    Box::new(expr),
-                ));
+                Self::check_constant_expr(&expr, field)?;
+                Self::check_value_range(&expr, field)?;
+                Self::check_date_value(&expr, field)?;
+                let expr = Self::cast_value_expr(expr, field, schema)?;
+                exprs.push(Expression::Alias(field.name().to_string(), Box::new(expr)));
             }
             value_exprs.push(exprs);
         }
@@ -167,6 +185,165 @@ impl DfInsertStatement {
         Ok(InsertInputSource::Expressions(value_exprs))
     }
 
+    /// The values are evaluated without any input columns, so they must be literals or
+    /// expressions which can be folded into a constant, e.g. `1 + 2` or `now()`.
+    fn check_constant_expr(expr: &Expression, field: &DataField) -> Result<()> {
+        struct ConstantExpressionChecker<'a>(&'a DataField);
+
+        impl<'a> ExpressionVisitor for ConstantExpressionChecker<'a> {
+            fn pre_visit(self, expr: &Expression) -> Result<Recursion<Self>> {
+                match expr {
+                    Expression::Column(_)
+                    | Expression::QualifiedColumn(_)
+                    | Expression::Wildcard
+                    | Expression::AggregateFunction { .. }
+                    | Expression::Subquery { .. }
+                    | Expression::ScalarSubquery { .. } => Err(ErrorCode::BadArguments(format!(
+                        "Insert value of column {} must be a constant expression, but got: {:?}",
+                        self.0.name(),
+                        expr
+                    ))),
+                    _ => Ok(Recursion::Continue(self)),
+                }
+            }
+        }
+
+        expr.accept(ConstantExpressionChecker(field))?;
+        Ok(())
+    }
+
+    /// The numeric casts wrap around on overflow, so the number literals are checked
+    /// against the range of the integer columns before they are casted.
+    fn check_value_range(expr: &Expression, field: &DataField) -> Result<()> {
+        let (negative, value) = match expr {
+            Expression::Literal { value, .. } => (false, value),
+            Expression::UnaryExpression { op, expr } if op.eq_ignore_ascii_case("negate") => {
+                match expr.as_ref() {
+                    Expression::Literal { value, .. } => (true, value),
+                    _ => return Ok(()),
+                }
+            }
+            _ => return Ok(()),
+        };
+
+        let data_type = remove_nullable(field.data_type());
+        let (min, max) = match data_type.data_type_id() {
+            TypeID::UInt8 => (0, u8::MAX as i128),
+            TypeID::UInt16 => (0, u16::MAX as i128),
+            TypeID::UInt32 => (0, u32::MAX as i128),
+            TypeID::UInt64 => (0, u64::MAX as i128),
+            TypeID::Int8 => (i8::MIN as i128, i8::MAX as i128),
+            TypeID::Int16 => (i16::MIN as i128, i16::MAX as i128),
+            TypeID::Int32 => (i32::MIN as i128, i32::MAX as i128),
+            TypeID::Int64 => (i64::MIN as i128, i64::MAX as i128),
+            _ => return Ok(()),
+        };
+
+        let in_range = match value {
+            DataValue::Int64(v) => (min..=max).contains(&Self::signed(*v as i128, negative)),
+            DataValue::UInt64(v) => (min..=max).contains(&Self::signed(*v as i128, negative)),
+            DataValue::Float64(v) => {
+                let v = if negative { -*v } else { *v };
+                v >= min as f64 && v <= max as f64
+            }
+            _ => true,
+        };
+
+        match in_range {
+            true => Ok(()),
+            false => Err(ErrorCode::BadDataValueType(format!(
+                "Value {}{:?} is out of range of column {} with type {}",
+                if negative { "-" } else { "" },
+                value,
+                field.name(),
+                data_type.name()
+            ))),
+        }
+    }
+
+    /// The strings which are not valid dates are casted into NULL, so the string literals
+    /// are parsed before they are casted into the date columns.
+    fn check_date_value(expr: &Expression, field: &DataField) -> Result<()> {
+        let data_type = remove_nullable(field.data_type());
+        let value = match (expr, data_type.data_type_id()) {
+            (
+                Expression::Literal {
+                    value: value @ DataValue::String(_),
+                    ..
+                },
+                TypeID::Date16 | TypeID::Date32 | TypeID::DateTime32 | TypeID::DateTime64,
+            ) => value,
+            _ => return Ok(()),
+        };
+
+        let string_type = Vu8::to_data_type();
+        let column = string_type.create_constant_column(value, 1)?;
+        match cast_with_type(&column, &string_type, &data_type, &DEFAULT_CAST_OPTIONS) {
+            Ok(_) => Ok(()),
+            Err(_) => Err(ErrorCode::BadDataValueType(format!(
+                "Value {} is not a valid {} of column {}",
+                value,
+                data_type.name(),
+                field.name()
+            ))),
+        }
+    }
+
+    fn signed(v: i128, negative: bool) -> i128 {
+        match negative {
+            true => -v,
+            false => v,
+        }
+    }
+
+    /// Cast the value into the type of the target column. The values which can't be
+    /// represented by the column type are errors, even if the column is nullable.
+    /// The string expressions which are not valid dates are only caught when the
+    /// values are evaluated, see `SendableWithSchema`.
+    fn cast_value_expr(
+        expr: Expression,
+        field: &DataField,
+        schema: &DataSchemaRef,
+    ) -> Result<Expression> {
+        let data_type = field.data_type();
+        let expr_type = expr.to_data_type(schema)?;
+        if &expr_type == data_type {
+            return Ok(expr);
+        }
+
+        if expr_type.data_type_id() == TypeID::Null {
+            if !field.is_nullable() {
+                return Err(ErrorCode::BadArguments(format!(
+                    "Can not insert NULL into non-nullable column {}",
+                    field.name()
+                )));
+            }
+        } else if field.is_nullable() && !expr_type.is_nullable() {
+            // The nullable cast turns the invalid values into NULL, cast into the inner type first.
+            let inner_type = remove_nullable(data_type);
+            let expr = match expr_type == inner_type {
+                true => expr,
+                false => Expression::Cast {
+                    expr: Box::new(expr),
+                    data_type: inner_type,
+                    is_nullable: false,
+                },
+            };
+
+            return Ok(Expression::Cast {
+                expr: Box::new(expr),
+                data_type: data_type.clone(),
+                is_nullable: true,
+            });
+        }
+
+        Ok(Expression::Cast {
+            expr: Box::new(expr),
+            data_type: data_type.clone(),
+            is_nullable: field.is_nullable(),
+        })
+    }
+
     async fn analyze_insert_without_source(&self) -> Result<InsertInputSource> {
         let format = self.format.as_ref().ok_or_else(|| {
             ErrorCode::SyntaxException("FORMAT must be specified in streaming insertion")
@@ -178,13 +355,60 @@ impl DfInsertStatement {
         &self,
         ctx: Arc<QueryContext>,
         source: &Query,
+        schema: &DataSchemaRef,
+        table_schema: &DataSchemaRef,
     ) -> Result<InsertInputSource> {
         let statement = DfQueryStatement::try_from(source.clone())?;
         let select_plan =
             PlanParser::build_plan(vec![DfStatement::Query(Box::new(statement))], ctx).await?;
+
+        Self::check_select_schema(&select_plan.schema(), schema)?;
+        Self::check_missing_columns(schema, table_schema)?;
         Ok(InsertInputSource::SelectPlan(Box::new(select_plan)))
     }
 
+    /// The select output is matched to the insert columns by position, each column
+    /// will be casted into the type of the target column when the insert is executed.
+    fn check_select_schema(select_schema: &DataSchemaRef, schema: &DataSchemaRef) -> Result<()> {
+        if select_schema.fields().len() != schema.fields().len() {
+            return Err(ErrorCode::BadArguments(format!(
+                "Insert into {} columns, but the select statement returns {} columns",
+                schema.fields().len(),
+                select_schema.fields().len()
+            )));
+        }
+
+        for (select_field, field) in select_schema.fields().iter().zip(schema.fields()) {
+            let from_type = select_field.data_type();
+            if from_type.data_type_id() == TypeID::Null && !field.is_nullable() {
+                return Err(ErrorCode::BadArguments(format!(
+                    "Can not cast column {} from NULL into non-nullable column {}",
+                    select_field.name(),
+                    field.name()
+                )));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Columns not mentioned in the insert are filled with NULL or their default expression.
+    fn check_missing_columns(schema: &DataSchemaRef, table_schema: &DataSchemaRef) -> Result<()> {
+        for field in table_schema.fields() {
+            if !schema.has_field(field.name())
+                && !field.is_nullable()
+                && field.default_expr().is_none()
+            {
+                return Err(ErrorCode::BadArguments(format!(
+                    "Column {} is non-nullable and has no default value, it must be specified in insert",
+                    field.name()
+                )));
+            }
+        }
+
+        Ok(())
+    }
+
     fn insert_schema(&self, read_table: Arc<dyn Table>) -> Result<DataSchemaRef> {
         match self.columns.is_empty() {
             true => Ok(read_table.schema()),
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
 use std::sync::Arc;
 
 use common_datablocks::DataBlock;
@@ -46,24 +47,35 @@ use crate::storages::ToReadDataSourcePlan;
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct DfQueryStatement {
+    pub distinct: bool,
     pub from: Vec<TableWithJoins>,
     pub projection: Vec<SelectItem>,
     pub selection: Option<Expr>,
     pub group_by: Vec<Expr>,
+    /// `GROUP BY ROLLUP(expr, ...)`, the expressions are kept in `group_by`.
+    pub group_by_rollup: bool,
     pub having: Option<Expr>,
     pub order_by: Vec<OrderByExpr>,
+    /// ClickHouse style `LIMIT n BY expr, ...`, keeps at most n rows for each distinct key.
+    pub limit_by_count: Option<Expr>,
+    pub limit_by: Vec<Expr>,
     pub limit: Option<Expr>,
     pub offset: Option<Offset>,
+    /// ClickHouse style `SETTINGS name = value, ...`, overrides the settings for this query only.
+    pub settings: HashMap<String, String>,
 }
 
 #[async_trait::async_trait]
 impl AnalyzableStatement for DfQueryStatement {
     #[tracing::instrument(level = "debug", skip(self, ctx), fields(ctx.id = ctx.get_id().as_str()))]
     async fn analyze(&self, ctx: Arc<QueryContext>) -> Result<AnalyzedResult> {
+        ctx.apply_query_settings(&self.settings)?;
+
         let analyzer = JoinedSchemaAnalyzer::create(ctx.clone());
         let mut joined_schema = analyzer.analyze(self).await?;
 
-        let mut ir = QueryNormalizer::normalize(ctx.clone(), self).await?;
+        let mut ir =
+            QueryNormalizer::normalize_with_schema(ctx.clone(), self, &joined_schema).await?;
 
         QualifiedRewriter::rewrite(&joined_schema, ctx.clone(), &mut ir)?;
 
@@ -80,11 +92,21 @@ impl DfQueryStatement {
         let limit = ir.limit;
         let offset = ir.offset;
         let mut analyze_state = QueryAnalyzeState {
+            distinct: self.distinct,
+            group_by_rollup: self.group_by_rollup,
+            limit_by_count: ir.limit_by_count,
             limit,
             offset,
             ..Default::default()
         };
 
+        // Wildcard is already expanded here, so `SELECT * FROM t` over a zero-column t is empty.
+        if ir.projection_expressions.is_empty() {
+            return Err(ErrorCode::SyntaxException(
+                "SELECT list is empty after wildcard expansion",
+            ));
+        }
+
         if let Some(predicate) = &ir.filter_predicate {
             Self::verify_no_aggregate(predicate, "filter")?;
             analyze_state.filter = Some(predicate.clone());
@@ -103,6 +125,7 @@ impl DfQueryStatement {
                     expr,
                     asc,
                     nulls_first,
+                    case_insensitive,
                     origin_expr,
                 } => {
                     analyze_state.add_expression(expr);
@@ -110,6 +133,7 @@ impl DfQueryStatement {
                         expr: Box::new(rebase_expr(expr, &analyze_state.expressions)?),
                         asc: *asc,
                         nulls_first: *nulls_first,
+                        case_insensitive: *case_insensitive,
                         origin_expr: Box::new(rebase_expr(
                             origin_expr,
                             &analyze_state.expressions,
@@ -124,6 +148,12 @@ impl DfQueryStatement {
             }
         }
 
+        for limit_by_expression in &ir.limit_by_expressions {
+            analyze_state.add_expression(limit_by_expression);
+            let expression = rebase_expr(limit_by_expression, &analyze_state.expressions)?;
+            analyze_state.limit_by_expressions.push(expression);
+        }
+
         if !ir.aggregate_expressions.is_empty() || !ir.group_by_expressions.is_empty() {
             // Rebase expressions using aggregate expressions and group by expressions
             let mut expressions = Vec::with_capacity(analyze_state.expressions.len());
@@ -145,9 +175,40 @@ impl DfQueryStatement {
             Self::analyze_aggregate(&ir.aggregate_expressions, &mut analyze_state)?;
         }
 
+        if analyze_state.distinct {
+            Self::verify_distinct_keys(&analyze_state)?;
+        }
+
         Ok(analyze_state)
     }
 
+    // The rows are deduplicated on the projection before sorting,
+    // so ORDER BY and LIMIT BY can only reference the projected columns.
+    fn verify_distinct_keys(state: &QueryAnalyzeState) -> Result<()> {
+        let distinct_keys = state.distinct_expressions();
+        for order_by_expression in &state.order_by_expressions {
+            if let Expression::Sort { expr, .. } = order_by_expression {
+                if !distinct_keys.contains(expr.as_ref()) {
+                    return Err(ErrorCode::SyntaxException(format!(
+                        "For SELECT DISTINCT, ORDER BY expression {} must appear in select list",
+                        expr.column_name()
+                    )));
+                }
+            }
+        }
+
+        for limit_by_expression in &state.limit_by_expressions {
+            if !distinct_keys.contains(limit_by_expression) {
+                return Err(ErrorCode::SyntaxException(format!(
+                    "For SELECT DISTINCT, LIMIT BY expression {} must appear in select list",
+                    limit_by_expression.column_name()
+                )));
+            }
+        }
+
+        Ok(())
+    }
+
     fn analyze_aggregate(exprs: &[Expression], state: &mut QueryAnalyzeState) -> Result<()> {
         let aggregate_functions = find_aggregate_exprs(exprs);
         let aggregate_functions_args = expand_aggregate_arg_exprs(&aggregate_functions);
@@ -294,6 +355,12 @@ impl DfQueryStatement {
             }
         }
 
+        if !state.limit_by_expressions.is_empty() {
+            if let Err(cause) = Self::dry_run_exprs(&state.limit_by_expressions, &data_block) {
+                return Err(cause.add_message_back(" (while in select limit by)"));
+            }
+        }
+
         if !state.projection_expressions.is_empty() {
             match Self::dry_run_exprs(&state.projection_expressions, &data_block) {
                 Ok(res) => {
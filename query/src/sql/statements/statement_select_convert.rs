@@ -12,8 +12,14 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
 use std::convert::TryFrom;
 
+use sqlparser::ast::Expr;
+use sqlparser::ast::FunctionArg;
+use sqlparser::ast::FunctionArgExpr;
+use sqlparser::ast::Offset;
+use sqlparser::ast::OrderByExpr;
 use sqlparser::ast::Query;
 use sqlparser::ast::Select;
 use sqlparser::ast::SetExpr;
@@ -26,7 +32,18 @@ impl TryFrom<Query> for DfQueryStatement {
 
     fn try_from(query: Query) -> Result<Self, Self::Error> {
         let query_body = Self::get_body(&query)?;
+        Self::check_query(&query)?;
+        Self::try_from_select(
+            query_body,
+            query.order_by.clone(),
+            query.limit.clone(),
+            query.offset.clone(),
+        )
+    }
+}
 
+impl DfQueryStatement {
+    pub(crate) fn check_query(query: &Query) -> Result<(), ParserError> {
         if query.with.is_some() {
             return Err(ParserError::ParserError(String::from(
                 "CTE is not yet implement",
@@ -39,6 +56,16 @@ impl TryFrom<Query> for DfQueryStatement {
             )));
         }
 
+        Ok(())
+    }
+
+    /// Convert a single SELECT, `order_by`, `limit` and `offset` belong to the enclosing query.
+    pub fn try_from_select(
+        query_body: &Select,
+        order_by: Vec<OrderByExpr>,
+        limit: Option<Expr>,
+        offset: Option<Offset>,
+    ) -> Result<Self, ParserError> {
         if query_body.top.is_some() {
             return Err(ParserError::ParserError(String::from(
                 "TOP is not yet implement",
@@ -63,20 +90,62 @@ impl TryFrom<Query> for DfQueryStatement {
             )));
         }
 
+        let (group_by, group_by_rollup) = Self::unwrap_rollup(&query_body.group_by)?;
+
         Ok(DfQueryStatement {
+            distinct: query_body.distinct,
             from: query_body.from.clone(),
             projection: query_body.projection.clone(),
             selection: query_body.selection.clone(),
-            group_by: query_body.group_by.clone(),
+            group_by,
+            group_by_rollup,
             having: query_body.having.clone(),
-            order_by: query.order_by.clone(),
-            limit: query.limit.clone(),
-            offset: query.offset.clone(),
+            order_by,
+            limit_by_count: None,
+            limit_by: vec![],
+            limit,
+            offset,
+            settings: HashMap::new(),
         })
     }
-}
 
-impl DfQueryStatement {
+    // `GROUP BY ROLLUP(a, b)` is parsed as a call of the function rollup.
+    fn unwrap_rollup(group_by: &[Expr]) -> Result<(Vec<Expr>, bool), ParserError> {
+        fn is_rollup(expr: &Expr) -> bool {
+            matches!(expr, Expr::Function(f) if f.name.to_string().eq_ignore_ascii_case("rollup"))
+        }
+
+        match group_by {
+            [Expr::Function(rollup)] if is_rollup(&group_by[0]) => {
+                if rollup.args.is_empty() {
+                    return Err(ParserError::ParserError(String::from(
+                        "ROLLUP requires at least one expression",
+                    )));
+                }
+
+                let mut exprs = Vec::with_capacity(rollup.args.len());
+                for arg in &rollup.args {
+                    match arg {
+                        FunctionArg::Unnamed(FunctionArgExpr::Expr(expr)) => {
+                            exprs.push(expr.clone())
+                        }
+                        other => {
+                            return Err(ParserError::ParserError(format!(
+                                "ROLLUP argument {} must be an expression",
+                                other
+                            )))
+                        }
+                    }
+                }
+                Ok((exprs, true))
+            }
+            _ if group_by.iter().any(is_rollup) => Err(ParserError::ParserError(String::from(
+                "ROLLUP must be the only item of GROUP BY",
+            ))),
+            _ => Ok((group_by.to_vec(), false)),
+        }
+    }
+
     fn get_body(query: &Query) -> Result<&Select, ParserError> {
         match &query.body {
             SetExpr::Select(query) => Ok(query),
@@ -14,12 +14,15 @@
 
 use std::sync::Arc;
 
+use common_exception::ErrorCode;
 use common_exception::Result;
 use common_planners::PlanNode;
 use common_planners::PlanShowKind;
 use common_planners::ShowPlan;
 use common_planners::ShowTablesPlan;
 use common_tracing::tracing;
+use sqlparser::ast::Expr;
+use sqlparser::ast::Ident;
 
 use crate::sessions::QueryContext;
 use crate::sql::statements::AnalyzableStatement;
@@ -35,6 +38,40 @@ impl DfShowTables {
     pub fn create(kind: DfShowKind) -> DfShowTables {
         DfShowTables { kind }
     }
+
+    // The pattern is a string literal in the query, so the quotes must be escaped.
+    pub(crate) fn like_pattern(pattern: &Ident) -> String {
+        format!("'{}'", pattern.value.replace('\'', "''"))
+    }
+
+    // The predicate is pushed into the query of system.tables, subqueries are not allowed in it.
+    pub(crate) fn check_where_expr(expr: &Expr) -> Result<()> {
+        match expr {
+            Expr::Subquery(_) | Expr::Exists(_) | Expr::InSubquery { .. } => Err(
+                ErrorCode::SyntaxException(format!("Unsupported subquery in show where: {}", expr)),
+            ),
+            Expr::BinaryOp { left, right, .. } => {
+                Self::check_where_expr(left)?;
+                Self::check_where_expr(right)
+            }
+            Expr::UnaryOp { expr, .. }
+            | Expr::Nested(expr)
+            | Expr::IsNull(expr)
+            | Expr::IsNotNull(expr) => Self::check_where_expr(expr),
+            Expr::InList { expr, list, .. } => {
+                Self::check_where_expr(expr)?;
+                list.iter().try_for_each(Self::check_where_expr)
+            }
+            Expr::Between {
+                expr, low, high, ..
+            } => {
+                Self::check_where_expr(expr)?;
+                Self::check_where_expr(low)?;
+                Self::check_where_expr(high)
+            }
+            _ => Ok(()),
+        }
+    }
 }
 
 #[async_trait::async_trait]
@@ -45,9 +82,10 @@ impl AnalyzableStatement for DfShowTables {
         match &self.kind {
             DfShowKind::All => {}
             DfShowKind::Like(v) => {
-                kind = PlanShowKind::Like(format!("{}", v));
+                kind = PlanShowKind::Like(Self::like_pattern(v));
             }
             DfShowKind::Where(v) => {
+                Self::check_where_expr(v)?;
                 kind = PlanShowKind::Where(format!("{}", v));
             }
             DfShowKind::FromOrIn(v) => {
@@ -0,0 +1,177 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::convert::TryFrom;
+use std::sync::Arc;
+
+use common_datavalues2::DataSchemaRef;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_planners::sort;
+use common_planners::Expression;
+use common_planners::PlanBuilder;
+use common_planners::PlanNode;
+use common_tracing::tracing;
+use sqlparser::ast::Expr;
+use sqlparser::ast::OrderByExpr;
+use sqlparser::ast::Query;
+use sqlparser::ast::SetExpr;
+use sqlparser::ast::SetOperator;
+use sqlparser::ast::Value;
+use sqlparser::parser::ParserError;
+
+use crate::sessions::QueryContext;
+use crate::sql::statements::AnalyzableStatement;
+use crate::sql::statements::AnalyzedResult;
+use crate::sql::statements::DfQueryStatement;
+use crate::sql::PlanParser;
+
+/// `SELECT ... UNION ALL SELECT ...`, the inputs are flattened from left to right.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DfUnionAllStatement {
+    pub inputs: Vec<DfQueryStatement>,
+    /// ORDER BY over the whole union, by output column name or position.
+    pub order_by: Vec<OrderByExpr>,
+}
+
+#[async_trait::async_trait]
+impl AnalyzableStatement for DfUnionAllStatement {
+    #[tracing::instrument(level = "debug", skip(self, ctx), fields(ctx.id = ctx.get_id().as_str()))]
+    async fn analyze(&self, ctx: Arc<QueryContext>) -> Result<AnalyzedResult> {
+        let mut inputs = Vec::with_capacity(self.inputs.len());
+        for input in &self.inputs {
+            match input.analyze(ctx.clone()).await? {
+                AnalyzedResult::SelectQuery(state) => match PlanParser::build_query_plan(&state)? {
+                    PlanNode::Select(plan) => inputs.push(plan.input.as_ref().clone()),
+                    plan => inputs.push(plan),
+                },
+                _ => {
+                    return Err(ErrorCode::LogicalError(
+                        "Logical error: UNION ALL input must be a select query",
+                    ))
+                }
+            }
+        }
+
+        let mut builder = PlanBuilder::from(&inputs[0]).union_all(&inputs[1..])?;
+        if !self.order_by.is_empty() {
+            let order_by = self.order_by_expressions(builder.build()?.schema())?;
+            builder = builder.sort(&order_by)?;
+        }
+
+        let plan = builder.select()?.build()?;
+        Ok(AnalyzedResult::SimpleQuery(Box::new(plan)))
+    }
+}
+
+impl TryFrom<Query> for DfUnionAllStatement {
+    type Error = ParserError;
+
+    fn try_from(query: Query) -> std::result::Result<Self, Self::Error> {
+        DfQueryStatement::check_query(&query)?;
+
+        if query.limit.is_some() || query.offset.is_some() {
+            return Err(ParserError::ParserError(String::from(
+                "LIMIT and OFFSET over UNION ALL is not yet implemented",
+            )));
+        }
+
+        let mut inputs = vec![];
+        Self::collect_inputs(&query.body, &mut inputs)?;
+        Ok(DfUnionAllStatement {
+            inputs,
+            order_by: query.order_by,
+        })
+    }
+}
+
+impl DfUnionAllStatement {
+    fn order_by_expressions(&self, schema: DataSchemaRef) -> Result<Vec<Expression>> {
+        let mut expressions = Vec::with_capacity(self.order_by.len());
+        for order_by_expr in &self.order_by {
+            let field = match &order_by_expr.expr {
+                Expr::Identifier(ident) => schema.field_with_name(&ident.value)?,
+                Expr::Value(Value::Number(position, _)) => match position.parse::<usize>() {
+                    Ok(position) if position >= 1 && position <= schema.fields().len() => {
+                        schema.field(position - 1)
+                    }
+                    _ => {
+                        return Err(ErrorCode::SyntaxException(format!(
+                            "ORDER BY position {} is not in select list",
+                            position
+                        )))
+                    }
+                },
+                other => {
+                    return Err(ErrorCode::SyntaxException(format!(
+                        "ORDER BY over UNION ALL only supports output column names and positions, but got {}",
+                        other
+                    )))
+                }
+            };
+
+            let asc = order_by_expr.asc.unwrap_or(true);
+            let nulls_first = order_by_expr.nulls_first.unwrap_or(asc);
+            expressions.push(sort(field.name(), asc, nulls_first));
+        }
+
+        Ok(expressions)
+    }
+
+    fn collect_inputs(
+        body: &SetExpr,
+        inputs: &mut Vec<DfQueryStatement>,
+    ) -> std::result::Result<(), ParserError> {
+        match body {
+            SetExpr::SetOperation {
+                op: SetOperator::Union,
+                all: true,
+                left,
+                right,
+            } => {
+                Self::collect_inputs(left, inputs)?;
+                Self::collect_inputs(right, inputs)
+            }
+            SetExpr::SetOperation { op, all, .. } => Err(ParserError::ParserError(format!(
+                "{}{} is not yet implemented, only UNION ALL is supported",
+                op,
+                if *all { " ALL" } else { "" }
+            ))),
+            SetExpr::Select(select) => {
+                inputs.push(DfQueryStatement::try_from_select(
+                    select,
+                    vec![],
+                    None,
+                    None,
+                )?);
+                Ok(())
+            }
+            SetExpr::Query(query) => match &query.body {
+                SetExpr::SetOperation { .. } => {
+                    let nested = Self::try_from(query.as_ref().clone())?;
+                    inputs.extend(nested.inputs);
+                    Ok(())
+                }
+                _ => {
+                    inputs.push(DfQueryStatement::try_from(query.as_ref().clone())?);
+                    Ok(())
+                }
+            },
+            other => Err(ParserError::ParserError(format!(
+                "Query {} is not yet implemented",
+                other
+            ))),
+        }
+    }
+}
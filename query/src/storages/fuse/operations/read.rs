@@ -23,6 +23,7 @@ use common_tracing::tracing_futures::Instrument;
 use futures::StreamExt;
 
 use super::part_info::PartInfo;
+use crate::catalogs::Catalog;
 use crate::sessions::QueryContext;
 use crate::storages::fuse::io::BlockReader;
 use crate::storages::fuse::io::MetaReaders;
@@ -60,6 +61,8 @@ impl FuseTable {
             .flatten();
         let operator = ctx.get_storage_operator().await?;
         let table_schema = self.table_info.schema();
+        let table_id = self.table_info.ident.table_id;
+        let table_name = self.table_info.name.clone();
 
         let part_stream = futures::stream::iter(iter);
 
@@ -70,7 +73,20 @@ impl FuseTable {
                 let table_schema = table_schema.clone();
                 let projection = projection.clone();
                 let reader = MetaReaders::block_meta_reader(ctx.clone());
+                let ctx = ctx.clone();
+                let table_name = table_name.clone();
                 async move {
+                    // The files of a dropped table may be purged at any time.
+                    let table_dropped = || {
+                        ErrorCode::TableDropped(format!(
+                            "Table '{}' has been dropped during the scan",
+                            table_name
+                        ))
+                    };
+                    if ctx.get_catalog().is_table_dropped(table_id) {
+                        return Err(table_dropped());
+                    }
+
                     let part_info = PartInfo::decode(&part.name)?;
                     let part_location = part_info.location();
                     let part_len = part_info.length();
@@ -85,6 +101,9 @@ impl FuseTable {
                         reader,
                     );
                     block_reader.read().await.map_err(|e| {
+                        if ctx.get_catalog().is_table_dropped(table_id) {
+                            return table_dropped();
+                        }
                         ErrorCode::ParquetError(format!(
                             "fail to read block {}, {}",
                             part_location, e
@@ -34,10 +34,15 @@ use crate::sessions::QueryContext;
 use crate::storages::fuse::io::MetaReaders;
 use crate::storages::fuse::meta::TableSnapshot;
 use crate::storages::fuse::operations::AppendOperationLogEntry;
+use crate::storages::fuse::TBL_OPT_KEY_BLOCK_PER_SEGMENT;
+use crate::storages::fuse::TBL_OPT_KEY_ROW_PER_BLOCK;
 use crate::storages::fuse::TBL_OPT_KEY_SNAPSHOT_LOC;
 use crate::storages::StorageContext;
 use crate::storages::StorageDescription;
 use crate::storages::Table;
+use crate::storages::TableOptionDescription;
+use crate::storages::TableOptionType;
+use crate::storages::TableStatistics;
 
 pub struct FuseTable {
     pub(crate) table_info: TableInfo,
@@ -52,6 +57,16 @@ impl FuseTable {
         StorageDescription {
             engine_name: "FUSE".to_string(),
             comment: "FUSE Storage Engine".to_string(),
+            table_options: vec![
+                TableOptionDescription::optional(
+                    TBL_OPT_KEY_ROW_PER_BLOCK,
+                    TableOptionType::UInt64,
+                ),
+                TableOptionDescription::optional(
+                    TBL_OPT_KEY_BLOCK_PER_SEGMENT,
+                    TableOptionType::UInt64,
+                ),
+            ],
         }
     }
 }
@@ -132,6 +147,37 @@ impl Table for FuseTable {
     async fn optimize(&self, ctx: Arc<QueryContext>, keep_last_snapshot: bool) -> Result<()> {
         self.do_optimize(ctx, keep_last_snapshot).await
     }
+
+    async fn statistics(&self, ctx: Arc<QueryContext>) -> Result<Option<TableStatistics>> {
+        let num_fields = self.table_info.schema().fields().len();
+        let snapshot = self.read_table_snapshot(ctx.as_ref()).await?;
+        let stats = match snapshot {
+            None => TableStatistics {
+                column_bytes: vec![0; num_fields],
+                ..Default::default()
+            },
+            Some(snapshot) => {
+                let summary = &snapshot.summary;
+                // per-column sizes are only tracked uncompressed, the table size is on-disk
+                let column_bytes = (0..num_fields)
+                    .map(|idx| {
+                        summary
+                            .col_stats
+                            .get(&(idx as u32))
+                            .map(|col_stats| col_stats.in_memory_size)
+                            .unwrap_or(0)
+                    })
+                    .collect();
+                TableStatistics {
+                    num_rows: summary.row_count,
+                    data_bytes: summary.compressed_byte_size,
+                    num_blocks: summary.block_count,
+                    column_bytes,
+                }
+            }
+        };
+        Ok(Some(stats))
+    }
 }
 
 impl FuseTable {
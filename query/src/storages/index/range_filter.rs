@@ -108,7 +108,22 @@ pub fn build_verifiable_expr(
 
     let (exprs, op) = match expr {
         Expression::Literal { .. } => return expr.clone(),
-        Expression::ScalarFunction { op, args } => (args.clone(), op.clone()),
+        Expression::Between {
+            expr,
+            negated,
+            low,
+            high,
+        } => {
+            // Each bound is checked against the statistics on its own.
+            let low = low.as_ref().clone();
+            let high = high.as_ref().clone();
+            let bounds = match negated {
+                false => expr.gt_eq(low).and(expr.lt_eq(high)),
+                true => expr.lt(low).or(expr.gt(high)),
+            };
+            return build_verifiable_expr(&bounds, schema, stat_columns);
+        }
+        Expression::ScalarFunction { op, args, .. } => (args.clone(), op.clone()),
         Expression::BinaryExpression { left, op, right } => match op.to_lowercase().as_str() {
             "and" => {
                 let left = build_verifiable_expr(left, schema, stat_columns);
@@ -624,7 +639,7 @@ fn check_maybe_monotonic(expr: &Expression) -> Result<bool> {
         Expression::UnaryExpression { op, expr } => {
             get_maybe_monotonic(op, vec![expr.as_ref().clone()])
         }
-        Expression::ScalarFunction { op, args } => get_maybe_monotonic(op, args.clone()),
+        Expression::ScalarFunction { op, args, .. } => get_maybe_monotonic(op, args.clone()),
         Expression::Cast { expr, .. } => check_maybe_monotonic(expr),
         _ => Ok(false),
     }
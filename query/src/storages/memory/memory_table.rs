@@ -18,7 +18,9 @@ use std::collections::HashSet;
 use std::sync::Arc;
 
 use common_datablocks::DataBlock;
+use common_datablocks::InMemoryBlocks;
 use common_datavalues2::ColumnRef;
+use common_exception::ErrorCode;
 use common_exception::Result;
 use common_infallible::RwLock;
 use common_meta_types::TableInfo;
@@ -34,10 +36,11 @@ use crate::storages::memory::MemoryTableStream;
 use crate::storages::StorageContext;
 use crate::storages::StorageDescription;
 use crate::storages::Table;
+use crate::storages::TableStatistics;
 
 pub struct MemoryTable {
     table_info: TableInfo,
-    blocks: Arc<RwLock<Vec<DataBlock>>>,
+    blocks: Arc<RwLock<InMemoryBlocks>>,
 }
 
 impl MemoryTable {
@@ -48,7 +51,7 @@ impl MemoryTable {
             let x = in_mem_data.get(table_id);
             match x {
                 None => {
-                    let blocks = Arc::new(RwLock::new(vec![]));
+                    let blocks = Arc::new(RwLock::new(InMemoryBlocks::default()));
                     in_mem_data.insert(*table_id, blocks.clone());
                     blocks
                 }
@@ -64,6 +67,7 @@ impl MemoryTable {
         StorageDescription {
             engine_name: "MEMORY".to_string(),
             comment: "MEMORY Storage Engine".to_string(),
+            ..Default::default()
         }
     }
 }
@@ -87,7 +91,8 @@ impl Table for MemoryTable {
         ctx: Arc<QueryContext>,
         push_downs: Option<Extras>,
     ) -> Result<(Statistics, Partitions)> {
-        let blocks = self.blocks.read();
+        let data = self.blocks.read();
+        let blocks = &data.blocks;
 
         let statistics = match push_downs {
             Some(push_downs) => {
@@ -122,11 +127,19 @@ impl Table for MemoryTable {
             }
         };
 
+        // The block ranges and the generation of the parts pin the snapshot this read sees,
+        // blocks appended after planning are out of range.
         let parts = crate::table_functions::generate_block_parts(
             0,
             ctx.get_settings().get_max_threads()? as u64,
             blocks.len() as u64,
-        );
+        )
+        .into_iter()
+        .map(|mut part| {
+            part.version = data.generation;
+            part
+        })
+        .collect();
         Ok((statistics, parts))
     }
 
@@ -136,7 +149,16 @@ impl Table for MemoryTable {
         plan: &ReadDataSourcePlan,
     ) -> Result<SendableDataBlockStream> {
         let push_downs = &plan.push_downs;
-        let raw_blocks = self.blocks.read().clone();
+        let raw_blocks = {
+            let data = self.blocks.read();
+            if let Some(part) = plan.parts.iter().find(|p| p.version != data.generation) {
+                return Err(ErrorCode::TableVersionMismatched(format!(
+                    "Table '{}' was truncated or overwritten after the read was planned (snapshot {}, current {})",
+                    self.table_info.name, part.version, data.generation
+                )));
+            }
+            data.blocks.clone()
+        };
 
         let blocks = match push_downs {
             Some(push_downs) => match &push_downs.projection {
@@ -181,14 +203,12 @@ impl Table for MemoryTable {
         ctx.get_dal_context().inc_write_rows(written_rows);
         ctx.get_dal_context().inc_write_bytes(written_bytes);
 
+        let mut data = self.blocks.write();
         if overwrite {
-            let mut blocks = self.blocks.write();
-            blocks.clear();
-        }
-        let mut blocks = self.blocks.write();
-        for block in operations {
-            blocks.push(block);
+            data.generation += 1;
+            data.blocks.clear();
         }
+        data.blocks.extend(operations);
         Ok(())
     }
 
@@ -197,8 +217,30 @@ impl Table for MemoryTable {
         _ctx: Arc<QueryContext>,
         _truncate_plan: TruncateTablePlan,
     ) -> Result<()> {
-        let mut blocks = self.blocks.write();
-        blocks.clear();
+        let mut data = self.blocks.write();
+        data.generation += 1;
+        data.blocks.clear();
         Ok(())
     }
+
+    async fn statistics(&self, _ctx: Arc<QueryContext>) -> Result<Option<TableStatistics>> {
+        let data = self.blocks.read();
+        let blocks = &data.blocks;
+
+        let mut column_bytes = vec![0u64; self.table_info.schema().fields().len()];
+        for block in blocks.iter() {
+            for (idx, column) in block.columns().iter().enumerate() {
+                if let Some(bytes) = column_bytes.get_mut(idx) {
+                    *bytes += column.memory_size() as u64;
+                }
+            }
+        }
+
+        Ok(Some(TableStatistics {
+            num_rows: blocks.iter().map(|block| block.num_rows() as u64).sum(),
+            data_bytes: blocks.iter().map(|block| block.memory_size() as u64).sum(),
+            num_blocks: blocks.len() as u64,
+            column_bytes,
+        }))
+    }
 }
@@ -29,5 +29,9 @@ pub use storage_context::StorageContext;
 pub use storage_factory::StorageCreator;
 pub use storage_factory::StorageDescription;
 pub use storage_factory::StorageFactory;
+pub use storage_factory::TableOptionDescription;
+pub use storage_factory::TableOptionType;
+pub use storage_factory::TBL_OPT_KEY_COMMENT;
 pub use storage_table::Table;
+pub use storage_table::TableStatistics;
 pub use storage_table_read_plan::ToReadDataSourcePlan;
@@ -42,10 +42,98 @@ where
     }
 }
 
+/// The option every table engine accepts.
+pub const TBL_OPT_KEY_COMMENT: &str = "COMMENT";
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum TableOptionType {
+    String,
+    UInt64,
+}
+
+/// A `CREATE TABLE` option accepted by a table engine, the name is uppercase.
+#[derive(Clone, Debug)]
+pub struct TableOptionDescription {
+    pub name: String,
+    pub option_type: TableOptionType,
+    pub required: bool,
+}
+
+impl TableOptionDescription {
+    pub fn optional(name: &str, option_type: TableOptionType) -> TableOptionDescription {
+        TableOptionDescription {
+            name: name.to_string(),
+            option_type,
+            required: false,
+        }
+    }
+
+    pub fn required(name: &str, option_type: TableOptionType) -> TableOptionDescription {
+        TableOptionDescription {
+            name: name.to_string(),
+            option_type,
+            required: true,
+        }
+    }
+}
+
 #[derive(Default, Clone)]
 pub struct StorageDescription {
     pub engine_name: String,
     pub comment: String,
+    pub table_options: Vec<TableOptionDescription>,
+}
+
+impl StorageDescription {
+    /// Check the `CREATE TABLE` options against the options of the engine.
+    /// The option names are expected to be uppercase already.
+    pub fn check_table_options(&self, options: &HashMap<String, String>) -> Result<()> {
+        for (name, value) in options {
+            if name == TBL_OPT_KEY_COMMENT {
+                continue;
+            }
+
+            let option = self
+                .table_options
+                .iter()
+                .find(|option| &option.name == name)
+                .ok_or_else(|| {
+                    ErrorCode::BadOption(format!(
+                        "Unknown table option {} for engine {}, valid options are: {}",
+                        name,
+                        self.engine_name,
+                        self.valid_table_options()
+                    ))
+                })?;
+
+            if option.option_type == TableOptionType::UInt64 && value.parse::<u64>().is_err() {
+                return Err(ErrorCode::BadOption(format!(
+                    "Table option {} expects an unsigned integer, but got '{}'",
+                    name, value
+                )));
+            }
+        }
+
+        for option in self.table_options.iter().filter(|option| option.required) {
+            if !options.contains_key(&option.name) {
+                return Err(ErrorCode::BadOption(format!(
+                    "Missing required table option {} for engine {}",
+                    option.name, self.engine_name
+                )));
+            }
+        }
+
+        Ok(())
+    }
+
+    fn valid_table_options(&self) -> String {
+        self.table_options
+            .iter()
+            .map(|option| option.name.as_str())
+            .chain(std::iter::once(TBL_OPT_KEY_COMMENT))
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
 }
 
 pub trait StorageDescriptor: Send + Sync {
@@ -136,4 +136,19 @@ pub trait Table: Sync + Send {
     async fn optimize(&self, _ctx: Arc<QueryContext>, _keep_last_snapshot: bool) -> Result<()> {
         Ok(())
     }
+
+    // Size statistics of the data currently stored in the table,
+    // defaults to None for the tables that do not track them.
+    async fn statistics(&self, _ctx: Arc<QueryContext>) -> Result<Option<TableStatistics>> {
+        Ok(None)
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TableStatistics {
+    pub num_rows: u64,
+    pub data_bytes: u64,
+    pub num_blocks: u64,
+    /// Byte size of each column, in the order of the table schema fields.
+    pub column_bytes: Vec<u64>,
 }
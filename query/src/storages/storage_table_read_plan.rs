@@ -15,12 +15,14 @@
 use std::collections::BTreeMap;
 use std::sync::Arc;
 
+use common_exception::ErrorCode;
 use common_exception::Result;
 use common_meta_types::TableInfo;
 use common_planners::Extras;
 use common_planners::ReadDataSourcePlan;
 use common_planners::Statistics;
 
+use crate::catalogs::Catalog;
 use crate::sessions::QueryContext;
 use crate::storages::Table;
 
@@ -41,7 +43,25 @@ impl ToReadDataSourcePlan for dyn Table {
         ctx: Arc<QueryContext>,
         push_downs: Option<Extras>,
     ) -> Result<ReadDataSourcePlan> {
-        let (statistics, parts) = self.read_partitions(ctx, push_downs.clone()).await?;
+        let dropped = || {
+            ctx.get_catalog().is_table_dropped(self.get_id()).then(|| {
+                ErrorCode::UnknownTable(format!(
+                    "Unknown table: '{}', it has been dropped",
+                    self.name()
+                ))
+            })
+        };
+
+        if let Some(cause) = dropped() {
+            return Err(cause);
+        }
+
+        // The table may be dropped and purged while its partitions are read.
+        let (statistics, parts) = match self.read_partitions(ctx.clone(), push_downs.clone()).await
+        {
+            Ok(partitions) => partitions,
+            Err(cause) => return Err(dropped().unwrap_or(cause)),
+        };
         let table_info = self.get_table_info();
         let description = get_description(table_info, &statistics);
 
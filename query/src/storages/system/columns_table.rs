@@ -41,6 +41,7 @@ impl ColumnsTable {
             DataField::new("table", Vu8::to_data_type()),
             DataField::new("data_type", Vu8::to_data_type()),
             DataField::new("is_nullable", bool::to_data_type()),
+            DataField::new_nullable("data_bytes", u64::to_data_type()),
         ]);
 
         let table_info = TableInfo {
@@ -60,19 +61,28 @@ impl ColumnsTable {
     pub async fn dump_table_columns(
         &self,
         ctx: Arc<QueryContext>,
-    ) -> Result<Vec<(String, String, DataField)>> {
+    ) -> Result<Vec<(String, String, DataField, Option<u64>)>> {
         let tenant = ctx.get_tenant();
         let catalog = ctx.get_catalog();
         let databases = catalog.list_databases(tenant.as_str()).await?;
 
-        let mut rows: Vec<(String, String, DataField)> = vec![];
+        let mut rows: Vec<(String, String, DataField, Option<u64>)> = vec![];
         for database in databases {
             for table in catalog
                 .list_tables(tenant.as_str(), database.name())
                 .await?
             {
-                for field in table.schema().fields() {
-                    rows.push((database.name().into(), table.name().into(), field.clone()))
+                let stats = table.statistics(ctx.clone()).await?;
+                for (idx, field) in table.schema().fields().iter().enumerate() {
+                    let bytes = stats
+                        .as_ref()
+                        .and_then(|stats| stats.column_bytes.get(idx).cloned());
+                    rows.push((
+                        database.name().into(),
+                        table.name().into(),
+                        field.clone(),
+                        bytes,
+                    ))
                 }
             }
         }
@@ -102,13 +112,15 @@ impl Table for ColumnsTable {
         let mut databases: Vec<Vec<u8>> = Vec::with_capacity(rows.len());
         let mut data_types: Vec<Vec<u8>> = Vec::with_capacity(rows.len());
         let mut is_nullables: Vec<bool> = Vec::with_capacity(rows.len());
-        for (database_name, table_name, field) in rows.into_iter() {
+        let mut data_bytes: Vec<Option<u64>> = Vec::with_capacity(rows.len());
+        for (database_name, table_name, field, bytes) in rows.into_iter() {
             names.push(field.name().clone().into_bytes());
             tables.push(table_name.into_bytes());
             databases.push(database_name.into_bytes());
             let type_str = format!("{:?}", field.data_type());
             data_types.push(type_str.into_bytes());
             is_nullables.push(field.is_nullable());
+            data_bytes.push(bytes);
         }
 
         let block = DataBlock::create(self.table_info.schema(), vec![
@@ -117,6 +129,7 @@ impl Table for ColumnsTable {
             Series::from_data(tables),
             Series::from_data(data_types),
             Series::from_data(is_nullables),
+            Series::from_data(data_bytes),
         ]);
         Ok(Box::pin(DataBlockStream::create(
             self.table_info.schema(),
@@ -15,7 +15,6 @@
 use std::any::Any;
 use std::sync::Arc;
 
-use common_datablocks::DataBlock;
 use common_datavalues2::prelude::*;
 use common_exception::Result;
 use common_meta_types::TableIdent;
@@ -27,6 +26,7 @@ use common_streams::SendableDataBlockStream;
 
 use crate::catalogs::Catalog;
 use crate::sessions::QueryContext;
+use crate::storages::system::SystemTablePushDowns;
 use crate::storages::Table;
 
 pub struct DatabasesTable {
@@ -65,23 +65,23 @@ impl Table for DatabasesTable {
     async fn read(
         &self,
         ctx: Arc<QueryContext>,
-        _plan: &ReadDataSourcePlan,
+        plan: &ReadDataSourcePlan,
     ) -> Result<SendableDataBlockStream> {
+        let push_downs = SystemTablePushDowns::create(plan);
         let tenant = ctx.get_tenant();
         let dbs = ctx.get_catalog().list_databases(tenant.as_str()).await?;
 
         let db_names: Vec<&[u8]> = dbs
             .iter()
             .map(|database| database.name().as_bytes())
+            .filter(|name| push_downs.accept("name", name))
             .collect();
 
-        let block = DataBlock::create(self.table_info.schema(), vec![Series::from_data(db_names)]);
+        let block = push_downs.create_block(|_| Ok(Series::from_data(db_names.clone())))?;
 
-        let s = Box::pin(DataBlockStream::create(
-            self.table_info.schema(),
-            None,
-            vec![block],
-        ));
+        let s = Box::pin(DataBlockStream::create(push_downs.schema(), None, vec![
+            block,
+        ]));
         Ok(s)
     }
 }
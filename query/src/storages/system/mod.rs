@@ -24,7 +24,9 @@ mod metrics_table;
 mod one_table;
 mod processes_table;
 mod query_log_table;
+mod query_stages_table;
 mod settings_table;
+mod system_table_push_downs;
 mod tables_table;
 mod tracing_table;
 mod tracing_table_stream;
@@ -42,7 +44,9 @@ pub use metrics_table::MetricsTable;
 pub use one_table::OneTable;
 pub use processes_table::ProcessesTable;
 pub use query_log_table::QueryLogTable;
+pub use query_stages_table::QueryStagesTable;
 pub use settings_table::SettingsTable;
+pub use system_table_push_downs::SystemTablePushDowns;
 pub use tables_table::TablesTable;
 pub use tracing_table::TracingTable;
 pub use tracing_table_stream::TracingTableStream;
@@ -51,6 +51,8 @@ impl ProcessesTable {
             DataField::new_nullable("dal_metrics_write_bytes", u64::to_data_type()),
             DataField::new_nullable("scan_progress_read_rows", u64::to_data_type()),
             DataField::new_nullable("scan_progress_read_bytes", u64::to_data_type()),
+            DataField::new_nullable("query_id", Vu8::to_data_type()),
+            DataField::new_nullable("elapsed_ms", u64::to_data_type()),
         ]);
 
         let table_info = TableInfo {
@@ -135,6 +137,8 @@ impl Table for ProcessesTable {
         let mut processes_dal_metrics_write_bytes = Vec::with_capacity(processes_info.len());
         let mut processes_scan_progress_read_rows = Vec::with_capacity(processes_info.len());
         let mut processes_scan_progress_read_bytes = Vec::with_capacity(processes_info.len());
+        let mut processes_query_id = Vec::with_capacity(processes_info.len());
+        let mut processes_elapsed_ms = Vec::with_capacity(processes_info.len());
 
         for process_info in &processes_info {
             processes_id.push(process_info.id.clone().into_bytes());
@@ -155,6 +159,12 @@ impl Table for ProcessesTable {
                 ProcessesTable::process_scan_progress_values(&process_info.scan_progress_value);
             processes_scan_progress_read_rows.push(scan_progress_read_rows);
             processes_scan_progress_read_bytes.push(scan_progress_read_bytes);
+            processes_query_id.push(process_info.query_id.clone().map(|s| s.into_bytes()));
+            processes_elapsed_ms.push(
+                process_info
+                    .query_elapsed
+                    .map(|elapsed| elapsed.as_millis() as u64),
+            );
         }
 
         let schema = self.table_info.schema();
@@ -171,6 +181,8 @@ impl Table for ProcessesTable {
             Series::from_data(processes_dal_metrics_write_bytes),
             Series::from_data(processes_scan_progress_read_rows),
             Series::from_data(processes_scan_progress_read_bytes),
+            Series::from_data(processes_query_id),
+            Series::from_data(processes_elapsed_ms),
         ]);
 
         Ok(Box::pin(DataBlockStream::create(schema, None, vec![block])))
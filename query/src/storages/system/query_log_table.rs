@@ -30,6 +30,7 @@ use common_streams::SendableDataBlockStream;
 use futures::StreamExt;
 
 use crate::sessions::QueryContext;
+use crate::storages::system::SystemTablePushDowns;
 use crate::storages::Table;
 
 pub struct QueryLogTable {
@@ -125,15 +126,19 @@ impl Table for QueryLogTable {
     async fn read(
         &self,
         _ctx: Arc<QueryContext>,
-        _plan: &ReadDataSourcePlan,
+        plan: &ReadDataSourcePlan,
     ) -> Result<SendableDataBlockStream> {
+        let push_downs = SystemTablePushDowns::create(plan);
         let data = self.data.read().clone();
         let mut blocks = Vec::with_capacity(data.len());
         for block in data {
-            blocks.push(block);
+            let block = push_downs.filter_block(&block, &["query_id", "current_database"])?;
+            if block.num_rows() > 0 {
+                blocks.push(block);
+            }
         }
         Ok(Box::pin(DataBlockStream::create(
-            self.table_info.schema(),
+            push_downs.schema(),
             None,
             blocks,
         )))
@@ -0,0 +1,114 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::sync::Arc;
+
+use common_datablocks::DataBlock;
+use common_datavalues2::prelude::*;
+use common_exception::Result;
+use common_meta_types::TableIdent;
+use common_meta_types::TableInfo;
+use common_meta_types::TableMeta;
+use common_planners::ReadDataSourcePlan;
+use common_streams::DataBlockStream;
+use common_streams::SendableDataBlockStream;
+
+use crate::sessions::QueryContext;
+use crate::storages::Table;
+
+pub struct QueryStagesTable {
+    table_info: TableInfo,
+}
+
+impl QueryStagesTable {
+    pub fn create(table_id: u64) -> Self {
+        let schema = DataSchemaRefExt::create(vec![
+            DataField::new("query_id", Vu8::to_data_type()),
+            DataField::new("stage_id", Vu8::to_data_type()),
+            DataField::new("kind", Vu8::to_data_type()),
+            DataField::new("nodes", Vu8::to_data_type()),
+            DataField::new("state", Vu8::to_data_type()),
+            DataField::new("read_rows", u64::to_data_type()),
+            DataField::new("read_bytes", u64::to_data_type()),
+        ]);
+
+        let table_info = TableInfo {
+            desc: "'system'.'query_stages'".to_string(),
+            name: "query_stages".to_string(),
+            ident: TableIdent::new(table_id, 0),
+            meta: TableMeta {
+                schema,
+                engine: "SystemQueryStages".to_string(),
+
+                ..Default::default()
+            },
+        };
+        QueryStagesTable { table_info }
+    }
+}
+
+#[async_trait::async_trait]
+impl Table for QueryStagesTable {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    async fn read(
+        &self,
+        ctx: Arc<QueryContext>,
+        _plan: &ReadDataSourcePlan,
+    ) -> Result<SendableDataBlockStream> {
+        let query_stages = ctx
+            .get_processes_info()
+            .into_iter()
+            .flat_map(|process_info| process_info.query_stages)
+            .collect::<Vec<_>>();
+
+        let mut query_ids = Vec::with_capacity(query_stages.len());
+        let mut stage_ids = Vec::with_capacity(query_stages.len());
+        let mut kinds = Vec::with_capacity(query_stages.len());
+        let mut nodes = Vec::with_capacity(query_stages.len());
+        let mut states = Vec::with_capacity(query_stages.len());
+        let mut read_rows = Vec::with_capacity(query_stages.len());
+        let mut read_bytes = Vec::with_capacity(query_stages.len());
+
+        for query_stage in &query_stages {
+            query_ids.push(query_stage.query_id.clone().into_bytes());
+            stage_ids.push(query_stage.stage_id.clone().into_bytes());
+            kinds.push(query_stage.kind.clone().into_bytes());
+            nodes.push(query_stage.nodes.join(",").into_bytes());
+            states.push(query_stage.state.to_string().into_bytes());
+            read_rows.push(query_stage.read_rows as u64);
+            read_bytes.push(query_stage.read_bytes as u64);
+        }
+
+        let schema = self.table_info.schema();
+        let block = DataBlock::create(schema.clone(), vec![
+            Series::from_data(query_ids),
+            Series::from_data(stage_ids),
+            Series::from_data(kinds),
+            Series::from_data(nodes),
+            Series::from_data(states),
+            Series::from_data(read_rows),
+            Series::from_data(read_bytes),
+        ]);
+
+        Ok(Box::pin(DataBlockStream::create(schema, None, vec![block])))
+    }
+}
@@ -15,8 +15,8 @@
 use std::any::Any;
 use std::sync::Arc;
 
-use common_datablocks::DataBlock;
 use common_datavalues2::prelude::*;
+use common_exception::ErrorCode;
 use common_exception::Result;
 use common_meta_types::TableIdent;
 use common_meta_types::TableInfo;
@@ -26,6 +26,7 @@ use common_streams::DataBlockStream;
 use common_streams::SendableDataBlockStream;
 
 use crate::sessions::QueryContext;
+use crate::storages::system::SystemTablePushDowns;
 use crate::storages::Table;
 
 pub struct SettingsTable {
@@ -72,8 +73,9 @@ impl Table for SettingsTable {
     async fn read(
         &self,
         ctx: Arc<QueryContext>,
-        _plan: &ReadDataSourcePlan,
+        plan: &ReadDataSourcePlan,
     ) -> Result<SendableDataBlockStream> {
+        let push_downs = SystemTablePushDowns::create(plan);
         let settings = ctx.get_settings().get_setting_values();
 
         let mut names: Vec<String> = vec![];
@@ -84,8 +86,13 @@ impl Table for SettingsTable {
         let mut types: Vec<String> = vec![];
         for setting in settings {
             if let DataValue::Struct(vals) = setting {
+                let name = format!("{:?}", vals[0]);
+                if !push_downs.accept("name", name.as_bytes()) {
+                    continue;
+                }
+
                 // Name.
-                names.push(format!("{:?}", vals[0]));
+                names.push(name);
                 // Value.
                 values.push(format!("{:?}", vals[1]));
                 // Default Value.
@@ -99,23 +106,27 @@ impl Table for SettingsTable {
             }
         }
 
-        let names: Vec<&[u8]> = names.iter().map(|x| x.as_bytes()).collect();
-        let values: Vec<&[u8]> = values.iter().map(|x| x.as_bytes()).collect();
-        let defaults: Vec<&[u8]> = defaults.iter().map(|x| x.as_bytes()).collect();
-        let levels: Vec<&[u8]> = levels.iter().map(|x| x.as_bytes()).collect();
-        let descs: Vec<&[u8]> = descs.iter().map(|x| x.as_bytes()).collect();
-        let types: Vec<&[u8]> = types.iter().map(|x| x.as_bytes()).collect();
-        let block = DataBlock::create(self.table_info.schema(), vec![
-            Series::from_data(names),
-            Series::from_data(values),
-            Series::from_data(defaults),
-            Series::from_data(levels),
-            Series::from_data(descs),
-            Series::from_data(types),
-        ]);
+        let block = push_downs.create_block(|column| {
+            let column = match column {
+                "name" => &names,
+                "value" => &values,
+                "default" => &defaults,
+                "level" => &levels,
+                "description" => &descs,
+                "type" => &types,
+                _ => {
+                    return Err(ErrorCode::LogicalError(format!(
+                        "Unknown column {} of system.settings",
+                        column
+                    )))
+                }
+            };
+            let column: Vec<&[u8]> = column.iter().map(|x| x.as_bytes()).collect();
+            Ok(Series::from_data(column))
+        })?;
 
         Ok(Box::pin(DataBlockStream::create(
-            self.table_info.schema(),
+            push_downs.schema(),
             None,
             vec![block],
         )))
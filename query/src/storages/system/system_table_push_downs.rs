@@ -0,0 +1,146 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_datablocks::DataBlock;
+use common_datavalues2::prelude::*;
+use common_exception::Result;
+use common_planners::Expression;
+use common_planners::ReadDataSourcePlan;
+
+/// The projection and filters pushed down into a system table read.
+///
+/// System tables generate their rows on every read: they only build the required columns,
+/// and skip the entries whose key columns can not pass the filters. Skipping is only an
+/// optimization, the filters are still evaluated on the generated rows.
+pub struct SystemTablePushDowns {
+    schema: DataSchemaRef,
+    projection: Option<Vec<usize>>,
+    filters: Vec<Expression>,
+}
+
+impl SystemTablePushDowns {
+    pub fn create(plan: &ReadDataSourcePlan) -> Self {
+        let (projection, filters) = match &plan.push_downs {
+            Some(extras) => (extras.projection.clone(), extras.filters.clone()),
+            None => (None, vec![]),
+        };
+
+        SystemTablePushDowns {
+            schema: plan.table_info.schema(),
+            projection,
+            filters,
+        }
+    }
+
+    /// The schema of the generated blocks.
+    pub fn schema(&self) -> DataSchemaRef {
+        match &self.projection {
+            Some(projection) => Arc::new(self.schema.project(projection.clone())),
+            None => self.schema.clone(),
+        }
+    }
+
+    pub fn is_required(&self, column: &str) -> bool {
+        match &self.projection {
+            Some(projection) => projection
+                .iter()
+                .any(|index| self.schema.field(*index).name() == column),
+            None => true,
+        }
+    }
+
+    /// Whether an entry whose `column` is `value` may pass the filters.
+    pub fn accept(&self, column: &str, value: &[u8]) -> bool {
+        self.filters
+            .iter()
+            .all(|filter| Self::may_match(filter, column, value))
+    }
+
+    // Only `column = 'literal'` predicates combined by AND/OR are understood,
+    // anything else may match.
+    fn may_match(expr: &Expression, column: &str, value: &[u8]) -> bool {
+        match expr {
+            Expression::BinaryExpression { left, op, right } => match op.to_lowercase().as_str() {
+                "and" => {
+                    Self::may_match(left, column, value) && Self::may_match(right, column, value)
+                }
+                "or" => {
+                    Self::may_match(left, column, value) || Self::may_match(right, column, value)
+                }
+                "=" => match (left.as_ref(), right.as_ref()) {
+                    (
+                        Expression::Column(name),
+                        Expression::Literal {
+                            value: DataValue::String(literal),
+                            ..
+                        },
+                    )
+                    | (
+                        Expression::Literal {
+                            value: DataValue::String(literal),
+                            ..
+                        },
+                        Expression::Column(name),
+                    ) if name == column => literal.as_slice() == value,
+                    _ => true,
+                },
+                _ => true,
+            },
+            _ => true,
+        }
+    }
+
+    /// Create a block of the required columns, `build` is only called for them.
+    pub fn create_block<F>(&self, mut build: F) -> Result<DataBlock>
+    where F: FnMut(&str) -> Result<ColumnRef> {
+        let schema = self.schema();
+        let columns = schema
+            .fields()
+            .iter()
+            .map(|field| build(field.name()))
+            .collect::<Result<Vec<_>>>()?;
+        Ok(DataBlock::create(schema, columns))
+    }
+
+    /// Keep the rows of a block of the whole table whose `columns` may pass the filters,
+    /// and project them to the required columns.
+    pub fn filter_block(&self, block: &DataBlock, columns: &[&str]) -> Result<DataBlock> {
+        let mut block = block.clone();
+        if !self.filters.is_empty() {
+            let mut predicate = vec![true; block.num_rows()];
+            for column in columns {
+                let values = block.try_column_by_name(column)?;
+                for (row, accepted) in predicate.iter_mut().enumerate() {
+                    if let DataValue::String(value) = values.get_checked(row)? {
+                        *accepted = *accepted && self.accept(column, &value);
+                    }
+                }
+            }
+            block = DataBlock::filter_block(&block, &Series::from_data(predicate))?;
+        }
+
+        match &self.projection {
+            Some(projection) => {
+                let columns = projection
+                    .iter()
+                    .map(|index| block.column(*index).clone())
+                    .collect();
+                Ok(DataBlock::create(self.schema(), columns))
+            }
+            None => Ok(block),
+        }
+    }
+}
@@ -15,8 +15,8 @@
 use std::any::Any;
 use std::sync::Arc;
 
-use common_datablocks::DataBlock;
 use common_datavalues2::prelude::*;
+use common_exception::ErrorCode;
 use common_exception::Result;
 use common_meta_types::TableIdent;
 use common_meta_types::TableInfo;
@@ -27,6 +27,7 @@ use common_streams::SendableDataBlockStream;
 
 use crate::catalogs::Catalog;
 use crate::sessions::QueryContext;
+use crate::storages::system::SystemTablePushDowns;
 use crate::storages::Table;
 
 pub struct TablesTable {
@@ -40,6 +41,9 @@ impl TablesTable {
             DataField::new("name", Vu8::to_data_type()),
             DataField::new("engine", Vu8::to_data_type()),
             DataField::new("created_on", Vu8::to_data_type()),
+            DataField::new_nullable("num_rows", u64::to_data_type()),
+            DataField::new_nullable("data_bytes", u64::to_data_type()),
+            DataField::new_nullable("num_blocks", u64::to_data_type()),
         ]);
 
         let table_info = TableInfo {
@@ -71,8 +75,9 @@ impl Table for TablesTable {
     async fn read(
         &self,
         ctx: Arc<QueryContext>,
-        _plan: &ReadDataSourcePlan,
+        plan: &ReadDataSourcePlan,
     ) -> Result<SendableDataBlockStream> {
+        let push_downs = SystemTablePushDowns::create(plan);
         let tenant = ctx.get_tenant();
         let catalog = ctx.get_catalog();
         let databases = catalog.list_databases(tenant.as_str()).await?;
@@ -80,41 +85,96 @@ impl Table for TablesTable {
         let mut database_tables = vec![];
         for database in databases {
             let name = database.name();
+            if !push_downs.accept("database", name.as_bytes()) {
+                continue;
+            }
+
             for table in catalog.list_tables(tenant.as_str(), name).await? {
-                database_tables.push((name.to_string(), table));
+                if push_downs.accept("name", table.name().as_bytes()) {
+                    database_tables.push((name.to_string(), table));
+                }
             }
         }
 
-        let databases: Vec<&[u8]> = database_tables.iter().map(|(d, _)| d.as_bytes()).collect();
-        let names: Vec<&[u8]> = database_tables
-            .iter()
-            .map(|(_, v)| v.name().as_bytes())
-            .collect();
-        let engines: Vec<&[u8]> = database_tables
-            .iter()
-            .map(|(_, v)| v.engine().as_bytes())
-            .collect();
-        let created_ons: Vec<String> = database_tables
+        // Collecting the statistics may be expensive, only do it if they are required.
+        let mut table_stats = Vec::with_capacity(database_tables.len());
+        if ["num_rows", "data_bytes", "num_blocks"]
             .iter()
-            .map(|(_, v)| {
-                v.get_table_info()
-                    .meta
-                    .created_on
-                    .format("%Y-%m-%d %H:%M:%S.%3f %z")
-                    .to_string()
+            .any(|column| push_downs.is_required(column))
+        {
+            for (_, table) in &database_tables {
+                table_stats.push(table.statistics(ctx.clone()).await?);
+            }
+        }
+
+        let block = push_downs.create_block(|column| {
+            Ok(match column {
+                "database" => {
+                    let databases: Vec<&[u8]> =
+                        database_tables.iter().map(|(d, _)| d.as_bytes()).collect();
+                    Series::from_data(databases)
+                }
+                "name" => {
+                    let names: Vec<&[u8]> = database_tables
+                        .iter()
+                        .map(|(_, v)| v.name().as_bytes())
+                        .collect();
+                    Series::from_data(names)
+                }
+                "engine" => {
+                    let engines: Vec<&[u8]> = database_tables
+                        .iter()
+                        .map(|(_, v)| v.engine().as_bytes())
+                        .collect();
+                    Series::from_data(engines)
+                }
+                "created_on" => {
+                    let created_ons: Vec<String> = database_tables
+                        .iter()
+                        .map(|(_, v)| {
+                            v.get_table_info()
+                                .meta
+                                .created_on
+                                .format("%Y-%m-%d %H:%M:%S.%3f %z")
+                                .to_string()
+                        })
+                        .collect();
+                    let created_ons: Vec<&[u8]> =
+                        created_ons.iter().map(|s| s.as_bytes()).collect();
+                    Series::from_data(created_ons)
+                }
+                "num_rows" => {
+                    let num_rows: Vec<Option<u64>> = table_stats
+                        .iter()
+                        .map(|v| v.as_ref().map(|v| v.num_rows))
+                        .collect();
+                    Series::from_data(num_rows)
+                }
+                "data_bytes" => {
+                    let data_bytes: Vec<Option<u64>> = table_stats
+                        .iter()
+                        .map(|v| v.as_ref().map(|v| v.data_bytes))
+                        .collect();
+                    Series::from_data(data_bytes)
+                }
+                "num_blocks" => {
+                    let num_blocks: Vec<Option<u64>> = table_stats
+                        .iter()
+                        .map(|v| v.as_ref().map(|v| v.num_blocks))
+                        .collect();
+                    Series::from_data(num_blocks)
+                }
+                _ => {
+                    return Err(ErrorCode::LogicalError(format!(
+                        "Unknown column {} of system.tables",
+                        column
+                    )))
+                }
             })
-            .collect();
-        let created_ons: Vec<&[u8]> = created_ons.iter().map(|s| s.as_bytes()).collect();
-
-        let block = DataBlock::create(self.table_info.schema(), vec![
-            Series::from_data(databases),
-            Series::from_data(names),
-            Series::from_data(engines),
-            Series::from_data(created_ons),
-        ]);
+        })?;
 
         Ok(Box::pin(DataBlockStream::create(
-            self.table_info.schema(),
+            push_downs.schema(),
             None,
             vec![block],
         )))
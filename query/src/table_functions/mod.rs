@@ -16,11 +16,14 @@
 mod memory_block_part;
 mod numbers_stream;
 mod numbers_table;
+mod range_table;
 mod table_function;
 mod table_function_factory;
 
 pub use memory_block_part::generate_block_parts;
 pub use numbers_table::NumbersTable;
+pub use range_table::RangeTable;
+pub use range_table::RANGE_MAX_ROWS;
 pub use table_function::TableFunction;
 pub use table_function_factory::TableArgs;
 pub use table_function_factory::TableFunctionFactory;
@@ -0,0 +1,330 @@
+//  Copyright 2022 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+
+use std::any::Any;
+use std::mem::size_of;
+use std::sync::Arc;
+use std::task::Context;
+use std::task::Poll;
+
+use chrono::NaiveDate;
+use chrono::NaiveDateTime;
+use common_datablocks::DataBlock;
+use common_datavalues2::chrono::TimeZone;
+use common_datavalues2::chrono::Utc;
+use common_datavalues2::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_types::TableIdent;
+use common_meta_types::TableInfo;
+use common_meta_types::TableMeta;
+use common_planners::Expression;
+use common_planners::Extras;
+use common_planners::Partitions;
+use common_planners::ReadDataSourcePlan;
+use common_planners::Statistics;
+use common_streams::SendableDataBlockStream;
+use futures::stream::Stream;
+
+use crate::sessions::QueryContext;
+use crate::storages::Table;
+use crate::table_functions::generate_block_parts;
+use crate::table_functions::table_function_factory::TableArgs;
+use crate::table_functions::TableFunction;
+
+/// The max number of rows a range table function can generate.
+pub const RANGE_MAX_ROWS: u64 = 100_000_000;
+
+const SECONDS_PER_DAY: i64 = 24 * 3600;
+
+/// `range(start, end, step)` generates the values in `[start, end)` with the interval `step`.
+/// The column is Date32 if both bounds are dates, otherwise it is DateTime32.
+pub struct RangeTable {
+    table_info: TableInfo,
+    table_args: Vec<Expression>,
+    is_date: bool,
+    // The bounds and the step in seconds.
+    start: i64,
+    step: i64,
+    total: u64,
+}
+
+impl RangeTable {
+    pub fn create(
+        database_name: &str,
+        table_func_name: &str,
+        table_id: u64,
+        table_args: TableArgs,
+    ) -> Result<Arc<dyn TableFunction>> {
+        let args = match table_args {
+            Some(args) if args.len() == 3 => args,
+            _ => {
+                return Err(ErrorCode::BadArguments(format!(
+                    "Table function {} must have exactly three arguments: start, end and step",
+                    table_func_name
+                )));
+            }
+        };
+
+        let (start_is_date, start) = Self::parse_bound(&args[0])?;
+        let (end_is_date, end) = Self::parse_bound(&args[1])?;
+        let (step_is_days, step) = Self::parse_step(&args[2])?;
+
+        let is_date = start_is_date && end_is_date;
+        if is_date && !step_is_days {
+            return Err(ErrorCode::BadArguments(
+                "The step of a date range must be an interval of days",
+            ));
+        }
+
+        let total = match end > start {
+            true => ((end - start - 1) / step + 1) as u64,
+            false => 0,
+        };
+        if total > RANGE_MAX_ROWS {
+            return Err(ErrorCode::BadArguments(format!(
+                "Table function {} generates {} rows, exceeds the limit {}",
+                table_func_name, total, RANGE_MAX_ROWS
+            )));
+        }
+
+        let data_type = match is_date {
+            true => Date32Type::arc(),
+            false => DateTime32Type::arc(None),
+        };
+
+        let table_info = TableInfo {
+            ident: TableIdent::new(table_id, 0),
+            desc: format!("'{}'.'{}'", database_name, table_func_name),
+            name: table_func_name.to_string(),
+            meta: TableMeta {
+                schema: DataSchemaRefExt::create(vec![DataField::new("range", data_type)]),
+                engine: "SystemRange".to_string(),
+                // Keep created_on fixed like the numbers table function.
+                created_on: Utc.from_utc_datetime(&NaiveDateTime::from_timestamp(0, 0)),
+                ..Default::default()
+            },
+        };
+
+        Ok(Arc::new(RangeTable {
+            table_info,
+            table_args: args,
+            is_date,
+            start,
+            step,
+            total,
+        }))
+    }
+
+    // Returns whether the bound is a date and its seconds since the epoch.
+    fn parse_bound(arg: &Expression) -> Result<(bool, i64)> {
+        if let Expression::Literal {
+            value: DataValue::String(value),
+            ..
+        } = arg
+        {
+            let value = String::from_utf8_lossy(value);
+            if let Ok(date) = value.parse::<NaiveDate>() {
+                return Ok((true, date.and_hms(0, 0, 0).timestamp()));
+            }
+            if let Ok(datetime) = NaiveDateTime::parse_from_str(&value, "%Y-%m-%d %H:%M:%S") {
+                return Ok((false, datetime.timestamp()));
+            }
+        }
+
+        Err(ErrorCode::BadArguments(format!(
+            "The bound of range must be a date or timestamp string, but got {:?}",
+            arg
+        )))
+    }
+
+    // Returns whether the step is in days and its length in seconds.
+    fn parse_step(arg: &Expression) -> Result<(bool, i64)> {
+        if let Expression::Literal {
+            value, data_type, ..
+        } = arg
+        {
+            if let Some(interval) = data_type.as_any().downcast_ref::<IntervalType>() {
+                let num = value.as_i64()?;
+                if num <= 0 {
+                    return Err(ErrorCode::BadArguments(format!(
+                        "The step of range must be a positive interval, but got {} {}",
+                        num,
+                        interval.kind()
+                    )));
+                }
+
+                let (is_days, seconds) = match interval.kind() {
+                    IntervalKind::Day => (true, num.checked_mul(SECONDS_PER_DAY)),
+                    IntervalKind::Hour => (false, num.checked_mul(3600)),
+                    IntervalKind::Minute => (false, num.checked_mul(60)),
+                    IntervalKind::Second => (false, Some(num)),
+                    kind => {
+                        return Err(ErrorCode::BadArguments(format!(
+                            "Unsupported interval {} for the step of range",
+                            kind
+                        )));
+                    }
+                };
+
+                return match seconds {
+                    Some(seconds) => Ok((is_days, seconds)),
+                    None => Err(ErrorCode::Overflow(format!(
+                        "The step of range overflows: {} {}",
+                        num,
+                        interval.kind()
+                    ))),
+                };
+            }
+        }
+
+        Err(ErrorCode::BadArguments(format!(
+            "The step of range must be an interval, e.g: INTERVAL 1 DAY, but got {:?}",
+            arg
+        )))
+    }
+}
+
+#[async_trait::async_trait]
+impl Table for RangeTable {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    async fn read_partitions(
+        &self,
+        ctx: Arc<QueryContext>,
+        _push_downs: Option<Extras>,
+    ) -> Result<(Statistics, Partitions)> {
+        let max_block_size = ctx.get_settings().get_max_block_size()?;
+        let fake_partitions = (self.total / max_block_size) + 1;
+        let statistics = Statistics::new_exact(
+            self.total as usize,
+            ((self.total) * size_of::<u32>() as u64) as usize,
+            fake_partitions as usize,
+            fake_partitions as usize,
+        );
+        let parts =
+            generate_block_parts(0, ctx.get_settings().get_max_threads()? as u64, self.total);
+
+        Ok((statistics, parts))
+    }
+
+    fn table_args(&self) -> Option<Vec<Expression>> {
+        Some(self.table_args.clone())
+    }
+
+    async fn read(
+        &self,
+        ctx: Arc<QueryContext>,
+        _plan: &ReadDataSourcePlan,
+    ) -> Result<SendableDataBlockStream> {
+        Ok(Box::pin(RangeStream {
+            ctx,
+            schema: self.schema(),
+            is_date: self.is_date,
+            start: self.start,
+            step: self.step,
+            begin: 0,
+            end: 0,
+        }))
+    }
+}
+
+impl TableFunction for RangeTable {
+    fn function_name(&self) -> &str {
+        self.name()
+    }
+
+    fn as_table<'a>(self: Arc<Self>) -> Arc<dyn Table + 'a>
+    where Self: 'a {
+        self
+    }
+}
+
+struct RangeStream {
+    ctx: Arc<QueryContext>,
+    schema: DataSchemaRef,
+    is_date: bool,
+    start: i64,
+    step: i64,
+    // The row indexes remaining in the current partition.
+    begin: u64,
+    end: u64,
+}
+
+impl RangeStream {
+    fn try_get_one_block(&mut self) -> Result<Option<DataBlock>> {
+        if self.begin == self.end {
+            let partitions = self.ctx.try_get_partitions(1)?;
+            if partitions.is_empty() || partitions[0].name.is_empty() {
+                return Ok(None);
+            }
+
+            let names: Vec<_> = partitions[0].name.split('-').collect();
+            self.begin = names[1].parse()?;
+            self.end = names[2].parse()?;
+            if self.begin == self.end {
+                return Ok(None);
+            }
+        }
+
+        let block_size = self.ctx.get_settings().get_max_block_size()?;
+        let (begin, end) = (self.begin, self.end.min(self.begin + block_size));
+        self.begin = end;
+
+        let (start, step) = (self.start, self.step);
+        let seconds = (begin..end).map(|row| start + row as i64 * step);
+
+        let column = match self.is_date {
+            true => Series::from_data(
+                seconds
+                    .map(|v| (v / SECONDS_PER_DAY) as i32)
+                    .collect::<Vec<_>>(),
+            ),
+            false => {
+                // DateTime32 only holds the seconds from 1970-01-01 00:00:00 to 2106-02-07 06:28:15.
+                let values = seconds
+                    .map(|v| {
+                        u32::try_from(v).map_err(|_| {
+                            ErrorCode::Overflow(format!(
+                                "The timestamp {} of range is out of the range of DateTime32",
+                                v
+                            ))
+                        })
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                Series::from_data(values)
+            }
+        };
+        Ok(Some(DataBlock::create(self.schema.clone(), vec![column])))
+    }
+}
+
+impl Stream for RangeStream {
+    type Item = Result<DataBlock>;
+
+    fn poll_next(
+        mut self: std::pin::Pin<&mut Self>,
+        _: &mut Context<'_>,
+    ) -> Poll<Option<Self::Item>> {
+        let block = self.try_get_one_block()?;
+
+        Poll::Ready(block.map(Ok))
+    }
+}
@@ -27,6 +27,7 @@ use crate::catalogs::SYS_TBL_FUNC_ID_BEGIN;
 use crate::storages::fuse::FuseHistoryTable;
 use crate::storages::fuse::FUSE_FUNC_HIST;
 use crate::table_functions::NumbersTable;
+use crate::table_functions::RangeTable;
 use crate::table_functions::TableFunction;
 
 pub type TableArgs = Option<Vec<Expression>>;
@@ -95,6 +96,11 @@ impl TableFunctionFactory {
             (next_id(), number_table_func_creator),
         );
 
+        creators.insert(
+            "range".to_string(),
+            (next_id(), Arc::new(RangeTable::create)),
+        );
+
         creators.insert(
             FUSE_FUNC_HIST.to_string(),
             (next_id(), Arc::new(FuseHistoryTable::create)),
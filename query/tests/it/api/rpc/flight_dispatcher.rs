@@ -64,12 +64,13 @@ async fn test_run_shuffle_action_with_no_scatters() -> Result<()> {
                     plan: PlanParser::parse(ctx.clone(), "SELECT number FROM numbers(5)").await?,
                     sinks: vec![stream_id.clone()],
                     scatters_expression: Expression::create_literal(DataValue::UInt64(1)),
+                    checksum: true,
                 }),
             )
             .await?;
 
         let stream = stream_ticket(&query_id, &stage_id, &stream_id);
-        let (receiver, _data_scheme) = flight_dispatcher.get_stream(&stream)?;
+        let (receiver, _data_scheme, _checksum) = flight_dispatcher.get_stream(&stream)?;
         let receiver_stream = ReceiverStream::new(receiver);
         let collect_data_blocks = receiver_stream.collect::<Result<Vec<_>>>();
 
@@ -109,12 +110,13 @@ async fn test_run_shuffle_action_with_scatter() -> Result<()> {
                     plan: PlanParser::parse(ctx.clone(), "SELECT number FROM numbers(5)").await?,
                     sinks: vec!["stream_1".to_string(), "stream_2".to_string()],
                     scatters_expression: Expression::Column("number".to_string()),
+                    checksum: true,
                 }),
             )
             .await?;
 
         let stream_1 = stream_ticket(&query_id, &stage_id, "stream_1");
-        let (receiver, _data_scheme) = flight_dispatcher.get_stream(&stream_1)?;
+        let (receiver, _data_scheme, _checksum) = flight_dispatcher.get_stream(&stream_1)?;
         let receiver_stream = ReceiverStream::new(receiver);
         let collect_data_blocks = receiver_stream.collect::<Result<Vec<_>>>();
 
@@ -131,7 +133,7 @@ async fn test_run_shuffle_action_with_scatter() -> Result<()> {
         assert_blocks_eq(expect, &collect_data_blocks.await?);
 
         let stream_2 = stream_ticket(&query_id, &stage_id, "stream_2");
-        let (receiver, _data_scheme) = flight_dispatcher.get_stream(&stream_2)?;
+        let (receiver, _data_scheme, _checksum) = flight_dispatcher.get_stream(&stream_2)?;
         let receiver_stream = ReceiverStream::new(receiver);
         let collect_data_blocks = receiver_stream.collect::<Result<Vec<_>>>();
 
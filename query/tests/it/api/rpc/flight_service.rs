@@ -19,7 +19,7 @@ use common_arrow::arrow_format::flight::data::Action;
 use common_arrow::arrow_format::flight::data::Ticket;
 use common_arrow::arrow_format::flight::service::flight_service_server::FlightService;
 use common_base::tokio;
-use common_datavalues2::DataValue;
+use common_datavalues2::prelude::*;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_exception::ABORT_SESSION;
@@ -27,10 +27,12 @@ use common_planners::Expression;
 use databend_query::api::DatabendQueryFlightDispatcher;
 use databend_query::api::DatabendQueryFlightService;
 use databend_query::api::FlightAction;
+use databend_query::api::FlightClientStream;
 use databend_query::api::FlightTicket;
 use databend_query::api::ShuffleAction;
 use databend_query::api::StreamTicket;
 use databend_query::sql::PlanParser;
+use futures::StreamExt;
 use tonic::Request;
 
 use crate::tests::create_query_context;
@@ -151,6 +153,86 @@ async fn test_do_flight_action_with_abort_and_new_session() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_do_get_with_block_checksum() -> Result<()> {
+    let sessions = SessionManagerBuilder::create().build()?;
+    let dispatcher = Arc::new(DatabendQueryFlightDispatcher::create());
+    let service = DatabendQueryFlightService::create(dispatcher, sessions);
+
+    let query_id = "query_id";
+    let stage_id = "stage_id";
+    let request = do_action_request(query_id, stage_id).await;
+    service.do_action(request?).await?;
+
+    let request = do_get_request(query_id, stage_id);
+    let mut stream = service.do_get(request?).await?.into_inner();
+    let flight_data = match stream.next().await {
+        Some(flight_data) => flight_data?,
+        None => return Err(ErrorCode::LogicalError("Expect a block from the stream")),
+    };
+    assert_eq!(flight_data.app_metadata.len(), 8);
+
+    let schema = DataSchemaRefExt::create(vec![DataField::new("number", u64::to_data_type())]);
+    let block = FlightClientStream::deserialize_block(&schema, &flight_data, true)?;
+    assert_eq!(block.num_rows(), 5);
+
+    // Corrupt the value 3 of the payload.
+    let mut corrupted = flight_data.clone();
+    let value = 3u64.to_le_bytes();
+    let position = corrupted
+        .data_body
+        .windows(value.len())
+        .position(|window| window == value)
+        .ok_or_else(|| ErrorCode::LogicalError("Expect the value in the payload"))?;
+    corrupted.data_body[position] ^= 0x10;
+
+    let result = FlightClientStream::deserialize_block(&schema, &corrupted, true);
+    assert!(result.is_err());
+    assert_eq!(result.unwrap_err().code(), 1075);
+
+    // The corruption is silent when the verification is disabled.
+    let block = FlightClientStream::deserialize_block(&schema, &corrupted, false)?;
+    assert_eq!(block.num_rows(), 5);
+
+    // A block without the checksum is rejected when the verification is enabled.
+    let mut missing = flight_data.clone();
+    missing.app_metadata = vec![];
+    let result = FlightClientStream::deserialize_block(&schema, &missing, true);
+    assert!(result.is_err());
+    assert_eq!(result.unwrap_err().code(), 1075);
+
+    let block = FlightClientStream::deserialize_block(&schema, &missing, false)?;
+    assert_eq!(block.num_rows(), 5);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_do_get_without_block_checksum() -> Result<()> {
+    let sessions = SessionManagerBuilder::create().build()?;
+    let dispatcher = Arc::new(DatabendQueryFlightDispatcher::create());
+    let service = DatabendQueryFlightService::create(dispatcher, sessions);
+
+    let query_id = "query_id";
+    let stage_id = "stage_id";
+    let request = do_action_request_with_checksum(query_id, stage_id, false).await;
+    service.do_action(request?).await?;
+
+    let request = do_get_request(query_id, stage_id);
+    let mut stream = service.do_get(request?).await?.into_inner();
+    let flight_data = match stream.next().await {
+        Some(flight_data) => flight_data?,
+        None => return Err(ErrorCode::LogicalError("Expect a block from the stream")),
+    };
+    assert!(flight_data.app_metadata.is_empty());
+
+    let schema = DataSchemaRefExt::create(vec![DataField::new("number", u64::to_data_type())]);
+    let block = FlightClientStream::deserialize_block(&schema, &flight_data, false)?;
+    assert_eq!(block.num_rows(), 5);
+
+    Ok(())
+}
+
 fn do_get_request(query_id: &str, stage_id: &str) -> Result<Request<Ticket>> {
     let stream_ticket = FlightTicket::StreamTicket(StreamTicket {
         query_id: String::from(query_id),
@@ -162,6 +244,14 @@ fn do_get_request(query_id: &str, stage_id: &str) -> Result<Request<Ticket>> {
 }
 
 async fn do_action_request(query_id: &str, stage_id: &str) -> Result<Request<Action>> {
+    do_action_request_with_checksum(query_id, stage_id, true).await
+}
+
+async fn do_action_request_with_checksum(
+    query_id: &str,
+    stage_id: &str,
+    checksum: bool,
+) -> Result<Request<Action>> {
     let ctx = create_query_context()?;
     let flight_action = FlightAction::PrepareShuffleAction(ShuffleAction {
         query_id: String::from(query_id),
@@ -169,6 +259,7 @@ async fn do_action_request(query_id: &str, stage_id: &str) -> Result<Request<Act
         plan: PlanParser::parse(ctx.clone(), "SELECT number FROM numbers(5)").await?,
         sinks: vec![String::from("stream_id")],
         scatters_expression: Expression::create_literal(DataValue::UInt64(1)),
+        checksum,
     });
 
     Ok(Request::new(flight_action.try_into()?))
@@ -44,6 +44,8 @@ mysql_handler_port = 3307
 max_active_sessions = 256
 clickhouse_handler_host = \"127.0.0.1\"
 clickhouse_handler_port = 9000
+pg_handler_host = \"127.0.0.1\"
+pg_handler_port = 5433
 http_handler_host = \"127.0.0.1\"
 http_handler_port = 8000
 http_handler_result_timeout_millis = 10000
@@ -126,6 +128,8 @@ fn test_env_config() -> Result<()> {
     std::env::set_var("QUERY_MAX_ACTIVE_SESSIONS", "255");
     std::env::set_var("QUERY_CLICKHOUSE_HANDLER_HOST", "1.2.3.4");
     std::env::set_var("QUERY_CLICKHOUSE_HANDLER_PORT", "9000");
+    std::env::set_var("QUERY_PG_HANDLER_HOST", "1.2.3.5");
+    std::env::set_var("QUERY_PG_HANDLER_PORT", "5432");
     std::env::set_var("QUERY_FLIGHT_API_ADDRESS", "1.2.3.4:9091");
     std::env::set_var("QUERY_HTTP_API_ADDRESS", "1.2.3.4:8081");
     std::env::set_var("QUERY_METRIC_API_ADDRESS", "1.2.3.4:7071");
@@ -158,6 +162,8 @@ fn test_env_config() -> Result<()> {
     assert_eq!(255, configured.query.max_active_sessions);
     assert_eq!("1.2.3.4", configured.query.clickhouse_handler_host);
     assert_eq!(9000, configured.query.clickhouse_handler_port);
+    assert_eq!("1.2.3.5", configured.query.pg_handler_host);
+    assert_eq!(5432, configured.query.pg_handler_port);
 
     assert_eq!("1.2.3.4:9091", configured.query.flight_api_address);
     assert_eq!("1.2.3.4:8081", configured.query.http_api_address);
@@ -193,6 +199,8 @@ fn test_env_config() -> Result<()> {
     std::env::remove_var("QUERY_MAX_ACTIVE_SESSIONS");
     std::env::remove_var("QUERY_CLICKHOUSE_HANDLER_HOST");
     std::env::remove_var("QUERY_CLICKHOUSE_HANDLER_PORT");
+    std::env::remove_var("QUERY_PG_HANDLER_HOST");
+    std::env::remove_var("QUERY_PG_HANDLER_PORT");
     std::env::remove_var("QUERY_CLICKHOUSE_HANDLER_THREAD_NUM");
     std::env::remove_var("QUERY_FLIGHT_API_ADDRESS");
     std::env::remove_var("QUERY_HTTP_API_ADDRESS");
@@ -12,9 +12,12 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::sync::Arc;
+
 use common_base::tokio;
 use common_exception::Result;
 use databend_query::interpreters::*;
+use databend_query::sessions::QueryContext;
 use databend_query::sql::PlanParser;
 use futures::TryStreamExt;
 use pretty_assertions::assert_eq;
@@ -52,3 +55,318 @@ async fn test_explain_interpreter() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_explain_optimized_interpreter() -> Result<()> {
+    let ctx = crate::tests::create_query_context()?;
+
+    let query = "EXPLAIN OPTIMIZED SELECT number FROM numbers_mt(10) WHERE number > 1 + 1";
+    let plan = PlanParser::parse(ctx.clone(), query).await?;
+    let executor = InterpreterFactory::get(ctx, plan)?;
+    assert_eq!(executor.name(), "ExplainInterpreter");
+
+    let stream = executor.execute(None).await?;
+    let result = stream.try_collect::<Vec<_>>().await?;
+    let column = result[0].column(0);
+    let actual = (0..column.len())
+        .map(|i| column.get(i).to_string())
+        .collect::<Vec<_>>();
+
+    let expected = vec![
+        "Projection: number:UInt64",
+        "  Filter: (number > 2)",
+        "    ReadDataSource: scan schema: [number:UInt64], statistics: [read_rows: 10, read_bytes: 80, partitions_scanned: 1, partitions_total: 1], push_downs: [projections: [0], filters: [(number > 2)]]",
+    ];
+    assert_eq!(expected, actual);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_explain_graph_interpreter() -> Result<()> {
+    let ctx = crate::tests::create_query_context()?;
+
+    let query = "EXPLAIN GRAPH SELECT number FROM numbers_mt(10) WHERE number > 1";
+    let plan = PlanParser::parse(ctx.clone(), query).await?;
+    let executor = InterpreterFactory::get(ctx, plan)?;
+    assert_eq!(executor.name(), "ExplainInterpreter");
+
+    let stream = executor.execute(None).await?;
+    let result = stream.try_collect::<Vec<_>>().await?;
+    let column = result[0].column(0);
+    let actual = (0..column.len())
+        .map(|i| column.get(i).to_string())
+        .collect::<Vec<_>>();
+
+    let expected = vec![
+        "// Begin Databend GraphViz Plan (see https://graphviz.org)",
+        "digraph {",
+        "    n0 [shape=box, label=\"ProjectionPlan\\nnumber\"];",
+        "    n1 [shape=box, label=\"FilterPlan\\n(number > 1)\"];",
+        "    n1 -> n0;",
+        "    n2 [shape=box, label=\"ReadSourcePlan\\n'system'.'numbers_mt'\\nread_rows: 10, read_bytes: 80\"];",
+        "    n2 -> n1;",
+        "}",
+        "// End Databend GraphViz Plan",
+    ];
+    assert_eq!(expected, actual);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_explain_with_types_interpreter() -> Result<()> {
+    let ctx = crate::tests::create_query_context()?;
+
+    let query = "EXPLAIN WITH TYPES SELECT number + 1 FROM numbers_mt(10) WHERE number > 1";
+    let plan = PlanParser::parse(ctx.clone(), query).await?;
+    let executor = InterpreterFactory::get(ctx, plan)?;
+    assert_eq!(executor.name(), "ExplainInterpreter");
+
+    let stream = executor.execute(None).await?;
+    let result = stream.try_collect::<Vec<_>>().await?;
+    let column = result[0].column(0);
+    let actual = (0..column.len())
+        .map(|i| column.get(i).to_string())
+        .collect::<Vec<_>>();
+
+    let expected = vec![
+        "Projection: (number + 1):UInt64 NOT NULL",
+        "  Expression: (number + 1):UInt64 NOT NULL (Before Projection)",
+        "    Filter: (number > 1):Boolean NOT NULL",
+        "      ReadDataSource: scan schema: [number:UInt64], statistics: [read_rows: 10, read_bytes: 80, partitions_scanned: 1, partitions_total: 1], push_downs: [projections: [0], filters: [(number > 1)]]",
+    ];
+    assert_eq!(expected, actual);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_explain_without_stages_in_cluster() -> Result<()> {
+    let ctx = crate::tests::create_query_context_with_cluster(
+        crate::tests::ClusterDescriptor::new()
+            .with_node("Github", "www.github.com:9090")
+            .with_node("dummy_local", "127.0.0.1:9090")
+            .with_local_id("dummy_local"),
+    )?;
+
+    let mut explained = vec![];
+    for kind in ["OPTIMIZED", "WITH TYPES"] {
+        let query = format!("EXPLAIN {} SELECT number FROM numbers(100000000)", kind);
+        let plan = PlanParser::parse(ctx.clone(), &query).await?;
+        let executor = InterpreterFactory::get(ctx.clone(), plan)?;
+        let stream = executor.execute(None).await?;
+        let result = stream.try_collect::<Vec<_>>().await?;
+        let column = result[0].column(0);
+        let actual = (0..column.len())
+            .map(|i| column.get(i).to_string())
+            .collect::<Vec<_>>();
+        explained.push(actual);
+    }
+
+    // Neither of them shows the stages of the scatters optimizer, so the plans agree.
+    assert_eq!(explained[0], vec![
+        "Projection: number:UInt64",
+        "  ReadDataSource: scan schema: [number:UInt64], statistics: [read_rows: 100000000, read_bytes: 800000000, partitions_scanned: 10001, partitions_total: 10001], push_downs: [projections: [0]]",
+    ]);
+    assert_eq!(explained[1], vec![
+        "Projection: number:UInt64 NOT NULL",
+        "  ReadDataSource: scan schema: [number:UInt64], statistics: [read_rows: 100000000, read_bytes: 800000000, partitions_scanned: 10001, partitions_total: 10001], push_downs: [projections: [0]]",
+    ]);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_explain_analyze_interpreter() -> Result<()> {
+    let ctx = crate::tests::create_query_context()?;
+
+    let query = "EXPLAIN ANALYZE SELECT number FROM numbers_mt(10) WHERE number > 4";
+    let plan = PlanParser::parse(ctx.clone(), query).await?;
+    let executor = InterpreterFactory::get(ctx, plan)?;
+    assert_eq!(executor.name(), "ExplainInterpreter");
+
+    let stream = executor.execute(None).await?;
+    let result = stream.try_collect::<Vec<_>>().await?;
+    assert_eq!(result[0].num_columns(), 1);
+    let column = result[0].column(0);
+    let actual = (0..column.len())
+        .map(|i| column.get(i).to_string())
+        .collect::<Vec<_>>();
+
+    // The elapsed time is not deterministic, only check the node and its counters.
+    let expected = vec![
+        (
+            "Projection: number:UInt64",
+            "[rows: 5, bytes: 40, elapsed: ",
+        ),
+        ("  Filter: (number > 4)", "[rows: 5, bytes: 40, elapsed: "),
+        ("    ReadDataSource: ", "[rows: 10, bytes: 80, elapsed: "),
+    ];
+    assert_eq!(expected.len(), actual.len(), "{:?}", actual);
+    for ((prefix, metrics), line) in expected.iter().zip(actual.iter()) {
+        assert!(line.starts_with(prefix), "{}", line);
+        assert!(line.contains(metrics), "{}", line);
+    }
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_explain_projection_pruning() -> Result<()> {
+    let ctx = crate::tests::create_query_context()?;
+
+    for query in [
+        "CREATE TABLE default.t(a bigint not null, b bigint not null, c varchar not null) Engine = Memory",
+        "INSERT INTO default.t VALUES (1, 2, 'x'), (3, 4, 'y')",
+    ] {
+        let plan = PlanParser::parse(ctx.clone(), query).await?;
+        let executor = InterpreterFactory::get(ctx.clone(), plan)?;
+        executor.execute(None).await?.try_collect::<Vec<_>>().await?;
+    }
+
+    // Returns the scan schema and the read bytes of the ReadDataSource.
+    async fn explain_scan(
+        ctx: &std::sync::Arc<databend_query::sessions::QueryContext>,
+        query: &str,
+    ) -> Result<(String, usize)> {
+        let plan = PlanParser::parse(ctx.clone(), &format!("EXPLAIN {}", query)).await?;
+        let executor = InterpreterFactory::get(ctx.clone(), plan)?;
+        let result = executor
+            .execute(None)
+            .await?
+            .try_collect::<Vec<_>>()
+            .await?;
+        let column = result[0].column(0);
+        let line = (0..column.len())
+            .map(|i| column.get(i).to_string())
+            .find(|line| line.contains("ReadDataSource"))
+            .unwrap();
+
+        let schema = line.split("scan schema: ").nth(1).unwrap();
+        let schema = schema.split(", statistics").next().unwrap().to_string();
+        let read_bytes = line.split("read_bytes: ").nth(1).unwrap();
+        let read_bytes = read_bytes.split(',').next().unwrap().parse().unwrap();
+        Ok((schema, read_bytes))
+    }
+
+    let (schema, all_bytes) = explain_scan(&ctx, "SELECT * FROM default.t").await?;
+    assert_eq!(schema, "[a:Int64, b:Int64, c:String]");
+
+    let (schema, a_bytes) = explain_scan(&ctx, "SELECT a FROM default.t").await?;
+    assert_eq!(schema, "[a:Int64]");
+    assert!(a_bytes < all_bytes);
+
+    // Columns referenced by filters and sorts are read as well.
+    let (schema, _) =
+        explain_scan(&ctx, "SELECT a FROM default.t WHERE c = 'x' ORDER BY b").await?;
+    assert_eq!(schema, "[a:Int64, b:Int64, c:String]");
+
+    let (schema, _) = explain_scan(&ctx, "SELECT a FROM default.t WHERE c = 'x'").await?;
+    assert_eq!(schema, "[a:Int64, c:String]");
+
+    let (schema, _) = explain_scan(&ctx, "SELECT sum(b) FROM default.t GROUP BY a").await?;
+    assert_eq!(schema, "[a:Int64, b:Int64]");
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_explain_pipeline_with_settings_interpreter() -> Result<()> {
+    let ctx = crate::tests::create_query_context()?;
+    ctx.get_settings().set_max_threads(8)?;
+
+    let query = "EXPLAIN PIPELINE SELECT number FROM numbers_mt(80000) WHERE number > 1";
+    let actual = explain_lines(ctx.clone(), query).await?;
+    let expected = vec![
+        "ProjectionTransform × 8 processors",
+        "  FilterTransform × 8 processors",
+        "    SourceTransform × 8 processors",
+    ];
+    assert_eq!(expected, actual);
+
+    let query = "EXPLAIN PIPELINE SELECT number FROM numbers_mt(80000) WHERE number > 1 \
+        SETTINGS max_threads = 1";
+    let actual = explain_lines(ctx.clone(), query).await?;
+    let expected = vec![
+        "Settings: max_threads = 1",
+        "ProjectionTransform × 1 processor",
+        "  FilterTransform × 1 processor",
+        "    SourceTransform × 1 processor",
+    ];
+    assert_eq!(expected, actual);
+
+    // The SETTINGS clause only changes the query, the session keeps its own value.
+    assert_eq!(ctx.get_settings().get_max_threads()?, 1);
+    let session_settings = ctx.get_current_session().get_settings();
+    assert_eq!(session_settings.get_max_threads()?, 8);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_explain_insert_interpreter() -> Result<()> {
+    let ctx = crate::tests::create_query_context()?;
+
+    let query = "CREATE TABLE default.t(a bigint not null, b varchar) Engine = Memory";
+    let plan = PlanParser::parse(ctx.clone(), query).await?;
+    let executor = InterpreterFactory::get(ctx.clone(), plan)?;
+    executor
+        .execute(None)
+        .await?
+        .try_collect::<Vec<_>>()
+        .await?;
+
+    let query = "EXPLAIN INSERT INTO default.t SELECT number, toString(number) FROM numbers(2)";
+    let actual = explain_lines(ctx.clone(), query).await?;
+    let expected = vec![
+        "Insert into default.t, schema: [a:Int64, b:String;N], overwrite: false, source: select",
+        "  Projection: number:UInt64, toString(number):String",
+        "    Expression: number:UInt64, toString(number):String (Before Projection)",
+        "      ReadDataSource: scan schema: [number:UInt64], statistics: [read_rows: 2, read_bytes: 16, partitions_scanned: 1, partitions_total: 1], push_downs: [projections: [0]]",
+    ];
+    assert_eq!(expected, actual);
+
+    let query = "EXPLAIN INSERT INTO default.t(a) VALUES (1), (2)";
+    let actual = explain_lines(ctx.clone(), query).await?;
+    let expected =
+        vec!["Insert into default.t, schema: [a:Int64], overwrite: false, source: values, rows: 2"];
+    assert_eq!(expected, actual);
+
+    let query = "EXPLAIN INSERT INTO default.t FORMAT CSV";
+    let actual = explain_lines(ctx.clone(), query).await?;
+    let expected = vec![
+        "Insert into default.t, schema: [a:Int64, b:String;N], overwrite: false, source: streaming, format: CSV",
+    ];
+    assert_eq!(expected, actual);
+
+    // Explaining never runs the insert, the table is still empty.
+    let plan = PlanParser::parse(ctx.clone(), "SELECT count() FROM default.t").await?;
+    let executor = InterpreterFactory::get(ctx.clone(), plan)?;
+    let result = executor
+        .execute(None)
+        .await?
+        .try_collect::<Vec<_>>()
+        .await?;
+    assert_eq!(result[0].column(0).get(0).to_string(), "0");
+
+    let query = "EXPLAIN PIPELINE INSERT INTO default.t FORMAT CSV";
+    let result = PlanParser::parse(ctx.clone(), query).await;
+    assert_eq!(
+        result.unwrap_err().message(),
+        "EXPLAIN Pipeline is not supported for INSERT"
+    );
+
+    Ok(())
+}
+
+async fn explain_lines(ctx: Arc<QueryContext>, query: &str) -> Result<Vec<String>> {
+    let plan = PlanParser::parse(ctx.clone(), query).await?;
+    let executor = InterpreterFactory::get(ctx, plan)?;
+    let stream = executor.execute(None).await?;
+    let result = stream.try_collect::<Vec<_>>().await?;
+    let column = result[0].column(0);
+    Ok((0..column.len())
+        .map(|i| column.get(i).to_string())
+        .collect::<Vec<_>>())
+}
@@ -120,3 +120,263 @@ async fn test_insert_into_interpreter() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn test_insert_into_select_with_columns_interpreter() -> Result<()> {
+    let ctx = crate::tests::create_query_context()?;
+
+    // Create tables.
+    {
+        let queries = vec![
+            "create table default.src_table(a Int32, b String) Engine = Memory",
+            "create table default.dst_table(a Int64, b String, c Int32) Engine = Memory",
+            "create table default.strict_table(a Int64, c Int32 not null) Engine = Memory",
+            "insert into default.src_table values(1, 'x'), (2, 'y')",
+        ];
+        for query in queries {
+            let plan = PlanParser::parse(ctx.clone(), query).await?;
+            let executor = InterpreterFactory::get(ctx.clone(), plan.clone())?;
+            let _ = executor.execute(None).await?;
+        }
+    }
+
+    // Insert into select with a column subset.
+    {
+        let query = "insert into default.dst_table(c, a) select a + 10, a from default.src_table";
+        let plan = PlanParser::parse(ctx.clone(), query).await?;
+        let executor = InterpreterFactory::get(ctx.clone(), plan.clone())?;
+        let _ = executor.execute(None).await?;
+    }
+
+    // select.
+    {
+        let query = "select * from default.dst_table";
+        let plan = PlanParser::parse(ctx.clone(), query).await?;
+        let executor = InterpreterFactory::get(ctx.clone(), plan.clone())?;
+        let stream = executor.execute(None).await?;
+        let result = stream.try_collect::<Vec<_>>().await?;
+        let expected = vec![
+            "+---+------+----+",
+            "| a | b    | c  |",
+            "+---+------+----+",
+            "| 1 | NULL | 11 |",
+            "| 2 | NULL | 12 |",
+            "+---+------+----+",
+        ];
+        common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
+    }
+
+    // Arity mismatch.
+    {
+        let query = "insert into default.dst_table(a) select a, b from default.src_table";
+        let result = PlanParser::parse(ctx.clone(), query).await;
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err().message(),
+            "Insert into 1 columns, but the select statement returns 2 columns"
+        );
+    }
+
+    // Missing non-nullable column.
+    {
+        let query = "insert into default.strict_table(a) select a from default.src_table";
+        let result = PlanParser::parse(ctx.clone(), query).await;
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err().message(),
+            "Column c is non-nullable and has no default value, it must be specified in insert"
+        );
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_insert_into_values_interpreter() -> Result<()> {
+    let ctx = crate::tests::create_query_context()?;
+
+    // Create table.
+    {
+        let query = "create table default.typed_table(a UInt8, b Int32, c String, d Boolean) Engine = Memory";
+        let plan = PlanParser::parse(ctx.clone(), query).await?;
+        let executor = InterpreterFactory::get(ctx.clone(), plan.clone())?;
+        let _ = executor.execute(None).await?;
+    }
+
+    // Insert typed values and NULL.
+    {
+        let query = "insert into default.typed_table values(1, -2, 'x', true), (NULL, 3, NULL, NULL), ('2', 4.0, 5, false)";
+        let plan = PlanParser::parse(ctx.clone(), query).await?;
+        let executor = InterpreterFactory::get(ctx.clone(), plan.clone())?;
+        let _ = executor.execute(None).await?;
+    }
+
+    // select.
+    {
+        let query = "select * from default.typed_table where b > 0 and a is null";
+        let plan = PlanParser::parse(ctx.clone(), query).await?;
+        let executor = InterpreterFactory::get(ctx.clone(), plan.clone())?;
+        let stream = executor.execute(None).await?;
+        let result = stream.try_collect::<Vec<_>>().await?;
+        let expected = vec![
+            "+------+---+------+------+",
+            "| a    | b | c    | d    |",
+            "+------+---+------+------+",
+            "| NULL | 3 | NULL | NULL |",
+            "+------+---+------+------+",
+        ];
+        common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
+    }
+
+    // Arity mismatch.
+    {
+        let query = "insert into default.typed_table(a, b) values(1, 2), (3)";
+        let result = PlanParser::parse(ctx.clone(), query).await;
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err().message(),
+            "Insert into 2 columns, but the row 2 of values has 1 columns"
+        );
+    }
+
+    // Numeric overflow.
+    {
+        let query = "insert into default.typed_table(a, b) values(1, -2147483649)";
+        let result = PlanParser::parse(ctx.clone(), query).await;
+        assert!(result.is_err());
+
+        let error = result.unwrap_err();
+        assert_eq!(error.code(), 1010);
+        assert_eq!(
+            error.message(),
+            "Value -2147483649 is out of range of column b with type Int32"
+        );
+    }
+
+    // Invalid string value.
+    {
+        let query = "insert into default.typed_table(a, b) values('x', 1)";
+        let plan = PlanParser::parse(ctx.clone(), query).await?;
+        let executor = InterpreterFactory::get(ctx.clone(), plan.clone())?;
+        let result = executor.execute(None).await;
+        assert!(result.is_err());
+        assert!(result.err().unwrap().message().ends_with(", column: a"));
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_insert_null_into_non_nullable_boolean_interpreter() -> Result<()> {
+    let ctx = crate::tests::create_query_context()?;
+
+    // Create table.
+    {
+        let query = "create table default.bool_table(b Boolean not null) Engine = Memory";
+        let plan = PlanParser::parse(ctx.clone(), query).await?;
+        let executor = InterpreterFactory::get(ctx.clone(), plan.clone())?;
+        let _ = executor.execute(None).await?;
+    }
+
+    // Insert NULL from values.
+    {
+        let query = "insert into default.bool_table values(NULL)";
+        let result = PlanParser::parse(ctx.clone(), query).await;
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err().message(),
+            "Can not insert NULL into non-nullable column b"
+        );
+    }
+
+    // Insert NULL from select.
+    {
+        let query = "insert into default.bool_table select NULL";
+        let result = PlanParser::parse(ctx.clone(), query).await;
+        assert!(result.is_err());
+        assert!(result
+            .unwrap_err()
+            .message()
+            .ends_with("from NULL into non-nullable column b"));
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_insert_into_values_expressions_interpreter() -> Result<()> {
+    let ctx = crate::tests::create_query_context()?;
+
+    // Create table.
+    {
+        let query = "create table default.expr_table(a Int32, b UInt8, c Int64) Engine = Memory";
+        let plan = PlanParser::parse(ctx.clone(), query).await?;
+        let executor = InterpreterFactory::get(ctx.clone(), plan.clone())?;
+        let _ = executor.execute(None).await?;
+    }
+
+    // Insert expressions.
+    {
+        let query = "insert into default.expr_table values(1 + 2, CAST('7' AS UInt8), -4), (2 * 3, CAST(8 AS UInt8), -(1 + 1))";
+        let plan = PlanParser::parse(ctx.clone(), query).await?;
+        let executor = InterpreterFactory::get(ctx.clone(), plan.clone())?;
+        let _ = executor.execute(None).await?;
+    }
+
+    // select.
+    {
+        let query = "select * from default.expr_table";
+        let plan = PlanParser::parse(ctx.clone(), query).await?;
+        let executor = InterpreterFactory::get(ctx.clone(), plan.clone())?;
+        let stream = executor.execute(None).await?;
+        let result = stream.try_collect::<Vec<_>>().await?;
+        let expected = vec![
+            "+---+---+----+",
+            "| a | b | c  |",
+            "+---+---+----+",
+            "| 3 | 7 | -4 |",
+            "| 6 | 8 | -2 |",
+            "+---+---+----+",
+        ];
+        common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
+    }
+
+    // Non-constant expressions.
+    for query in [
+        "insert into default.expr_table(a) values(a + 1)",
+        "insert into default.expr_table(a) values(sum(1))",
+        "insert into default.expr_table(a) values((select 1))",
+    ] {
+        let result = PlanParser::parse(ctx.clone(), query).await;
+        assert!(result.is_err(), "{}", query);
+
+        let error = result.unwrap_err();
+        assert_eq!(error.code(), 1006);
+        assert!(error
+            .message()
+            .starts_with("Insert value of column a must be a constant expression"));
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_insert_into_table_without_append_interpreter() -> Result<()> {
+    let ctx = crate::tests::create_query_context()?;
+
+    // The insert goes through Table::append_data, engines without it return an error.
+    let query = "insert into system.settings values('a', 'b', 'c', 'd', 'e', 'f')";
+    let plan = PlanParser::parse(ctx.clone(), query).await?;
+    let executor = InterpreterFactory::get(ctx.clone(), plan.clone())?;
+    let result = executor.execute(None).await;
+    assert!(result.is_err());
+
+    let error = result.err().unwrap();
+    assert_eq!(error.code(), 1002);
+    assert_eq!(
+        error.message(),
+        "append operation for table settings is not implemented, table engine is SystemSettings"
+    );
+
+    Ok(())
+}
@@ -0,0 +1,93 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_base::tokio;
+use common_exception::Result;
+use databend_query::interpreters::*;
+use databend_query::sessions::QueryContext;
+use databend_query::sql::PlanParser;
+use futures::TryStreamExt;
+
+async fn execute_result(ctx: &Arc<QueryContext>, query: &str) -> Result<InterpreterResult> {
+    let plan = PlanParser::parse(ctx.clone(), query).await?;
+    let interpreter = InterpreterFactory::get(ctx.clone(), plan)?;
+    interpreter.execute_result(None).await
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_interpreter_result() -> Result<()> {
+    common_tracing::init_default_ut_tracing();
+    let ctx = crate::tests::create_query_context()?;
+
+    // DDL.
+    let result = execute_result(
+        &ctx,
+        "CREATE TABLE default.a(a bigint, b int) Engine = Memory",
+    )
+    .await?;
+    assert!(matches!(result, InterpreterResult::Empty));
+
+    // SET.
+    let result = execute_result(&ctx, "SET max_block_size=1").await?;
+    assert!(matches!(result, InterpreterResult::Empty));
+
+    // INSERT.
+    let result =
+        execute_result(&ctx, "INSERT INTO default.a VALUES (1, 2), (3, 4), (5, 6)").await?;
+    assert!(matches!(result, InterpreterResult::Affected(3)));
+
+    // SELECT.
+    match execute_result(&ctx, "SELECT a FROM default.a").await? {
+        InterpreterResult::ResultSet(schema, stream) => {
+            assert_eq!(schema.fields().len(), 1);
+            assert_eq!(schema.field(0).name(), "a");
+            let blocks = stream.try_collect::<Vec<_>>().await?;
+            let rows: usize = blocks.iter().map(|block| block.num_rows()).sum();
+            assert_eq!(rows, 3);
+        }
+        _ => panic!("SELECT must return a result set"),
+    }
+
+    // SHOW statements are result sets, even without any row.
+    let result = execute_result(&ctx, "SHOW TABLES").await?;
+    assert!(matches!(result, InterpreterResult::ResultSet(_, _)));
+
+    match execute_result(&ctx, "SHOW TABLES LIKE 'missing%'").await? {
+        InterpreterResult::ResultSet(schema, stream) => {
+            assert_eq!(schema.fields().len(), 1);
+            assert_eq!(schema.field(0).name(), "name");
+            let blocks = stream.try_collect::<Vec<_>>().await?;
+            let rows: usize = blocks.iter().map(|block| block.num_rows()).sum();
+            assert_eq!(rows, 0);
+        }
+        _ => panic!("SHOW TABLES must return a result set"),
+    }
+
+    // DESCRIBE declares its schema.
+    match execute_result(&ctx, "DESCRIBE default.a").await? {
+        InterpreterResult::ResultSet(schema, _) => assert_eq!(schema.fields().len(), 4),
+        _ => panic!("DESCRIBE must return a result set"),
+    }
+
+    // EXPLAIN and SHOW CREATE are classified by their schema too.
+    let result = execute_result(&ctx, "EXPLAIN SELECT a FROM default.a").await?;
+    assert!(matches!(result, InterpreterResult::ResultSet(_, _)));
+
+    let result = execute_result(&ctx, "SHOW CREATE TABLE default.a").await?;
+    assert!(matches!(result, InterpreterResult::ResultSet(_, _)));
+
+    Ok(())
+}
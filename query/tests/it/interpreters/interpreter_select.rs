@@ -13,6 +13,9 @@
 // limitations under the License.
 
 use common_base::tokio;
+use common_datablocks::DataBlock;
+use common_datavalues2::prelude::*;
+use common_exception::ErrorCode;
 use common_exception::Result;
 use databend_query::interpreters::*;
 use databend_query::sql::*;
@@ -74,5 +77,213 @@ async fn test_select_interpreter() -> Result<()> {
         ];
         common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
     }
+
+    // Min/max per group over numeric and string columns.
+    {
+        let query = "select number % 3 as k, min(number) as min_n, max(number) as max_n, \
+            min(toString(number)) as min_s, max(toString(number)) as max_s \
+            from numbers_mt(10) group by k";
+        let plan = PlanParser::parse(ctx.clone(), query).await?;
+        let executor = InterpreterFactory::get(ctx.clone(), plan)?;
+
+        let stream = executor.execute(None).await?;
+        let result = stream.try_collect::<Vec<_>>().await?;
+        let expected = vec![
+            "+---+-------+-------+-------+-------+",
+            "| k | min_n | max_n | min_s | max_s |",
+            "+---+-------+-------+-------+-------+",
+            "| 0 | 0     | 9     | 0     | 9     |",
+            "| 1 | 1     | 7     | 1     | 7     |",
+            "| 2 | 2     | 8     | 2     | 8     |",
+            "+---+-------+-------+-------+-------+",
+        ];
+        common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
+    }
+
+    // An empty SELECT list doesn't parse, so it never reaches the analyzer.
+    {
+        let query = "select from numbers_mt(10)";
+        let res = PlanParser::parse(ctx.clone(), query).await;
+        let err = res.unwrap_err();
+        assert_eq!(err.code(), ErrorCode::SyntaxExceptionCode());
+        assert_ne!(
+            err.message(),
+            "SELECT list is empty after wildcard expansion"
+        );
+    }
+
+    // Wildcard over a zero-column table.
+    {
+        let query = "create table default.t0() Engine = Memory";
+        let plan = PlanParser::parse(ctx.clone(), query).await?;
+        let executor = InterpreterFactory::get(ctx.clone(), plan)?;
+        let _ = executor.execute(None).await?;
+
+        for query in [
+            "select * from default.t0",
+            "select count() from (select * from default.t0)",
+        ] {
+            let res = PlanParser::parse(ctx.clone(), query).await;
+            let err = res.unwrap_err();
+            assert_eq!(err.code(), ErrorCode::SyntaxExceptionCode());
+            assert_eq!(
+                err.message(),
+                "SELECT list is empty after wildcard expansion"
+            );
+        }
+    }
+
+    // Uncorrelated scalar subqueries are substituted by their values.
+    {
+        let query =
+            "select number, (select max(number) from numbers_mt(5)) as m from numbers_mt(10) \
+            where number > (select min(number) + 6 from numbers_mt(3))";
+        let plan = PlanParser::parse(ctx.clone(), query).await?;
+        let executor = InterpreterFactory::get(ctx.clone(), plan)?;
+
+        let stream = executor.execute(None).await?;
+        let result = stream.try_collect::<Vec<_>>().await?;
+        let expected = vec![
+            "+--------+---+",
+            "| number | m |",
+            "+--------+---+",
+            "| 7      | 4 |",
+            "| 8      | 4 |",
+            "| 9      | 4 |",
+            "+--------+---+",
+        ];
+        common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
+
+        let query =
+            "select number from numbers_mt(10) where number = (select number from numbers_mt(2))";
+        let plan = PlanParser::parse(ctx.clone(), query).await?;
+        let executor = InterpreterFactory::get(ctx.clone(), plan)?;
+        let err = executor.execute(None).await.err().unwrap();
+        assert_eq!(err.code(), ErrorCode::ScalarSubqueryBadRowsCode());
+    }
+
+    // Top-N sort matches the full sort followed by the limit.
+    {
+        let top_n_query =
+            "select number % 7 as a, number from numbers_mt(100000) order by a desc, number limit 10 offset 3";
+        let plan = PlanParser::parse(ctx.clone(), top_n_query).await?;
+        let executor = InterpreterFactory::get(ctx.clone(), plan)?;
+        let stream = executor.execute(None).await?;
+        let top_n = stream.try_collect::<Vec<_>>().await?;
+
+        let full_sort_query =
+            "select number % 7 as a, number from numbers_mt(100000) order by a desc, number";
+        let plan = PlanParser::parse(ctx.clone(), full_sort_query).await?;
+        let executor = InterpreterFactory::get(ctx.clone(), plan)?;
+        let stream = executor.execute(None).await?;
+        let full_sort = stream.try_collect::<Vec<_>>().await?;
+        let full_sort = DataBlock::concat_blocks(&full_sort)?;
+
+        assert_eq!(
+            common_datablocks::pretty_format_blocks(&[DataBlock::slice_block(&full_sort, 3, 10)])?,
+            common_datablocks::pretty_format_blocks(&top_n)?,
+        );
+    }
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_select_interpreter_plan_complexity() -> Result<()> {
+    common_tracing::init_default_ut_tracing();
+    let ctx = crate::tests::create_query_context()?;
+    let settings = ctx.get_settings();
+    settings.set_settings("max_expression_nodes".to_string(), "100".to_string(), false)?;
+
+    // number + 1 + 1 + ... + 1
+    let nested = |n: usize| format!("select number{} from numbers_mt(10)", " + 1".repeat(n));
+
+    {
+        let plan = PlanParser::parse(ctx.clone(), &nested(10)).await?;
+        let executor = InterpreterFactory::get(ctx.clone(), plan)?;
+        let stream = executor.execute(None).await?;
+        let result = stream.try_collect::<Vec<_>>().await?;
+        assert_eq!(result.iter().map(|b| b.num_rows()).sum::<usize>(), 10);
+    }
+
+    {
+        let plan = PlanParser::parse(ctx.clone(), &nested(200)).await?;
+        let executor = InterpreterFactory::get(ctx.clone(), plan)?;
+        let err = executor.execute(None).await.err().unwrap();
+        assert_eq!(err.code(), ErrorCode::QueryTooComplexCode());
+        assert!(
+            err.message().contains("exceeds max_expression_nodes 100"),
+            "{}",
+            err.message()
+        );
+    }
+
+    {
+        settings.set_settings("max_plan_depth".to_string(), "2".to_string(), false)?;
+        let plan =
+            PlanParser::parse(ctx.clone(), "select number from numbers_mt(10) limit 1").await?;
+        let executor = InterpreterFactory::get(ctx.clone(), plan)?;
+        let err = executor.execute(None).await.err().unwrap();
+        assert_eq!(err.code(), ErrorCode::QueryTooComplexCode());
+        assert!(
+            err.message().contains("exceeds max_plan_depth 2"),
+            "{}",
+            err.message()
+        );
+    }
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_select_interpreter_table_sample() -> Result<()> {
+    common_tracing::init_default_ut_tracing();
+    let ctx = crate::tests::create_query_context()?;
+
+    let count_and_sum = |query: &'static str| {
+        let ctx = ctx.clone();
+        async move {
+            let plan = PlanParser::parse(ctx.clone(), query).await?;
+            let executor = InterpreterFactory::get(ctx.clone(), plan)?;
+            let stream = executor.execute(None).await?;
+            let result = stream.try_collect::<Vec<_>>().await?;
+            let block = &result[0];
+            let count = block.column(0).get_u64(0)?;
+            let sum = block.column(1).get_u64(0)?;
+            Result::Ok((count, sum))
+        }
+    };
+
+    {
+        let query = "select count(), sum(number) from numbers_mt(100000) tablesample (50 percent)";
+        let (count, _) = count_and_sum(query).await?;
+        assert!((45000..=55000).contains(&count), "sampled {} rows", count);
+    }
+
+    {
+        let query = "select count(), sum(number) from numbers_mt(100000) tablesample bernoulli (10) repeatable (42)";
+        let (first_count, first_sum) = count_and_sum(query).await?;
+        assert!(
+            (8000..=12000).contains(&first_count),
+            "sampled {} rows",
+            first_count
+        );
+
+        // Whichever thread reads a part, its rows are sampled the same way.
+        for _ in 0..5 {
+            assert_eq!(count_and_sum(query).await?, (first_count, first_sum));
+        }
+    }
+
+    {
+        let query = "select count(), count(number) from numbers_mt(100) tablesample (0 percent)";
+        assert_eq!(count_and_sum(query).await?, (0, 0));
+    }
+
+    {
+        let query = "select number from numbers_mt(10) tablesample (150 percent)";
+        let err = PlanParser::parse(ctx.clone(), query).await.err().unwrap();
+        assert_eq!(err.code(), ErrorCode::BadArgumentsCode());
+    }
+
     Ok(())
 }
@@ -0,0 +1,143 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_base::tokio;
+use common_exception::Result;
+use databend_query::interpreters::*;
+use databend_query::sql::PlanParser;
+use futures::TryStreamExt;
+use pretty_assertions::assert_eq;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_show_table_status_interpreter() -> Result<()> {
+    let ctx = crate::tests::create_query_context()?;
+
+    // Setup.
+    {
+        let query = "CREATE TABLE default.a(a UInt64, b String) Engine = Memory";
+        let plan = PlanParser::parse(ctx.clone(), query).await?;
+        let executor = InterpreterFactory::get(ctx.clone(), plan.clone())?;
+        let _ = executor.execute(None).await?;
+
+        let query = "INSERT INTO default.a VALUES(1, 'x'), (2, 'y'), (3, 'z')";
+        let plan = PlanParser::parse(ctx.clone(), query).await?;
+        let executor = InterpreterFactory::get(ctx.clone(), plan.clone())?;
+        let _ = executor.execute(None).await?;
+    }
+
+    // show table status.
+    {
+        let plan = PlanParser::parse(ctx.clone(), "show table status from default").await?;
+        let executor = InterpreterFactory::get(ctx.clone(), plan.clone())?;
+        assert_eq!(executor.name(), "ShowTableStatusInterpreter");
+        let stream = executor.execute(None).await?;
+        let result = stream.try_collect::<Vec<_>>().await?;
+        let block = &result[0];
+        assert_eq!(block.num_rows(), 1);
+        let names = block
+            .schema()
+            .fields()
+            .iter()
+            .map(|field| field.name().as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(names, vec![
+            "Name",
+            "Engine",
+            "Rows",
+            "Data_length",
+            "Create_time"
+        ]);
+    }
+
+    // show table status like, the pattern is a string literal.
+    {
+        for (query, rows) in [
+            ("show table status like 'a'", 1),
+            ("show table status like 'x'' or name = ''a'", 0),
+        ] {
+            let plan = PlanParser::parse(ctx.clone(), query).await?;
+            let executor = InterpreterFactory::get(ctx.clone(), plan.clone())?;
+            let stream = executor.execute(None).await?;
+            let result = stream.try_collect::<Vec<_>>().await?;
+            let num_rows = result.iter().map(|block| block.num_rows()).sum::<usize>();
+            assert_eq!(num_rows, rows, "{}", query);
+        }
+    }
+
+    // show table status where with subquery.
+    {
+        let query = "show table status where name in (select name from system.databases)";
+        let result = PlanParser::parse(ctx.clone(), query).await;
+        assert!(result.is_err());
+    }
+
+    // sizes after insert.
+    {
+        let query = "SELECT name, num_rows, num_blocks, data_bytes > 0 AS has_data \
+            FROM system.tables WHERE database = 'default' AND name = 'a'";
+        let plan = PlanParser::parse(ctx.clone(), query).await?;
+        let executor = InterpreterFactory::get(ctx.clone(), plan.clone())?;
+        let stream = executor.execute(None).await?;
+        let result = stream.try_collect::<Vec<_>>().await?;
+        let expected = vec![
+            "+------+----------+------------+----------+",
+            "| name | num_rows | num_blocks | has_data |",
+            "+------+----------+------------+----------+",
+            "| a    | 3        | 1          | true     |",
+            "+------+----------+------------+----------+",
+        ];
+        common_datablocks::assert_blocks_eq(expected, result.as_slice());
+
+        let query = "SELECT name, data_bytes > 0 AS has_data FROM system.columns \
+            WHERE database = 'default'";
+        let plan = PlanParser::parse(ctx.clone(), query).await?;
+        let executor = InterpreterFactory::get(ctx.clone(), plan.clone())?;
+        let stream = executor.execute(None).await?;
+        let result = stream.try_collect::<Vec<_>>().await?;
+        let expected = vec![
+            "+------+----------+",
+            "| name | has_data |",
+            "+------+----------+",
+            "| a    | true     |",
+            "| b    | true     |",
+            "+------+----------+",
+        ];
+        common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
+    }
+
+    // sizes after truncate.
+    {
+        let query = "TRUNCATE TABLE default.a";
+        let plan = PlanParser::parse(ctx.clone(), query).await?;
+        let executor = InterpreterFactory::get(ctx.clone(), plan.clone())?;
+        let _ = executor.execute(None).await?;
+
+        let query = "SELECT name, num_rows, num_blocks, data_bytes \
+            FROM system.tables WHERE database = 'default' AND name = 'a'";
+        let plan = PlanParser::parse(ctx.clone(), query).await?;
+        let executor = InterpreterFactory::get(ctx.clone(), plan.clone())?;
+        let stream = executor.execute(None).await?;
+        let result = stream.try_collect::<Vec<_>>().await?;
+        let expected = vec![
+            "+------+----------+------------+------------+",
+            "| name | num_rows | num_blocks | data_bytes |",
+            "+------+----------+------------+------------+",
+            "| a    | 0        | 0          | 0          |",
+            "+------+----------+------------+------------+",
+        ];
+        common_datablocks::assert_blocks_eq(expected, result.as_slice());
+    }
+
+    Ok(())
+}
@@ -76,7 +76,17 @@ async fn test_show_tables_interpreter() -> Result<()> {
         common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
     }
 
-    // show tables like '%da%'.
+    // show tables like 'b%'.
+    {
+        let plan = PlanParser::parse(ctx.clone(), "show tables like 'b%'").await?;
+        let executor = InterpreterFactory::get(ctx.clone(), plan.clone())?;
+        let stream = executor.execute(None).await?;
+        let result = stream.try_collect::<Vec<_>>().await?;
+        let expected = vec!["+------+", "| name |", "+------+", "| bend |", "+------+"];
+        common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
+    }
+
+    // show tables where name != 'data'.
     {
         let plan = PlanParser::parse(ctx.clone(), "show tables where name != 'data'").await?;
         let executor = InterpreterFactory::get(ctx.clone(), plan.clone())?;
@@ -114,6 +124,13 @@ async fn test_show_tables_interpreter() -> Result<()> {
         common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
     }
 
+    // show tables where with subquery.
+    {
+        let query = "show tables where name in (select name from system.databases)";
+        let result = PlanParser::parse(ctx.clone(), query).await;
+        assert!(result.is_err());
+    }
+
     // Teardown.
     {
         let plan = PlanParser::parse(ctx.clone(), "drop database db1").await?;
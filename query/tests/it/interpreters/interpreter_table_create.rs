@@ -17,6 +17,7 @@ use common_exception::Result;
 use databend_query::interpreters::*;
 use databend_query::sql::PlanParser;
 use futures::stream::StreamExt;
+use futures::TryStreamExt;
 
 #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
 async fn test_create_table_interpreter() -> Result<()> {
@@ -66,7 +67,7 @@ async fn test_create_table_interpreter() -> Result<()> {
             "CREATE TABLE default.test_b(a varchar, x int) select b, a from default.test_a";
 
         let plan = PlanParser::parse(ctx.clone(), TEST_CREATE_QUERY_SELECT).await?;
-        let interpreter = InterpreterFactory::get(ctx, plan.clone())?;
+        let interpreter = InterpreterFactory::get(ctx.clone(), plan.clone())?;
         let mut stream = interpreter.execute(None).await?;
         while let Some(_block) = stream.next().await {}
 
@@ -91,5 +92,90 @@ async fn test_create_table_interpreter() -> Result<()> {
         );
     }
 
+    // CTAS with IF NOT EXISTS must not insert again into an existing table.
+    {
+        static TEST_CREATE_QUERY_SELECT: &str =
+            "CREATE TABLE IF NOT EXISTS default.test_c Engine = Memory \
+            AS SELECT number, number * 2 AS doubled FROM numbers(100)";
+
+        for _ in 0..2 {
+            let plan = PlanParser::parse(ctx.clone(), TEST_CREATE_QUERY_SELECT).await?;
+            let interpreter = InterpreterFactory::get(ctx.clone(), plan)?;
+            let mut stream = interpreter.execute(None).await?;
+            while let Some(_block) = stream.next().await {}
+        }
+
+        let query = "SELECT count(), sum(doubled) FROM default.test_c";
+        let plan = PlanParser::parse(ctx.clone(), query).await?;
+        let interpreter = InterpreterFactory::get(ctx.clone(), plan)?;
+        let stream = interpreter.execute(None).await?;
+        let result = stream.try_collect::<Vec<_>>().await?;
+        let expected = vec![
+            "+---------+--------------+",
+            "| count() | sum(doubled) |",
+            "+---------+--------------+",
+            "| 100     | 9900         |",
+            "+---------+--------------+",
+        ];
+        common_datablocks::assert_blocks_eq(expected, result.as_slice());
+    }
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_create_table_options() -> Result<()> {
+    let ctx = crate::tests::create_query_context()?;
+
+    // Option names are case-insensitive.
+    {
+        let query =
+            "CREATE TABLE default.a(a int) Engine = Fuse row_per_block = 10 Block_Per_Segment = 1";
+        let plan = PlanParser::parse(ctx.clone(), query).await?;
+        let interpreter = InterpreterFactory::get(ctx.clone(), plan)?;
+        interpreter.execute(None).await?;
+
+        let table = ctx.get_table("default", "a").await?;
+        let options = table.options();
+        assert_eq!(options.get("ROW_PER_BLOCK"), Some(&"10".to_string()));
+        assert_eq!(options.get("BLOCK_PER_SEGMENT"), Some(&"1".to_string()));
+    }
+
+    // A typo in the option name.
+    {
+        let query = "CREATE TABLE default.b(a int) Engine = Fuse row_per_blok = 10";
+        let plan = PlanParser::parse(ctx.clone(), query).await?;
+        let interpreter = InterpreterFactory::get(ctx.clone(), plan)?;
+        let res = interpreter.execute(None).await;
+        assert_eq!(
+            res.err().unwrap().to_string(),
+            "Code: 1022, displayText = Unknown table option ROW_PER_BLOK for engine FUSE, valid options are: ROW_PER_BLOCK, BLOCK_PER_SEGMENT, COMMENT."
+        );
+    }
+
+    // Only COMMENT is valid for engines without options.
+    {
+        let query = "CREATE TABLE default.c(a int) Engine = Null row_per_block = 10";
+        let plan = PlanParser::parse(ctx.clone(), query).await?;
+        let interpreter = InterpreterFactory::get(ctx.clone(), plan)?;
+        let res = interpreter.execute(None).await;
+        assert_eq!(
+            res.err().unwrap().to_string(),
+            "Code: 1022, displayText = Unknown table option ROW_PER_BLOCK for engine NULL, valid options are: COMMENT."
+        );
+    }
+
+    // A value of the wrong type.
+    {
+        let query = "CREATE TABLE default.d(a int) Engine = Fuse row_per_block = 'ten'";
+        let plan = PlanParser::parse(ctx.clone(), query).await?;
+        let interpreter = InterpreterFactory::get(ctx.clone(), plan)?;
+        let res = interpreter.execute(None).await;
+        assert_eq!(
+            res.err().unwrap().to_string(),
+            "Code: 1022, displayText = Table option ROW_PER_BLOCK expects an unsigned integer, but got 'ten'."
+        );
+    }
+
     Ok(())
 }
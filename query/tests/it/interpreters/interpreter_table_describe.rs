@@ -45,18 +45,70 @@ async fn interpreter_describe_table_test() -> Result<()> {
         let stream = executor.execute(None).await?;
         let result = stream.try_collect::<Vec<_>>().await?;
         let expected = vec![
-            "+-------+--------+------+",
-            "| Field | Type   | Null |",
-            "+-------+--------+------+",
-            "| a     | Int64  | YES  |",
-            "| b     | Int32  | YES  |",
-            "| c     | String | YES  |",
-            "| d     | Int16  | YES  |",
-            "| e     | Date16 | YES  |",
-            "+-------+--------+------+",
+            "+-------+--------+------+---------+",
+            "| Field | Type   | Null | Comment |",
+            "+-------+--------+------+---------+",
+            "| a     | Int64  | YES  |         |",
+            "| b     | Int32  | YES  |         |",
+            "| c     | String | YES  |         |",
+            "| d     | Int16  | YES  |         |",
+            "| e     | Date16 | YES  |         |",
+            "+-------+--------+------+---------+",
         ];
         common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
     }
 
+    // describe qualified table.
+    {
+        let plan = PlanParser::parse(ctx.clone(), "DESC default.a").await?;
+        let executor = InterpreterFactory::get(ctx.clone(), plan.clone())?;
+        let stream = executor.execute(None).await?;
+        let result = stream.try_collect::<Vec<_>>().await?;
+        let expected = vec![
+            "+-------+--------+------+---------+",
+            "| Field | Type   | Null | Comment |",
+            "+-------+--------+------+---------+",
+            "| a     | Int64  | YES  |         |",
+            "| b     | Int32  | YES  |         |",
+            "| c     | String | YES  |         |",
+            "| d     | Int16  | YES  |         |",
+            "| e     | Date16 | YES  |         |",
+            "+-------+--------+------+---------+",
+        ];
+        common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
+    }
+
+    // describe table with column comments.
+    {
+        let query = "CREATE TABLE default.b(a bigint COMMENT 'id', b int) Engine = Null";
+        let plan = PlanParser::parse(ctx.clone(), query).await?;
+        let executor = InterpreterFactory::get(ctx.clone(), plan.clone())?;
+        let _ = executor.execute(None).await?;
+
+        let plan = PlanParser::parse(ctx.clone(), "DESC b").await?;
+        let executor = InterpreterFactory::get(ctx.clone(), plan.clone())?;
+        let stream = executor.execute(None).await?;
+        let result = stream.try_collect::<Vec<_>>().await?;
+        let expected = vec![
+            "+-------+-------+------+---------+",
+            "| Field | Type  | Null | Comment |",
+            "+-------+-------+------+---------+",
+            "| a     | Int64 | YES  | id      |",
+            "| b     | Int32 | YES  |         |",
+            "+-------+-------+------+---------+",
+        ];
+        common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
+    }
+
+    // describe invalid table name.
+    {
+        let result = PlanParser::parse(ctx.clone(), "DESC a.b.c").await;
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err().message(),
+            "Desc table name must be [`db`].`table`"
+        );
+    }
+
     Ok(())
 }
@@ -13,9 +13,12 @@
 // limitations under the License.
 
 use common_base::tokio;
+use common_exception::ErrorCode;
 use common_exception::Result;
 use databend_query::interpreters::*;
 use databend_query::sql::PlanParser;
+use databend_query::storages::ToReadDataSourcePlan;
+use futures::StreamExt;
 use futures::TryStreamExt;
 use pretty_assertions::assert_eq;
 
@@ -49,3 +52,70 @@ async fn test_drop_table_interpreter() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn test_drop_table_during_scan() -> Result<()> {
+    let ctx = crate::tests::create_query_context()?;
+
+    // Create table.
+    {
+        let query = "CREATE TABLE default.t(a bigint) Engine = Memory";
+        let plan = PlanParser::parse(ctx.clone(), query).await?;
+        let executor = InterpreterFactory::get(ctx.clone(), plan.clone())?;
+        let _ = executor.execute(None).await?;
+    }
+
+    // Insert into, one block per insert.
+    for _ in 0..4 {
+        let query = "INSERT INTO default.t VALUES(1),(2)";
+        let plan = PlanParser::parse(ctx.clone(), query).await?;
+        let executor = InterpreterFactory::get(ctx.clone(), plan.clone())?;
+        let _ = executor.execute(None).await?;
+    }
+
+    let table = ctx.get_table("default", "t").await?;
+
+    // Start a scan, drop the table mid-way and drain the rest.
+    {
+        let plan = PlanParser::parse(ctx.clone(), "SELECT * FROM default.t").await?;
+        let executor = InterpreterFactory::get(ctx.clone(), plan.clone())?;
+        let mut stream = executor.execute(None).await?;
+
+        let mut rows = match stream.next().await {
+            Some(block) => block?.num_rows(),
+            None => 0,
+        };
+
+        let plan = PlanParser::parse(ctx.clone(), "DROP TABLE default.t").await?;
+        let executor = InterpreterFactory::get(ctx.clone(), plan.clone())?;
+        let _ = executor.execute(None).await?;
+
+        // Blocks already materialized by the memory engine are still delivered.
+        while let Some(block) = stream.next().await {
+            rows += block?.num_rows();
+        }
+        assert_eq!(rows, 8);
+    }
+
+    // A read plan built from the stale table instance is rejected.
+    {
+        let result = table.read_plan(ctx.clone(), None).await;
+        assert!(result.is_err());
+        assert_eq!(result.unwrap_err().code(), ErrorCode::UnknownTableCode());
+    }
+
+    // A new scan fails cleanly instead of reading the dropped table.
+    {
+        let result = async {
+            let plan = PlanParser::parse(ctx.clone(), "SELECT * FROM default.t").await?;
+            let executor = InterpreterFactory::get(ctx.clone(), plan.clone())?;
+            let stream = executor.execute(None).await?;
+            stream.try_collect::<Vec<_>>().await
+        }
+        .await;
+        assert!(result.is_err());
+        assert_eq!(result.unwrap_err().code(), ErrorCode::UnknownTableCode());
+    }
+
+    Ok(())
+}
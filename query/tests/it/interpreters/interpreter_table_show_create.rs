@@ -59,5 +59,58 @@ async fn interpreter_show_create_table_test() -> Result<()> {
         common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
     }
 
+    // Quotes in option values are escaped, so the statement can be replayed.
+    {
+        let query = "CREATE TABLE default.b(a bigint) Engine = Null COMMENT = 'it''s \"b\"'";
+        let plan = PlanParser::parse(ctx.clone(), query).await?;
+        let executor = InterpreterFactory::get(ctx.clone(), plan.clone())?;
+        let _ = executor.execute(None).await?;
+
+        let table = ctx.get_table("default", "b").await?;
+        assert_eq!(
+            table.options().get("COMMENT"),
+            Some(&r#"it's "b""#.to_string())
+        );
+
+        let plan = PlanParser::parse(ctx.clone(), "SHOW CREATE TABLE b").await?;
+        let executor = InterpreterFactory::get(ctx.clone(), plan.clone())?;
+        let stream = executor.execute(None).await?;
+        let result = stream.try_collect::<Vec<_>>().await?;
+        let expected = vec![
+            "+-------+-----------------------------------+",
+            "| Table | Create Table                      |",
+            "+-------+-----------------------------------+",
+            "| b     | CREATE TABLE `b` (                |",
+            "|       |   `a` Int64,                      |",
+            r#"|       | ) ENGINE=Null COMMENT='it''s "b"' |"#,
+            "+-------+-----------------------------------+",
+        ];
+        common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
+    }
+
+    // Column comments are kept in the column definitions.
+    {
+        let query = "CREATE TABLE default.c(a bigint COMMENT 'id', b int) Engine = Null";
+        let plan = PlanParser::parse(ctx.clone(), query).await?;
+        let executor = InterpreterFactory::get(ctx.clone(), plan.clone())?;
+        let _ = executor.execute(None).await?;
+
+        let plan = PlanParser::parse(ctx.clone(), "SHOW CREATE TABLE c").await?;
+        let executor = InterpreterFactory::get(ctx.clone(), plan.clone())?;
+        let stream = executor.execute(None).await?;
+        let result = stream.try_collect::<Vec<_>>().await?;
+        let expected = vec![
+            "+-------+---------------------------+",
+            "| Table | Create Table              |",
+            "+-------+---------------------------+",
+            "| c     | CREATE TABLE `c` (        |",
+            "|       |   `a` Int64 COMMENT 'id', |",
+            "|       |   `b` Int32,              |",
+            "|       | ) ENGINE=Null             |",
+            "+-------+---------------------------+",
+        ];
+        common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
+    }
+
     Ok(())
 }
@@ -20,6 +20,7 @@ mod interpreter_database_show_create;
 mod interpreter_explain;
 mod interpreter_factory_interceptor;
 mod interpreter_insert;
+mod interpreter_result;
 mod interpreter_select;
 mod interpreter_setting;
 mod interpreter_show_databases;
@@ -28,6 +29,7 @@ mod interpreter_show_functions;
 mod interpreter_show_metrics;
 mod interpreter_show_processlist;
 mod interpreter_show_settings;
+mod interpreter_show_table_status;
 mod interpreter_show_tables;
 mod interpreter_show_users;
 mod interpreter_table_create;
@@ -21,6 +21,7 @@ use common_planners::*;
 use databend_query::api::FlightAction;
 use databend_query::interpreters::PlanScheduler;
 use databend_query::sessions::QueryContext;
+use databend_query::sessions::QueryStageState;
 
 use crate::tests::create_query_context_with_cluster;
 use crate::tests::ClusterDescriptor;
@@ -137,6 +138,7 @@ async fn test_scheduler_plan_with_convergent_and_expansive_stage() -> Result<()>
                     scatters_expr: Expression::ScalarFunction {
                         op: String::from("blockNumber"),
                         args: vec![],
+                        ordinal: None,
                     },
                     input: Arc::new(PlanNode::Empty(EmptyPlan::create())),
                 })),
@@ -163,6 +165,7 @@ async fn test_scheduler_plan_with_convergent_and_expansive_stage() -> Result<()>
         Expression::ScalarFunction {
             op: String::from("blockNumber"),
             args: vec![],
+            ordinal: None,
         }
     );
     assert_eq!(
@@ -324,6 +327,170 @@ async fn test_scheduler_plan_with_convergent_and_normal_stage() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_scheduler_plan_with_cluster_union() -> Result<()> {
+    // The inputs run in cluster mode, so each node unions its own part of them.
+    let context = create_env().await?;
+    let scheduler = PlanScheduler::try_create(context)?;
+    let cluster_input = Arc::new(PlanNode::Empty(EmptyPlan::cluster()));
+    let union_plan = PlanNode::Union(UnionPlan {
+        schema: cluster_input.schema(),
+        inputs: vec![cluster_input.clone(), cluster_input.clone()],
+    });
+    let scheduled_tasks = scheduler.reschedule(&PlanNode::Stage(StagePlan {
+        kind: StageKind::Convergent,
+        scatters_expr: Expression::create_literal(DataValue::UInt64(0)),
+        input: Arc::new(union_plan.clone()),
+    }))?;
+
+    let mut remote_actions = vec![];
+    for (node, remote_action) in scheduled_tasks.get_tasks()? {
+        match remote_action {
+            FlightAction::CancelAction(_) => panic!(),
+            FlightAction::BroadcastAction(_) => panic!(),
+            FlightAction::PrepareShuffleAction(action) => remote_actions.push((node, action)),
+        }
+    }
+
+    assert_eq!(remote_actions.len(), 2);
+    assert_eq!(remote_actions[0].0.id, String::from("dummy_local"));
+    assert_eq!(remote_actions[0].1.plan, union_plan);
+    assert_eq!(remote_actions[1].0.id, String::from("dummy"));
+    assert_eq!(remote_actions[1].1.plan, union_plan);
+
+    match scheduled_tasks.get_local_task() {
+        PlanNode::Remote(plan) => {
+            assert_eq!(plan.stream_id, "dummy_local");
+            assert_eq!(plan.fetch_nodes, ["dummy_local", "dummy"]);
+        }
+        _ => panic!("test_scheduler_plan_with_cluster_union must be have Remote plan!"),
+    }
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_scheduler_plan_with_converged_union_input() -> Result<()> {
+    // The cluster input is converged, so the union runs on the local node only.
+    let context = create_env().await?;
+    let scheduler = PlanScheduler::try_create(context)?;
+    let standalone_input = Arc::new(PlanNode::Empty(EmptyPlan::create()));
+    let scheduled_tasks = scheduler.reschedule(&PlanNode::Union(UnionPlan {
+        schema: standalone_input.schema(),
+        inputs: vec![
+            Arc::new(PlanNode::Stage(StagePlan {
+                kind: StageKind::Convergent,
+                scatters_expr: Expression::create_literal(DataValue::UInt64(0)),
+                input: Arc::new(PlanNode::Empty(EmptyPlan::cluster())),
+            })),
+            standalone_input.clone(),
+        ],
+    }))?;
+
+    let mut remote_actions = vec![];
+    for (node, remote_action) in scheduled_tasks.get_tasks()? {
+        match remote_action {
+            FlightAction::CancelAction(_) => panic!(),
+            FlightAction::BroadcastAction(_) => panic!(),
+            FlightAction::PrepareShuffleAction(action) => remote_actions.push((node, action)),
+        }
+    }
+
+    assert_eq!(remote_actions.len(), 2);
+    assert_eq!(remote_actions[0].1.sinks, vec![String::from("dummy_local")]);
+    assert_eq!(
+        remote_actions[0].1.plan,
+        PlanNode::Empty(EmptyPlan::cluster())
+    );
+    assert_eq!(remote_actions[1].1.sinks, vec![String::from("dummy_local")]);
+    assert_eq!(
+        remote_actions[1].1.plan,
+        PlanNode::Empty(EmptyPlan::cluster())
+    );
+
+    match scheduled_tasks.get_local_task() {
+        PlanNode::Union(plan) => {
+            assert_eq!(plan.inputs.len(), 2);
+            match plan.inputs[0].as_ref() {
+                PlanNode::Remote(plan) => {
+                    assert_eq!(plan.stream_id, "dummy_local");
+                    assert_eq!(plan.fetch_nodes, ["dummy_local", "dummy"]);
+                }
+                _ => panic!(
+                    "test_scheduler_plan_with_converged_union_input must be have Remote plan!"
+                ),
+            }
+            assert_eq!(plan.inputs[1], standalone_input);
+        }
+        _ => panic!("test_scheduler_plan_with_converged_union_input must be have Union plan!"),
+    }
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_scheduler_plan_stage_ids() -> Result<()> {
+    let context = create_env().await?;
+    let scheduler = PlanScheduler::try_create(context.clone())?;
+    let scheduled_tasks = scheduler.reschedule(&PlanNode::Select(SelectPlan {
+        input: Arc::new(PlanNode::Stage(StagePlan {
+            kind: StageKind::Convergent,
+            scatters_expr: Expression::create_literal(DataValue::UInt64(0)),
+            input: Arc::new(PlanNode::Select(SelectPlan {
+                input: Arc::new(PlanNode::Stage(StagePlan {
+                    kind: StageKind::Expansive,
+                    scatters_expr: Expression::ScalarFunction {
+                        op: String::from("blockNumber"),
+                        args: vec![],
+                        ordinal: None,
+                    },
+                    input: Arc::new(PlanNode::Empty(EmptyPlan::create())),
+                })),
+            })),
+        })),
+    }))?;
+
+    let mut stage_ids = vec![];
+    for (_node, remote_action) in scheduled_tasks.get_tasks()? {
+        assert_eq!(remote_action.get_query_id(), context.get_id());
+        stage_ids.push(remote_action.get_stage_id());
+    }
+    assert_eq!(stage_ids, vec!["1", "2", "2"]);
+
+    match scheduled_tasks.get_local_task() {
+        PlanNode::Select(plan) => match plan.input.as_ref() {
+            PlanNode::Remote(plan) => {
+                assert_eq!(plan.query_id, context.get_id());
+                assert_eq!(plan.stage_id, "2");
+            }
+            _ => panic!("test_scheduler_plan_stage_ids must be have Remote plan!"),
+        },
+        _ => panic!("test_scheduler_plan_stage_ids must be have Select plan!"),
+    }
+
+    // The stages are recorded in the coordinator.
+    context.add_query_stage_progress("2", 10, 100);
+    let query_stages = context.get_query_stages();
+    assert_eq!(query_stages.len(), 2);
+    assert_eq!(query_stages[0].query_id, context.get_id());
+    assert_eq!(query_stages[0].stage_id, "1");
+    assert_eq!(query_stages[0].kind, "Expansive");
+    assert_eq!(query_stages[0].nodes, vec!["dummy_local", "dummy"]);
+    assert_eq!(query_stages[0].state, QueryStageState::Scheduled);
+    assert_eq!(query_stages[0].read_rows, 0);
+    assert_eq!(query_stages[1].stage_id, "2");
+    assert_eq!(query_stages[1].kind, "Convergent");
+    assert_eq!(query_stages[1].read_rows, 10);
+    assert_eq!(query_stages[1].read_bytes, 100);
+
+    context.set_query_stages_state(QueryStageState::Finished);
+    let query_stages = context.get_query_stages();
+    assert_eq!(query_stages[0].state, QueryStageState::Finished);
+    assert_eq!(query_stages[1].state, QueryStageState::Finished);
+
+    Ok(())
+}
+
 async fn create_env() -> Result<Arc<QueryContext>> {
     create_query_context_with_cluster(
         ClusterDescriptor::new()
@@ -58,7 +58,7 @@ async fn test_literal_false_filter() -> Result<()> {
     let expect = "\
         Projection: number:UInt64\
         \n  Filter: false\
-        \n    ReadDataSource: scan schema: [number:UInt64], statistics: [read_rows: 0, read_bytes: 0, partitions_scanned: 0, partitions_total: 0], push_downs: [projections: [0], filters: [((1 + 2) = 2)]]";
+        \n    ReadDataSource: scan schema: [number:UInt64], statistics: [read_rows: 0, read_bytes: 0, partitions_scanned: 0, partitions_total: 0], push_downs: [projections: [0], filters: [false]]";
 
     assert_eq!(actual, expect);
     Ok(())
@@ -79,7 +79,7 @@ async fn test_skip_read_data_source() -> Result<()> {
                 expect:"\
                 Projection: number:UInt64\
                 \n  Filter: false\
-                \n    ReadDataSource: scan schema: [number:UInt64], statistics: [read_rows: 0, read_bytes: 0, partitions_scanned: 0, partitions_total: 0], push_downs: [projections: [0], filters: [((1 + 2) = 2)]]",
+                \n    ReadDataSource: scan schema: [number:UInt64], statistics: [read_rows: 0, read_bytes: 0, partitions_scanned: 0, partitions_total: 0], push_downs: [projections: [0], filters: [false]]",
             },
             Test {
                 name: "Limit with zero should skip the scan",
@@ -98,6 +98,30 @@ async fn test_constant_folding_optimizer() -> Result<()> {
                 \n  Expression: String:String (Before Projection)\
                 \n    ReadDataSource: scan schema: [dummy:UInt8], statistics: [read_rows: 1, read_bytes: 1, partitions_scanned: 1, partitions_total: 1], push_downs: [projections: [0]]",
             },
+            Test {
+                name: "Projection non const with const argument",
+                query: "SELECT dummy + (1 + 2)",
+                expect: "\
+                Projection: (dummy + (1 + 2)):UInt32\
+                \n  Expression: (dummy + 3):UInt32 (Before Projection)\
+                \n    ReadDataSource: scan schema: [dummy:UInt8], statistics: [read_rows: 1, read_bytes: 1, partitions_scanned: 1, partitions_total: 1], push_downs: [projections: [0]]",
+            },
+            Test {
+                name: "Projection alias const recursion",
+                query: "SELECT 1 + 2 AS three",
+                expect: "\
+                Projection: (1 + 2) as three:UInt16\
+                \n  Expression: 3:UInt16 (Before Projection)\
+                \n    ReadDataSource: scan schema: [dummy:UInt8], statistics: [read_rows: 1, read_bytes: 1, partitions_scanned: 1, partitions_total: 1], push_downs: [projections: [0]]",
+            },
+            Test {
+                name: "Projection non deterministic function",
+                query: "SELECT rand() + (1 + 2)",
+                expect: "\
+                Projection: (rand() + (1 + 2)):Float64\
+                \n  Expression: (rand() + 3):Float64 (Before Projection)\
+                \n    ReadDataSource: scan schema: [dummy:UInt8], statistics: [read_rows: 1, read_bytes: 1, partitions_scanned: 1, partitions_total: 1], push_downs: [projections: [0]]",
+            },
         ];
 
     for test in tests {
@@ -111,3 +135,51 @@ async fn test_constant_folding_optimizer() -> Result<()> {
     }
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_constant_folding_optimizer_error() -> Result<()> {
+    struct Test {
+        name: &'static str,
+        query: &'static str,
+        error: Option<&'static str>,
+    }
+
+    let tests: Vec<Test> = vec![
+        Test {
+            name: "Projection const error",
+            query: "SELECT intDiv(1, 0) + 1",
+            error: Some("Code: 1006, displayText = Division by zero."),
+        },
+        Test {
+            name: "Filter const error",
+            query: "SELECT number FROM numbers(0) WHERE intDiv(1, 0) = 1",
+            error: Some("Code: 1006, displayText = Division by zero."),
+        },
+        Test {
+            name: "Coalesce first argument error",
+            query: "SELECT COALESCE(intDiv(1, 0), 1)",
+            error: Some("Code: 1006, displayText = Division by zero."),
+        },
+        Test {
+            name: "Coalesce fallback argument error",
+            query: "SELECT COALESCE(1, intDiv(1, 0) + 1)",
+            error: None,
+        },
+    ];
+
+    for test in tests {
+        let ctx = crate::tests::create_query_context()?;
+
+        let plan = PlanParser::parse(ctx.clone(), test.query).await?;
+        let mut optimizer = ConstantFoldingOptimizer::create(ctx);
+        let optimized = optimizer.optimize(&plan);
+        match test.error {
+            Some(error) => {
+                let cause = optimized.unwrap_err();
+                assert_eq!(error, cause.to_string(), "{:#?}", test.name);
+            }
+            None => assert!(optimized.is_ok(), "{:#?}", test.name),
+        }
+    }
+    Ok(())
+}
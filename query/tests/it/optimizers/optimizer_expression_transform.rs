@@ -179,6 +179,22 @@ async fn test_expression_transform_optimizer() -> Result<()> {
                 \n  Filter: true\
                 \n    ReadDataSource: scan schema: [number:UInt64], statistics: [read_rows: 10, read_bytes: 80, partitions_scanned: 1, partitions_total: 1], push_downs: [projections: [0], filters: [((number > 1) OR true)]]",
             },
+            Test {
+                name: "Reorder conjunctions by cost",
+                query: "select number from numbers_mt(10) where toBoolean(number) and number = 5",
+                expect: "\
+                Projection: number:UInt64\
+                \n  Filter: ((number = 5) and toBoolean(number))\
+                \n    ReadDataSource: scan schema: [number:UInt64], statistics: [read_rows: 10, read_bytes: 80, partitions_scanned: 1, partitions_total: 1], push_downs: [projections: [0], filters: [(toBoolean(number) AND (number = 5))]]",
+            },
+            Test {
+                name: "Reorder conjunctions by selectivity",
+                query: "select number from numbers_mt(10) where number > 1 and number < 8 and number = 5",
+                expect: "\
+                Projection: number:UInt64\
+                \n  Filter: (((number = 5) and (number > 1)) and (number < 8))\
+                \n    ReadDataSource: scan schema: [number:UInt64], statistics: [read_rows: 10, read_bytes: 80, partitions_scanned: 1, partitions_total: 1], push_downs: [projections: [0], filters: [(((number > 1) AND (number < 8)) AND (number = 5))]]",
+            },
             Test {
                 name: "Projection logics const",
                 query: "SELECT 1 = 1 and 2 > 1",
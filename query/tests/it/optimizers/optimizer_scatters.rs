@@ -152,6 +152,28 @@ async fn test_scatter_optimizer() -> Result<()> {
             \n      AggregatorPartial: groupBy=[[]], aggr=[[SUM(number)]]\
             \n        ReadDataSource: scan schema: [number:UInt64], statistics: [read_rows: 100000000, read_bytes: 800000000, partitions_scanned: 10001, partitions_total: 10001], push_downs: [projections: [0]]",
         },
+        Test {
+            name: "Cluster union query",
+            query: "SELECT number FROM numbers(100000000) UNION ALL SELECT number FROM numbers(100000000)",
+            expect: "\
+            RedistributeStage[expr: 0]\
+            \n  UnionAll\
+            \n    Projection: number:UInt64\
+            \n      ReadDataSource: scan schema: [number:UInt64], statistics: [read_rows: 100000000, read_bytes: 800000000, partitions_scanned: 10001, partitions_total: 10001], push_downs: [projections: [0]]\
+            \n    Projection: number:UInt64\
+            \n      ReadDataSource: scan schema: [number:UInt64], statistics: [read_rows: 100000000, read_bytes: 800000000, partitions_scanned: 10001, partitions_total: 10001], push_downs: [projections: [0]]",
+        },
+        Test {
+            name: "Cluster and standalone union query",
+            query: "SELECT number FROM numbers(100000000) UNION ALL SELECT number FROM numbers_local(100)",
+            expect: "\
+            UnionAll\
+            \n  RedistributeStage[expr: 0]\
+            \n    Projection: number:UInt64\
+            \n      ReadDataSource: scan schema: [number:UInt64], statistics: [read_rows: 100000000, read_bytes: 800000000, partitions_scanned: 10001, partitions_total: 10001], push_downs: [projections: [0]]\
+            \n  Projection: number:UInt64\
+            \n    ReadDataSource: scan schema: [number:UInt64], statistics: [read_rows: 100, read_bytes: 800, partitions_scanned: 1, partitions_total: 1], push_downs: [projections: [0]]",
+        },
         Test {
             name: "Standalone query with standalone subquery",
             query: "SELECT * FROM numbers_local(1) WHERE EXISTS(SELECT * FROM numbers_local(1))",
@@ -30,7 +30,7 @@ async fn test_simple() -> Result<()> {
     let expect = "\
     Limit: 10\
     \n  Projection: number:UInt64\
-    \n    Sort: number:UInt64\
+    \n    Sort: number:UInt64 (limit: 10)\
     \n      ReadDataSource: scan schema: [number:UInt64], statistics: [read_rows: 1000, read_bytes: 8000, partitions_scanned: 1, partitions_total: 1], push_downs: [projections: [0], limit: 10, order_by: [number]]";
 
     let actual = format!("{:?}", plan_node);
@@ -51,7 +51,7 @@ async fn test_simple_with_offset() -> Result<()> {
     let expect = "\
     Limit: 10, 5\
     \n  Projection: number:UInt64\
-    \n    Sort: number:UInt64\
+    \n    Sort: number:UInt64 (limit: 15)\
     \n      ReadDataSource: scan schema: [number:UInt64], statistics: [read_rows: 1000, read_bytes: 8000, partitions_scanned: 1, partitions_total: 1], push_downs: [projections: [0], limit: 15, order_by: [number]]";
 
     let actual = format!("{:?}", plan_node);
@@ -75,7 +75,7 @@ async fn test_nested_projection() -> Result<()> {
     \n  Projection: number:UInt64\
     \n    Limit: 11\
     \n      Projection: number:UInt64\
-    \n        Sort: number:UInt64\
+    \n        Sort: number:UInt64 (limit: 11)\
     \n          ReadDataSource: scan schema: [number:UInt64], statistics: [read_rows: 1000, read_bytes: 8000, partitions_scanned: 1, partitions_total: 1], push_downs: [projections: [0], limit: 11, order_by: [number]]";
 
     let actual = format!("{:?}", plan_node);
@@ -97,7 +97,7 @@ async fn test_aggregate() -> Result<()> {
     let expect = "\
     Limit: 5\
     \n  Projection: sum(number):UInt64\
-    \n    Sort: sum(number):UInt64\
+    \n    Sort: sum(number):UInt64 (limit: 5)\
     \n      AggregatorFinal: groupBy=[[(number % 10)]], aggr=[[sum(number)]]\
     \n        AggregatorPartial: groupBy=[[(number % 10)]], aggr=[[sum(number)]]\
     \n          Expression: (number % 10):UInt8, number:UInt64 (Before GroupBy)\
@@ -108,6 +108,29 @@ async fn test_aggregate() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_limit_by() -> Result<()> {
+    let query = "select number from numbers(1000) order by number limit 1 by number % 10 limit 5;";
+    let ctx = crate::tests::create_query_context()?;
+
+    let plan = PlanParser::parse(ctx.clone(), query).await?;
+
+    let mut optimizer = TopNPushDownOptimizer::create(ctx);
+    let plan_node = optimizer.optimize(&plan)?;
+
+    let expect = "\
+    Limit: 5\
+    \n  Projection: number:UInt64\
+    \n    LimitBy: 1 by [(number % 10)]\
+    \n      Sort: number:UInt64\
+    \n        Expression: number:UInt64, (number % 10):UInt8 (Before OrderBy)\
+    \n          ReadDataSource: scan schema: [number:UInt64], statistics: [read_rows: 1000, read_bytes: 8000, partitions_scanned: 1, partitions_total: 1], push_downs: [projections: [0]]";
+
+    let actual = format!("{:?}", plan_node);
+    assert_eq!(expect, actual);
+    Ok(())
+}
+
 #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
 async fn test_monotonic_function() -> Result<()> {
     struct Test {
@@ -133,7 +156,7 @@ async fn test_monotonic_function() -> Result<()> {
             expect: "\
             Limit: 10\
             \n  Projection: (number * number):UInt64\
-            \n    Sort: ((number + number) + 3):UInt64\
+            \n    Sort: ((number + number) + 3):UInt64 (limit: 10)\
             \n      Expression: (number * number):UInt64, ((number + number) + 3):UInt64 (Before OrderBy)\
             \n        ReadDataSource: scan schema: [number:UInt64], statistics: [read_rows: 100, read_bytes: 800, partitions_scanned: 1, partitions_total: 1], push_downs: [projections: [0], limit: 10, order_by: [((number + number) + 3)]]",
         },
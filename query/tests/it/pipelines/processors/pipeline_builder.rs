@@ -191,3 +191,45 @@ async fn test_local_pipeline_builds() -> Result<()> {
     }
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_local_pipeline_operator_threads() -> Result<()> {
+    let ctx = crate::tests::create_query_context()?;
+    ctx.get_settings()
+        .set_settings("max_sort_threads".to_string(), "2".to_string(), false)?;
+    ctx.get_settings()
+        .set_settings("max_aggregate_threads".to_string(), "3".to_string(), false)?;
+
+    // Sort runs on max_sort_threads processors.
+    {
+        let query = "select number from numbers_mt(10) order by number";
+        let plan = PlanParser::parse(ctx.clone(), query).await?;
+        let pipeline = PipelineBuilder::create(ctx.clone()).build(&plan)?;
+        let expect = "\
+            ProjectionTransform × 1 processor\
+            \n  SortMergeTransform × 1 processor\
+            \n    Merge (SortMergeTransform × 2 processors) to (SortMergeTransform × 1)\
+            \n      SortMergeTransform × 2 processors\
+            \n        SortPartialTransform × 2 processors\
+            \n          Mixed (SourceTransform × 8 processors) to (SortPartialTransform × 2 processors)\
+            \n            SourceTransform × 8 processors";
+        let actual = format!("{:?}", pipeline);
+        assert_eq!(expect, actual);
+    }
+
+    // Aggregation runs on max_aggregate_threads processors.
+    {
+        let query = "select sum(number) from numbers_mt(10) group by number % 3";
+        let plan = PlanParser::parse(ctx.clone(), query).await?;
+        let pipeline = PipelineBuilder::create(ctx.clone()).build(&plan)?;
+        let actual = format!("{:?}", pipeline);
+        assert!(
+            actual.contains("GroupByPartialTransform × 3 processors"),
+            "{}",
+            actual
+        );
+        assert!(!actual.contains("× 8 processors) to (GroupByPartialTransform"));
+    }
+
+    Ok(())
+}
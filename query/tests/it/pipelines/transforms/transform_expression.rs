@@ -15,10 +15,13 @@
 use std::sync::Arc;
 
 use common_base::tokio;
+use common_datablocks::DataBlock;
+use common_datavalues2::prelude::*;
 use common_exception::Result;
 use common_planners::*;
 use databend_query::pipelines::processors::*;
 use databend_query::pipelines::transforms::*;
+use databend_query::sql::PlanParser;
 use futures::TryStreamExt;
 use pretty_assertions::assert_eq;
 
@@ -141,3 +144,161 @@ async fn test_transform_expression_issue2857() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_expression_executor_cached_coalesce() -> Result<()> {
+    let schema = DataSchemaRefExt::create(vec![
+        DataField::new_nullable("a", u64::to_data_type()),
+        DataField::new_nullable("b", u64::to_data_type()),
+    ]);
+    let expr = Expression::create_scalar_function("coalesce", vec![
+        col("a"),
+        add(col("b"), lit(1u8)),
+        lit(0u8),
+    ]);
+    let output_schema = DataSchemaRefExt::create(vec![expr.to_data_field(&schema)?]);
+    let create_executor = || {
+        ExpressionExecutor::try_create(
+            "test",
+            schema.clone(),
+            output_schema.clone(),
+            vec![expr.clone()],
+            false,
+        )
+    };
+
+    // One executor for all the blocks must give the same results as one executor per block.
+    let cached = create_executor()?;
+    for i in 0..16u64 {
+        let block = DataBlock::create(schema.clone(), vec![
+            Series::from_data(vec![Some(i), None, None, Some(i * 2)]),
+            Series::from_data(vec![None, Some(i), None, Some(i * 3)]),
+        ]);
+
+        let actual = cached.execute(&block)?;
+        let expect = create_executor()?.execute(&block)?;
+        assert_eq!(actual.num_rows(), 4);
+        assert_eq!(actual.column(0).to_values(), expect.column(0).to_values());
+        assert_eq!(actual.column(0).to_values(), vec![
+            DataValue::UInt64(i),
+            DataValue::UInt64(i + 1),
+            DataValue::UInt64(0),
+            DataValue::UInt64(i * 2),
+        ]);
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_expression_executor_multi_if_short_circuit() -> Result<()> {
+    let schema = DataSchemaRefExt::create(vec![DataField::new("a", u64::to_data_type())]);
+    let divide =
+        |dividend: u64| Expression::create_scalar_function("intDiv", vec![lit(dividend), col("a")]);
+    // A value or a condition is never evaluated for a row with a = 0.
+    let expr = Expression::create_scalar_function("multiIf", vec![
+        col("a").eq(lit(0u8)),
+        lit(0u8),
+        divide(10).gt(lit(2u8)),
+        lit(1u8),
+        divide(100),
+    ]);
+    let output_schema = DataSchemaRefExt::create(vec![expr.to_data_field(&schema)?]);
+    let executor =
+        ExpressionExecutor::try_create("test", schema.clone(), output_schema, vec![expr], false)?;
+
+    let block = DataBlock::create(schema, vec![Series::from_data(vec![0u64, 2, 5, 0])]);
+    let actual = executor.execute(&block)?;
+    assert_eq!(actual.column(0).to_values(), vec![
+        DataValue::UInt64(0),
+        DataValue::UInt64(1),
+        DataValue::UInt64(20),
+        DataValue::UInt64(0),
+    ]);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_transform_expression_common_subexpression() -> Result<()> {
+    let ctx = crate::tests::create_query_context()?;
+
+    let query = "SELECT (number + 1) * 2, (number + 1) * 3 FROM numbers_mt(8)";
+    let plan = PlanParser::parse(ctx.clone(), query).await?;
+    let pipeline = PipelineBuilder::create(ctx).build(&plan)?;
+
+    let expected = "\
+        ProjectionTransform × 8 processors\
+        \n  ExpressionTransform × 8 processors\
+        \n    SourceTransform × 8 processors";
+    assert_eq!(expected, format!("{}", pipeline.display_indent()));
+
+    // The shared (number + 1) is an intermediate column of the expression transform,
+    // computed once per block for both of the projected expressions.
+    let mut function_names = vec![];
+    pipeline.walk_preorder(|pipe| {
+        let processor = pipe.processor_by_index(0);
+        if let Some(transform) = processor.as_any().downcast_ref::<ExpressionTransform>() {
+            for action in transform.executor().chain().actions.iter() {
+                if let ExpressionAction::Function(f) = action {
+                    function_names.push(f.name.clone());
+                }
+            }
+        }
+        Result::Ok(true)
+    })?;
+    assert_eq!(function_names, vec![
+        "(number + 1)",
+        "((number + 1) * 2)",
+        "((number + 1) * 3)",
+    ]);
+
+    let stream = pipeline.execute().await?;
+    let result = stream.try_collect::<Vec<_>>().await?;
+    let expected = vec![
+        "+--------------------+--------------------+",
+        "| ((number + 1) * 2) | ((number + 1) * 3) |",
+        "+--------------------+--------------------+",
+        "| 10                 | 15                 |",
+        "| 12                 | 18                 |",
+        "| 14                 | 21                 |",
+        "| 16                 | 24                 |",
+        "| 2                  | 3                  |",
+        "| 4                  | 6                  |",
+        "| 6                  | 9                  |",
+        "| 8                  | 12                 |",
+        "+--------------------+--------------------+",
+    ];
+    common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
+
+    Ok(())
+}
+
+#[test]
+fn test_expression_executor_non_deterministic_not_shared() -> Result<()> {
+    let schema = DataSchemaRefExt::create(vec![DataField::new("a", u64::to_data_type())]);
+    // The call sites are numbered as the expression analyzer does when the plan is built.
+    let rand = |ordinal| Expression::ScalarFunction {
+        op: "rand".to_string(),
+        args: vec![],
+        ordinal: Some(ordinal),
+    };
+    let expr = Expression::create_binary_expression("-", vec![
+        add(rand(1), lit(1u8)),
+        Expression::create_binary_expression("-", vec![rand(2), lit(1u8)]),
+    ]);
+    assert_eq!(expr.column_name(), "((rand() + 1) - (rand()#2 - 1))");
+    let output_schema = DataSchemaRefExt::create(vec![expr.to_data_field(&schema)?]);
+    let executor =
+        ExpressionExecutor::try_create("test", schema.clone(), output_schema, vec![expr], false)?;
+
+    let block = DataBlock::create(schema, vec![Series::from_data(vec![0u64; 64])]);
+    let result = executor.execute(&block)?;
+
+    // (rand() + 1) - (rand() - 1) is always 2 if the two rand() calls share one column.
+    let values = result.column(0).to_values();
+    assert_eq!(values.len(), 64);
+    assert!(values.iter().any(|v| *v != DataValue::Float64(2.0)));
+
+    Ok(())
+}
@@ -16,6 +16,7 @@ use common_arrow::arrow::bitmap::MutableBitmap;
 use common_datablocks::DataBlock;
 use common_datavalues2::prelude::*;
 use common_exception::Result;
+use common_io::prelude::FloatFormat;
 use databend_query::servers::http::v1::block_to_json::block_to_json;
 use pretty_assertions::assert_eq;
 use serde::Serialize;
@@ -68,7 +69,7 @@ fn test_data_block(is_nullable: bool) -> Result<()> {
         block
     };
 
-    let json_block = block_to_json(&block)?;
+    let json_block = block_to_json(&block, FloatFormat::Shortest)?;
     let expect = vec![
         vec![val(1), val("a"), val(true), val(1.1), val("1970-01-02")],
         vec![val(2), val("b"), val(true), val(2.2), val("1970-01-03")],
@@ -88,3 +89,21 @@ fn test_data_block_nullable() -> Result<()> {
 fn test_data_block_not_nullable() -> Result<()> {
     test_data_block(false)
 }
+
+#[test]
+fn test_data_block_float_format() -> Result<()> {
+    let schema = DataSchemaRefExt::create(vec![
+        DataField::new("c1", i32::to_data_type()),
+        DataField::new_nullable("c2", f64::to_data_type()),
+    ]);
+    let block = DataBlock::create(schema, vec![
+        Series::from_data(vec![1, 2]),
+        Series::from_data(vec![Some(1.0 / 3.0), None]),
+    ]);
+
+    let json_block = block_to_json(&block, FloatFormat::Fixed(2))?;
+    let expect = vec![vec![val(1), val(0.33)], vec![val(2), Value::Null]];
+
+    assert_eq!(json_block, expect);
+    Ok(())
+}
@@ -15,3 +15,4 @@
 mod clickhouse;
 mod http;
 mod mysql;
+mod postgres;
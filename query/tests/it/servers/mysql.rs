@@ -45,6 +45,44 @@ async fn test_generic_code_with_on_query() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_ignore_error_hint_with_on_query() -> Result<()> {
+    let mut handler =
+        MySQLHandler::create(SessionManagerBuilder::create().max_sessions(1).build()?);
+
+    let listening = "127.0.0.1:0".parse::<SocketAddr>()?;
+    let runnable_server = handler.start(listening).await?;
+    let mut connection = create_connection(runnable_server.port()).await?;
+
+    // Without the hint, the missing table is an error.
+    let result = connection
+        .query_drop("INSERT INTO default.missing_table VALUES(1)")
+        .await;
+    assert!(result.is_err());
+
+    // The hinted error is ignored.
+    connection
+        .query_drop("INSERT /*+ ignore_error(1025) */ INTO default.missing_table VALUES(1)")
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "Ignore error hint")?;
+    assert_eq!(connection.affected_rows(), 0);
+
+    // The other errors still propagate.
+    let result = connection
+        .query_drop("INSERT /*+ ignore_error(1006) */ INTO default.missing_table VALUES(1)")
+        .await;
+    assert!(result.is_err());
+
+    // The ignored error is written into the query log.
+    let exception_code: Option<i32> = connection
+        .query_first("SELECT exception_code FROM system.query_log WHERE log_type = 3")
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "Query log")?;
+    assert_eq!(exception_code, Some(1025));
+
+    Ok(())
+}
+
 #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
 async fn test_rejected_session_with_sequence() -> Result<()> {
     let mut handler =
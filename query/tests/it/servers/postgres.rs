@@ -0,0 +1,187 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::net::SocketAddr;
+
+use common_base::tokio;
+use common_base::tokio::io::AsyncReadExt;
+use common_base::tokio::io::AsyncWriteExt;
+use common_base::tokio::net::TcpStream;
+use common_exception::Result;
+use databend_query::servers::PgHandler;
+
+use crate::tests::SessionManagerBuilder;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_pg_handler_simple_query() -> Result<()> {
+    let mut handler = PgHandler::create(SessionManagerBuilder::create().max_sessions(1).build()?);
+
+    let listening = "127.0.0.1:0".parse::<SocketAddr>()?;
+    let listening = handler.start(listening).await?;
+    let mut stream = TcpStream::connect(listening).await?;
+
+    // Startup, the root user has no password.
+    {
+        write_startup(&mut stream, "root", None).await?;
+        let messages = read_until_ready(&mut stream).await?;
+        assert_eq!(messages[0].0, b'R');
+        assert_eq!(messages[0].1, 0_i32.to_be_bytes());
+        assert_eq!(messages.last().unwrap().0, b'Z');
+    }
+
+    // Select.
+    {
+        write_query(&mut stream, "SELECT 1 AS a, 'x' AS b, NULL AS c").await?;
+        let messages = read_until_ready(&mut stream).await?;
+        let tags: Vec<u8> = messages.iter().map(|(tag, _)| *tag).collect();
+        assert_eq!(tags, vec![b'T', b'D', b'C', b'Z']);
+
+        let names = row_description_names(&messages[0].1);
+        assert_eq!(names, vec!["a", "b", "c"]);
+
+        let values = data_row_values(&messages[1].1);
+        assert_eq!(values, vec![
+            Some("1".to_string()),
+            Some("x".to_string()),
+            None
+        ]);
+
+        assert_eq!(messages[2].1, b"SELECT 1\0".to_vec());
+    }
+
+    // Error, the connection stays usable.
+    {
+        write_query(&mut stream, "SELECT * FROM default.missing_table").await?;
+        let messages = read_until_ready(&mut stream).await?;
+        let tags: Vec<u8> = messages.iter().map(|(tag, _)| *tag).collect();
+        assert_eq!(tags, vec![b'E', b'Z']);
+
+        write_query(&mut stream, "SELECT number FROM numbers(3)").await?;
+        let messages = read_until_ready(&mut stream).await?;
+        assert_eq!(messages.len(), 6);
+        assert_eq!(messages[4].1, b"SELECT 3\0".to_vec());
+    }
+
+    stream.write_all(&[b'X', 0, 0, 0, 4]).await?;
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_pg_handler_startup_database() -> Result<()> {
+    let mut handler = PgHandler::create(SessionManagerBuilder::create().max_sessions(2).build()?);
+
+    let listening = "127.0.0.1:0".parse::<SocketAddr>()?;
+    let listening = handler.start(listening).await?;
+
+    // The database of the startup message becomes the current database.
+    {
+        let mut stream = TcpStream::connect(listening).await?;
+        write_startup(&mut stream, "root", Some("system")).await?;
+        let messages = read_until_ready(&mut stream).await?;
+        assert_eq!(messages[0].0, b'R');
+
+        write_query(&mut stream, "SELECT database()").await?;
+        let messages = read_until_ready(&mut stream).await?;
+        let values = data_row_values(&messages[1].1);
+        assert_eq!(values, vec![Some("system".to_string())]);
+
+        stream.write_all(&[b'X', 0, 0, 0, 4]).await?;
+    }
+
+    // The database name is never run as a query.
+    {
+        let mut stream = TcpStream::connect(listening).await?;
+        let database = "system`; DROP DATABASE default; --";
+        write_startup(&mut stream, "root", Some(database)).await?;
+        let tag = stream.read_u8().await?;
+        assert_eq!(tag, b'E');
+    }
+
+    Ok(())
+}
+
+async fn write_startup(stream: &mut TcpStream, user: &str, database: Option<&str>) -> Result<()> {
+    let mut body = 196608_i32.to_be_bytes().to_vec();
+    body.extend_from_slice(b"user\0");
+    body.extend_from_slice(user.as_bytes());
+    body.push(0);
+    if let Some(database) = database {
+        body.extend_from_slice(b"database\0");
+        body.extend_from_slice(database.as_bytes());
+        body.push(0);
+    }
+    body.push(0);
+
+    let mut message = ((body.len() + 4) as i32).to_be_bytes().to_vec();
+    message.extend_from_slice(&body);
+    stream.write_all(&message).await?;
+    Ok(())
+}
+
+async fn write_query(stream: &mut TcpStream, query: &str) -> Result<()> {
+    let mut message = vec![b'Q'];
+    message.extend_from_slice(&((query.len() + 5) as i32).to_be_bytes());
+    message.extend_from_slice(query.as_bytes());
+    message.push(0);
+    stream.write_all(&message).await?;
+    Ok(())
+}
+
+async fn read_until_ready(stream: &mut TcpStream) -> Result<Vec<(u8, Vec<u8>)>> {
+    let mut messages = vec![];
+    loop {
+        let tag = stream.read_u8().await?;
+        let len = stream.read_i32().await?;
+        let mut body = vec![0; len as usize - 4];
+        stream.read_exact(&mut body).await?;
+        messages.push((tag, body));
+
+        if tag == b'Z' {
+            return Ok(messages);
+        }
+    }
+}
+
+fn row_description_names(body: &[u8]) -> Vec<String> {
+    let count = i16::from_be_bytes([body[0], body[1]]) as usize;
+    let mut names = Vec::with_capacity(count);
+    let mut rest = &body[2..];
+    for _ in 0..count {
+        let end = rest.iter().position(|b| *b == 0).unwrap();
+        names.push(String::from_utf8(rest[..end].to_vec()).unwrap());
+        // Skip the name terminator and the 18 bytes of fixed size attributes.
+        rest = &rest[end + 1 + 18..];
+    }
+    names
+}
+
+fn data_row_values(body: &[u8]) -> Vec<Option<String>> {
+    let count = i16::from_be_bytes([body[0], body[1]]) as usize;
+    let mut values = Vec::with_capacity(count);
+    let mut rest = &body[2..];
+    for _ in 0..count {
+        let len = i32::from_be_bytes([rest[0], rest[1], rest[2], rest[3]]);
+        rest = &rest[4..];
+        match len {
+            -1 => values.push(None),
+            len => {
+                let len = len as usize;
+                values.push(Some(String::from_utf8(rest[..len].to_vec()).unwrap()));
+                rest = &rest[len..];
+            }
+        }
+    }
+    values
+}
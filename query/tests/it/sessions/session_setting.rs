@@ -39,5 +39,31 @@ async fn test_session_setting() -> Result<()> {
         assert_eq!(actual, expect);
     }
 
+    // Operator threads fall back to max_threads when unset.
+    {
+        let settings = session.get_settings();
+        assert_eq!(settings.get_max_aggregate_threads()?, 3);
+        assert_eq!(settings.get_max_sort_threads()?, 3);
+
+        settings.set_settings("max_aggregate_threads".to_string(), "2".to_string(), false)?;
+        settings.set_settings("max_sort_threads".to_string(), "1".to_string(), false)?;
+        assert_eq!(settings.get_max_aggregate_threads()?, 2);
+        assert_eq!(settings.get_max_sort_threads()?, 1);
+        assert_eq!(settings.get_max_threads()?, 3);
+    }
+
+    // String settings.
+    {
+        let settings = session.get_settings();
+        assert_eq!(settings.get_sql_dialect()?, "ansi");
+
+        settings.set_settings("sql_dialect".to_string(), "MySQL".to_string(), false)?;
+        assert_eq!(settings.get_sql_dialect()?, "mysql");
+
+        let result = settings.set_settings("sql_dialect".to_string(), "pg".to_string(), false);
+        assert!(result.is_err());
+        assert_eq!(settings.get_sql_dialect()?, "mysql");
+    }
+
     Ok(())
 }
@@ -18,6 +18,7 @@ use databend_query::sql::statements::DfShowEngines;
 use databend_query::sql::statements::DfShowFunctions;
 use databend_query::sql::statements::DfShowKind;
 use databend_query::sql::statements::DfShowSettings;
+use databend_query::sql::statements::DfShowTableStatus;
 use databend_query::sql::statements::DfShowTables;
 use databend_query::sql::*;
 use sqlparser::ast::*;
@@ -98,6 +99,32 @@ fn show_tables_test() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn show_table_status_test() -> Result<()> {
+    expect_parse_ok(
+        "SHOW TABLE STATUS",
+        DfStatement::ShowTableStatus(DfShowTableStatus::create(DfShowKind::All)),
+    )?;
+    expect_parse_ok(
+        "SHOW TABLE STATUS FROM db1",
+        DfStatement::ShowTableStatus(DfShowTableStatus::create(DfShowKind::FromOrIn(ObjectName(
+            vec![Ident::new("db1")],
+        )))),
+    )?;
+    expect_parse_ok(
+        "SHOW TABLE STATUS LIKE 'aaa'",
+        DfStatement::ShowTableStatus(DfShowTableStatus::create(DfShowKind::Like(
+            Ident::with_quote('\'', "aaa"),
+        ))),
+    )?;
+    expect_parse_err(
+        "SHOW TABLE t1",
+        "sql parser error: Expected STATUS, found: t1".to_string(),
+    )?;
+
+    Ok(())
+}
 #[test]
 fn show_functions_tests() -> Result<()> {
     // positive case
@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
+
 use common_exception::Result;
 use databend_query::sql::statements::DfCreateTable;
 use databend_query::sql::statements::DfDescribeTable;
@@ -21,6 +23,7 @@ use databend_query::sql::statements::DfShowCreateTable;
 use databend_query::sql::statements::DfTruncateTable;
 use databend_query::sql::*;
 use sqlparser::ast::*;
+use sqlparser::tokenizer::Token;
 
 use crate::sql::sql_parser::*;
 
@@ -60,6 +63,54 @@ fn create_table() -> Result<()> {
     });
     expect_parse_ok(sql, expected)?;
 
+    // the quotes inside a quoted value are kept
+    let sql = r#"CREATE TABLE t(c1 int) ENGINE = Fuse location = '100' comment = "'a'" label = "it's 'b'""#;
+    let expected = DfStatement::CreateTable(DfCreateTable {
+        if_not_exists: false,
+        name: ObjectName(vec![Ident::new("t")]),
+        columns: vec![make_column_def("c1", DataType::Int(None))],
+        engine: "Fuse".to_string(),
+        options: maplit::hashmap! {
+            "location".into() => "100".into(),
+            "comment".into() => "'a'".into(),
+            "label".into() => "it's 'b'".into(),
+        },
+        like: None,
+        query: None,
+    });
+    expect_parse_ok(sql, expected)?;
+
+    // column comment
+    let sql = "CREATE TABLE t(c1 int COMMENT 'id', c2 int) ENGINE = Fuse";
+    let expected = DfStatement::CreateTable(DfCreateTable {
+        if_not_exists: false,
+        name: ObjectName(vec![Ident::new("t")]),
+        columns: vec![
+            ColumnDef {
+                name: Ident::new("c1"),
+                data_type: DataType::Int(None),
+                collation: None,
+                options: vec![ColumnOptionDef {
+                    name: None,
+                    option: ColumnOption::DialectSpecific(vec![
+                        Token::make_keyword("COMMENT"),
+                        Token::SingleQuotedString("id".to_string()),
+                    ]),
+                }],
+            },
+            make_column_def("c2", DataType::Int(None)),
+        ],
+        engine: "Fuse".to_string(),
+        options: maplit::hashmap! {},
+        like: None,
+        query: None,
+    });
+    expect_parse_ok(sql, expected)?;
+
+    // column comment must be a string literal
+    let sql = "CREATE TABLE t(c1 int COMMENT id) ENGINE = Fuse";
+    expect_parse_err_contains(sql, "Expected literal string after COMMENT".to_string())?;
+
     // create table like statement
     let sql = "CREATE TABLE db1.test1 LIKE db2.test2 ENGINE = Parquet location = 'batcave'";
     let expected = DfStatement::CreateTable(DfCreateTable {
@@ -88,6 +139,7 @@ fn create_table() -> Result<()> {
         options: maplit::hashmap! {"location".into() => "batcave".into()},
         like: None,
         query: Some(Box::new(DfQueryStatement {
+            distinct: false,
             from: vec![TableWithJoins {
                 relation: TableFactor::Table {
                     name: ObjectName(vec![Ident::new("t2")]),
@@ -100,10 +152,14 @@ fn create_table() -> Result<()> {
             projection: vec![SelectItem::Wildcard],
             selection: None,
             group_by: vec![],
+            group_by_rollup: false,
             having: None,
             order_by: vec![],
+            limit_by_count: None,
+            limit_by: vec![],
             limit: None,
             offset: None,
+            settings: HashMap::new(),
         })),
     });
     expect_parse_ok(sql, expected)?;
@@ -111,6 +167,28 @@ fn create_table() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn table_sample_test() -> Result<()> {
+    let query = verified_query("SELECT a FROM t AS t1 TABLESAMPLE (10 PERCENT) REPEATABLE (3)")?;
+    match &query.from[0].relation {
+        TableFactor::Table { with_hints, .. } => assert_eq!(with_hints.len(), 1),
+        relation => panic!("expected a table, but got {:?}", relation),
+    }
+
+    // TABLESAMPLE is a name when it doesn't follow a table factor.
+    let query = verified_query("SELECT tablesample(1) FROM t WHERE tablesample(a)")?;
+    assert!(matches!(&query.projection[..], [SelectItem::UnnamedExpr(
+        Expr::Function(_)
+    )]));
+    assert!(matches!(query.selection, Some(Expr::Function(_))));
+    match &query.from[0].relation {
+        TableFactor::Table { with_hints, .. } => assert!(with_hints.is_empty()),
+        relation => panic!("expected a table, but got {:?}", relation),
+    }
+
+    Ok(())
+}
+
 #[test]
 fn create_table_select() -> Result<()> {
     expect_parse_ok(
@@ -181,6 +259,13 @@ fn describe_table() -> Result<()> {
         });
         expect_parse_ok(sql, expected)?;
     }
+    {
+        let sql = "DESC db1.t1";
+        let expected = DfStatement::DescribeTable(DfDescribeTable {
+            name: ObjectName(vec![Ident::new("db1"), Ident::new("t1")]),
+        });
+        expect_parse_ok(sql, expected)?;
+    }
 
     Ok(())
 }
@@ -66,15 +66,29 @@ async fn test_plan_parser() -> Result<()> {
         Test {
             name: "create-table-passed",
             sql: "CREATE TABLE t(c1 int, c2 bigint, c3 varchar(255) ) ENGINE = Parquet location = 'foo.parquet' ",
-            expect: "Create table default.t DataField { name: \"c1\", data_type: Int32, nullable: true }, DataField { name: \"c2\", data_type: Int64, nullable: true }, DataField { name: \"c3\", data_type: String, nullable: true }, engine: Parquet, if_not_exists:false, option: {\"location\": \"foo.parquet\"}, as_select: None",
+            expect: "Create table default.t DataField { name: \"c1\", data_type: Int32, nullable: true }, DataField { name: \"c2\", data_type: Int64, nullable: true }, DataField { name: \"c3\", data_type: String, nullable: true }, engine: Parquet, if_not_exists:false, option: {\"LOCATION\": \"foo.parquet\"}, as_select: None",
             error: "",
         },
         Test {
             name: "create-table-if-not-exists-passed",
             sql: "CREATE TABLE IF NOT EXISTS t(c1 int, c2 bigint, c3 varchar(255) ) ENGINE = Parquet location = 'foo.parquet' ",
-            expect: "Create table default.t DataField { name: \"c1\", data_type: Int32, nullable: true }, DataField { name: \"c2\", data_type: Int64, nullable: true }, DataField { name: \"c3\", data_type: String, nullable: true }, engine: Parquet, if_not_exists:true, option: {\"location\": \"foo.parquet\"}, as_select: None",
+            expect: "Create table default.t DataField { name: \"c1\", data_type: Int32, nullable: true }, DataField { name: \"c2\", data_type: Int64, nullable: true }, DataField { name: \"c3\", data_type: String, nullable: true }, engine: Parquet, if_not_exists:true, option: {\"LOCATION\": \"foo.parquet\"}, as_select: None",
             error: "",
         },
+        Test {
+            name: "create-table-as-select-passed",
+            sql: "CREATE TABLE t AS SELECT number, number * 2 AS doubled FROM numbers(10)",
+            expect: "Create table default.t DataField { name: \"number\", data_type: UInt64, nullable: false }, DataField { name: \"doubled\", data_type: UInt64, nullable: false }, engine: FUSE, if_not_exists:false, option: {}, as_select: Some(Projection: number:UInt64, (number * 2) as doubled:UInt64\
+            \n  Expression: number:UInt64, (number * 2):UInt64 (Before Projection)\
+            \n    ReadDataSource: scan schema: [number:UInt64], statistics: [read_rows: 10, read_bytes: 80, partitions_scanned: 1, partitions_total: 1], push_downs: [projections: [0]])",
+            error: "",
+        },
+        Test {
+            name: "create-table-columns-and-as-select-failed",
+            sql: "CREATE TABLE t(c1 varchar) AS SELECT number FROM numbers(1)",
+            expect: "",
+            error: "Code: 1065, displayText = Create table as select can not define columns, they are derived from the select.",
+        },
         Test {
             name: "drop-table-passed",
             sql: "DROP TABLE t1",
@@ -108,7 +122,13 @@ async fn test_plan_parser() -> Result<()> {
         Test {
             name: "truncate-table-passed",
             sql: "TRUNCATE TABLE db1.t1",
-            expect: "",
+            expect: "Truncate table db1.t1, purge:false",
+            error: "",
+        },
+        Test {
+            name: "truncate-table-current-database-passed",
+            sql: "TRUNCATE TABLE t1 PURGE",
+            expect: "Truncate table default.t1, purge:true",
             error: "",
         },
         Test {
@@ -137,6 +157,18 @@ async fn test_plan_parser() -> Result<()> {
             expect: "Projection: COUNT():UInt64\n  AggregatorFinal: groupBy=[[]], aggr=[[COUNT()]]\n    AggregatorPartial: groupBy=[[]], aggr=[[COUNT()]]\n      ReadDataSource: scan schema: [number:UInt64], statistics: [read_rows: 10, read_bytes: 80, partitions_scanned: 1, partitions_total: 1], push_downs: [projections: [0]]",
             error: "",
         },
+        Test {
+            name: "select-table-sample",
+            sql: "SELECT COUNT() FROM numbers(10) TABLESAMPLE BERNOULLI (50 PERCENT) REPEATABLE (7)",
+            expect: "Projection: COUNT():UInt64\n  AggregatorFinal: groupBy=[[]], aggr=[[COUNT()]]\n    AggregatorPartial: groupBy=[[]], aggr=[[COUNT()]]\n      ReadDataSource: scan schema: [number:UInt64], statistics: [read_rows: 10, read_bytes: 80, partitions_scanned: 1, partitions_total: 1], push_downs: [projections: [0], sample: 50 PERCENT REPEATABLE (7)]",
+            error: "",
+        },
+        Test {
+            name: "select-table-sample-not-number",
+            sql: "SELECT COUNT() FROM numbers(10) TABLESAMPLE (abc PERCENT)",
+            expect: "",
+            error: "Code: 1005, displayText = sql parser error: Expected a number in TABLESAMPLE, found: abc.",
+        },
         Test {
             name: "unsupported-function",
             sql: "select unsupported()",
@@ -195,12 +227,219 @@ async fn test_plan_parser() -> Result<()> {
             \n                  ReadDataSource: scan schema: [number:UInt64], statistics: [read_rows: 10, read_bytes: 80, partitions_scanned: 1, partitions_total: 1], push_downs: [projections: [0], filters: [(number > 1)]]",
             error: "",
         },
+        Test {
+            name: "select-having-without-group-by",
+            sql: "select sum(number) from numbers(10) having sum(number) > 40",
+            expect: "\
+            Projection: sum(number):UInt64\
+            \n  Having: (sum(number) > 40)\
+            \n    AggregatorFinal: groupBy=[[]], aggr=[[sum(number)]]\
+            \n      AggregatorPartial: groupBy=[[]], aggr=[[sum(number)]]\
+            \n        ReadDataSource: scan schema: [number:UInt64], statistics: [read_rows: 10, read_bytes: 80, partitions_scanned: 1, partitions_total: 1], push_downs: [projections: [0]]",
+            error: "",
+        },
+        Test {
+            name: "select-having-alias-without-group-by",
+            sql: "select sum(number) as s from numbers(10) having s > 1",
+            expect: "\
+            Projection: sum(number) as s:UInt64\
+            \n  Having: (sum(number) > 1)\
+            \n    AggregatorFinal: groupBy=[[]], aggr=[[sum(number)]]\
+            \n      AggregatorPartial: groupBy=[[]], aggr=[[sum(number)]]\
+            \n        ReadDataSource: scan schema: [number:UInt64], statistics: [read_rows: 10, read_bytes: 80, partitions_scanned: 1, partitions_total: 1], push_downs: [projections: [0]]",
+            error: "",
+        },
+        Test {
+            name: "select-having-other-aggregate-without-group-by",
+            sql: "select sum(number) from numbers(10) having count() > 1",
+            expect: "\
+            Projection: sum(number):UInt64\
+            \n  Having: (count() > 1)\
+            \n    AggregatorFinal: groupBy=[[]], aggr=[[sum(number), count()]]\
+            \n      AggregatorPartial: groupBy=[[]], aggr=[[sum(number), count()]]\
+            \n        ReadDataSource: scan schema: [number:UInt64], statistics: [read_rows: 10, read_bytes: 80, partitions_scanned: 1, partitions_total: 1], push_downs: [projections: [0]]",
+            error: "",
+        },
+        Test {
+            name: "select-having-column-without-group-by",
+            sql: "select sum(number) from numbers(10) having number > 1",
+            expect: "",
+            error: "Code: 1006, displayText = Unable to get field named \"number\". Valid fields: [\"sum(number)\"] (while in select having).",
+        },
+        Test {
+            name: "select-distinct",
+            sql: "select distinct number%3 as id from numbers(10) order by id desc limit 2",
+            expect: "\
+            Limit: 2\
+            \n  Projection: (number % 3) as id:UInt8\
+            \n    Sort: (number % 3):UInt8\
+            \n      AggregatorFinal: groupBy=[[(number % 3)]], aggr=[[]]\
+            \n        AggregatorPartial: groupBy=[[(number % 3)]], aggr=[[]]\
+            \n          Expression: (number % 3):UInt8 (Before OrderBy)\
+            \n            ReadDataSource: scan schema: [number:UInt64], statistics: [read_rows: 10, read_bytes: 80, partitions_scanned: 1, partitions_total: 1], push_downs: [projections: [0]]",
+            error: "",
+        },
+        Test {
+            name: "select-distinct-order-by-not-in-select-list",
+            sql: "select distinct number%3 from numbers(10) order by number",
+            expect: "",
+            error: "Code: 1005, displayText = For SELECT DISTINCT, ORDER BY expression number must appear in select list.",
+        },
+        Test {
+            name: "select-limit-by",
+            sql: "select number from numbers(10) order by number % 3, number limit 2 by number % 3 limit 4 offset 1",
+            expect: "\
+            Limit: 4, 1\
+            \n  Projection: number:UInt64\
+            \n    LimitBy: 2 by [(number % 3)]\
+            \n      Sort: (number % 3):UInt8, number:UInt64\
+            \n        Expression: number:UInt64, (number % 3):UInt8 (Before OrderBy)\
+            \n          ReadDataSource: scan schema: [number:UInt64], statistics: [read_rows: 10, read_bytes: 80, partitions_scanned: 1, partitions_total: 1], push_downs: [projections: [0]]",
+            error: "",
+        },
+        Test {
+            name: "select-limit-by-with-offset",
+            sql: "select number from numbers(10) limit 2 offset 1 by number % 3",
+            expect: "",
+            error: "Code: 1005, displayText = sql parser error: OFFSET in LIMIT BY is not yet implement.",
+        },
+        Test {
+            name: "select-alias-output-names",
+            sql: "select n.number as \"数字\", n.number + 1 as \"select\" from numbers(10) n",
+            expect: "\
+            Projection: number as 数字:UInt64, (number + 1) as select:UInt64\
+            \n  Expression: number:UInt64, (number + 1):UInt64 (Before Projection)\
+            \n    ReadDataSource: scan schema: [number:UInt64], statistics: [read_rows: 10, read_bytes: 80, partitions_scanned: 1, partitions_total: 1], push_downs: [projections: [0]]",
+            error: "",
+        },
+        Test {
+            name: "select-alias-collides-with-column",
+            sql: "select number, number + 1 as number from numbers(10)",
+            expect: "",
+            error: "Code: 1065, displayText = Duplicate column name number in the select list (while in analyze select projection).",
+        },
+        Test {
+            name: "select-limit-constant-expression",
+            sql: "select number from numbers(10) order by number limit 2+3 offset 1*2",
+            expect: "\
+            Limit: 5, 2\
+            \n  Projection: number:UInt64\
+            \n    Sort: number:UInt64\
+            \n      ReadDataSource: scan schema: [number:UInt64], statistics: [read_rows: 10, read_bytes: 80, partitions_scanned: 1, partitions_total: 1], push_downs: [projections: [0]]",
+            error: "",
+        },
+        Test {
+            name: "select-limit-negative-expression",
+            sql: "select number from numbers(10) limit 1-2",
+            expect: "",
+            error: "Code: 1005, displayText = LIMIT must be a non-negative integer, but got -1 (while in analyze select limit).",
+        },
+        Test {
+            name: "select-limit-integral-float-expression",
+            sql: "select number from numbers(10) order by number limit 10/2",
+            expect: "\
+            Limit: 5\
+            \n  Projection: number:UInt64\
+            \n    Sort: number:UInt64\
+            \n      ReadDataSource: scan schema: [number:UInt64], statistics: [read_rows: 10, read_bytes: 80, partitions_scanned: 1, partitions_total: 1], push_downs: [projections: [0]]",
+            error: "",
+        },
+        Test {
+            name: "select-limit-fractional-expression",
+            sql: "select number from numbers(10) limit 10/4",
+            expect: "",
+            error: "Code: 1005, displayText = LIMIT must be an integer, but got 2.5 (while in analyze select limit).",
+        },
+        Test {
+            name: "select-limit-column-expression",
+            sql: "select number from numbers(10) limit number",
+            expect: "",
+            error: "Code: 1005, displayText = Unexpected expression for LIMIT clause: Identifier(Ident { value: \"number\", quote_style: None }) (while in analyze select limit).",
+        },
+        Test {
+            name: "group-by-context-function",
+            sql: "select database(), count() from numbers(10) group by database()",
+            expect: "\
+            Projection: database():String, count():UInt64\
+            \n  Expression: database(default):String, count():UInt64 (Before Projection)\
+            \n    AggregatorFinal: groupBy=[[database(default)]], aggr=[[count()]]\
+            \n      AggregatorPartial: groupBy=[[database(default)]], aggr=[[count()]]\
+            \n        Expression: database(default):String (Before GroupBy)\
+            \n          ReadDataSource: scan schema: [number:UInt64], statistics: [read_rows: 10, read_bytes: 80, partitions_scanned: 1, partitions_total: 1], push_downs: [projections: [0]]",
+            error: "",
+        },
+        Test {
+            name: "group-by-nondeterministic-function",
+            sql: "select count() from numbers(10) group by rand()",
+            expect: "",
+            error: "Code: 1065, displayText = GROUP BY expression rand() contains the nondeterministic function rand() (while in analyze select group by).",
+        },
+        Test {
+            name: "nondeterministic-function-call-sites",
+            sql: "select rand(), rand()",
+            expect: "Projection: rand():Float64, rand()#2:Float64\n  Expression: rand():Float64, rand()#2:Float64 (Before Projection)\n    ReadDataSource: scan schema: [dummy:UInt8], statistics: [read_rows: 1, read_bytes: 1, partitions_scanned: 1, partitions_total: 1], push_downs: [projections: [0]]",
+            error: "",
+        },
         Test {
             name: "unimplemented-cte",
             sql: "with t as ( select sum(number) n from numbers_mt(1000) )select * from t",
             expect: "",
             error: "Code: 1005, displayText = sql parser error: CTE is not yet implement.",
         },
+        Test {
+            name: "exists-subquery-passed",
+            sql: "select number from numbers(10) where exists (select number from numbers(5))",
+            expect: "\
+            Projection: number:UInt64\
+            \n  Filter: exists(subquery(_subquery_1))\
+            \n    Create sub queries sets: [_subquery_1]\
+            \n      Projection: number:UInt64\
+            \n        ReadDataSource: scan schema: [number:UInt64], statistics: [read_rows: 5, read_bytes: 40, partitions_scanned: 1, partitions_total: 1], push_downs: [projections: [0]]\
+            \n      ReadDataSource: scan schema: [number:UInt64], statistics: [read_rows: 10, read_bytes: 80, partitions_scanned: 1, partitions_total: 1], push_downs: [projections: [0], filters: [exists(subquery(_subquery_1))]]",
+            error: "",
+        },
+        Test {
+            name: "not-exists-subquery-passed",
+            sql: "select number from numbers(10) where not exists (select number from numbers(5))",
+            expect: "\
+            Projection: number:UInt64\
+            \n  Filter: (NOT exists(subquery(_subquery_2)))\
+            \n    Create sub queries sets: [_subquery_2]\
+            \n      Projection: number:UInt64\
+            \n        ReadDataSource: scan schema: [number:UInt64], statistics: [read_rows: 5, read_bytes: 40, partitions_scanned: 1, partitions_total: 1], push_downs: [projections: [0]]\
+            \n      ReadDataSource: scan schema: [number:UInt64], statistics: [read_rows: 10, read_bytes: 80, partitions_scanned: 1, partitions_total: 1], push_downs: [projections: [0], filters: [(NOT exists(subquery(_subquery_2)))]]",
+            error: "",
+        },
+        Test {
+            name: "in-subquery-passed",
+            sql: "select number from numbers(10) where number in (select number from numbers(5))",
+            expect: "\
+            Projection: number:UInt64\
+            \n  Filter: IN(number, subquery(_subquery_3))\
+            \n    Create sub queries sets: [_subquery_3]\
+            \n      Projection: number:UInt64\
+            \n        ReadDataSource: scan schema: [number:UInt64], statistics: [read_rows: 5, read_bytes: 40, partitions_scanned: 1, partitions_total: 1], push_downs: [projections: [0]]\
+            \n      ReadDataSource: scan schema: [number:UInt64], statistics: [read_rows: 10, read_bytes: 80, partitions_scanned: 1, partitions_total: 1], push_downs: [projections: [0], filters: [IN(number, subquery(_subquery_3))]]",
+            error: "",
+        },
+        Test {
+            name: "not-in-subquery-passed",
+            sql: "select number from numbers(10) where number not in (select number from numbers(5))",
+            expect: "\
+            Projection: number:UInt64\
+            \n  Filter: NOT_IN(number, subquery(_subquery_4))\
+            \n    Create sub queries sets: [_subquery_4]\
+            \n      Projection: number:UInt64\
+            \n        ReadDataSource: scan schema: [number:UInt64], statistics: [read_rows: 5, read_bytes: 40, partitions_scanned: 1, partitions_total: 1], push_downs: [projections: [0]]\
+            \n      ReadDataSource: scan schema: [number:UInt64], statistics: [read_rows: 10, read_bytes: 80, partitions_scanned: 1, partitions_total: 1], push_downs: [projections: [0], filters: [NOT_IN(number, subquery(_subquery_4))]]",
+            error: "",
+        },
+        Test {
+            name: "correlated-subquery-unimplemented",
+            sql: "select number from numbers(10) where exists (select name from system.databases where name = toString(number))",
+            expect: "",
+            error: "Code: 1002, displayText = Correlated subquery is unimplemented, outer column number is referenced (while in analyze select filter).",
+        },
         Test {
             name: "kleene-logic-null",
             sql: "select * from numbers(10) where null",
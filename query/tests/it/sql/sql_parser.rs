@@ -118,5 +118,84 @@ fn hint_test() -> Result<()> {
         assert_eq!(expected.error_code, None);
     }
 
+    {
+        let comment = "+ ignore_error(1025) ";
+        let expected = DfHint::create_from_multi_line_comment(comment);
+        assert_eq!(expected.ignore_error_code, Some(1025));
+        assert_eq!(expected.error_code, None);
+    }
+
+    {
+        let comment = "+IGNORE_ERROR( 22 )";
+        let expected = DfHint::create_from_multi_line_comment(comment);
+        assert_eq!(expected.ignore_error_code, Some(22));
+    }
+
+    {
+        let comment = " ignore_error(1025) ";
+        let expected = DfHint::create_from_multi_line_comment(comment);
+        assert_eq!(expected.ignore_error_code, None);
+    }
+
+    {
+        let sql = "INSERT /*+ ignore_error(1025) */ INTO t VALUES(1)";
+        let (_, hints) = DfParser::parse_sql(sql)?;
+        assert_eq!(hints.len(), 1);
+        assert_eq!(hints[0].ignore_error_code, Some(1025));
+    }
+
+    Ok(())
+}
+
+#[test]
+fn query_settings_test() -> Result<()> {
+    let settings = maplit::hashmap! {
+        "max_threads".to_string() => "1".to_string(),
+        "max_block_size".to_string() => "100".to_string(),
+    };
+
+    {
+        let query =
+            verified_query("SELECT * FROM t SETTINGS max_threads = 1, max_block_size = 100")?;
+        assert_eq!(query.settings, settings);
+        assert_eq!(query.from, verified_query("SELECT * FROM t")?.from);
+    }
+
+    {
+        let query = verified_query("SELECT a FROM t WHERE a > 1 SETTINGS MAX_THREADS = 1")?;
+        assert_eq!(query.settings.get("max_threads"), Some(&"1".to_string()));
+        assert!(query.selection.is_some());
+    }
+
+    {
+        let query = verified_query("SELECT 1 SETTINGS max_threads = 1, max_block_size = 100")?;
+        assert_eq!(query.settings, settings);
+        assert_eq!(query.projection, verified_query("SELECT 1")?.projection);
+    }
+
+    // Without any setting after it, SETTINGS is still an alias.
+    {
+        let query = verified_query("SELECT a settings FROM t settings")?;
+        assert!(query.settings.is_empty());
+        assert_eq!(
+            query,
+            *verified_query("SELECT a AS settings FROM t AS settings")?
+        );
+    }
+
+    {
+        let sql = "INSERT INTO t SELECT * FROM s SETTINGS max_threads = 1, max_block_size = 100";
+        let (statements, _) = DfParser::parse_sql(sql)?;
+        match &statements[0] {
+            DfStatement::InsertQuery(insert) => assert_eq!(insert.settings, settings),
+            other => panic!("Expect insert statement, but got {:?}", other),
+        }
+    }
+
+    expect_parse_err_contains(
+        "SELECT * FROM t SETTINGS max_threads",
+        "Expected =".to_string(),
+    )?;
+
     Ok(())
 }
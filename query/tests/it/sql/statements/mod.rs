@@ -14,3 +14,4 @@
 
 mod query;
 mod statement_select;
+mod statement_show_tables;
@@ -115,6 +115,11 @@ async fn test_query_normalizer() -> Result<()> {
             query: "SELECT SUM(number) AS number1 FROM numbers(100) GROUP BY number ORDER BY number1",
             expect: "NormalQuery { group by: [number], aggregate: [SUM(number)], order by: [SUM(number)], projection: [SUM(number) as number1] }",
         },
+        TestCase {
+            name: "Group by context function",
+            query: "SELECT database(), COUNT() FROM numbers(100) GROUP BY database()",
+            expect: "NormalQuery { group by: [database(default)], aggregate: [COUNT()], projection: [database(default), COUNT()] }",
+        },
     ];
 
     for test_case in &tests {
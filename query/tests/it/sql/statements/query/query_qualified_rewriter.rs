@@ -97,6 +97,21 @@ async fn test_query_qualified_rewriter() -> Result<()> {
             query: "SELECT COUNT(system.databases.name) AS name FROM system.databases WHERE system.databases.name = 'xxx'",
             expect: "NormalQuery { filter: (name = xxx), aggregate: [COUNT(name)], projection: [COUNT(name) as name] }",
         },
+        TestCase {
+            name: "Alias qualified wildcard query",
+            query: "SELECT t.* FROM numbers(10) AS t",
+            expect: "NormalQuery { projection: [number] }",
+        },
+        TestCase {
+            name: "Alias qualified wildcard query with column",
+            query: "SELECT t.*, t.number AS n FROM numbers(10) AS t",
+            expect: "NormalQuery { projection: [number, number as n] }",
+        },
+        TestCase {
+            name: "Database and table qualified wildcard query",
+            query: "SELECT system.databases.* FROM system.databases",
+            expect: "NormalQuery { projection: [name] }",
+        },
     ];
 
     for test_case in &tests {
@@ -126,3 +141,26 @@ async fn test_query_qualified_rewriter() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn test_query_qualified_rewriter_with_unknown_qualifier() -> Result<()> {
+    let ctx = create_query_context()?;
+    let (mut statements, _) = DfParser::parse_sql("SELECT x.* FROM numbers(10) AS t")?;
+
+    match statements.remove(0) {
+        DfStatement::Query(query) => {
+            let analyzer = JoinedSchemaAnalyzer::create(ctx.clone());
+            let schema = analyzer.analyze(&query).await?;
+
+            let mut ir = QueryNormalizer::normalize(ctx.clone(), &query).await?;
+            let error = QualifiedRewriter::rewrite(&schema, ctx, &mut ir).unwrap_err();
+            assert_eq!(error.code(), ErrorCode::UnknownTable("").code());
+            assert_eq!(error.message(), "Unknown table x");
+        }
+        _ => {
+            return Err(ErrorCode::LogicalError("Cannot get analyze query state."));
+        }
+    }
+
+    Ok(())
+}
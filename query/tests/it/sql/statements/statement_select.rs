@@ -44,7 +44,22 @@ async fn test_statement_select_analyze() -> Result<()> {
         TestCase {
             name: "Simple filter query between",
             query: "SELECT * FROM system.databases WHERE name = 'xxx' AND (name between 'aaa' and 'bbb')",
-            expect: "QueryAnalyzeState { filter: ((name = xxx) AND ((name >= aaa) and (name <= bbb))), before_projection: [name], projection: [name] }",
+            expect: "QueryAnalyzeState { filter: ((name = xxx) AND (name BETWEEN aaa AND bbb)), before_projection: [name], projection: [name] }",
+        },
+        TestCase {
+            name: "Filter query with alias",
+            query: "SELECT number % 3 AS m FROM numbers(10) WHERE m = 1",
+            expect: "QueryAnalyzeState { filter: ((number % 3) = 1), before_projection: [(number % 3)], projection: [(number % 3) as m] }",
+        },
+        TestCase {
+            name: "Filter query with alias shadowed by column",
+            query: "SELECT number + 1 AS number FROM numbers(10) WHERE number > 1",
+            expect: "QueryAnalyzeState { filter: (number > 1), before_projection: [(number + 1)], projection: [(number + 1) as number] }",
+        },
+        TestCase {
+            name: "Qualified column with table alias",
+            query: "SELECT n.number AS \"数字\", n.number + 1 AS \"select\" FROM numbers(10) n",
+            expect: "QueryAnalyzeState { before_projection: [number, (number + 1)], projection: [number as 数字, (number + 1) as select] }",
         },
         TestCase {
             name: "Simple having query",
@@ -86,6 +101,16 @@ async fn test_statement_select_analyze() -> Result<()> {
             query: "SELECT number % 2 AS number FROM numbers(10) GROUP BY number HAVING COUNT() > 2",
             expect: "QueryAnalyzeState { before_group_by: [(number % 2)], aggregator: [(number % 2)], aggregate: [COUNT()], before_projection: [(number % 2)], having: (COUNT() > 2), projection: [(number % 2) as number] }",
         },
+        TestCase {
+            name: "Aggregate query with having",
+            query: "SELECT sum(number) FROM numbers(10) HAVING sum(number) > 40",
+            expect: "QueryAnalyzeState { before_group_by: [number], aggregate: [sum(number)], before_projection: [sum(number)], having: (sum(number) > 40), projection: [sum(number)] }",
+        },
+        TestCase {
+            name: "Aggregate query with having alias",
+            query: "SELECT sum(number) AS s FROM numbers(10) HAVING s > 1",
+            expect: "QueryAnalyzeState { before_group_by: [number], aggregate: [sum(number)], before_projection: [sum(number)], having: (sum(number) > 1), projection: [sum(number) as s] }",
+        },
         TestCase {
             name: "Group by query with order",
             query: "SELECT number % 2 AS number FROM numbers(10) GROUP BY number ORDER BY number",
@@ -118,9 +143,19 @@ async fn test_statement_select_analyze() -> Result<()> {
         },
         TestCase {
             name: "Group by query with projection 4",
-            query: "SELECT avg(number), max(number + 1) + 1 FROM numbers_mt(10000) GROUP BY 1;",
+            query: "SELECT avg(number), max(number + 1) + 1 FROM numbers_mt(10000) GROUP BY (1);",
             expect: "QueryAnalyzeState { before_group_by: [1, number, (number + 1)], aggregator: [1], aggregate: [avg(number), max((number + 1))], before_projection: [avg(number), (max((number + 1)) + 1)], projection: [avg(number), (max((number + 1)) + 1)] }",
         },
+        TestCase {
+            name: "Group by and order by position",
+            query: "SELECT number % 2 AS k, COUNT() FROM numbers(10) GROUP BY 1 ORDER BY 2",
+            expect: "QueryAnalyzeState { before_group_by: [(number % 2)], aggregator: [(number % 2)], aggregate: [COUNT()], before_order_by: [(number % 2), COUNT()], order_by: [COUNT()], projection: [(number % 2) as k, COUNT()] }",
+        },
+        TestCase {
+            name: "Order by position with wildcard projection",
+            query: "SELECT * FROM system.databases ORDER BY 1",
+            expect: "QueryAnalyzeState { before_order_by: [name], order_by: [name], projection: [name] }",
+        },
     ];
 
     for test_case in &tests {
@@ -153,3 +188,100 @@ async fn test_statement_select_analyze() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn test_statement_select_analyze_position_error() -> Result<()> {
+    let tests = vec![
+        "SELECT number % 2 FROM numbers(10) GROUP BY 2",
+        "SELECT number % 2 FROM numbers(10) GROUP BY 0",
+        "SELECT number FROM numbers(10) ORDER BY 2",
+        "SELECT COUNT() FROM numbers(10) GROUP BY 1",
+        "SELECT * FROM numbers(10) ORDER BY 2",
+    ];
+
+    for query in tests {
+        let ctx = create_query_context()?;
+        let (mut statements, _) = DfParser::parse_sql(query)?;
+
+        match statements.remove(0) {
+            DfStatement::Query(query_statement) => {
+                let result = query_statement.analyze(ctx).await;
+                assert!(result.is_err(), "{}", query);
+                assert_eq!(
+                    result.err().unwrap().code(),
+                    ErrorCode::SemanticErrorCode(),
+                    "{}",
+                    query
+                );
+            }
+            _ => {
+                return Err(ErrorCode::LogicalError("Cannot get analyze query state."));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_statement_select_analyze_duplicate_alias_error() -> Result<()> {
+    let tests = vec![
+        "SELECT number AS a, number + 1 AS a FROM numbers(10)",
+        "SELECT number, number + 1 AS number FROM numbers(10)",
+        "SELECT number + 1 AS number, number FROM numbers(10)",
+    ];
+
+    for query in tests {
+        let ctx = create_query_context()?;
+        let (mut statements, _) = DfParser::parse_sql(query)?;
+
+        match statements.remove(0) {
+            DfStatement::Query(query_statement) => {
+                let result = query_statement.analyze(ctx).await;
+                assert!(result.is_err(), "{}", query);
+                assert_eq!(
+                    result.err().unwrap().code(),
+                    ErrorCode::SemanticErrorCode(),
+                    "{}",
+                    query
+                );
+            }
+            _ => {
+                return Err(ErrorCode::LogicalError("Cannot get analyze query state."));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_statement_select_analyze_in_subquery_error() -> Result<()> {
+    let tests = vec![
+        "SELECT number FROM numbers(10) WHERE number IN (SELECT number, number FROM numbers(3))",
+        "SELECT number FROM numbers(10) WHERE number NOT IN (SELECT * FROM system.tables)",
+    ];
+
+    for query in tests {
+        let ctx = create_query_context()?;
+        let (mut statements, _) = DfParser::parse_sql(query)?;
+
+        match statements.remove(0) {
+            DfStatement::Query(query_statement) => {
+                let result = query_statement.analyze(ctx).await;
+                assert!(result.is_err(), "{}", query);
+                assert_eq!(
+                    result.err().unwrap().code(),
+                    ErrorCode::SemanticErrorCode(),
+                    "{}",
+                    query
+                );
+            }
+            _ => {
+                return Err(ErrorCode::LogicalError("Cannot get analyze query state."));
+            }
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,108 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_base::tokio;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_planners::PlanNode;
+use common_planners::PlanShowKind;
+use common_planners::ShowPlan;
+use common_planners::ShowTablesPlan;
+use databend_query::sql::statements::AnalyzableStatement;
+use databend_query::sql::statements::AnalyzedResult;
+use databend_query::sql::DfParser;
+use databend_query::sql::DfStatement;
+
+use crate::tests::create_query_context;
+
+#[tokio::test]
+async fn test_statement_show_tables_analyze() -> Result<()> {
+    struct TestCase {
+        name: &'static str,
+        query: &'static str,
+        expect: PlanShowKind,
+    }
+
+    let tests = vec![
+        TestCase {
+            name: "Show tables",
+            query: "SHOW TABLES",
+            expect: PlanShowKind::All,
+        },
+        TestCase {
+            name: "Show tables like",
+            query: "SHOW TABLES LIKE 'user%'",
+            expect: PlanShowKind::Like("'user%'".to_string()),
+        },
+        TestCase {
+            name: "Show tables like with quote",
+            query: "SHOW TABLES LIKE 'user''s%'",
+            expect: PlanShowKind::Like("'user''s%'".to_string()),
+        },
+        TestCase {
+            name: "Show tables where",
+            query: "SHOW TABLES WHERE name LIKE 'user%' AND name != 'users'",
+            expect: PlanShowKind::Where("name LIKE 'user%' AND name <> 'users'".to_string()),
+        },
+    ];
+
+    for test_case in &tests {
+        let ctx = create_query_context()?;
+        let (mut statements, _) = DfParser::parse_sql(test_case.query)?;
+
+        match statements.remove(0) {
+            DfStatement::ShowTables(show) => match show.analyze(ctx).await? {
+                AnalyzedResult::SimpleQuery(plan) => {
+                    let expect = PlanNode::Show(ShowPlan::ShowTables(ShowTablesPlan {
+                        kind: test_case.expect.clone(),
+                    }));
+                    assert_eq!(expect, *plan, "{:#?}", test_case.name);
+                }
+                _ => {
+                    return Err(ErrorCode::LogicalError(
+                        "Show tables analyzed must be return SimpleQuery",
+                    ));
+                }
+            },
+            _ => {
+                return Err(ErrorCode::LogicalError("Cannot get show tables statement."));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_statement_show_tables_analyze_with_subquery() -> Result<()> {
+    let ctx = create_query_context()?;
+    let query = "SHOW TABLES WHERE name IN (SELECT name FROM system.databases)";
+    let (mut statements, _) = DfParser::parse_sql(query)?;
+
+    match statements.remove(0) {
+        DfStatement::ShowTables(show) => {
+            let result = show.analyze(ctx).await;
+            assert!(result.is_err());
+            assert_eq!(
+                result.err().unwrap().code(),
+                ErrorCode::SyntaxException("").code()
+            );
+        }
+        _ => {
+            return Err(ErrorCode::LogicalError("Cannot get show tables statement."));
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,103 @@
+//  Copyright 2022 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+//
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use common_base::tokio;
+use common_datablocks::DataBlock;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use databend_query::sessions::QueryContext;
+use futures::TryStreamExt;
+
+use crate::storages::fuse::table_test_fixture::append_sample_data;
+use crate::storages::fuse::table_test_fixture::execute_command;
+use crate::storages::fuse::table_test_fixture::execute_query;
+use crate::storages::fuse::table_test_fixture::TestFixture;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn test_fuse_concurrent_drop_and_read() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    fixture.create_default_table().await?;
+    append_sample_data(10, &fixture).await?;
+
+    let table = format!(
+        "{}.{}",
+        fixture.default_db_name(),
+        fixture.default_table_name()
+    );
+    drop_during_reads(fixture.ctx(), &table).await
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn test_memory_concurrent_drop_and_read() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    let ctx = fixture.ctx();
+    let table = format!("{}.t", fixture.default_db_name());
+
+    let qry = format!("create table {}(a bigint) engine = Memory", table);
+    execute_command(ctx.clone(), &qry).await?;
+    for _ in 0..10 {
+        let qry = format!("insert into {} values(1),(2),(3)", table);
+        execute_command(ctx.clone(), &qry).await?;
+    }
+
+    drop_during_reads(ctx, &table).await
+}
+
+// Scans the table from several readers while it is dropped. Every scan either
+// sees all the rows or fails as an unknown/dropped table.
+async fn drop_during_reads(ctx: Arc<QueryContext>, table: &str) -> Result<()> {
+    let query = format!("select * from {}", table);
+    let expected_rows = read_rows(ctx.clone(), &query).await?;
+    assert!(expected_rows > 0);
+
+    let readers = (0..4)
+        .map(|_| {
+            let ctx = QueryContext::create_from(ctx.clone());
+            let query = query.clone();
+            tokio::spawn(async move {
+                loop {
+                    match read_rows(ctx.clone(), &query).await {
+                        Ok(rows) => assert_eq!(rows, expected_rows),
+                        Err(cause)
+                            if cause.code() == ErrorCode::UnknownTableCode()
+                                || cause.code() == ErrorCode::TableDroppedCode() =>
+                        {
+                            return Ok(());
+                        }
+                        Err(cause) => return Err(cause),
+                    }
+                }
+            })
+        })
+        .collect::<Vec<_>>();
+
+    // Let the readers get going before the drop.
+    tokio::time::sleep(Duration::from_millis(50)).await;
+    execute_command(ctx.clone(), &format!("drop table {}", table)).await?;
+
+    for reader in readers {
+        reader.await.expect("reader panicked")?;
+    }
+    Ok(())
+}
+
+async fn read_rows(ctx: Arc<QueryContext>, query: &str) -> Result<usize> {
+    let stream = execute_query(ctx, query).await?;
+    let blocks = stream.try_collect::<Vec<DataBlock>>().await?;
+    Ok(blocks.iter().map(|block| block.num_rows()).sum())
+}
@@ -14,6 +14,7 @@
 //
 
 mod commit;
+mod drop_read;
 mod optimize;
 mod part_info;
 mod purge_drop;
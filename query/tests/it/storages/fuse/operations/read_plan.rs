@@ -88,6 +88,7 @@ fn test_to_partitions() -> Result<()> {
         filters: vec![],
         limit: None,
         order_by: vec![],
+        sample: None,
     });
     let (stats, _) = FuseTable::to_partitions(&blocks_metas, push_down);
     assert_eq!(expected_block_size * num_of_block, stats.read_bytes as u64);
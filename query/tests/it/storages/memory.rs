@@ -13,15 +13,20 @@
 //  limitations under the License.
 //
 
+use std::sync::Arc;
+
 use common_base::tokio;
 use common_datablocks::assert_blocks_sorted_eq;
 use common_datablocks::DataBlock;
 use common_datavalues2::prelude::*;
+use common_exception::ErrorCode;
 use common_exception::Result;
 use common_meta_types::TableInfo;
 use common_meta_types::TableMeta;
 use common_planners::*;
+use databend_query::sessions::QueryContext;
 use databend_query::storages::memory::MemoryTable;
+use databend_query::storages::Table;
 use databend_query::storages::ToReadDataSourcePlan;
 use futures::TryStreamExt;
 
@@ -78,6 +83,7 @@ async fn test_memorytable() -> Result<()> {
                         filters: vec![],
                         limit: None,
                         order_by: vec![],
+                        sample: None,
                     })
                 })
                 .collect();
@@ -200,3 +206,86 @@ async fn test_memorytable() -> Result<()> {
 
     Ok(())
 }
+
+async fn insert(
+    ctx: &Arc<QueryContext>,
+    table: &dyn Table,
+    schema: &DataSchemaRef,
+    values: Vec<u32>,
+) -> Result<()> {
+    let block = DataBlock::create(schema.clone(), vec![Series::from_data(values)]);
+    table
+        .commit_insertion(ctx.clone(), vec![block], false)
+        .await
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_memorytable_read_snapshot() -> Result<()> {
+    let ctx = crate::tests::create_query_context()?;
+    let schema = DataSchemaRefExt::create(vec![DataField::new("a", u32::to_data_type())]);
+    let table = MemoryTable::try_create(crate::tests::create_storage_context()?, TableInfo {
+        desc: "'default'.'a'".into(),
+        name: "a".into(),
+        ident: Default::default(),
+        meta: TableMeta {
+            schema: schema.clone(),
+            engine: "Memory".to_string(),
+            options: TableOptions::default(),
+            ..Default::default()
+        },
+    })?;
+
+    insert(&ctx, table.as_ref(), &schema, vec![1, 2]).await?;
+
+    // Rows inserted after the read is planned are not visible to it.
+    {
+        let source_plan = table.read_plan(ctx.clone(), None).await?;
+        insert(&ctx, table.as_ref(), &schema, vec![3, 4]).await?;
+
+        ctx.try_set_partitions(source_plan.parts.clone())?;
+        let stream = table.read(ctx.clone(), &source_plan).await?;
+        insert(&ctx, table.as_ref(), &schema, vec![5, 6]).await?;
+
+        let result = stream.try_collect::<Vec<_>>().await?;
+        assert_blocks_sorted_eq(
+            vec![
+                "+---+", //
+                "| a |", //
+                "+---+", //
+                "| 1 |", //
+                "| 2 |", //
+                "+---+", //
+            ],
+            &result,
+        );
+    }
+
+    // A new read sees everything committed before it is planned.
+    {
+        let source_plan = table.read_plan(ctx.clone(), None).await?;
+        ctx.try_set_partitions(source_plan.parts.clone())?;
+        let stream = table.read(ctx.clone(), &source_plan).await?;
+        let result = stream.try_collect::<Vec<_>>().await?;
+        assert_eq!(result.iter().map(|b| b.num_rows()).sum::<usize>(), 6);
+    }
+
+    // A read planned before a truncate fails instead of reading the new blocks.
+    {
+        let source_plan = table.read_plan(ctx.clone(), None).await?;
+        let truncate_plan = TruncateTablePlan {
+            db: "default".to_string(),
+            table: "a".to_string(),
+            purge: false,
+        };
+        table.truncate(ctx.clone(), truncate_plan).await?;
+
+        let result = table.read(ctx.clone(), &source_plan).await;
+        assert!(result.is_err());
+        assert_eq!(
+            result.err().unwrap().code(),
+            ErrorCode::TableVersionMismatchedCode()
+        );
+    }
+
+    Ok(())
+}
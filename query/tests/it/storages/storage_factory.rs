@@ -0,0 +1,62 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+
+use common_exception::Result;
+use databend_query::storages::StorageDescription;
+use databend_query::storages::TableOptionDescription;
+use databend_query::storages::TableOptionType;
+
+#[test]
+fn test_check_table_options() -> Result<()> {
+    let desc = StorageDescription {
+        engine_name: "CSV".to_string(),
+        comment: "CSV Storage Engine".to_string(),
+        table_options: vec![
+            TableOptionDescription::required("LOCATION", TableOptionType::String),
+            TableOptionDescription::optional("SKIP_HEADER", TableOptionType::UInt64),
+        ],
+    };
+
+    let options = |pairs: &[(&str, &str)]| -> HashMap<String, String> {
+        pairs
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect()
+    };
+
+    desc.check_table_options(&options(&[("LOCATION", "/x.csv")]))?;
+    desc.check_table_options(&options(&[
+        ("LOCATION", "/it's.csv"),
+        ("SKIP_HEADER", "1"),
+        ("COMMENT", "csv"),
+    ]))?;
+
+    // Required option missing.
+    let res = desc.check_table_options(&options(&[("SKIP_HEADER", "1")]));
+    assert_eq!(
+        res.err().unwrap().to_string(),
+        "Code: 1022, displayText = Missing required table option LOCATION for engine CSV."
+    );
+
+    // Typo in the option name.
+    let res = desc.check_table_options(&options(&[("LOCATON", "/x.csv")]));
+    assert_eq!(
+        res.err().unwrap().to_string(),
+        "Code: 1022, displayText = Unknown table option LOCATON for engine CSV, valid options are: LOCATION, SKIP_HEADER, COMMENT."
+    );
+
+    Ok(())
+}
@@ -75,6 +75,8 @@ async fn test_configs_table() -> Result<()> {
         "| mysql_handler_host                   | 127.0.0.1        | query   |             |",
         "| mysql_handler_port                   | 3307             | query   |             |",
         "| num_cpus                             | 8                | query   |             |",
+        "| pg_handler_host                      | 127.0.0.1        | query   |             |",
+        "| pg_handler_port                      | 5433             | query   |             |",
         "| management_mode                      | false            | query   |             |",
         "| rpc_tls_meta_server_root_ca_cert     |                  | meta    |             |",
         "| rpc_tls_meta_service_domain_name     | localhost        | meta    |             |",
@@ -18,6 +18,8 @@ use common_base::tokio;
 use common_datablocks::pretty_format_blocks;
 use common_exception::Result;
 use common_metrics::init_default_metrics_recorder;
+use databend_query::interpreters::InterpreterFactory;
+use databend_query::sql::PlanParser;
 use databend_query::storages::system::MetricsTable;
 use databend_query::storages::Table;
 use databend_query::storages::ToReadDataSourcePlan;
@@ -46,3 +48,32 @@ async fn test_metrics_table() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_metrics_table_with_sql() -> Result<()> {
+    init_default_metrics_recorder();
+    let ctx = crate::tests::create_query_context()?;
+
+    // Parsing any query records the parser metric.
+    let plan = PlanParser::parse(ctx.clone(), "select * from numbers(1)").await?;
+    let executor = InterpreterFactory::get(ctx.clone(), plan)?;
+    let _ = executor
+        .execute(None)
+        .await?
+        .try_collect::<Vec<_>>()
+        .await?;
+
+    let query = "select metric, kind from system.metrics where metric = 'parser_parse_usedtime'";
+    let plan = PlanParser::parse(ctx.clone(), query).await?;
+    let executor = InterpreterFactory::get(ctx.clone(), plan)?;
+    let result = executor
+        .execute(None)
+        .await?
+        .try_collect::<Vec<_>>()
+        .await?;
+
+    let output = pretty_format_blocks(result.as_slice())?;
+    assert!(output.contains("parser_parse_usedtime"));
+
+    Ok(())
+}
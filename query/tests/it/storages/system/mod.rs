@@ -21,7 +21,9 @@ mod databases_table;
 mod engines_table;
 mod functions_table;
 mod metrics_table;
+mod processes_table;
 mod query_log_table;
+mod query_stages_table;
 mod settings_table;
 mod tables_table;
 mod tracing_table;
@@ -0,0 +1,105 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use common_base::tokio;
+use common_datablocks::DataBlock;
+use common_datavalues2::DataValue;
+use common_exception::Result;
+use databend_query::interpreters::InterpreterFactory;
+use databend_query::sessions::QueryContext;
+use databend_query::sql::PlanParser;
+use databend_query::storages::system::ProcessesTable;
+use databend_query::storages::Table;
+use databend_query::storages::ToReadDataSourcePlan;
+use futures::StreamExt;
+use futures::TryStreamExt;
+
+use crate::tests::SessionManagerBuilder;
+
+async fn read_processes(ctx: Arc<QueryContext>) -> Result<Vec<DataBlock>> {
+    let table: Arc<dyn Table> = Arc::new(ProcessesTable::create(1));
+    let source_plan = table.read_plan(ctx.clone(), None).await?;
+    let stream = table.read(ctx, &source_plan).await?;
+    stream.try_collect::<Vec<_>>().await
+}
+
+// Returns (state, extra_info, query_id, elapsed_ms) of the session.
+fn process_row(blocks: &[DataBlock], session_id: &str) -> Result<Option<Vec<DataValue>>> {
+    for block in blocks {
+        for row in 0..block.num_rows() {
+            let id = block.column(0).get_checked(row)?;
+            if id == DataValue::String(session_id.as_bytes().to_vec()) {
+                let mut values = vec![];
+                for column in [4, 6, 12, 13] {
+                    values.push(block.column(column).get_checked(row)?);
+                }
+                return Ok(Some(values));
+            }
+        }
+    }
+    Ok(None)
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_processes_table_running_query() -> Result<()> {
+    let sessions = SessionManagerBuilder::create().build()?;
+    let query_session = sessions.create_session("TestSession")?;
+    let inspect_session = sessions.create_session("TestSession")?;
+    let inspect_ctx = inspect_session.create_query_context().await?;
+
+    let query = "SELECT number FROM numbers_mt(1000000)";
+    let query_ctx = query_session.create_query_context().await?;
+    query_ctx.attach_query_str(query);
+    let query_id = query_ctx.get_id();
+
+    let plan = PlanParser::parse(query_ctx.clone(), query).await?;
+    let interpreter = InterpreterFactory::get(query_ctx.clone(), plan)?;
+    let mut stream = interpreter.execute(None).await?;
+    assert!(stream.next().await.is_some());
+
+    // The query is executing.
+    {
+        let blocks = read_processes(inspect_ctx.clone()).await?;
+        let values = process_row(&blocks, &query_session.get_id())?.unwrap();
+        assert_eq!(values[0], DataValue::String(b"Query".to_vec()));
+        assert_eq!(values[1], DataValue::String(query.as_bytes().to_vec()));
+        assert_eq!(values[2], DataValue::String(query_id.into_bytes()));
+        assert!(matches!(values[3], DataValue::UInt64(_)));
+    }
+
+    while stream.next().await.is_some() {}
+    drop(stream);
+    drop(interpreter);
+    drop(query_ctx);
+
+    // The query is completed, the session is idle.
+    let mut values = vec![];
+    for _ in 0..10 {
+        let blocks = read_processes(inspect_ctx.clone()).await?;
+        values = process_row(&blocks, &query_session.get_id())?.unwrap();
+        if values[0] == DataValue::String(b"Idle".to_vec()) {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(100)).await;
+    }
+    assert_eq!(values[0], DataValue::String(b"Idle".to_vec()));
+    assert_eq!(values[1], DataValue::Null);
+    assert_eq!(values[2], DataValue::Null);
+    assert_eq!(values[3], DataValue::Null);
+
+    Ok(())
+}
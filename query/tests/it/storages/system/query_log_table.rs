@@ -15,11 +15,13 @@
 use std::sync::Arc;
 
 use common_base::tokio;
+use common_datablocks::assert_blocks_eq;
 use common_datablocks::assert_blocks_sorted_eq;
 use common_datablocks::DataBlock;
-use common_datavalues2::prelude::Series;
-use common_datavalues2::prelude::SeriesFrom;
+use common_datavalues2::prelude::*;
 use common_exception::Result;
+use databend_query::interpreters::InterpreterFactory;
+use databend_query::sql::PlanParser;
 use databend_query::storages::system::QueryLogTable;
 use databend_query::storages::Table;
 use databend_query::storages::ToReadDataSourcePlan;
@@ -67,3 +69,49 @@ async fn test_query_log_table() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_query_log_table_push_downs() -> Result<()> {
+    let ctx = crate::tests::create_query_context()?;
+    let table = ctx.get_table("system", "query_log").await?;
+    let schema = table.schema();
+
+    // A large synthetic log, one block per query.
+    let mut blocks = vec![];
+    for i in 0..1000 {
+        let mut columns = vec![];
+        for field in schema.fields() {
+            let value = match field.name().as_str() {
+                "query_id" => DataValue::String(format!("query_{}", i).into_bytes()),
+                _ => field.data_type().default_value(),
+            };
+            columns.push(field.data_type().create_column(&[value])?);
+        }
+        blocks.push(Ok(DataBlock::create(schema.clone(), columns)));
+    }
+    let input_stream = futures::stream::iter::<Vec<Result<DataBlock>>>(blocks);
+    table
+        .append_data(ctx.clone(), Box::pin(input_stream))
+        .await?;
+
+    let query = "SELECT query_id FROM system.query_log WHERE query_id = 'query_42'";
+    let plan = PlanParser::parse(ctx.clone(), query).await?;
+    let interpreter = InterpreterFactory::get(ctx.clone(), plan)?;
+    let stream = interpreter.execute(None).await?;
+    let result = stream.try_collect::<Vec<_>>().await?;
+    assert_blocks_eq(
+        vec![
+            "+----------+",
+            "| query_id |",
+            "+----------+",
+            "| query_42 |",
+            "+----------+",
+        ],
+        &result,
+    );
+
+    // Only the matching row is generated by the scan.
+    assert_eq!(ctx.get_scan_progress_value().read_rows, 1);
+
+    Ok(())
+}
@@ -0,0 +1,166 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::net::SocketAddr;
+use std::str::FromStr;
+use std::sync::Arc;
+
+use common_base::tokio;
+use common_base::tokio::sync::Notify;
+use common_datavalues2::Column;
+use common_exception::Result;
+use common_meta_types::AuthInfo;
+use common_meta_types::GrantObject;
+use common_meta_types::PasswordHashMethod;
+use common_meta_types::UserInfo;
+use common_meta_types::UserPrivilegeSet;
+use databend_query::api::DatabendQueryFlightDispatcher;
+use databend_query::api::RpcService;
+use databend_query::configs::Config;
+use databend_query::interpreters::InterpreterFactory;
+use databend_query::servers::Server;
+use databend_query::sessions::SessionManager;
+use databend_query::sql::PlanParser;
+use databend_query::storages::system::QueryStagesTable;
+use databend_query::storages::Table;
+use databend_query::storages::ToReadDataSourcePlan;
+use futures::TryStreamExt;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_query_stages_table() -> Result<()> {
+    let ctx = crate::tests::create_query_context()?;
+    let table: Arc<dyn Table> = Arc::new(QueryStagesTable::create(1));
+
+    let source_plan = table.read_plan(ctx.clone(), None).await?;
+
+    let stream = table.read(ctx, &source_plan).await?;
+    let result = stream.try_collect::<Vec<_>>().await?;
+    let block = &result[0];
+    assert_eq!(block.num_columns(), 7);
+
+    // No distributed query is running.
+    let expected = vec![
+        "+----------+----------+------+-------+-------+-----------+------------+",
+        "| query_id | stage_id | kind | nodes | state | read_rows | read_bytes |",
+        "+----------+----------+------+-------+-------+-----------+------------+",
+        "+----------+----------+------+-------+-------+-----------+------------+",
+    ];
+    common_datablocks::assert_blocks_eq(expected, result.as_slice());
+
+    Ok(())
+}
+
+// Start a query node with its flight service bound to a random local port, and register it
+// into the cluster with that address.
+async fn start_loopback_node(
+    mut conf: Config,
+) -> Result<(Arc<SessionManager>, RpcService, String)> {
+    let sessions = SessionManager::from_conf(conf.clone()).await?;
+    let mut rpc_service = RpcService {
+        abort_notify: Arc::new(Notify::new()),
+        dispatcher: Arc::new(DatabendQueryFlightDispatcher::create()),
+        sessions: sessions.clone(),
+    };
+
+    let address = rpc_service
+        .start(SocketAddr::from_str("127.0.0.1:0")?)
+        .await?;
+    conf.query.flight_api_address = address.to_string();
+
+    let cluster_discovery = sessions.get_cluster_discovery();
+    cluster_discovery.register_to_metastore(&conf).await?;
+    Ok((sessions, rpc_service, address.to_string()))
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_query_stages_table_loopback_cluster() -> Result<()> {
+    let mut conf = crate::tests::ConfigBuilder::create().config();
+    conf.query.cluster_id = "test_query_stages_loopback".to_string();
+
+    let (sessions, _rpc_service_1, _) = start_loopback_node(conf.clone()).await?;
+    let (_sessions_2, _rpc_service_2, _) = start_loopback_node(conf.clone()).await?;
+
+    let session = sessions.create_session("TestSession")?;
+    let mut user_info = UserInfo::new(
+        "root".to_string(),
+        "127.0.0.1".to_string(),
+        AuthInfo::Password {
+            hash_method: PasswordHashMethod::Sha256,
+            hash_value: Vec::from("pass"),
+        },
+    );
+    user_info.grants.grant_privileges(
+        "root",
+        "127.0.0.1",
+        &GrantObject::Global,
+        UserPrivilegeSet::available_privileges_on_global(),
+    );
+    session.set_current_user(user_info);
+
+    let ctx = session.create_query_context().await?;
+    let cluster = ctx.get_cluster();
+    assert_eq!(cluster.get_nodes().len(), 2);
+
+    let query = "SELECT sum(number) FROM numbers_mt(100000)";
+    let plan = PlanParser::parse(ctx.clone(), query).await?;
+    let interpreter = InterpreterFactory::get(ctx.clone(), plan)?;
+    let stream = interpreter.execute(None).await?;
+    let result = stream.try_collect::<Vec<_>>().await?;
+    let expected = vec![
+        "+-------------+",
+        "| sum(number) |",
+        "+-------------+",
+        "| 4999950000  |",
+        "+-------------+",
+    ];
+    common_datablocks::assert_blocks_eq(expected, result.as_slice());
+
+    let table: Arc<dyn Table> = Arc::new(QueryStagesTable::create(1));
+    let source_plan = table.read_plan(ctx.clone(), None).await?;
+    let stream = table.read(ctx.clone(), &source_plan).await?;
+    let result = stream.try_collect::<Vec<_>>().await?;
+
+    let mut stage_ids = vec![];
+    for block in &result {
+        for row in 0..block.num_rows() {
+            let value = |column: usize| -> Result<String> {
+                let bytes = block.column(column).get(row).as_string()?;
+                Ok(String::from_utf8_lossy(&bytes).to_string())
+            };
+
+            if value(0)? != ctx.get_id() {
+                continue;
+            }
+
+            stage_ids.push(value(1)?);
+            assert_eq!(value(4)?, "Finished");
+
+            // Every stage of the query is scheduled onto both nodes.
+            let nodes = value(3)?;
+            for node in cluster.get_nodes() {
+                assert!(nodes.split(',').any(|id| id == node.id), "{}", nodes);
+            }
+        }
+    }
+
+    let expected_stage_ids = ctx
+        .get_query_stages()
+        .into_iter()
+        .map(|stage| stage.stage_id)
+        .collect::<Vec<_>>();
+    assert!(!stage_ids.is_empty());
+    assert_eq!(stage_ids, expected_stage_ids);
+
+    Ok(())
+}
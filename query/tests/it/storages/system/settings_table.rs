@@ -33,19 +33,26 @@ async fn test_settings_table() -> Result<()> {
     let result = stream.try_collect::<Vec<_>>().await?;
 
     let expected = vec![
-        "+------------------------------------+---------+---------+---------+--------------------------------------------------------------------------------------------------------------------------------------------+--------+",
-        "| name                               | value   | default | level   | description                                                                                                                                | type   |",
-        "+------------------------------------+---------+---------+---------+--------------------------------------------------------------------------------------------------------------------------------------------+--------+",
-        "| enable_new_processor_framework     | 0       | 0       | SESSION | Enable new processor framework if value != 0, default value: 0                                                                             | UInt64 |",
-        "| flight_client_timeout              | 60      | 60      | SESSION | Max duration the flight client request is allowed to take in seconds. By default, it is 60 seconds                                         | UInt64 |",
-        "| max_block_size                     | 10000   | 10000   | SESSION | Maximum block size for reading                                                                                                             | UInt64 |",
-        "| max_threads                        | 2       | 16      | SESSION | The maximum number of threads to execute the request. By default, it is determined automatically.                                          | UInt64 |",
-        "| parallel_read_threads              | 1       | 1       | SESSION | The maximum number of parallelism for reading data. By default, it is 1.                                                                   | UInt64 |",
-        "| storage_occ_backoff_init_delay_ms  | 5       | 5       | SESSION | The initial retry delay in millisecond. By default, it is 5 ms.                                                                            | UInt64 |",
-        "| storage_occ_backoff_max_delay_ms   | 20000   | 20000   | SESSION | The maximum  back off delay in millisecond, once the retry interval reaches this value, it stops increasing. By default, it is 20 seconds. | UInt64 |",
-        "| storage_occ_backoff_max_elapsed_ms | 120000  | 120000  | SESSION | The maximum elapsed time after the occ starts, beyond which there will be no more retries. By default, it is 2 minutes.                    | UInt64 |",
-        "| storage_read_buffer_size           | 1048576 | 1048576 | SESSION | The size of buffer in bytes for buffered reader of dal. By default, it is 1MB.                                                             | UInt64 |",
-        "+------------------------------------+---------+---------+---------+--------------------------------------------------------------------------------------------------------------------------------------------+--------+",
+        "+------------------------------------+----------+----------+---------+--------------------------------------------------------------------------------------------------------------------------------------------+--------+",
+        "| name                               | value    | default  | level   | description                                                                                                                                | type   |",
+        "+------------------------------------+----------+----------+---------+--------------------------------------------------------------------------------------------------------------------------------------------+--------+",
+        "| enable_exchange_checksum           | 1        | 1        | SESSION | Verify the block checksum on data exchange if value != 0, default value: 1                                                                 | UInt64 |",
+        "| enable_new_processor_framework     | 0        | 0        | SESSION | Enable new processor framework if value != 0, default value: 0                                                                             | UInt64 |",
+        "| flight_client_timeout              | 60       | 60       | SESSION | Max duration the flight client request is allowed to take in seconds. By default, it is 60 seconds                                         | UInt64 |",
+        "| float_precision                    | shortest | shortest | SESSION | Digits after the point of floats in query results, or 'shortest' for the shortest round-trip representation. By default, it is shortest.   | String |",
+        "| max_aggregate_threads              | 0        | 0        | SESSION | The maximum number of threads to execute the aggregation. By default, it is 0 which means using max_threads.                               | UInt64 |",
+        "| max_block_size                     | 10000    | 10000    | SESSION | Maximum block size for reading                                                                                                             | UInt64 |",
+        "| max_expression_nodes               | 100000   | 100000   | SESSION | The maximum number of expression nodes in the query plan, larger queries are rejected before execution. By default, it is 100000.          | UInt64 |",
+        "| max_plan_depth                     | 256      | 256      | SESSION | The maximum depth of the query plan, deeper queries are rejected before execution. By default, it is 256.                                  | UInt64 |",
+        "| max_sort_threads                   | 0        | 0        | SESSION | The maximum number of threads to execute the sort. By default, it is 0 which means using max_threads.                                      | UInt64 |",
+        "| max_threads                        | 2        | 16       | SESSION | The maximum number of threads to execute the request. By default, it is determined automatically.                                          | UInt64 |",
+        "| parallel_read_threads              | 1        | 1        | SESSION | The maximum number of parallelism for reading data. By default, it is 1.                                                                   | UInt64 |",
+        "| sql_dialect                        | ansi     | ansi     | SESSION | The SQL dialect, 'ansi' or 'mysql'. In mysql dialect '||' is the logical OR instead of the string concatenation. By default, it is ansi.   | String |",
+        "| storage_occ_backoff_init_delay_ms  | 5        | 5        | SESSION | The initial retry delay in millisecond. By default, it is 5 ms.                                                                            | UInt64 |",
+        "| storage_occ_backoff_max_delay_ms   | 20000    | 20000    | SESSION | The maximum  back off delay in millisecond, once the retry interval reaches this value, it stops increasing. By default, it is 20 seconds. | UInt64 |",
+        "| storage_occ_backoff_max_elapsed_ms | 120000   | 120000   | SESSION | The maximum elapsed time after the occ starts, beyond which there will be no more retries. By default, it is 2 minutes.                    | UInt64 |",
+        "| storage_read_buffer_size           | 1048576  | 1048576  | SESSION | The size of buffer in bytes for buffered reader of dal. By default, it is 1MB.                                                             | UInt64 |",
+        "+------------------------------------+----------+----------+---------+--------------------------------------------------------------------------------------------------------------------------------------------+--------+",
     ];
     common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
 
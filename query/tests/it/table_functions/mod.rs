@@ -14,3 +14,4 @@
 
 mod memory_block_part;
 mod numbers_table;
+mod range_table;
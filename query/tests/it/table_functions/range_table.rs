@@ -0,0 +1,157 @@
+//  Copyright 2022 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+//
+
+use common_base::tokio;
+use common_datavalues2::prelude::*;
+use common_exception::Result;
+use common_planners::*;
+use databend_query::storages::ToReadDataSourcePlan;
+use databend_query::table_functions::RangeTable;
+use databend_query::table_functions::TableArgs;
+use futures::TryStreamExt;
+
+fn range_args(start: &str, end: &str, step: i64, kind: IntervalKind) -> TableArgs {
+    Some(vec![
+        Expression::create_literal(DataValue::String(start.as_bytes().to_vec())),
+        Expression::create_literal(DataValue::String(end.as_bytes().to_vec())),
+        Expression::Literal {
+            value: DataValue::Int64(step),
+            column_name: Some(step.to_string()),
+            data_type: IntervalType::arc(kind),
+        },
+    ])
+}
+
+async fn read_range(tbl_args: TableArgs) -> Result<Vec<common_datablocks::DataBlock>> {
+    let ctx = crate::tests::create_query_context()?;
+    let table = RangeTable::create("system", "range", 1, tbl_args)?;
+
+    let source_plan = table
+        .clone()
+        .as_table()
+        .read_plan(ctx.clone(), Some(Extras::default()))
+        .await?;
+    ctx.try_set_partitions(source_plan.parts.clone())?;
+
+    let stream = table.read(ctx, &source_plan).await?;
+    stream.try_collect::<Vec<_>>().await
+}
+
+#[tokio::test]
+async fn test_range_table_dates() -> Result<()> {
+    let result = read_range(range_args("2021-01-01", "2021-02-01", 1, IntervalKind::Day)).await?;
+    let rows: usize = result.iter().map(|block| block.num_rows()).sum();
+    assert_eq!(rows, 31);
+    assert_eq!(result[0].schema().field(0).data_type().name(), "Date32");
+
+    let result = read_range(range_args("2021-01-30", "2021-02-03", 2, IntervalKind::Day)).await?;
+    let expected = vec![
+        "+------------+",
+        "| range      |",
+        "+------------+",
+        "| 2021-01-30 |",
+        "| 2021-02-01 |",
+        "+------------+",
+    ];
+    common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_range_table_timestamps() -> Result<()> {
+    let result = read_range(range_args(
+        "2021-01-01 00:00:00",
+        "2021-01-01 01:00:00",
+        20,
+        IntervalKind::Minute,
+    ))
+    .await?;
+    assert_eq!(result[0].schema().field(0).data_type().name(), "DateTime32");
+    let expected = vec![
+        "+---------------------+",
+        "| range               |",
+        "+---------------------+",
+        "| 2021-01-01 00:00:00 |",
+        "| 2021-01-01 00:20:00 |",
+        "| 2021-01-01 00:40:00 |",
+        "+---------------------+",
+    ];
+    common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
+
+    // DateTime32 can't hold the timestamps before 1970.
+    let result = read_range(range_args(
+        "1969-12-31 23:00:00",
+        "1970-01-01 01:00:00",
+        20,
+        IntervalKind::Minute,
+    ))
+    .await;
+    assert_eq!(
+        result.unwrap_err().to_string(),
+        "Code: 1049, displayText = The timestamp -3600 of range is out of the range of DateTime32."
+    );
+
+    // An empty range.
+    let result = read_range(range_args("2021-01-02", "2021-01-01", 1, IntervalKind::Day)).await?;
+    let rows: usize = result.iter().map(|block| block.num_rows()).sum();
+    assert_eq!(rows, 0);
+
+    Ok(())
+}
+
+#[test]
+fn test_range_table_bad_arguments() -> Result<()> {
+    let cases = vec![
+        (
+            range_args("2021-01-01", "2021-02-01", 0, IntervalKind::Day),
+            "Code: 1006, displayText = The step of range must be a positive interval, but got 0 DAY.",
+        ),
+        (
+            range_args("2021-01-01", "2021-02-01", i64::MAX / 1000, IntervalKind::Day),
+            "Code: 1049, displayText = The step of range overflows: 9223372036854775 DAY.",
+        ),
+        (
+            range_args("2021-01-01", "2021-02-01", 1, IntervalKind::Month),
+            "Code: 1006, displayText = Unsupported interval MONTH for the step of range.",
+        ),
+        (
+            range_args("2021-01-01", "2021-02-01", 1, IntervalKind::Hour),
+            "Code: 1006, displayText = The step of a date range must be an interval of days.",
+        ),
+        (
+            range_args(
+                "1970-01-01 00:00:00",
+                "2100-01-01 00:00:00",
+                1,
+                IntervalKind::Second,
+            ),
+            "Code: 1006, displayText = Table function range generates 4102444800 rows, exceeds the limit 100000000.",
+        ),
+        (
+            Some(vec![Expression::create_literal(DataValue::UInt64(1))]),
+            "Code: 1006, displayText = Table function range must have exactly three arguments: start, end and step.",
+        ),
+    ];
+
+    for (args, expect) in cases {
+        match RangeTable::create("system", "range", 1, args) {
+            Ok(_) => panic!("expected error: {}", expect),
+            Err(cause) => assert_eq!(cause.to_string(), expect),
+        }
+    }
+
+    Ok(())
+}
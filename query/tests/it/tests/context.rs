@@ -107,6 +107,7 @@ pub fn create_catalog_context() -> Result<CatalogContext> {
         storage_factory: Arc::new(storage_factory),
         database_factory: Arc::new(database_factory),
         in_memory_data: Arc::new(Default::default()),
+        dropped_tables: Arc::new(Default::default()),
     })
 }
 
@@ -24,3 +24,7 @@ SELECT argMax(b, a) from (select number + 5 as a, number - 5 as b from numbers_m
  SELECT stddev_pop(number) between  2886.751 and 2886.752 from numbers_mt(10000);
  SELECT covar_samp(number, number) from (select * from numbers_mt(5) order by number asc);
  SELECT covar_pop(number, number) from (select * from numbers_mt(5) order by number asc);
+ SELECT varPop(number) between 1.9999 and 2.0001 from numbers_mt(5);
+ SELECT var_samp(number) between 2.4999 and 2.5001 from numbers_mt(5);
+ SELECT stddevPop(number) between 1.4142 and 1.4143 from numbers_mt(5);
+ SELECT stddevSamp(number) between 1.5811 and 1.5812 from numbers_mt(5);
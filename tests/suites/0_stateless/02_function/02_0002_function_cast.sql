@@ -32,3 +32,9 @@ SELECT toDate(toDateTime('2021-03-05 01:00:00')) = toDate('2021-03-05');
 SELECT toString(toDateTime64(1640019661000)) = '2021-12-20 17:01:01.000';
 SELECT toDate(toDateTime64(1640019661000)) = toDate('2021-12-20');
 SELECT toDateTime(toDateTime64(1640019661000)) = toDateTime('2021-12-20 17:01:01');
+SELECT DATE '2021-03-05' = toDate('2021-03-05');
+SELECT CAST('2021-03-05T01:01:01Z' AS DATE) = toDate('2021-03-05');
+SELECT toString(CAST('2021-03-05' AS Date32));
+SELECT CAST('2021-02-30' AS DATE);
+SELECT CAST('x' AS DATE) IS NULL;
+SELECT toTypeName(CAST('2021-03-05' AS DATE));
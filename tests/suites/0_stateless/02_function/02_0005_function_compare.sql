@@ -4,6 +4,10 @@ SELECT number ,number-1 , number*100 , 1> 100 ,1 < 10 FROM numbers_mt (10) order
 -- between
 select number from numbers_mt(10) where number not between 4 + 0.1  and 8 - 0.1 order by number;
 select number from numbers_mt(10) where number between 4 + 0.1  and 8 - 0.1  order by number;
+select 0 between 1 and null;
+select 5 between 1 and null;
+select 0 not between 1 and null;
+select 5 not between null and 4;
 
 -- like
 select * from system.databases where name like '%sys%';
@@ -23,6 +23,11 @@ SELECT SHA1('1234567890');
 SELECT SHA1('1');
 SELECT SHA1('1.2');
 
+-- SHA256
+SELECT SHA256('1234567890');
+SELECT SHA256('1');
+SELECT SHA256('1.2');
+
 -- XXHASH32
 SELECT XXHASH32('1234567890');
 SELECT XXHASH32('1');
@@ -5,3 +5,16 @@ select if(number>0, 'Z+', 'zero') from numbers(3) order by number;
 select if(number<1, true, null) from numbers(3) order by number;
 select toTypeName(if(number % 3 = 0, toUInt32(1), toInt64(3))) from numbers(10) limit 1;
 select toTypeName(if(number % 3 = 0, toUInt32(1), toFloat32(3))) from numbers(10) limit 1;
+select multiIf(number = 0, 'zero', number = 1, 'one', 'many') from numbers(4) order by number;
+select multiIf(number > 2, number, number > 0, 10, 0) from numbers(4) order by number;
+select multiIf(number > 2, toInt64(number), number > 0, 10, -1) from numbers(4) order by number;
+select multiIf(number > 2, number, number > 0, 10, -1) from numbers(4); -- {ErrorCode 1010}
+select multiIf(number = 1, 'one') from numbers(2); -- {ErrorCode 1005}
+select multiIf(number = 1, 'one', number = 2, 'two') from numbers(2); -- {ErrorCode 1028}
+
+CREATE TABLE IF NOT EXISTS t_multi_if(s String) Engine = Memory;
+INSERT INTO t_multi_if VALUES ('1'), (''), ('3');
+select toInt64(s) from t_multi_if; -- {ErrorCode 1010}
+select multiIf(s = '', NULL, toInt64(s)) from t_multi_if order by s;
+select multiIf(s = '', 0, toInt64(s) > 1, 2, 1) from t_multi_if order by s;
+DROP TABLE t_multi_if;
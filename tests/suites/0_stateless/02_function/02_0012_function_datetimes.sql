@@ -128,6 +128,13 @@ select '===addSeconds===';
 select addSeconds(toDateTime(1582970400), cast(61, INT32)); -- 2020-2-29T10:00:00 + 61 seconds
 select '===addSeconds===';
 
+select '===toYear===';
+select toYear(toDateTime(1615809600));
+select toYear(toDate(18701));
+select toMonth(toDate(18701)), toDayOfMonth(toDate(18701));
+select toYear(null);
+select '===toYear===';
+
 select '===toMonth===';
 select toMonth(toDateTime(1633081817));
 select toMonth(toDate(18901));
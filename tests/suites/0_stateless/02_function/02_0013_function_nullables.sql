@@ -7,4 +7,10 @@ SELECT a, isNull(a), b, isNotNull(b) FROM nullable_test ORDER BY a, b ASC;
 SELECT a FROM nullable_test WHERE a is Not Null ORDER BY a;
 SELECT b FROM nullable_test WHERE a is Null ORDER BY b;
 
+SELECT a FROM nullable_test WHERE isTrue(a = b);
+SELECT count() FROM nullable_test WHERE isNotTrue(a > 1);
+SELECT count() FROM nullable_test WHERE isFalse(a > 1);
+SELECT count() FROM nullable_test WHERE isNotFalse(a > 1);
+SELECT count() FROM nullable_test WHERE isUnknown(a > 1);
+
 DROP TABLE IF EXISTS nullable_test;
@@ -79,6 +79,9 @@ SELECT '===pow===';
 SELECT pow(2, 2);
 SELECT pow(-2, 2);
 SELECT pow(2, -2);
+SELECT pow(4, 0.5);
+SELECT pow(-8, 0.5);
+SELECT pow(-8, 1.0);
 SELECT pow(NULL, 2);
 SELECT pow(2, NULL);
 SELECT pow(NULL, number) from numbers(2);
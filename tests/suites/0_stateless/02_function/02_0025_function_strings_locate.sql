@@ -2,10 +2,16 @@ SELECT '===LOCATE===';
 SELECT LOCATE('bar', 'foobarbar');
 SELECT LOCATE('xbar', 'foobar');
 SELECT LOCATE('bar', 'foobarbar', 5);
+SELECT LOCATE('', 'foobar', 7);
+SELECT LOCATE('', 'foobar', 8);
 SELECT '===POSITION===';
 SELECT POSITION('bar' IN 'foobarbar');
 SELECT POSITION('xbar' IN 'foobar');
+SELECT POSITION('' IN 'foobar');
+SELECT POSITION('bar' IN NULL);
+SELECT POSITION(NULL IN 'foobar');
+SELECT POSITION('b' IN 'ébar');
+SELECT count() FROM numbers(100) WHERE POSITION('9' IN toString(number)) > 0;
 SELECT '===INSTR===';
 SELECT INSTR('foobarbar', 'bar');
 SELECT INSTR('foobar', 'xbar');
-
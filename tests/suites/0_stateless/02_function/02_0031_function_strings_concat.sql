@@ -4,3 +4,6 @@ SELECT CONCAT('14.3');
 SELECT CONCAT('14.3', 'SQL');
 select CONCAT(toString(number), 'a', toString(number+1)) from numbers(3) order by number;
 SELECT CONCAT(toString(number), NULL) from numbers(4);
+SELECT CONCAT('a', 1, 'b', -2);
+select CONCAT(number, '-', number + 1) from numbers(3) order by number;
+SELECT CONCAT('a', NULL, 1);
@@ -16,6 +16,8 @@ SELECT LPAD('12345', 1, 'a');
 SELECT LPAD('12345', 10, 'a');
 SELECT LPAD('12345', 10, 'abc');
 SELECT LPAD('12345', 10, 'abcde');
+SELECT LPAD('12345', 10, '');
+SELECT LPAD('12345', 3, '');
 
 SELECT '=== LPAD series, const, const ===';
 SELECT LPAD(toString(number), 3, 'a') FROM numbers(5) ORDER BY number;
@@ -50,6 +52,8 @@ SELECT RPAD('12345', 1, 'a');
 SELECT RPAD('12345', 10, 'a');
 SELECT RPAD('12345', 10, 'abc');
 SELECT RPAD('12345', 10, 'abcde');
+SELECT RPAD('12345', 10, '');
+SELECT RPAD('12345', 3, '');
 
 SELECT '=== RPAD series, const, const ===';
 SELECT RPAD(toString(number), 3, 'a') FROM numbers(5) ORDER BY number;
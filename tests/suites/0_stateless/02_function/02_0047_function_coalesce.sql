@@ -0,0 +1,25 @@
+SELECT COALESCE(1, 2);
+SELECT COALESCE(NULL, 2);
+SELECT COALESCE(NULL, NULL);
+SELECT COALESCE(NULL, NULL, 'a');
+SELECT COALESCE(number, NULL) FROM numbers(3) ORDER BY number;
+
+-- the fallback arguments are not evaluated when the previous arguments are not null
+SELECT COALESCE(number, intDiv(1, 0)) FROM numbers(3) ORDER BY number;
+SELECT COALESCE(1, intDiv(1, 0));
+SELECT COALESCE(NULL, intDiv(1, 0)); -- {ErrorCode 1006}
+
+DROP DATABASE IF EXISTS db1;
+CREATE DATABASE db1;
+USE db1;
+
+CREATE TABLE t(id Int32, a Int32, b Int32) Engine = Memory;
+INSERT INTO t VALUES (1, 1, NULL), (2, NULL, 2), (3, NULL, NULL);
+
+SELECT id, COALESCE(a, b, 0) FROM t ORDER BY id;
+SELECT id, COALESCE(a, b) FROM t ORDER BY id;
+SELECT id, COALESCE(a, intDiv(1, 0)) FROM t WHERE a IS NOT NULL ORDER BY id;
+SELECT id, COALESCE(a, b, intDiv(1, 0)) FROM t WHERE id < 3 ORDER BY id;
+SELECT id, COALESCE(a, intDiv(1, 0)) FROM t ORDER BY id; -- {ErrorCode 1006}
+
+DROP DATABASE db1;
@@ -0,0 +1,17 @@
+SELECT 'a' || 'b';
+SELECT 'a' || 'b' || 'c';
+SELECT 'a' || NULL;
+SELECT NULL || 'b';
+SELECT CONCAT('a', NULL);
+SELECT 'a' || CONCAT('b', 'c');
+
+-- '||' is the logical OR in mysql dialect
+SET sql_dialect = 'mysql';
+SELECT 1 || 0;
+SELECT 0 || 0;
+SELECT NULL || 1;
+
+SET sql_dialect = 'ansi';
+SELECT 'a' || 'b';
+
+SET sql_dialect = 'postgres'; -- {ErrorCode 1006}
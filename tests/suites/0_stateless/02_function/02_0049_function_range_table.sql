@@ -0,0 +1,9 @@
+SELECT count(*) FROM range('2021-01-01','2021-02-01', INTERVAL '1' DAY);
+SELECT min(range), max(range) FROM range('2021-01-01','2021-02-01', INTERVAL '1' DAY);
+SELECT * FROM range('2021-01-01','2021-01-10', INTERVAL '3' DAY) ORDER BY range;
+SELECT * FROM range('2021-01-01 00:00:00','2021-01-01 02:00:00', INTERVAL '45' MINUTE) ORDER BY range;
+SELECT toMonth(range) AS m, count(*) FROM range('2021-01-01','2021-03-01', INTERVAL '1' DAY) GROUP BY m ORDER BY m;
+SELECT count(*) FROM range('2021-02-01','2021-01-01', INTERVAL '1' DAY);
+SELECT * FROM range('2021-01-01','2021-02-01', INTERVAL '-1' DAY); -- {ErrorCode 1006}
+SELECT * FROM range('2021-01-01','2021-02-01', INTERVAL '1' MONTH); -- {ErrorCode 1006}
+SELECT * FROM range('2021-01-01','2021-02-01'); -- {ErrorCode 1006}
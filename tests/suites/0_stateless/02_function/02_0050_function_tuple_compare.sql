@@ -0,0 +1,10 @@
+SELECT tuple(1, 'a') = tuple(1, 'a');
+SELECT tuple(1, 'a') = tuple(1, 'b');
+SELECT tuple(1, 'a') != tuple(2, 'a');
+SELECT tuple(1, NULL) = tuple(1, NULL);
+SELECT tuple(1, NULL) = tuple(2, NULL);
+SELECT tuple(1, NULL) != tuple(2, NULL);
+SELECT count(*) FROM numbers(12) WHERE tuple(number % 2, number % 3) = tuple(1, 2);
+SELECT count(*) FROM (SELECT tuple(number % 2, number % 3) AS t FROM numbers(12) GROUP BY t);
+SELECT count(*), sum(c) FROM (SELECT tuple(number % 2, toString(number % 3)) AS t, count(*) AS c FROM numbers(12) GROUP BY t);
+SELECT tuple(1, 'a') < tuple(2, 'a'); -- {ErrorCode 1006}
@@ -0,0 +1,14 @@
+SELECT 0.1 + 0.2;
+SELECT 1 / 3;
+SELECT toFloat32(0.1);
+SELECT pow(10, 20);
+SELECT 2.0 * 3;
+SET float_precision = 3;
+SELECT 0.1 + 0.2;
+SELECT 1 / 3;
+SELECT toFloat32(0.1);
+SET float_precision = 0;
+SELECT 1 / 3;
+SET float_precision = 'shortest';
+SELECT 1 / 3;
+SET float_precision = 'x'; -- {ErrorCode 1006}
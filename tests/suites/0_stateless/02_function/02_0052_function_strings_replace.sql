@@ -0,0 +1,8 @@
+SELECT REPLACE('abcabc', 'bc', 'X');
+SELECT REPLACE('aaa', 'aa', 'b');
+SELECT REPLACE('abc', '', 'X');
+SELECT REPLACE('abc', 'x', 'X');
+SELECT REPLACE(NULL, 'a', 'X');
+SELECT REPLACE('abc', NULL, 'X');
+SELECT REPLACE('abc', 'a', NULL);
+SELECT REPLACE(toString(number), '1', 'one') FROM numbers(3) ORDER BY number;
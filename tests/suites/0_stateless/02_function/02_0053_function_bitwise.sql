@@ -0,0 +1,4 @@
+SELECT 12 & 10, 12 | 10, 12 ^ 10;
+SELECT bitAnd(number, 6), bitOr(number, 8), bitXor(number, 1) FROM numbers(4) ORDER BY number;
+SELECT bitShiftLeft(1, 3), bitShiftRight(16, 2), bitShiftLeft(1, 70), bitNot(0);
+SELECT bitAnd(1.5, 1); -- {ErrorCode 1006}
@@ -0,0 +1,9 @@
+select trim('   abc  ');
+select trim(both 'x' from 'xxabcxx');
+select trim(leading 'x' from 'xxabcxx');
+select trim(trailing 'x' from 'xxabcxx');
+select trim(both 'xy' from 'xyxabcyx');
+select trim(both '' from ' abc ');
+select trim(both 'è' from 'éabcé');
+select trim(both 'x' from null);
+select trim(null);
@@ -0,0 +1,6 @@
+select greatest(1, 3, 2), least(1, 3, 2);
+select greatest(number, 2), least(number, 2) from numbers(4) order by number;
+select greatest(1, null, 3), least(null, 2, 1);
+select greatest(null, null);
+select greatest('abc', 'abd'), least('abc', 'abd');
+select greatest(1); -- {ErrorCode 1005}
@@ -3,3 +3,19 @@ SELECT number, number + 3 FROM numbers_mt (1000) where number > 5 order by numbe
 SELECT number%3 as c1, number%2 as c2 FROM numbers_mt (10) order by c1 desc, c2 asc;
 EXPLAIN SELECT number%3 as c1, number%2 as c2 FROM numbers_mt (10) order by c1, number desc;
 SELECT number%3 as c1, number%2 as c2 FROM numbers_mt (10) order by c1, number desc;
+
+CREATE TABLE IF NOT EXISTS t_collate(id Int32, name String) Engine = Memory;
+INSERT INTO t_collate VALUES (1, 'b'), (2, 'A'), (3, 'a'), (4, 'B'), (5, 'c');
+SELECT name, id FROM t_collate ORDER BY name, id;
+SELECT name, id FROM t_collate ORDER BY name COLLATE nocase, id;
+SELECT name, id FROM t_collate ORDER BY name COLLATE nocase DESC, id;
+DROP TABLE t_collate;
+
+CREATE TABLE IF NOT EXISTS t_nulls(id Int32, name String) Engine = Memory;
+INSERT INTO t_nulls VALUES (1, 'b'), (2, NULL), (NULL, 'a'), (3, 'c');
+SELECT id FROM t_nulls ORDER BY id ASC NULLS FIRST;
+SELECT id FROM t_nulls ORDER BY id ASC NULLS LAST;
+SELECT id FROM t_nulls ORDER BY id DESC NULLS FIRST;
+SELECT id FROM t_nulls ORDER BY id DESC NULLS LAST;
+SELECT name, id FROM t_nulls ORDER BY name DESC NULLS LAST, id;
+DROP TABLE t_nulls;
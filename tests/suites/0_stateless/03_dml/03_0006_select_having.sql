@@ -4,3 +4,8 @@ SELECT * FROM numbers_mt (3) having 1=2;
 SELECT MAX(number) AS max FROM numbers_mt(10) GROUP BY number%3 HAVING max>8;
 SELECT MAX(number) AS max FROM numbers_mt(10) GROUP BY number%3 HAVING max>7 ORDER BY max;
 SELECT MAX(number) AS max FROM numbers_mt(10) GROUP BY number%3 HAVING max<7;
+SELECT sum(number) FROM numbers_mt(10) HAVING sum(number) > 40;
+SELECT sum(number) FROM numbers_mt(10) HAVING sum(number) > 50;
+SELECT sum(number) AS s FROM numbers_mt(10) HAVING s > 1;
+SELECT sum(number) FROM numbers_mt(10) HAVING count() > 5;
+SELECT sum(number) FROM numbers_mt(10) HAVING number > 1; -- {ErrorCode 1006}
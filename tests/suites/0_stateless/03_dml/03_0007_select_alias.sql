@@ -1,2 +1,5 @@
 set max_threads=1;
-SELECT (number+1) as c1, max(number) as c2 FROM numbers_mt(10) group by number+1 having c2>1 order by c1 desc, c2 asc;
\ No newline at end of file
+SELECT (number+1) as c1, max(number) as c2 FROM numbers_mt(10) group by number+1 having c2>1 order by c1 desc, c2 asc;
+SELECT number % 3 AS m, number FROM numbers(10) WHERE m = 1 ORDER BY number;
+SELECT number + 1 AS number FROM numbers(5) WHERE number > 2;
+SELECT count() AS c FROM numbers(10) WHERE c > 1; -- {ErrorCode 1065}
@@ -3,6 +3,13 @@ select sumDistinct(number) from ( select number % 100 as number from numbers(100
 select countDistinct(number) from ( select number % 100 as number from numbers(100000));
 select sumDistinct(number) /  countDistinct(number) = avgDistinct(number) from ( select number % 100 as number from numbers(100000));
 
+CREATE TABLE t_distinct_nulls(a Int64, b Int64) Engine = Memory;
+INSERT INTO t_distinct_nulls VALUES (1, 1), (NULL, 1), (2, NULL), (2, 2), (1, 1), (3, 3), (3, 4);
+select count(distinct a) from t_distinct_nulls;
+select count(distinct a, b) from t_distinct_nulls;
+select a, count(distinct b) from t_distinct_nulls where a is not null group by a order by a;
+DROP TABLE t_distinct_nulls;
+
 -- if
 select sumIf(number, number >= 100000 - 1) from numbers(100000);
 select sumIf(number, number > 100) /  countIf(number,  number > 100) = avgIf(number,  number > 100) from numbers(100000);
@@ -10,3 +10,8 @@ select '=== Test offset ===';
 select number from numbers_mt(10) order by number asc offset 5;
 select '===================';
 select number from numbers_mt(10) order by number+number asc offset 5;
+select '=== Test constant expressions ===';
+select number from numbers_mt(10) order by number asc limit 1+2 offset 2*2;
+select number from numbers_mt(10) limit 1-2; -- {ErrorCode 1005}
+select number from numbers_mt(10) order by number asc limit 10/5;
+select number from numbers_mt(10) limit 10/3; -- {ErrorCode 1005}
@@ -30,4 +30,20 @@ SELECT * FROM aggregate_table ORDER BY b;
 DROP TABLE base_table;
 DROP TABLE aggregate_table;
 
+-- Column subset test
+CREATE TABLE src_table(a Int32, b String);
+CREATE TABLE dst_table(a Int64, b String, c Int32, d Int32 NOT NULL DEFAULT 3);
+CREATE TABLE strict_table(a Int64, c Int32 NOT NULL);
+
+INSERT INTO src_table VALUES(1, 'x'),(2, 'y');
+INSERT INTO dst_table (a, b) SELECT a, b FROM src_table;
+INSERT INTO dst_table (c, a) SELECT a + 10, a FROM src_table WHERE a = 1;
+INSERT INTO dst_table (a) SELECT a, b FROM src_table; -- {ErrorCode 1006}
+INSERT INTO strict_table (a) SELECT a FROM src_table; -- {ErrorCode 1006}
+SELECT * FROM dst_table ORDER BY a, b;
+
+DROP TABLE src_table;
+DROP TABLE dst_table;
+DROP TABLE strict_table;
+
 DROP DATABASE db1;
@@ -11,4 +11,30 @@ INSERT INTO t1 (a,b,c,d) VALUES(-1, 33, '2021-08-15 10:00:00', 'string1234'),
 select * from t1;
 select sum(a),sum(b) from t1;
 
+CREATE TABLE IF NOT EXISTS t2(a UInt8, b Int32 NOT NULL, c String) Engine = Memory;
+
+INSERT INTO t2 VALUES(1, 2, 'x'), (NULL, 3, NULL), ('4', 5.0, 6);
+INSERT INTO t2 VALUES(1, 2); -- {ErrorCode 1006}
+INSERT INTO t2 VALUES(1, NULL, 'y'); -- {ErrorCode 1006}
+INSERT INTO t2 VALUES(256, 2, 'y'); -- {ErrorCode 1010}
+INSERT INTO t2 VALUES(-1, 2, 'y'); -- {ErrorCode 1010}
+
+select * from t2 order by b;
+
+CREATE TABLE IF NOT EXISTS t3(b Boolean NOT NULL) Engine = Memory;
+
+INSERT INTO t3 VALUES(NULL); -- {ErrorCode 1006}
+INSERT INTO t3 SELECT NULL; -- {ErrorCode 1006}
+INSERT INTO t3 VALUES(true);
+
+select * from t3;
+
+CREATE TABLE IF NOT EXISTS t4(a Date, b Date NOT NULL) Engine = Memory;
+
+INSERT INTO t4 VALUES('2021-02-30', '2021-03-05'); -- {ErrorCode 1010}
+INSERT INTO t4 VALUES('2021-03-05', concat('2021-02-', '30')); -- {ErrorCode 1010}
+INSERT INTO t4 VALUES(concat('2021-02-', '30'), '2021-03-05');
+
+select * from t4;
+
 DROP DATABASE db1;
@@ -0,0 +1,13 @@
+select number from numbers_mt(10) where number = (select max(number) from numbers_mt(5));
+select number from numbers_mt(10) where number > (select count() from numbers_mt(7)) order by number asc;
+select number from numbers_mt(10) where number > 5 and exists (select name from system.databases where name = toString(number)); -- {ErrorCode 1002}
+select number from numbers_mt(10) where number in (select number * 3 from numbers_mt(3)) order by number asc;
+select number from numbers_mt(5) where number not in (select number from numbers_mt(3)) order by number asc;
+select number from numbers_mt(5) where number in (select number from numbers_mt(10) where number > 100);
+select number from numbers_mt(5) where number in (select number, number from numbers_mt(3)); -- {ErrorCode 1065}
+DROP TABLE IF EXISTS t_null;
+CREATE TABLE t_null(a UInt64) Engine = Memory;
+INSERT INTO t_null VALUES (1), (NULL);
+select number from numbers_mt(5) where number in (select a from t_null);
+select number from numbers_mt(5) where number not in (select a from t_null);
+DROP TABLE t_null;
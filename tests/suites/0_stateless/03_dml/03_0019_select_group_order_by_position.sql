@@ -0,0 +1,9 @@
+SELECT number % 3 AS k, count() FROM numbers(10) GROUP BY 1 ORDER BY 2 DESC, 1;
+SELECT number % 3, number % 2 FROM numbers(10) GROUP BY 1, 2 ORDER BY 1, 2;
+SELECT number FROM numbers(5) ORDER BY 1 DESC;
+SELECT number + 1 AS n FROM numbers(3) ORDER BY (1), 1 DESC;
+SELECT * FROM numbers(3) ORDER BY 1 DESC;
+SELECT *, number % 2 FROM numbers(4) ORDER BY 2, 1 DESC;
+SELECT number % 3 FROM numbers(10) GROUP BY 2; -- {ErrorCode 1065}
+SELECT * FROM numbers(3) ORDER BY 2; -- {ErrorCode 1065}
+SELECT number FROM numbers(10) ORDER BY 0; -- {ErrorCode 1065}
@@ -0,0 +1,16 @@
+select number from numbers(3) union all select number from numbers(5) order by number;
+select number, 'a' as s from numbers(2) union all select number + 10, 'b' from numbers_mt(3) order by 2, 1 desc;
+select 'a' union all select 'a' union all (select 'a' union all select 'a');
+
+DROP TABLE IF EXISTS t1;
+CREATE TABLE t1(a UInt64, b String) Engine = Memory;
+INSERT INTO t1 VALUES (100, 'x'), (200, 'y');
+select a, b from t1 union all select number, 'n' from numbers(3) order by a;
+DROP TABLE t1;
+
+select 1 union all select 1, 2; -- {ErrorCode 1006}
+select 1 union all select 'a'; -- {ErrorCode 1007}
+select 1 union select 1; -- {ErrorCode 1005}
+select 1 intersect select 1; -- {ErrorCode 1005}
+select 1 union all select 1 order by 2; -- {ErrorCode 1005}
+select 1 union all select 1 limit 1; -- {ErrorCode 1005}
@@ -0,0 +1,5 @@
+select distinct number%3 as c from numbers(10) order by c;
+select distinct number%3 as c from numbers(10) order by c desc limit 2;
+select distinct number%2 as a, number%3 as b from numbers(12) order by a, b;
+select count() from (select distinct number%5 from numbers_mt(1000));
+select distinct number%3 from numbers(10) order by number; -- {ErrorCode 1005}
@@ -0,0 +1,4 @@
+select number % 3 as k, number from numbers(10) order by k, number limit 2 by k;
+select number % 3 as k, number from numbers(10) order by k, number limit 2 by k limit 3 offset 2;
+select number % 2 as a, number % 3 as b, number from numbers(12) order by number desc limit 1 by a, b;
+select number from numbers(10) limit 2 offset 1 by number; -- {ErrorCode 1005}
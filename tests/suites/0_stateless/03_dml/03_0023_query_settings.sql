@@ -0,0 +1,20 @@
+set max_threads = 8;
+select value from system.settings where name = 'max_threads' settings max_threads = 2;
+select value from system.settings where name = 'max_threads';
+select value from system.settings where name = 'max_block_size' limit 1 settings max_threads = 2, max_block_size = 100;
+select count() from numbers_mt(1000) settings max_threads = 1, max_block_size = 10;
+select number settings from numbers(1);
+select * from numbers(1) settings unknown_setting = 1; -- {ErrorCode 2801}
+select * from numbers(1) settings max_threads = 'x'; -- {ErrorCode 1002}
+
+DROP DATABASE IF EXISTS db1;
+CREATE DATABASE db1;
+USE db1;
+
+CREATE TABLE IF NOT EXISTS t1(a UInt64) Engine = Memory;
+INSERT INTO t1 VALUES (1), (2) SETTINGS max_block_size = 1;
+INSERT INTO t1 SELECT number FROM numbers(3) SETTINGS max_threads = 1;
+SELECT a FROM t1 ORDER BY a;
+select value from system.settings where name = 'max_threads';
+
+DROP DATABASE db1;
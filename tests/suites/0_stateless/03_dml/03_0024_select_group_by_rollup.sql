@@ -0,0 +1,7 @@
+select number % 2 as a, sum(number) from numbers(4) group by rollup(number % 2) order by a nulls last;
+select number % 2 as a, number % 3 as b, count() from numbers(6) group by rollup(number % 2, number % 3) order by a nulls last, b nulls last;
+select number % 2 as a, grouping(number % 2), count() from numbers(4) group by rollup(number % 2) order by a nulls last;
+select number % 2 as a, number % 3 as b, grouping(number % 2, number % 3) as g, count() from numbers_mt(6) group by rollup(number % 2, number % 3) having grouping(number % 2, number % 3) > 0 order by g, a nulls last;
+select number % 2 as a, grouping(number % 2) from numbers(4) group by number % 2 order by a;
+select number from numbers(4) group by rollup(); -- {ErrorCode 1005}
+select number from numbers(4) group by rollup(number), number; -- {ErrorCode 1005}
@@ -0,0 +1,3 @@
+SELECT t.* FROM numbers(3) AS t ORDER BY number;
+SELECT t.*, t.number + 1 FROM numbers(2) AS t ORDER BY number;
+SELECT x.* FROM numbers(3) AS t; -- {ErrorCode 1025}
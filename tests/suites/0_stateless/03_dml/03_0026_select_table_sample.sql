@@ -0,0 +1,7 @@
+select count() between 4000 and 6000 from numbers(10000) tablesample (50 percent);
+select count() from numbers(10000) tablesample bernoulli (100);
+select count() from numbers(10000) tablesample (0 percent);
+select count() from (select number from numbers(10000) as n tablesample (50 percent) limit 10);
+select * from numbers(10) tablesample (101 percent); -- {ErrorCode 1006}
+select * from numbers(10) tablesample (abc); -- {ErrorCode 1005}
+select * from numbers(10) with (nolock); -- {ErrorCode 1005}
@@ -0,0 +1,3 @@
+SELECT (number + 1) * 2, (number + 1) * 3 FROM numbers(3);
+SELECT count(DISTINCT (rand() + 1) - (rand() - 1)) > 1 FROM numbers(1000);
+SELECT count(DISTINCT a - b) > 1 FROM (SELECT rand() AS a, rand() AS b FROM numbers(1000));
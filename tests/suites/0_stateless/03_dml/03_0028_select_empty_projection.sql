@@ -0,0 +1,5 @@
+DROP TABLE IF EXISTS t0;
+CREATE TABLE t0() Engine = Memory;
+SELECT * FROM t0; -- {ErrorCode 1005}
+SELECT count() FROM (SELECT * FROM t0); -- {ErrorCode 1005}
+DROP TABLE t0;
@@ -0,0 +1,5 @@
+set max_threads = 8;
+explain pipeline select number from numbers_mt(80000) where number > 1;
+explain pipeline select number from numbers_mt(80000) where number > 1 settings max_threads = 1;
+explain pipeline select number from numbers_mt(80000) settings max_threads = 2, max_block_size = 40000;
+select value from system.settings where name = 'max_threads';
@@ -32,15 +32,15 @@ DESCRIBE db2.test2;
 SELECT '====END TEST CREATE TABLE LIKE STATEMENT====';
 
 SELECT '====BEGIN TEST CREATE TABLE AS SELECT STATEMENT====';
--- test 'create table as select' statement, expect db2.test3 has the data from db1.test1 with casting
-CREATE TABLE db2.test3(a Varchar, y Varchar) ENGINE=fuse AS SELECT * FROM db1.test1;
+-- test 'create table as select' statement, expect db2.test3 has the schema and the data from db1.test1
+CREATE TABLE db2.test3 ENGINE=fuse AS SELECT * FROM db1.test1;
 DESCRIBE db2.test3;
 SELECT a FROM db2.test3;
-CREATE TABLE db2.test4(a Varchar, y Varchar) ENGINE=fuse AS SELECT b, a FROM db1.test1;
+CREATE TABLE db2.test4 ENGINE=fuse AS SELECT b, a FROM db1.test1;
 DESCRIBE db2.test4;
 SELECT a FROM db2.test4;
-CREATE TABLE db2.test5(a Varchar, y Varchar) ENGINE=fuse AS SELECT b FROM db1.test1;
-SELECT a FROM db2.test5;
+-- the columns are derived from the select, they can not be defined as well
+CREATE TABLE db2.test5(a Varchar, y Varchar) ENGINE=fuse AS SELECT b FROM db1.test1; -- {ErrorCode 1065}
 SELECT '====END TEST CREATE TABLE AS SELECT STATEMENT====';
 
 -- clean up test databases
@@ -8,6 +8,17 @@ CREATE TABLE `test`.`b` (
     a bigint, b int, c varchar(255), d smallint, e Date
 ) Engine = Null COMMENT = 'test b';
 SHOW CREATE TABLE `test`.`b`;
+CREATE TABLE `test`.`c` (
+    a bigint
+) Engine = Fuse comment = 'it''s "c"' row_per_block = 100;
+SHOW CREATE TABLE `test`.`c`;
+CREATE TABLE `test`.`d` (
+    a bigint COMMENT 'primary ''id''', b int
+) Engine = Null;
+SHOW CREATE TABLE `test`.`d`;
+DESC `test`.`d`;
 DROP TABLE `test`.`a`;
 DROP TABLE `test`.`b`;
+DROP TABLE `test`.`c`;
+DROP TABLE `test`.`d`;
 DROP DATABASE `test`;
@@ -11,11 +11,13 @@ SHOW TABLES;
 SHOW TABLES LIKE 't%';
 SHOW TABLES LIKE 't2';
 SHOW TABLES LIKE 't';
+SHOW TABLES LIKE 't''%';
 
 SHOW TABLES WHERE name LIKE 't%';
 SHOW TABLES WHERE name = 't%' AND 1 = 0;
 SHOW TABLES WHERE name = 't2' OR 1 = 1;
 SHOW TABLES WHERE name = 't2' AND 1 = 1;
+SHOW TABLES WHERE name IN (SELECT 't1'); -- {ErrorCode 1005}
 
 DROP TABLE IF EXISTS t1;
 DROP TABLE IF EXISTS t2;
@@ -0,0 +1,14 @@
+DROP DATABASE IF EXISTS showtablestatus;
+CREATE DATABASE showtablestatus;
+
+CREATE TABLE showtablestatus.t1(c1 int) ENGINE = Null;
+SHOW TABLE STATUS FROM showtablestatus;
+
+CREATE TABLE showtablestatus.t2(c1 int) ENGINE = Memory;
+INSERT INTO showtablestatus.t2 VALUES(1), (2), (3);
+SELECT num_rows, num_blocks, data_bytes > 0 FROM system.tables WHERE database = 'showtablestatus' AND name = 't2';
+
+TRUNCATE TABLE showtablestatus.t2;
+SELECT num_rows, num_blocks, data_bytes FROM system.tables WHERE database = 'showtablestatus' AND name = 't2';
+
+DROP DATABASE showtablestatus;
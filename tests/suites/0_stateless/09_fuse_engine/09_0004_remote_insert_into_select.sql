@@ -40,7 +40,8 @@ create table d1(n String, a UInt8 not null, b Int16 default a + 3, c String defa
 insert into d1(a) values (1);
 insert into d1(b) values (2);
 -- https://github.com/datafuselabs/databend/issues/3636
-insert into d1(b) select b from d1;
+insert into d1(b) select b from d1; -- {ErrorCode 1006}
+insert into d1(a, b) select 0, b from d1;
 select * from d1 order by a, b;
 
 
@@ -4,5 +4,6 @@ CREATE TABLE t(a bigint, b int, c varchar(255), d smallint, e Date ) ENGINE = Nu
 
 DESCRIBE t;
 DESC t;
+DESC default.t;
 
 DROP TABLE IF EXISTS t;